@@ -0,0 +1,291 @@
+//! DWARF debug info generation.
+//!
+//! Enabled with [`CodeGen::set_debug_info`]. Every MIR function with a
+//! recorded source location (`MirModule::fn_locations`) gets a subprogram in
+//! the `.mpl` file it was defined in, its instructions carry line locations
+//! from `MirExpr::SourceLine` markers, and parameters and `let` bindings of
+//! scalar types are described as local variables. Generated functions without
+//! a location (dispatch helpers, wrappers, the C `main`) get no debug info.
+//!
+//! All debug info goes through inkwell's `DebugInfoBuilder`, which must be
+//! finalized before the module is verified.
+
+use std::path::Path;
+
+use inkwell::debug_info::{
+    AsDIScope, DICompileUnit, DIFile, DIFlags, DIFlagsConstants, DIScope, DISubprogram, DIType,
+    DWARFEmissionKind, DWARFSourceLanguage, DebugInfoBuilder,
+};
+use inkwell::module::FlagBehavior;
+use inkwell::values::{FunctionValue, InstructionValue, PointerValue};
+use rustc_hash::FxHashMap;
+
+use super::CodeGen;
+use crate::mir::{MirFunction, MirModule, MirSourceLoc, MirType};
+
+/// DWARF base type encodings (`DW_ATE_*`).
+const DW_ATE_ADDRESS: u32 = 0x01;
+const DW_ATE_BOOLEAN: u32 = 0x02;
+const DW_ATE_FLOAT: u32 = 0x04;
+const DW_ATE_SIGNED: u32 = 0x05;
+const DW_ATE_UNSIGNED: u32 = 0x08;
+
+/// Debug info state for one LLVM module.
+pub(crate) struct DebugInfo<'ctx> {
+    builder: DebugInfoBuilder<'ctx>,
+    compile_unit: DICompileUnit<'ctx>,
+    /// Source locations of MIR functions, by function name.
+    fn_locations: FxHashMap<String, MirSourceLoc>,
+    /// One file descriptor per source path.
+    files: FxHashMap<String, DIFile<'ctx>>,
+    /// Subprogram and file of the function being compiled, if it has a location.
+    scope: Option<(DISubprogram<'ctx>, DIFile<'ctx>)>,
+    /// Line of the statement being compiled.
+    line: u32,
+    is_optimized: bool,
+}
+
+impl<'ctx> DebugInfo<'ctx> {
+    fn file(&mut self, path: &str) -> DIFile<'ctx> {
+        if let Some(file) = self.files.get(path) {
+            return *file;
+        }
+        let (name, dir) = split_path(path);
+        let file = self.builder.create_file(&name, &dir);
+        self.files.insert(path.to_string(), file);
+        file
+    }
+}
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Emit DWARF debug info (compile unit, subprograms, line locations and
+    /// local variables) when compiling the next MIR module.
+    pub fn set_debug_info(&mut self, enabled: bool) {
+        self.emit_debug_info = enabled;
+    }
+
+    /// Create the debug info builder and compile unit for `mir`, if enabled.
+    ///
+    /// The compile unit is named after the file defining the entry function
+    /// (or the first test), falling back to the first file in path order.
+    pub(crate) fn init_debug_info(&mut self, mir: &MirModule) {
+        if !self.emit_debug_info {
+            return;
+        }
+
+        let main_file = mir
+            .entry_function
+            .iter()
+            .chain(mir.tests.iter().map(|t| &t.fn_name))
+            .find_map(|name| mir.fn_locations.get(name))
+            .or_else(|| mir.fn_locations.values().min_by(|a, b| a.file.cmp(&b.file)))
+            .map(|loc| loc.file.clone())
+            .unwrap_or_else(|| "main.mpl".to_string());
+        let (name, dir) = split_path(&main_file);
+        let is_optimized = self.opt_level > 0;
+
+        self.module.add_basic_value_flag(
+            "Debug Info Version",
+            FlagBehavior::Warning,
+            self.context
+                .i32_type()
+                .const_int(inkwell::debug_info::debug_metadata_version() as u64, false),
+        );
+        let (builder, compile_unit) = self.module.create_debug_info_builder(
+            true,
+            DWARFSourceLanguage::C,
+            &name,
+            &dir,
+            concat!("meshc ", env!("CARGO_PKG_VERSION")),
+            is_optimized,
+            "",
+            0,
+            "",
+            DWARFEmissionKind::Full,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+
+        let mut files = FxHashMap::default();
+        files.insert(main_file, compile_unit.get_file());
+        self.debug_info = Some(DebugInfo {
+            builder,
+            compile_unit,
+            fn_locations: mir
+                .fn_locations
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+            files,
+            scope: None,
+            line: 0,
+            is_optimized,
+        });
+    }
+
+    /// Resolve all debug info. Must run before the module is verified.
+    pub(crate) fn finalize_debug_info(&self) {
+        if let Some(debug) = &self.debug_info {
+            debug.builder.finalize();
+        }
+    }
+
+    /// Attach a subprogram to `fn_val` and start emitting locations at the
+    /// function's definition line. Functions without a recorded location
+    /// are compiled without debug locations.
+    pub(crate) fn debug_enter_function(&mut self, func: &MirFunction, fn_val: FunctionValue<'ctx>) {
+        let Some(debug) = self.debug_info.as_mut() else {
+            return;
+        };
+        let Some(loc) = debug.fn_locations.get(&func.name).cloned() else {
+            debug.scope = None;
+            self.builder.unset_current_debug_location();
+            return;
+        };
+
+        let file = debug.file(&loc.file);
+        let fn_type = debug
+            .builder
+            .create_subroutine_type(file, None, &[], DIFlags::ZERO);
+        let subprogram = debug.builder.create_function(
+            debug.compile_unit.as_debug_info_scope(),
+            &func.name,
+            None,
+            file,
+            loc.line,
+            fn_type,
+            false,
+            true,
+            loc.line,
+            DIFlags::ZERO,
+            debug.is_optimized,
+        );
+        fn_val.set_subprogram(subprogram);
+        debug.scope = Some((subprogram, file));
+        self.debug_set_line(loc.line);
+    }
+
+    /// Attribute the following instructions to `line` of the current function.
+    pub(crate) fn debug_set_line(&mut self, line: u32) {
+        let Some(debug) = self.debug_info.as_mut() else {
+            return;
+        };
+        let Some((subprogram, _)) = debug.scope else {
+            return;
+        };
+        debug.line = line;
+        let location = debug.builder.create_debug_location(
+            self.context,
+            line,
+            0,
+            subprogram.as_debug_info_scope(),
+            None,
+        );
+        self.builder.set_current_debug_location(location);
+    }
+
+    /// Describe a parameter (`arg_no` is 1-based) or `let` binding stored in
+    /// `storage`, declaring it before the instruction that initializes it.
+    /// Compiler-generated (`__`-prefixed) and `_` bindings and variables of
+    /// non-scalar types are not described.
+    pub(crate) fn debug_declare_variable(
+        &self,
+        name: &str,
+        ty: &MirType,
+        storage: PointerValue<'ctx>,
+        arg_no: Option<u32>,
+        before: InstructionValue<'ctx>,
+    ) {
+        if name == "_" || name.starts_with("__") {
+            return;
+        }
+        let Some(debug) = self.debug_info.as_ref() else {
+            return;
+        };
+        let Some((subprogram, file)) = debug.scope else {
+            return;
+        };
+        let Some(di_type) = self.debug_type(&debug.builder, ty) else {
+            return;
+        };
+        let Some(location) = self.builder.get_current_debug_location() else {
+            return;
+        };
+
+        let scope: DIScope<'ctx> = subprogram.as_debug_info_scope();
+        let variable = match arg_no {
+            Some(arg_no) => debug.builder.create_parameter_variable(
+                scope,
+                name,
+                arg_no,
+                file,
+                debug.line,
+                di_type,
+                true,
+                DIFlags::ZERO,
+            ),
+            None => debug.builder.create_auto_variable(
+                scope,
+                name,
+                file,
+                debug.line,
+                di_type,
+                true,
+                DIFlags::ZERO,
+                0,
+            ),
+        };
+        debug
+            .builder
+            .insert_declare_before_instruction(storage, Some(variable), None, location, before);
+    }
+
+    /// DWARF type for a scalar MIR type.
+    fn debug_type(&self, builder: &DebugInfoBuilder<'ctx>, ty: &MirType) -> Option<DIType<'ctx>> {
+        let pointer_bits = self.target_machine.get_target_data().get_pointer_byte_size(None) as u64 * 8;
+        let (name, bits, encoding) = match ty {
+            MirType::Int => ("Int", 64, DW_ATE_SIGNED),
+            MirType::Float => ("Float", 64, DW_ATE_FLOAT),
+            MirType::Bool => ("Bool", 8, DW_ATE_BOOLEAN),
+            MirType::String => ("String", pointer_bits, DW_ATE_ADDRESS),
+            MirType::Pid(_) => ("Pid", 64, DW_ATE_UNSIGNED),
+            _ => return None,
+        };
+        builder
+            .create_basic_type(name, bits, encoding, DIFlags::ZERO)
+            .ok()
+            .map(|t| t.as_type())
+    }
+}
+
+/// Split a source path into the file name and directory recorded in DWARF.
+fn split_path(path: &str) -> (String, String) {
+    let path = Path::new(path);
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let dir = path
+        .parent()
+        .map(|d| d.display().to_string())
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| ".".to_string());
+    (name, dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_path() {
+        assert_eq!(
+            split_path("/src/app/main.mpl"),
+            ("main.mpl".to_string(), "/src/app".to_string())
+        );
+        assert_eq!(split_path("main.mpl"), ("main.mpl".to_string(), ".".to_string()));
+    }
+}
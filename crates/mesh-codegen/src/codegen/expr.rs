@@ -6,13 +6,13 @@
 
 use inkwell::intrinsics::Intrinsic;
 use inkwell::types::BasicType;
-use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum};
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, IntValue};
 use inkwell::IntPredicate;
 
 use super::intrinsics::get_intrinsic;
 use super::types::{closure_type, variant_struct_type};
 use super::CodeGen;
-use crate::mir::{BinOp, MirChildSpec, MirExpr, MirMatchArm, MirPattern, MirType, UnaryOp};
+use crate::mir::{BinOp, MirChildSpec, MirExpr, MirLiteral, MirMatchArm, MirPattern, MirType, UnaryOp};
 use crate::pattern::compile::compile_match;
 
 impl<'ctx> CodeGen<'ctx> {
@@ -46,6 +46,14 @@ impl<'ctx> CodeGen<'ctx> {
 
             MirExpr::BinOp { op, lhs, rhs, ty } => self.codegen_binop(op, lhs, rhs, ty),
 
+            MirExpr::CheckedIntOp {
+                op,
+                lhs,
+                rhs,
+                file,
+                line,
+            } => self.codegen_checked_int_op(op, lhs, rhs, file, *line),
+
             MirExpr::UnaryOp { op, operand, ty } => self.codegen_unaryop(op, operand, ty),
 
             MirExpr::Call { func, args, ty } => self.codegen_call(func, args, ty),
@@ -115,6 +123,11 @@ impl<'ctx> CodeGen<'ctx> {
 
             MirExpr::Unit => Ok(self.context.struct_type(&[], false).const_zero().into()),
 
+            MirExpr::SourceLine(line) => {
+                self.debug_set_line(*line);
+                Ok(self.context.struct_type(&[], false).const_zero().into())
+            }
+
             // Actor primitives
             MirExpr::ActorSpawn {
                 func,
@@ -340,8 +353,7 @@ impl<'ctx> CodeGen<'ctx> {
             BinOp::Add => self.builder.build_int_add(l, r, "add").map_err(|e| e.to_string())?.into(),
             BinOp::Sub => self.builder.build_int_sub(l, r, "sub").map_err(|e| e.to_string())?.into(),
             BinOp::Mul => self.builder.build_int_mul(l, r, "mul").map_err(|e| e.to_string())?.into(),
-            BinOp::Div => self.builder.build_int_signed_div(l, r, "div").map_err(|e| e.to_string())?.into(),
-            BinOp::Mod => self.builder.build_int_signed_rem(l, r, "mod").map_err(|e| e.to_string())?.into(),
+            BinOp::Div | BinOp::Mod => self.codegen_int_div_rem(op, l, r)?,
             BinOp::Eq => self.builder.build_int_compare(IntPredicate::EQ, l, r, "eq").map_err(|e| e.to_string())?.into(),
             BinOp::NotEq => self.builder.build_int_compare(IntPredicate::NE, l, r, "ne").map_err(|e| e.to_string())?.into(),
             BinOp::Lt => self.builder.build_int_compare(IntPredicate::SLT, l, r, "lt").map_err(|e| e.to_string())?.into(),
@@ -353,6 +365,142 @@ impl<'ctx> CodeGen<'ctx> {
         Ok(result)
     }
 
+    /// `Int` `/` or `%` that wraps instead of trapping on overflow.
+    ///
+    /// LLVM leaves `sdiv`/`srem` of Int.min by -1 undefined (x86 raises
+    /// SIGFPE), so a divisor of -1 is replaced by 1 and the result selected
+    /// afterwards: `x / -1` is the wrapping negation of `x`, and `x % -1`
+    /// is 0.
+    fn codegen_int_div_rem(
+        &mut self,
+        op: &BinOp,
+        l: IntValue<'ctx>,
+        r: IntValue<'ctx>,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
+        let i64_ty = self.context.i64_type();
+        let is_minus_one = self
+            .builder
+            .build_int_compare(IntPredicate::EQ, r, i64_ty.const_all_ones(), "is_minus_one")
+            .map_err(|e| e.to_string())?;
+        let divisor = self
+            .builder
+            .build_select(is_minus_one, i64_ty.const_int(1, false), r, "divisor")
+            .map_err(|e| e.to_string())?
+            .into_int_value();
+        let (result, by_minus_one) = match op {
+            BinOp::Div => (
+                self.builder.build_int_signed_div(l, divisor, "div").map_err(|e| e.to_string())?,
+                self.builder.build_int_sub(i64_ty.const_zero(), l, "neg").map_err(|e| e.to_string())?,
+            ),
+            BinOp::Mod => (
+                self.builder.build_int_signed_rem(l, divisor, "mod").map_err(|e| e.to_string())?,
+                i64_ty.const_zero(),
+            ),
+            _ => return Err(format!("Unsupported int division: {:?}", op)),
+        };
+        self.builder
+            .build_select(is_minus_one, by_minus_one, result, "div_result")
+            .map_err(|e| e.to_string())
+    }
+
+    /// `Int` `+`, `-`, `*`, `/` or `%` that panics at `file:line` on
+    /// overflow when overflow checks are on. Addition, subtraction and
+    /// multiplication use LLVM's `*.with.overflow` intrinsics; division and
+    /// remainder overflow only for `Int.min / -1` and `Int.min % -1`, which
+    /// are tested before dividing.
+    fn codegen_checked_int_op(
+        &mut self,
+        op: &BinOp,
+        lhs: &MirExpr,
+        rhs: &MirExpr,
+        file: &str,
+        line: u32,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
+        let lhs_val = self.codegen_expr(lhs)?;
+        let rhs_val = self.codegen_expr(rhs)?;
+        if !self.overflow_checks {
+            return self.codegen_int_binop(op, lhs_val, rhs_val);
+        }
+
+        let i64_ty = self.context.i64_type();
+        let (overflowed, result) = match op {
+            BinOp::Div | BinOp::Mod => {
+                let is_min = self
+                    .builder
+                    .build_int_compare(
+                        IntPredicate::EQ,
+                        lhs_val.into_int_value(),
+                        i64_ty.const_int(i64::MIN as u64, false),
+                        "is_min",
+                    )
+                    .map_err(|e| e.to_string())?;
+                let is_minus_one = self
+                    .builder
+                    .build_int_compare(
+                        IntPredicate::EQ,
+                        rhs_val.into_int_value(),
+                        i64_ty.const_all_ones(),
+                        "is_minus_one",
+                    )
+                    .map_err(|e| e.to_string())?;
+                let overflowed = self
+                    .builder
+                    .build_and(is_min, is_minus_one, "overflowed")
+                    .map_err(|e| e.to_string())?;
+                // The division itself is emitted after the check: LLVM
+                // leaves `sdiv`/`srem` of Int.min by -1 undefined.
+                (overflowed, None)
+            }
+            _ => {
+                let intrinsic_name = match op {
+                    BinOp::Add => "llvm.sadd.with.overflow",
+                    BinOp::Sub => "llvm.ssub.with.overflow",
+                    BinOp::Mul => "llvm.smul.with.overflow",
+                    _ => return Err(format!("Unsupported checked int op: {:?}", op)),
+                };
+                let intrinsic = Intrinsic::find(intrinsic_name)
+                    .ok_or_else(|| format!("{} not found", intrinsic_name))?;
+                let decl = intrinsic
+                    .get_declaration(&self.module, &[i64_ty.into()])
+                    .ok_or_else(|| format!("Failed to get {} declaration", intrinsic_name))?;
+                let pair = self
+                    .builder
+                    .build_call(decl, &[lhs_val.into(), rhs_val.into()], "checked")
+                    .map_err(|e| e.to_string())?
+                    .try_as_basic_value()
+                    .basic()
+                    .ok_or("overflow intrinsic returned void")?
+                    .into_struct_value();
+                let result = self
+                    .builder
+                    .build_extract_value(pair, 0, "checked_result")
+                    .map_err(|e| e.to_string())?;
+                let overflowed = self
+                    .builder
+                    .build_extract_value(pair, 1, "overflowed")
+                    .map_err(|e| e.to_string())?
+                    .into_int_value();
+                (overflowed, Some(result))
+            }
+        };
+
+        let fn_val = self.current_function();
+        let overflow_bb = self.context.append_basic_block(fn_val, "overflow");
+        let ok_bb = self.context.append_basic_block(fn_val, "no_overflow");
+        self.builder
+            .build_conditional_branch(overflowed, overflow_bb, ok_bb)
+            .map_err(|e| e.to_string())?;
+
+        self.builder.position_at_end(overflow_bb);
+        self.codegen_panic(&format!("Int overflow in `{}`", op), file, line)?;
+
+        self.builder.position_at_end(ok_bb);
+        match result {
+            Some(result) => Ok(result),
+            None => self.codegen_int_binop(op, lhs_val, rhs_val),
+        }
+    }
+
     fn codegen_float_binop(
         &mut self,
         op: &BinOp,
@@ -623,6 +771,13 @@ impl<'ctx> CodeGen<'ctx> {
         args: &[MirExpr],
         ty: &MirType,
     ) -> Result<BasicValueEnum<'ctx>, String> {
+        // ~r/pattern/flags: compiled once per call site, see codegen_regex_literal.
+        if let MirExpr::Var(name, _) = func {
+            if name == "mesh_regex_literal" && args.len() == 2 {
+                return self.codegen_regex_literal(args);
+            }
+        }
+
         // Check if this is a user-defined function (declared via MirFunction).
         // User functions accept closure params as {ptr, ptr} structs directly.
         // Runtime intrinsics expect closures split into separate (fn_ptr, env_ptr) args.
@@ -1022,6 +1177,15 @@ impl<'ctx> CodeGen<'ctx> {
                                         coerced_args[i] = cast.into();
                                     }
                                 }
+                                // A boxed struct/sum value (e.g. a collection element handed
+                                // to an Eq callback) passed where the function takes it by
+                                // value: load it from the heap.
+                                else if let inkwell::types::BasicMetadataTypeEnum::StructType(st) = param_ty {
+                                    let loaded = self.builder
+                                        .build_load(*st, pv, "unbox_struct_arg")
+                                        .map_err(|e| e.to_string())?;
+                                    coerced_args[i] = loaded.into();
+                                }
                             }
                             _ => {}
                         }
@@ -1516,9 +1680,11 @@ impl<'ctx> CodeGen<'ctx> {
             val
         };
 
-        self.builder
+        let store = self
+            .builder
             .build_store(alloca, val)
             .map_err(|e| e.to_string())?;
+        self.debug_declare_variable(name, ty, alloca, None, store);
 
         // Register the variable
         let old_alloca = self.locals.insert(name.to_string(), alloca);
@@ -1576,8 +1742,17 @@ impl<'ctx> CodeGen<'ctx> {
     ) -> Result<BasicValueEnum<'ctx>, String> {
         // Evaluate the scrutinee
         let scrutinee_val = self.codegen_expr(scrutinee)?;
-        let scrutinee_ty = scrutinee.ty();
+        self.codegen_match_value(scrutinee_val, scrutinee.ty(), arms, ty)
+    }
 
+    /// Match an already evaluated scrutinee against `arms`.
+    fn codegen_match_value(
+        &mut self,
+        scrutinee_val: BasicValueEnum<'ctx>,
+        scrutinee_ty: &MirType,
+        arms: &[MirMatchArm],
+        ty: &MirType,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
         // Alloca for the scrutinee so pattern codegen can GEP into it.
         // For runtime-returned sum types (heap pointers), the value was already
         // dereferenced at the let binding site, so here it's a proper struct value.
@@ -2267,6 +2442,66 @@ impl<'ctx> CodeGen<'ctx> {
         Ok(self.context.struct_type(&[], false).const_zero().into())
     }
 
+    /// Codegen for a `~r/pattern/flags` literal, lowered as
+    /// `mesh_regex_literal(pattern, flags)`.
+    ///
+    /// Each call site gets a zeroed global slot. Once the runtime has stored
+    /// the compiled regex there, later evaluations load it without building
+    /// the pattern strings or calling into the runtime.
+    fn codegen_regex_literal(&mut self, args: &[MirExpr]) -> Result<BasicValueEnum<'ctx>, String> {
+        let i64_ty = self.context.i64_type();
+        let slot = self.module.add_global(i64_ty, None, ".regex_slot");
+        slot.set_initializer(&i64_ty.const_zero());
+        slot.set_linkage(inkwell::module::Linkage::Private);
+        let slot_ptr = slot.as_pointer_value();
+
+        // Acquire pairs with the runtime's release store of the slot, which
+        // another actor's thread may make concurrently.
+        let cached = self
+            .builder
+            .build_load(i64_ty, slot_ptr, "regex_cached")
+            .map_err(|e| e.to_string())?
+            .into_int_value();
+        let load = cached.as_instruction().ok_or("regex slot load is not an instruction")?;
+        load.set_alignment(8).map_err(|e| e.to_string())?;
+        load.set_atomic_ordering(inkwell::AtomicOrdering::Acquire).map_err(|e| e.to_string())?;
+        let is_compiled = self
+            .builder
+            .build_int_compare(IntPredicate::NE, cached, i64_ty.const_zero(), "regex_is_compiled")
+            .map_err(|e| e.to_string())?;
+
+        let fn_val = self.current_function();
+        let compile_bb = self.context.append_basic_block(fn_val, "regex_compile");
+        let merge_bb = self.context.append_basic_block(fn_val, "regex_merge");
+        let cached_bb = self.builder.get_insert_block().ok_or("no insert block")?;
+        self.builder
+            .build_conditional_branch(is_compiled, merge_bb, compile_bb)
+            .map_err(|e| e.to_string())?;
+
+        self.builder.position_at_end(compile_bb);
+        let pattern = self.codegen_expr(&args[0])?;
+        let flags = self.codegen_expr(&args[1])?;
+        let literal_fn = get_intrinsic(&self.module, "mesh_regex_literal");
+        let compiled = self
+            .builder
+            .build_call(literal_fn, &[slot_ptr.into(), pattern.into(), flags.into()], "regex")
+            .map_err(|e| e.to_string())?
+            .try_as_basic_value()
+            .basic()
+            .ok_or("mesh_regex_literal returned void")?
+            .into_int_value();
+        // Codegen of the arguments may have added blocks.
+        let compiled_bb = self.builder.get_insert_block().ok_or("no insert block")?;
+        self.builder
+            .build_unconditional_branch(merge_bb)
+            .map_err(|e| e.to_string())?;
+
+        self.builder.position_at_end(merge_bb);
+        let handle = self.builder.build_phi(i64_ty, "regex_handle").map_err(|e| e.to_string())?;
+        handle.add_incoming(&[(&cached, cached_bb), (&compiled, compiled_bb)]);
+        Ok(handle.as_basic_value())
+    }
+
     /// Codegen for Timer.send_after(pid, ms, msg).
     ///
     /// Serializes the message (3rd arg) to (ptr, size) like codegen_actor_send,
@@ -2651,8 +2886,7 @@ impl<'ctx> CodeGen<'ctx> {
 
             // msg_bb: process the received message (existing logic).
             self.builder.position_at_end(msg_bb);
-            let msg_val = self.codegen_recv_load_message(msg_ptr, result_ty)?;
-            let msg_result = self.codegen_recv_process_arms(arms, msg_val)?;
+            let msg_result = self.codegen_recv_message(arms, msg_ptr, result_ty)?;
             if self.builder.get_insert_block().unwrap().get_terminator().is_none() {
                 self.builder
                     .build_store(result_alloca, msg_result)
@@ -2671,8 +2905,30 @@ impl<'ctx> CodeGen<'ctx> {
             Ok(result)
         } else {
             // No timeout body: infinite wait path (existing behavior, no null possible).
-            let msg_val = self.codegen_recv_load_message(msg_ptr, result_ty)?;
-            self.codegen_recv_process_arms(arms, msg_val)
+            self.codegen_recv_message(arms, msg_ptr, result_ty)
+        }
+    }
+
+    /// Load a received message and run the arm it matches.
+    ///
+    /// Arms that test the message (map, literal or pinned patterns) are
+    /// compiled to a decision tree like a `case` on the message; otherwise
+    /// the first arm binds the message and runs.
+    fn codegen_recv_message(
+        &mut self,
+        arms: &[MirMatchArm],
+        msg_ptr: inkwell::values::PointerValue<'ctx>,
+        result_ty: &MirType,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
+        match recv_match_type(arms) {
+            Some(msg_ty) => {
+                let msg_val = self.codegen_recv_load_message(msg_ptr, &msg_ty)?;
+                self.codegen_match_value(msg_val, &msg_ty, arms, result_ty)
+            }
+            None => {
+                let msg_val = self.codegen_recv_load_message(msg_ptr, result_ty)?;
+                self.codegen_recv_process_arms(arms, msg_val)
+            }
         }
     }
 
@@ -2720,6 +2976,11 @@ impl<'ctx> CodeGen<'ctx> {
                     .build_load(ptr_ty, data_ptr, "msg_string")
                     .map_err(|e| e.to_string())?
             }
+            MirType::Ptr => {
+                self.builder
+                    .build_load(ptr_ty, data_ptr, "msg_ptr_val")
+                    .map_err(|e| e.to_string())?
+            }
             _ => {
                 self.builder
                     .build_load(i64_ty, data_ptr, "msg_data")
@@ -5122,3 +5383,33 @@ impl<'ctx> CodeGen<'ctx> {
         Ok(final_result)
     }
 }
+
+/// The type of the messages a `receive` matches, when its arms test them:
+/// some arm has a guard (from a pinned pattern) or a pattern other than a
+/// variable or wildcard. `None` when the first arm takes any message, or
+/// when no arm says how to load one.
+fn recv_match_type(arms: &[MirMatchArm]) -> Option<MirType> {
+    let tests = arms
+        .iter()
+        .any(|arm| arm.guard.is_some() || !matches!(arm.pattern, MirPattern::Var(..) | MirPattern::Wildcard));
+    if !tests {
+        return None;
+    }
+    arms.iter().find_map(|arm| message_pattern_type(&arm.pattern))
+}
+
+/// The message type a receive pattern implies, for the types
+/// `codegen_recv_load_message` can load.
+fn message_pattern_type(pattern: &MirPattern) -> Option<MirType> {
+    match pattern {
+        MirPattern::Map { .. } => Some(MirType::Ptr),
+        MirPattern::Literal(MirLiteral::Int(_)) => Some(MirType::Int),
+        MirPattern::Literal(MirLiteral::Float(_)) => Some(MirType::Float),
+        MirPattern::Literal(MirLiteral::String(_)) => Some(MirType::String),
+        MirPattern::Var(_, ty) if matches!(ty, MirType::Int | MirType::Float | MirType::String | MirType::Ptr) => {
+            Some(ty.clone())
+        }
+        MirPattern::Or(alternatives) => alternatives.iter().find_map(message_pattern_type),
+        _ => None,
+    }
+}
@@ -73,6 +73,10 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     let init_actor_ty = void_type.fn_type(&[i32_type.into()], false);
     module.add_function("mesh_rt_init_actor", init_actor_ty, Some(inkwell::module::Linkage::External));
 
+    // mesh_rt_set_stack_size(bytes: i64) -> void
+    let set_stack_size_ty = void_type.fn_type(&[i64_type.into()], false);
+    module.add_function("mesh_rt_set_stack_size", set_stack_size_ty, Some(inkwell::module::Linkage::External));
+
     // mesh_actor_spawn(fn_ptr: ptr, args: ptr, args_size: i64, priority: i8) -> i64
     let spawn_ty = i64_type.fn_type(
         &[ptr_type.into(), ptr_type.into(), i64_type.into(), i8_type.into()],
@@ -202,6 +206,141 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     let string_to_float_ty = ptr_type.fn_type(&[ptr_type.into()], false);
     module.add_function("mesh_string_to_float", string_to_float_ty, Some(inkwell::module::Linkage::External));
 
+    // ── Int overflow arithmetic ───────────────────────────────────────
+
+    // mesh_int_{wrapping,saturating}_{add,sub,mul}(a: i64, b: i64) -> i64
+    // mesh_int_checked_{add,sub,mul,div}(a: i64, b: i64) -> ptr (MeshOption)
+    for op in ["add", "sub", "mul"] {
+        for mode in ["wrapping", "saturating"] {
+            module.add_function(&format!("mesh_int_{}_{}", mode, op), i64_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+        }
+    }
+    for op in ["add", "sub", "mul", "div"] {
+        module.add_function(&format!("mesh_int_checked_{}", op), ptr_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    }
+
+    // ── Fixed-width integers ──────────────────────────────────────────
+
+    // mesh_<type>_from_int(val: i64) -> i64 (wraps into range)
+    // mesh_<type>_try_from_int(val: i64) -> ptr (MeshOption)
+    for ty_name in ["int8", "int16", "int32", "uint8", "uint16", "uint32", "uint64"] {
+        let from_int_ty = i64_type.fn_type(&[i64_type.into()], false);
+        module.add_function(&format!("mesh_{}_from_int", ty_name), from_int_ty, Some(inkwell::module::Linkage::External));
+        let try_from_int_ty = ptr_type.fn_type(&[i64_type.into()], false);
+        module.add_function(&format!("mesh_{}_try_from_int", ty_name), try_from_int_ty, Some(inkwell::module::Linkage::External));
+    }
+
+    // mesh_fixed_int_to_int(val: i64) -> i64
+    module.add_function("mesh_fixed_int_to_int", i64_type.fn_type(&[i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // mesh_uint64_div / mesh_uint64_rem(a: i64, b: i64) -> i64
+    module.add_function("mesh_uint64_div", i64_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_uint64_rem", i64_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // mesh_uint64_lt(a: i64, b: i64) -> i8
+    module.add_function("mesh_uint64_lt", i8_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // mesh_uint64_to_string(val: i64) -> ptr
+    module.add_function("mesh_uint64_to_string", ptr_type.fn_type(&[i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // ── Bytes ─────────────────────────────────────────────────────────
+
+    module.add_function("mesh_bytes_new", ptr_type.fn_type(&[], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_from_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_to_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_length", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_get", i64_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_slice", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_concat", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_eq", i8_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_bytes_to_string", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // ── IoData ────────────────────────────────────────────────────────
+
+    module.add_function("mesh_iodata_new", ptr_type.fn_type(&[], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_from_string", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_from_bytes", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_from_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_append", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_append_bytes", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_concat", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_byte_size", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_to_string", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iodata_to_bytes", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_to_bytes(s: ptr) -> ptr
+    module.add_function("mesh_string_to_bytes", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_from_utf8(b: ptr) -> ptr (MeshResult)
+    module.add_function("mesh_string_from_utf8", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // Unicode text: grapheme-aware String functions
+    module.add_function("mesh_string_byte_size", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_<op>(s: ptr) -> ptr (List or String)
+    module.add_function("mesh_string_graphemes", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_chars", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_codepoints", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_reverse", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_fold_case", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_normalize_nfc", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_normalize_nfd", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_at(s: ptr, index: i64) -> ptr (MeshOption)
+    module.add_function("mesh_string_at", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_index_of(s: ptr, needle: ptr) -> ptr (MeshOption)
+    module.add_function("mesh_string_index_of", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_pad_left/right(s: ptr, width: i64, pad: ptr) -> ptr
+    module.add_function("mesh_string_pad_left", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_string_pad_right", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_format_int/uint64(value: i64, spec: ptr) -> ptr
+    module.add_function("mesh_format_int", ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_format_uint64", ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_format_float(value: f64, spec: ptr) -> ptr
+    module.add_function("mesh_format_float", ptr_type.fn_type(&[f64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_format_string(value: ptr, spec: ptr) -> ptr
+    module.add_function("mesh_format_string", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_string_format(template: ptr, args: ptr) -> ptr (MeshResult)
+    module.add_function("mesh_string_format", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bytes_matches(b: ptr, spec: ptr) -> i8
+    module.add_function("mesh_bytes_matches", i8_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bytes_segment(b: ptr, spec: ptr, index: i64) -> i64
+    module.add_function("mesh_bytes_segment", i64_type.fn_type(&[ptr_type.into(), ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bytes_put_int(acc: ptr, value: i64, spec: ptr) -> ptr
+    module.add_function("mesh_bytes_put_int", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bytes_put_bytes(acc: ptr, value: ptr, size: i64) -> ptr
+    module.add_function("mesh_bytes_put_bytes", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // ── BigInt and Decimal ────────────────────────────────────────────
+
+    // Both are opaque heap values; every operation takes and returns ptrs.
+    for ty_name in ["bigint", "decimal"] {
+        // mesh_<type>_from_int(val: i64) -> ptr
+        module.add_function(&format!("mesh_{}_from_int", ty_name), ptr_type.fn_type(&[i64_type.into()], false), Some(inkwell::module::Linkage::External));
+        // mesh_<type>_parse(s: ptr) -> ptr (MeshOption)
+        module.add_function(&format!("mesh_{}_parse", ty_name), ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+        // mesh_<type>_to_string / _neg / _to_json / _from_json(a: ptr) -> ptr
+        for op in ["to_string", "neg", "to_json", "from_json"] {
+            module.add_function(&format!("mesh_{}_{}", ty_name, op), ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+        }
+        // mesh_<type>_<op>(a: ptr, b: ptr) -> ptr
+        for op in ["add", "sub", "mul", "div", "mod"] {
+            module.add_function(&format!("mesh_{}_{}", ty_name, op), ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+        }
+        // mesh_<type>_eq(a: ptr, b: ptr) -> i8
+        module.add_function(&format!("mesh_{}_eq", ty_name), i8_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+        // mesh_<type>_compare(a: ptr, b: ptr) -> i64 (-1, 0 or 1)
+        module.add_function(&format!("mesh_{}_compare", ty_name), i64_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+        // mesh_<type>_hash(a: ptr) -> i64
+        module.add_function(&format!("mesh_{}_hash", ty_name), i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    }
+    // mesh_bigint_to_int(a: ptr) -> ptr (MeshOption)
+    module.add_function("mesh_bigint_to_int", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bigint_abs(a: ptr) -> ptr
+    module.add_function("mesh_bigint_abs", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_bigint_pow(a: ptr, exp: i64) -> ptr
+    module.add_function("mesh_bigint_pow", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_decimal_to_float(d: ptr) -> f64
+    module.add_function("mesh_decimal_to_float", f64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_decimal_scale(d: ptr) -> i64
+    module.add_function("mesh_decimal_scale", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_decimal_round(d: ptr, scale: i64) -> ptr
+    module.add_function("mesh_decimal_round", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+
     // ── Standard library: File I/O functions (Phase 8) ────────────────
 
     // mesh_file_read(path: ptr) -> ptr (MeshResult)
@@ -216,6 +355,10 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     let file_append_ty = ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false);
     module.add_function("mesh_file_append", file_append_ty, Some(inkwell::module::Linkage::External));
 
+    // mesh_file_write_iodata/mesh_file_append_iodata(path: ptr, content: ptr) -> ptr (MeshResult)
+    module.add_function("mesh_file_write_iodata", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_file_append_iodata", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+
     // mesh_file_exists(path: ptr) -> i8
     let file_exists_ty = i8_type.fn_type(&[ptr_type.into()], false);
     module.add_function("mesh_file_exists", file_exists_ty, Some(inkwell::module::Linkage::External));
@@ -234,13 +377,17 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     let io_eprintln_ty = void_type.fn_type(&[ptr_type.into()], false);
     module.add_function("mesh_io_eprintln", io_eprintln_ty, Some(inkwell::module::Linkage::External));
 
+    // mesh_io_write(s: ptr) -> void, mesh_io_write_iodata(io: ptr) -> void
+    module.add_function("mesh_io_write", void_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_io_write_iodata", void_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+
     // ── Standard library: Env functions (Phase 8) ────────────────────
 
     // mesh_env_get(key: ptr) -> ptr (MeshOption)
     let env_get_ty = ptr_type.fn_type(&[ptr_type.into()], false);
     module.add_function("mesh_env_get", env_get_ty, Some(inkwell::module::Linkage::External));
 
-    // mesh_env_args() -> ptr (packed array)
+    // mesh_env_args() -> ptr (List<String>)
     let env_args_ty = ptr_type.fn_type(&[], false);
     module.add_function("mesh_env_args", env_args_ty, Some(inkwell::module::Linkage::External));
 
@@ -250,6 +397,7 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_list_new", ptr_type.fn_type(&[], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_list_length", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_list_append", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_list_prepend", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_list_head", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_list_tail", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_list_get", i64_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
@@ -288,6 +436,7 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_map_new", ptr_type.fn_type(&[], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_map_new_typed", ptr_type.fn_type(&[i64_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_map_tag_string", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_map_tag_hashed", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_map_put", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_map_get", i64_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_map_has_key", i8_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
@@ -315,12 +464,15 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_set_difference", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_set_to_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_set_from_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // Set<T>: order-insensitive equality, with the sets tagged for the element type
+    module.add_function("mesh_set_eq", i8_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
     // Tuple functions
     module.add_function("mesh_tuple_nth", i64_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_tuple_first", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_tuple_second", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_tuple_size", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_tuple_to_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
     // Range functions
     module.add_function("mesh_range_new", ptr_type.fn_type(&[i64_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
@@ -336,6 +488,10 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_queue_peek", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_queue_size", i64_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     module.add_function("mesh_queue_is_empty", i8_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_queue_to_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_queue_from_list", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_queue_eq", i8_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_queue_to_string", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
     // ── Standard library: JSON functions (Phase 8 Plan 04) ──────────────
 
@@ -442,6 +598,9 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     // mesh_ws_send_binary(conn: ptr, data: ptr, len: i64) -> i64
     module.add_function("mesh_ws_send_binary", i64_type.fn_type(&[ptr_type.into(), ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
 
+    // mesh_ws_send_iodata(conn: ptr, msg: ptr) -> i64
+    module.add_function("mesh_ws_send_iodata", i64_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+
     // mesh_ws_serve_tls(on_connect_fn: ptr, on_connect_env: ptr, on_message_fn: ptr, on_message_env: ptr, on_close_fn: ptr, on_close_env: ptr, port: i64, cert_path: ptr, key_path: ptr) -> void
     module.add_function("mesh_ws_serve_tls", void_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into(), i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
@@ -464,6 +623,11 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     // mesh_http_response_with_headers(status: i64, body: ptr, headers: ptr) -> ptr
     module.add_function("mesh_http_response_with_headers", ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
+    // IoData bodies: mesh_http_response_new_iodata(status: i64, body: ptr) -> ptr,
+    // mesh_http_response_with_headers_iodata(status: i64, body: ptr, headers: ptr) -> ptr
+    module.add_function("mesh_http_response_new_iodata", ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_http_response_with_headers_iodata", ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+
     // mesh_http_get(url: ptr) -> ptr (MeshResult)
     module.add_function("mesh_http_get", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
@@ -644,6 +808,16 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
         ptr_type.fn_type(&[ptr_type.into()], false),
         Some(inkwell::module::Linkage::External));
 
+    // mesh_row_parse_bigint(s: ptr) -> ptr (MeshResult)
+    module.add_function("mesh_row_parse_bigint",
+        ptr_type.fn_type(&[ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_row_parse_decimal(s: ptr) -> ptr (MeshResult)
+    module.add_function("mesh_row_parse_decimal",
+        ptr_type.fn_type(&[ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
     // mesh_pg_query_as(conn: i64, sql: ptr, params: ptr, from_row_fn: ptr) -> ptr (MeshResult)
     module.add_function("mesh_pg_query_as",
         ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into()], false),
@@ -757,6 +931,38 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
         ),
     );
 
+    // ── Test framework ──────────────────────────────────────────────────
+
+    // mesh_assert_fail(msg: ptr, file: ptr, line: i64) -> void
+    // (noreturn -- crashes the current actor or exits the process)
+    let assert_fail_fn = module.add_function(
+        "mesh_assert_fail",
+        void_type.fn_type(&[ptr_type.into(), ptr_type.into(), i64_type.into()], false),
+        Some(inkwell::module::Linkage::External),
+    );
+    assert_fail_fn.add_attribute(
+        inkwell::attributes::AttributeLoc::Function,
+        context.create_enum_attribute(
+            inkwell::attributes::Attribute::get_named_enum_kind_id("noreturn"),
+            0,
+        ),
+    );
+
+    // mesh_test_register(name: ptr, name_len: i64, module: ptr, module_len: i64, fn: ptr) -> void
+    module.add_function("mesh_test_register",
+        void_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into(), i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_test_run() -> i32
+    module.add_function("mesh_test_run",
+        i32_type.fn_type(&[], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_register_symbol(symbol: ptr, symbol_len: i64, name: ptr, name_len: i64) -> void
+    module.add_function("mesh_register_symbol",
+        void_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into(), i64_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
     // ── Phase 67: Node distribution & remote spawn ──────────────────────
 
     // mesh_node_start(name_ptr: ptr, name_len: i64, cookie_ptr: ptr, cookie_len: i64) -> i64
@@ -853,6 +1059,39 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_iter_enumerate", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     // mesh_iter_zip(source_a: ptr, source_b: ptr) -> ptr
     module.add_function("mesh_iter_zip", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_take_while(source: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_take_while", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_skip_while(source: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_skip_while", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_flat_map(source: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_flat_map", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_chain(source_a: ptr, source_b: ptr) -> ptr
+    module.add_function("mesh_iter_chain", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_chunks(source: ptr, n: i64) -> ptr
+    module.add_function("mesh_iter_chunks", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_windows(source: ptr, n: i64) -> ptr
+    module.add_function("mesh_iter_windows", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_step_by(source: ptr, n: i64) -> ptr
+    module.add_function("mesh_iter_step_by", ptr_type.fn_type(&[ptr_type.into(), i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_dedup(source: ptr) -> ptr
+    module.add_function("mesh_iter_dedup", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_dedup_by(source: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_dedup_by", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_scan(source: ptr, init: i64, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_scan", ptr_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_inspect(source: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_inspect", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+
+    // Generators
+    // mesh_iter_repeat(value: i64) -> ptr
+    module.add_function("mesh_iter_repeat", ptr_type.fn_type(&[i64_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_unfold(state: i64, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_unfold", ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_iterate(value: i64, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_iter_iterate", ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_chars / mesh_iter_graphemes(s: ptr) -> ptr
+    module.add_function("mesh_iter_chars", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    module.add_function("mesh_iter_graphemes", ptr_type.fn_type(&[ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
     // Terminals
     // mesh_iter_count(iter: ptr) -> i64
@@ -867,6 +1106,14 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_iter_find", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
     // mesh_iter_reduce(iter: ptr, init: i64, fn_ptr: ptr, env_ptr: ptr) -> i64
     module.add_function("mesh_iter_reduce", i64_type.fn_type(&[ptr_type.into(), i64_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_min_by(iter: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr (MeshOption)
+    module.add_function("mesh_iter_min_by", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_max_by(iter: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr (MeshOption)
+    module.add_function("mesh_iter_max_by", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_group_by(iter: ptr, fn_ptr: ptr, env_ptr: ptr, groups: ptr) -> ptr (Map)
+    module.add_function("mesh_iter_group_by", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
+    // mesh_iter_partition(iter: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr (tuple of two Lists)
+    module.add_function("mesh_iter_partition", ptr_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false), Some(inkwell::module::Linkage::External));
 
     // Adapter _next functions (for resolve_iterator_fn dispatch)
     // mesh_iter_generic_next(iter: ptr) -> ptr (MeshOption)
@@ -1217,6 +1464,48 @@ pub fn declare_intrinsics<'ctx>(module: &Module<'ctx>) {
     module.add_function("mesh_global_unregister",
         i64_type.fn_type(&[ptr_type.into(), i64_type.into()], false),
         Some(inkwell::module::Linkage::External));
+
+    // ── Regex ───────────────────────────────────────────────────────────
+
+    // mesh_regex_compile(pattern: ptr) -> ptr (Result<Regex, String>)
+    module.add_function("mesh_regex_compile",
+        ptr_type.fn_type(&[ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_literal(slot: ptr, pattern: ptr, flags: ptr) -> i64
+    module.add_function("mesh_regex_literal",
+        i64_type.fn_type(&[ptr_type.into(), ptr_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_is_match(re: i64, s: ptr) -> i8
+    module.add_function("mesh_regex_is_match",
+        i8_type.fn_type(&[i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_captures(re: i64, s: ptr) -> ptr
+    module.add_function("mesh_regex_captures",
+        ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_named_captures(re: i64, s: ptr) -> ptr
+    module.add_function("mesh_regex_named_captures",
+        ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_scan(re: i64, s: ptr) -> ptr
+    module.add_function("mesh_regex_scan",
+        ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_replace(re: i64, s: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+    module.add_function("mesh_regex_replace",
+        ptr_type.fn_type(&[i64_type.into(), ptr_type.into(), ptr_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
+
+    // mesh_regex_split(re: i64, s: ptr) -> ptr
+    module.add_function("mesh_regex_split",
+        ptr_type.fn_type(&[i64_type.into(), ptr_type.into()], false),
+        Some(inkwell::module::Linkage::External));
 }
 
 /// Get a runtime function by name from the module.
@@ -1250,9 +1539,14 @@ mod tests {
         assert!(module.get_function("mesh_print").is_some());
         assert!(module.get_function("mesh_println").is_some());
         assert!(module.get_function("mesh_panic").is_some());
+        assert!(module.get_function("mesh_assert_fail").is_some());
+        assert!(module.get_function("mesh_test_register").is_some());
+        assert!(module.get_function("mesh_test_run").is_some());
+        assert!(module.get_function("mesh_register_symbol").is_some());
 
         // Actor runtime functions
         assert!(module.get_function("mesh_rt_init_actor").is_some());
+        assert!(module.get_function("mesh_rt_set_stack_size").is_some());
         assert!(module.get_function("mesh_actor_spawn").is_some());
         assert!(module.get_function("mesh_actor_send").is_some());
         assert!(module.get_function("mesh_actor_receive").is_some());
@@ -1290,8 +1584,60 @@ mod tests {
         assert!(module.get_function("mesh_string_join").is_some());
         assert!(module.get_function("mesh_string_to_int").is_some());
         assert!(module.get_function("mesh_string_to_float").is_some());
+        assert!(module.get_function("mesh_int_wrapping_add").is_some());
+        assert!(module.get_function("mesh_int_checked_mul").is_some());
+        assert!(module.get_function("mesh_int_checked_div").is_some());
+        assert!(module.get_function("mesh_int_saturating_sub").is_some());
+        assert!(module.get_function("mesh_uint8_from_int").is_some());
+        assert!(module.get_function("mesh_int32_try_from_int").is_some());
+        assert!(module.get_function("mesh_fixed_int_to_int").is_some());
+        assert!(module.get_function("mesh_uint64_div").is_some());
+        assert!(module.get_function("mesh_uint64_rem").is_some());
+        assert!(module.get_function("mesh_uint64_lt").is_some());
+        assert!(module.get_function("mesh_uint64_to_string").is_some());
+        assert!(module.get_function("mesh_bytes_new").is_some());
+        assert!(module.get_function("mesh_bytes_from_list").is_some());
+        assert!(module.get_function("mesh_bytes_to_list").is_some());
+        assert!(module.get_function("mesh_bytes_length").is_some());
+        assert!(module.get_function("mesh_bytes_get").is_some());
+        assert!(module.get_function("mesh_bytes_slice").is_some());
+        assert!(module.get_function("mesh_bytes_concat").is_some());
+        assert!(module.get_function("mesh_bytes_eq").is_some());
+        assert!(module.get_function("mesh_bytes_to_string").is_some());
+        assert!(module.get_function("mesh_iodata_new").is_some());
+        assert!(module.get_function("mesh_iodata_append").is_some());
+        assert!(module.get_function("mesh_iodata_concat").is_some());
+        assert!(module.get_function("mesh_iodata_to_string").is_some());
+        assert!(module.get_function("mesh_iodata_to_bytes").is_some());
+        assert!(module.get_function("mesh_string_to_bytes").is_some());
+        assert!(module.get_function("mesh_string_from_utf8").is_some());
+        assert!(module.get_function("mesh_string_byte_size").is_some());
+        assert!(module.get_function("mesh_string_graphemes").is_some());
+        assert!(module.get_function("mesh_string_at").is_some());
+        assert!(module.get_function("mesh_string_index_of").is_some());
+        assert!(module.get_function("mesh_string_pad_left").is_some());
+        assert!(module.get_function("mesh_string_normalize_nfd").is_some());
+        assert!(module.get_function("mesh_format_int").is_some());
+        assert!(module.get_function("mesh_format_uint64").is_some());
+        assert!(module.get_function("mesh_format_float").is_some());
+        assert!(module.get_function("mesh_format_string").is_some());
+        assert!(module.get_function("mesh_string_format").is_some());
+        assert!(module.get_function("mesh_bytes_matches").is_some());
+        assert!(module.get_function("mesh_bytes_segment").is_some());
+        assert!(module.get_function("mesh_bytes_put_int").is_some());
+        assert!(module.get_function("mesh_bytes_put_bytes").is_some());
+        assert!(module.get_function("mesh_bigint_add").is_some());
+        assert!(module.get_function("mesh_bigint_compare").is_some());
+        assert!(module.get_function("mesh_bigint_pow").is_some());
+        assert!(module.get_function("mesh_decimal_div").is_some());
+        assert!(module.get_function("mesh_decimal_round").is_some());
+        assert!(module.get_function("mesh_decimal_to_float").is_some());
         assert!(module.get_function("mesh_io_read_line").is_some());
         assert!(module.get_function("mesh_io_eprintln").is_some());
+        assert!(module.get_function("mesh_io_write").is_some());
+        assert!(module.get_function("mesh_io_write_iodata").is_some());
+        assert!(module.get_function("mesh_file_write_iodata").is_some());
+        assert!(module.get_function("mesh_file_append_iodata").is_some());
         assert!(module.get_function("mesh_env_get").is_some());
         assert!(module.get_function("mesh_env_args").is_some());
 
@@ -1299,6 +1645,7 @@ mod tests {
         assert!(module.get_function("mesh_list_new").is_some());
         assert!(module.get_function("mesh_list_length").is_some());
         assert!(module.get_function("mesh_list_append").is_some());
+        assert!(module.get_function("mesh_list_prepend").is_some());
         assert!(module.get_function("mesh_list_head").is_some());
         assert!(module.get_function("mesh_list_tail").is_some());
         assert!(module.get_function("mesh_list_get").is_some());
@@ -1330,6 +1677,7 @@ mod tests {
         assert!(module.get_function("mesh_map_put").is_some());
         assert!(module.get_function("mesh_map_get").is_some());
         assert!(module.get_function("mesh_map_has_key").is_some());
+        assert!(module.get_function("mesh_map_tag_hashed").is_some());
         assert!(module.get_function("mesh_map_delete").is_some());
         assert!(module.get_function("mesh_map_size").is_some());
         assert!(module.get_function("mesh_map_keys").is_some());
@@ -1350,10 +1698,12 @@ mod tests {
         assert!(module.get_function("mesh_set_difference").is_some());
         assert!(module.get_function("mesh_set_to_list").is_some());
         assert!(module.get_function("mesh_set_from_list").is_some());
+        assert!(module.get_function("mesh_set_eq").is_some());
         assert!(module.get_function("mesh_tuple_nth").is_some());
         assert!(module.get_function("mesh_tuple_first").is_some());
         assert!(module.get_function("mesh_tuple_second").is_some());
         assert!(module.get_function("mesh_tuple_size").is_some());
+        assert!(module.get_function("mesh_tuple_to_list").is_some());
         assert!(module.get_function("mesh_range_new").is_some());
         assert!(module.get_function("mesh_range_to_list").is_some());
         assert!(module.get_function("mesh_range_map").is_some());
@@ -1365,6 +1715,10 @@ mod tests {
         assert!(module.get_function("mesh_queue_peek").is_some());
         assert!(module.get_function("mesh_queue_size").is_some());
         assert!(module.get_function("mesh_queue_is_empty").is_some());
+        assert!(module.get_function("mesh_queue_to_list").is_some());
+        assert!(module.get_function("mesh_queue_from_list").is_some());
+        assert!(module.get_function("mesh_queue_eq").is_some());
+        assert!(module.get_function("mesh_queue_to_string").is_some());
 
         // HTTP functions (Phase 8 Plan 05)
         assert!(module.get_function("mesh_http_router").is_some());
@@ -1372,6 +1726,8 @@ mod tests {
         assert!(module.get_function("mesh_http_serve").is_some());
         assert!(module.get_function("mesh_http_serve_tls").is_some());
         assert!(module.get_function("mesh_http_response_new").is_some());
+        assert!(module.get_function("mesh_http_response_new_iodata").is_some());
+        assert!(module.get_function("mesh_http_response_with_headers_iodata").is_some());
         assert!(module.get_function("mesh_http_get").is_some());
         assert!(module.get_function("mesh_http_post").is_some());
         assert!(module.get_function("mesh_http_request_method").is_some());
@@ -1426,6 +1782,8 @@ mod tests {
         assert!(module.get_function("mesh_row_parse_int").is_some());
         assert!(module.get_function("mesh_row_parse_float").is_some());
         assert!(module.get_function("mesh_row_parse_bool").is_some());
+        assert!(module.get_function("mesh_row_parse_bigint").is_some());
+        assert!(module.get_function("mesh_row_parse_decimal").is_some());
         assert!(module.get_function("mesh_pg_query_as").is_some());
         assert!(module.get_function("mesh_pool_query_as").is_some());
 
@@ -1498,6 +1856,7 @@ mod tests {
         assert!(module.get_function("mesh_ws_serve").is_some());
         assert!(module.get_function("mesh_ws_send").is_some());
         assert!(module.get_function("mesh_ws_send_binary").is_some());
+        assert!(module.get_function("mesh_ws_send_iodata").is_some());
         assert!(module.get_function("mesh_ws_serve_tls").is_some());
 
         // WebSocket Room functions (Phase 62)
@@ -1547,6 +1906,26 @@ mod tests {
         assert!(module.get_function("mesh_iter_all").is_some());
         assert!(module.get_function("mesh_iter_find").is_some());
         assert!(module.get_function("mesh_iter_reduce").is_some());
+        assert!(module.get_function("mesh_iter_take_while").is_some());
+        assert!(module.get_function("mesh_iter_skip_while").is_some());
+        assert!(module.get_function("mesh_iter_flat_map").is_some());
+        assert!(module.get_function("mesh_iter_chain").is_some());
+        assert!(module.get_function("mesh_iter_chunks").is_some());
+        assert!(module.get_function("mesh_iter_windows").is_some());
+        assert!(module.get_function("mesh_iter_step_by").is_some());
+        assert!(module.get_function("mesh_iter_dedup").is_some());
+        assert!(module.get_function("mesh_iter_dedup_by").is_some());
+        assert!(module.get_function("mesh_iter_scan").is_some());
+        assert!(module.get_function("mesh_iter_inspect").is_some());
+        assert!(module.get_function("mesh_iter_repeat").is_some());
+        assert!(module.get_function("mesh_iter_unfold").is_some());
+        assert!(module.get_function("mesh_iter_iterate").is_some());
+        assert!(module.get_function("mesh_iter_chars").is_some());
+        assert!(module.get_function("mesh_iter_graphemes").is_some());
+        assert!(module.get_function("mesh_iter_min_by").is_some());
+        assert!(module.get_function("mesh_iter_max_by").is_some());
+        assert!(module.get_function("mesh_iter_group_by").is_some());
+        assert!(module.get_function("mesh_iter_partition").is_some());
         assert!(module.get_function("mesh_iter_generic_next").is_some());
         assert!(module.get_function("mesh_iter_map_next").is_some());
         assert!(module.get_function("mesh_iter_filter_next").is_some());
@@ -1577,6 +1956,16 @@ mod tests {
         assert!(module.get_function("mesh_migration_drop_index").is_some());
         assert!(module.get_function("mesh_migration_execute").is_some());
 
+        // Regex
+        assert!(module.get_function("mesh_regex_compile").is_some());
+        assert!(module.get_function("mesh_regex_literal").is_some());
+        assert!(module.get_function("mesh_regex_is_match").is_some());
+        assert!(module.get_function("mesh_regex_captures").is_some());
+        assert!(module.get_function("mesh_regex_named_captures").is_some());
+        assert!(module.get_function("mesh_regex_scan").is_some());
+        assert!(module.get_function("mesh_regex_replace").is_some());
+        assert!(module.get_function("mesh_regex_split").is_some());
+
         // Phase 98: Query Builder
         assert!(module.get_function("mesh_query_from").is_some());
         assert!(module.get_function("mesh_query_where").is_some());
@@ -10,12 +10,15 @@
 //! - [`intrinsics`]: Runtime function declarations
 //! - [`expr`]: Expression codegen (implemented in Task 2)
 //! - [`pattern`]: Decision tree codegen (implemented in Task 2)
+//! - [`debug`]: DWARF debug info
 
+pub mod debug;
 pub mod expr;
 pub mod intrinsics;
 pub mod pattern;
 pub mod types;
 
+use std::collections::HashSet;
 use std::path::Path;
 
 use inkwell::builder::Builder;
@@ -30,7 +33,8 @@ use inkwell::values::{FunctionValue, PointerValue};
 use inkwell::OptimizationLevel;
 use rustc_hash::FxHashMap;
 
-use crate::mir::{MirFunction, MirModule, MirStructDef, MirSumTypeDef, MirType};
+use crate::RuntimeConfig;
+use crate::mir::{MirFunction, MirModule, MirStructDef, MirSumTypeDef, MirTest, MirType};
 
 use self::types::{create_sum_type_layout, llvm_closure_fn_type, llvm_fn_type, llvm_type};
 
@@ -85,6 +89,10 @@ pub struct CodeGen<'ctx> {
     /// Each entry is (cond_bb, merge_bb) for the innermost enclosing while loop.
     pub(crate) loop_stack: Vec<(inkwell::basic_block::BasicBlock<'ctx>, inkwell::basic_block::BasicBlock<'ctx>)>,
 
+    /// Source names (`Module.fn/arity`) of MIR functions, registered with
+    /// the runtime at startup to symbolize crash backtraces.
+    pub(crate) fn_source_names: Vec<(String, String)>,
+
     /// Service dispatch tables.
     /// Maps service loop function name -> (call_handlers, cast_handlers).
     /// Each entry: (type_tag, handler_fn_name, num_args).
@@ -101,6 +109,28 @@ pub struct CodeGen<'ctx> {
     /// Parameter names for the current tail-recursive function, in order.
     /// Used by TailCall codegen to know which allocas to store into.
     pub(crate) tce_param_names: Vec<String>,
+
+    // ── Debug info ───────────────────────────────────────────────────
+
+    /// Optimization level the module is compiled at.
+    pub(crate) opt_level: u8,
+    /// Whether `compile` emits DWARF debug info (see [`debug`]).
+    pub(crate) emit_debug_info: bool,
+    /// Debug info builder state, present while compiling with debug info.
+    pub(crate) debug_info: Option<debug::DebugInfo<'ctx>>,
+
+    /// Actor runtime settings the generated `main` passes to the runtime.
+    pub(crate) runtime: RuntimeConfig,
+    /// Whether `MirExpr::CheckedIntOp` panics on overflow instead of wrapping.
+    pub(crate) overflow_checks: bool,
+
+    // ── Codegen unit ─────────────────────────────────────────────────
+
+    /// Functions whose bodies `compile` emits; `None` emits all of them.
+    /// The others are only declared and defined by another object file.
+    pub(crate) defined_functions: Option<HashSet<String>>,
+    /// Whether `compile` emits the C `main` (entry wrapper or test runner).
+    pub(crate) emit_main: bool,
 }
 
 impl<'ctx> CodeGen<'ctx> {
@@ -137,7 +167,8 @@ impl<'ctx> CodeGen<'ctx> {
         let opt = match opt_level {
             0 => OptimizationLevel::None,
             1 => OptimizationLevel::Less,
-            _ => OptimizationLevel::Default,
+            2 => OptimizationLevel::Default,
+            _ => OptimizationLevel::Aggressive,
         };
 
         let target_machine = target
@@ -176,12 +207,39 @@ impl<'ctx> CodeGen<'ctx> {
             mir_functions: Vec::new(),
             mir_struct_defs: FxHashMap::default(),
             loop_stack: Vec::new(),
+            fn_source_names: Vec::new(),
             service_dispatch: std::collections::HashMap::new(),
             tce_loop_header: None,
             tce_param_names: Vec::new(),
+            opt_level,
+            emit_debug_info: false,
+            debug_info: None,
+            runtime: RuntimeConfig::default(),
+            overflow_checks: false,
+            defined_functions: None,
+            emit_main: true,
         })
     }
 
+    /// Set the scheduler count and actor stack size of the generated `main`.
+    pub fn set_runtime_config(&mut self, runtime: RuntimeConfig) {
+        self.runtime = runtime;
+    }
+
+    /// Make `Int` `+`, `-`, `*`, `/`, `%` and negation panic with their
+    /// source location on overflow. Off by default: the operations wrap.
+    pub fn set_overflow_checks(&mut self, enabled: bool) {
+        self.overflow_checks = enabled;
+    }
+
+    /// Compile only part of the program into this module: the bodies of
+    /// `functions`, plus the C `main` if `emit_main` is set. Every other
+    /// function is declared so the objects of all parts link together.
+    pub fn set_codegen_unit(&mut self, functions: HashSet<String>, emit_main: bool) {
+        self.defined_functions = Some(functions);
+        self.emit_main = emit_main;
+    }
+
     /// Compile a MIR module to LLVM IR.
     ///
     /// This is the main compilation entry point. It:
@@ -191,10 +249,22 @@ impl<'ctx> CodeGen<'ctx> {
     /// 4. Compiles function bodies
     /// 5. Generates a main wrapper (if entry function exists)
     /// 6. Verifies the LLVM module
+    ///
+    /// Debug info is emitted throughout when enabled with `set_debug_info`.
     pub fn compile(&mut self, mir: &MirModule) -> Result<(), String> {
         // Store MIR functions for arm body lookup during pattern codegen.
         self.mir_functions = mir.functions.clone();
 
+        self.init_debug_info(mir);
+
+        // Sorted so the registration code is deterministic.
+        self.fn_source_names = mir
+            .fn_locations
+            .iter()
+            .map(|(symbol, loc)| (symbol.clone(), loc.name.clone()))
+            .collect();
+        self.fn_source_names.sort();
+
         // Store service dispatch tables for codegen.
         self.service_dispatch = mir.service_dispatch.clone();
 
@@ -212,17 +282,37 @@ impl<'ctx> CodeGen<'ctx> {
         // Step 3: Forward-declare all functions.
         self.declare_functions(&mir.functions);
 
-        // Step 4: Compile function bodies.
+        // Step 4: Compile function bodies (those of this codegen unit).
         for func in &mir.functions {
-            self.compile_function(func)?;
+            let defined = self
+                .defined_functions
+                .as_ref()
+                .map_or(true, |functions| functions.contains(&func.name));
+            if defined {
+                self.compile_function(func)?;
+            }
         }
 
-        // Step 5: Generate main wrapper if entry function exists.
-        if let Some(entry_name) = &mir.entry_function {
-            self.generate_main_wrapper(entry_name)?;
+        // Step 5: Generate main wrapper if entry function exists, or a test
+        // runner main for `meshc test` builds (tests and no entry point).
+        if self.emit_main {
+            if let Some(entry_name) = &mir.entry_function {
+                self.generate_main_wrapper(entry_name)?;
+            } else if !mir.tests.is_empty() {
+                self.generate_test_main(&mir.tests)?;
+            }
+        }
+
+        // Constants are only referenced from this module. Keep them private
+        // so the objects of separately compiled codegen units link together.
+        for global in self.module.get_globals() {
+            if global.get_initializer().is_some() {
+                global.set_linkage(inkwell::module::Linkage::Private);
+            }
         }
 
         // Step 6: Verify the module.
+        self.finalize_debug_info();
         self.module
             .verify()
             .map_err(|e| format!("LLVM module verification failed: {}", e))?;
@@ -235,13 +325,36 @@ impl<'ctx> CodeGen<'ctx> {
         let passes = match opt_level {
             0 => "default<O0>",
             1 => "default<O1>",
-            _ => "default<O2>",
+            2 => "default<O2>",
+            _ => "default<O3>",
         };
         self.module
             .run_passes(passes, &self.target_machine, PassBuilderOptions::create())
             .map_err(|e| format!("Optimization passes failed: {}", e))
     }
 
+    /// Run the link-time optimization pipeline on the module.
+    ///
+    /// The module holds the whole Mesh program, so every function other than
+    /// `main` is made internal first. This lets LLVM inline across modules and
+    /// drop functions nothing reaches.
+    pub fn run_lto_passes(&self, opt_level: u8) -> Result<(), String> {
+        for function in self.module.get_functions() {
+            if function.count_basic_blocks() > 0 && function.get_name().to_bytes() != b"main" {
+                function.set_linkage(inkwell::module::Linkage::Internal);
+            }
+        }
+        let passes = match opt_level {
+            0 => "globaldce",
+            1 => "lto<O1>",
+            2 => "lto<O2>",
+            _ => "lto<O3>",
+        };
+        self.module
+            .run_passes(passes, &self.target_machine, PassBuilderOptions::create())
+            .map_err(|e| format!("Link-time optimization failed: {}", e))
+    }
+
     /// Emit the LLVM module as an object file.
     pub fn emit_object(&self, path: &Path) -> Result<(), String> {
         self.target_machine
@@ -373,6 +486,7 @@ impl<'ctx> CodeGen<'ctx> {
         // Create entry basic block.
         let entry = self.context.append_basic_block(fn_val, "entry");
         self.builder.position_at_end(entry);
+        self.debug_enter_function(func, fn_val);
 
         // Alloca for each parameter and store incoming values.
         for (i, (name, ty)) in func.params.iter().enumerate() {
@@ -386,9 +500,11 @@ impl<'ctx> CodeGen<'ctx> {
             let param_val = fn_val.get_nth_param(i as u32).ok_or_else(|| {
                 format!("Missing parameter {} for function '{}'", i, func.name)
             })?;
-            self.builder
+            let store = self
+                .builder
                 .build_store(alloca, param_val)
                 .map_err(|e| e.to_string())?;
+            self.debug_declare_variable(name, ty, alloca, Some(i as u32 + 1), store);
             self.locals.insert(name.clone(), alloca);
             self.local_types.insert(name.clone(), ty.clone());
         }
@@ -615,6 +731,93 @@ impl<'ctx> CodeGen<'ctx> {
     /// Creates: `main(argc: i32, argv: ptr) -> i32` that calls
     /// `mesh_rt_init()`, then calls the Mesh entry function, then returns 0.
     fn generate_main_wrapper(&mut self, entry_name: &str) -> Result<(), String> {
+        let i32_type = self.context.i32_type();
+        self.build_main_prologue()?;
+
+        // Call the Mesh entry function on the main thread.
+        // mesh_main runs synchronously, spawning service/job actors along the way.
+        // The runtime handles service calls from the main thread context by using
+        // a dedicated main process entry in the process table.
+        let mesh_main = self
+            .functions
+            .get(entry_name)
+            .ok_or_else(|| format!("Entry function '{}' not found", entry_name))?;
+        self.builder
+            .build_call(*mesh_main, &[], "")
+            .map_err(|e| e.to_string())?;
+
+        // Run the actor scheduler to process all spawned actors.
+        // This blocks until all actors have completed.
+        let rt_run_scheduler = intrinsics::get_intrinsic(&self.module, "mesh_rt_run_scheduler");
+        self.builder
+            .build_call(rt_run_scheduler, &[], "")
+            .map_err(|e| e.to_string())?;
+
+        // Return 0
+        self.builder
+            .build_return(Some(&i32_type.const_int(0, false)))
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Generate the C `main` for a test binary.
+    ///
+    /// Initializes the runtime exactly like `generate_main_wrapper`, registers
+    /// every test with `mesh_test_register`, and returns the exit code from
+    /// `mesh_test_run`. The scheduler is not drained afterwards so a test that
+    /// timed out cannot keep the binary alive.
+    fn generate_test_main(&mut self, tests: &[MirTest]) -> Result<(), String> {
+        self.build_main_prologue()?;
+
+        let i64_type = self.context.i64_type();
+        let register_test = intrinsics::get_intrinsic(&self.module, "mesh_test_register");
+        for (i, test) in tests.iter().enumerate() {
+            let fn_val = *self
+                .functions
+                .get(&test.fn_name)
+                .ok_or_else(|| format!("Test function '{}' not found", test.fn_name))?;
+            let name_global = self
+                .builder
+                .build_global_string_ptr(&test.name, &format!("test_name_{}", i))
+                .map_err(|e| e.to_string())?;
+            let module_global = self
+                .builder
+                .build_global_string_ptr(&test.module, &format!("test_module_{}", i))
+                .map_err(|e| e.to_string())?;
+            self.builder
+                .build_call(
+                    register_test,
+                    &[
+                        name_global.as_pointer_value().into(),
+                        i64_type.const_int(test.name.len() as u64, false).into(),
+                        module_global.as_pointer_value().into(),
+                        i64_type.const_int(test.module.len() as u64, false).into(),
+                        fn_val.as_global_value().as_pointer_value().into(),
+                    ],
+                    "",
+                )
+                .map_err(|e| e.to_string())?;
+        }
+
+        let test_run = intrinsics::get_intrinsic(&self.module, "mesh_test_run");
+        let exit_code = self
+            .builder
+            .build_call(test_run, &[], "exit_code")
+            .map_err(|e| e.to_string())?
+            .try_as_basic_value()
+            .basic()
+            .ok_or_else(|| "mesh_test_run returned void".to_string())?;
+        self.builder
+            .build_return(Some(&exit_code))
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Emit the C `main` function and the runtime setup shared by program and
+    /// test binaries, leaving the builder positioned after the setup code.
+    fn build_main_prologue(&mut self) -> Result<(), String> {
         let i32_type = self.context.i32_type();
         let ptr_type = self.context.ptr_type(inkwell::AddressSpace::default());
         let main_type = i32_type.fn_type(&[i32_type.into(), ptr_type.into()], false);
@@ -622,6 +825,8 @@ impl<'ctx> CodeGen<'ctx> {
 
         let entry = self.context.append_basic_block(main_fn, "entry");
         self.builder.position_at_end(entry);
+        // `main` has no debug info; drop the location of the last function.
+        self.builder.unset_current_debug_location();
 
         // Call mesh_rt_init()
         let rt_init = intrinsics::get_intrinsic(&self.module, "mesh_rt_init");
@@ -629,11 +834,23 @@ impl<'ctx> CodeGen<'ctx> {
             .build_call(rt_init, &[], "")
             .map_err(|e| e.to_string())?;
 
-        // Call mesh_rt_init_actor(0) -- initialize actor scheduler with default threads
+        // Call mesh_rt_set_stack_size(bytes) when the profile overrides the default
+        if self.runtime.stack_size > 0 {
+            let set_stack_size = intrinsics::get_intrinsic(&self.module, "mesh_rt_set_stack_size");
+            let bytes = self.context.i64_type().const_int(self.runtime.stack_size, false);
+            self.builder
+                .build_call(set_stack_size, &[bytes.into()], "")
+                .map_err(|e| e.to_string())?;
+        }
+
+        // Call mesh_rt_init_actor(n) -- initialize actor scheduler (0 = one thread per core)
         let rt_init_actor = intrinsics::get_intrinsic(&self.module, "mesh_rt_init_actor");
-        let zero = self.context.i32_type().const_int(0, false);
+        let schedulers = self
+            .context
+            .i32_type()
+            .const_int(u64::from(self.runtime.schedulers), false);
         self.builder
-            .build_call(rt_init_actor, &[zero.into()], "")
+            .build_call(rt_init_actor, &[schedulers.into()], "")
             .map_err(|e| e.to_string())?;
 
         // Register all top-level functions for remote spawn (Phase 67).
@@ -671,29 +888,35 @@ impl<'ctx> CodeGen<'ctx> {
             }
         }
 
-        // Call the Mesh entry function on the main thread.
-        // mesh_main runs synchronously, spawning service/job actors along the way.
-        // The runtime handles service calls from the main thread context by using
-        // a dedicated main process entry in the process table.
-        let mesh_main = self
-            .functions
-            .get(entry_name)
-            .ok_or_else(|| format!("Entry function '{}' not found", entry_name))?;
-        self.builder
-            .build_call(*mesh_main, &[], "")
-            .map_err(|e| e.to_string())?;
-
-        // Run the actor scheduler to process all spawned actors.
-        // This blocks until all actors have completed.
-        let rt_run_scheduler = intrinsics::get_intrinsic(&self.module, "mesh_rt_run_scheduler");
-        self.builder
-            .build_call(rt_run_scheduler, &[], "")
-            .map_err(|e| e.to_string())?;
-
-        // Return 0
-        self.builder
-            .build_return(Some(&i32_type.const_int(0, false)))
-            .map_err(|e| e.to_string())?;
+        // Register the source names of Mesh functions so crash backtraces
+        // show `Module.fn/arity` instead of mangled symbols.
+        let register_symbol = intrinsics::get_intrinsic(&self.module, "mesh_register_symbol");
+        let i64_type = self.context.i64_type();
+        for (i, (symbol, name)) in self.fn_source_names.iter().enumerate() {
+            if !self.functions.contains_key(symbol) {
+                continue;
+            }
+            let symbol_global = self
+                .builder
+                .build_global_string_ptr(symbol, &format!("sym_{}", i))
+                .map_err(|e| e.to_string())?;
+            let name_global = self
+                .builder
+                .build_global_string_ptr(name, &format!("sym_name_{}", i))
+                .map_err(|e| e.to_string())?;
+            self.builder
+                .build_call(
+                    register_symbol,
+                    &[
+                        symbol_global.as_pointer_value().into(),
+                        i64_type.const_int(symbol.len() as u64, false).into(),
+                        name_global.as_pointer_value().into(),
+                        i64_type.const_int(name.len() as u64, false).into(),
+                    ],
+                    "",
+                )
+                .map_err(|e| e.to_string())?;
+        }
 
         Ok(())
     }
@@ -769,6 +992,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         }
     }
 
@@ -787,6 +1012,8 @@ mod tests {
             sum_types: vec![],
             entry_function: Some("mesh_main".to_string()),
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         }
     }
 
@@ -833,6 +1060,45 @@ mod tests {
         assert!(ir.contains("mesh_rt_init"), "Should call mesh_rt_init");
     }
 
+    #[test]
+    fn test_codegen_unit_declares_functions_it_does_not_define() {
+        let mut mir = hello_world_mir();
+        mir.functions.push(MirFunction {
+            name: "helper".to_string(),
+            params: vec![],
+            return_type: MirType::Unit,
+            body: MirExpr::Unit,
+            is_closure_fn: false,
+            captures: vec![],
+            has_tail_calls: false,
+        });
+        // The `define` or `declare` line of `@name`, if any.
+        fn signature<'a>(ir: &'a str, name: &str) -> Option<&'a str> {
+            let symbol = format!("@{}(", name);
+            ir.lines()
+                .find(|line| line.contains(&symbol) && !line.trim_start().starts_with("call"))
+                .and_then(|line| line.split_whitespace().next())
+        }
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.set_codegen_unit(["helper".to_string()].into_iter().collect(), false);
+        codegen.compile(&mir).unwrap();
+        let ir = codegen.get_llvm_ir();
+        assert_eq!(signature(&ir, "helper"), Some("define"), "IR:\n{}", ir);
+        assert_eq!(signature(&ir, "mesh_main"), Some("declare"), "IR:\n{}", ir);
+        assert_eq!(signature(&ir, "main"), None, "IR:\n{}", ir);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.set_codegen_unit(Default::default(), true);
+        codegen.compile(&mir).unwrap();
+        let ir = codegen.get_llvm_ir();
+        assert_eq!(signature(&ir, "main"), Some("define"), "IR:\n{}", ir);
+        assert_eq!(signature(&ir, "mesh_main"), Some("declare"), "IR:\n{}", ir);
+        assert_eq!(signature(&ir, "helper"), Some("declare"), "IR:\n{}", ir);
+    }
+
     #[test]
     fn test_emit_llvm_ir() {
         let context = Context::create();
@@ -859,6 +1125,84 @@ mod tests {
         std::fs::remove_file(&tmp).ok();
     }
 
+    #[test]
+    fn test_debug_info_emits_subprograms_and_lines() {
+        let mut mir = hello_world_mir();
+        mir.functions[0].body = MirExpr::Block(
+            vec![
+                MirExpr::SourceLine(3),
+                MirExpr::Let {
+                    name: "total".to_string(),
+                    ty: MirType::Int,
+                    value: Box::new(MirExpr::IntLit(1, MirType::Int)),
+                    body: Box::new(MirExpr::Unit),
+                },
+            ],
+            MirType::Unit,
+        );
+        mir.fn_locations.insert(
+            "mesh_main".to_string(),
+            crate::mir::MirSourceLoc {
+                name: "main/0".to_string(),
+                file: "/app/main.mpl".to_string(),
+                line: 2,
+            },
+        );
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.set_debug_info(true);
+        codegen.compile(&mir).unwrap();
+
+        let ir = codegen.get_llvm_ir();
+        assert!(ir.contains("!DICompileUnit("), "IR: {}", ir);
+        assert!(ir.contains("filename: \"main.mpl\", directory: \"/app\""), "IR: {}", ir);
+        assert!(ir.contains("!DISubprogram(name: \"mesh_main\""), "IR: {}", ir);
+        assert!(ir.contains("!DILocation(line: 3"), "IR: {}", ir);
+        assert!(ir.contains("!DILocalVariable(name: \"total\""), "IR: {}", ir);
+        assert!(ir.contains("\"Debug Info Version\""), "IR: {}", ir);
+    }
+
+    #[test]
+    fn test_debug_info_off_by_default() {
+        let mut mir = hello_world_mir();
+        mir.fn_locations.insert(
+            "mesh_main".to_string(),
+            crate::mir::MirSourceLoc {
+                name: "main/0".to_string(),
+                file: "main.mpl".to_string(),
+                line: 1,
+            },
+        );
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.compile(&mir).unwrap();
+
+        assert!(!codegen.get_llvm_ir().contains("DISubprogram"));
+    }
+
+    #[test]
+    fn test_main_registers_source_names_for_backtraces() {
+        let mut mir = hello_world_mir();
+        mir.fn_locations.insert(
+            "mesh_main".to_string(),
+            crate::mir::MirSourceLoc {
+                name: "main/0".to_string(),
+                file: "main.mpl".to_string(),
+                line: 1,
+            },
+        );
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.compile(&mir).unwrap();
+
+        let ir = codegen.get_llvm_ir();
+        assert!(ir.contains("c\"main/0\\00\""), "IR: {}", ir);
+        assert!(ir.contains("call void @mesh_register_symbol("), "IR: {}", ir);
+    }
+
     // ── Expression codegen tests ─────────────────────────────────────
 
     /// Helper: compile a single function body and return the LLVM IR string.
@@ -877,6 +1221,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -905,6 +1251,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1080,6 +1428,8 @@ mod tests {
             sum_types: vec![],
             entry_function: Some("mesh_main".to_string()),
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1123,6 +1473,8 @@ mod tests {
             sum_types: vec![],
             entry_function: Some("mesh_main".to_string()),
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1170,6 +1522,8 @@ mod tests {
             }],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1243,6 +1597,8 @@ mod tests {
             }],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1293,6 +1649,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1347,6 +1705,43 @@ mod tests {
         assert!(ir.contains("sub i64 0"), "Should contain int negation: {}", ir);
     }
 
+    #[test]
+    fn test_checked_division_panics_on_min_by_minus_one() {
+        let checked = |op| MirExpr::CheckedIntOp {
+            op,
+            lhs: Box::new(MirExpr::Var("a".to_string(), MirType::Int)),
+            rhs: Box::new(MirExpr::Var("b".to_string(), MirType::Int)),
+            file: "math.mpl".to_string(),
+            line: 3,
+        };
+        for (op, instruction) in [(BinOp::Div, "sdiv i64"), (BinOp::Mod, "srem i64")] {
+            let mut mir = empty_mir_module();
+            mir.functions.push(MirFunction {
+                name: "test_fn".to_string(),
+                params: vec![("a".to_string(), MirType::Int), ("b".to_string(), MirType::Int)],
+                return_type: MirType::Int,
+                body: checked(op),
+                is_closure_fn: false,
+                captures: vec![],
+                has_tail_calls: false,
+            });
+            let context = Context::create();
+            let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+            codegen.set_overflow_checks(true);
+            codegen.compile(&mir).unwrap();
+            let ir = codegen.get_llvm_ir();
+
+            // Int.min and -1 are compared before dividing, and the division
+            // only happens on the non-overflowing branch.
+            assert!(ir.contains(", -9223372036854775808"), "IR: {}", ir);
+            assert!(ir.contains(", -1"), "IR: {}", ir);
+            let no_overflow = ir.find("no_overflow:").expect("no_overflow block");
+            assert!(ir[no_overflow..].contains(instruction), "IR: {}", ir);
+            assert!(!ir[..no_overflow].contains(instruction), "IR: {}", ir);
+            assert!(ir.contains("call void @mesh_panic("), "IR: {}", ir);
+        }
+    }
+
     #[test]
     fn test_actor_spawn_codegen() {
         // Test that ActorSpawn generates a call to mesh_actor_spawn
@@ -1387,6 +1782,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1477,6 +1874,39 @@ mod tests {
             "Main should call mesh_rt_run_scheduler: {}",
             ir
         );
+        assert!(
+            ir.contains("call void @mesh_rt_init_actor(i32 0)"),
+            "Main should default to one scheduler per core: {}",
+            ir
+        );
+        assert!(
+            !ir.contains("call void @mesh_rt_set_stack_size"),
+            "Main should keep the default stack size: {}",
+            ir
+        );
+    }
+
+    #[test]
+    fn test_main_wrapper_runtime_config() {
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test", 0, None).unwrap();
+        codegen.set_runtime_config(crate::RuntimeConfig {
+            schedulers: 4,
+            stack_size: 2 * 1024 * 1024,
+        });
+        codegen.compile(&hello_world_mir()).unwrap();
+
+        let ir = codegen.get_llvm_ir();
+        assert!(
+            ir.contains("call void @mesh_rt_init_actor(i32 4)"),
+            "Main should start 4 schedulers: {}",
+            ir
+        );
+        assert!(
+            ir.contains("call void @mesh_rt_set_stack_size(i64 2097152)"),
+            "Main should set the actor stack size: {}",
+            ir
+        );
     }
 
     #[test]
@@ -1514,6 +1944,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1598,6 +2030,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -1887,6 +2321,8 @@ mod tests {
             }],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
@@ -2022,6 +2458,8 @@ mod tests {
             }],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         let context = Context::create();
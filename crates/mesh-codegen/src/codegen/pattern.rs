@@ -13,17 +13,21 @@
 //! - `Test`: Load scrutinee value, compare with literal, conditional branch,
 //!   recurse for success/failure
 //! - `Guard`: Codegen guard expression, conditional branch, recurse
+//! - `BinaryMatch`: Call mesh_bytes_matches with the segment spec, conditional
+//!   branch, recurse for success/failure
+//! - `MapHasKey`: Call mesh_map_has_key with the key, conditional branch,
+//!   recurse for success/failure
 //! - `Fail`: Emit mesh_panic + unreachable
 
 use inkwell::basic_block::BasicBlock;
-use inkwell::values::{BasicValueEnum, PointerValue};
+use inkwell::values::{BasicValueEnum, IntValue, PointerValue};
 use inkwell::IntPredicate;
 
 use super::intrinsics::get_intrinsic;
 use super::types::variant_struct_type;
 use super::CodeGen;
-use crate::mir::{MirLiteral, MirMatchArm, MirType};
-use crate::pattern::{AccessPath, DecisionTree};
+use crate::mir::{MirLiteral, MirMapKey, MirMatchArm, MirType};
+use crate::pattern::{binary_segment_type, AccessPath, DecisionTree};
 
 impl<'ctx> CodeGen<'ctx> {
     /// Generate LLVM IR for a decision tree.
@@ -131,6 +135,42 @@ impl<'ctx> CodeGen<'ctx> {
                     merge_bb,
                 )
             }
+            DecisionTree::BinaryMatch {
+                scrutinee_path,
+                spec,
+                success,
+                failure,
+            } => {
+                self.codegen_binary_match(
+                    scrutinee_path,
+                    spec,
+                    success,
+                    failure,
+                    scrutinee_alloca,
+                    scrutinee_ty,
+                    arms,
+                    result_alloca,
+                    merge_bb,
+                )
+            }
+            DecisionTree::MapHasKey {
+                scrutinee_path,
+                key,
+                success,
+                failure,
+            } => {
+                self.codegen_map_has_key(
+                    scrutinee_path,
+                    key,
+                    success,
+                    failure,
+                    scrutinee_alloca,
+                    scrutinee_ty,
+                    arms,
+                    result_alloca,
+                    merge_bb,
+                )
+            }
             DecisionTree::Fail {
                 message,
                 file,
@@ -596,6 +636,154 @@ impl<'ctx> CodeGen<'ctx> {
         Ok(())
     }
 
+    // ── BinaryMatch node ─────────────────────────────────────────────
+
+    fn codegen_binary_match(
+        &mut self,
+        scrutinee_path: &AccessPath,
+        spec: &str,
+        success: &DecisionTree,
+        failure: &DecisionTree,
+        scrutinee_alloca: PointerValue<'ctx>,
+        scrutinee_ty: &MirType,
+        arms: &[MirMatchArm],
+        result_alloca: PointerValue<'ctx>,
+        merge_bb: BasicBlock<'ctx>,
+    ) -> Result<(), String> {
+        let fn_val = self.current_function();
+
+        // Load the Bytes pointer at the access path.
+        let bytes_val = self.navigate_access_path(scrutinee_alloca, scrutinee_ty, scrutinee_path)?;
+        let spec_str = self.codegen_string_lit(spec)?;
+
+        // Call mesh_bytes_matches(bytes, spec).
+        let matches_fn = get_intrinsic(&self.module, "mesh_bytes_matches");
+        let result = self
+            .builder
+            .build_call(matches_fn, &[bytes_val.into(), spec_str.into()], "bin_match")
+            .map_err(|e| e.to_string())?;
+        let i8_result = result
+            .try_as_basic_value()
+            .basic()
+            .ok_or("mesh_bytes_matches returned void")?
+            .into_int_value();
+        let zero = self.context.i8_type().const_int(0, false);
+        let cond = self
+            .builder
+            .build_int_compare(IntPredicate::NE, i8_result, zero, "bin_match_bool")
+            .map_err(|e| e.to_string())?;
+
+        let success_bb = self.context.append_basic_block(fn_val, "binary_match");
+        let failure_bb = self.context.append_basic_block(fn_val, "binary_no_match");
+
+        self.builder
+            .build_conditional_branch(cond, success_bb, failure_bb)
+            .map_err(|e| e.to_string())?;
+
+        self.builder.position_at_end(success_bb);
+        self.codegen_decision_tree(
+            success,
+            scrutinee_alloca,
+            scrutinee_ty,
+            arms,
+            result_alloca,
+            merge_bb,
+        )?;
+
+        self.builder.position_at_end(failure_bb);
+        self.codegen_decision_tree(
+            failure,
+            scrutinee_alloca,
+            scrutinee_ty,
+            arms,
+            result_alloca,
+            merge_bb,
+        )?;
+
+        Ok(())
+    }
+
+    // ── MapHasKey node ───────────────────────────────────────────────
+
+    fn codegen_map_has_key(
+        &mut self,
+        scrutinee_path: &AccessPath,
+        key: &MirMapKey,
+        success: &DecisionTree,
+        failure: &DecisionTree,
+        scrutinee_alloca: PointerValue<'ctx>,
+        scrutinee_ty: &MirType,
+        arms: &[MirMatchArm],
+        result_alloca: PointerValue<'ctx>,
+        merge_bb: BasicBlock<'ctx>,
+    ) -> Result<(), String> {
+        let fn_val = self.current_function();
+
+        // Load the map pointer at the access path.
+        let map_val = self.navigate_access_path(scrutinee_alloca, scrutinee_ty, scrutinee_path)?;
+        let key_val = self.codegen_map_key(key)?;
+
+        // Call mesh_map_has_key(map, key).
+        let has_key_fn = get_intrinsic(&self.module, "mesh_map_has_key");
+        let result = self
+            .builder
+            .build_call(has_key_fn, &[map_val.into(), key_val.into()], "map_has_key")
+            .map_err(|e| e.to_string())?;
+        let i8_result = result
+            .try_as_basic_value()
+            .basic()
+            .ok_or("mesh_map_has_key returned void")?
+            .into_int_value();
+        let zero = self.context.i8_type().const_int(0, false);
+        let cond = self
+            .builder
+            .build_int_compare(IntPredicate::NE, i8_result, zero, "map_has_key_bool")
+            .map_err(|e| e.to_string())?;
+
+        let success_bb = self.context.append_basic_block(fn_val, "map_key_present");
+        let failure_bb = self.context.append_basic_block(fn_val, "map_key_absent");
+
+        self.builder
+            .build_conditional_branch(cond, success_bb, failure_bb)
+            .map_err(|e| e.to_string())?;
+
+        self.builder.position_at_end(success_bb);
+        self.codegen_decision_tree(
+            success,
+            scrutinee_alloca,
+            scrutinee_ty,
+            arms,
+            result_alloca,
+            merge_bb,
+        )?;
+
+        self.builder.position_at_end(failure_bb);
+        self.codegen_decision_tree(
+            failure,
+            scrutinee_alloca,
+            scrutinee_ty,
+            arms,
+            result_alloca,
+            merge_bb,
+        )?;
+
+        Ok(())
+    }
+
+    /// A map pattern key as the u64 word the map runtime takes: the Int
+    /// itself, or a pointer to a String.
+    fn codegen_map_key(&mut self, key: &MirMapKey) -> Result<IntValue<'ctx>, String> {
+        match key {
+            MirMapKey::Int(n) => Ok(self.context.i64_type().const_int(*n as u64, true)),
+            MirMapKey::String(s) => {
+                let key_str = self.codegen_string_lit(s)?;
+                self.builder
+                    .build_ptr_to_int(key_str.into_pointer_value(), self.context.i64_type(), "map_key")
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
+
     // ── Fail node ────────────────────────────────────────────────────
 
     fn codegen_fail(
@@ -785,6 +973,79 @@ impl<'ctx> CodeGen<'ctx> {
                     .map_err(|e| e.to_string())?;
                 Ok(alloca)
             }
+
+            AccessPath::BinarySegment(parent, spec, index) => {
+                // Load the Bytes pointer, call mesh_bytes_segment, store result in an alloca.
+                let parent_val = self.navigate_access_path(scrutinee_alloca, scrutinee_ty, parent)?;
+                let spec_str = self.codegen_string_lit(spec)?;
+                let index_val = self.context.i64_type().const_int(*index as u64, false);
+
+                let segment_fn = get_intrinsic(&self.module, "mesh_bytes_segment");
+                let segment_result = self
+                    .builder
+                    .build_call(
+                        segment_fn,
+                        &[parent_val.into(), spec_str.into(), index_val.into()],
+                        "bin_segment",
+                    )
+                    .map_err(|e| e.to_string())?;
+                let segment_i64 = segment_result
+                    .try_as_basic_value()
+                    .basic()
+                    .ok_or("mesh_bytes_segment returned void")?
+                    .into_int_value();
+
+                // Integer segments stay i64; bytes segments are pointers.
+                let path_ty = binary_segment_type(spec, *index);
+                let converted = self.convert_list_elem_from_u64(segment_i64, &path_ty)?;
+
+                let llvm_ty = self.llvm_type(&path_ty);
+                let alloca = self
+                    .builder
+                    .build_alloca(llvm_ty, "bin_segment_alloca")
+                    .map_err(|e| e.to_string())?;
+                self.builder
+                    .build_store(alloca, converted)
+                    .map_err(|e| e.to_string())?;
+                Ok(alloca)
+            }
+
+            AccessPath::MapValue(parent, key, val_ty) => {
+                // Load the map pointer, call mesh_map_get, store result in an alloca.
+                let parent_val = self.navigate_access_path(scrutinee_alloca, scrutinee_ty, parent)?;
+                let key_val = self.codegen_map_key(key)?;
+
+                let get_fn = get_intrinsic(&self.module, "mesh_map_get");
+                let get_result = self
+                    .builder
+                    .build_call(get_fn, &[parent_val.into(), key_val.into()], "map_value")
+                    .map_err(|e| e.to_string())?;
+                let value_i64 = get_result
+                    .try_as_basic_value()
+                    .basic()
+                    .ok_or("mesh_map_get returned void")?
+                    .into_int_value();
+
+                let converted = self.convert_list_elem_from_u64(value_i64, val_ty)?;
+
+                // Struct and sum type values are stored boxed: load the value.
+                let llvm_ty = self.llvm_type(val_ty);
+                let converted = if matches!(val_ty, MirType::Struct(_) | MirType::SumType(_)) {
+                    self.builder
+                        .build_load(llvm_ty, converted.into_pointer_value(), "map_value_unboxed")
+                        .map_err(|e| e.to_string())?
+                } else {
+                    converted
+                };
+                let alloca = self
+                    .builder
+                    .build_alloca(llvm_ty, "map_value_alloca")
+                    .map_err(|e| e.to_string())?;
+                self.builder
+                    .build_store(alloca, converted)
+                    .map_err(|e| e.to_string())?;
+                Ok(alloca)
+            }
         }
     }
 
@@ -911,6 +1172,10 @@ impl<'ctx> CodeGen<'ctx> {
                 // Tail of a list is always a list (Ptr at MIR level).
                 Ok(MirType::Ptr)
             }
+
+            AccessPath::BinarySegment(_parent, spec, index) => Ok(binary_segment_type(spec, *index)),
+
+            AccessPath::MapValue(_parent, _key, val_ty) => Ok(val_ty.clone()),
         }
     }
 }
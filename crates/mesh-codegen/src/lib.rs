@@ -27,7 +27,7 @@ use std::path::Path;
 use inkwell::context::Context;
 
 use codegen::CodeGen;
-use mir::lower::lower_to_mir;
+use mir::lower::{lower_to_mir, lower_to_mir_with_source};
 use mir::mono::monomorphize;
 
 /// Lower a parsed and type-checked Mesh program to MIR.
@@ -53,6 +53,8 @@ pub fn lower_to_mir_module(
 ///
 /// Use this when lowering multiple modules that will be merged before
 /// monomorphization (which requires reachability analysis from the entry point).
+/// `source_path` is the file name that assertion failures report; `debug`
+/// adds the statement line markers that debug info is built from.
 ///
 /// # Errors
 ///
@@ -62,8 +64,10 @@ pub fn lower_to_mir_raw(
     typeck: &mesh_typeck::TypeckResult,
     module_name: &str,
     pub_fns: &HashSet<String>,
+    source_path: &str,
+    debug: bool,
 ) -> Result<mir::MirModule, String> {
-    let module = lower_to_mir(parse, typeck, module_name, pub_fns)?;
+    let module = lower_to_mir_with_source(parse, typeck, module_name, pub_fns, source_path, debug)?;
     Ok(module)
 }
 
@@ -198,6 +202,33 @@ pub fn compile(
 
 // ── Multi-Module Compilation (Phase 39) ────────────────────────────────
 
+/// Actor runtime settings baked into the generated `main`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// Scheduler worker threads; 0 = one per CPU core.
+    pub schedulers: u32,
+    /// Actor stack size in bytes; 0 = the runtime default (512 KiB).
+    pub stack_size: u64,
+}
+
+/// Code generation settings for the `compile_mir_*` functions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CodegenOptions<'a> {
+    /// Optimization level (0 = none, 2 = default).
+    pub opt_level: u8,
+    /// Emit DWARF debug info mapping back to `.mpl` files.
+    pub debug: bool,
+    /// Run the link-time optimization pipeline over the whole program.
+    pub lto: bool,
+    /// Target triple; `None` = host default.
+    pub target_triple: Option<&'a str>,
+    /// Actor runtime settings for the generated `main`.
+    pub runtime: RuntimeConfig,
+    /// Panic on `Int` overflow in `+`, `-`, `*`, `/`, `%` and negation
+    /// instead of wrapping.
+    pub overflow_checks: bool,
+}
+
 /// Compile a pre-built MIR module to a native binary.
 ///
 /// This accepts a MIR module directly (already lowered and optionally merged
@@ -205,34 +236,89 @@ pub fn compile(
 pub fn compile_mir_to_binary(
     mir: &mir::MirModule,
     output: &Path,
-    opt_level: u8,
-    target_triple: Option<&str>,
+    options: &CodegenOptions,
     rt_lib_path: Option<&Path>,
 ) -> Result<(), String> {
     let obj_path = output.with_extension("o");
+    compile_mir_to_object(mir, &obj_path, options)?;
+    link::link(&obj_path, output, rt_lib_path)?;
+
+    Ok(())
+}
 
+/// Compile a pre-built MIR module to an object file without linking it.
+///
+/// Used by callers that cache object files between builds and link them
+/// separately with [`link::link_objects`].
+pub fn compile_mir_to_object(
+    mir: &mir::MirModule,
+    output: &Path,
+    options: &CodegenOptions,
+) -> Result<(), String> {
     let context = Context::create();
-    let mut codegen = CodeGen::new(&context, "mesh_module", opt_level, target_triple)?;
+    let mut codegen =
+        CodeGen::new(&context, "mesh_module", options.opt_level, options.target_triple)?;
+    codegen.set_debug_info(options.debug);
+    codegen.set_runtime_config(options.runtime);
+    codegen.set_overflow_checks(options.overflow_checks);
     codegen.compile(mir)?;
 
-    if opt_level > 0 {
-        codegen.run_optimization_passes(opt_level)?;
+    if options.lto {
+        codegen.run_lto_passes(options.opt_level)?;
+    } else if options.opt_level > 0 {
+        codegen.run_optimization_passes(options.opt_level)?;
     }
 
-    codegen.emit_object(&obj_path)?;
-    link::link(&obj_path, output, rt_lib_path)?;
+    codegen.emit_object(output)?;
 
     Ok(())
 }
 
-/// Compile a pre-built MIR module to LLVM IR text.
+/// Compile one codegen unit of a pre-built MIR module to an object file.
+///
+/// The object defines the bodies of `functions`, and the C `main` if
+/// `emit_main` is set, and declares every other function of `mir`. Compiling
+/// each source module's functions as its own unit lets callers cache the
+/// objects separately and link them with [`link::link_objects`].
+///
+/// Link-time optimization needs the whole program in one module, so
+/// `options.lto` is ignored; use [`compile_mir_to_object`] for LTO builds.
+pub fn compile_mir_unit_to_object(
+    mir: &mir::MirModule,
+    functions: HashSet<String>,
+    emit_main: bool,
+    output: &Path,
+    options: &CodegenOptions,
+) -> Result<(), String> {
+    let context = Context::create();
+    let mut codegen =
+        CodeGen::new(&context, "mesh_module", options.opt_level, options.target_triple)?;
+    codegen.set_debug_info(options.debug);
+    codegen.set_runtime_config(options.runtime);
+    codegen.set_overflow_checks(options.overflow_checks);
+    codegen.set_codegen_unit(functions, emit_main);
+    codegen.compile(mir)?;
+
+    if options.opt_level > 0 {
+        codegen.run_optimization_passes(options.opt_level)?;
+    }
+
+    codegen.emit_object(output)?;
+
+    Ok(())
+}
+
+/// Compile a pre-built MIR module to unoptimized LLVM IR text.
 pub fn compile_mir_to_llvm_ir(
     mir: &mir::MirModule,
     output: &Path,
-    target_triple: Option<&str>,
+    options: &CodegenOptions,
 ) -> Result<(), String> {
     let context = Context::create();
-    let mut codegen = CodeGen::new(&context, "mesh_module", 0, target_triple)?;
+    let mut codegen = CodeGen::new(&context, "mesh_module", 0, options.target_triple)?;
+    codegen.set_debug_info(options.debug);
+    codegen.set_runtime_config(options.runtime);
+    codegen.set_overflow_checks(options.overflow_checks);
     codegen.compile(mir)?;
 
     codegen.emit_llvm_ir(output)?;
@@ -244,13 +330,33 @@ pub fn compile_mir_to_llvm_ir(
 /// Functions, struct definitions, and sum type definitions from all modules
 /// are combined. The entry function is taken from the designated entry module.
 /// Duplicate struct/sum type definitions (e.g., builtins registered in every
-/// module) are deduplicated by name.
+/// module) are deduplicated by name. Test blocks are dropped.
 ///
 /// After merging, runs the monomorphization pass to eliminate unreachable
 /// functions (which requires the entry point from the merged module).
 pub fn merge_mir_modules(
     modules: Vec<mir::MirModule>,
     entry_module_idx: usize,
+) -> mir::MirModule {
+    let entry_function = modules
+        .get(entry_module_idx)
+        .and_then(|entry| entry.entry_function.clone());
+    merge_modules(modules, entry_function, false)
+}
+
+/// Merge multiple MIR modules into a single test module for `meshc test`.
+///
+/// Like [`merge_mir_modules`], but keeps the test blocks of every module and
+/// drops the entry function, so codegen emits a test runner `main` and
+/// monomorphization keeps only what the tests reach.
+pub fn merge_mir_modules_for_tests(modules: Vec<mir::MirModule>) -> mir::MirModule {
+    merge_modules(modules, None, true)
+}
+
+fn merge_modules(
+    modules: Vec<mir::MirModule>,
+    entry_function: Option<String>,
+    keep_tests: bool,
 ) -> mir::MirModule {
     use std::collections::HashSet;
 
@@ -258,19 +364,16 @@ pub fn merge_mir_modules(
         functions: Vec::new(),
         structs: Vec::new(),
         sum_types: Vec::new(),
-        entry_function: None,
+        entry_function,
         service_dispatch: std::collections::HashMap::new(),
+        tests: Vec::new(),
+        fn_locations: std::collections::HashMap::new(),
     };
 
     let mut seen_functions: HashSet<String> = HashSet::new();
     let mut seen_structs: HashSet<String> = HashSet::new();
     let mut seen_sum_types: HashSet<String> = HashSet::new();
 
-    // Process entry module first (its main() takes priority)
-    if let Some(entry) = modules.get(entry_module_idx) {
-        merged.entry_function = entry.entry_function.clone();
-    }
-
     for module in &modules {
         for func in &module.functions {
             if seen_functions.insert(func.name.clone()) {
@@ -290,6 +393,12 @@ pub fn merge_mir_modules(
         for (key, value) in &module.service_dispatch {
             merged.service_dispatch.entry(key.clone()).or_insert_with(|| value.clone());
         }
+        for (name, loc) in &module.fn_locations {
+            merged.fn_locations.entry(name.clone()).or_insert_with(|| loc.clone());
+        }
+        if keep_tests {
+            merged.tests.extend(module.tests.iter().cloned());
+        }
     }
 
     // Run monomorphization on the merged module to eliminate unreachable
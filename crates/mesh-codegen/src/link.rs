@@ -24,6 +24,28 @@ pub fn link(
     object_path: &Path,
     output_path: &Path,
     rt_lib_path: Option<&Path>,
+) -> Result<(), String> {
+    link_objects(&[object_path.to_path_buf()], output_path, rt_lib_path)?;
+
+    // Clean up the object file
+    std::fs::remove_file(object_path).ok();
+
+    Ok(())
+}
+
+/// Link several object files with the Mesh runtime to produce a native
+/// executable.
+///
+/// Unlike [`link`], the object files are left in place, so callers can link
+/// cached objects directly.
+///
+/// # Errors
+///
+/// Returns an error string if the linker cannot be found or linking fails.
+pub fn link_objects(
+    object_paths: &[PathBuf],
+    output_path: &Path,
+    rt_lib_path: Option<&Path>,
 ) -> Result<(), String> {
     // Find the runtime library
     let rt_path = match rt_lib_path {
@@ -44,7 +66,7 @@ pub fn link(
 
     // Invoke system cc as linker driver
     let mut cmd = Command::new("cc");
-    cmd.arg(object_path)
+    cmd.args(object_paths)
         .arg("-L")
         .arg(rt_dir)
         .arg("-lmesh_rt")
@@ -66,9 +88,6 @@ pub fn link(
         return Err(format!("Linking failed:\n{}", stderr));
     }
 
-    // Clean up the object file
-    std::fs::remove_file(object_path).ok();
-
     Ok(())
 }
 
@@ -5,20 +5,20 @@
 
 use std::collections::{HashMap, HashSet};
 
-use rowan::TextRange;
+use rowan::{TextRange, TextSize};
 use rustc_hash::FxHashMap;
 use mesh_parser::ast::expr::{
-    BinaryExpr, CallExpr, CaseExpr, ClosureExpr, Expr, FieldAccess, ForInExpr, IfExpr, LinkExpr,
-    ListLiteral, Literal, MapLiteral, MatchArm, NameRef, PipeExpr, ReceiveExpr, ReturnExpr,
+    BinaryExpr, BytesLiteral, CallExpr, CaseExpr, ClosureExpr, Expr, FieldAccess, ForInExpr, IfExpr, LinkExpr,
+    Interpolation, ListLiteral, Literal, MapLiteral, MatchArm, NameRef, PipeExpr, ReceiveExpr, ReturnExpr,
     SendExpr, SpawnExpr, StringExpr, StructLiteral, StructUpdate, TryExpr, TupleExpr, UnaryExpr,
     WhileExpr,
 };
 use mesh_parser::ast::item::{
     ActorDef, Block, FnDef, ImplDef, InterfaceMethod, Item, LetBinding, RelationshipDecl,
-    ServiceDef, SourceFile, StructDef, SumTypeDef, SupervisorDef,
+    ServiceDef, SourceFile, StructDef, SumTypeDef, SupervisorDef, TestDef,
 };
-use mesh_parser::ast::pat::Pattern;
-use mesh_parser::ast::AstNode;
+use mesh_parser::ast::pat::{BytesPat, MapPat, Pattern, PinPat, SegmentType};
+use mesh_parser::ast::{item_range, AstNode};
 use mesh_parser::syntax_kind::SyntaxKind;
 use mesh_parser::Parse;
 use mesh_typeck::ty::Ty;
@@ -26,8 +26,8 @@ use mesh_typeck::{TraitRegistry, TypeckResult};
 
 use super::types::{mangle_type_name, mir_type_to_impl_name, mir_type_to_ty, resolve_type};
 use super::{
-    BinOp, MirChildSpec, MirExpr, MirFunction, MirLiteral, MirMatchArm, MirModule, MirPattern,
-    MirStructDef, MirSumTypeDef, MirType, MirVariantDef, UnaryOp,
+    BinOp, MirChildSpec, MirExpr, MirFunction, MirLiteral, MirMapKey, MirMatchArm, MirModule,
+    MirPattern, MirSourceLoc, MirStructDef, MirSumTypeDef, MirTest, MirType, MirVariantDef, UnaryOp,
 };
 
 // ── Helpers ──────────────────────────────────────────────────────────
@@ -90,6 +90,39 @@ fn extract_set_elem_type(ty: &Ty) -> Option<Ty> {
     }
 }
 
+/// Extract the element type T from a `Ty::App(Con("Queue"), [T])`.
+/// Returns `None` if the type is not a Queue.
+fn extract_queue_elem_type(ty: &Ty) -> Option<Ty> {
+    match ty {
+        Ty::App(con_ty, args) => {
+            if let Ty::Con(con) = con_ty.as_ref() {
+                if con.name == "Queue" && !args.is_empty() {
+                    return Some(args[0].clone());
+                }
+            }
+            None
+        }
+        Ty::Con(con) if con.name == "Queue" => {
+            Some(Ty::int())
+        }
+        _ => None,
+    }
+}
+
+/// Name segment for the callbacks of a tuple with these element types,
+/// e.g. `Int_String` for `(Int, String)`. Nested tuples are wrapped in
+/// `T_` ... `_E` so `((Int, Int), Int)` and `(Int, (Int, Int))` differ.
+fn tuple_callback_suffix(elems: &[MirType]) -> String {
+    elems
+        .iter()
+        .map(|elem| match elem {
+            MirType::Tuple(inner) => format!("T_{}_E", tuple_callback_suffix(inner)),
+            other => mir_type_to_impl_name(other),
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
 /// Extract the trait name, trait type args, and type name from an ImplDef's PATH children.
 /// Returns `(trait_name, trait_type_args, type_name)`, e.g. `("From", vec!["Int"], "Float")`.
 /// For non-parameterized traits, trait_type_args is empty.
@@ -250,15 +283,36 @@ struct Lowerer<'a> {
     /// Counter for generating unique try binding names (Phase 45).
     /// Incremented per `?` usage to avoid shadowing in nested `?` expressions.
     try_counter: u32,
+    /// Counter for unique `|N>` pipe value bindings, which may nest.
+    pipe_counter: u32,
+    /// Counter for the bindings that unbox struct and sum type elements
+    /// read by `Tuple` accessors.
+    tuple_counter: u32,
+    /// Counter for the bindings that hold values matched by pinned patterns.
+    pin_counter: u32,
+    /// Tests for the pinned patterns (`^name`) lowered since the current
+    /// match arm began; they become part of the arm's guard.
+    pin_tests: Vec<MirExpr>,
+    /// Lowered `test` blocks, in source order.
+    tests: Vec<MirTest>,
+    /// Source file path reported by assertion failures and debug info.
+    source_path: String,
+    /// Offsets of the newlines in the syntax tree text, for `source_line`.
+    newline_offsets: Vec<TextSize>,
+    /// Source locations of the functions lowered so far.
+    fn_locations: HashMap<String, MirSourceLoc>,
+    /// Emit `SourceLine` markers for debug info.
+    debug: bool,
 }
 
 /// Walk through Let/Block wrappers to find the effective return type of a MIR expression.
 /// Let { ty, body, .. } has `ty` as the binding's value type, but the effective type is body's type.
-/// Block(exprs, ty) already stores the last expression's type as `ty`.
+/// Block(exprs, ty) stores the last expression's `ty()`, which is wrong when that
+/// expression is a Let (e.g. a let preceded by a source line marker), so walk into it.
 fn effective_return_type(expr: &MirExpr) -> MirType {
     match expr {
         MirExpr::Let { body, .. } => effective_return_type(body),
-        MirExpr::Block(_, ty) => ty.clone(),
+        MirExpr::Block(exprs, ty) => exprs.last().map_or_else(|| ty.clone(), effective_return_type),
         other => other.ty().clone(),
     }
 }
@@ -277,7 +331,14 @@ fn mir_type_to_sql_type(ty: &MirType) -> &'static str {
 }
 
 impl<'a> Lowerer<'a> {
-    fn new(typeck: &'a TypeckResult, parse: &'a Parse, module_name: &str, pub_fns: &HashSet<String>) -> Self {
+    fn new(
+        typeck: &'a TypeckResult,
+        parse: &'a Parse,
+        module_name: &str,
+        pub_fns: &HashSet<String>,
+        source_path: &str,
+        debug: bool,
+    ) -> Self {
         Lowerer {
             types: &typeck.types,
             registry: &typeck.type_registry,
@@ -306,6 +367,25 @@ impl<'a> Lowerer<'a> {
             user_fn_defs: HashSet::new(),
             current_fn_return_type: None,
             try_counter: 0,
+            pipe_counter: 0,
+            tuple_counter: 0,
+            pin_counter: 0,
+            pin_tests: Vec::new(),
+            tests: Vec::new(),
+            source_path: if source_path.is_empty() {
+                "<source>".to_string()
+            } else {
+                source_path.to_string()
+            },
+            newline_offsets: parse
+                .syntax()
+                .text()
+                .to_string()
+                .match_indices('\n')
+                .map(|(i, _)| TextSize::from(i as u32))
+                .collect(),
+            fn_locations: HashMap::new(),
+            debug,
         }
     }
 
@@ -432,32 +512,6 @@ impl<'a> Lowerer<'a> {
         }
     }
 
-    /// Walk a pipe chain backwards to determine if the source data has string
-    /// map keys. Used for Map.collect dispatch: when the source of the pipe
-    /// chain is a List<(String, V)> or Map<String, V>, the collected map needs
-    /// string key_type (1) rather than integer key_type (0).
-    fn pipe_chain_has_string_keys(&self, pipe: &PipeExpr) -> bool {
-        // Walk backwards through the pipe chain until we find a non-pipe LHS.
-        let mut current_lhs = pipe.lhs();
-        loop {
-            match current_lhs {
-                Some(Expr::PipeExpr(inner_pipe)) => {
-                    // Check the inner pipe's LHS type -- if it's not a pipe,
-                    // check its typeck type for string keys.
-                    current_lhs = inner_pipe.lhs();
-                }
-                Some(ref expr) => {
-                    // Found the deepest non-pipe expression. Check its typeck type.
-                    if let Some(ty) = self.types.get(&expr.syntax().text_range()) {
-                        return Self::ty_has_string_map_keys(ty);
-                    }
-                    return false;
-                }
-                None => return false,
-            }
-        }
-    }
-
     // ── Top-level lowering ───────────────────────────────────────────
 
     fn lower_source_file(&mut self, sf: SourceFile) {
@@ -605,6 +659,104 @@ impl<'a> Lowerer<'a> {
             "mesh_string_to_float".to_string(),
             MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)),
         );
+        // Fixed-width integers
+        for ty_name in ["int8", "int16", "int32", "uint8", "uint16", "uint32", "uint64"] {
+            self.known_functions.insert(
+                format!("mesh_{}_from_int", ty_name),
+                MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Int)),
+            );
+            self.known_functions.insert(
+                format!("mesh_{}_try_from_int", ty_name),
+                MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Ptr)),
+            );
+        }
+        // Int arithmetic with explicit overflow behavior
+        for op in ["add", "sub", "mul"] {
+            for mode in ["wrapping", "saturating"] {
+                self.known_functions.insert(
+                    format!("mesh_int_{}_{}", mode, op),
+                    MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Int)),
+                );
+            }
+        }
+        for op in ["add", "sub", "mul", "div"] {
+            self.known_functions.insert(
+                format!("mesh_int_checked_{}", op),
+                MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Ptr)),
+            );
+        }
+        self.known_functions.insert("mesh_fixed_int_to_int".to_string(), MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_uint64_div".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_uint64_rem".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_uint64_lt".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Bool)));
+        self.known_functions.insert("mesh_uint64_to_string".to_string(), MirType::FnPtr(vec![MirType::Int], Box::new(MirType::String)));
+        // BigInt and Decimal (opaque heap values)
+        for ty_name in ["bigint", "decimal"] {
+            self.known_functions.insert(format!("mesh_{}_from_int", ty_name), MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Ptr)));
+            self.known_functions.insert(format!("mesh_{}_parse", ty_name), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
+            self.known_functions.insert(format!("mesh_{}_to_string", ty_name), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)));
+            for op in ["neg", "to_json", "from_json"] {
+                self.known_functions.insert(format!("mesh_{}_{}", ty_name, op), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+            }
+            for op in ["add", "sub", "mul", "div", "mod"] {
+                self.known_functions.insert(format!("mesh_{}_{}", ty_name, op), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
+            }
+            self.known_functions.insert(format!("mesh_{}_eq", ty_name), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)));
+            self.known_functions.insert(format!("mesh_{}_compare", ty_name), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Int)));
+            self.known_functions.insert(format!("mesh_{}_hash", ty_name), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
+        }
+        self.known_functions.insert("mesh_bigint_to_int".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bigint_abs".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bigint_pow".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_decimal_to_float".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Float)));
+        self.known_functions.insert("mesh_decimal_scale".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_decimal_round".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
+        // Bytes
+        self.known_functions.insert("mesh_bytes_new".to_string(), MirType::FnPtr(vec![], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_from_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_to_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_length".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_bytes_get".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_bytes_slice".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int, MirType::Int], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_concat".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_eq".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)));
+        self.known_functions.insert("mesh_bytes_to_string".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)));
+        self.known_functions.insert("mesh_string_to_bytes".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
+        // IoData
+        self.known_functions.insert("mesh_iodata_new".to_string(), MirType::FnPtr(vec![], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_iodata_from_string".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
+        for name in ["mesh_iodata_from_bytes", "mesh_iodata_from_list", "mesh_iodata_to_bytes"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        }
+        self.known_functions.insert("mesh_iodata_append".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::String], Box::new(MirType::Ptr)));
+        for name in ["mesh_iodata_append_bytes", "mesh_iodata_concat"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
+        }
+        self.known_functions.insert("mesh_iodata_byte_size".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_iodata_to_string".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)));
+        self.known_functions.insert("mesh_string_from_utf8".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        // Unicode text: grapheme-aware String functions
+        self.known_functions.insert("mesh_string_byte_size".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Int)));
+        for name in ["mesh_string_graphemes", "mesh_string_chars", "mesh_string_codepoints"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
+        }
+        self.known_functions.insert("mesh_string_at".to_string(), MirType::FnPtr(vec![MirType::String, MirType::Int], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_string_index_of".to_string(), MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::Ptr)));
+        for name in ["mesh_string_pad_left", "mesh_string_pad_right"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::String, MirType::Int, MirType::String], Box::new(MirType::String)));
+        }
+        for name in ["mesh_string_reverse", "mesh_string_fold_case", "mesh_string_normalize_nfc", "mesh_string_normalize_nfd"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::String)));
+        }
+        // Format specs: `${value:spec}` and String.format
+        for name in ["mesh_format_int", "mesh_format_uint64"] {
+            self.known_functions.insert(name.to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::String)));
+        }
+        self.known_functions.insert("mesh_format_float".to_string(), MirType::FnPtr(vec![MirType::Float, MirType::String], Box::new(MirType::String)));
+        self.known_functions.insert("mesh_format_string".to_string(), MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::String)));
+        self.known_functions.insert("mesh_string_format".to_string(), MirType::FnPtr(vec![MirType::String, MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_put_int".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int, MirType::String], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_bytes_put_bytes".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
         // File I/O functions
         self.known_functions.insert(
             "mesh_file_read".to_string(),
@@ -618,6 +770,12 @@ impl<'a> Lowerer<'a> {
             "mesh_file_append".to_string(),
             MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::Ptr)),
         );
+        for name in ["mesh_file_write_iodata", "mesh_file_append_iodata"] {
+            self.known_functions.insert(
+                name.to_string(),
+                MirType::FnPtr(vec![MirType::String, MirType::Ptr], Box::new(MirType::Ptr)),
+            );
+        }
         self.known_functions.insert(
             "mesh_file_exists".to_string(),
             MirType::FnPtr(vec![MirType::String], Box::new(MirType::Bool)),
@@ -635,6 +793,14 @@ impl<'a> Lowerer<'a> {
             "mesh_io_eprintln".to_string(),
             MirType::FnPtr(vec![MirType::String], Box::new(MirType::Unit)),
         );
+        self.known_functions.insert(
+            "mesh_io_write".to_string(),
+            MirType::FnPtr(vec![MirType::String], Box::new(MirType::Unit)),
+        );
+        self.known_functions.insert(
+            "mesh_io_write_iodata".to_string(),
+            MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Unit)),
+        );
         // Env functions
         self.known_functions.insert(
             "mesh_env_get".to_string(),
@@ -649,6 +815,7 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_list_new".to_string(), MirType::FnPtr(vec![], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_list_length".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_list_append".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_list_prepend".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_list_head".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_list_tail".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_list_get".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Ptr)));
@@ -677,6 +844,7 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_map_new".to_string(), MirType::FnPtr(vec![], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_map_new_typed".to_string(), MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_map_tag_string".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_map_tag_hashed".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_map_put".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int, MirType::Int], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_map_get".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_map_has_key".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Bool)));
@@ -700,6 +868,8 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_set_difference".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_set_to_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_set_from_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        // Set<T>: order-insensitive equality, with the sets tagged for the element type
+        self.known_functions.insert("mesh_set_eq".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)));
         // Collection Display (Phase 21 Plan 04)
         self.known_functions.insert("mesh_list_to_string".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_map_to_string".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
@@ -713,6 +883,7 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_tuple_first".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_tuple_second".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_tuple_size".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
+        self.known_functions.insert("mesh_tuple_to_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         // Range
         self.known_functions.insert("mesh_range_new".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_range_to_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
@@ -726,6 +897,10 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_queue_peek".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_queue_size".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)));
         self.known_functions.insert("mesh_queue_is_empty".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Bool)));
+        self.known_functions.insert("mesh_queue_to_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_queue_from_list".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_queue_eq".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)));
+        self.known_functions.insert("mesh_queue_to_string".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         // JSON functions (Phase 8 Plan 04)
         self.known_functions.insert("mesh_json_parse".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_json_encode".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)));
@@ -771,6 +946,8 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_http_serve_tls".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Int, MirType::String, MirType::String], Box::new(MirType::Unit)));
         self.known_functions.insert("mesh_http_response_new".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_http_response_with_headers".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String, MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_http_response_new_iodata".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_http_response_with_headers_iodata".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_http_get".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_http_post".to_string(), MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_http_request_method".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)));
@@ -793,6 +970,8 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_ws_send".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Int)));
         // mesh_ws_send_binary(conn: ptr, data: ptr, len: i64) -> i64
         self.known_functions.insert("mesh_ws_send_binary".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Int], Box::new(MirType::Int)));
+        // mesh_ws_send_iodata(conn: ptr, msg: ptr) -> i64
+        self.known_functions.insert("mesh_ws_send_iodata".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Int)));
         // mesh_ws_serve_tls(on_connect_fn: ptr, on_connect_env: ptr, on_message_fn: ptr, on_message_env: ptr, on_close_fn: ptr, on_close_env: ptr, port: i64, cert_path: ptr, key_path: ptr) -> void
         self.known_functions.insert("mesh_ws_serve_tls".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr, MirType::Ptr, MirType::Ptr, MirType::Ptr, MirType::Int, MirType::Ptr, MirType::Ptr], Box::new(MirType::Unit)));
         // ── WebSocket Room functions (Phase 62) ──────────────────────────
@@ -840,6 +1019,8 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_row_parse_int".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_row_parse_float".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_row_parse_bool".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_row_parse_bigint".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
+        self.known_functions.insert("mesh_row_parse_decimal".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_pg_query_as".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         self.known_functions.insert("mesh_pool_query_as".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         // ── Phase 97: ORM SQL Generation ─────────────────────────────────
@@ -949,6 +1130,25 @@ impl<'a> Lowerer<'a> {
         self.known_functions.insert("mesh_changeset_get_change".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
         // mesh_changeset_get_error(cs: ptr, field: ptr) -> ptr
         self.known_functions.insert("mesh_changeset_get_error".to_string(), MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
+
+        // ── Regex ───────────────────────────────────────────────────────
+        // Regex handles are opaque u64s (MirType::Int), like PoolHandle.
+        // mesh_regex_compile(pattern: ptr) -> ptr (Result<Regex, String>)
+        self.known_functions.insert("mesh_regex_compile".to_string(), MirType::FnPtr(vec![MirType::String], Box::new(MirType::Ptr)));
+        // mesh_regex_literal(pattern: ptr, flags: ptr) -> i64
+        self.known_functions.insert("mesh_regex_literal".to_string(), MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::Int)));
+        // mesh_regex_is_match(re: i64, s: ptr) -> i8
+        self.known_functions.insert("mesh_regex_is_match".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Bool)));
+        // mesh_regex_captures(re: i64, s: ptr) -> ptr (Option<List<String>>)
+        self.known_functions.insert("mesh_regex_captures".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Ptr)));
+        // mesh_regex_named_captures(re: i64, s: ptr) -> ptr (Option<Map<String, String>>)
+        self.known_functions.insert("mesh_regex_named_captures".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Ptr)));
+        // mesh_regex_scan(re: i64, s: ptr) -> ptr
+        self.known_functions.insert("mesh_regex_scan".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Ptr)));
+        // mesh_regex_replace(re: i64, s: ptr, fn_ptr: ptr, env_ptr: ptr) -> ptr
+        self.known_functions.insert("mesh_regex_replace".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String, MirType::Ptr, MirType::Ptr], Box::new(MirType::String)));
+        // mesh_regex_split(re: i64, s: ptr) -> ptr
+        self.known_functions.insert("mesh_regex_split".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::String], Box::new(MirType::Ptr)));
         // ── Phase 101: Migration DDL Operations ─────────────────────────
         // mesh_migration_create_table(pool: i64, table: ptr, columns: ptr) -> ptr
         self.known_functions.insert("mesh_migration_create_table".to_string(), MirType::FnPtr(vec![MirType::Int, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)));
@@ -1090,6 +1290,251 @@ impl<'a> Lowerer<'a> {
             Item::ActorDef(actor_def) => self.lower_actor_def(&actor_def),
             Item::ServiceDef(service_def) => self.lower_service_def(&service_def),
             Item::SupervisorDef(sup_def) => self.lower_supervisor_def(&sup_def),
+            Item::TestDef(test_def) => self.lower_test_def(&test_def),
+        }
+    }
+
+    // ── Test lowering ────────────────────────────────────────────────
+
+    /// Lower a `test "name" do ... end` block to a zero-argument Unit function.
+    ///
+    /// The function is named `__test_*` so it is never registered for remote
+    /// spawn, and is recorded in `tests` for the test runner main.
+    fn lower_test_def(&mut self, test_def: &TestDef) {
+        let name = test_def.name().unwrap_or_default();
+        let fn_name = if self.module_name.is_empty() {
+            format!("__test_{}", self.tests.len())
+        } else {
+            format!("__test_{}_{}", self.module_name.replace('.', "_"), self.tests.len())
+        };
+
+        let prev_fn_return_type = self.current_fn_return_type.replace(MirType::Unit);
+        self.push_scope();
+        let body = match test_def.body() {
+            Some(block) => self.lower_block(&block),
+            None => MirExpr::Unit,
+        };
+        self.pop_scope();
+        self.current_fn_return_type = prev_fn_return_type;
+
+        // Discard the block's value so every test has the same `() -> ()` ABI.
+        let body = MirExpr::Block(vec![body, MirExpr::Unit], MirType::Unit);
+
+        let source_name = self.source_fn_name(&format!("test {:?}", name), 0);
+        self.record_fn_location(&fn_name, source_name, test_def.syntax().text_range());
+
+        self.functions.push(MirFunction {
+            name: fn_name.clone(),
+            params: vec![],
+            return_type: MirType::Unit,
+            body,
+            is_closure_fn: false,
+            captures: vec![],
+            has_tail_calls: false,
+        });
+        self.tests.push(MirTest {
+            name,
+            fn_name,
+            module: self.module_name.clone(),
+        });
+    }
+
+    /// Lower `assert(cond)`, `refute(cond)`, or `assert_eq(left, right)`.
+    ///
+    /// Each becomes a conditional call to `mesh_assert_fail` with a message and
+    /// the call's source location. A passing assertion evaluates to Unit.
+    fn lower_assertion(&mut self, name: &str, call: &CallExpr) -> MirExpr {
+        let args: Vec<Expr> = call
+            .arg_list()
+            .map(|al| al.args().collect())
+            .unwrap_or_default();
+        let line = self.source_line(call.syntax().text_range());
+
+        if name == "assert_eq" {
+            let (Some(left_ast), Some(right_ast)) = (args.first(), args.get(1)) else {
+                return MirExpr::Unit;
+            };
+            let left = self.lower_expr(left_ast);
+            let right = self.lower_expr(right_ast);
+            let left_ty = left.ty().clone();
+            let right_ty = right.ty().clone();
+            let typeck_ty = self.get_ty(left_ast.syntax().text_range()).cloned();
+
+            // Bind both sides once so they are evaluated a single time and can
+            // appear in both the comparison and the failure message.
+            let left_var = MirExpr::Var("__assert_left".to_string(), left_ty.clone());
+            let right_var = MirExpr::Var("__assert_right".to_string(), right_ty.clone());
+
+            let equal = self.lower_equality(left_var.clone(), right_var.clone(), typeck_ty.as_ref());
+            let left_str = self.wrap_to_string(left_var, typeck_ty.as_ref());
+            let right_str = self.wrap_to_string(right_var, typeck_ty.as_ref());
+            let segments = [
+                MirExpr::StringLit("assert_eq failed\n  left:  ".to_string(), MirType::String),
+                left_str,
+                MirExpr::StringLit("\n  right: ".to_string(), MirType::String),
+                right_str,
+            ];
+            let message = segments
+                .into_iter()
+                .reduce(|acc, seg| MirExpr::Call {
+                    func: Box::new(MirExpr::Var(
+                        "mesh_string_concat".to_string(),
+                        MirType::FnPtr(
+                            vec![MirType::String, MirType::String],
+                            Box::new(MirType::String),
+                        ),
+                    )),
+                    args: vec![acc, seg],
+                    ty: MirType::String,
+                })
+                .unwrap_or(MirExpr::Unit);
+
+            return MirExpr::Let {
+                name: "__assert_left".to_string(),
+                ty: left_ty,
+                value: Box::new(left),
+                body: Box::new(MirExpr::Let {
+                    name: "__assert_right".to_string(),
+                    ty: right_ty,
+                    value: Box::new(right),
+                    body: Box::new(MirExpr::If {
+                        cond: Box::new(equal),
+                        then_body: Box::new(MirExpr::Unit),
+                        else_body: Box::new(self.assert_fail_call(message, line)),
+                        ty: MirType::Unit,
+                    }),
+                }),
+            };
+        }
+
+        let cond = match args.first() {
+            Some(arg) => self.lower_expr(arg),
+            None => return MirExpr::Unit,
+        };
+        let message = MirExpr::StringLit(format!("{} failed", name), MirType::String);
+        let fail = self.assert_fail_call(message, line);
+        let (then_body, else_body) = if name == "refute" {
+            (fail, MirExpr::Unit)
+        } else {
+            (MirExpr::Unit, fail)
+        };
+        MirExpr::If {
+            cond: Box::new(cond),
+            then_body: Box::new(then_body),
+            else_body: Box::new(else_body),
+            ty: MirType::Unit,
+        }
+    }
+
+    /// Build a `mesh_assert_fail(message, file, line)` call.
+    fn assert_fail_call(&self, message: MirExpr, line: u32) -> MirExpr {
+        MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                "mesh_assert_fail".to_string(),
+                MirType::FnPtr(
+                    vec![MirType::String, MirType::String, MirType::Int],
+                    Box::new(MirType::Unit),
+                ),
+            )),
+            args: vec![
+                message,
+                MirExpr::StringLit(self.source_path.clone(), MirType::String),
+                MirExpr::IntLit(line as i64, MirType::Int),
+            ],
+            ty: MirType::Unit,
+        }
+    }
+
+    /// Compare two lowered values for equality the way `==` does: trait
+    /// dispatch for structs and sum types, `mesh_list_eq` for lists, and a
+    /// primitive comparison otherwise.
+    fn lower_equality(&mut self, lhs: MirExpr, rhs: MirExpr, typeck_ty: Option<&Ty>) -> MirExpr {
+        let lhs_ty = lhs.ty().clone();
+        if matches!(lhs_ty, MirType::Struct(_) | MirType::SumType(_)) {
+            let mangled = format!("Eq__eq__{}", mir_type_to_impl_name(&lhs_ty));
+            if self.trait_registry.has_impl("Eq", &mir_type_to_ty(&lhs_ty))
+                || self.known_functions.contains_key(&mangled)
+            {
+                let fn_ty = MirType::FnPtr(
+                    vec![lhs_ty.clone(), rhs.ty().clone()],
+                    Box::new(MirType::Bool),
+                );
+                return MirExpr::Call {
+                    func: Box::new(MirExpr::Var(mangled, fn_ty)),
+                    args: vec![lhs, rhs],
+                    ty: MirType::Bool,
+                };
+            }
+        }
+
+        if matches!(lhs_ty, MirType::Ptr) {
+            if let Some(ty) = typeck_ty {
+                if extract_set_elem_type(ty).is_some() || extract_queue_elem_type(ty).is_some() {
+                    return self.lower_collection_eq(lhs, rhs, ty);
+                }
+            }
+            if let Some(elem_ty) = typeck_ty.and_then(extract_list_elem_type) {
+                let eq_callback = self.resolve_eq_callback(&elem_ty);
+                return MirExpr::Call {
+                    func: Box::new(MirExpr::Var(
+                        "mesh_list_eq".to_string(),
+                        MirType::FnPtr(
+                            vec![MirType::Ptr, MirType::Ptr, MirType::Ptr],
+                            Box::new(MirType::Bool),
+                        ),
+                    )),
+                    args: vec![
+                        lhs,
+                        rhs,
+                        MirExpr::Var(
+                            eq_callback,
+                            MirType::FnPtr(
+                                vec![MirType::Int, MirType::Int],
+                                Box::new(MirType::Bool),
+                            ),
+                        ),
+                    ],
+                    ty: MirType::Bool,
+                };
+            }
+        }
+
+        MirExpr::BinOp {
+            op: BinOp::Eq,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            ty: MirType::Bool,
+        }
+    }
+
+    /// 1-based source line of a syntax range.
+    ///
+    /// The syntax tree omits whitespace but keeps every newline token, so the
+    /// number of newlines before the range start gives the source line.
+    fn source_line(&self, range: TextRange) -> u32 {
+        self.newline_offsets
+            .partition_point(|&offset| offset < range.start()) as u32
+            + 1
+    }
+
+    /// Record where function `name` is defined, and its source-level name
+    /// (see [`Lowerer::source_fn_name`]), for debug info and backtraces.
+    fn record_fn_location(&mut self, name: &str, source_name: String, range: TextRange) {
+        let loc = MirSourceLoc {
+            name: source_name,
+            file: self.source_path.clone(),
+            line: self.source_line(range),
+        };
+        self.fn_locations.insert(name.to_string(), loc);
+    }
+
+    /// Source-level name of a function: `Module.fn/arity`, or `fn/arity` in
+    /// single-file mode.
+    fn source_fn_name(&self, name: &str, arity: usize) -> String {
+        if self.module_name.is_empty() {
+            format!("{}/{}", name, arity)
+        } else {
+            format!("{}.{}/{}", self.module_name, name, arity)
         }
     }
 
@@ -1172,6 +1617,8 @@ impl<'a> Lowerer<'a> {
 
         self.pop_scope();
 
+        let source_name = self.source_fn_name(&name, params.len());
+
         // Rename "main" to "mesh_main" to avoid collision with C main() entry point.
         // Then apply module-qualified naming for private functions.
         let fn_name = if name == "main" {
@@ -1194,6 +1641,7 @@ impl<'a> Lowerer<'a> {
         // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
         let has_tail_calls = rewrite_tail_calls(&mut body, &fn_name);
 
+        self.record_fn_location(&fn_name, source_name, item_range(fn_def.syntax()));
         self.functions.push(MirFunction {
             name: fn_name,
             params,
@@ -1325,6 +1773,9 @@ impl<'a> Lowerer<'a> {
         // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
         let has_tail_calls = rewrite_tail_calls(&mut body, mangled_name);
 
+        let method_name = method.name().and_then(|n| n.text()).unwrap_or_default();
+        let source_name = format!("{}.{}/{}", type_name, method_name, params.len());
+        self.record_fn_location(mangled_name, source_name, item_range(method.syntax()));
         self.functions.push(MirFunction {
             name: mangled_name.to_string(),
             params,
@@ -1441,6 +1892,8 @@ impl<'a> Lowerer<'a> {
         // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
         let has_tail_calls = rewrite_tail_calls(&mut body, &mangled);
 
+        let source_name = format!("{}.{}/{}", type_name, method_name, params.len());
+        self.record_fn_location(&mangled, source_name, item_range(interface_method.syntax()));
         self.functions.push(MirFunction {
             name: mangled,
             params,
@@ -1511,6 +1964,7 @@ impl<'a> Lowerer<'a> {
 
                 let pattern = self.lower_clause_param_pattern(clause, 0, &params);
                 let guard = self.lower_clause_guard(clause);
+                let guard = guard_with_pins(std::mem::take(&mut self.pin_tests), guard);
                 let body = self.lower_clause_body(clause);
                 self.pop_scope();
 
@@ -1529,6 +1983,7 @@ impl<'a> Lowerer<'a> {
 
             self.pop_scope();
 
+            let source_name = self.source_fn_name(&name, arity);
             let fn_name = if name == "main" {
                 self.entry_function = Some("mesh_main".to_string());
                 "mesh_main".to_string()
@@ -1548,6 +2003,7 @@ impl<'a> Lowerer<'a> {
             // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
             let has_tail_calls = rewrite_tail_calls(&mut body, &fn_name);
 
+            self.record_fn_location(&fn_name, source_name, item_range(first.syntax()));
             self.functions.push(MirFunction {
                 name: fn_name,
                 params,
@@ -1563,6 +2019,7 @@ impl<'a> Lowerer<'a> {
             let mut body = self.lower_multi_clause_if_chain(clauses, &params, &return_type);
             self.pop_scope();
 
+            let source_name = self.source_fn_name(&name, arity);
             let fn_name = if name == "main" {
                 self.entry_function = Some("mesh_main".to_string());
                 "mesh_main".to_string()
@@ -1582,6 +2039,7 @@ impl<'a> Lowerer<'a> {
             // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
             let has_tail_calls = rewrite_tail_calls(&mut body, &fn_name);
 
+            self.record_fn_location(&fn_name, source_name, item_range(first.syntax()));
             self.functions.push(MirFunction {
                 name: fn_name,
                 params,
@@ -2177,7 +2635,8 @@ impl<'a> Lowerer<'a> {
             };
 
             // Convert field value to string using wrap_to_string
-            let field_str = self.wrap_to_string(field_access, None);
+            let field_typeck_ty = self.struct_field_ty(name, field_name);
+            let field_str = self.wrap_to_string(field_access, field_typeck_ty.as_ref());
 
             // Append field value string
             result = MirExpr::Call {
@@ -2279,6 +2738,32 @@ impl<'a> Lowerer<'a> {
 
     // ── Eq/Ord generation for structs ────────────────────────────────
 
+    /// The typeck type of field `field_name` of struct `struct_name`. Derived
+    /// impls need it for fields whose MIR type is an opaque `Ptr`.
+    fn struct_field_ty(&self, struct_name: &str, field_name: &str) -> Option<Ty> {
+        let info = self.registry.struct_defs.get(struct_name)?;
+        info.fields.iter().find(|(n, _)| n == field_name).map(|(_, ty)| ty.clone())
+    }
+
+    /// `BigInt` or `Decimal` if that is the type of the given struct field.
+    fn struct_field_big_numeric(&self, struct_name: &str, field_name: &str) -> Option<&'static str> {
+        self.struct_field_ty(struct_name, field_name)?.big_numeric_name()
+    }
+
+    /// `lhs op rhs` for two values of a struct field, going through the
+    /// runtime for `BigInt` and `Decimal` fields.
+    fn struct_field_binop(&self, struct_name: &str, field_name: &str, op: BinOp, lhs: MirExpr, rhs: MirExpr) -> MirExpr {
+        match self.struct_field_big_numeric(struct_name, field_name) {
+            Some(big) => lower_big_numeric_binop(big, op, lhs, rhs, MirType::Bool),
+            None => MirExpr::BinOp {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                ty: MirType::Bool,
+            },
+        }
+    }
+
     /// Generate a synthetic `Eq__eq__StructName` MIR function.
     /// Performs field-by-field equality: all fields must be equal.
     /// Empty structs always return true.
@@ -2322,12 +2807,7 @@ impl<'a> Lowerer<'a> {
                     }
                     _ => {
                         // Primitive/string: use BinOp::Eq directly
-                        MirExpr::BinOp {
-                            op: BinOp::Eq,
-                            lhs: Box::new(self_field),
-                            rhs: Box::new(other_field),
-                            ty: MirType::Bool,
-                        }
+                        self.struct_field_binop(name, field_name, BinOp::Eq, self_field, other_field)
                     }
                 };
                 comparisons.push(cmp);
@@ -2387,7 +2867,7 @@ impl<'a> Lowerer<'a> {
             //       ...last field: self.fN < other.fN
             //     else false
             //   else false
-            self.build_lexicographic_lt(&self_var, &other_var, fields, 0)
+            self.build_lexicographic_lt(name, &self_var, &other_var, fields, 0)
         };
 
         let func = MirFunction {
@@ -2413,6 +2893,7 @@ impl<'a> Lowerer<'a> {
     /// Build a lexicographic less-than comparison chain for field at `index` and beyond.
     fn build_lexicographic_lt(
         &self,
+        struct_name: &str,
         self_var: &MirExpr,
         other_var: &MirExpr,
         fields: &[(String, MirType)],
@@ -2446,12 +2927,7 @@ impl<'a> Lowerer<'a> {
                     ty: MirType::Bool,
                 }
             }
-            _ => MirExpr::BinOp {
-                op: BinOp::Lt,
-                lhs: Box::new(self_field.clone()),
-                rhs: Box::new(other_field.clone()),
-                ty: MirType::Bool,
-            },
+            _ => self.struct_field_binop(struct_name, field_name, BinOp::Lt, self_field.clone(), other_field.clone()),
         };
 
         if is_last {
@@ -2472,16 +2948,11 @@ impl<'a> Lowerer<'a> {
                         ty: MirType::Bool,
                     }
                 }
-                _ => MirExpr::BinOp {
-                    op: BinOp::Eq,
-                    lhs: Box::new(self_field),
-                    rhs: Box::new(other_field),
-                    ty: MirType::Bool,
-                },
+                _ => self.struct_field_binop(struct_name, field_name, BinOp::Eq, self_field, other_field),
             };
 
             // Recurse for remaining fields
-            let rest = self.build_lexicographic_lt(self_var, other_var, fields, index + 1);
+            let rest = self.build_lexicographic_lt(struct_name, self_var, other_var, fields, index + 1);
 
             // if self.field < other.field then true
             // else if self.field == other.field then <rest>
@@ -3061,7 +3532,10 @@ impl<'a> Lowerer<'a> {
                     ty: field_ty.clone(),
                 };
 
-                let field_hash = self.emit_hash_for_type(field_access, field_ty);
+                let field_hash = match self.struct_field_big_numeric(name, field_name) {
+                    Some(big) => big_numeric_call(big, "hash", vec![field_access], MirType::Int),
+                    None => self.emit_hash_for_type(field_access, field_ty),
+                };
 
                 result = Some(match result {
                     None => field_hash,
@@ -3804,7 +4278,10 @@ impl<'a> Lowerer<'a> {
         };
 
         let some_var = MirExpr::Var("__opt_val".to_string(), inner_mir_type.clone());
-        let some_body = self.emit_to_json_for_type(some_var, &inner_mir_type, context_struct);
+        let some_body = match inner_type_str {
+            "BigInt" | "Decimal" => big_numeric_call(inner_type_str, "to_json", vec![some_var], MirType::Ptr),
+            _ => self.emit_to_json_for_type(some_var, &inner_mir_type, context_struct),
+        };
 
         MirExpr::Match {
             scrutinee: Box::new(expr),
@@ -3854,7 +4331,11 @@ impl<'a> Lowerer<'a> {
     }
 
     /// Emit collection (List/Map) to JSON encoding using callback-based runtime helpers.
+    /// `BigInt` and `Decimal` are also `Ptr` fields and encode as JSON strings.
     fn emit_collection_to_json(&mut self, expr: MirExpr, typeck_ty: &Ty, _context_struct: &str) -> MirExpr {
+        if let Some(big) = typeck_ty.big_numeric_name() {
+            return big_numeric_call(big, "to_json", vec![expr], MirType::Ptr);
+        }
         match typeck_ty {
             Ty::App(base, args) => {
                 if let Ty::Con(con) = base.as_ref() {
@@ -3901,6 +4382,8 @@ impl<'a> Lowerer<'a> {
                 "Float" => "mesh_json_from_float".to_string(),
                 "Bool" => "mesh_json_from_bool".to_string(),
                 "String" => "mesh_json_from_string".to_string(),
+                "BigInt" => "mesh_bigint_to_json".to_string(),
+                "Decimal" => "mesh_decimal_to_json".to_string(),
                 name => {
                     // For struct/sum types, the list stores heap pointers as u64.
                     // The runtime callback receives u64 (reinterpreted as ptr), but
@@ -3961,6 +4444,8 @@ impl<'a> Lowerer<'a> {
                 "Float" => "mesh_json_as_float".to_string(),
                 "Bool" => "mesh_json_as_bool".to_string(),
                 "String" => "mesh_json_as_string".to_string(),
+                "BigInt" => "mesh_bigint_from_json".to_string(),
+                "Decimal" => "mesh_decimal_from_json".to_string(),
                 name => format!("FromJson__from_json__{}", name),
             },
             _ => "mesh_json_as_int".to_string(),
@@ -4340,11 +4825,16 @@ impl<'a> Lowerer<'a> {
                         }),
                     };
                 } else {
-                    // Int, Float, Bool: get column value, then parse
+                    // Int, Float, Bool, BigInt, Decimal: get column value, then parse
                     let parse_fn = match field_ty {
                         MirType::Int => "mesh_row_parse_int",
                         MirType::Float => "mesh_row_parse_float",
                         MirType::Bool => "mesh_row_parse_bool",
+                        MirType::Ptr => match self.struct_field_big_numeric(name, field_name) {
+                            Some("BigInt") => "mesh_row_parse_bigint",
+                            Some(_) => "mesh_row_parse_decimal",
+                            None => "mesh_row_parse_int",
+                        },
                         _ => "mesh_row_parse_int", // fallback
                     };
                     let parse_fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr));
@@ -4456,11 +4946,13 @@ impl<'a> Lowerer<'a> {
             };
         }
 
-        // For Int/Float/Bool: parse, then wrap in Some
+        // For Int/Float/Bool/BigInt/Decimal: parse, then wrap in Some
         let parse_fn = match inner_type_str {
             "Int" => "mesh_row_parse_int",
             "Float" => "mesh_row_parse_float",
             "Bool" => "mesh_row_parse_bool",
+            "BigInt" => "mesh_row_parse_bigint",
+            "Decimal" => "mesh_row_parse_decimal",
             _ => "mesh_row_parse_int",
         };
         let parse_fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr));
@@ -4776,6 +5268,8 @@ impl<'a> Lowerer<'a> {
             "Float" => "mesh_json_as_float",
             "Bool" => "mesh_json_as_bool",
             "String" => "mesh_json_as_string",
+            "BigInt" => "mesh_bigint_from_json",
+            "Decimal" => "mesh_decimal_from_json",
             _ => "mesh_json_as_int",
         };
         let fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr));
@@ -4787,7 +5281,11 @@ impl<'a> Lowerer<'a> {
     }
 
     /// Emit collection (List/Map) from JSON decoding using callback-based runtime helpers.
+    /// `BigInt` and `Decimal` fields decode from a JSON string or number.
     fn emit_collection_from_json(&mut self, json_expr: MirExpr, typeck_ty: &Ty, _context_struct: &str) -> MirExpr {
+        if let Some(big) = typeck_ty.big_numeric_name() {
+            return big_numeric_call(big, "from_json", vec![json_expr], MirType::Ptr);
+        }
         match typeck_ty {
             Ty::App(base, args) => {
                 if let Ty::Con(con) = base.as_ref() {
@@ -4953,7 +5451,8 @@ impl<'a> Lowerer<'a> {
                 };
 
                 // Convert field value to string (no label prefix -- Display is positional)
-                let field_str = self.wrap_to_string(field_access, None);
+                let field_typeck_ty = self.struct_field_ty(name, field_name);
+                let field_str = self.wrap_to_string(field_access, field_typeck_ty.as_ref());
 
                 // Append field value string
                 result = MirExpr::Call {
@@ -5296,8 +5795,11 @@ impl<'a> Lowerer<'a> {
         // and are wrapped to nest the remaining block as the body.
         let mut parts: Vec<MirExpr> = Vec::new();
         let mut let_names: Vec<String> = Vec::new();
+        // Source line of each part, for debug line markers.
+        let mut lines: Vec<u32> = Vec::new();
 
         for child in block.syntax().children() {
+            let line = self.source_line(child.text_range());
             if let Some(item) = Item::cast(child.clone()) {
                 match item {
                     Item::LetBinding(ref let_) => {
@@ -5313,6 +5815,7 @@ impl<'a> Lowerer<'a> {
                         let ty = value.ty().clone();
                         self.insert_var(name.clone(), ty.clone());
                         let_names.push(name.clone());
+                        lines.push(line);
                         parts.push(MirExpr::Let {
                             name,
                             ty,
@@ -5329,10 +5832,27 @@ impl<'a> Lowerer<'a> {
             }
             if let Some(expr) = Expr::cast(child) {
                 let mir = self.lower_expr(&expr);
+                lines.push(line);
                 parts.push(mir);
             }
         }
 
+        // Mark where each statement starts so debug info can map instructions
+        // back to source lines. A single-statement block is covered by the
+        // location of its enclosing statement or function.
+        if self.debug && parts.len() > 1 {
+            let mut marked = Vec::with_capacity(parts.len() * 2);
+            let mut prev_line = 0;
+            for (part, line) in parts.into_iter().zip(lines) {
+                if line != prev_line {
+                    marked.push(MirExpr::SourceLine(line));
+                    prev_line = line;
+                }
+                marked.push(part);
+            }
+            parts = marked;
+        }
+
         // Build the final expression. Let bindings need to nest their body
         // over subsequent parts. We build from the end backwards:
         // [Let(x), expr1, Let(y), expr2] becomes:
@@ -5441,6 +5961,22 @@ impl<'a> Lowerer<'a> {
                 let name = atom.atom_text().unwrap_or_default();
                 MirExpr::StringLit(name, MirType::String)
             }
+            // Regex literal -- compiled by the runtime once per call site
+            // (codegen adds the caching slot); the type checker has already
+            // rejected invalid patterns.
+            Expr::RegexLiteral(re) => {
+                let fn_ty = MirType::FnPtr(vec![MirType::String, MirType::String], Box::new(MirType::Int));
+                MirExpr::Call {
+                    func: Box::new(MirExpr::Var("mesh_regex_literal".to_string(), fn_ty)),
+                    args: vec![
+                        MirExpr::StringLit(re.pattern().unwrap_or_default(), MirType::String),
+                        MirExpr::StringLit(re.flags(), MirType::String),
+                    ],
+                    ty: MirType::Int,
+                }
+            }
+            // Binary construction: <<tag::u8, body::bytes>>
+            Expr::BytesLiteral(lit) => self.lower_bytes_literal(lit),
             // Struct update expression: %{base | field: value, ...}
             Expr::StructUpdate(update) => self.lower_struct_update(update),
         }
@@ -5633,9 +6169,25 @@ impl<'a> Lowerer<'a> {
 
         // List Eq/Ord dispatch: if lhs is Ptr and typeck type is List<T>,
         // emit mesh_list_eq / mesh_list_compare with element callback.
+        // Set<T> and Queue<T> support Eq only.
         if matches!(lhs_ty, MirType::Ptr) {
             if let Some(lhs_ast) = bin.lhs() {
                 if let Some(lhs_typeck) = self.get_ty(lhs_ast.syntax().text_range()).cloned() {
+                    if matches!(op, BinOp::Eq | BinOp::NotEq)
+                        && (extract_set_elem_type(&lhs_typeck).is_some()
+                            || extract_queue_elem_type(&lhs_typeck).is_some())
+                    {
+                        let call = self.lower_collection_eq(lhs, rhs, &lhs_typeck);
+                        if op == BinOp::NotEq {
+                            return MirExpr::BinOp {
+                                op: BinOp::Eq,
+                                lhs: Box::new(call),
+                                rhs: Box::new(MirExpr::BoolLit(false, MirType::Bool)),
+                                ty,
+                            };
+                        }
+                        return call;
+                    }
                     if let Some(elem_ty) = extract_list_elem_type(&lhs_typeck) {
                         match op {
                             BinOp::Eq | BinOp::NotEq => {
@@ -5703,6 +6255,59 @@ impl<'a> Lowerer<'a> {
             }
         }
 
+        // Bytes Eq dispatch: compare contents rather than pointers.
+        if matches!(op, BinOp::Eq | BinOp::NotEq)
+            && bin.lhs().and_then(|e| self.get_ty(e.syntax().text_range()).cloned()) == Some(Ty::bytes())
+        {
+            let call = MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_bytes_eq".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)),
+                )),
+                args: vec![lhs, rhs],
+                ty: MirType::Bool,
+            };
+            if op == BinOp::NotEq {
+                return MirExpr::BinOp {
+                    op: BinOp::Eq,
+                    lhs: Box::new(call),
+                    rhs: Box::new(MirExpr::BoolLit(false, MirType::Bool)),
+                    ty,
+                };
+            }
+            return call;
+        }
+
+        // BigInt and Decimal are heap values; every operator is a runtime call.
+        let big = bin
+            .lhs()
+            .and_then(|e| self.get_ty(e.syntax().text_range()))
+            .and_then(|t| t.big_numeric_name());
+        if let Some(name) = big {
+            return lower_big_numeric_binop(name, op, lhs, rhs, ty);
+        }
+
+        // Fixed-width integers are plain Ints at the MIR level; the operand's
+        // typeck type says which width to wrap to.
+        if matches!(lhs_ty, MirType::Int) {
+            let fixed = bin
+                .lhs()
+                .and_then(|e| self.get_ty(e.syntax().text_range()))
+                .and_then(|t| t.fixed_int_name());
+            if let Some(name) = fixed {
+                return lower_fixed_int_binop(name, op, lhs, rhs, ty);
+            }
+            if matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod) {
+                return MirExpr::CheckedIntOp {
+                    op,
+                    lhs: Box::new(lhs),
+                    rhs: Box::new(rhs),
+                    file: self.source_path.clone(),
+                    line: self.source_line(bin.syntax().text_range()),
+                };
+            }
+        }
+
         MirExpr::BinOp {
             op,
             lhs: Box::new(lhs),
@@ -5760,6 +6365,40 @@ impl<'a> Lowerer<'a> {
             }
         }
 
+        // Negating a BigInt or Decimal allocates a new value.
+        if op == UnaryOp::Neg {
+            if let Some(name) = self.get_ty(un.syntax().text_range()).and_then(|t| t.big_numeric_name()) {
+                return big_numeric_call(name, "neg", vec![operand], MirType::Ptr);
+            }
+        }
+
+        // Negating a signed fixed-width integer wraps back into range.
+        if op == UnaryOp::Neg {
+            if let Some(name) = self.get_ty(un.syntax().text_range()).and_then(|t| t.fixed_int_name()) {
+                let neg = MirExpr::UnaryOp {
+                    op,
+                    operand: Box::new(operand),
+                    ty: MirType::Int,
+                };
+                return wrap_fixed_int(name, neg);
+            }
+        }
+
+        // Negating an Int overflows only for Int.min, exactly like `0 - x`,
+        // so it is checked as that subtraction. Literals cannot overflow.
+        if op == UnaryOp::Neg
+            && matches!(ty, MirType::Int)
+            && !matches!(operand, MirExpr::IntLit(..))
+        {
+            return MirExpr::CheckedIntOp {
+                op: BinOp::Sub,
+                lhs: Box::new(MirExpr::IntLit(0, MirType::Int)),
+                rhs: Box::new(operand),
+                file: self.source_path.clone(),
+                line: self.source_line(un.syntax().text_range()),
+            };
+        }
+
         MirExpr::UnaryOp {
             op,
             operand: Box::new(operand),
@@ -5781,6 +6420,24 @@ impl<'a> Lowerer<'a> {
         self.registry.struct_defs.contains_key(name)
     }
 
+    /// The runtime function for `method` called on a `Bytes`, `BigInt`,
+    /// `Decimal` or fixed-width integer `receiver`, e.g. `mesh_bytes_length`
+    /// for `b.length()`. Returns None for other receivers and for methods
+    /// their module does not have, which then go through trait dispatch.
+    fn typed_receiver_method(&self, receiver: &Expr, method: &str) -> Option<String> {
+        let ty = self.get_ty(receiver.syntax().text_range())?;
+        let module = if *ty == Ty::bytes() {
+            "bytes".to_string()
+        } else if let Some(name) = ty.big_numeric_name() {
+            name.to_lowercase()
+        } else {
+            ty.fixed_int_name()?.to_lowercase()
+        };
+        let method = if method == "inspect" { "to_string" } else { method };
+        let runtime = map_builtin_name(&format!("{}_{}", module, method));
+        self.known_functions.contains_key(&runtime).then_some(runtime)
+    }
+
     /// Resolve a trait method callee: given a method name and the first argument's type,
     /// check if it's a trait method and rewrite to the mangled name (Trait__Method__Type).
     /// Returns the resolved callee (either mangled or original).
@@ -5932,6 +6589,20 @@ impl<'a> Lowerer<'a> {
 
                     let ty = self.resolve_range(call.syntax().text_range());
 
+                    // Bytes and fixed-width integer receivers lower to plain
+                    // Ptr/Int, so their module functions are found through the
+                    // receiver's typeck type (b.length() -> Bytes.length(b)).
+                    if let Some(runtime) =
+                        fa.base().and_then(|base| self.typed_receiver_method(&base, &method_name))
+                    {
+                        let fn_ty = self.known_functions[&runtime].clone();
+                        return MirExpr::Call {
+                            func: Box::new(MirExpr::Var(runtime, fn_ty)),
+                            args,
+                            ty,
+                        };
+                    }
+
                     // Route through the shared trait dispatch helper
                     let first_arg_ty = args[0].ty().clone();
                     let callee_var_ty = MirType::FnPtr(
@@ -5992,6 +6663,18 @@ impl<'a> Lowerer<'a> {
             }
         }
 
+        // Assertion builtins (`assert`, `assert_eq`, `refute`) unless shadowed
+        // by a user definition or local binding.
+        if let Some(Expr::NameRef(ref name_ref)) = call.callee() {
+            if let Some(name) = name_ref.text() {
+                if matches!(name.as_str(), "assert" | "assert_eq" | "refute")
+                    && self.lookup_var(&name).is_none()
+                {
+                    return self.lower_assertion(&name, call);
+                }
+            }
+        }
+
         // Non-method-call path: normal function calls (unchanged from before).
         let callee = call.callee().map(|e| self.lower_expr(&e));
         let args: Vec<MirExpr> = call
@@ -6065,59 +6748,6 @@ impl<'a> Lowerer<'a> {
             }
         }
 
-        // For Map functions that take a key argument (put, get, has_key, delete),
-        // handle key type dispatch:
-        // - String keys: wrap the map argument in mesh_map_tag_string()
-        // - Struct keys with Hash impl: hash the key via Hash__hash__TypeName,
-        //   use the hash as an integer key (hash-as-key approach for v1.3)
-        let args = if let MirExpr::Var(ref name, _) = callee {
-            if matches!(name.as_str(), "mesh_map_put" | "mesh_map_get" | "mesh_map_has_key" | "mesh_map_delete")
-                && args.len() >= 2
-            {
-                let key_ty = args[1].ty().clone();
-                if matches!(key_ty, MirType::String) {
-                    // String key: tag the map for string comparison
-                    let mut new_args = args;
-                    let map_arg = new_args.remove(0);
-                    let tagged_map = MirExpr::Call {
-                        func: Box::new(MirExpr::Var("mesh_map_tag_string".to_string(), MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)))),
-                        args: vec![map_arg],
-                        ty: MirType::Ptr,
-                    };
-                    new_args.insert(0, tagged_map);
-                    new_args
-                } else if matches!(key_ty, MirType::Struct(_)) {
-                    // Struct key with Hash impl: hash the key, use hash as int key.
-                    let ty_for_lookup = mir_type_to_ty(&key_ty);
-                    if self.trait_registry.has_impl("Hash", &ty_for_lookup) {
-                        let type_name = mir_type_to_impl_name(&key_ty);
-                        let hash_fn_name = format!("Hash__hash__{}", type_name);
-                        let hash_fn_ty = MirType::FnPtr(
-                            vec![key_ty.clone()],
-                            Box::new(MirType::Int),
-                        );
-                        let mut new_args = args;
-                        let key_arg = new_args.remove(1);
-                        let hashed_key = MirExpr::Call {
-                            func: Box::new(MirExpr::Var(hash_fn_name, hash_fn_ty)),
-                            args: vec![key_arg],
-                            ty: MirType::Int,
-                        };
-                        new_args.insert(1, hashed_key);
-                        new_args
-                    } else {
-                        args
-                    }
-                } else {
-                    args
-                }
-            } else {
-                args
-            }
-        } else {
-            args
-        };
-
         // Static trait method dispatch: bare `default()` with zero arguments.
         // The type is resolved from the call-site context (type annotation / inference),
         // NOT from a first argument (since Default::default has no self parameter).
@@ -6301,11 +6931,11 @@ impl<'a> Lowerer<'a> {
         };
 
         if is_known_fn {
-            MirExpr::Call {
-                func: Box::new(callee),
-                args,
-                ty,
-            }
+            let key_ty = call
+                .arg_list()
+                .and_then(|al| al.args().nth(1))
+                .and_then(|arg| self.get_ty(arg.syntax().text_range()).cloned());
+            self.finish_call(callee, args, ty, call.syntax().text_range(), key_ty)
         } else {
             // Check the callee type. If it's a Closure type, use ClosureCall.
             match callee.ty() {
@@ -6328,25 +6958,49 @@ impl<'a> Lowerer<'a> {
     fn lower_pipe_expr(&mut self, pipe: &PipeExpr) -> MirExpr {
         // Desugar: `x |> f` -> `f(x)`
         //          `x |> f(a, b)` -> `f(x, a, b)`
+        //          `x |2> f(a, b)` -> `let __pipe_N = x in f(a, __pipe_N, b)`
         let lhs = pipe
             .lhs()
             .map(|e| self.lower_expr(&e))
             .unwrap_or(MirExpr::Unit);
+        // Out-of-range positions were rejected by the type checker.
+        let position = pipe.position().unwrap_or(1).saturating_sub(1);
+
+        // A value piped past the first argument is bound first, so it is
+        // still evaluated before the arguments to its left.
+        let mut pipe_binding = None;
+        let lhs = if position > 0 {
+            self.pipe_counter += 1;
+            let name = format!("__pipe_{}", self.pipe_counter);
+            let lhs_ty = lhs.ty().clone();
+            pipe_binding = Some((name.clone(), lhs_ty.clone(), lhs));
+            MirExpr::Var(name, lhs_ty)
+        } else {
+            lhs
+        };
 
         let rhs = pipe.rhs();
         let ty = self.resolve_range(pipe.syntax().text_range());
+        // Type of the second full argument, the key of a Map.put/get/... call.
+        let mut key_ty = None;
 
         let mut result = match rhs {
             Some(Expr::CallExpr(call)) => {
-                // `x |> f(a, b)` -> `f(x, a, b)` -- prepend lhs to existing args.
+                // `x |N> f(a, b)` -> insert lhs into the existing args at position N.
+                let key_range = match position {
+                    1 => pipe.lhs().map(|e| e.syntax().text_range()),
+                    0 => call.arg_list().and_then(|al| al.args().next()).map(|e| e.syntax().text_range()),
+                    _ => call.arg_list().and_then(|al| al.args().nth(1)).map(|e| e.syntax().text_range()),
+                };
+                key_ty = key_range.and_then(|range| self.get_ty(range).cloned());
                 let callee = call.callee().map(|e| self.lower_expr(&e));
                 let mut args: Vec<MirExpr> = Vec::new();
-                args.push(lhs);
                 if let Some(arg_list) = call.arg_list() {
                     for arg in arg_list.args() {
                         args.push(self.lower_expr(&arg));
                     }
                 }
+                args.insert(position.min(args.len()), lhs);
                 let callee = match callee {
                     Some(c) => c,
                     None => return MirExpr::Unit,
@@ -6369,19 +7023,20 @@ impl<'a> Lowerer<'a> {
             None => MirExpr::Unit,
         };
 
-        // Phase 96: Map.collect string key detection.
-        // If the pipe result is mesh_map_collect and the pipe chain's source
-        // has string keys (e.g., List<(String, V)> or Map<String, V>), swap
-        // to the string-key variant.
-        if let MirExpr::Call { ref mut func, .. } = result {
-            if let MirExpr::Var(ref name, _) = **func {
-                if name == "mesh_map_collect" {
-                    if self.pipe_chain_has_string_keys(pipe) {
-                        let fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr));
-                        **func = MirExpr::Var("mesh_map_collect_string_keys".to_string(), fn_ty);
-                    }
-                }
-            }
+        if let MirExpr::Call { func, args, ty } = result {
+            result = self.finish_call(*func, args, ty, pipe.syntax().text_range(), key_ty);
+        }
+
+        // `Let` reports the type of its binding, so a block carries the call's.
+        if let Some((name, lhs_ty, value)) = pipe_binding {
+            let ty = result.ty().clone();
+            let binding = MirExpr::Let {
+                name,
+                ty: lhs_ty,
+                value: Box::new(value),
+                body: Box::new(result),
+            };
+            result = MirExpr::Block(vec![binding], ty);
         }
 
         result
@@ -6628,6 +7283,9 @@ impl<'a> Lowerer<'a> {
             if let Some(elem_ty) = extract_set_elem_type(ty) {
                 return self.lower_for_in_set(for_in, &elem_ty);
             }
+            if let Some(elem_ty) = extract_queue_elem_type(ty) {
+                return self.lower_for_in_queue(for_in, &elem_ty);
+            }
             if let Some(elem_ty) = extract_list_elem_type(ty) {
                 return self.lower_for_in_list(for_in, &elem_ty);
             }
@@ -6887,6 +7545,24 @@ impl<'a> Lowerer<'a> {
         }
     }
 
+    /// For-in over a Queue iterates its elements front to back: the same
+    /// loop as a List, over `mesh_queue_to_list(queue)`.
+    fn lower_for_in_queue(&mut self, for_in: &ForInExpr, elem_ty_src: &Ty) -> MirExpr {
+        let mut lowered = self.lower_for_in_list(for_in, elem_ty_src);
+        if let MirExpr::ForInList { ref mut collection, .. } = lowered {
+            let queue = std::mem::replace(collection.as_mut(), MirExpr::Unit);
+            **collection = MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_queue_to_list".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)),
+                )),
+                args: vec![queue],
+                ty: MirType::Ptr,
+            };
+        }
+        lowered
+    }
+
     // ── Case expression lowering ─────────────────────────────────────
 
     fn lower_case_expr(&mut self, case: &CaseExpr) -> MirExpr {
@@ -6895,7 +7571,7 @@ impl<'a> Lowerer<'a> {
             .map(|e| self.lower_expr(&e))
             .unwrap_or(MirExpr::Unit);
 
-        let arms: Vec<MirMatchArm> = case.arms().map(|arm| self.lower_match_arm(&arm)).collect();
+        let arms: Vec<MirMatchArm> = case.arms().flat_map(|arm| self.lower_match_arm(&arm)).collect();
 
         let ty = self.resolve_range(case.syntax().text_range());
 
@@ -6906,13 +7582,10 @@ impl<'a> Lowerer<'a> {
         }
     }
 
-    fn lower_match_arm(&mut self, arm: &MatchArm) -> MirMatchArm {
+    fn lower_match_arm(&mut self, arm: &MatchArm) -> Vec<MirMatchArm> {
         self.push_scope();
 
-        let pattern = arm
-            .pattern()
-            .map(|p| self.lower_pattern(&p))
-            .unwrap_or(MirPattern::Wildcard);
+        let patterns = self.lower_arm_patterns(arm.pattern());
 
         let guard = arm.guard().map(|e| self.lower_expr(&e));
 
@@ -6923,11 +7596,7 @@ impl<'a> Lowerer<'a> {
 
         self.pop_scope();
 
-        MirMatchArm {
-            pattern,
-            guard,
-            body,
-        }
+        pinned_arms(patterns, guard, body)
     }
 
     // ── Pattern lowering ─────────────────────────────────────────────
@@ -7092,9 +7761,277 @@ impl<'a> Lowerer<'a> {
                     elem_ty: elem_mir_ty,
                 }
             }
+
+            Pattern::Bytes(bytes_pat) => self.lower_bytes_pattern(bytes_pat),
+
+            Pattern::Map(map_pat) => self.lower_map_pattern(map_pat),
+
+            Pattern::Pin(pin_pat) => self.lower_pin_pattern(pin_pat),
+        }
+    }
+
+    /// Lower a map pattern. A map pattern without entries matches any map.
+    fn lower_map_pattern(&mut self, map_pat: &MapPat) -> MirPattern {
+        let val_ty = self
+            .get_ty(map_pat.syntax().text_range())
+            .and_then(extract_map_types)
+            .map(|(_, v)| resolve_type(&v, self.registry, false))
+            .unwrap_or(MirType::Int);
+        let mut entries = Vec::new();
+        for entry in map_pat.entries() {
+            let Some(lit) = entry.key() else { continue };
+            let key = if lit.token().is_some_and(|t| t.kind() == SyntaxKind::STRING_START) {
+                MirMapKey::String(extract_simple_string_content(lit.syntax()))
+            } else if lit.syntax().children_with_tokens().any(|t| t.kind() == SyntaxKind::MINUS) {
+                MirMapKey::Int(extract_negative_literal(lit.syntax()))
+            } else {
+                MirMapKey::Int(lit.token().and_then(|t| t.text().parse().ok()).unwrap_or(0))
+            };
+            let value = entry
+                .value()
+                .map(|p| self.lower_pattern(&p))
+                .unwrap_or(MirPattern::Wildcard);
+            entries.push((key, value));
+        }
+        if entries.is_empty() {
+            return MirPattern::Wildcard;
+        }
+        MirPattern::Map { entries, val_ty }
+    }
+
+    /// Lower a pinned pattern (`^name`) to a fresh binding whose value the
+    /// arm's guard compares with `name`; see `pinned_arms`.
+    fn lower_pin_pattern(&mut self, pin_pat: &PinPat) -> MirPattern {
+        let range = pin_pat.syntax().text_range();
+        let ty = self.resolve_range(range);
+        let typeck_ty = self.get_ty(range).cloned().unwrap_or_else(Ty::int);
+        let name = pin_pat.name().map(|t| t.text().to_string()).unwrap_or_default();
+        let pinned_ty = self.lookup_var(&name).unwrap_or_else(|| ty.clone());
+
+        self.pin_counter += 1;
+        let binder = format!("__pin_{}", self.pin_counter);
+        self.insert_var(binder.clone(), ty.clone());
+        let test = self.lower_eq_test(
+            MirExpr::Var(binder.clone(), ty.clone()),
+            MirExpr::Var(name, pinned_ty),
+            &typeck_ty,
+        );
+        self.pin_tests.push(test);
+        MirPattern::Var(binder, ty)
+    }
+
+    /// `lhs == rhs` for two values of type `ty`, dispatched like the `==`
+    /// operator: user types call their `Eq` impl and heap values compare
+    /// their contents.
+    fn lower_eq_test(&mut self, lhs: MirExpr, rhs: MirExpr, ty: &Ty) -> MirExpr {
+        let mir_ty = lhs.ty().clone();
+        if matches!(mir_ty, MirType::Struct(_) | MirType::SumType(_)) {
+            let mangled = format!("Eq__eq__{}", mir_type_to_impl_name(&mir_ty));
+            if self.trait_registry.has_impl("Eq", &mir_type_to_ty(&mir_ty))
+                || self.known_functions.contains_key(&mangled)
+            {
+                return MirExpr::Call {
+                    func: Box::new(MirExpr::Var(
+                        mangled,
+                        MirType::FnPtr(vec![mir_ty.clone(), mir_ty], Box::new(MirType::Bool)),
+                    )),
+                    args: vec![lhs, rhs],
+                    ty: MirType::Bool,
+                };
+            }
+        }
+        if extract_set_elem_type(ty).is_some() || extract_queue_elem_type(ty).is_some() {
+            return self.lower_collection_eq(lhs, rhs, ty);
+        }
+        if let Some(elem_ty) = extract_list_elem_type(ty) {
+            let eq_callback = self.resolve_eq_callback(&elem_ty);
+            return MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_list_eq".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)),
+                )),
+                args: vec![
+                    lhs,
+                    rhs,
+                    MirExpr::Var(
+                        eq_callback,
+                        MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Bool)),
+                    ),
+                ],
+                ty: MirType::Bool,
+            };
+        }
+        if *ty == Ty::bytes() {
+            return MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_bytes_eq".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)),
+                )),
+                args: vec![lhs, rhs],
+                ty: MirType::Bool,
+            };
+        }
+        if let Some(name) = ty.big_numeric_name() {
+            return lower_big_numeric_binop(name, BinOp::Eq, lhs, rhs, MirType::Bool);
+        }
+        MirExpr::BinOp {
+            op: BinOp::Eq,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            ty: MirType::Bool,
         }
     }
 
+    /// Lower the pattern of a `case` or `receive` arm. Returns one pattern
+    /// per arm to emit, each with the tests of its pinned patterns: a
+    /// top-level or-pattern whose alternatives pin values is split so that
+    /// each alternative is guarded by its own tests.
+    fn lower_arm_patterns(&mut self, pat: Option<Pattern>) -> Vec<(MirPattern, Vec<MirExpr>)> {
+        let Some(pat) = pat else {
+            return vec![(MirPattern::Wildcard, Vec::new())];
+        };
+        match &pat {
+            Pattern::Or(or) => {
+                let alts: Vec<(MirPattern, Vec<MirExpr>)> = or
+                    .alternatives()
+                    .map(|p| {
+                        let alt = self.lower_pattern(&p);
+                        (alt, std::mem::take(&mut self.pin_tests))
+                    })
+                    .collect();
+                if alts.iter().all(|(_, tests)| tests.is_empty()) {
+                    vec![(MirPattern::Or(alts.into_iter().map(|(p, _)| p).collect()), Vec::new())]
+                } else {
+                    alts
+                }
+            }
+            _ => {
+                let lowered = self.lower_pattern(&pat);
+                vec![(lowered, std::mem::take(&mut self.pin_tests))]
+            }
+        }
+    }
+
+    /// Lower a binary pattern to the runtime segment spec read by
+    /// `mesh_bytes_matches` plus one sub-pattern per segment.
+    ///
+    /// A `bytes` size naming an earlier integer segment becomes `bytes:@K`,
+    /// K being that segment's index; a string literal supplies its own size.
+    fn lower_bytes_pattern(&mut self, bytes_pat: &BytesPat) -> MirPattern {
+        let mut entries = Vec::new();
+        let mut fields = Vec::new();
+        let mut int_names: Vec<(String, usize)> = Vec::new();
+        for (i, segment) in bytes_pat.segments().enumerate() {
+            let field = match segment.pattern() {
+                Some(Pattern::Literal(lit))
+                    if lit.token().is_some_and(|t| t.kind() == SyntaxKind::INT_LITERAL) =>
+                {
+                    let has_minus = lit
+                        .syntax()
+                        .children_with_tokens()
+                        .any(|t| t.kind() == SyntaxKind::MINUS);
+                    let value = if has_minus {
+                        extract_negative_literal(lit.syntax())
+                    } else {
+                        lit.token().and_then(|t| t.text().parse().ok()).unwrap_or(0)
+                    };
+                    MirPattern::Literal(MirLiteral::Int(value))
+                }
+                Some(p) => self.lower_pattern(&p),
+                None => MirPattern::Wildcard,
+            };
+            let entry = match segment.segment_type() {
+                Ok(SegmentType::Int { bits, signed, little }) => {
+                    if let Some(Pattern::Ident(ident)) = segment.pattern() {
+                        if let Some(name) = ident.name() {
+                            int_names.push((name.text().to_string(), i));
+                        }
+                    }
+                    let sign = if signed { "i" } else { "u" };
+                    format!("{}{}{}", sign, bits, if little { "le" } else { "" })
+                }
+                Ok(SegmentType::Bytes) => {
+                    let size = match (segment.size(), &field) {
+                        (Some(Expr::NameRef(name)), _) => {
+                            let name = name.text().unwrap_or_default();
+                            let index = int_names.iter().rev().find(|(n, _)| *n == name).map(|(_, k)| *k);
+                            format!("@{}", index.unwrap_or(usize::MAX))
+                        }
+                        (Some(Expr::Literal(size)), _) => {
+                            size.token().map(|t| t.text().to_string()).unwrap_or_default()
+                        }
+                        (_, MirPattern::Literal(MirLiteral::String(s))) => s.len().to_string(),
+                        _ => "0".to_string(),
+                    };
+                    format!("bytes:{}", size)
+                }
+                Ok(SegmentType::Rest) | Err(_) => "rest".to_string(),
+            };
+            entries.push(entry);
+            fields.push(field);
+        }
+        MirPattern::Binary {
+            spec: entries.join(","),
+            fields,
+        }
+    }
+
+    /// Lower a binary construction to a chain of appends onto an empty
+    /// buffer: `mesh_bytes_put_int` with the segment's spec entry for
+    /// integer segments, `mesh_bytes_put_bytes` (size -1 for the whole
+    /// value) for `bytes` and `rest` segments.
+    fn lower_bytes_literal(&mut self, lit: &BytesLiteral) -> MirExpr {
+        let mut acc = MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                "mesh_bytes_new".to_string(),
+                MirType::FnPtr(vec![], Box::new(MirType::Ptr)),
+            )),
+            args: vec![],
+            ty: MirType::Ptr,
+        };
+        for segment in lit.segments() {
+            let value = segment
+                .value()
+                .map(|v| self.lower_expr(&v))
+                .unwrap_or(MirExpr::IntLit(0, MirType::Int));
+            acc = match segment.segment_type() {
+                Ok(SegmentType::Int { bits, signed, little }) => {
+                    let sign = if signed { "i" } else { "u" };
+                    let entry = format!("{}{}{}", sign, bits, if little { "le" } else { "" });
+                    MirExpr::Call {
+                        func: Box::new(MirExpr::Var(
+                            "mesh_bytes_put_int".to_string(),
+                            MirType::FnPtr(
+                                vec![MirType::Ptr, MirType::Int, MirType::String],
+                                Box::new(MirType::Ptr),
+                            ),
+                        )),
+                        args: vec![acc, value, MirExpr::StringLit(entry, MirType::String)],
+                        ty: MirType::Ptr,
+                    }
+                }
+                _ => {
+                    let size = segment
+                        .size()
+                        .map(|s| self.lower_expr(&s))
+                        .unwrap_or(MirExpr::IntLit(-1, MirType::Int));
+                    MirExpr::Call {
+                        func: Box::new(MirExpr::Var(
+                            "mesh_bytes_put_bytes".to_string(),
+                            MirType::FnPtr(
+                                vec![MirType::Ptr, MirType::Ptr, MirType::Int],
+                                Box::new(MirType::Ptr),
+                            ),
+                        )),
+                        args: vec![acc, value, size],
+                        ty: MirType::Ptr,
+                    }
+                }
+            };
+        }
+        acc
+    }
+
     // ── Closure expression lowering (CLOSURE CONVERSION) ─────────────
 
     fn lower_closure_expr(&mut self, closure: &ClosureExpr) -> MirExpr {
@@ -7191,6 +8128,8 @@ impl<'a> Lowerer<'a> {
         }
 
         // Create the lifted function.
+        let source_name = self.source_fn_name("fn", param_names.len());
+        self.record_fn_location(&closure_fn_name, source_name, closure.syntax().text_range());
         self.functions.push(MirFunction {
             name: closure_fn_name.clone(),
             params: fn_params,
@@ -7296,6 +8235,7 @@ impl<'a> Lowerer<'a> {
                     .guard()
                     .and_then(|gc| gc.expr())
                     .map(|e| self.lower_expr(&e));
+                let guard = guard_with_pins(std::mem::take(&mut self.pin_tests), guard);
                 let body = if let Some(block) = closure.body() {
                     self.lower_block(&block)
                 } else {
@@ -7324,6 +8264,7 @@ impl<'a> Lowerer<'a> {
                     .guard()
                     .and_then(|gc| gc.expr())
                     .map(|e| self.lower_expr(&e));
+                let guard = guard_with_pins(std::mem::take(&mut self.pin_tests), guard);
                 let body = if let Some(block) = clause.body() {
                     self.lower_block(&block)
                 } else {
@@ -7363,6 +8304,8 @@ impl<'a> Lowerer<'a> {
         }
 
         // Create the lifted function.
+        let source_name = self.source_fn_name("fn", arity);
+        self.record_fn_location(&closure_fn_name, source_name, closure.syntax().text_range());
         self.functions.push(MirFunction {
             name: closure_fn_name.clone(),
             params: fn_params,
@@ -7642,17 +8585,20 @@ impl<'a> Lowerer<'a> {
                     }
                 }
                 SyntaxKind::INTERPOLATION => {
-                    // INTERPOLATION node contains an expression child.
-                    if let Some(node) = child.as_node() {
-                        for inner in node.children() {
-                            if let Some(expr) = Expr::cast(inner) {
-                                let typeck_ty = self.get_ty(expr.syntax().text_range()).cloned();
-                                let lowered = self.lower_expr(&expr);
-                                // Wrap in a to_string call based on the expression's type.
-                                let converted = self.wrap_to_string(lowered, typeck_ty.as_ref());
-                                segments.push(converted);
-                            }
-                        }
+                    // INTERPOLATION node contains an expression child and
+                    // an optional format spec.
+                    let Some(interp) = child.as_node().cloned().and_then(Interpolation::cast) else {
+                        continue;
+                    };
+                    if let Some(expr) = interp.expr() {
+                        let typeck_ty = self.get_ty(expr.syntax().text_range()).cloned();
+                        let lowered = self.lower_expr(&expr);
+                        let converted = match interp.format_spec_text() {
+                            Some(spec) => self.wrap_format_spec(lowered, typeck_ty.as_ref(), spec),
+                            // Wrap in a to_string call based on the expression's type.
+                            None => self.wrap_to_string(lowered, typeck_ty.as_ref()),
+                        };
+                        segments.push(converted);
                     }
                 }
                 _ => {
@@ -7691,6 +8637,28 @@ impl<'a> Lowerer<'a> {
         result
     }
 
+    /// Format an interpolated value with its `:spec` (already checked by the
+    /// type checker). Ints and Floats format directly; any other value goes
+    /// through `wrap_to_string` and is padded or truncated as a String.
+    fn wrap_format_spec(&mut self, expr: MirExpr, typeck_ty: Option<&Ty>, spec: String) -> MirExpr {
+        let (func, arg_ty, value) = match expr.ty() {
+            MirType::Int if typeck_ty.and_then(|t| t.fixed_int_name()) == Some("UInt64") => {
+                ("mesh_format_uint64", MirType::Int, expr)
+            }
+            MirType::Int => ("mesh_format_int", MirType::Int, expr),
+            MirType::Float => ("mesh_format_float", MirType::Float, expr),
+            _ => ("mesh_format_string", MirType::String, self.wrap_to_string(expr, typeck_ty)),
+        };
+        MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                func.to_string(),
+                MirType::FnPtr(vec![arg_ty, MirType::String], Box::new(MirType::String)),
+            )),
+            args: vec![value, MirExpr::StringLit(spec, MirType::String)],
+            ty: MirType::String,
+        }
+    }
+
     /// Wrap an expression in a to_string runtime call based on its type.
     ///
     /// `typeck_ty` is the optional original typeck `Ty` for the expression,
@@ -7698,6 +8666,14 @@ impl<'a> Lowerer<'a> {
     fn wrap_to_string(&mut self, expr: MirExpr, typeck_ty: Option<&Ty>) -> MirExpr {
         match expr.ty() {
             MirType::String => expr, // already a string
+            MirType::Int if typeck_ty.and_then(|t| t.fixed_int_name()) == Some("UInt64") => MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_uint64_to_string".to_string(),
+                    MirType::FnPtr(vec![MirType::Int], Box::new(MirType::String)),
+                )),
+                args: vec![expr],
+                ty: MirType::String,
+            },
             MirType::Int => MirExpr::Call {
                 func: Box::new(MirExpr::Var(
                     "mesh_int_to_string".to_string(),
@@ -7789,6 +8765,18 @@ impl<'a> Lowerer<'a> {
                     }
                 }
             }
+            MirType::Ptr if typeck_ty == Some(&Ty::bytes()) => MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_bytes_to_string".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::String)),
+                )),
+                args: vec![expr],
+                ty: MirType::String,
+            },
+            MirType::Ptr if typeck_ty.and_then(|t| t.big_numeric_name()).is_some() => {
+                let name = typeck_ty.and_then(|t| t.big_numeric_name()).unwrap_or_default();
+                big_numeric_call(name, "to_string", vec![expr], MirType::String)
+            }
             MirType::Ptr => {
                 // Check if the typeck type is a collection (List, Map, Set).
                 // If so, emit a runtime collection-to-string call with element
@@ -7824,11 +8812,11 @@ impl<'a> Lowerer<'a> {
 
     /// Attempt to wrap a collection expression in its Display runtime call.
     ///
-    /// Returns `Some(MirExpr)` if the `Ty` is a List, Map, or Set with known
+    /// Returns `Some(MirExpr)` if the `Ty` is a List, Map, Set, or Queue with known
     /// element types; `None` otherwise (fallback to generic to_string).
     fn wrap_collection_to_string(&mut self, expr: &MirExpr, ty: &Ty) -> Option<MirExpr> {
-        // Match Ty::App(Con("List"|"Map"|"Set"), args).
-        // Also handle Ty::Con("List"|"Map"|"Set") without type args (empty collections).
+        // Match Ty::App(Con("List"|"Map"|"Set"|"Queue"), args).
+        // Also handle the bare Ty::Con forms without type args (empty collections).
         let (base_name, args) = match ty {
             Ty::App(con_ty, args) => {
                 if let Ty::Con(con) = con_ty.as_ref() {
@@ -7889,7 +8877,7 @@ impl<'a> Lowerer<'a> {
                     ty: MirType::String,
                 })
             }
-            "Set" => {
+            "Set" | "Queue" => {
                 let elem_fn = if args.is_empty() {
                     self.resolve_to_string_callback(&Ty::int())
                 } else {
@@ -7898,7 +8886,7 @@ impl<'a> Lowerer<'a> {
                 let fn_ptr_expr = MirExpr::Var(elem_fn, fn_ptr_ty.clone());
                 Some(MirExpr::Call {
                     func: Box::new(MirExpr::Var(
-                        "mesh_set_to_string".to_string(),
+                        if base_name == "Set" { "mesh_set_to_string" } else { "mesh_queue_to_string" }.to_string(),
                         MirType::FnPtr(
                             vec![MirType::Ptr, MirType::Ptr],
                             Box::new(MirType::String),
@@ -7921,13 +8909,20 @@ impl<'a> Lowerer<'a> {
     fn resolve_to_string_callback(&mut self, elem_ty: &Ty) -> String {
         match elem_ty {
             Ty::Con(con) => match con.name.as_str() {
-                "Int" => "mesh_int_to_string".to_string(),
+                "Int" | "Int8" | "Int16" | "Int32" | "UInt8" | "UInt16" | "UInt32" => {
+                    "mesh_int_to_string".to_string()
+                }
+                "UInt64" => "mesh_uint64_to_string".to_string(),
+                "Bytes" => "mesh_bytes_to_string".to_string(),
+                "BigInt" => "mesh_bigint_to_string".to_string(),
+                "Decimal" => "mesh_decimal_to_string".to_string(),
                 "Float" => "mesh_float_to_string".to_string(),
                 "Bool" => "mesh_bool_to_string".to_string(),
                 "String" => "mesh_string_to_string".to_string(),
                 // Bare collection type without type args -- default to Int callback
                 "List" => self.generate_display_collection_wrapper("list", "mesh_list_to_string", &Ty::int(), None),
                 "Set" => self.generate_display_collection_wrapper("set", "mesh_set_to_string", &Ty::int(), None),
+                "Queue" => self.generate_display_collection_wrapper("queue", "mesh_queue_to_string", &Ty::int(), None),
                 "Map" => self.generate_display_map_wrapper(&Ty::int(), &Ty::int()),
                 name => {
                     // Check if this user type has a Display impl
@@ -7960,6 +8955,10 @@ impl<'a> Lowerer<'a> {
                             let inner_ty = args.first().cloned().unwrap_or_else(Ty::int);
                             self.generate_display_collection_wrapper("set", "mesh_set_to_string", &inner_ty, None)
                         }
+                        "Queue" => {
+                            let inner_ty = args.first().cloned().unwrap_or_else(Ty::int);
+                            self.generate_display_collection_wrapper("queue", "mesh_queue_to_string", &inner_ty, None)
+                        }
                         "Map" => {
                             let key_ty = args.first().cloned().unwrap_or_else(Ty::int);
                             let val_ty = args.get(1).cloned().unwrap_or_else(Ty::int);
@@ -8013,6 +9012,7 @@ impl<'a> Lowerer<'a> {
                     let base = match con.name.as_str() {
                         "List" => "list",
                         "Set" => "set",
+                        "Queue" => "queue",
                         "Map" => "map",
                         other => other,
                     };
@@ -8030,8 +9030,8 @@ impl<'a> Lowerer<'a> {
         }
     }
 
-    /// Generate a synthetic MIR wrapper function for displaying a List or Set
-    /// element that is itself a collection or complex type.
+    /// Generate a synthetic MIR wrapper function for displaying a List, Set or
+    /// Queue element that is itself a collection or complex type.
     ///
     /// The wrapper bridges the `fn(u64) -> *mut u8` callback signature expected
     /// by the runtime. It takes a single Ptr parameter and calls the appropriate
@@ -8040,8 +9040,8 @@ impl<'a> Lowerer<'a> {
     /// Returns the name of the wrapper function.
     fn generate_display_collection_wrapper(
         &mut self,
-        collection_kind: &str,   // "list" or "set"
-        runtime_fn: &str,        // "mesh_list_to_string" or "mesh_set_to_string"
+        collection_kind: &str,   // "list", "set" or "queue"
+        runtime_fn: &str,        // "mesh_list_to_string", "mesh_set_to_string", ...
         inner_ty: &Ty,
         _extra: Option<&str>,
     ) -> String {
@@ -8142,16 +9142,478 @@ impl<'a> Lowerer<'a> {
         };
 
         self.functions.push(MirFunction {
-            name: wrapper_name.clone(),
-            params: vec![(param_name, MirType::Ptr)],
-            return_type: MirType::Ptr,
-            body,
+            name: wrapper_name.clone(),
+            params: vec![(param_name, MirType::Ptr)],
+            return_type: MirType::Ptr,
+            body,
+            is_closure_fn: false,
+            captures: vec![],
+            has_tail_calls: false,
+        });
+
+        wrapper_name
+    }
+
+    // ── Post-call fixups ────────────────────────────────────────────
+
+    /// Build a call to the known function `callee`, applying the fixups a
+    /// runtime call needs from the type checker's types. Direct calls and
+    /// pipes both end here once their arguments are lowered.
+    ///
+    /// `range` is the call's syntax range, whose type is the call's result,
+    /// and `key_ty` the type of its second full argument (the key of a map
+    /// or set element operation), if known.
+    fn finish_call(
+        &mut self,
+        callee: MirExpr,
+        args: Vec<MirExpr>,
+        ty: MirType,
+        range: TextRange,
+        key_ty: Option<Ty>,
+    ) -> MirExpr {
+        let args = self.attach_set_elem_ops(&callee, args, range);
+        let (callee, args) = self.attach_group_by_map(callee, args, range);
+        let args = self.attach_map_key_ops(&callee, args, key_ty);
+        let callee = self.route_iodata_payload(callee, &args);
+        let call = self.finish_tuple_access(MirExpr::Call { func: Box::new(callee), args, ty });
+        self.tag_built_map(call, range)
+    }
+
+    // ── Typed tuple access ──────────────────────────────────────────
+
+    /// Give a `Tuple.first/second/nth` call the representation of the
+    /// element type the type checker found for it.
+    ///
+    /// The runtime returns every element as a u64, which codegen converts
+    /// by the call's type. Two element types need help: a nested tuple is
+    /// a heap pointer rather than an LLVM struct, and a struct or sum type
+    /// element is boxed, so it is bound with `let` to load the value.
+    fn finish_tuple_access(&mut self, call: MirExpr) -> MirExpr {
+        let MirExpr::Call { func, args, ty } = call else {
+            return call;
+        };
+        let is_access = matches!(
+            &*func,
+            MirExpr::Var(name, _) if matches!(name.as_str(), "mesh_tuple_first" | "mesh_tuple_second" | "mesh_tuple_nth")
+        );
+        if !is_access {
+            return MirExpr::Call { func, args, ty };
+        }
+        match ty {
+            MirType::Tuple(_) => MirExpr::Call { func, args, ty: MirType::Ptr },
+            MirType::Struct(_) | MirType::SumType(_) => {
+                self.tuple_counter += 1;
+                let name = format!("__tuple_elem_{}", self.tuple_counter);
+                MirExpr::Let {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    value: Box::new(MirExpr::Call { func, args, ty: ty.clone() }),
+                    body: Box::new(MirExpr::Var(name, ty)),
+                }
+            }
+            ty => MirExpr::Call { func, args, ty },
+        }
+    }
+
+    // ── Set<T>/Queue<T> element equality ────────────────────────────
+
+    /// Tag both set arguments of `Set.union/intersection/difference` with
+    /// the element type's key ops (see `tag_map_keys`), so the result hashes
+    /// and compares elements with `==` (string contents, Eq impls) even when
+    /// either set was built without them. Element operations on one set are
+    /// tagged by `attach_map_key_ops`.
+    ///
+    /// `range` is the call's syntax range, whose type is the `Set<T>` produced.
+    fn attach_set_elem_ops(&mut self, callee: &MirExpr, args: Vec<MirExpr>, range: TextRange) -> Vec<MirExpr> {
+        let is_set_op = matches!(
+            callee,
+            MirExpr::Var(name, _) if matches!(name.as_str(), "mesh_set_union" | "mesh_set_intersection" | "mesh_set_difference")
+        );
+        if !is_set_op {
+            return args;
+        }
+        let elem_ty = self
+            .get_ty(range)
+            .and_then(extract_set_elem_type)
+            .unwrap_or_else(Ty::int);
+        args.into_iter().map(|set| self.tag_map_keys(set, &elem_ty)).collect()
+    }
+
+    /// Build `lhs == rhs` for two `Set<T>` or `Queue<T>` values of type `ty`.
+    ///
+    /// Sets compare order-insensitively, tagged with the element type's key
+    /// ops; queues compare front to back with an explicit element callback,
+    /// like `mesh_list_eq`. Callers check the type with
+    /// `extract_set_elem_type` / `extract_queue_elem_type` first.
+    fn lower_collection_eq(&mut self, lhs: MirExpr, rhs: MirExpr, ty: &Ty) -> MirExpr {
+        let mut args = vec![lhs, rhs];
+        let eq_fn = match extract_queue_elem_type(ty) {
+            Some(elem_ty) => {
+                let eq_callback = self.resolve_eq_callback(&elem_ty);
+                args.push(MirExpr::Var(
+                    eq_callback,
+                    MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Bool)),
+                ));
+                "mesh_queue_eq"
+            }
+            None => {
+                let elem_ty = extract_set_elem_type(ty).unwrap_or_else(Ty::int);
+                args = args.into_iter().map(|set| self.tag_map_keys(set, &elem_ty)).collect();
+                "mesh_set_eq"
+            }
+        };
+        let fn_ty = MirType::FnPtr(args.iter().map(|a| a.ty().clone()).collect(), Box::new(MirType::Bool));
+        MirExpr::Call {
+            func: Box::new(MirExpr::Var(eq_fn.to_string(), fn_ty)),
+            args,
+            ty: MirType::Bool,
+        }
+    }
+
+    // ── IoData payloads ─────────────────────────────────────────────
+
+    /// Call the `_iodata` variant of an output function (`IO.write`,
+    /// `File.write/append`, `HTTP.response*`, `Ws.send`) when its payload is
+    /// an IoData rather than a String. The variant flattens the IoData only
+    /// as it writes. A String payload lowers to `MirType::String` and an
+    /// IoData to `MirType::Ptr`.
+    fn route_iodata_payload(&self, callee: MirExpr, args: &[MirExpr]) -> MirExpr {
+        let name = match &callee {
+            MirExpr::Var(name, _) => name.clone(),
+            _ => return callee,
+        };
+        let payload = match name.as_str() {
+            "mesh_io_write" => 0,
+            "mesh_file_write" | "mesh_file_append" | "mesh_http_response_new"
+            | "mesh_http_response_with_headers" | "mesh_ws_send" => 1,
+            _ => return callee,
+        };
+        if !matches!(args.get(payload).map(MirExpr::ty), Some(MirType::Ptr)) {
+            return callee;
+        }
+        let variant = format!("{}_iodata", name);
+        match self.known_functions.get(&variant) {
+            Some(fn_ty) => MirExpr::Var(variant, fn_ty.clone()),
+            None => callee,
+        }
+    }
+
+    // ── Map key hashing ─────────────────────────────────────────────
+
+    /// Make a `Map.put/get/has_key/delete` or `Set.add/remove/contains` call
+    /// hash and compare its key (or element) by the key's type. String keys
+    /// tag the map with `mesh_map_tag_string`; struct, sum type and tuple
+    /// keys tag it with `mesh_map_tag_hashed` and the type's hash and eq
+    /// callbacks. Int-like keys need no tag. A set is a map whose keys are
+    /// its elements, so it is tagged the same way.
+    ///
+    /// `key_ty` is the type checker's type of the key argument, if known.
+    fn attach_map_key_ops(
+        &mut self,
+        callee: &MirExpr,
+        mut args: Vec<MirExpr>,
+        key_ty: Option<Ty>,
+    ) -> Vec<MirExpr> {
+        let is_key_op = matches!(
+            callee,
+            MirExpr::Var(name, _) if matches!(
+                name.as_str(),
+                "mesh_map_put" | "mesh_map_get" | "mesh_map_has_key" | "mesh_map_delete"
+                    | "mesh_set_add" | "mesh_set_remove" | "mesh_set_contains"
+            )
+        );
+        if !is_key_op || args.len() < 2 {
+            return args;
+        }
+        let key_ty = key_ty
+            .filter(|ty| !matches!(resolve_type(ty, self.registry, false), MirType::Unit))
+            .unwrap_or_else(|| mir_type_to_ty(args[1].ty()));
+        let map = args.remove(0);
+        args.insert(0, self.tag_map_keys(map, &key_ty));
+        args
+    }
+
+    /// Tag the map built by `Map.from_list` or `Map.collect`, or the set
+    /// built by `Set.from_list` or `Set.collect` (the call at `range`), with
+    /// its key type's callbacks. The runtime builds these with raw keys;
+    /// tagging rebuilds them so equal keys merge. `Map.collect` into a
+    /// `Map<String, V>` calls the string-key variant instead.
+    fn tag_built_map(&mut self, call: MirExpr, range: TextRange) -> MirExpr {
+        let builder = match &call {
+            MirExpr::Call { func, .. } => match &**func {
+                MirExpr::Var(name, _) => name.as_str(),
+                _ => return call,
+            },
+            _ => return call,
+        };
+        match builder {
+            "mesh_map_collect" if self.get_ty(range).is_some_and(Self::ty_has_string_map_keys) => {
+                let MirExpr::Call { args, ty, .. } = call else {
+                    unreachable!()
+                };
+                let fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr));
+                MirExpr::Call {
+                    func: Box::new(MirExpr::Var("mesh_map_collect_string_keys".to_string(), fn_ty)),
+                    args,
+                    ty,
+                }
+            }
+            "mesh_map_from_list" | "mesh_map_collect" => match self.get_ty(range).and_then(extract_map_types) {
+                Some((key_ty, _)) if self.is_hashed_map_key(&key_ty) => self.tag_map_keys(call, &key_ty),
+                _ => call,
+            },
+            "mesh_set_from_list" | "mesh_set_collect" => match self.get_ty(range).and_then(extract_set_elem_type) {
+                Some(elem_ty) => self.tag_map_keys(call, &elem_ty),
+                None => call,
+            },
+            _ => call,
+        }
+    }
+
+    /// Give `Iter.group_by` the empty map it fills, tagged for the key type
+    /// of the `Map<K, List<T>>` it returns (the call at `range`), so that
+    /// equal string, struct and tuple keys land in the same group.
+    fn attach_group_by_map(
+        &mut self,
+        callee: MirExpr,
+        mut args: Vec<MirExpr>,
+        range: TextRange,
+    ) -> (MirExpr, Vec<MirExpr>) {
+        if !matches!(&callee, MirExpr::Var(name, _) if name == "mesh_iter_group_by") {
+            return (callee, args);
+        }
+        let key_ty = self
+            .get_ty(range)
+            .and_then(extract_map_types)
+            .map(|(key_ty, _)| key_ty)
+            .unwrap_or_else(Ty::int);
+        let groups = MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                "mesh_map_new".to_string(),
+                MirType::FnPtr(vec![], Box::new(MirType::Ptr)),
+            )),
+            args: vec![],
+            ty: MirType::Ptr,
+        };
+        args.push(self.tag_map_keys(groups, &key_ty));
+        let fn_ty = MirType::FnPtr(args.iter().map(|a| a.ty().clone()).collect(), Box::new(MirType::Ptr));
+        (MirExpr::Var("mesh_iter_group_by".to_string(), fn_ty), args)
+    }
+
+    /// Whether keys of this type are hashed through callbacks rather than
+    /// by their bits or string contents.
+    fn is_hashed_map_key(&self, key_ty: &Ty) -> bool {
+        matches!(
+            resolve_type(key_ty, self.registry, false),
+            MirType::Struct(_) | MirType::SumType(_) | MirType::Tuple(_)
+        )
+    }
+
+    /// Wrap `map` in the runtime call that makes it use `key_ty`'s key
+    /// semantics. Returns `map` unchanged for Int-like keys.
+    fn tag_map_keys(&mut self, map: MirExpr, key_ty: &Ty) -> MirExpr {
+        match resolve_type(key_ty, self.registry, false) {
+            MirType::String => MirExpr::Call {
+                func: Box::new(MirExpr::Var(
+                    "mesh_map_tag_string".to_string(),
+                    MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Ptr)),
+                )),
+                args: vec![map],
+                ty: MirType::Ptr,
+            },
+            MirType::Struct(_) | MirType::SumType(_) | MirType::Tuple(_) => {
+                let hash_callback = self.resolve_hash_callback(key_ty);
+                let eq_callback = self.resolve_eq_callback(key_ty);
+                MirExpr::Call {
+                    func: Box::new(MirExpr::Var(
+                        "mesh_map_tag_hashed".to_string(),
+                        MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr, MirType::Ptr], Box::new(MirType::Ptr)),
+                    )),
+                    args: vec![
+                        map,
+                        MirExpr::Var(hash_callback, MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Int))),
+                        MirExpr::Var(
+                            eq_callback,
+                            MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Bool)),
+                        ),
+                    ],
+                    ty: MirType::Ptr,
+                }
+            }
+            _ => map,
+        }
+    }
+
+    /// Resolve the hash callback for a map key type: a function with
+    /// signature `fn(u64) -> i64` taking the boxed key.
+    ///
+    /// Struct and sum type keys call their `Hash` impl. A tuple key hashes
+    /// each element and combines the results.
+    fn resolve_hash_callback(&mut self, key_ty: &Ty) -> String {
+        let mir_ty = resolve_type(key_ty, self.registry, false);
+        let name = match &mir_ty {
+            MirType::Tuple(elems) => format!("__hash_tuple_{}_callback", tuple_callback_suffix(elems)),
+            _ => format!("__hash_{}_callback", mir_type_to_impl_name(&mir_ty)),
+        };
+        if self.known_functions.contains_key(&name) {
+            return name;
+        }
+        let fn_ty = MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int));
+        self.known_functions.insert(name.clone(), fn_ty);
+
+        let key = MirExpr::Var("__k".to_string(), MirType::Ptr);
+        let body = match (&mir_ty, key_ty) {
+            (MirType::Tuple(elem_mir_tys), Ty::Tuple(elem_tys)) => {
+                let combine_ty = MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(MirType::Int));
+                let mut hash: Option<MirExpr> = None;
+                for (i, (elem_ty, elem_mir_ty)) in elem_tys.iter().zip(elem_mir_tys).enumerate() {
+                    let elem = self.tuple_elem(key.clone(), i, elem_mir_ty);
+                    let elem_hash = self.emit_key_hash(elem, elem_ty);
+                    hash = Some(match hash {
+                        None => elem_hash,
+                        Some(prev) => MirExpr::Call {
+                            func: Box::new(MirExpr::Var("mesh_hash_combine".to_string(), combine_ty.clone())),
+                            args: vec![prev, elem_hash],
+                            ty: MirType::Int,
+                        },
+                    });
+                }
+                hash.unwrap_or(MirExpr::IntLit(0, MirType::Int))
+            }
+            _ => self.emit_key_hash(key, key_ty),
+        };
+
+        self.functions.push(MirFunction {
+            name: name.clone(),
+            params: vec![("__k".to_string(), MirType::Ptr)],
+            return_type: MirType::Int,
+            body,
+            is_closure_fn: false,
+            captures: vec![],
+            has_tail_calls: false,
+        });
+        name
+    }
+
+    /// Hash a key (or key element) of type `ty`. Struct and sum type keys
+    /// call their `Hash` impl, which the type checker requires them to have.
+    fn emit_key_hash(&mut self, value: MirExpr, ty: &Ty) -> MirExpr {
+        let mir_ty = resolve_type(ty, self.registry, false);
+        if let Some(name) = ty.big_numeric_name() {
+            return big_numeric_call(name, "hash", vec![value], MirType::Int);
+        }
+        match &mir_ty {
+            MirType::Struct(_) | MirType::SumType(_) => {
+                let hash_fn = format!("Hash__hash__{}", mir_type_to_impl_name(&mir_ty));
+                MirExpr::Call {
+                    func: Box::new(MirExpr::Var(hash_fn, MirType::FnPtr(vec![mir_ty.clone()], Box::new(MirType::Int)))),
+                    args: vec![value],
+                    ty: MirType::Int,
+                }
+            }
+            MirType::Tuple(_) if matches!(ty, Ty::Tuple(_)) => {
+                let callback = self.resolve_hash_callback(ty);
+                MirExpr::Call {
+                    func: Box::new(MirExpr::Var(callback, MirType::FnPtr(vec![MirType::Ptr], Box::new(MirType::Int)))),
+                    args: vec![value],
+                    ty: MirType::Int,
+                }
+            }
+            _ => self.emit_hash_for_type(value, &mir_ty),
+        }
+    }
+
+    /// Element `index` of the tuple `tuple`, with the representation of
+    /// `elem_ty` (see `finish_tuple_access`).
+    fn tuple_elem(&mut self, tuple: MirExpr, index: usize, elem_ty: &MirType) -> MirExpr {
+        self.finish_tuple_access(MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                "mesh_tuple_nth".to_string(),
+                MirType::FnPtr(vec![MirType::Ptr, MirType::Int], Box::new(MirType::Int)),
+            )),
+            args: vec![tuple, MirExpr::IntLit(index as i64, MirType::Int)],
+            ty: elem_ty.clone(),
+        })
+    }
+
+    /// Generate `__eq_tuple_<Types>_callback(a: Ptr, b: Ptr) -> Bool`, which
+    /// compares two tuples element by element with each element type's `==`.
+    fn generate_tuple_eq_callback(&mut self, elem_tys: &[Ty]) -> String {
+        let elem_mir_tys: Vec<MirType> = elem_tys
+            .iter()
+            .map(|ty| resolve_type(ty, self.registry, false))
+            .collect();
+        let name = format!("__eq_tuple_{}_callback", tuple_callback_suffix(&elem_mir_tys));
+        if self.known_functions.contains_key(&name) {
+            return name;
+        }
+        let fn_ty = MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool));
+        self.known_functions.insert(name.clone(), fn_ty);
+
+        let a = MirExpr::Var("__a".to_string(), MirType::Ptr);
+        let b = MirExpr::Var("__b".to_string(), MirType::Ptr);
+        let mut body: Option<MirExpr> = None;
+        for (i, (elem_ty, elem_mir_ty)) in elem_tys.iter().zip(&elem_mir_tys).enumerate() {
+            let elem_eq = match elem_mir_ty {
+                MirType::Int | MirType::Float | MirType::Bool | MirType::String => MirExpr::BinOp {
+                    op: BinOp::Eq,
+                    lhs: Box::new(self.tuple_elem(a.clone(), i, elem_mir_ty)),
+                    rhs: Box::new(self.tuple_elem(b.clone(), i, elem_mir_ty)),
+                    ty: MirType::Bool,
+                },
+                MirType::Tuple(_) => {
+                    let callback = self.resolve_eq_callback(elem_ty);
+                    MirExpr::Call {
+                        func: Box::new(MirExpr::Var(
+                            callback,
+                            MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)),
+                        )),
+                        args: vec![self.tuple_elem(a.clone(), i, elem_mir_ty), self.tuple_elem(b.clone(), i, elem_mir_ty)],
+                        ty: MirType::Bool,
+                    }
+                }
+                _ => match self.generate_trait_eq_callback(elem_ty) {
+                    // Elements are stored boxed, as the eq callback expects.
+                    Some(callback) => MirExpr::Call {
+                        func: Box::new(MirExpr::Var(
+                            callback,
+                            MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool)),
+                        )),
+                        args: vec![
+                            self.tuple_elem(a.clone(), i, &MirType::Ptr),
+                            self.tuple_elem(b.clone(), i, &MirType::Ptr),
+                        ],
+                        ty: MirType::Bool,
+                    },
+                    None => MirExpr::BinOp {
+                        op: BinOp::Eq,
+                        lhs: Box::new(self.tuple_elem(a.clone(), i, &MirType::Int)),
+                        rhs: Box::new(self.tuple_elem(b.clone(), i, &MirType::Int)),
+                        ty: MirType::Bool,
+                    },
+                },
+            };
+            body = Some(match body {
+                None => elem_eq,
+                Some(prev) => MirExpr::BinOp {
+                    op: BinOp::And,
+                    lhs: Box::new(prev),
+                    rhs: Box::new(elem_eq),
+                    ty: MirType::Bool,
+                },
+            });
+        }
+
+        self.functions.push(MirFunction {
+            name: name.clone(),
+            params: vec![("__a".to_string(), MirType::Ptr), ("__b".to_string(), MirType::Ptr)],
+            return_type: MirType::Bool,
+            body: body.unwrap_or(MirExpr::BoolLit(true, MirType::Bool)),
             is_closure_fn: false,
             captures: vec![],
             has_tail_calls: false,
         });
-
-        wrapper_name
+        name
     }
 
     // ── List Eq/Ord callback resolution (Phase 27 Plan 01) ──────────
@@ -8167,10 +9629,12 @@ impl<'a> Lowerer<'a> {
                 "Float" => self.generate_float_eq_callback(),
                 "Bool" => self.generate_bool_eq_callback(),
                 "String" => self.generate_string_eq_callback(),
-                _ => {
+                "BigInt" => "mesh_bigint_eq".to_string(),
+                "Decimal" => "mesh_decimal_eq".to_string(),
+                _ => self
+                    .generate_trait_eq_callback(elem_ty)
                     // Fallback to int eq for unknown types
-                    self.generate_int_eq_callback()
-                }
+                    .unwrap_or_else(|| self.generate_int_eq_callback()),
             },
             Ty::App(con_ty, args) => {
                 if let Ty::Con(con) = con_ty.as_ref() {
@@ -8179,12 +9643,59 @@ impl<'a> Lowerer<'a> {
                         return self.generate_list_eq_wrapper(&inner_ty);
                     }
                 }
-                self.generate_int_eq_callback()
+                self.generate_trait_eq_callback(elem_ty)
+                    .unwrap_or_else(|| self.generate_int_eq_callback())
             }
+            Ty::Tuple(elems) => self.generate_tuple_eq_callback(elems),
             _ => self.generate_int_eq_callback(),
         }
     }
 
+    /// Generate `__eq_<Type>_callback(a: Ptr, b: Ptr) -> Bool { Eq__eq__<Type>(a, b) }`
+    /// for a struct or sum type with an Eq impl. Collections hold such values
+    /// boxed, so the callback takes pointers and codegen loads the values for
+    /// the by-value Eq call. Returns `None` if the type has no Eq impl.
+    fn generate_trait_eq_callback(&mut self, elem_ty: &Ty) -> Option<String> {
+        let mir_ty = resolve_type(elem_ty, self.registry, false);
+        if !matches!(mir_ty, MirType::Struct(_) | MirType::SumType(_)) {
+            return None;
+        }
+        let type_name = mir_type_to_impl_name(&mir_ty);
+        let eq_fn = format!("Eq__eq__{}", type_name);
+        if !self.trait_registry.has_impl("Eq", elem_ty) && !self.known_functions.contains_key(&eq_fn) {
+            return None;
+        }
+        let name = format!("__eq_{}_callback", type_name);
+        if self.known_functions.contains_key(&name) {
+            return Some(name);
+        }
+        let fn_ty = MirType::FnPtr(vec![MirType::Ptr, MirType::Ptr], Box::new(MirType::Bool));
+        self.known_functions.insert(name.clone(), fn_ty);
+
+        let body = MirExpr::Call {
+            func: Box::new(MirExpr::Var(
+                eq_fn,
+                MirType::FnPtr(vec![mir_ty.clone(), mir_ty], Box::new(MirType::Bool)),
+            )),
+            args: vec![
+                MirExpr::Var("__a".to_string(), MirType::Ptr),
+                MirExpr::Var("__b".to_string(), MirType::Ptr),
+            ],
+            ty: MirType::Bool,
+        };
+
+        self.functions.push(MirFunction {
+            name: name.clone(),
+            params: vec![("__a".to_string(), MirType::Ptr), ("__b".to_string(), MirType::Ptr)],
+            return_type: MirType::Bool,
+            body,
+            is_closure_fn: false,
+            captures: vec![],
+            has_tail_calls: false,
+        });
+        Some(name)
+    }
+
     /// Resolve the compare callback function name for an element type.
     ///
     /// Returns the name of a function with signature `fn(u64, u64) -> i64`
@@ -8194,6 +9705,8 @@ impl<'a> Lowerer<'a> {
             Ty::Con(con) => match con.name.as_str() {
                 "Int" => self.generate_int_cmp_callback(),
                 "String" => self.generate_string_cmp_callback(),
+                "BigInt" => "mesh_bigint_compare".to_string(),
+                "Decimal" => "mesh_decimal_compare".to_string(),
                 _ => self.generate_int_cmp_callback(),
             },
             Ty::App(con_ty, args) => {
@@ -8881,6 +10394,11 @@ impl<'a> Lowerer<'a> {
             args: vec![MirExpr::IntLit(key_type_tag, MirType::Int)],
             ty: MirType::Ptr,
         };
+        if let Some((key_ty, _)) = self.get_ty(map_lit.syntax().text_range()).and_then(extract_map_types) {
+            if self.is_hashed_map_key(&key_ty) {
+                result = self.tag_map_keys(result, &key_ty);
+            }
+        }
 
         let put_fn_ty = MirType::FnPtr(
             vec![MirType::Ptr, MirType::Int, MirType::Int],
@@ -9133,6 +10651,8 @@ impl<'a> Lowerer<'a> {
             let has_tail_calls = rewrite_tail_calls(&mut body, &name);
 
             // 1. Push the body function with original typed params.
+            let source_name = self.source_fn_name(&name, params.len());
+            self.record_fn_location(&body_fn_name, source_name, actor_range);
             self.functions.push(MirFunction {
                 name: body_fn_name.clone(),
                 params: params.clone(),
@@ -9176,6 +10696,8 @@ impl<'a> Lowerer<'a> {
             // TCE: Rewrite self-recursive tail calls to TailCall nodes (Phase 48).
             let has_tail_calls = rewrite_tail_calls(&mut body, &name);
 
+            let source_name = self.source_fn_name(&name, params.len());
+            self.record_fn_location(&name, source_name, actor_range);
             self.functions.push(MirFunction {
                 name,
                 params,
@@ -10193,22 +11715,17 @@ impl<'a> Lowerer<'a> {
         // Lower receive arms (reuse pattern matching infrastructure).
         let arms: Vec<MirMatchArm> = recv
             .arms()
-            .map(|arm| {
+            .flat_map(|arm| {
                 self.push_scope();
-                let pattern = arm
-                    .pattern()
-                    .map(|p| self.lower_pattern(&p))
-                    .unwrap_or(MirPattern::Wildcard);
+                let patterns = self.lower_arm_patterns(arm.pattern());
                 let body = arm
                     .body()
                     .map(|e| self.lower_expr(&e))
                     .unwrap_or(MirExpr::Unit);
                 self.pop_scope();
-                MirMatchArm {
-                    pattern,
-                    guard: None, // Receive arms don't have guards (they use when clauses which are separate)
-                    body,
-                }
+                // Receive arms don't have guards (they use when clauses which
+                // are separate); only pinned patterns add one.
+                pinned_arms(patterns, None, body)
             })
             .collect();
 
@@ -10250,6 +11767,125 @@ impl<'a> Lowerer<'a> {
 
 // ── Helper functions ─────────────────────────────────────────────────
 
+/// Wrap an Int-valued expression into the range of the fixed-width integer
+/// type `name` (a no-op for `UInt64`, which uses the whole word).
+fn wrap_fixed_int(name: &str, expr: MirExpr) -> MirExpr {
+    if name == "UInt64" {
+        return expr;
+    }
+    MirExpr::Call {
+        func: Box::new(MirExpr::Var(
+            format!("mesh_{}_from_int", name.to_lowercase()),
+            MirType::FnPtr(vec![MirType::Int], Box::new(MirType::Int)),
+        )),
+        args: vec![expr],
+        ty: MirType::Int,
+    }
+}
+
+/// Lower a binary operator whose operands are the fixed-width integer type
+/// `name`. Arithmetic results are wrapped back into range; `UInt64` also
+/// needs unsigned division, remainder and ordering. Everything else works
+/// on the normalized i64 unchanged.
+fn lower_fixed_int_binop(name: &str, op: BinOp, lhs: MirExpr, rhs: MirExpr, ty: MirType) -> MirExpr {
+    let uint64_call = |func: &str, args: Vec<MirExpr>, ret: MirType| MirExpr::Call {
+        func: Box::new(MirExpr::Var(
+            func.to_string(),
+            MirType::FnPtr(vec![MirType::Int, MirType::Int], Box::new(ret.clone())),
+        )),
+        args,
+        ty: ret,
+    };
+    let unsigned_64 = name == "UInt64";
+    match op {
+        BinOp::Div | BinOp::Mod if unsigned_64 => {
+            let func = if op == BinOp::Div { "mesh_uint64_div" } else { "mesh_uint64_rem" };
+            uint64_call(func, vec![lhs, rhs], MirType::Int)
+        }
+        BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod => {
+            let raw = MirExpr::BinOp {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                ty: MirType::Int,
+            };
+            wrap_fixed_int(name, raw)
+        }
+        BinOp::Lt | BinOp::Gt | BinOp::LtEq | BinOp::GtEq if unsigned_64 => {
+            // a > b is b < a; a <= b is !(b < a); a >= b is !(a < b).
+            let (a, b, negate) = match op {
+                BinOp::Lt => (lhs, rhs, false),
+                BinOp::Gt => (rhs, lhs, false),
+                BinOp::LtEq => (rhs, lhs, true),
+                _ => (lhs, rhs, true),
+            };
+            let lt = uint64_call("mesh_uint64_lt", vec![a, b], MirType::Bool);
+            if negate {
+                MirExpr::BinOp {
+                    op: BinOp::Eq,
+                    lhs: Box::new(lt),
+                    rhs: Box::new(MirExpr::BoolLit(false, MirType::Bool)),
+                    ty,
+                }
+            } else {
+                lt
+            }
+        }
+        _ => MirExpr::BinOp {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            ty,
+        },
+    }
+}
+
+/// Call `mesh_<type>_<func>` for the `BigInt` or `Decimal` type `name`. Every
+/// argument is a pointer to a value of that type.
+fn big_numeric_call(name: &str, func: &str, args: Vec<MirExpr>, ret: MirType) -> MirExpr {
+    MirExpr::Call {
+        func: Box::new(MirExpr::Var(
+            format!("mesh_{}_{}", name.to_lowercase(), func),
+            MirType::FnPtr(vec![MirType::Ptr; args.len()], Box::new(ret.clone())),
+        )),
+        args,
+        ty: ret,
+    }
+}
+
+/// Lower a binary operator whose operands are `BigInt` or `Decimal` (`name`)
+/// to a call into that type's runtime module. Orderings compare the result
+/// of `mesh_<type>_compare` against zero.
+fn lower_big_numeric_binop(name: &str, op: BinOp, lhs: MirExpr, rhs: MirExpr, ty: MirType) -> MirExpr {
+    let call = |func: &str, ret: MirType, lhs: MirExpr, rhs: MirExpr| big_numeric_call(name, func, vec![lhs, rhs], ret);
+    match op {
+        BinOp::Add => call("add", MirType::Ptr, lhs, rhs),
+        BinOp::Sub => call("sub", MirType::Ptr, lhs, rhs),
+        BinOp::Mul => call("mul", MirType::Ptr, lhs, rhs),
+        BinOp::Div => call("div", MirType::Ptr, lhs, rhs),
+        BinOp::Mod => call("mod", MirType::Ptr, lhs, rhs),
+        BinOp::Eq => call("eq", MirType::Bool, lhs, rhs),
+        BinOp::NotEq => MirExpr::BinOp {
+            op: BinOp::Eq,
+            lhs: Box::new(call("eq", MirType::Bool, lhs, rhs)),
+            rhs: Box::new(MirExpr::BoolLit(false, MirType::Bool)),
+            ty,
+        },
+        BinOp::Lt | BinOp::Gt | BinOp::LtEq | BinOp::GtEq => MirExpr::BinOp {
+            op,
+            lhs: Box::new(call("compare", MirType::Int, lhs, rhs)),
+            rhs: Box::new(MirExpr::IntLit(0, MirType::Int)),
+            ty,
+        },
+        _ => MirExpr::BinOp {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            ty,
+        },
+    }
+}
+
 /// Set of known stdlib module names for qualified access lowering.
 const STDLIB_MODULES: &[&str] = &[
     "String", "IO", "Env", "File", "List", "Map", "Set", "Tuple", "Range", "Queue", "HTTP", "JSON", "Json", "Request", "Job",
@@ -10262,6 +11898,9 @@ const STDLIB_MODULES: &[&str] = &[
     "Repo",  // Phase 98
     "Changeset",  // Phase 99
     "Migration",  // Phase 101
+    "Regex",
+    "Int8", "Int16", "Int32", "UInt8", "UInt16", "UInt32", "UInt64", "Bytes",
+    "BigInt", "Decimal", "IoData",
 ];
 
 /// Map Mesh builtin function names to their runtime equivalents.
@@ -10296,6 +11935,7 @@ fn map_builtin_name(name: &str) -> String {
         // IO functions
         "io_read_line" => "mesh_io_read_line".to_string(),
         "io_eprintln" => "mesh_io_eprintln".to_string(),
+        "io_write" => "mesh_io_write".to_string(),
         // Env functions
         "env_get" => "mesh_env_get".to_string(),
         "env_args" => "mesh_env_args".to_string(),
@@ -10326,6 +11966,7 @@ fn map_builtin_name(name: &str) -> String {
         "list_new" => "mesh_list_new".to_string(),
         "list_length" => "mesh_list_length".to_string(),
         "list_append" => "mesh_list_append".to_string(),
+        "list_prepend" => "mesh_list_prepend".to_string(),
         "list_head" => "mesh_list_head".to_string(),
         "list_tail" => "mesh_list_tail".to_string(),
         "list_get" => "mesh_list_get".to_string(),
@@ -10379,6 +12020,7 @@ fn map_builtin_name(name: &str) -> String {
         "tuple_first" => "mesh_tuple_first".to_string(),
         "tuple_second" => "mesh_tuple_second".to_string(),
         "tuple_size" => "mesh_tuple_size".to_string(),
+        "tuple_to_list" => "mesh_tuple_to_list".to_string(),
         // Range operations
         "range_new" => "mesh_range_new".to_string(),
         "range_to_list" => "mesh_range_to_list".to_string(),
@@ -10392,6 +12034,8 @@ fn map_builtin_name(name: &str) -> String {
         "queue_peek" => "mesh_queue_peek".to_string(),
         "queue_size" => "mesh_queue_size".to_string(),
         "queue_is_empty" => "mesh_queue_is_empty".to_string(),
+        "queue_to_list" => "mesh_queue_to_list".to_string(),
+        "queue_from_list" => "mesh_queue_from_list".to_string(),
         // Bare names for prelude functions (map, filter, reduce, head, tail)
         // These are ambiguous -- default to list operations.
         "map" => "mesh_list_map".to_string(),
@@ -10541,6 +12185,14 @@ fn map_builtin_name(name: &str) -> String {
         "changeset_changes" => "mesh_changeset_changes".to_string(),
         "changeset_get_change" => "mesh_changeset_get_change".to_string(),
         "changeset_get_error" => "mesh_changeset_get_error".to_string(),
+        // ── Regex ───────────────────────────────────────────────────────
+        "regex_compile" => "mesh_regex_compile".to_string(),
+        "regex_is_match" => "mesh_regex_is_match".to_string(),
+        "regex_captures" => "mesh_regex_captures".to_string(),
+        "regex_named_captures" => "mesh_regex_named_captures".to_string(),
+        "regex_scan" => "mesh_regex_scan".to_string(),
+        "regex_replace" => "mesh_regex_replace".to_string(),
+        "regex_split" => "mesh_regex_split".to_string(),
         // ── Phase 101: Migration DDL Operations ─────────────────────────
         "migration_create_table" => "mesh_migration_create_table".to_string(),
         "migration_drop_table" => "mesh_migration_drop_table".to_string(),
@@ -10571,6 +12223,84 @@ fn map_builtin_name(name: &str) -> String {
         "math_round" => "mesh_math_round".to_string(),
         "int_to_float" => "mesh_int_to_float".to_string(),
         "float_to_int" => "mesh_float_to_int".to_string(),
+        "int_wrapping_add" => "mesh_int_wrapping_add".to_string(),
+        "int_wrapping_sub" => "mesh_int_wrapping_sub".to_string(),
+        "int_wrapping_mul" => "mesh_int_wrapping_mul".to_string(),
+        "int_checked_add" => "mesh_int_checked_add".to_string(),
+        "int_checked_sub" => "mesh_int_checked_sub".to_string(),
+        "int_checked_mul" => "mesh_int_checked_mul".to_string(),
+        "int_checked_div" => "mesh_int_checked_div".to_string(),
+        "int_saturating_add" => "mesh_int_saturating_add".to_string(),
+        "int_saturating_sub" => "mesh_int_saturating_sub".to_string(),
+        "int_saturating_mul" => "mesh_int_saturating_mul".to_string(),
+        // ── Fixed-width integers ─────────────────────────────────────────
+        "int8_from_int" => "mesh_int8_from_int".to_string(),
+        "int16_from_int" => "mesh_int16_from_int".to_string(),
+        "int32_from_int" => "mesh_int32_from_int".to_string(),
+        "uint8_from_int" => "mesh_uint8_from_int".to_string(),
+        "uint16_from_int" => "mesh_uint16_from_int".to_string(),
+        "uint32_from_int" => "mesh_uint32_from_int".to_string(),
+        "uint64_from_int" => "mesh_uint64_from_int".to_string(),
+        "int8_try_from_int" => "mesh_int8_try_from_int".to_string(),
+        "int16_try_from_int" => "mesh_int16_try_from_int".to_string(),
+        "int32_try_from_int" => "mesh_int32_try_from_int".to_string(),
+        "uint8_try_from_int" => "mesh_uint8_try_from_int".to_string(),
+        "uint16_try_from_int" => "mesh_uint16_try_from_int".to_string(),
+        "uint32_try_from_int" => "mesh_uint32_try_from_int".to_string(),
+        "uint64_try_from_int" => "mesh_uint64_try_from_int".to_string(),
+        "int8_to_int" | "int16_to_int" | "int32_to_int" | "uint8_to_int" | "uint16_to_int"
+        | "uint32_to_int" | "uint64_to_int" => "mesh_fixed_int_to_int".to_string(),
+        "int8_to_string" | "int16_to_string" | "int32_to_string" | "uint8_to_string"
+        | "uint16_to_string" | "uint32_to_string" => "mesh_int_to_string".to_string(),
+        "uint64_to_string" => "mesh_uint64_to_string".to_string(),
+        // ── BigInt and Decimal ───────────────────────────────────────────
+        "bigint_from_int" => "mesh_bigint_from_int".to_string(),
+        "bigint_parse" => "mesh_bigint_parse".to_string(),
+        "bigint_to_int" => "mesh_bigint_to_int".to_string(),
+        "bigint_to_string" => "mesh_bigint_to_string".to_string(),
+        "bigint_abs" => "mesh_bigint_abs".to_string(),
+        "bigint_pow" => "mesh_bigint_pow".to_string(),
+        "decimal_from_int" => "mesh_decimal_from_int".to_string(),
+        "decimal_parse" => "mesh_decimal_parse".to_string(),
+        "decimal_to_float" => "mesh_decimal_to_float".to_string(),
+        "decimal_to_string" => "mesh_decimal_to_string".to_string(),
+        "decimal_scale" => "mesh_decimal_scale".to_string(),
+        "decimal_round" => "mesh_decimal_round".to_string(),
+        // ── Bytes ────────────────────────────────────────────────────────
+        "bytes_new" => "mesh_bytes_new".to_string(),
+        "bytes_from_list" => "mesh_bytes_from_list".to_string(),
+        "bytes_to_list" => "mesh_bytes_to_list".to_string(),
+        "bytes_length" => "mesh_bytes_length".to_string(),
+        "bytes_get" => "mesh_bytes_get".to_string(),
+        "bytes_slice" => "mesh_bytes_slice".to_string(),
+        "bytes_concat" => "mesh_bytes_concat".to_string(),
+        "bytes_to_string" => "mesh_bytes_to_string".to_string(),
+        "string_to_bytes" => "mesh_string_to_bytes".to_string(),
+        // ── IoData ───────────────────────────────────────────────────────
+        "iodata_new" => "mesh_iodata_new".to_string(),
+        "iodata_from_string" => "mesh_iodata_from_string".to_string(),
+        "iodata_from_bytes" => "mesh_iodata_from_bytes".to_string(),
+        "iodata_from_list" => "mesh_iodata_from_list".to_string(),
+        "iodata_append" => "mesh_iodata_append".to_string(),
+        "iodata_append_bytes" => "mesh_iodata_append_bytes".to_string(),
+        "iodata_concat" => "mesh_iodata_concat".to_string(),
+        "iodata_byte_size" => "mesh_iodata_byte_size".to_string(),
+        "iodata_to_string" => "mesh_iodata_to_string".to_string(),
+        "iodata_to_bytes" => "mesh_iodata_to_bytes".to_string(),
+        "string_from_utf8" => "mesh_string_from_utf8".to_string(),
+        "string_byte_size" => "mesh_string_byte_size".to_string(),
+        "string_graphemes" => "mesh_string_graphemes".to_string(),
+        "string_chars" => "mesh_string_chars".to_string(),
+        "string_codepoints" => "mesh_string_codepoints".to_string(),
+        "string_at" => "mesh_string_at".to_string(),
+        "string_index_of" => "mesh_string_index_of".to_string(),
+        "string_pad_left" => "mesh_string_pad_left".to_string(),
+        "string_pad_right" => "mesh_string_pad_right".to_string(),
+        "string_reverse" => "mesh_string_reverse".to_string(),
+        "string_fold_case" => "mesh_string_fold_case".to_string(),
+        "string_normalize_nfc" => "mesh_string_normalize_nfc".to_string(),
+        "string_normalize_nfd" => "mesh_string_normalize_nfd".to_string(),
+        "string_format" => "mesh_string_format".to_string(),
         // ── Phase 77: From conversion dispatch ──────────────────────────
         "float_from" => "mesh_int_to_float".to_string(),
         "string_from" => "mesh_string_from".to_string(),
@@ -10620,6 +12350,26 @@ fn map_builtin_name(name: &str) -> String {
         "iter_all" => "mesh_iter_all".to_string(),
         "iter_find" => "mesh_iter_find".to_string(),
         "iter_reduce" => "mesh_iter_reduce".to_string(),
+        "iter_take_while" => "mesh_iter_take_while".to_string(),
+        "iter_skip_while" => "mesh_iter_skip_while".to_string(),
+        "iter_flat_map" => "mesh_iter_flat_map".to_string(),
+        "iter_chain" => "mesh_iter_chain".to_string(),
+        "iter_chunks" => "mesh_iter_chunks".to_string(),
+        "iter_windows" => "mesh_iter_windows".to_string(),
+        "iter_step_by" => "mesh_iter_step_by".to_string(),
+        "iter_dedup" => "mesh_iter_dedup".to_string(),
+        "iter_dedup_by" => "mesh_iter_dedup_by".to_string(),
+        "iter_scan" => "mesh_iter_scan".to_string(),
+        "iter_inspect" => "mesh_iter_inspect".to_string(),
+        "iter_min_by" => "mesh_iter_min_by".to_string(),
+        "iter_max_by" => "mesh_iter_max_by".to_string(),
+        "iter_group_by" => "mesh_iter_group_by".to_string(),
+        "iter_partition" => "mesh_iter_partition".to_string(),
+        "iter_repeat" => "mesh_iter_repeat".to_string(),
+        "iter_unfold" => "mesh_iter_unfold".to_string(),
+        "iter_iterate" => "mesh_iter_iterate".to_string(),
+        "iter_chars" => "mesh_iter_chars".to_string(),
+        "iter_graphemes" => "mesh_iter_graphemes".to_string(),
         // ── Phase 79: Collect terminal operations ────────────────────────
         "list_collect" => "mesh_list_collect".to_string(),
         "map_collect" => "mesh_map_collect".to_string(),
@@ -10713,6 +12463,34 @@ fn find_type_for_variant(variant: &str, registry: &mesh_typeck::TypeRegistry) ->
     None
 }
 
+/// Build the match arms for the patterns of one source arm (see
+/// `lower_arm_patterns`), each guarded by its pin tests and then `guard`.
+fn pinned_arms(
+    patterns: Vec<(MirPattern, Vec<MirExpr>)>,
+    guard: Option<MirExpr>,
+    body: MirExpr,
+) -> Vec<MirMatchArm> {
+    patterns
+        .into_iter()
+        .map(|(pattern, pin_tests)| MirMatchArm {
+            pattern,
+            guard: guard_with_pins(pin_tests, guard.clone()),
+            body: body.clone(),
+        })
+        .collect()
+}
+
+/// Conjoin the tests of an arm's pinned patterns with its guard. The pin
+/// tests come first so the guard only runs for matching values.
+fn guard_with_pins(pin_tests: Vec<MirExpr>, guard: Option<MirExpr>) -> Option<MirExpr> {
+    pin_tests.into_iter().chain(guard).reduce(|lhs, rhs| MirExpr::BinOp {
+        op: BinOp::And,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        ty: MirType::Bool,
+    })
+}
+
 /// Collect bindings introduced by a list of patterns (for constructor pattern bindings).
 fn collect_pattern_bindings(patterns: &[MirPattern]) -> Vec<(String, MirType)> {
     let mut bindings = Vec::new();
@@ -10747,6 +12525,16 @@ fn collect_bindings_recursive(pat: &MirPattern, bindings: &mut Vec<(String, MirT
             collect_bindings_recursive(head, bindings);
             collect_bindings_recursive(tail, bindings);
         }
+        MirPattern::Binary { fields, .. } => {
+            for f in fields {
+                collect_bindings_recursive(f, bindings);
+            }
+        }
+        MirPattern::Map { entries, .. } => {
+            for (_, value) in entries {
+                collect_bindings_recursive(value, bindings);
+            }
+        }
         MirPattern::Wildcard | MirPattern::Literal(_) => {}
     }
 }
@@ -10771,7 +12559,7 @@ fn collect_free_vars(
                 }
             }
         }
-        MirExpr::BinOp { lhs, rhs, .. } => {
+        MirExpr::BinOp { lhs, rhs, .. } | MirExpr::CheckedIntOp { lhs, rhs, .. } => {
             collect_free_vars(lhs, params, outer_vars, captures);
             collect_free_vars(rhs, params, outer_vars, captures);
         }
@@ -10846,7 +12634,8 @@ fn collect_free_vars(
         | MirExpr::BoolLit(_, _)
         | MirExpr::StringLit(_, _)
         | MirExpr::Panic { .. }
-        | MirExpr::Unit => {}
+        | MirExpr::Unit
+        | MirExpr::SourceLine(_) => {}
         // Actor primitives
         MirExpr::ActorSpawn { func, args, terminate_callback, .. } => {
             collect_free_vars(func, params, outer_vars, captures);
@@ -11030,13 +12819,27 @@ fn rewrite_tail_calls(expr: &mut MirExpr, current_fn_name: &str) -> bool {
 /// typed AST, desugars pipe operators and string interpolation, lifts closures,
 /// and produces a flat MIR module.
 pub fn lower_to_mir(parse: &Parse, typeck: &TypeckResult, module_name: &str, pub_fns: &HashSet<String>) -> Result<MirModule, String> {
+    lower_to_mir_with_source(parse, typeck, module_name, pub_fns, "", false)
+}
+
+/// Lower a module to MIR, recording `source_path` as the file that assertion
+/// failures report. An empty path is reported as `<source>`. With `debug`,
+/// statements are preceded by `SourceLine` markers for debug info.
+pub fn lower_to_mir_with_source(
+    parse: &Parse,
+    typeck: &TypeckResult,
+    module_name: &str,
+    pub_fns: &HashSet<String>,
+    source_path: &str,
+    debug: bool,
+) -> Result<MirModule, String> {
     let tree = parse.syntax();
     let source_file = match SourceFile::cast(tree.clone()) {
         Some(sf) => sf,
         None => return Err("Failed to cast root node to SourceFile".to_string()),
     };
 
-    let mut lowerer = Lowerer::new(typeck, parse, module_name, pub_fns);
+    let mut lowerer = Lowerer::new(typeck, parse, module_name, pub_fns, source_path, debug);
 
     // Also register builtin sum types from the registry (Option, Result).
     // Generic type params (T, E) are resolved to Ptr since all Mesh values
@@ -11277,6 +13080,8 @@ pub fn lower_to_mir(parse: &Parse, typeck: &TypeckResult, module_name: &str, pub
         sum_types: lowerer.sum_types,
         entry_function: lowerer.entry_function,
         service_dispatch,
+        tests: lowerer.tests,
+        fn_locations: lowerer.fn_locations,
     })
 }
 
@@ -11314,8 +13119,8 @@ mod tests {
         assert_eq!(func.params[1].1, MirType::Int);
         assert_eq!(func.return_type, MirType::Int);
 
-        // Body should be a BinOp
-        assert!(matches!(func.body, MirExpr::BinOp { op: BinOp::Add, .. }));
+        // Body should be an overflow-checked Int addition
+        assert!(matches!(func.body, MirExpr::CheckedIntOp { op: BinOp::Add, .. }));
     }
 
     #[test]
@@ -11340,6 +13145,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lower_numbered_pipe_binds_value_first() {
+        // `x |2> f(a)` should desugar to `{ let __pipe_1 = x in f(a, __pipe_1) }`
+        let mir = lower(
+            "fn sub(a :: Int, b :: Int) -> Int do a - b end\n\
+             fn main() do 5 |2> sub(9) end",
+        );
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+
+        let MirExpr::Block(exprs, ty) = &main.body else {
+            panic!("Expected Block, got {:?}", main.body);
+        };
+        assert_eq!(*ty, MirType::Int);
+        match exprs.as_slice() {
+            [MirExpr::Let { name, value, body, .. }] => {
+                assert_eq!(name, "__pipe_1");
+                assert!(matches!(value.as_ref(), MirExpr::IntLit(5, _)));
+                match body.as_ref() {
+                    MirExpr::Call { args, .. } => {
+                        assert!(matches!(&args[0], MirExpr::IntLit(9, _)));
+                        assert!(matches!(&args[1], MirExpr::Var(n, _) if n == "__pipe_1"));
+                    }
+                    other => panic!("Expected Call, got {:?}", other),
+                }
+            }
+            other => panic!("Expected a single Let, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lower_regex_literal_calls_runtime() {
+        // `~r/a\/b/i` becomes mesh_regex_literal("a/b", "i") returning an Int handle.
+        let mir = lower("fn main() do Regex.is_match(~r/a\\/b/i, \"A/B\") end");
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        let MirExpr::Call { func, args, .. } = &main.body else {
+            panic!("Expected Call, got {:?}", main.body);
+        };
+        assert!(matches!(func.as_ref(), MirExpr::Var(n, _) if n == "mesh_regex_is_match"));
+        match &args[0] {
+            MirExpr::Call { func, args, ty } => {
+                assert!(matches!(func.as_ref(), MirExpr::Var(n, _) if n == "mesh_regex_literal"));
+                assert!(matches!(&args[0], MirExpr::StringLit(p, _) if p == "a/b"));
+                assert!(matches!(&args[1], MirExpr::StringLit(f, _) if f == "i"));
+                assert_eq!(*ty, MirType::Int);
+            }
+            other => panic!("Expected Call, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lower_iodata_payload_calls_iodata_variant() {
+        // A String body keeps the String entry point; an IoData body, passed
+        // directly or piped, calls the `_iodata` variant.
+        let mir = lower(
+            "fn main() do\n\
+             let body = IoData.append(IoData.new(), \"row\\n\")\n\
+             File.write(\"a.csv\", \"plain\")\n\
+             File.write(\"a.csv\", body)\n\
+             body |> IO.write()\n\
+             end",
+        );
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        let mut called = Vec::new();
+        fn collect(expr: &MirExpr, out: &mut Vec<String>) {
+            match expr {
+                MirExpr::Call { func, args, .. } => {
+                    if let MirExpr::Var(name, _) = func.as_ref() {
+                        out.push(name.clone());
+                    }
+                    args.iter().for_each(|a| collect(a, out));
+                }
+                MirExpr::Let { value, body, .. } => {
+                    collect(value, out);
+                    collect(body, out);
+                }
+                MirExpr::Block(exprs, _) => exprs.iter().for_each(|e| collect(e, out)),
+                _ => {}
+            }
+        }
+        collect(&main.body, &mut called);
+        assert!(called.contains(&"mesh_file_write".to_string()), "{:?}", called);
+        assert!(called.contains(&"mesh_file_write_iodata".to_string()), "{:?}", called);
+        assert!(called.contains(&"mesh_io_write_iodata".to_string()), "{:?}", called);
+        assert!(!called.contains(&"mesh_io_write".to_string()), "{:?}", called);
+    }
+
+    #[test]
+    fn lower_bytes_pattern_and_construction() {
+        // A `bytes` size naming an earlier segment refers to it by index in
+        // the spec; construction appends each segment to an empty buffer.
+        let source = "fn main() do\n  let b = <<2::u16-little, \"hi\"::bytes>>\n  case b do\n    <<len::u16, body::bytes(len), _::rest>> -> body\n    <<\"GET\"::bytes, n::i8>> -> b\n    _ -> b\n  end\nend";
+        let mir = lower(source);
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        let debug = format!("{:?}", main.body);
+        assert!(debug.contains("spec: \"u16,bytes:@0,rest\""), "body: {}", debug);
+        assert!(debug.contains("spec: \"bytes:3,i8\""), "body: {}", debug);
+        assert!(debug.contains("Literal(String(\"GET\"))"), "body: {}", debug);
+        assert!(debug.contains("\"mesh_bytes_new\""), "body: {}", debug);
+        assert!(debug.contains("StringLit(\"u16le\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_bytes_put_bytes\""), "body: {}", debug);
+        assert!(debug.contains("IntLit(-1"), "body: {}", debug);
+    }
+
+    #[test]
+    fn lower_big_numeric_operators_call_runtime() {
+        // BigInt and Decimal operators become runtime calls; orderings
+        // compare the result of `compare` against zero.
+        let source = "struct Price do\n  amount :: Decimal\nend deriving(Eq, Ord, Hash)\n\nfn main() do\n  let a = BigInt.from_int(2)\n  let b = a * a + BigInt.from_int(1)\n  let d = Decimal.from_int(3)\n  let p = Price { amount: -d }\n  if b > a do \"${b}\" else \"${p.amount}\" end\nend";
+        let mir = lower(source);
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        let debug = format!("{:?}", main.body);
+        assert!(debug.contains("\"mesh_bigint_mul\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_bigint_add\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_bigint_compare\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_decimal_neg\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_bigint_to_string\""), "body: {}", debug);
+        assert!(debug.contains("\"mesh_decimal_to_string\""), "body: {}", debug);
+
+        let eq = mir.functions.iter().find(|f| f.name == "Eq__eq__Price").unwrap();
+        assert!(format!("{:?}", eq.body).contains("\"mesh_decimal_eq\""));
+        let lt = mir.functions.iter().find(|f| f.name == "Ord__lt__Price").unwrap();
+        assert!(format!("{:?}", lt.body).contains("\"mesh_decimal_compare\""));
+        let hash = mir.functions.iter().find(|f| f.name == "Hash__hash__Price").unwrap();
+        assert!(format!("{:?}", hash.body).contains("\"mesh_decimal_hash\""));
+    }
+
     #[test]
     fn lower_string_interpolation_desugars_to_concat() {
         let source = r#"
@@ -11409,6 +13340,135 @@ end
         assert_eq!(mir.entry_function, Some("mesh_main".to_string()));
     }
 
+    #[test]
+    fn lower_test_block_registers_test() {
+        let mir = lower("test \"first\" do\n  assert(true)\nend\ntest \"second\" do\n  1\nend");
+        let names: Vec<&str> = mir.tests.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["first", "second"]);
+        assert_eq!(mir.tests[0].fn_name, "__test_0");
+
+        let func = mir.functions.iter().find(|f| f.name == "__test_1").unwrap();
+        assert!(func.params.is_empty());
+        assert_eq!(func.return_type, MirType::Unit);
+        assert_eq!(mir.entry_function, None);
+    }
+
+    #[test]
+    fn lower_assert_reports_source_line() {
+        let parse = mesh_parser::parse("test \"t\" do\n  let x = 1\n  assert(x == 2)\nend");
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "math.mpl", true)
+            .expect("MIR lowering failed");
+        let func = mir.functions.iter().find(|f| f.name == "__test_0").unwrap();
+
+        let debug = format!("{:?}", func.body);
+        assert!(debug.contains("mesh_assert_fail"), "body: {}", debug);
+        assert!(debug.contains("StringLit(\"math.mpl\""), "body: {}", debug);
+        assert!(debug.contains("IntLit(3, Int)"), "body: {}", debug);
+    }
+
+    #[test]
+    fn lower_int_arithmetic_is_overflow_checked() {
+        // Int `+`, `-`, `*`, `/` and `%` carry their location for the overflow
+        // panic; Float arithmetic stays a plain BinOp.
+        let parse = mesh_parser::parse(
+            "fn f(a :: Int, b :: Int) -> Int do\n  let c = a / b\n  a * c % b\nend\n\nfn g(x :: Float) -> Float do\n  x + 1.0\nend",
+        );
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "math.mpl", true)
+            .expect("MIR lowering failed");
+
+        let f = mir.functions.iter().find(|f| f.name == "f").unwrap();
+        let debug = format!("{:?}", f.body);
+        assert!(debug.contains("CheckedIntOp { op: Mul"), "body: {}", debug);
+        assert!(debug.contains("file: \"math.mpl\", line: 3"), "body: {}", debug);
+        assert!(debug.contains("CheckedIntOp { op: Div"), "body: {}", debug);
+        assert!(debug.contains("file: \"math.mpl\", line: 2"), "body: {}", debug);
+        assert!(debug.contains("CheckedIntOp { op: Mod"), "body: {}", debug);
+        assert!(!debug.contains("BinOp { op: Div"), "body: {}", debug);
+
+        let g = mir.functions.iter().find(|f| f.name == "g").unwrap();
+        assert!(matches!(g.body, MirExpr::BinOp { op: BinOp::Add, .. }), "body: {:?}", g.body);
+    }
+
+    #[test]
+    fn lower_int_negation_is_overflow_checked() {
+        // `-x` overflows for Int.min and is checked as `0 - x`; negative
+        // literals and Float negation stay plain UnaryOps.
+        let parse = mesh_parser::parse(
+            "fn f(a :: Int) -> Int do\n  -a\nend\n\nfn g() -> Int do\n  -5\nend\n\nfn h(x :: Float) -> Float do\n  -x\nend",
+        );
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "math.mpl", true)
+            .expect("MIR lowering failed");
+
+        let body = |name: &str| mir.functions.iter().find(|f| f.name == name).unwrap().body.clone();
+        match body("f") {
+            MirExpr::CheckedIntOp { op: BinOp::Sub, lhs, line, .. } => {
+                assert!(matches!(*lhs, MirExpr::IntLit(0, _)));
+                assert_eq!(line, 2);
+            }
+            other => panic!("expected a checked negation, got {:?}", other),
+        }
+        assert!(!format!("{:?}", body("g")).contains("CheckedIntOp"), "body: {:?}", body("g"));
+        assert!(matches!(body("h"), MirExpr::UnaryOp { op: UnaryOp::Neg, .. }), "body: {:?}", body("h"));
+    }
+
+    #[test]
+    fn lower_records_function_locations_and_lines() {
+        let parse = mesh_parser::parse(
+            "fn add(a :: Int, b :: Int) -> Int do\n  a + b\nend\n\nfn main() do\n  let x = add(1, 2)\n  println(\"${x}\")\nend",
+        );
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "main.mpl", true)
+            .expect("MIR lowering failed");
+
+        let loc = |name: &str| mir.fn_locations.get(name).cloned();
+        let at = |name: &str, line| MirSourceLoc { name: name.to_string(), file: "main.mpl".to_string(), line };
+        assert_eq!(loc("add"), Some(at("add/2", 1)));
+        assert_eq!(loc("mesh_main"), Some(at("main/0", 5)));
+
+        // Single-expression bodies carry no markers; multi-statement blocks
+        // mark the line of each statement.
+        let add = mir.functions.iter().find(|f| f.name == "add").unwrap();
+        assert!(matches!(add.body, MirExpr::CheckedIntOp { .. }));
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        let debug = format!("{:?}", main.body);
+        assert!(debug.contains("SourceLine(6)"), "body: {}", debug);
+        assert!(debug.contains("SourceLine(7)"), "body: {}", debug);
+
+        // Without debug info there are no markers.
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "main.mpl", false)
+            .expect("MIR lowering failed");
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        assert!(!format!("{:?}", main.body).contains("SourceLine"), "body: {:?}", main.body);
+    }
+
+    #[test]
+    fn lower_locates_documented_functions_at_fn_keyword() {
+        let parse = mesh_parser::parse("## Adds.\n## Really.\nfn add(a :: Int, b :: Int) -> Int do\n  a + b\nend");
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "", &HashSet::new(), "main.mpl", true)
+            .expect("MIR lowering failed");
+
+        assert_eq!(mir.fn_locations.get("add").map(|l| l.line), Some(3));
+    }
+
+    #[test]
+    fn lower_records_module_qualified_source_names() {
+        let parse = mesh_parser::parse(
+            "fn twice(x :: Int) -> Int do\n  let f = fn(y :: Int) -> y * 2 end\n  f(x)\nend\n\ntest \"doubles\" do\n  assert(twice(2) == 4)\nend",
+        );
+        let typeck = mesh_typeck::check(&parse);
+        let mir = lower_to_mir_with_source(&parse, &typeck, "Math.Ops", &HashSet::new(), "ops.mpl", true)
+            .expect("MIR lowering failed");
+
+        let names: HashSet<&str> = mir.fn_locations.values().map(|l| l.name.as_str()).collect();
+        assert!(names.contains("Math.Ops.twice/1"), "names: {:?}", names);
+        assert!(names.contains("Math.Ops.fn/1"), "names: {:?}", names);
+        assert!(names.contains("Math.Ops.test \"doubles\"/0"), "names: {:?}", names);
+    }
+
     #[test]
     fn lower_if_expr() {
         let mir = lower("fn test(x :: Bool) -> Int do if x do 1 else 2 end end");
@@ -11808,7 +13868,8 @@ end
 
     #[test]
     fn primitive_binop_unchanged() {
-        // Regression test: Int + Int should still produce BinOp, not a trait call.
+        // Regression test: Int + Int should still produce a hardware add, not a
+        // trait call.
         let source = r#"
 fn main() do
   let a = 1
@@ -11824,7 +13885,7 @@ end
 
         fn has_binop_add(expr: &MirExpr) -> bool {
             match expr {
-                MirExpr::BinOp {
+                MirExpr::CheckedIntOp {
                     op: BinOp::Add, ..
                 } => true,
                 MirExpr::Let { value, body, .. } => {
@@ -11837,7 +13898,7 @@ end
 
         assert!(
             has_binop_add(&main_fn.body),
-            "Expected BinOp::Add for Int + Int, got: {:?}",
+            "Expected CheckedIntOp Add for Int + Int, got: {:?}",
             main_fn.body
         );
     }
@@ -13110,25 +15171,59 @@ end
         let mir = lower(source);
         let main_fn = mir.functions.iter().find(|f| f.name == "mesh_main");
         assert!(main_fn.is_some(), "Expected mesh_main function in MIR");
-        // The MIR should contain a Hash__hash__Point call somewhere in the body
-        // (emitted as part of the map_put key hashing).
-        fn has_hash_call(expr: &MirExpr) -> bool {
+        fn has_call(expr: &MirExpr, target: &str) -> bool {
             match expr {
                 MirExpr::Call { func, args, .. } => {
                     if let MirExpr::Var(name, _) = func.as_ref() {
-                        if name == "Hash__hash__Point" {
+                        if name == target {
                             return true;
                         }
                     }
-                    args.iter().any(has_hash_call) || has_hash_call(func)
+                    args.iter().any(|a| has_call(a, target)) || has_call(func, target)
                 }
                 MirExpr::Let { value, body, .. } => {
-                    has_hash_call(value) || has_hash_call(body)
+                    has_call(value, target) || has_call(body, target)
                 }
+                MirExpr::Block(exprs, _) => exprs.iter().any(|e| has_call(e, target)),
                 _ => false,
             }
         }
-        assert!(has_hash_call(&main_fn.unwrap().body), "Map.put with struct key should emit Hash__hash__Point call");
+        // The map is tagged with the key type's callbacks; the key itself is
+        // stored as-is rather than replaced by its hash.
+        assert!(
+            has_call(&main_fn.unwrap().body, "mesh_map_tag_hashed"),
+            "Map.put with struct key should tag the map with mesh_map_tag_hashed"
+        );
+        let hash_cb = mir.functions.iter().find(|f| f.name == "__hash_Point_callback");
+        assert!(hash_cb.is_some(), "Expected __hash_Point_callback function");
+        assert!(
+            has_call(&hash_cb.unwrap().body, "Hash__hash__Point"),
+            "__hash_Point_callback should call Hash__hash__Point"
+        );
+        assert!(mir.functions.iter().any(|f| f.name == "__eq_Point_callback"));
+    }
+
+    #[test]
+    fn map_put_with_tuple_key_generates_tuple_callbacks() {
+        let source = r#"
+fn main() do
+  let m = Map.put(Map.new(), (1, "a"), 42)
+  Map.get(m, (1, "a"))
+end
+"#;
+        let mir = lower(source);
+        let hash_cb = mir
+            .functions
+            .iter()
+            .find(|f| f.name == "__hash_tuple_Int_String_callback");
+        assert!(hash_cb.is_some(), "Expected a hash callback for (Int, String) keys");
+        assert_eq!(hash_cb.unwrap().return_type, MirType::Int);
+        let eq_cb = mir
+            .functions
+            .iter()
+            .find(|f| f.name == "__eq_tuple_Int_String_callback");
+        assert!(eq_cb.is_some(), "Expected an eq callback for (Int, String) keys");
+        assert_eq!(eq_cb.unwrap().return_type, MirType::Bool);
     }
 
     // ── Default MIR lowering tests ──────────────────────────────────
@@ -13444,6 +15539,95 @@ end
         );
     }
 
+    #[test]
+    fn set_element_ops_are_tagged_for_the_element_type() {
+        // Set<String> element operations and `==` tag their sets with string
+        // key ops; a struct element type tags them with its hash and eq.
+        let source = r#"
+struct Point do
+  x :: Int
+  y :: Int
+end deriving(Eq, Hash)
+
+fn main() do
+  let s = Set.add(Set.new(), "a")
+  let same = s == Set.from_list(["a"])
+  let points = Set.add(Set.new(), Point { x: 1, y: 2 })
+  Set.contains(points, Point { x: 1, y: 2 })
+end
+"#;
+        let mir = lower(source);
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        assert!(has_call_to(&main.body, "mesh_map_tag_string"), "Body: {:?}", main.body);
+        assert!(has_call_to(&main.body, "mesh_set_eq"), "Body: {:?}", main.body);
+        assert!(has_call_to(&main.body, "mesh_map_tag_hashed"), "Body: {:?}", main.body);
+    }
+
+    #[test]
+    fn queue_for_in_iterates_list_view() {
+        let source = r#"
+fn main() do
+  let q = Queue.push(Queue.new(), "job")
+  for item in q do
+    item
+  end
+end
+"#;
+        let mir = lower(source);
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        fn find_for_in(expr: &MirExpr) -> Option<&MirExpr> {
+            match expr {
+                MirExpr::ForInList { collection, .. } => Some(collection),
+                MirExpr::Let { body, .. } => find_for_in(body),
+                MirExpr::Block(exprs, _) => exprs.iter().find_map(find_for_in),
+                _ => None,
+            }
+        }
+        let collection = find_for_in(&main.body).expect("Expected ForInList over the queue");
+        assert!(has_call_to(collection, "mesh_queue_to_list"), "Collection: {:?}", collection);
+    }
+
+    #[test]
+    fn tuple_accessors_take_element_types() {
+        // A String element keeps its type on the call; a struct element is
+        // bound with `let` so codegen unboxes it; a nested tuple is a Ptr.
+        let source = r#"
+struct User do
+  name :: String
+end
+
+fn main() do
+  let pair = ("a", User { name: "b" })
+  let s = Tuple.first(pair)
+  let u = Tuple.second(pair)
+  let inner = Tuple.nth((1, (2, 3)), 1)
+  s
+end
+"#;
+        let mir = lower(source);
+        let main = mir.functions.iter().find(|f| f.name == "mesh_main").unwrap();
+        fn bindings(expr: &MirExpr, out: &mut Vec<(String, MirExpr)>) {
+            match expr {
+                MirExpr::Let { name, value, body, .. } => {
+                    out.push((name.clone(), (**value).clone()));
+                    bindings(value, out);
+                    bindings(body, out);
+                }
+                MirExpr::Block(exprs, _) => exprs.iter().for_each(|e| bindings(e, out)),
+                _ => {}
+            }
+        }
+        let mut lets = Vec::new();
+        bindings(&main.body, &mut lets);
+        let value_of = |name: &str| lets.iter().find(|(n, _)| n == name).map(|(_, v)| v.clone()).unwrap();
+
+        assert!(matches!(value_of("s"), MirExpr::Call { ty: MirType::String, .. }));
+        let unboxed = lets.iter().find(|(n, _)| n.starts_with("__tuple_elem_")).expect("struct element binding");
+        assert!(matches!(unboxed.1, MirExpr::Call { ty: MirType::Struct(ref n), .. } if n == "User"));
+        assert!(matches!(value_of("u").ty(), MirType::Struct(_)));
+        assert!(matches!(value_of("inner"), MirExpr::Call { ty: MirType::Ptr, .. }));
+    }
+
     // ── Phase 24 Plan 01: Nested collection Display ─────────────────
 
     #[test]
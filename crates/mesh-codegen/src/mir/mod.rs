@@ -12,10 +12,12 @@ pub mod types;
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 // ── MirModule ─────────────────────────────────────────────────────────
 
 /// Top-level compilation unit containing all functions, structs, and sum types.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirModule {
     /// All functions (including lifted closure functions).
     pub functions: Vec<MirFunction>,
@@ -32,13 +34,43 @@ pub struct MirModule {
         String,
         (Vec<(u64, String, usize)>, Vec<(u64, String, usize)>),
     >,
+    /// `test "name" do ... end` blocks, in source order.
+    pub tests: Vec<MirTest>,
+    /// Where each function was defined, for debug info and backtraces.
+    /// Maps function name to its source name, file and line.
+    pub fn_locations: std::collections::HashMap<String, MirSourceLoc>,
+}
+
+/// Where a function was defined: its source name, file path and 1-based line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MirSourceLoc {
+    /// Source-level name in `Module.fn/arity` form, shown in backtraces.
+    pub name: String,
+    /// Source file path, as given to the lowerer.
+    pub file: String,
+    /// 1-based line number.
+    pub line: u32,
+}
+
+/// A `test` block lowered to a zero-argument function.
+///
+/// Test functions are named `__test_*` so the main wrapper never registers
+/// them as spawnable functions; only the `meshc test` entry point runs them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MirTest {
+    /// Human-readable test name (the string after `test`).
+    pub name: String,
+    /// Name of the generated function holding the test body.
+    pub fn_name: String,
+    /// Source module the test was declared in (empty in single-file mode).
+    pub module: String,
 }
 
 // ── MirFunction ───────────────────────────────────────────────────────
 
 /// A function in MIR -- either a user-defined function, a lifted closure,
 /// or a monomorphized generic instantiation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirFunction {
     /// Mangled name (e.g., "identity_Int" for monomorphized generics).
     pub name: String,
@@ -60,7 +92,7 @@ pub struct MirFunction {
 // ── MirType ───────────────────────────────────────────────────────────
 
 /// A concrete MIR type. No type variables remain -- all types are fully resolved.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MirType {
     /// 64-bit signed integer.
     Int,
@@ -142,7 +174,7 @@ impl fmt::Display for MirType {
 // ── MirExpr ───────────────────────────────────────────────────────────
 
 /// A MIR expression node. Each variant carries its resolved type.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MirExpr {
     /// Integer literal.
     IntLit(i64, MirType),
@@ -161,6 +193,16 @@ pub enum MirExpr {
         rhs: Box<MirExpr>,
         ty: MirType,
     },
+    /// `Int` `+`, `-`, `*`, `/` or `%` written in source; negation `-x` is
+    /// lowered as `0 - x`. With overflow checks on it panics at `file:line`
+    /// when the result overflows; otherwise it compiles like the plain `BinOp`.
+    CheckedIntOp {
+        op: BinOp,
+        lhs: Box<MirExpr>,
+        rhs: Box<MirExpr>,
+        file: std::string::String,
+        line: u32,
+    },
     /// Unary operation.
     UnaryOp {
         op: UnaryOp,
@@ -242,6 +284,9 @@ pub enum MirExpr {
     },
     /// Unit value (empty tuple).
     Unit,
+    /// Marks that the following statements of a block start at this source
+    /// line. Evaluates to unit; only used for debug line information.
+    SourceLine(u32),
 
     // ── Actor primitives ──────────────────────────────────────────────
 
@@ -431,6 +476,7 @@ impl MirExpr {
             MirExpr::StringLit(_, ty) => ty,
             MirExpr::Var(_, ty) => ty,
             MirExpr::BinOp { ty, .. } => ty,
+            MirExpr::CheckedIntOp { .. } => &MirType::Int,
             MirExpr::UnaryOp { ty, .. } => ty,
             MirExpr::Call { ty, .. } => ty,
             MirExpr::ClosureCall { ty, .. } => ty,
@@ -446,6 +492,7 @@ impl MirExpr {
             MirExpr::Return(_) => &MirType::Never,
             MirExpr::Panic { .. } => &MirType::Never,
             MirExpr::Unit => &MirType::Unit,
+            MirExpr::SourceLine(_) => &MirType::Unit,
             MirExpr::ActorSpawn { ty, .. } => ty,
             MirExpr::ActorSend { ty, .. } => ty,
             MirExpr::ActorReceive { ty, .. } => ty,
@@ -467,7 +514,7 @@ impl MirExpr {
 }
 
 /// A child specification in a supervisor's MIR representation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirChildSpec {
     /// Child identifier string.
     pub id: String,
@@ -484,7 +531,7 @@ pub struct MirChildSpec {
 // ── MirMatchArm ───────────────────────────────────────────────────────
 
 /// A match arm in MIR with pattern, optional guard, and body.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirMatchArm {
     /// The pattern to match.
     pub pattern: MirPattern,
@@ -497,7 +544,7 @@ pub struct MirMatchArm {
 // ── MirPattern ────────────────────────────────────────────────────────
 
 /// A MIR pattern for match expressions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MirPattern {
     /// Matches anything, binds nothing.
     Wildcard,
@@ -522,12 +569,25 @@ pub enum MirPattern {
         tail: Box<MirPattern>,
         elem_ty: MirType,
     },
+    /// Binary pattern on `Bytes`: matches when the buffer fits the runtime
+    /// segment `spec` (e.g. `"u16,bytes:@0,rest"`), with one sub-pattern per
+    /// segment.
+    Binary {
+        spec: std::string::String,
+        fields: Vec<MirPattern>,
+    },
+    /// Map pattern: matches a map holding every key, with one sub-pattern
+    /// per key for its value. Keys the pattern does not name are ignored.
+    Map {
+        entries: Vec<(MirMapKey, MirPattern)>,
+        val_ty: MirType,
+    },
 }
 
 // ── MirLiteral ────────────────────────────────────────────────────────
 
 /// A literal value used in patterns.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MirLiteral {
     Int(i64),
     Float(f64),
@@ -535,10 +595,17 @@ pub enum MirLiteral {
     String(std::string::String),
 }
 
+/// A key literal in a map pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MirMapKey {
+    Int(i64),
+    String(std::string::String),
+}
+
 // ── BinOp / UnaryOp ──────────────────────────────────────────────────
 
 /// Binary operators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BinOp {
     // Arithmetic
     Add,
@@ -582,7 +649,7 @@ impl fmt::Display for BinOp {
 }
 
 /// Unary operators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UnaryOp {
     /// Arithmetic negation.
     Neg,
@@ -602,7 +669,7 @@ impl fmt::Display for UnaryOp {
 // ── MirStructDef ──────────────────────────────────────────────────────
 
 /// A struct type definition in MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirStructDef {
     /// Struct name.
     pub name: std::string::String,
@@ -613,7 +680,7 @@ pub struct MirStructDef {
 // ── MirSumTypeDef ─────────────────────────────────────────────────────
 
 /// A sum type definition in MIR.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirSumTypeDef {
     /// Sum type name.
     pub name: std::string::String,
@@ -622,7 +689,7 @@ pub struct MirSumTypeDef {
 }
 
 /// A single variant in a MIR sum type definition.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirVariantDef {
     /// Variant name.
     pub name: std::string::String,
@@ -3,7 +3,8 @@
 //! Takes a MIR module and ensures all functions use only concrete types.
 //! Since the type checker already resolves concrete types at each call site,
 //! this pass primarily:
-//! 1. Collects all reachable functions starting from the entry point.
+//! 1. Collects all reachable functions starting from the entry point
+//!    (or from the test functions when building tests).
 //! 2. Removes unreachable functions.
 //! 3. In future: creates specialized copies of generic functions for each
 //!    concrete type instantiation.
@@ -34,9 +35,14 @@ fn collect_reachable_functions(module: &MirModule) -> HashSet<String> {
     let mut reachable = HashSet::new();
     let mut worklist: Vec<String> = Vec::new();
 
-    // Start from the entry function, or all functions if no entry.
+    // Start from the entry function, the test functions of a test build,
+    // or all functions if there is neither.
     if let Some(ref entry) = module.entry_function {
         worklist.push(entry.clone());
+    } else if !module.tests.is_empty() {
+        for test in &module.tests {
+            worklist.push(test.fn_name.clone());
+        }
     } else {
         // No entry point: keep all functions reachable.
         for f in &module.functions {
@@ -125,7 +131,7 @@ fn collect_function_refs(expr: &MirExpr, refs: &mut Vec<String>) {
                 collect_function_refs(cap, refs);
             }
         }
-        MirExpr::BinOp { lhs, rhs, .. } => {
+        MirExpr::BinOp { lhs, rhs, .. } | MirExpr::CheckedIntOp { lhs, rhs, .. } => {
             collect_function_refs(lhs, refs);
             collect_function_refs(rhs, refs);
         }
@@ -193,7 +199,8 @@ fn collect_function_refs(expr: &MirExpr, refs: &mut Vec<String>) {
         | MirExpr::BoolLit(_, _)
         | MirExpr::StringLit(_, _)
         | MirExpr::Panic { .. }
-        | MirExpr::Unit => {}
+        | MirExpr::Unit
+        | MirExpr::SourceLine(_) => {}
         // Actor primitives
         MirExpr::ActorSpawn { func, args, terminate_callback, .. } => {
             collect_function_refs(func, refs);
@@ -344,6 +351,8 @@ mod tests {
             sum_types: vec![],
             entry_function: Some("main".to_string()),
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         monomorphize(&mut module);
@@ -381,6 +390,8 @@ mod tests {
             sum_types: vec![],
             entry_function: None,
             service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
         };
 
         monomorphize(&mut module);
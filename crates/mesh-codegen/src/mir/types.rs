@@ -67,6 +67,8 @@ pub fn resolve_type(ty: &Ty, registry: &TypeRegistry, is_closure_context: bool)
 fn resolve_con(con: &TyCon, registry: &TypeRegistry) -> MirType {
     match con.name.as_str() {
         "Int" => MirType::Int,
+        // Fixed-width integers are carried as a normalized i64.
+        "Int8" | "Int16" | "Int32" | "UInt8" | "UInt16" | "UInt32" | "UInt64" => MirType::Int,
         "Float" => MirType::Float,
         "Bool" => MirType::Bool,
         "String" => MirType::String,
@@ -79,8 +81,10 @@ fn resolve_con(con: &TyCon, registry: &TypeRegistry) -> MirType {
         "PgConn" => MirType::Int,
         // PoolHandle is an opaque u64 handle, lowered to Int for GC safety (same as PgConn/SqliteConn).
         "PoolHandle" => MirType::Int,
+        // Regex is an opaque u64 handle to an interned runtime regex, lowered to Int like PoolHandle.
+        "Regex" => MirType::Int,
         // Collection types, Json, HTTP types, and iterator handles are opaque pointers at LLVM level.
-        "List" | "Map" | "Set" | "Range" | "Queue" | "Tuple" | "Json"
+        "List" | "Map" | "Set" | "Range" | "Queue" | "Tuple" | "Json" | "Bytes" | "IoData" | "BigInt" | "Decimal"
         | "Router" | "Request" | "Response"
         | "ListIterator" | "MapIterator" | "SetIterator" | "RangeIterator"
         // Phase 78: Adapter iterator types
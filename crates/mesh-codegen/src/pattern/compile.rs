@@ -11,8 +11,10 @@
 
 use rustc_hash::FxHashMap;
 
-use crate::mir::{MirExpr, MirLiteral, MirMatchArm, MirModule, MirPattern, MirSumTypeDef, MirType};
-use crate::pattern::{AccessPath, ConstructorTag, DecisionTree};
+use crate::mir::{
+    MirExpr, MirLiteral, MirMapKey, MirMatchArm, MirModule, MirPattern, MirSumTypeDef, MirType,
+};
+use crate::pattern::{binary_segment_type, AccessPath, ConstructorTag, DecisionTree};
 
 // ── Pattern Matrix ──────────────────────────────────────────────────
 
@@ -60,6 +62,16 @@ enum HeadCtor {
     ListCons {
         elem_ty: MirType,
     },
+    /// A binary pattern with its segment spec and segment count.
+    Binary {
+        spec: String,
+        arity: usize,
+    },
+    /// A map pattern, tested one key at a time.
+    Map {
+        key: MirMapKey,
+        val_ty: MirType,
+    },
 }
 
 // ── Public API ──────────────────────────────────────────────────────
@@ -198,11 +210,18 @@ fn compile_matrix(
         return compile_matrix(reduced, file, line, sum_type_defs);
     }
 
-    // Step 3: Determine if we need a Switch (constructors), ListDecons, or Tests (literals).
+    // Step 3: Determine if we need a Switch (constructors), ListDecons,
+    // BinaryMatch, MapHasKey, or Tests (literals).
     let has_list_cons = head_ctors.iter().any(|c| matches!(c, HeadCtor::ListCons { .. }));
     let has_constructors = head_ctors.iter().any(|c| matches!(c, HeadCtor::Constructor { .. }));
-
-    if has_list_cons {
+    let has_binary = head_ctors.iter().any(|c| matches!(c, HeadCtor::Binary { .. }));
+    let has_map = head_ctors.iter().any(|c| matches!(c, HeadCtor::Map { .. }));
+
+    if has_map {
+        compile_map_match(&matrix, col, &head_ctors, file, line, sum_type_defs)
+    } else if has_binary {
+        compile_binary_match(&matrix, col, &head_ctors, file, line, sum_type_defs)
+    } else if has_list_cons {
         compile_list_cons(&matrix, col, &head_ctors, file, line, sum_type_defs)
     } else if has_constructors {
         compile_constructor_switch(&matrix, col, &head_ctors, file, line, sum_type_defs)
@@ -325,6 +344,8 @@ fn head_ctor_key(p: &MirPattern) -> Option<String> {
         MirPattern::Constructor { variant, .. } => Some(format!("ctor:{}", variant)),
         MirPattern::Tuple(elems) => Some(format!("tuple:{}", elems.len())),
         MirPattern::ListCons { .. } => Some("list_cons".to_string()),
+        MirPattern::Binary { spec, .. } => Some(format!("binary:{}", spec)),
+        MirPattern::Map { entries, .. } => entries.first().map(|(key, _)| format!("map:{}", map_key_key(key))),
         MirPattern::Or(_) => None, // Should be expanded already
         MirPattern::Wildcard | MirPattern::Var(..) => None,
     }
@@ -339,6 +360,13 @@ fn literal_key(lit: &MirLiteral) -> String {
     }
 }
 
+fn map_key_key(key: &MirMapKey) -> String {
+    match key {
+        MirMapKey::Int(n) => format!("int:{}", n),
+        MirMapKey::String(s) => format!("str:{}", s),
+    }
+}
+
 // ── Head constructor collection ─────────────────────────────────────
 
 /// Collect all distinct head constructors from a column.
@@ -415,6 +443,30 @@ fn collect_head_constructors(
                     });
                 }
             }
+            MirPattern::Binary { spec, fields } => {
+                let key = format!("binary:{}", spec);
+                if !seen.contains(&key) {
+                    seen.push(key);
+                    result.push(HeadCtor::Binary {
+                        spec: spec.clone(),
+                        arity: fields.len(),
+                    });
+                }
+            }
+            MirPattern::Map { entries, val_ty } => {
+                // Only the first key of each map is a head constructor; the
+                // others are tested after it is removed from the pattern.
+                if let Some((key, _)) = entries.first() {
+                    let map_key = format!("map:{}", map_key_key(key));
+                    if !seen.contains(&map_key) {
+                        seen.push(map_key);
+                        result.push(HeadCtor::Map {
+                            key: key.clone(),
+                            val_ty: val_ty.clone(),
+                        });
+                    }
+                }
+            }
             _ => {} // Wildcards/variables don't contribute head constructors.
         }
     }
@@ -755,6 +807,230 @@ fn specialize_for_list_cons(
     }
 }
 
+// ── Binary pattern compilation ──────────────────────────────────────
+
+/// Compile a BinaryMatch node for `<<...>>` patterns on `Bytes`.
+///
+/// Unlike constructors, binary patterns with different specs can match the
+/// same buffer, so specs are tested one at a time in row order. The success
+/// branch keeps every row: rows with the tested spec get their segments as
+/// new columns (and a wildcard in the tested column), other rows keep their
+/// pattern and get wildcard segments, so a later arm with another spec can
+/// still match. The failure branch drops only the rows with the tested spec.
+fn compile_binary_match(
+    matrix: &PatMatrix,
+    col: usize,
+    head_ctors: &[HeadCtor],
+    file: &str,
+    line: u32,
+    sum_type_defs: &FxHashMap<String, MirSumTypeDef>,
+) -> DecisionTree {
+    let scrutinee_path = matrix.column_paths[col].clone();
+
+    // Head constructors are collected in row order, so this is the spec of
+    // the first row with a binary pattern in this column.
+    let (spec, arity) = head_ctors
+        .iter()
+        .find_map(|hc| {
+            if let HeadCtor::Binary { spec, arity } = hc {
+                Some((spec.clone(), *arity))
+            } else {
+                None
+            }
+        })
+        .unwrap_or_default();
+
+    let specialized = specialize_for_binary(matrix, col, &spec, arity);
+    let success = compile_matrix(specialized, file, line, sum_type_defs);
+
+    let failure_mat = PatMatrix {
+        rows: matrix
+            .rows
+            .iter()
+            .filter(|row| !matches!(&row.patterns[col], MirPattern::Binary { spec: s, .. } if *s == spec))
+            .cloned()
+            .collect(),
+        column_paths: matrix.column_paths.clone(),
+        column_types: matrix.column_types.clone(),
+    };
+    let failure = compile_matrix(failure_mat, file, line, sum_type_defs);
+
+    DecisionTree::BinaryMatch {
+        scrutinee_path,
+        spec,
+        success: Box::new(success),
+        failure: Box::new(failure),
+    }
+}
+
+/// Specialize the matrix for a buffer known to match `target_spec`.
+///
+/// Every row is kept and `arity` segment columns are appended: the segment
+/// sub-patterns for rows with this spec (whose own column becomes a
+/// wildcard), wildcards for all other rows.
+fn specialize_for_binary(
+    matrix: &PatMatrix,
+    col: usize,
+    target_spec: &str,
+    arity: usize,
+) -> PatMatrix {
+    let parent_path = &matrix.column_paths[col];
+
+    let new_rows = matrix
+        .rows
+        .iter()
+        .map(|row| {
+            let mut new_pats = row.patterns.clone();
+            match &row.patterns[col] {
+                MirPattern::Binary { spec, fields } if spec == target_spec => {
+                    new_pats[col] = MirPattern::Wildcard;
+                    new_pats.extend(fields.iter().cloned());
+                }
+                _ => new_pats.extend(std::iter::repeat_n(MirPattern::Wildcard, arity)),
+            }
+            PatRow {
+                patterns: new_pats,
+                arm_index: row.arm_index,
+                guard: row.guard.clone(),
+                bindings: row.bindings.clone(),
+            }
+        })
+        .collect();
+
+    let mut new_paths = matrix.column_paths.clone();
+    let mut new_types = matrix.column_types.clone();
+    for i in 0..arity {
+        new_paths.push(AccessPath::BinarySegment(
+            Box::new(parent_path.clone()),
+            target_spec.to_string(),
+            i,
+        ));
+        new_types.push(binary_segment_type(target_spec, i));
+    }
+
+    PatMatrix {
+        rows: new_rows,
+        column_paths: new_paths,
+        column_types: new_types,
+    }
+}
+
+// ── Map pattern compilation ─────────────────────────────────────────
+
+/// Compile a MapHasKey node for `%{...}` patterns.
+///
+/// Map patterns are tested one key at a time, taking the first key of the
+/// first row with a map pattern in this column. When the key is present,
+/// every row is kept and its value becomes a new column: rows whose map
+/// names the key move that entry's value pattern into it (and keep their
+/// other entries), all other rows get a wildcard. When it is absent, the
+/// rows whose map names the key are dropped.
+fn compile_map_match(
+    matrix: &PatMatrix,
+    col: usize,
+    head_ctors: &[HeadCtor],
+    file: &str,
+    line: u32,
+    sum_type_defs: &FxHashMap<String, MirSumTypeDef>,
+) -> DecisionTree {
+    let scrutinee_path = matrix.column_paths[col].clone();
+
+    let Some((key, val_ty)) = head_ctors.iter().find_map(|hc| {
+        if let HeadCtor::Map { key, val_ty } = hc {
+            Some((key.clone(), val_ty.clone()))
+        } else {
+            None
+        }
+    }) else {
+        return compile_matrix(remove_wildcard_column(matrix, col), file, line, sum_type_defs);
+    };
+
+    let specialized = specialize_for_map_key(matrix, col, &key, &val_ty);
+    let success = compile_matrix(specialized, file, line, sum_type_defs);
+
+    let failure_mat = PatMatrix {
+        rows: matrix
+            .rows
+            .iter()
+            .filter(|row| !map_pattern_has_key(&row.patterns[col], &key))
+            .cloned()
+            .collect(),
+        column_paths: matrix.column_paths.clone(),
+        column_types: matrix.column_types.clone(),
+    };
+    let failure = compile_matrix(failure_mat, file, line, sum_type_defs);
+
+    DecisionTree::MapHasKey {
+        scrutinee_path,
+        key,
+        success: Box::new(success),
+        failure: Box::new(failure),
+    }
+}
+
+/// Check if a pattern is a map pattern naming `key`.
+fn map_pattern_has_key(p: &MirPattern, key: &MirMapKey) -> bool {
+    matches!(p, MirPattern::Map { entries, .. } if entries.iter().any(|(k, _)| k == key))
+}
+
+/// Specialize the matrix for a map known to hold `key`, appending a column
+/// for its value.
+fn specialize_for_map_key(
+    matrix: &PatMatrix,
+    col: usize,
+    key: &MirMapKey,
+    val_ty: &MirType,
+) -> PatMatrix {
+    let parent_path = &matrix.column_paths[col];
+
+    let new_rows = matrix
+        .rows
+        .iter()
+        .map(|row| {
+            let mut new_pats = row.patterns.clone();
+            let value = match &row.patterns[col] {
+                MirPattern::Map { entries, val_ty } if map_pattern_has_key(&row.patterns[col], key) => {
+                    let mut rest = entries.clone();
+                    let index = rest.iter().position(|(k, _)| k == key).unwrap_or_default();
+                    let (_, value) = rest.remove(index);
+                    new_pats[col] = if rest.is_empty() {
+                        MirPattern::Wildcard
+                    } else {
+                        MirPattern::Map {
+                            entries: rest,
+                            val_ty: val_ty.clone(),
+                        }
+                    };
+                    value
+                }
+                _ => MirPattern::Wildcard,
+            };
+            new_pats.push(value);
+            PatRow {
+                patterns: new_pats,
+                arm_index: row.arm_index,
+                guard: row.guard.clone(),
+                bindings: row.bindings.clone(),
+            }
+        })
+        .collect();
+
+    let mut new_paths = matrix.column_paths.clone();
+    let mut new_types = matrix.column_types.clone();
+    new_paths.push(AccessPath::MapValue(
+        Box::new(parent_path.clone()),
+        key.clone(),
+        val_ty.clone(),
+    ));
+    new_types.push(val_ty.clone());
+
+    PatMatrix {
+        rows: new_rows,
+        column_paths: new_paths,
+        column_types: new_types,
+    }
+}
+
 // ── Literal test compilation ────────────────────────────────────────
 
 /// Compile a chain of Test nodes for literal patterns.
@@ -1136,7 +1412,7 @@ fn compile_expr_patterns(
             // is computed and will be used by the LLVM codegen directly via
             // compile_match() calls.
         }
-        MirExpr::BinOp { lhs, rhs, .. } => {
+        MirExpr::BinOp { lhs, rhs, .. } | MirExpr::CheckedIntOp { lhs, rhs, .. } => {
             compile_expr_patterns(lhs, sum_type_defs);
             compile_expr_patterns(rhs, sum_type_defs);
         }
@@ -1208,7 +1484,8 @@ fn compile_expr_patterns(
         | MirExpr::StringLit(..)
         | MirExpr::Var(..)
         | MirExpr::Panic { .. }
-        | MirExpr::Unit => {}
+        | MirExpr::Unit
+        | MirExpr::SourceLine(_) => {}
         // Actor primitives -- recurse into sub-expressions.
         MirExpr::ActorSpawn { func, args, terminate_callback, .. } => {
             compile_expr_patterns(func, sum_type_defs);
@@ -2334,4 +2611,184 @@ mod tests {
             other => panic!("Expected Switch, got {:?}", other),
         }
     }
+
+    // ── Test 17: Binary patterns with overlapping specs ───────────────
+
+    #[test]
+    fn test_binary_patterns() {
+        // case b { <<1, n::u16>> -> n, <<tag, rest::rest>> -> tag, _ -> 0 }
+        // A buffer matching the first spec but not its literal must still
+        // reach the second arm, so the success branch keeps that arm.
+        let arms = vec![
+            make_arm(
+                MirPattern::Binary {
+                    spec: "u8,u16".to_string(),
+                    fields: vec![
+                        MirPattern::Literal(MirLiteral::Int(1)),
+                        MirPattern::Var("n".to_string(), MirType::Int),
+                    ],
+                },
+                None,
+                var_expr("n", MirType::Int),
+            ),
+            make_arm(
+                MirPattern::Binary {
+                    spec: "u8,rest".to_string(),
+                    fields: vec![
+                        MirPattern::Var("tag".to_string(), MirType::Int),
+                        MirPattern::Wildcard,
+                    ],
+                },
+                None,
+                var_expr("tag", MirType::Int),
+            ),
+            make_arm(MirPattern::Wildcard, None, int_body(0)),
+        ];
+
+        let tree = compile_match(&MirType::Ptr, &arms, "test.mpl", 1, &FxHashMap::default());
+
+        let (success, failure) = match &tree {
+            DecisionTree::BinaryMatch {
+                scrutinee_path,
+                spec,
+                success,
+                failure,
+            } => {
+                assert_eq!(*scrutinee_path, AccessPath::Root);
+                assert_eq!(spec, "u8,u16");
+                (success.as_ref(), failure.as_ref())
+            }
+            other => panic!("Expected BinaryMatch, got {:?}", other),
+        };
+
+        fn leaf_arms(tree: &DecisionTree, out: &mut Vec<usize>) {
+            match tree {
+                DecisionTree::Leaf { arm_index, .. } => out.push(*arm_index),
+                DecisionTree::Test { success, failure, .. }
+                | DecisionTree::BinaryMatch { success, failure, .. } => {
+                    leaf_arms(success, out);
+                    leaf_arms(failure, out);
+                }
+                other => panic!("Unexpected node {:?}", other),
+            }
+        }
+
+        // Matching the first spec does not rule out the second arm.
+        let mut arms_on_success = Vec::new();
+        leaf_arms(success, &mut arms_on_success);
+        assert!(arms_on_success.contains(&0));
+        assert!(arms_on_success.contains(&1));
+
+        // Not matching it rules out only the first arm.
+        let mut arms_on_failure = Vec::new();
+        leaf_arms(failure, &mut arms_on_failure);
+        assert!(!arms_on_failure.contains(&0));
+        assert!(arms_on_failure.contains(&1));
+        assert!(arms_on_failure.contains(&2));
+
+        match failure {
+            DecisionTree::BinaryMatch { spec, success, .. } => {
+                assert_eq!(spec, "u8,rest");
+                match success.as_ref() {
+                    DecisionTree::Leaf { arm_index: 1, bindings } => {
+                        assert_eq!(
+                            bindings[0].2,
+                            AccessPath::BinarySegment(Box::new(AccessPath::Root), "u8,rest".to_string(), 0)
+                        );
+                    }
+                    other => panic!("Expected Leaf for arm 1, got {:?}", other),
+                }
+            }
+            other => panic!("Expected BinaryMatch, got {:?}", other),
+        }
+    }
+
+    // ── Test 18: Map patterns tested key by key ───────────────────────
+
+    #[test]
+    fn test_map_patterns() {
+        // case m { %{"type" => "error", "id" => id} -> id, %{"id" => id} -> id, _ -> "" }
+        let key = |k: &str| MirMapKey::String(k.to_string());
+        let id = || MirPattern::Var("id".to_string(), MirType::String);
+        let arms = vec![
+            make_arm(
+                MirPattern::Map {
+                    entries: vec![
+                        (key("type"), MirPattern::Literal(MirLiteral::String("error".to_string()))),
+                        (key("id"), id()),
+                    ],
+                    val_ty: MirType::String,
+                },
+                None,
+                var_expr("id", MirType::String),
+            ),
+            make_arm(
+                MirPattern::Map {
+                    entries: vec![(key("id"), id())],
+                    val_ty: MirType::String,
+                },
+                None,
+                var_expr("id", MirType::String),
+            ),
+            make_arm(MirPattern::Wildcard, None, string_body("")),
+        ];
+
+        let tree = compile_match(&MirType::Ptr, &arms, "test.mpl", 1, &FxHashMap::default());
+
+        fn leaf_arms(tree: &DecisionTree, out: &mut Vec<usize>) {
+            match tree {
+                DecisionTree::Leaf { arm_index, .. } => out.push(*arm_index),
+                DecisionTree::Test { success, failure, .. }
+                | DecisionTree::MapHasKey { success, failure, .. } => {
+                    leaf_arms(success, out);
+                    leaf_arms(failure, out);
+                }
+                other => panic!("Unexpected node {:?}", other),
+            }
+        }
+
+        let (success, failure) = match &tree {
+            DecisionTree::MapHasKey {
+                scrutinee_path,
+                key: tested,
+                success,
+                failure,
+            } => {
+                assert_eq!(*scrutinee_path, AccessPath::Root);
+                assert_eq!(*tested, key("type"));
+                (success.as_ref(), failure.as_ref())
+            }
+            other => panic!("Expected MapHasKey, got {:?}", other),
+        };
+
+        // A map with a "type" key can still match any arm.
+        let mut arms_on_success = Vec::new();
+        leaf_arms(success, &mut arms_on_success);
+        assert!(arms_on_success.contains(&0));
+        assert!(arms_on_success.contains(&1));
+        assert!(arms_on_success.contains(&2));
+
+        // Without one, only the "id" key is left to test.
+        match failure {
+            DecisionTree::MapHasKey {
+                key: tested,
+                success,
+                failure,
+                ..
+            } => {
+                assert_eq!(*tested, key("id"));
+                match success.as_ref() {
+                    DecisionTree::Leaf { arm_index: 1, bindings } => {
+                        assert_eq!(
+                            bindings[0].2,
+                            AccessPath::MapValue(Box::new(AccessPath::Root), key("id"), MirType::String)
+                        );
+                    }
+                    other => panic!("Expected Leaf for arm 1, got {:?}", other),
+                }
+                assert!(matches!(failure.as_ref(), DecisionTree::Leaf { arm_index: 2, .. }));
+            }
+            other => panic!("Expected MapHasKey, got {:?}", other),
+        }
+    }
 }
@@ -10,11 +10,13 @@
 //! - `Switch` -- switch on sum type constructor tag
 //! - `Test` -- test literal equality
 //! - `Guard` -- evaluate a guard expression
+//! - `BinaryMatch` -- test a `Bytes` value against a binary segment spec
+//! - `MapHasKey` -- test whether a map holds a key
 //! - `Fail` -- runtime panic for non-exhaustive match
 
 pub mod compile;
 
-use crate::mir::{MirExpr, MirLiteral, MirType};
+use crate::mir::{MirExpr, MirLiteral, MirMapKey, MirType};
 
 // ── AccessPath ──────────────────────────────────────────────────────
 
@@ -33,6 +35,20 @@ pub enum AccessPath {
     ListHead(Box<AccessPath>),
     /// Tail of a list (remaining elements after head).
     ListTail(Box<AccessPath>),
+    /// Segment N of a `Bytes` value matched against a binary segment spec.
+    BinarySegment(Box<AccessPath>, String, usize),
+    /// The value stored under a key of a map, with the map's value type
+    /// (map values are stored as u64 words and converted on access).
+    MapValue(Box<AccessPath>, MirMapKey, MirType),
+}
+
+/// The MIR type of segment `index` of a binary segment spec: `Int` for
+/// integer segments, `Ptr` (a `Bytes` value) for `bytes` and `rest`.
+pub fn binary_segment_type(spec: &str, index: usize) -> MirType {
+    match spec.split(',').nth(index) {
+        Some(entry) if entry.starts_with('u') || entry.starts_with('i') => MirType::Int,
+        _ => MirType::Ptr,
+    }
 }
 
 // ── ConstructorTag ──────────────────────────────────────────────────
@@ -92,6 +108,26 @@ pub enum DecisionTree {
         /// Tree when list is empty (fallthrough to next arm).
         empty: Box<DecisionTree>,
     },
+    /// Binary match: test whether a `Bytes` value fits the segment spec,
+    /// whose segments are then reachable through `AccessPath::BinarySegment`.
+    BinaryMatch {
+        scrutinee_path: AccessPath,
+        spec: String,
+        /// Tree when the value matches (segment columns added).
+        success: Box<DecisionTree>,
+        /// Tree when it does not (rows with this spec removed).
+        failure: Box<DecisionTree>,
+    },
+    /// Map key test: check whether a map holds `key`, whose value is then
+    /// reachable through `AccessPath::MapValue`.
+    MapHasKey {
+        scrutinee_path: AccessPath,
+        key: MirMapKey,
+        /// Tree when the key is present (value column added).
+        success: Box<DecisionTree>,
+        /// Tree when it is absent (rows requiring the key removed).
+        failure: Box<DecisionTree>,
+    },
     /// Runtime panic for non-exhaustive match (possible with guards).
     Fail {
         message: String,
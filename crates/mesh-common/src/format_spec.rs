@@ -0,0 +1,194 @@
+//! Format specifiers: the `.2` in `"${price:.2}"` and the `>8` in a
+//! `String.format` template placeholder `{:>8}`.
+//!
+//! The compiler checks interpolation specs against the type of the
+//! expression and the runtime applies them, so both parse with
+//! [`FormatSpec::parse`]. The grammar is
+//!
+//! ```text
+//! [[fill]align][+][0][width][.precision][type]
+//! ```
+//!
+//! - `align` is `<` (left), `>` (right) or `^` (center), optionally after a
+//!   `fill` character. Numbers align right by default, everything else left.
+//! - `+` shows the sign of non-negative numbers; `0` pads a number with
+//!   zeros after its sign.
+//! - `width` is the minimum width in graphemes.
+//! - `.precision` is the number of decimals of a Float, or the maximum
+//!   number of graphemes of a String.
+//! - `type` is `x`/`X` (hex), `o` (octal) or `b` (binary) for an Int and
+//!   `e` (scientific notation) for a Float.
+
+/// Where padding goes when the value is narrower than the width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// The kind of value a spec is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatTarget {
+    Int,
+    Float,
+    String,
+    /// Any other displayable value, formatted through its `to_string`.
+    Other,
+}
+
+/// A parsed format spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Option<Align>,
+    pub plus: bool,
+    pub zero: bool,
+    pub width: usize,
+    pub precision: Option<usize>,
+    /// One of `x`, `X`, `o`, `b` or `e`.
+    pub kind: Option<char>,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        FormatSpec { fill: ' ', align: None, plus: false, zero: false, width: 0, precision: None, kind: None }
+    }
+}
+
+fn align_of(c: char) -> Option<Align> {
+    match c {
+        '<' => Some(Align::Left),
+        '>' => Some(Align::Right),
+        '^' => Some(Align::Center),
+        _ => None,
+    }
+}
+
+impl FormatSpec {
+    /// Parse the text after the `:`. Returns a message describing the
+    /// problem when the text does not follow the grammar.
+    pub fn parse(text: &str) -> Result<FormatSpec, String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut spec = FormatSpec::default();
+        let mut i = 0;
+
+        if let Some(align) = chars.get(1).copied().and_then(align_of) {
+            spec.fill = chars[0];
+            spec.align = Some(align);
+            i = 2;
+        } else if let Some(align) = chars.first().copied().and_then(align_of) {
+            spec.align = Some(align);
+            i = 1;
+        }
+        if chars.get(i) == Some(&'+') {
+            spec.plus = true;
+            i += 1;
+        }
+        if chars.get(i) == Some(&'0') {
+            spec.zero = true;
+            i += 1;
+        }
+        let digits = |i: &mut usize| -> Option<usize> {
+            let start = *i;
+            while chars.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+                *i += 1;
+            }
+            chars[start..*i].iter().collect::<String>().parse().ok()
+        };
+        spec.width = digits(&mut i).unwrap_or(0);
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            spec.precision = Some(digits(&mut i).ok_or("expected digits after `.`")?);
+        }
+        if let Some(&c) = chars.get(i).filter(|c| matches!(c, 'x' | 'X' | 'o' | 'b' | 'e')) {
+            spec.kind = Some(c);
+            i += 1;
+        }
+        if i < chars.len() {
+            let rest: String = chars[i..].iter().collect();
+            return Err(format!("unexpected `{}`; expected `[[fill]align][+][0][width][.precision][type]`", rest));
+        }
+        if spec.zero && spec.align.is_some() {
+            return Err("`0` padding cannot be combined with an alignment".to_string());
+        }
+        Ok(spec)
+    }
+
+    /// Check that the spec can format a value of `target`. `type_name`
+    /// names the value's type in the message, e.g. `Bool`.
+    pub fn check(&self, target: FormatTarget, type_name: &str) -> Result<(), String> {
+        let number = matches!(target, FormatTarget::Int | FormatTarget::Float);
+        match self.kind {
+            Some('e') if target != FormatTarget::Float => {
+                return Err(format!("`e` formats a Float, not {}", type_name));
+            }
+            Some(c) if c != 'e' && target != FormatTarget::Int => {
+                return Err(format!("`{}` formats an Int, not {}", c, type_name));
+            }
+            _ => {}
+        }
+        if let Some(p) = self.precision {
+            if !matches!(target, FormatTarget::Float | FormatTarget::String) {
+                return Err(format!("precision `.{}` applies to a Float or String, not {}", p, type_name));
+            }
+        }
+        if self.plus && !number {
+            return Err(format!("`+` applies to numbers, not {}", type_name));
+        }
+        if self.zero && !number {
+            return Err(format!("`0` padding applies to numbers, not {}", type_name));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full_spec() {
+        let spec = FormatSpec::parse("*^12.3").unwrap();
+        assert_eq!(spec.fill, '*');
+        assert_eq!(spec.align, Some(Align::Center));
+        assert_eq!(spec.width, 12);
+        assert_eq!(spec.precision, Some(3));
+
+        let spec = FormatSpec::parse("+08x").unwrap();
+        assert!(spec.plus && spec.zero);
+        assert_eq!((spec.width, spec.kind), (8, Some('x')));
+
+        assert_eq!(FormatSpec::parse("").unwrap(), FormatSpec::default());
+        assert_eq!(FormatSpec::parse(">").unwrap().align, Some(Align::Right));
+        assert_eq!(FormatSpec::parse("<>").unwrap().fill, '<');
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            FormatSpec::parse("*^+010.3e").unwrap_err(),
+            "`0` padding cannot be combined with an alignment"
+        );
+        assert_eq!(FormatSpec::parse(".x").unwrap_err(), "expected digits after `.`");
+        assert_eq!(
+            FormatSpec::parse("8q").unwrap_err(),
+            "unexpected `q`; expected `[[fill]align][+][0][width][.precision][type]`"
+        );
+    }
+
+    #[test]
+    fn check_against_targets() {
+        let check = |spec: &str, target, name| FormatSpec::parse(spec).unwrap().check(target, name);
+        assert!(check(">8", FormatTarget::Other, "Bool").is_ok());
+        assert!(check("08x", FormatTarget::Int, "Int").is_ok());
+        assert!(check(".2", FormatTarget::String, "String").is_ok());
+        assert_eq!(check("x", FormatTarget::Float, "Float").unwrap_err(), "`x` formats an Int, not Float");
+        assert_eq!(check("e", FormatTarget::Int, "Int").unwrap_err(), "`e` formats a Float, not Int");
+        assert_eq!(
+            check(".2", FormatTarget::Int, "Int").unwrap_err(),
+            "precision `.2` applies to a Float or String, not Int"
+        );
+        assert_eq!(check("+", FormatTarget::String, "String").unwrap_err(), "`+` applies to numbers, not String");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod error;
+pub mod format_spec;
 pub mod module_graph;
 pub mod span;
 pub mod token;
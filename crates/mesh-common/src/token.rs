@@ -77,7 +77,7 @@ pub enum TokenKind {
     Break,
     Continue,
 
-    // ── Operators (24) ─────────────────────────────────────────────────
+    // ── Operators (25) ─────────────────────────────────────────────────
     /// `+`
     Plus,
     /// `-`
@@ -106,7 +106,7 @@ pub enum TokenKind {
     PipePipe,
     /// `!`
     Bang,
-    /// `|>`
+    /// `|>`, or `|N>` to pipe into argument N
     Pipe,
     /// `..`
     DotDot,
@@ -126,6 +126,8 @@ pub enum TokenKind {
     Question,
     /// `|` bare pipe for or-patterns
     Bar,
+    /// `^` pin operator in patterns
+    Caret,
 
     // ── Delimiters (6) ─────────────────────────────────────────────────
     /// `(`
@@ -153,7 +155,7 @@ pub enum TokenKind {
     /// Significant newline (statement terminator).
     Newline,
 
-    // ── Literals (8) ───────────────────────────────────────────────────
+    // ── Literals (10) ──────────────────────────────────────────────────
     /// Integer literal, e.g. `42`, `0xFF`, `0b1010`.
     IntLiteral,
     /// Floating-point literal, e.g. `3.14`, `1.0e10`.
@@ -170,6 +172,11 @@ pub enum TokenKind {
     InterpolationEnd,
     /// Atom literal, e.g. `:name`, `:email`, `:asc`.
     Atom,
+    /// Regex literal, e.g. `~r/[a-z]+/i` (pattern and flags in one token).
+    Regex,
+    /// Format spec of an interpolation, from the `:` up to the closing `}`,
+    /// e.g. `:.2` in `"${price:.2}"`.
+    FormatSpec,
 
     // ── Identifiers and comments (4) ───────────────────────────────────
     /// Regular identifier, e.g. `foo`, `my_var`.
@@ -340,13 +347,13 @@ mod tests {
         // Literals: 7, Identifiers/comments: 4, Special: 2 = 93 total
         // This test documents the expected count.
         let keywords = 48u32;
-        let operators = 24;
+        let operators = 25;
         let delimiters = 6;
         let punctuation = 5;
-        let literals = 8;
+        let literals = 10;
         let ident_comments = 4;
         let special = 2;
         let total = keywords + operators + delimiters + punctuation + literals + ident_comments + special;
-        assert_eq!(total, 97, "TokenKind should have 97 variants");
+        assert_eq!(total, 100, "TokenKind should have 100 variants");
     }
 }
@@ -29,6 +29,48 @@ fn sp() -> FormatIR {
 /// This is the main entry point for converting a parsed Mesh syntax tree
 /// into the format IR that the printer can render.
 pub fn walk_node(node: &SyntaxNode) -> FormatIR {
+    let kind = node.kind();
+    if kind.is_doc_comment_owner() {
+        let docs: Vec<SyntaxToken> = leading_doc_comments(node).collect();
+        if !docs.is_empty() {
+            // One doc comment per line, directly above the item. The item
+            // walkers skip these tokens (see `children_after_docs`).
+            let mut parts = Vec::new();
+            for doc in docs {
+                parts.push(ir::text(doc.text()));
+                parts.push(ir::hardline());
+            }
+            parts.push(walk_item(node));
+            return ir::concat(parts);
+        }
+    }
+    walk_item(node)
+}
+
+/// The `##` doc comments an item owns: its leading DOC_COMMENT tokens.
+fn leading_doc_comments(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    node.children_with_tokens()
+        .map_while(|child| child.into_token())
+        .take_while(|tok| matches!(tok.kind(), SyntaxKind::DOC_COMMENT | SyntaxKind::NEWLINE))
+        .filter(|tok| tok.kind() == SyntaxKind::DOC_COMMENT)
+}
+
+/// Children of `node` after the doc comments it owns, which `walk_node`
+/// emits separately.
+fn children_after_docs(
+    node: &SyntaxNode,
+) -> impl Iterator<Item = NodeOrToken<SyntaxNode, SyntaxToken>> {
+    let owns_docs = node.kind().is_doc_comment_owner();
+    node.children_with_tokens().skip_while(move |child| {
+        owns_docs
+            && matches!(
+                child.as_token().map(|t| t.kind()),
+                Some(SyntaxKind::DOC_COMMENT | SyntaxKind::NEWLINE)
+            )
+    })
+}
+
+fn walk_item(node: &SyntaxNode) -> FormatIR {
     let kind = node.kind();
     match kind {
         SyntaxKind::SOURCE_FILE => walk_source_file(node),
@@ -81,11 +123,14 @@ pub fn walk_node(node: &SyntaxNode) -> FormatIR {
         SyntaxKind::TERMINATE_CLAUSE => walk_terminate_clause(node),
         SyntaxKind::CHILD_SPEC_DEF => walk_block_def(node),
         SyntaxKind::STRUCT_LITERAL => walk_struct_literal(node),
-        SyntaxKind::MAP_LITERAL => walk_map_literal(node),
-        SyntaxKind::MAP_ENTRY => walk_map_entry(node),
+        SyntaxKind::MAP_LITERAL | SyntaxKind::MAP_PAT => walk_map_literal(node),
+        SyntaxKind::MAP_ENTRY | SyntaxKind::MAP_PAT_ENTRY => walk_map_entry(node),
         SyntaxKind::LIST_LITERAL => walk_list_literal(node),
+        SyntaxKind::BYTES_LITERAL | SyntaxKind::BYTES_PAT => walk_bytes(node),
+        SyntaxKind::BYTES_SEGMENT | SyntaxKind::LITERAL_PAT | SyntaxKind::PIN_PAT => walk_unspaced(node),
         SyntaxKind::ASSOC_TYPE_BINDING => walk_assoc_type_binding(node),
         SyntaxKind::TRY_EXPR => walk_tokens_inline(node),
+        SyntaxKind::TEST_DEF => walk_test_def(node),
         // Simple leaf-like nodes: just emit their tokens inline.
         SyntaxKind::LITERAL
         | SyntaxKind::NAME
@@ -95,7 +140,6 @@ pub fn walk_node(node: &SyntaxNode) -> FormatIR {
         | SyntaxKind::VISIBILITY
         | SyntaxKind::WILDCARD_PAT
         | SyntaxKind::IDENT_PAT
-        | SyntaxKind::LITERAL_PAT
         | SyntaxKind::TUPLE_PAT
         | SyntaxKind::STRUCT_PAT
         | SyntaxKind::CONSTRUCTOR_PAT
@@ -187,7 +231,7 @@ fn walk_fn_def(node: &SyntaxNode) -> FormatIR {
     let mut has_block = false;
     let mut has_expr_body = false;
 
-    for child in node.children_with_tokens() {
+    for child in children_after_docs(node) {
         match child {
             NodeOrToken::Token(tok) => {
                 match tok.kind() {
@@ -454,6 +498,51 @@ fn walk_while_expr(node: &SyntaxNode) -> FormatIR {
     ir::concat(parts)
 }
 
+// ── Test block ─────────────────────────────────────────────────────────
+
+fn walk_test_def(node: &SyntaxNode) -> FormatIR {
+    let mut parts = Vec::new();
+
+    for child in node.children_with_tokens() {
+        match child {
+            NodeOrToken::Token(tok) => {
+                match tok.kind() {
+                    SyntaxKind::IDENT => {
+                        parts.push(ir::text("test"));
+                        parts.push(sp());
+                    }
+                    SyntaxKind::DO_KW => {
+                        parts.push(sp());
+                        parts.push(ir::text("do"));
+                    }
+                    SyntaxKind::END_KW => {
+                        parts.push(ir::hardline());
+                        parts.push(ir::text("end"));
+                    }
+                    SyntaxKind::NEWLINE => {}
+                    _ => {
+                        add_token_with_context(&tok, &mut parts);
+                    }
+                }
+            }
+            NodeOrToken::Node(n) => {
+                match n.kind() {
+                    SyntaxKind::BLOCK => {
+                        let body = walk_block_body(&n);
+                        parts.push(ir::indent(ir::concat(vec![ir::hardline(), body])));
+                    }
+                    _ => {
+                        // Test name string.
+                        parts.push(walk_node(&n));
+                    }
+                }
+            }
+        }
+    }
+
+    ir::concat(parts)
+}
+
 // ── For-in expression ──────────────────────────────────────────────────
 
 fn walk_for_in_expr(node: &SyntaxNode) -> FormatIR {
@@ -737,8 +826,9 @@ fn walk_pipe_expr(node: &SyntaxNode) -> FormatIR {
             NodeOrToken::Token(tok) => {
                 match tok.kind() {
                     SyntaxKind::PIPE => {
+                        // `|>` or `|N>`; the position is part of the token.
                         parts.push(ir::hardline());
-                        parts.push(ir::text("|>"));
+                        parts.push(ir::text(tok.text()));
                         parts.push(sp());
                     }
                     SyntaxKind::NEWLINE => {}
@@ -864,7 +954,7 @@ fn walk_block_def(node: &SyntaxNode) -> FormatIR {
     let mut past_do = false;
     let mut inner_items: Vec<FormatIR> = Vec::new();
 
-    for child in node.children_with_tokens() {
+    for child in children_after_docs(node) {
         match child {
             NodeOrToken::Token(tok) => {
                 match tok.kind() {
@@ -975,7 +1065,7 @@ fn walk_struct_def(node: &SyntaxNode) -> FormatIR {
     let mut fields: Vec<FormatIR> = Vec::new();
     let mut in_body = false;
 
-    for child in node.children_with_tokens() {
+    for child in children_after_docs(node) {
         match child {
             NodeOrToken::Token(tok) => {
                 match tok.kind() {
@@ -1874,6 +1964,45 @@ fn walk_list_literal(node: &SyntaxNode) -> FormatIR {
     ir::group(ir::concat(parts))
 }
 
+// ── Binary literal / pattern ────────────────────────────────────────
+
+/// `<<a, b::u16-little, rest::rest>>`: segments separated by `, ` inside
+/// `<<` and `>>` (the empty `<<>>` lexes as `<`, `<>`, `>`).
+fn walk_bytes(node: &SyntaxNode) -> FormatIR {
+    let segments: Vec<FormatIR> = node
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::BYTES_SEGMENT)
+        .map(|n| walk_node(&n))
+        .collect();
+    let mut parts = vec![ir::text("<<")];
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i > 0 {
+            parts.push(ir::text(","));
+            parts.push(sp());
+        }
+        parts.push(segment);
+    }
+    parts.push(ir::text(">>"));
+    ir::group(ir::concat(parts))
+}
+
+/// Nodes written without spaces between their tokens: a binary segment
+/// (`body::bytes(len)`), a literal pattern (`-1`) or a pinned variable (`^id`).
+fn walk_unspaced(node: &SyntaxNode) -> FormatIR {
+    let mut parts = Vec::new();
+    for child in node.children_with_tokens() {
+        match child {
+            NodeOrToken::Token(tok) => {
+                if tok.kind() != SyntaxKind::NEWLINE {
+                    parts.push(ir::text(tok.text()));
+                }
+            }
+            NodeOrToken::Node(n) => parts.push(walk_node(&n)),
+        }
+    }
+    ir::concat(parts)
+}
+
 // ── Associated type binding ─────────────────────────────────────────
 
 fn walk_assoc_type_binding(node: &SyntaxNode) -> FormatIR {
@@ -1948,7 +2077,7 @@ fn walk_block_inner_items(node: &SyntaxNode) -> FormatIR {
 fn walk_tokens_inline(node: &SyntaxNode) -> FormatIR {
     let mut parts = Vec::new();
 
-    for child in node.children_with_tokens() {
+    for child in children_after_docs(node) {
         match child {
             NodeOrToken::Token(tok) => {
                 let kind = tok.kind();
@@ -2097,6 +2226,12 @@ mod tests {
         assert_eq!(result, "fn add(a, b) do\n  a + b\nend\n");
     }
 
+    #[test]
+    fn test_block_def() {
+        let result = fmt("test   \"adds\"   do\nlet x = 1\nassert_eq(x, 1)\nend");
+        assert_eq!(result, "test \"adds\" do\n  let x = 1\n  assert_eq(x, 1)\nend\n");
+    }
+
     #[test]
     fn fn_def_multiple_statements() {
         let result = fmt("fn foo(x) do\nlet y = x + 1\ny\nend");
@@ -2181,6 +2316,49 @@ mod tests {
         assert!(result.contains("bar()"));
     }
 
+    #[test]
+    fn numbered_pipe_expression() {
+        let result = fmt("fn main() do\n\"-\" |2>   String.replace(s,\"_\")\nend");
+        assert_eq!(result, "fn main() do\n  \"-\"\n    |2> String.replace(s, \"_\")\nend\n");
+        assert_eq!(fmt(&result), result);
+    }
+
+    #[test]
+    fn regex_literal_kept_verbatim() {
+        let result = fmt("fn main() do\nlet re =   ~r/ a  \\/ b /ix\nend");
+        assert_eq!(result, "fn main() do\n  let re = ~r/ a  \\/ b /ix\nend\n");
+        assert_eq!(fmt(&result), result);
+    }
+
+    #[test]
+    fn interpolation_format_spec_kept_verbatim() {
+        let result = fmt("fn main() do\nprintln(  \"${price:*>8.2} ${n:x}\")\nend");
+        assert_eq!(result, "fn main() do\n  println(\"${price:*>8.2} ${n:x}\")\nend\n");
+        assert_eq!(fmt(&result), result);
+    }
+
+    #[test]
+    fn bytes_literal_and_pattern() {
+        let src = "fn main() do\nlet b = << 1 , n::u16 - little , body :: bytes( len ) >>\ncase b do\n<< tag , rest :: rest >> -> tag\n<< >> -> 0\nend\nend";
+        let result = fmt(src);
+        assert_eq!(
+            result,
+            "fn main() do\n  let b = <<1, n::u16-little, body::bytes(len)>>\n  case b do\n    <<tag, rest::rest>> -> tag\n    <<>> -> 0\n  end\nend\n"
+        );
+        assert_eq!(fmt(&result), result);
+    }
+
+    #[test]
+    fn map_and_pin_patterns() {
+        let src = "fn main() do\ncase m do\n%{ \"type\"=>\"error\" ,\"id\"  => ^ id , -1=>_ } -> 1\n%{ } -> 0\nend\nend";
+        let result = fmt(src);
+        assert_eq!(
+            result,
+            "fn main() do\n  case m do\n    %{\"type\" => \"error\", \"id\" => ^id, -1 => _} -> 1\n    %{} -> 0\n  end\nend\n"
+        );
+        assert_eq!(fmt(&result), result);
+    }
+
     #[test]
     fn call_with_args() {
         let result = fmt("foo(1, 2, 3)");
@@ -2400,4 +2578,24 @@ mod tests {
         let result = fmt("%{}");
         assert_eq!(result, "%{}\n");
     }
+
+    #[test]
+    fn doc_comments_stay_above_items() {
+        let src = "## Adds two.\n## Second line.\npub fn add(a :: Int, b :: Int) -> Int do\na + b\nend";
+        assert_eq!(
+            fmt(src),
+            "## Adds two.\n## Second line.\npub fn add(a :: Int, b :: Int) -> Int do\n  a + b\nend\n"
+        );
+    }
+
+    #[test]
+    fn doc_comments_on_interface_methods() {
+        let src = "## Greets.\ninterface Greeter do\n## Say hi.\nfn greet(self) -> String\nend";
+        let result = fmt(src);
+        assert_eq!(
+            result,
+            "## Greets.\ninterface Greeter do\n  ## Say hi.\n  fn greet(self) -> String\nend\n"
+        );
+        assert_eq!(fmt(&result), result);
+    }
 }
@@ -31,6 +31,11 @@ impl<'src> Cursor<'src> {
         iter.next()
     }
 
+    /// The unconsumed rest of the source text, for multi-character lookahead.
+    pub fn rest(&self) -> &'src str {
+        self.chars.as_str()
+    }
+
     /// Consume the current character and advance the position.
     ///
     /// Returns the consumed character, or `None` if at end of input.
@@ -12,8 +12,9 @@ enum LexerState {
     Normal,
     /// Inside a string literal (after StringStart emitted).
     InString { triple: bool },
-    /// Inside `${...}` string interpolation.
-    InInterpolation { brace_depth: u32 },
+    /// Inside `${...}` string interpolation. `group_depth` counts open
+    /// parentheses and brackets, inside which a `:` is not a format spec.
+    InInterpolation { brace_depth: u32, group_depth: u32 },
 }
 
 /// The Mesh lexer. Converts source text into a stream of tokens.
@@ -25,7 +26,6 @@ enum LexerState {
 /// Uses a state stack to handle nested string interpolation contexts.
 pub struct Lexer<'src> {
     cursor: Cursor<'src>,
-    #[allow(dead_code)]
     source: &'src str,
     /// Whether we have already emitted the `Eof` token.
     emitted_eof: bool,
@@ -122,6 +122,8 @@ impl<'src> Lexer<'src> {
 
             // ── Single-character operators ─────────────────────────────
             '?' => self.single_char_token(TokenKind::Question, start),
+            '^' => self.single_char_token(TokenKind::Caret, start),
+            '~' => self.lex_regex(start),
 
             // ── Comments ────────────────────────────────────────────────
             '#' => self.lex_comment(start),
@@ -223,7 +225,7 @@ impl<'src> Lexer<'src> {
         }
     }
 
-    /// `||` -> `PipePipe`, `|>` -> `Pipe`, single `|` -> `Bar`
+    /// `||` -> `PipePipe`, `|>` and `|N>` -> `Pipe`, single `|` -> `Bar`
     fn lex_pipe(&mut self, start: u32) -> Token {
         self.cursor.advance(); // consume '|'
         match self.cursor.peek() {
@@ -235,6 +237,20 @@ impl<'src> Lexer<'src> {
                 self.cursor.advance();
                 Token::new(TokenKind::Pipe, start, self.cursor.pos())
             }
+            Some(c) if c.is_ascii_digit() => {
+                // `|2>` pipes into argument 2. Digits not followed by `>`
+                // are an ordinary `|` and an integer.
+                let rest = self.cursor.rest();
+                let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+                if rest.as_bytes().get(digits) == Some(&b'>') {
+                    for _ in 0..=digits {
+                        self.cursor.advance();
+                    }
+                    Token::new(TokenKind::Pipe, start, self.cursor.pos())
+                } else {
+                    Token::new(TokenKind::Bar, start, self.cursor.pos())
+                }
+            }
             _ => Token::new(TokenKind::Bar, start, self.cursor.pos()),
         }
     }
@@ -280,6 +296,43 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// `~r/pattern/flags` -> `Regex`
+    ///
+    /// The pattern runs to the first unescaped `/` on the same line and any
+    /// letters after it are flags. The whole literal is one token; the pattern
+    /// itself is validated by the type checker. A `~` that does not start a
+    /// regex, or a pattern missing its closing `/`, is an `Error` token.
+    fn lex_regex(&mut self, start: u32) -> Token {
+        self.cursor.advance(); // consume '~'
+        if self.cursor.peek() != Some('r') || self.cursor.peek_next() != Some('/') {
+            return Token::new(TokenKind::Error, start, self.cursor.pos());
+        }
+        self.cursor.advance(); // consume 'r'
+        self.cursor.advance(); // consume opening '/'
+        loop {
+            match self.cursor.peek() {
+                Some('/') => {
+                    self.cursor.advance();
+                    break;
+                }
+                Some('\\') => {
+                    self.cursor.advance();
+                    if !matches!(self.cursor.peek(), None | Some('\n') | Some('\r')) {
+                        self.cursor.advance();
+                    }
+                }
+                None | Some('\n') | Some('\r') => {
+                    return Token::new(TokenKind::Error, start, self.cursor.pos());
+                }
+                Some(_) => {
+                    self.cursor.advance();
+                }
+            }
+        }
+        self.cursor.eat_while(|c| c.is_ascii_alphabetic());
+        Token::new(TokenKind::Regex, start, self.cursor.pos())
+    }
+
     /// `.` -> `Dot`, `..` -> `DotDot`
     fn lex_dot(&mut self, start: u32) -> Token {
         self.cursor.advance(); // consume '.'
@@ -510,7 +563,7 @@ impl<'src> Lexer<'src> {
                     // Pop InString, push InString back (we'll return to it),
                     // then push InInterpolation on top.
                     // Actually, we keep InString on the stack and push InInterpolation on top.
-                    self.state_stack.push(LexerState::InInterpolation { brace_depth: 0 });
+                    self.state_stack.push(LexerState::InInterpolation { brace_depth: 0, group_depth: 0 });
 
                     // Queue the InterpolationStart token
                     self.pending.push(Token::new(TokenKind::InterpolationStart, content_end, interp_end));
@@ -587,6 +640,7 @@ impl<'src> Lexer<'src> {
     ///
     /// Tokenizes normally but tracks brace depth. When the closing `}` is
     /// found (at depth 0), emits InterpolationEnd and pops back to InString.
+    /// A `:` after the expression at depth 0 starts a format spec.
     fn lex_interpolation(&mut self) -> Token {
         self.skip_whitespace();
 
@@ -601,13 +655,13 @@ impl<'src> Lexer<'src> {
         match c {
             '{' => {
                 // Increment brace depth
-                if let Some(LexerState::InInterpolation { ref mut brace_depth }) = self.state_stack.last_mut() {
+                if let Some(LexerState::InInterpolation { ref mut brace_depth, .. }) = self.state_stack.last_mut() {
                     *brace_depth += 1;
                 }
                 self.single_char_token(TokenKind::LBrace, start)
             }
             '}' => {
-                let brace_depth = if let Some(LexerState::InInterpolation { brace_depth }) = self.state_stack.last() {
+                let brace_depth = if let Some(LexerState::InInterpolation { brace_depth, .. }) = self.state_stack.last() {
                     *brace_depth
                 } else {
                     0
@@ -621,7 +675,7 @@ impl<'src> Lexer<'src> {
                     Token::new(TokenKind::InterpolationEnd, start, end)
                 } else {
                     // Decrement brace depth
-                    if let Some(LexerState::InInterpolation { ref mut brace_depth }) = self.state_stack.last_mut() {
+                    if let Some(LexerState::InInterpolation { ref mut brace_depth, .. }) = self.state_stack.last_mut() {
                         *brace_depth -= 1;
                     }
                     self.single_char_token(TokenKind::RBrace, start)
@@ -639,12 +693,26 @@ impl<'src> Lexer<'src> {
                 }
                 Token::new(TokenKind::Newline, start, self.cursor.pos())
             }
+            ':' if self.at_format_spec(start) => self.lex_format_spec(start),
+            '(' | '[' | ')' | ']' => {
+                if let Some(LexerState::InInterpolation { ref mut group_depth, .. }) = self.state_stack.last_mut() {
+                    *group_depth = if matches!(c, '(' | '[') {
+                        *group_depth + 1
+                    } else {
+                        group_depth.saturating_sub(1)
+                    };
+                }
+                let kind = match c {
+                    '(' => TokenKind::LParen,
+                    ')' => TokenKind::RParen,
+                    '[' => TokenKind::LBracket,
+                    _ => TokenKind::RBracket,
+                };
+                self.single_char_token(kind, start)
+            }
             // All other tokens: delegate to normal tokenization helpers
             '?' => self.single_char_token(TokenKind::Question, start),
-            '(' => self.single_char_token(TokenKind::LParen, start),
-            ')' => self.single_char_token(TokenKind::RParen, start),
-            '[' => self.single_char_token(TokenKind::LBracket, start),
-            ']' => self.single_char_token(TokenKind::RBracket, start),
+            '^' => self.single_char_token(TokenKind::Caret, start),
             ',' => self.single_char_token(TokenKind::Comma, start),
             ';' => self.single_char_token(TokenKind::Semicolon, start),
             '=' => self.lex_eq(start),
@@ -657,6 +725,7 @@ impl<'src> Lexer<'src> {
             '-' => self.lex_minus(start),
             ':' => self.lex_colon(start),
             '.' => self.lex_dot(start),
+            '~' => self.lex_regex(start),
             '*' => self.single_char_token(TokenKind::Star, start),
             '/' => self.single_char_token(TokenKind::Slash, start),
             '%' => self.single_char_token(TokenKind::Percent, start),
@@ -671,6 +740,24 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Whether the `:` at `start` begins a format spec: it is outside any
+    /// nested braces, parentheses or brackets, follows the start of the
+    /// expression, and is not part of `::`.
+    fn at_format_spec(&self, start: u32) -> bool {
+        let Some(LexerState::InInterpolation { brace_depth: 0, group_depth: 0 }) = self.state_stack.last() else {
+            return false;
+        };
+        self.cursor.peek_next() != Some(':') && !self.source[..start as usize].trim_end().ends_with("${")
+    }
+
+    /// `:spec` -> `FormatSpec`, running up to the `}` that closes the
+    /// interpolation. The spec itself is validated by the type checker.
+    fn lex_format_spec(&mut self, start: u32) -> Token {
+        self.cursor.advance(); // consume ':'
+        self.cursor.eat_while(|c| !matches!(c, '}' | '"' | '\n' | '\r'));
+        Token::new(TokenKind::FormatSpec, start, self.cursor.pos())
+    }
+
     // ── Identifiers and keywords ──────────────────────────────────────
 
     /// Lex an identifier or keyword.
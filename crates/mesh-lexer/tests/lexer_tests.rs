@@ -91,6 +91,36 @@ fn test_mixed_expression() {
     assert_yaml_snapshot!(tokens);
 }
 
+#[test]
+fn test_numbered_pipe() {
+    // `|2>` is one token; `|2` without `>` is a bar and an integer.
+    let tokens = tokenize_snapshot("s |2> replace(a, b) |12> f() |2 x");
+    assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn test_regex_literal() {
+    // `\/` does not close the pattern; trailing letters are flags. A `~`
+    // outside `~r/` and an unterminated pattern are errors.
+    let tokens = tokenize_snapshot("~r/a\\/b/ix ~r/[0-9]+/ ~x ~r/open\nx");
+    assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn test_pin_operator() {
+    let tokens = tokenize_snapshot("^id => ^x");
+    assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn test_interpolation_format_spec() {
+    // A `:` at the top level of an interpolation starts a format spec that
+    // runs to the `}`. Colons inside calls, `::` annotations and atoms at the
+    // start of the expression stay ordinary tokens.
+    let tokens = tokenize_snapshot(r#""${price:.2} ${id:*>8} ${f(a: 1)} ${x::Int} ${:ok}""#);
+    assert_yaml_snapshot!(tokens);
+}
+
 #[test]
 fn test_spans_accurate() {
     let tokens = tokenize_snapshot("let x = 42");
@@ -58,6 +58,14 @@ pub fn analyze_document(_uri: &str, source: &str) -> AnalysisResult {
         }
     }
 
+    // Convert lint findings to LSP diagnostics, at their default levels.
+    let lint_config = mesh_typeck::lint::LintConfig::default();
+    for finding in mesh_typeck::lint::lint(&parse, &typeck, &lint_config) {
+        if let Some(diag) = type_error_to_diagnostic(source, &finding, DiagnosticSeverity::WARNING) {
+            diagnostics.push(diag);
+        }
+    }
+
     AnalysisResult {
         diagnostics,
         parse,
@@ -215,6 +223,12 @@ fn type_error_span(error: &TypeError) -> Option<TextRange> {
         TypeError::MissingAssocType { .. } => None,
         TypeError::ExtraAssocType { .. } => None,
         TypeError::UnresolvedAssocType { span, .. } => Some(*span),
+        TypeError::PipePositionOutOfRange { span, .. } => Some(*span),
+        TypeError::InvalidRegex { span, .. } => Some(*span),
+        TypeError::TupleAccess { span, .. } => Some(*span),
+        TypeError::InvalidBytesSegment { span, .. } => Some(*span),
+        TypeError::InvalidFormatSpec { span, .. } => Some(*span),
+        TypeError::Lint { span, .. } => Some(*span),
     }
 }
 
@@ -273,7 +287,7 @@ mod tests {
 
     #[test]
     fn analyze_valid_function_no_diagnostics() {
-        let source = "fn add(a, b) do\na + b\nend";
+        let source = "pub fn add(a, b) do\na + b\nend";
         let result = analyze_document("file:///test.mpl", source);
         assert!(
             result.diagnostics.is_empty(),
@@ -282,6 +296,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn analyze_lint_produces_warning() {
+        let source = "pub fn add(a, b) do\nlet unused = 1\na + b\nend";
+        let result = analyze_document("file:///test.mpl", source);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(result.diagnostics[0].message, "unused variable `unused`");
+    }
+
     #[test]
     fn analyze_type_error_produces_diagnostic() {
         // Using an undefined variable should produce a type error diagnostic.
@@ -5,6 +5,7 @@
 //! if/else, case/match, closures, blocks, strings, return, and tuples.
 
 use crate::ast::item::{Block, GuardClause, ParamList};
+use crate::ast::pat::BytesSegment;
 use crate::ast::{ast_node, child_node, child_nodes, child_token, AstNode};
 use crate::cst::{SyntaxNode, SyntaxToken};
 use crate::syntax_kind::SyntaxKind;
@@ -47,6 +48,10 @@ pub enum Expr {
     TryExpr(TryExpr),
     // Atom literal
     AtomLiteral(AtomLiteral),
+    // Regex literal
+    RegexLiteral(RegexLiteral),
+    // Binary construction
+    BytesLiteral(BytesLiteral),
     // Struct update expression
     StructUpdate(StructUpdate),
 }
@@ -93,6 +98,8 @@ impl Expr {
             SyntaxKind::LINK_EXPR => Some(Expr::LinkExpr(LinkExpr { syntax: node })),
             SyntaxKind::TRY_EXPR => Some(Expr::TryExpr(TryExpr { syntax: node })),
             SyntaxKind::ATOM_EXPR => Some(Expr::AtomLiteral(AtomLiteral { syntax: node })),
+            SyntaxKind::REGEX_EXPR => Some(Expr::RegexLiteral(RegexLiteral { syntax: node })),
+            SyntaxKind::BYTES_LITERAL => Some(Expr::BytesLiteral(BytesLiteral { syntax: node })),
             SyntaxKind::STRUCT_UPDATE_EXPR => {
                 Some(Expr::StructUpdate(StructUpdate { syntax: node }))
             }
@@ -132,6 +139,8 @@ impl Expr {
             Expr::LinkExpr(n) => &n.syntax,
             Expr::TryExpr(n) => &n.syntax,
             Expr::AtomLiteral(n) => &n.syntax,
+            Expr::RegexLiteral(n) => &n.syntax,
+            Expr::BytesLiteral(n) => &n.syntax,
             Expr::StructUpdate(n) => &n.syntax,
         }
     }
@@ -273,6 +282,26 @@ impl PipeExpr {
     pub fn rhs(&self) -> Option<Expr> {
         self.syntax.children().filter_map(Expr::cast).nth(1)
     }
+
+    /// The operator token: `|>`, or `|N>` for an explicit argument position.
+    pub fn op(&self) -> Option<SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|t| t.kind() == SyntaxKind::PIPE)
+    }
+
+    /// The 1-based argument position the piped value is passed in: 1 for
+    /// `|>`, N for `|N>`. `None` if N is too large to represent.
+    pub fn position(&self) -> Option<usize> {
+        let op = self.op()?;
+        let digits = op.text().trim_start_matches('|').trim_end_matches('>');
+        if digits.is_empty() {
+            Some(1)
+        } else {
+            digits.parse().ok()
+        }
+    }
 }
 
 // ── Field Access ─────────────────────────────────────────────────────────
@@ -484,6 +513,32 @@ impl ClosureClause {
 
 ast_node!(StringExpr, STRING_EXPR);
 
+impl StringExpr {
+    /// The `${...}` segments, in order.
+    pub fn interpolations(&self) -> impl Iterator<Item = Interpolation> + '_ {
+        child_nodes(&self.syntax)
+    }
+}
+
+ast_node!(Interpolation, INTERPOLATION);
+
+impl Interpolation {
+    /// The interpolated expression.
+    pub fn expr(&self) -> Option<Expr> {
+        self.syntax.children().find_map(Expr::cast)
+    }
+
+    /// The format spec token, including its leading `:` (`:.2` in `${price:.2}`).
+    pub fn format_spec(&self) -> Option<SyntaxToken> {
+        child_token(&self.syntax, SyntaxKind::FORMAT_SPEC)
+    }
+
+    /// The format spec text after the `:`, if one is written.
+    pub fn format_spec_text(&self) -> Option<String> {
+        self.format_spec().map(|t| t.text()[1..].to_string())
+    }
+}
+
 // ── Return Expression ────────────────────────────────────────────────────
 
 ast_node!(ReturnExpr, RETURN_EXPR);
@@ -793,3 +848,44 @@ impl AtomLiteral {
         })
     }
 }
+
+// ── Regex Literal Expression ────────────────────────────────────────────
+
+ast_node!(RegexLiteral, REGEX_EXPR);
+
+impl RegexLiteral {
+    /// The pattern between the slashes, with `\/` unescaped to `/`.
+    ///
+    /// For `~r/a\/b/i`, returns `Some("a/b")`. Other escapes are kept for
+    /// the regex engine.
+    pub fn pattern(&self) -> Option<String> {
+        let text = self.token_text()?;
+        let body = text.strip_prefix("~r/")?;
+        let end = body.rfind('/')?;
+        Some(body[..end].replace("\\/", "/"))
+    }
+
+    /// The flag letters after the closing slash.
+    ///
+    /// For `~r/abc/im`, returns `"im"`.
+    pub fn flags(&self) -> String {
+        self.token_text()
+            .and_then(|text| text.rfind('/').map(|end| text[end + 1..].to_string()))
+            .unwrap_or_default()
+    }
+
+    fn token_text(&self) -> Option<String> {
+        child_token(&self.syntax, SyntaxKind::REGEX_LITERAL).map(|t| t.text().to_string())
+    }
+}
+
+// ── Bytes Literal Expression ────────────────────────────────────────────
+
+ast_node!(BytesLiteral, BYTES_LITERAL);
+
+impl BytesLiteral {
+    /// The segments between `<<` and `>>`, in order.
+    pub fn segments(&self) -> impl Iterator<Item = BytesSegment> + '_ {
+        child_nodes(&self.syntax)
+    }
+}
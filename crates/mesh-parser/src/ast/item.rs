@@ -2,7 +2,8 @@
 //!
 //! Covers: SourceFile, FnDef, ParamList, Param, TypeAnnotation, ModuleDef,
 //! ImportDecl, FromImportDecl, ImportList, StructDef, StructField, LetBinding,
-//! Visibility, Block, Name, NameRef, Path, SumTypeDef, VariantDef, VariantField.
+//! Visibility, Block, Name, NameRef, Path, SumTypeDef, VariantDef, VariantField,
+//! TestDef.
 
 use crate::ast::{ast_node, child_node, child_nodes, child_token, AstNode};
 use crate::cst::{SyntaxNode, SyntaxToken};
@@ -27,6 +28,16 @@ impl SourceFile {
     pub fn modules(&self) -> impl Iterator<Item = ModuleDef> + '_ {
         child_nodes(&self.syntax)
     }
+
+    /// The module's `##!` doc comment, if any.
+    pub fn module_doc(&self) -> Option<String> {
+        let lines = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| t.kind() == SyntaxKind::MODULE_DOC_COMMENT);
+        super::comment_text(lines, "##!")
+    }
 }
 
 // ── Item enum ────────────────────────────────────────────────────────────
@@ -47,6 +58,7 @@ pub enum Item {
     ActorDef(ActorDef),
     ServiceDef(ServiceDef),
     SupervisorDef(SupervisorDef),
+    TestDef(TestDef),
 }
 
 impl Item {
@@ -77,6 +89,7 @@ impl Item {
             SyntaxKind::SUPERVISOR_DEF => {
                 Some(Item::SupervisorDef(SupervisorDef { syntax: node }))
             }
+            SyntaxKind::TEST_DEF => Some(Item::TestDef(TestDef { syntax: node })),
             _ => None,
         }
     }
@@ -87,6 +100,11 @@ impl Item {
 ast_node!(FnDef, FN_DEF);
 
 impl FnDef {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The visibility modifier (`pub`), if present.
     pub fn visibility(&self) -> Option<Visibility> {
         child_node(&self.syntax)
@@ -259,6 +277,11 @@ impl ImportList {
 ast_node!(StructDef, STRUCT_DEF);
 
 impl StructDef {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The visibility modifier, if present.
     pub fn visibility(&self) -> Option<Visibility> {
         child_node(&self.syntax)
@@ -546,6 +569,11 @@ impl Path {
 ast_node!(InterfaceDef, INTERFACE_DEF);
 
 impl InterfaceDef {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The visibility modifier, if present.
     pub fn visibility(&self) -> Option<Visibility> {
         child_node(&self.syntax)
@@ -570,6 +598,11 @@ impl InterfaceDef {
 ast_node!(InterfaceMethod, INTERFACE_METHOD);
 
 impl InterfaceMethod {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The method name.
     pub fn name(&self) -> Option<Name> {
         child_node(&self.syntax)
@@ -676,6 +709,11 @@ impl TypeAliasDef {
 ast_node!(SumTypeDef, SUM_TYPE_DEF);
 
 impl SumTypeDef {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The visibility modifier, if present.
     pub fn visibility(&self) -> Option<Visibility> {
         child_node(&self.syntax)
@@ -794,6 +832,33 @@ impl ActorDef {
     }
 }
 
+// ── Test Definition ─────────────────────────────────────────────────────
+
+ast_node!(TestDef, TEST_DEF);
+
+impl TestDef {
+    /// The test name (the string literal after `test`), with quotes stripped.
+    pub fn name(&self) -> Option<String> {
+        let string = self
+            .syntax
+            .children()
+            .find(|n| n.kind() == SyntaxKind::STRING_EXPR)?;
+        Some(
+            string
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|t| t.kind() == SyntaxKind::STRING_CONTENT)
+                .map(|t| t.text().to_string())
+                .collect(),
+        )
+    }
+
+    /// The test body block.
+    pub fn body(&self) -> Option<Block> {
+        child_node(&self.syntax)
+    }
+}
+
 // ── Terminate Clause ─────────────────────────────────────────────────────
 
 ast_node!(TerminateClause, TERMINATE_CLAUSE);
@@ -908,6 +973,11 @@ impl SupervisorDef {
 ast_node!(ServiceDef, SERVICE_DEF);
 
 impl ServiceDef {
+    /// The `##` doc comment above this item, if any.
+    pub fn doc_comment(&self) -> Option<String> {
+        super::doc_comment(&self.syntax)
+    }
+
     /// The service name.
     pub fn name(&self) -> Option<Name> {
         child_node(&self.syntax)
@@ -23,6 +23,7 @@ pub mod pat;
 
 use crate::cst::{SyntaxNode, SyntaxToken};
 use crate::syntax_kind::SyntaxKind;
+use rowan::TextRange;
 
 /// Trait for typed AST nodes that wrap a rowan `SyntaxNode`.
 ///
@@ -85,3 +86,41 @@ pub fn child_token(parent: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxToken>
         .filter_map(|it| it.into_token())
         .find(|it| it.kind() == kind)
 }
+
+/// The `##` doc comment owned by `node` (see
+/// [`SyntaxKind::is_doc_comment_owner`]), or `None` if it has none.
+///
+/// Lines are joined with `\n`, each without its `##` marker and the single
+/// space after it.
+pub fn doc_comment(node: &SyntaxNode) -> Option<String> {
+    let lines = node
+        .children_with_tokens()
+        .map_while(|it| it.into_token())
+        .take_while(|t| t.kind().is_trivia())
+        .filter(|t| t.kind() == SyntaxKind::DOC_COMMENT);
+    comment_text(lines, "##")
+}
+
+/// Text range of an item without its leading doc comments, so that e.g. the
+/// definition line of a documented function is the line of its `fn`.
+pub fn item_range(node: &SyntaxNode) -> TextRange {
+    let start = node
+        .children_with_tokens()
+        .find(|it| !it.as_token().is_some_and(|t| t.kind().is_trivia()))
+        .map_or(node.text_range().start(), |it| it.text_range().start());
+    TextRange::new(start, node.text_range().end())
+}
+
+/// Join comment tokens into text, stripping `marker` and one space per line.
+pub(crate) fn comment_text(
+    tokens: impl Iterator<Item = SyntaxToken>,
+    marker: &str,
+) -> Option<String> {
+    let lines: Vec<String> = tokens
+        .map(|t| {
+            let text = t.text().strip_prefix(marker).unwrap_or(t.text());
+            text.strip_prefix(' ').unwrap_or(text).trim_end().to_string()
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
@@ -1,8 +1,11 @@
 //! Typed AST nodes for patterns.
 //!
-//! Covers: WildcardPat, IdentPat, LiteralPat, TuplePat, ConstructorPat, OrPat, AsPat.
+//! Covers: WildcardPat, IdentPat, LiteralPat, TuplePat, ConstructorPat, OrPat, AsPat,
+//! ConsPat, BytesPat (with the BytesSegment shared by binary construction), MapPat
+//! and PinPat.
 
-use crate::ast::{ast_node, child_token, AstNode};
+use crate::ast::expr::Expr;
+use crate::ast::{ast_node, child_nodes, child_token, AstNode};
 use crate::cst::{SyntaxNode, SyntaxToken};
 use crate::syntax_kind::SyntaxKind;
 
@@ -19,6 +22,9 @@ pub enum Pattern {
     Or(OrPat),
     As(AsPat),
     Cons(ConsPat),
+    Bytes(BytesPat),
+    Map(MapPat),
+    Pin(PinPat),
 }
 
 impl Pattern {
@@ -34,6 +40,9 @@ impl Pattern {
             SyntaxKind::OR_PAT => Some(Pattern::Or(OrPat { syntax: node })),
             SyntaxKind::AS_PAT => Some(Pattern::As(AsPat { syntax: node })),
             SyntaxKind::CONS_PAT => Some(Pattern::Cons(ConsPat { syntax: node })),
+            SyntaxKind::BYTES_PAT => Some(Pattern::Bytes(BytesPat { syntax: node })),
+            SyntaxKind::MAP_PAT => Some(Pattern::Map(MapPat { syntax: node })),
+            SyntaxKind::PIN_PAT => Some(Pattern::Pin(PinPat { syntax: node })),
             _ => None,
         }
     }
@@ -49,6 +58,9 @@ impl Pattern {
             Pattern::Or(n) => &n.syntax,
             Pattern::As(n) => &n.syntax,
             Pattern::Cons(n) => &n.syntax,
+            Pattern::Bytes(n) => &n.syntax,
+            Pattern::Map(n) => &n.syntax,
+            Pattern::Pin(n) => &n.syntax,
         }
     }
 }
@@ -196,6 +208,175 @@ impl ConsPat {
     }
 }
 
+// ── Binary Pattern ──────────────────────────────────────────────────────
+
+ast_node!(BytesPat, BYTES_PAT);
+
+impl BytesPat {
+    /// The segments between `<<` and `>>`, in order.
+    pub fn segments(&self) -> impl Iterator<Item = BytesSegment> + '_ {
+        child_nodes(&self.syntax)
+    }
+}
+
+/// The type of a binary segment, read from its `::type-modifier` spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentType {
+    /// `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32` or `i64`;
+    /// big-endian unless marked `-little`.
+    Int { bits: u32, signed: bool, little: bool },
+    /// `bytes` or `bytes(size)`.
+    Bytes,
+    /// `rest`: every remaining byte.
+    Rest,
+}
+
+ast_node!(BytesSegment, BYTES_SEGMENT);
+
+impl BytesSegment {
+    /// The segment's value in a binary pattern (`len` in `len::u16`).
+    pub fn pattern(&self) -> Option<Pattern> {
+        self.syntax.children().find_map(Pattern::cast)
+    }
+
+    /// The segment's value in a binary construction (`n + 1` in `n + 1::u8`).
+    pub fn value(&self) -> Option<Expr> {
+        self.syntax
+            .children_with_tokens()
+            .take_while(|it| it.kind() != SyntaxKind::COLON_COLON)
+            .filter_map(|it| it.into_node())
+            .find_map(Expr::cast)
+    }
+
+    /// The size expression in parentheses (`len` in `payload::bytes(len)`).
+    pub fn size(&self) -> Option<Expr> {
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|it| it.kind() != SyntaxKind::L_PAREN)
+            .filter_map(|it| it.into_node())
+            .find_map(Expr::cast)
+    }
+
+    /// The segment type name after `::`, if one is written.
+    pub fn type_name(&self) -> Option<SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .skip_while(|t| t.kind() != SyntaxKind::COLON_COLON)
+            .find(|t| t.kind() == SyntaxKind::IDENT)
+    }
+
+    /// The `-modifier` names after the type (`little` in `n::u32-little`).
+    pub fn modifiers(&self) -> Vec<SyntaxToken> {
+        let tokens: Vec<SyntaxToken> = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| !t.kind().is_trivia())
+            .collect();
+        tokens
+            .windows(2)
+            .filter(|w| w[0].kind() == SyntaxKind::MINUS && w[1].kind() == SyntaxKind::IDENT)
+            .map(|w| w[1].clone())
+            .collect()
+    }
+
+    /// Classify the segment from its type name and modifiers.
+    ///
+    /// A segment without `::type` is a `u8`. Returns a message describing
+    /// the problem for an unknown type or modifier.
+    pub fn segment_type(&self) -> Result<SegmentType, String> {
+        let name = self
+            .type_name()
+            .map(|t| t.text().to_string())
+            .unwrap_or_else(|| "u8".to_string());
+        let modifiers = self.modifiers();
+        let ty = match name.as_str() {
+            "bytes" => SegmentType::Bytes,
+            "rest" => SegmentType::Rest,
+            _ => {
+                let (signed, bits) = match (name.strip_prefix('u'), name.strip_prefix('i')) {
+                    (Some(bits), _) => (false, bits),
+                    (_, Some(bits)) => (true, bits),
+                    _ => (false, ""),
+                };
+                match bits {
+                    "8" | "16" | "32" | "64" => SegmentType::Int {
+                        bits: bits.parse().unwrap(),
+                        signed,
+                        little: false,
+                    },
+                    _ => {
+                        return Err(format!(
+                            "unknown segment type `{}`; expected u8, u16, u32, u64, i8, i16, i32, i64, bytes or rest",
+                            name
+                        ))
+                    }
+                }
+            }
+        };
+        let mut little = false;
+        for modifier in &modifiers {
+            match (ty, modifier.text()) {
+                (SegmentType::Int { .. }, "big") => little = false,
+                (SegmentType::Int { .. }, "little") => little = true,
+                (SegmentType::Int { .. }, other) => {
+                    return Err(format!("unknown modifier `-{}`; expected `-big` or `-little`", other))
+                }
+                (_, other) => return Err(format!("`{}` segments take no modifiers, found `-{}`", name, other)),
+            }
+        }
+        Ok(match ty {
+            SegmentType::Int { bits, signed, .. } => SegmentType::Int { bits, signed, little },
+            other => other,
+        })
+    }
+}
+
+// ── Map Pattern ─────────────────────────────────────────────────────────
+
+ast_node!(MapPat, MAP_PAT);
+
+impl MapPat {
+    /// The `key => pattern` entries, in order.
+    pub fn entries(&self) -> impl Iterator<Item = MapPatEntry> + '_ {
+        child_nodes(&self.syntax)
+    }
+}
+
+ast_node!(MapPatEntry, MAP_PAT_ENTRY);
+
+impl MapPatEntry {
+    /// The literal key (`"id"` in `"id" => id`).
+    pub fn key(&self) -> Option<LiteralPat> {
+        self.syntax
+            .children_with_tokens()
+            .take_while(|it| it.kind() != SyntaxKind::FAT_ARROW)
+            .filter_map(|it| it.into_node())
+            .find_map(LiteralPat::cast)
+    }
+
+    /// The pattern the key's value must match (`id` in `"id" => id`).
+    pub fn value(&self) -> Option<Pattern> {
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|it| it.kind() != SyntaxKind::FAT_ARROW)
+            .filter_map(|it| it.into_node())
+            .find_map(Pattern::cast)
+    }
+}
+
+// ── Pin Pattern ─────────────────────────────────────────────────────────
+
+ast_node!(PinPat, PIN_PAT);
+
+impl PinPat {
+    /// The pinned variable (`expected` in `^expected`).
+    pub fn name(&self) -> Option<SyntaxToken> {
+        child_token(&self.syntax, SyntaxKind::IDENT)
+    }
+}
+
 // ── As Pattern ──────────────────────────────────────────────────────────
 
 ast_node!(AsPat, AS_PAT);
@@ -10,6 +10,7 @@ use mesh_common::span::Span;
 
 use crate::syntax_kind::SyntaxKind;
 
+use super::patterns::parse_bytes_segment_spec;
 use super::{MarkClosed, Parser};
 
 // ── Binding Power Tables ───────────────────────────────────────────────
@@ -233,6 +234,13 @@ fn lhs(p: &mut Parser) -> Option<MarkClosed> {
             Some(p.close(m, SyntaxKind::ATOM_EXPR))
         }
 
+        // Regex literal
+        SyntaxKind::REGEX_LITERAL => {
+            let m = p.open();
+            p.advance();
+            Some(p.close(m, SyntaxKind::REGEX_EXPR))
+        }
+
         // Identifier
         SyntaxKind::IDENT => {
             let m = p.open();
@@ -335,6 +343,11 @@ fn lhs(p: &mut Parser) -> Option<MarkClosed> {
         }
         SyntaxKind::LINK_KW => Some(parse_link_expr(p)),
 
+        // Binary construction: <<len::u16, payload::bytes>>
+        SyntaxKind::LT if matches!(p.nth(1), SyntaxKind::LT | SyntaxKind::DIAMOND) => {
+            Some(parse_bytes_literal(p))
+        }
+
         _ => {
             p.error("expected expression");
             None
@@ -342,6 +355,43 @@ fn lhs(p: &mut Parser) -> Option<MarkClosed> {
     }
 }
 
+// ── Bytes Literal ─────────────────────────────────────────────────
+
+/// Segment values bind tighter than comparisons, so the closing `>>` ends
+/// the segment instead of being read as a `>` operator.
+const BYTES_SEGMENT_BP: u8 = 13;
+
+/// Parse a binary construction: `<<len::u16, payload::bytes(len)>>`.
+///
+/// Uses the same segment syntax as binary patterns (see
+/// `patterns::parse_bytes_segment_spec`), with expressions as values.
+fn parse_bytes_literal(p: &mut Parser) -> MarkClosed {
+    let m = p.open();
+    p.advance(); // <
+    if p.eat(SyntaxKind::DIAMOND) {
+        // `<<>>` lexes as `<`, `<>`, `>`.
+        p.expect(SyntaxKind::GT);
+        return p.close(m, SyntaxKind::BYTES_LITERAL);
+    }
+    p.advance(); // <
+
+    if !p.at(SyntaxKind::GT) {
+        loop {
+            let seg = p.open();
+            expr_bp(p, BYTES_SEGMENT_BP);
+            parse_bytes_segment_spec(p);
+            p.close(seg, SyntaxKind::BYTES_SEGMENT);
+            if p.has_error() || !p.eat(SyntaxKind::COMMA) {
+                break;
+            }
+        }
+    }
+
+    p.expect(SyntaxKind::GT);
+    p.expect(SyntaxKind::GT);
+    p.close(m, SyntaxKind::BYTES_LITERAL)
+}
+
 // ── Map Literal ───────────────────────────────────────────────────
 
 /// Parse a map literal or struct update expression.
@@ -623,7 +673,7 @@ fn parse_keyword_entry(p: &mut Parser) {
 /// Parse a string expression, which may contain interpolation segments.
 ///
 /// String tokens from the lexer look like:
-///   STRING_START  STRING_CONTENT?  (INTERPOLATION_START expr INTERPOLATION_END STRING_CONTENT?)*  STRING_END
+///   STRING_START  STRING_CONTENT?  (INTERPOLATION_START expr FORMAT_SPEC? INTERPOLATION_END STRING_CONTENT?)*  STRING_END
 fn parse_string_expr(p: &mut Parser) -> MarkClosed {
     let m = p.open();
     p.advance(); // STRING_START
@@ -637,6 +687,7 @@ fn parse_string_expr(p: &mut Parser) -> MarkClosed {
                 let interp = p.open();
                 p.advance(); // ${
                 expr_bp(p, 0);
+                p.eat(SyntaxKind::FORMAT_SPEC);
                 p.expect(SyntaxKind::INTERPOLATION_END);
                 p.close(interp, SyntaxKind::INTERPOLATION);
             }
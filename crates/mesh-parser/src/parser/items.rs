@@ -1164,6 +1164,66 @@ fn parse_item_block_body(p: &mut Parser) {
     p.close(m, SyntaxKind::BLOCK);
 }
 
+// ── Test Definition ─────────────────────────────────────────────────────
+
+/// Parse a test block: `test "name" do body end`
+///
+/// `test` is a contextual keyword (lexed as IDENT) and is only treated as a
+/// test declaration when followed by a string literal. The name must be a
+/// plain string -- interpolation is rejected since test names are static.
+pub(crate) fn parse_test_def(p: &mut Parser) {
+    let m = p.open();
+
+    p.advance(); // "test" IDENT
+
+    // Test name: a string literal without interpolation.
+    let name = p.open();
+    p.advance(); // STRING_START
+    loop {
+        match p.current() {
+            SyntaxKind::STRING_CONTENT => p.advance(),
+            SyntaxKind::STRING_END => {
+                p.advance();
+                break;
+            }
+            SyntaxKind::INTERPOLATION_START => {
+                p.error("test names cannot contain interpolation");
+                break;
+            }
+            _ => {
+                p.error("unterminated string");
+                break;
+            }
+        }
+    }
+    p.close(name, SyntaxKind::STRING_EXPR);
+
+    if p.has_error() {
+        p.close(m, SyntaxKind::TEST_DEF);
+        return;
+    }
+
+    // Expect `do`.
+    let do_span = p.current_span();
+    p.expect(SyntaxKind::DO_KW);
+
+    if !p.has_error() {
+        parse_item_block_body(p);
+    }
+
+    if !p.at(SyntaxKind::END_KW) {
+        p.error_with_related(
+            "expected `end` to close test body",
+            do_span,
+            "`do` block started here",
+        );
+    } else {
+        p.advance(); // END_KW
+    }
+
+    p.close(m, SyntaxKind::TEST_DEF);
+}
+
 // ── Actor Definition ────────────────────────────────────────────────────
 
 /// Parse an actor block definition: `actor Name(params) do body [terminate do ... end] end`
@@ -470,6 +470,11 @@ impl<'src> Parser<'src> {
         let mut builder = rowan::GreenNodeBuilder::new();
         let mut token_pos: usize = 0;
         let mut forward_parents: Vec<(usize, SyntaxKind)> = Vec::new();
+        // Start of the run of doc comments just emitted at the current
+        // level, and the newlines seen since the last one. A blank line or
+        // any other token ends the run.
+        let mut doc_start: Option<rowan::Checkpoint> = None;
+        let mut doc_newlines = 0;
 
         let mut i = 0;
         while i < self.events.len() {
@@ -526,13 +531,21 @@ impl<'src> Parser<'src> {
                                 builder.start_node(rowan::SyntaxKind(fk as u16));
                             }
                         }
+                        doc_start = None;
                     } else if kind != SyntaxKind::TOMBSTONE {
-                        builder.start_node(rowan::SyntaxKind(kind as u16));
+                        // Doc comment owners adopt the doc comments above them.
+                        match doc_start.take() {
+                            Some(checkpoint) if kind.is_doc_comment_owner() => {
+                                builder.start_node_at(checkpoint, rowan::SyntaxKind(kind as u16));
+                            }
+                            _ => builder.start_node(rowan::SyntaxKind(kind as u16)),
+                        }
                     }
                     // TOMBSTONE nodes are silently skipped.
                 }
                 Event::Close => {
                     builder.finish_node();
+                    doc_start = None;
                 }
                 Event::Advance => {
                     if token_pos < self.tokens.len() {
@@ -540,6 +553,21 @@ impl<'src> Parser<'src> {
                         let syntax_kind = SyntaxKind::from(token.kind.clone());
                         let text =
                             &self.source[token.span.start as usize..token.span.end as usize];
+                        match syntax_kind {
+                            SyntaxKind::DOC_COMMENT => {
+                                if doc_start.is_none() {
+                                    doc_start = Some(builder.checkpoint());
+                                }
+                                doc_newlines = 0;
+                            }
+                            SyntaxKind::NEWLINE if doc_start.is_some() => {
+                                doc_newlines += 1;
+                                if doc_newlines > 1 {
+                                    doc_start = None;
+                                }
+                            }
+                            _ => doc_start = None,
+                        }
                         builder.token(rowan::SyntaxKind(syntax_kind as u16), text);
                         token_pos += 1;
                     }
@@ -666,6 +694,14 @@ pub(crate) fn parse_item_or_stmt(p: &mut Parser) {
             items::parse_from_import_decl(p);
         }
 
+        // "test" is an IDENT, not a keyword -- only a test block when a
+        // string literal follows, so `test` stays usable as a variable name.
+        SyntaxKind::IDENT
+            if p.current_text() == "test" && p.nth(1) == SyntaxKind::STRING_START =>
+        {
+            items::parse_test_def(p);
+        }
+
         SyntaxKind::STRUCT_KW => items::parse_struct_def(p),
 
         SyntaxKind::INTERFACE_KW => items::parse_interface_def(p),
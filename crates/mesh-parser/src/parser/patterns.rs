@@ -2,7 +2,8 @@
 //!
 //! Parses patterns used in match arms, let bindings, and destructuring.
 //! Patterns include: wildcard (`_`), identifier, literal, tuple, struct,
-//! constructor, or-pattern, as-pattern, and cons-pattern.
+//! constructor, or-pattern, as-pattern, cons-pattern, binary, map and pinned
+//! variable patterns.
 //!
 //! Pattern grammar (precedence, lowest to highest):
 //! ```text
@@ -10,11 +11,18 @@
 //! as_pattern    = cons_pattern ["as" IDENT]
 //! cons_pattern  = or_pattern ("::" cons_pattern)?
 //! or_pattern    = primary_pattern ("|" primary_pattern)*
-//! primary_pattern = wildcard | literal | tuple | constructor | ident
+//! primary_pattern = wildcard | literal | tuple | constructor | ident | binary
+//!                 | map | pin
+//! binary        = "<<" [segment ("," segment)*] ">>"
+//! segment       = primary_pattern ["::" IDENT ("-" IDENT)* ["(" expr ")"]]
+//! map           = "%{" [map_entry ("," map_entry)* [","]] "}"
+//! map_entry     = (INT | "-" INT | STRING) "=>" pattern
+//! pin           = "^" IDENT
 //! ```
 
 use crate::syntax_kind::SyntaxKind;
 
+use super::expressions::expr;
 use super::{MarkClosed, Parser};
 
 /// Parse a pattern (top-level entry point).
@@ -97,6 +105,9 @@ fn parse_or_pattern(p: &mut Parser) -> Option<MarkClosed> {
 /// - `Name.Variant(args)` -> CONSTRUCTOR_PAT (qualified)
 /// - `Variant(args)` -> CONSTRUCTOR_PAT (unqualified, starts with uppercase + parens)
 /// - `ident` -> IDENT_PAT
+/// - `<<len::u16, rest::rest>>` -> BYTES_PAT
+/// - `%{"type" => "error", "id" => id}` -> MAP_PAT
+/// - `^expected` -> PIN_PAT
 fn parse_primary_pattern(p: &mut Parser) -> Option<MarkClosed> {
     match p.current() {
         // Wildcard: _
@@ -230,9 +241,116 @@ fn parse_primary_pattern(p: &mut Parser) -> Option<MarkClosed> {
             }
         }
 
+        // Binary pattern: <<segment, ...>>
+        SyntaxKind::LT if matches!(p.nth(1), SyntaxKind::LT | SyntaxKind::DIAMOND) => {
+            Some(parse_bytes_pattern(p))
+        }
+
+        // Map pattern: %{key => pattern, ...}
+        SyntaxKind::PERCENT if p.nth(1) == SyntaxKind::L_BRACE => Some(parse_map_pattern(p)),
+
+        // Pinned variable: ^name
+        SyntaxKind::CARET => {
+            let m = p.open();
+            p.advance(); // ^
+            if !p.eat(SyntaxKind::IDENT) {
+                p.error("expected variable name after `^`");
+            }
+            Some(p.close(m, SyntaxKind::PIN_PAT))
+        }
+
         _ => {
             p.error("expected pattern");
             None
         }
     }
 }
+
+/// Parse a binary pattern: `<<len::u16, payload::bytes(len), rest::rest>>`.
+///
+/// `<<` and `>>` are not tokens of their own -- they are two `<` or `>`
+/// tokens, so nested generics like `List<List<Int>>` lex as before. The
+/// empty pattern `<<>>` lexes as `<`, `<>`, `>`.
+fn parse_bytes_pattern(p: &mut Parser) -> MarkClosed {
+    let m = p.open();
+    p.advance(); // <
+    if p.eat(SyntaxKind::DIAMOND) {
+        p.expect(SyntaxKind::GT);
+        return p.close(m, SyntaxKind::BYTES_PAT);
+    }
+    p.advance(); // <
+
+    if !p.at(SyntaxKind::GT) {
+        loop {
+            let seg = p.open();
+            parse_primary_pattern(p);
+            parse_bytes_segment_spec(p);
+            p.close(seg, SyntaxKind::BYTES_SEGMENT);
+            if p.has_error() || !p.eat(SyntaxKind::COMMA) {
+                break;
+            }
+        }
+    }
+
+    p.expect(SyntaxKind::GT);
+    p.expect(SyntaxKind::GT);
+    p.close(m, SyntaxKind::BYTES_PAT)
+}
+
+/// Parse a map pattern: `%{"type" => "error", "id" => id}`.
+///
+/// Keys are Int or String literals; each value is a full pattern. The
+/// pattern matches any map that has all the listed keys, whatever else it
+/// contains, so `%{}` matches every map.
+fn parse_map_pattern(p: &mut Parser) -> MarkClosed {
+    let m = p.open();
+    p.advance(); // %
+    p.advance(); // {
+
+    while !p.at(SyntaxKind::R_BRACE) && !p.at(SyntaxKind::EOF) {
+        let entry = p.open();
+        let is_key = match p.current() {
+            SyntaxKind::INT_LITERAL | SyntaxKind::STRING_START => true,
+            SyntaxKind::MINUS => p.nth(1) == SyntaxKind::INT_LITERAL,
+            _ => false,
+        };
+        if is_key {
+            parse_primary_pattern(p);
+        } else {
+            p.error("expected an Int or String literal map key");
+        }
+        if !p.has_error() && p.expect(SyntaxKind::FAT_ARROW) {
+            parse_pattern(p);
+        }
+        p.close(entry, SyntaxKind::MAP_PAT_ENTRY);
+        if p.has_error() || !p.eat(SyntaxKind::COMMA) {
+            break;
+        }
+    }
+
+    p.expect(SyntaxKind::R_BRACE);
+    p.close(m, SyntaxKind::MAP_PAT)
+}
+
+/// Parse the optional `::type-modifier(size)` tail of a binary segment.
+///
+/// Shared by binary patterns and binary construction expressions. The
+/// segment type and modifiers are plain identifiers (`u16`, `big`, `rest`);
+/// they are checked by the type checker, not here.
+pub(crate) fn parse_bytes_segment_spec(p: &mut Parser) {
+    if !p.eat(SyntaxKind::COLON_COLON) {
+        return;
+    }
+    if !p.eat(SyntaxKind::IDENT) {
+        p.error("expected segment type, e.g. `u16` or `bytes`");
+        return;
+    }
+    while p.at(SyntaxKind::MINUS) && p.nth(1) == SyntaxKind::IDENT {
+        p.advance(); // -
+        p.advance(); // modifier
+    }
+    if p.eat(SyntaxKind::L_PAREN) {
+        expr(p);
+        p.expect(SyntaxKind::R_PAREN);
+    }
+}
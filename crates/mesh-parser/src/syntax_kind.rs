@@ -70,7 +70,7 @@ pub enum SyntaxKind {
     BREAK_KW,
     CONTINUE_KW,
 
-    // ── Operators (24) ─────────────────────────────────────────────────
+    // ── Operators (25) ─────────────────────────────────────────────────
     PLUS,
     MINUS,
     STAR,
@@ -97,6 +97,8 @@ pub enum SyntaxKind {
     QUESTION,
     /// `|` bare pipe for or-patterns
     BAR,
+    /// `^` pin operator in patterns
+    CARET,
 
     // ── Delimiters (6) ─────────────────────────────────────────────────
     L_PAREN,
@@ -113,7 +115,7 @@ pub enum SyntaxKind {
     SEMICOLON,
     NEWLINE,
 
-    // ── Literals (8) ───────────────────────────────────────────────────
+    // ── Literals (10) ──────────────────────────────────────────────────
     INT_LITERAL,
     FLOAT_LITERAL,
     STRING_START,
@@ -122,6 +124,8 @@ pub enum SyntaxKind {
     INTERPOLATION_START,
     INTERPOLATION_END,
     ATOM_LITERAL,
+    REGEX_LITERAL,
+    FORMAT_SPEC,
 
     // ── Identifiers and comments (4) ───────────────────────────────────
     IDENT,
@@ -192,6 +196,8 @@ pub enum SyntaxKind {
     LITERAL,
     /// Atom literal expression: `:name`, `:email`, `:asc`.
     ATOM_EXPR,
+    /// Regex literal expression: `~r/[a-z]+/i`.
+    REGEX_EXPR,
     /// Name in a definition position.
     NAME,
     /// Name reference (identifier used as expression).
@@ -270,6 +276,18 @@ pub enum SyntaxKind {
     AS_PAT,
     /// Cons pattern: `head :: tail` for list destructuring
     CONS_PAT,
+    /// Binary pattern: `<<len::u16, payload::bytes(len), rest::rest>>`
+    BYTES_PAT,
+    /// Map pattern: `%{"type" => "error", "id" => id}`
+    MAP_PAT,
+    /// Single entry in a map pattern: `"id" => id`
+    MAP_PAT_ENTRY,
+    /// Pinned variable pattern: `^expected`
+    PIN_PAT,
+    /// Binary construction: `<<len::u16, payload::bytes>>`
+    BYTES_LITERAL,
+    /// One segment of a binary pattern or construction: `len::u16-little`
+    BYTES_SEGMENT,
     /// Guard clause: `when r > 0.0`
     GUARD_CLAUSE,
     /// Deriving clause: `deriving(Eq, Display, ...)`
@@ -346,6 +364,10 @@ pub enum SyntaxKind {
     RESTART_LIMIT,
     /// Seconds limit in supervisor: `max_seconds: 5`
     SECONDS_LIMIT,
+
+    // ── Test node kinds ─────────────────────────────────────────────
+    /// Test block declaration: `test "name" do ... end`
+    TEST_DEF,
 }
 
 impl SyntaxKind {
@@ -363,6 +385,24 @@ impl SyntaxKind {
                 | SyntaxKind::MODULE_DOC_COMMENT
         )
     }
+
+    /// Whether nodes of this kind own the `##` doc comments directly above
+    /// them.
+    ///
+    /// When the tree is built, a run of doc comments separated from such a
+    /// node only by single newlines becomes the node's first children, so the
+    /// comments move with the item they document.
+    pub fn is_doc_comment_owner(self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::FN_DEF
+                | SyntaxKind::STRUCT_DEF
+                | SyntaxKind::SUM_TYPE_DEF
+                | SyntaxKind::INTERFACE_DEF
+                | SyntaxKind::INTERFACE_METHOD
+                | SyntaxKind::SERVICE_DEF
+        )
+    }
 }
 
 impl From<TokenKind> for SyntaxKind {
@@ -442,6 +482,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::ColonColon => SyntaxKind::COLON_COLON,
             TokenKind::Question => SyntaxKind::QUESTION,
             TokenKind::Bar => SyntaxKind::BAR,
+            TokenKind::Caret => SyntaxKind::CARET,
             // Delimiters
             TokenKind::LParen => SyntaxKind::L_PAREN,
             TokenKind::RParen => SyntaxKind::R_PAREN,
@@ -464,6 +505,8 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::InterpolationStart => SyntaxKind::INTERPOLATION_START,
             TokenKind::InterpolationEnd => SyntaxKind::INTERPOLATION_END,
             TokenKind::Atom => SyntaxKind::ATOM_LITERAL,
+            TokenKind::Regex => SyntaxKind::REGEX_LITERAL,
+            TokenKind::FormatSpec => SyntaxKind::FORMAT_SPEC,
             // Identifiers and comments
             TokenKind::Ident => SyntaxKind::IDENT,
             TokenKind::Comment => SyntaxKind::COMMENT,
@@ -533,7 +576,7 @@ mod tests {
             TokenKind::With,
             TokenKind::Break,
             TokenKind::Continue,
-            // Operators (24)
+            // Operators (25)
             TokenKind::Plus,
             TokenKind::Minus,
             TokenKind::Star,
@@ -558,6 +601,7 @@ mod tests {
             TokenKind::ColonColon,
             TokenKind::Question,
             TokenKind::Bar,
+            TokenKind::Caret,
             // Delimiters (6)
             TokenKind::LParen,
             TokenKind::RParen,
@@ -571,7 +615,7 @@ mod tests {
             TokenKind::Colon,
             TokenKind::Semicolon,
             TokenKind::Newline,
-            // Literals (8)
+            // Literals (10)
             TokenKind::IntLiteral,
             TokenKind::FloatLiteral,
             TokenKind::StringStart,
@@ -580,6 +624,8 @@ mod tests {
             TokenKind::InterpolationStart,
             TokenKind::InterpolationEnd,
             TokenKind::Atom,
+            TokenKind::Regex,
+            TokenKind::FormatSpec,
             // Identifiers and comments (4)
             TokenKind::Ident,
             TokenKind::Comment,
@@ -590,7 +636,7 @@ mod tests {
             TokenKind::Error,
         ];
 
-        assert_eq!(all_kinds.len(), 97, "must test all 97 TokenKind variants");
+        assert_eq!(all_kinds.len(), 100, "must test all 100 TokenKind variants");
 
         for kind in all_kinds {
             let _syntax_kind: SyntaxKind = kind.into();
@@ -648,6 +694,7 @@ mod tests {
             SyntaxKind::CLOSURE_EXPR,
             SyntaxKind::LITERAL,
             SyntaxKind::ATOM_EXPR,
+            SyntaxKind::REGEX_EXPR,
             SyntaxKind::NAME,
             SyntaxKind::NAME_REF,
             SyntaxKind::PATH,
@@ -686,6 +733,12 @@ mod tests {
             SyntaxKind::OR_PAT,
             SyntaxKind::AS_PAT,
             SyntaxKind::CONS_PAT,
+            SyntaxKind::BYTES_PAT,
+            SyntaxKind::MAP_PAT,
+            SyntaxKind::MAP_PAT_ENTRY,
+            SyntaxKind::PIN_PAT,
+            SyntaxKind::BYTES_LITERAL,
+            SyntaxKind::BYTES_SEGMENT,
             SyntaxKind::GUARD_CLAUSE,
             SyntaxKind::DERIVING_CLAUSE,
             SyntaxKind::FN_EXPR_BODY,
@@ -724,6 +777,8 @@ mod tests {
             SyntaxKind::STRATEGY_CLAUSE,
             SyntaxKind::RESTART_LIMIT,
             SyntaxKind::SECONDS_LIMIT,
+            // Test node kinds
+            SyntaxKind::TEST_DEF,
         ];
         assert!(
             node_kinds.len() >= 88,
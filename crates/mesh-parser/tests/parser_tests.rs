@@ -4,12 +4,16 @@
 //! and snapshots the debug tree output to verify correct structure.
 
 use insta::assert_snapshot;
-use mesh_parser::ast::expr::{BinaryExpr, ForInExpr, IfExpr, Literal};
+use mesh_parser::ast::expr::{
+    BinaryExpr, BytesLiteral, ForInExpr, IfExpr, Literal, PipeExpr, RegexLiteral, StringExpr,
+};
 use mesh_parser::ast::item::{
     FnDef, LetBinding, ServiceDef, SourceFile, StructDef, SumTypeDef,
 };
 use mesh_parser::SyntaxKind;
-use mesh_parser::ast::pat::{AsPat, ConstructorPat, OrPat, Pattern};
+use mesh_parser::ast::pat::{
+    AsPat, BytesPat, ConstructorPat, MapPat, OrPat, Pattern, SegmentType,
+};
 use mesh_parser::{debug_tree, parse, parse_block, parse_expr, AstNode};
 
 fn parse_and_debug(source: &str) -> String {
@@ -157,6 +161,38 @@ fn pipe_chain() {
     assert_snapshot!(parse_and_debug("x |> foo() |> bar()"));
 }
 
+#[test]
+fn pipe_numbered() {
+    // `|2>` parses like `|>`; the position lives in the operator token.
+    assert_snapshot!(parse_and_debug("sep |2> join(parts) |> length()"));
+}
+
+#[test]
+fn pipe_position_accessor() {
+    let positions: Vec<_> = ["x |> f()", "x |3> f(a, b)", "x |0> f()", "x |99999999999999999999> f()"]
+        .iter()
+        .map(|src| {
+            let parse = parse_expr(src);
+            let pipe = parse.syntax().descendants().find_map(PipeExpr::cast).unwrap();
+            pipe.position()
+        })
+        .collect();
+    assert_eq!(positions, vec![Some(1), Some(3), Some(0), None]);
+}
+
+#[test]
+fn regex_literal() {
+    assert_snapshot!(parse_and_debug("String.length(s) > 0 && Regex.is_match(~r/^[a-z]+$/i, s)"));
+}
+
+#[test]
+fn regex_literal_accessors() {
+    let parse = parse_expr("~r/a\\/b\\d+/im");
+    let re = parse.syntax().descendants().find_map(RegexLiteral::cast).unwrap();
+    assert_eq!(re.pattern().as_deref(), Some("a/b\\d+"));
+    assert_eq!(re.flags(), "im");
+}
+
 // ── Function Calls ─────────────────────────────────────────────────────
 
 #[test]
@@ -229,6 +265,19 @@ fn string_interpolation() {
     assert_snapshot!(parse_and_debug("\"hello ${name} world\""));
 }
 
+#[test]
+fn string_interpolation_format_spec() {
+    assert_snapshot!(parse_and_debug("\"total ${price * qty:>10.2}\""));
+}
+
+#[test]
+fn string_interpolation_accessors() {
+    let parse = parse_expr("\"${id:08x} ${name}\"");
+    let s = parse.syntax().descendants().find_map(StringExpr::cast).unwrap();
+    let specs: Vec<_> = s.interpolations().map(|i| i.format_spec_text()).collect();
+    assert_eq!(specs, vec![Some("08x".to_string()), None]);
+}
+
 // ── Pipe with Calls ────────────────────────────────────────────────────
 
 #[test]
@@ -1223,6 +1272,26 @@ fn lossless_pipe_chain() {
     assert_lossless_roundtrip("fn main() do\n  x |> foo() |> bar()\nend");
 }
 
+#[test]
+fn lossless_numbered_pipe() {
+    assert_lossless_roundtrip("fn main() do\n  \"-\" |2> String.replace(s, \"_\")\nend");
+}
+
+#[test]
+fn lossless_bytes() {
+    assert_lossless_roundtrip("fn main() do\n  case <<1, 2>> do\n    <<a::u8, _::rest>> -> a\n  end\nend");
+}
+
+#[test]
+fn lossless_regex_literal() {
+    assert_lossless_roundtrip("fn main() do\n  let re = ~r/(\\w+)@(\\w+)\\.com/i\nend");
+}
+
+#[test]
+fn lossless_interpolation_format_spec() {
+    assert_lossless_roundtrip("fn main() do\n  println(\"${n:+06}-${s:-^12.3}-${x::Int}\")\nend");
+}
+
 #[test]
 fn lossless_closure() {
     assert_lossless_roundtrip("fn (x) -> x + 1 end");
@@ -1450,6 +1519,105 @@ fn pattern_or_with_constructors() {
     ));
 }
 
+// ── Binary Patterns ─────────────────────────────────────────────────
+
+#[test]
+fn pattern_bytes() {
+    assert_snapshot!(parse_and_debug(
+        "case frame do\n  <<1, len::u16-little, payload::bytes(len), rest::rest>> -> payload\n  <<>> -> frame\nend"
+    ));
+}
+
+#[test]
+fn bytes_literal() {
+    assert_snapshot!(parse_and_debug("<<n + 1::u32, body::bytes, 0>>"));
+}
+
+#[test]
+fn bytes_pattern_accessors() {
+    let parse = parse_expr("case b do\n  <<tag, n::i32-little, body::bytes(n), _::rest>> -> n\nend");
+    assert!(parse.ok(), "parse errors: {:?}", parse.errors());
+    let pat = parse.syntax().descendants().find_map(BytesPat::cast).unwrap();
+    let segments: Vec<_> = pat.segments().collect();
+    assert_eq!(segments.len(), 4);
+
+    assert!(matches!(segments[0].pattern(), Some(Pattern::Ident(_))));
+    assert_eq!(
+        segments[0].segment_type(),
+        Ok(SegmentType::Int { bits: 8, signed: false, little: false })
+    );
+    assert_eq!(
+        segments[1].segment_type(),
+        Ok(SegmentType::Int { bits: 32, signed: true, little: true })
+    );
+    assert_eq!(segments[2].segment_type(), Ok(SegmentType::Bytes));
+    assert_eq!(segments[2].size().unwrap().syntax().text().to_string(), "n");
+    assert_eq!(segments[3].segment_type(), Ok(SegmentType::Rest));
+    assert!(segments[3].size().is_none());
+}
+
+#[test]
+fn bytes_segment_type_errors() {
+    let parse = parse_expr("<<a::u12, b::bytes-little, c::u8-middle>>");
+    assert!(parse.ok(), "parse errors: {:?}", parse.errors());
+    let lit = parse.syntax().descendants().find_map(BytesLiteral::cast).unwrap();
+    let errors: Vec<String> = lit.segments().filter_map(|s| s.segment_type().err()).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "unknown segment type `u12`; expected u8, u16, u32, u64, i8, i16, i32, i64, bytes or rest",
+            "`bytes` segments take no modifiers, found `-little`",
+            "unknown modifier `-middle`; expected `-big` or `-little`",
+        ]
+    );
+}
+
+#[test]
+fn bytes_literal_does_not_disturb_generics() {
+    let p = parse("fn f(xs :: List<List<Int>>) -> List<List<Int>> do\n  xs\nend");
+    assert!(p.ok(), "parse errors: {:?}", p.errors());
+}
+
+// ── Map and Pin Patterns ────────────────────────────────────────────
+
+#[test]
+fn pattern_map() {
+    assert_snapshot!(parse_and_debug(
+        "case msg do\n  %{\"type\" => \"error\", \"id\" => id} -> id\n  %{1 => Some(x), -2 => _} -> x\n  %{} -> 0\nend"
+    ));
+}
+
+#[test]
+fn pattern_pin() {
+    assert_snapshot!(parse_and_debug(
+        "case reply do\n  (^ref, value) -> value\n  ^fallback | 0 -> 0\nend"
+    ));
+}
+
+#[test]
+fn map_pattern_accessors() {
+    let parse = parse_expr("case m do\n  %{\"id\" => ^want, 7 => (a, b)} -> a\nend");
+    assert!(parse.ok(), "parse errors: {:?}", parse.errors());
+    let pat = parse.syntax().descendants().find_map(MapPat::cast).unwrap();
+    let entries: Vec<_> = pat.entries().collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].key().unwrap().syntax().text().to_string(), "\"id\"");
+    let Some(Pattern::Pin(pin)) = entries[0].value() else {
+        panic!("expected a pinned value");
+    };
+    assert_eq!(pin.name().unwrap().text(), "want");
+    assert_eq!(entries[1].key().unwrap().syntax().text().to_string(), "7");
+    assert!(matches!(entries[1].value(), Some(Pattern::Tuple(_))));
+}
+
+#[test]
+fn map_pattern_rejects_non_literal_keys() {
+    let p = parse_expr("case m do\n  %{key => v} -> v\nend");
+    assert_eq!(p.errors()[0].message, "expected an Int or String literal map key");
+    let p = parse_expr("case m do\n  ^ -> 0\nend");
+    assert_eq!(p.errors()[0].message, "expected variable name after `^`");
+}
+
 // ── Lossless Round-Trip for Sum Types ───────────────────────────────
 
 #[test]
@@ -2550,3 +2718,101 @@ fn impl_assoc_type_generic_binding() {
     let p = parse(source);
     assert!(p.ok(), "parse errors: {:?}", p.errors());
 }
+
+// ═══════════════════════════════════════════════════════════════════════
+// Test blocks: test "name" do ... end
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn parse_test_block_snapshot() {
+    let source = "test \"adds numbers\" do\n  assert_eq(1 + 1, 2)\nend";
+    assert_snapshot!(source_and_debug(source));
+}
+
+#[test]
+fn ast_test_def_accessors() {
+    let source = "test \"adds numbers\" do\n  assert(true)\n  refute(false)\nend";
+    let p = parse(source);
+    assert!(p.ok(), "parse errors: {:?}", p.errors());
+
+    use mesh_parser::ast::item::TestDef;
+    let tree = p.tree();
+    let test_def: TestDef = tree
+        .syntax()
+        .children()
+        .find_map(TestDef::cast)
+        .expect("should have test def");
+
+    assert_eq!(test_def.name().as_deref(), Some("adds numbers"));
+    assert!(test_def.body().is_some());
+}
+
+#[test]
+fn test_keyword_remains_usable_as_identifier() {
+    // `test` only starts a test block when followed by a string literal.
+    let source = "fn check(test) do\n  test\nend\nfn main() do\n  let test = 1\n  test\nend";
+    let p = parse(source);
+    assert!(p.ok(), "parse errors: {:?}", p.errors());
+    assert!(!format_parse(&p).contains("TEST_DEF"));
+}
+
+#[test]
+fn test_block_rejects_interpolated_name() {
+    let p = parse("test \"case ${1}\" do\n  assert(true)\nend");
+    assert!(!p.ok());
+    assert!(p
+        .errors()
+        .iter()
+        .any(|e| e.message.contains("cannot contain interpolation")));
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Doc comments: ## attached to the item below, ##! for the module
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn doc_comments_attached_to_items_snapshot() {
+    let source = "##! Geometry helpers.\n\n## A point.\n## In 2D.\npub struct Point do\n  x :: Int\nend\n\n## Detached.\n\nfn origin() -> Int do\n  0\nend";
+    assert_snapshot!(source_and_debug(source));
+}
+
+#[test]
+fn ast_doc_comment_accessors() {
+    let source = "##! Greeting helpers.\n##! Second line.\n\n## Says hi.\n##\n## Politely.\npub fn greet(name :: String) -> String do\n  name\nend\n\n# Not a doc comment.\nfn plain() do\n  1\nend\n\n## Greets things.\ninterface Greeter do\n  ## Greet one thing.\n  fn greet(self) -> String\nend\n\n## Shapes.\ntype Shape do\n  Circle(Float)\nend\n\n## Counts.\nservice Counter do\n  fn init(n :: Int) -> Int do\n    n\n  end\nend";
+    let p = parse(source);
+    assert!(p.ok(), "parse errors: {:?}", p.errors());
+    let tree = p.tree();
+
+    assert_eq!(
+        tree.module_doc().as_deref(),
+        Some("Greeting helpers.\nSecond line.")
+    );
+
+    let fns: Vec<FnDef> = tree.fn_defs().collect();
+    assert_eq!(fns[0].doc_comment().as_deref(), Some("Says hi.\n\nPolitely."));
+    assert_eq!(fns[1].doc_comment(), None);
+
+    use mesh_parser::ast::item::InterfaceDef;
+    let iface = tree
+        .syntax()
+        .children()
+        .find_map(InterfaceDef::cast)
+        .expect("should have interface");
+    assert_eq!(iface.doc_comment().as_deref(), Some("Greets things."));
+    let method = iface.methods().next().expect("should have method");
+    assert_eq!(method.doc_comment().as_deref(), Some("Greet one thing."));
+
+    let sum = tree
+        .syntax()
+        .children()
+        .find_map(SumTypeDef::cast)
+        .expect("should have sum type");
+    assert_eq!(sum.doc_comment().as_deref(), Some("Shapes."));
+
+    let service = tree
+        .syntax()
+        .children()
+        .find_map(ServiceDef::cast)
+        .expect("should have service");
+    assert_eq!(service.doc_comment().as_deref(), Some("Counts."));
+}
@@ -4,6 +4,6 @@ pub mod resolver;
 pub mod scaffold;
 
 // Re-export key types for convenience.
-pub use manifest::Manifest;
+pub use manifest::{Manifest, Profile};
 pub use resolver::resolve_dependencies;
 pub use scaffold::scaffold_project;
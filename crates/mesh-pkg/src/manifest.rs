@@ -8,8 +8,50 @@ pub struct Manifest {
     pub package: Package,
     #[serde(default)]
     pub dependencies: BTreeMap<String, Dependency>,
+    /// Lint levels from the [lints] section: lint name -> "allow", "warn" or "deny".
+    #[serde(default)]
+    pub lints: BTreeMap<String, String>,
+    /// Build profiles from the [profile.dev] and [profile.release] sections.
+    #[serde(default)]
+    pub profile: Profiles,
+}
+
+/// The build profiles of a project. `meshc build` uses `dev`, and
+/// `meshc build --release` uses `release`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profiles {
+    #[serde(default)]
+    pub dev: Profile,
+    #[serde(default)]
+    pub release: Profile,
 }
 
+/// Compiler settings of one build profile. Unset keys keep the compiler's
+/// defaults for that profile.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Profile {
+    /// Optimization level, 0-3.
+    pub opt_level: Option<u8>,
+    /// Emit DWARF debug info.
+    pub debug: Option<bool>,
+    /// Optimize the whole program at link time.
+    pub lto: Option<bool>,
+    /// Panic on `Int` overflow in `+`, `-`, `*`, `/`, `%` and negation
+    /// instead of wrapping.
+    pub overflow_checks: Option<bool>,
+    /// Actor stack size in bytes.
+    pub stack_size: Option<u64>,
+    /// Number of actor scheduler threads; 0 = one per CPU core.
+    pub schedulers: Option<u32>,
+    /// Output path of the binary, relative to the project directory.
+    pub output: Option<String>,
+}
+
+/// Smallest actor stack size a profile may set: 64 KiB.
+pub const MIN_STACK_SIZE: u64 = 64 * 1024;
+
 /// Package metadata from the [package] section of mesh.toml.
 #[derive(Debug, Deserialize)]
 pub struct Package {
@@ -49,7 +91,44 @@ impl Manifest {
 
     /// Parse a mesh.toml manifest from a string.
     pub fn from_str(content: &str) -> Result<Manifest, String> {
-        toml::from_str(content).map_err(|e| format!("Failed to parse manifest: {}", e))
+        let manifest: Manifest =
+            toml::from_str(content).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+        manifest.profile.dev.validate("dev")?;
+        manifest.profile.release.validate("release")?;
+        Ok(manifest)
+    }
+}
+
+impl Profiles {
+    /// The release profile with `release`, otherwise the dev profile.
+    pub fn get(&self, release: bool) -> &Profile {
+        if release {
+            &self.release
+        } else {
+            &self.dev
+        }
+    }
+}
+
+impl Profile {
+    fn validate(&self, name: &str) -> Result<(), String> {
+        if let Some(level) = self.opt_level {
+            if level > 3 {
+                return Err(format!(
+                    "[profile.{}] opt-level must be between 0 and 3, got {}",
+                    name, level
+                ));
+            }
+        }
+        if let Some(size) = self.stack_size {
+            if size < MIN_STACK_SIZE {
+                return Err(format!(
+                    "[profile.{}] stack-size must be at least {} bytes, got {}",
+                    name, MIN_STACK_SIZE, size
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -119,6 +198,26 @@ version = "0.0.1"
         assert!(manifest.package.description.is_none());
         assert!(manifest.package.authors.is_empty());
         assert!(manifest.dependencies.is_empty());
+        assert!(manifest.lints.is_empty());
+        assert_eq!(manifest.profile.dev, Profile::default());
+        assert_eq!(manifest.profile.release, Profile::default());
+    }
+
+    #[test]
+    fn parse_lints_section() {
+        let toml = r#"
+[package]
+name = "linted"
+version = "1.0.0"
+
+[lints]
+unused_variable = "deny"
+shadowing = "allow"
+"#;
+        let manifest = Manifest::from_str(toml).unwrap();
+        assert_eq!(manifest.lints.len(), 2);
+        assert_eq!(manifest.lints["unused_variable"], "deny");
+        assert_eq!(manifest.lints["shadowing"], "allow");
     }
 
     #[test]
@@ -194,4 +293,61 @@ lib = { git = "https://example.com/lib.git" }
             _ => panic!("Expected git dependency"),
         }
     }
+
+    #[test]
+    fn parse_profile_sections() {
+        let toml = r#"
+[package]
+name = "profiled"
+version = "1.0.0"
+
+[profile.dev]
+schedulers = 2
+
+[profile.release]
+opt-level = 3
+debug = true
+lto = true
+overflow-checks = true
+stack-size = 1048576
+schedulers = 8
+output = "dist/server"
+"#;
+        let manifest = Manifest::from_str(toml).unwrap();
+        assert_eq!(
+            manifest.profile.get(false),
+            &Profile { schedulers: Some(2), ..Profile::default() }
+        );
+        assert_eq!(
+            manifest.profile.get(true),
+            &Profile {
+                opt_level: Some(3),
+                debug: Some(true),
+                lto: Some(true),
+                overflow_checks: Some(true),
+                stack_size: Some(1048576),
+                schedulers: Some(8),
+                output: Some("dist/server".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn reject_invalid_profiles() {
+        let manifest = |profile: &str| {
+            Manifest::from_str(&format!(
+                "[package]\nname = \"p\"\nversion = \"1.0.0\"\n\n{}",
+                profile
+            ))
+        };
+
+        let err = manifest("[profile.release]\nopt-level = 4\n").unwrap_err();
+        assert_eq!(err, "[profile.release] opt-level must be between 0 and 3, got 4");
+        let err = manifest("[profile.dev]\nstack-size = 4096\n").unwrap_err();
+        assert!(err.contains("stack-size must be at least 65536 bytes"), "{}", err);
+        let err = manifest("[profile.dev]\nopt_level = 1\n").unwrap_err();
+        assert!(err.contains("unknown field `opt_level`"), "{}", err);
+        let err = manifest("[profile.bench]\n").unwrap_err();
+        assert!(err.contains("unknown field `bench`"), "{}", err);
+    }
 }
@@ -63,6 +63,8 @@ fn register_runtime_symbols() {
     add_sym("mesh_println", mesh_rt::mesh_println as *const ());
     add_sym("mesh_io_read_line", mesh_rt::mesh_io_read_line as *const ());
     add_sym("mesh_io_eprintln", mesh_rt::mesh_io_eprintln as *const ());
+    add_sym("mesh_io_write", mesh_rt::mesh_io_write as *const ());
+    add_sym("mesh_io_write_iodata", mesh_rt::mesh_io_write_iodata as *const ());
 
     // String operations
     add_sym("mesh_string_new", mesh_rt::mesh_string_new as *const ());
@@ -84,6 +86,121 @@ fn register_runtime_symbols() {
     add_sym("mesh_string_join", mesh_rt::mesh_string_join as *const ());
     add_sym("mesh_string_to_int", mesh_rt::mesh_string_to_int as *const ());
     add_sym("mesh_string_to_float", mesh_rt::mesh_string_to_float as *const ());
+    add_sym("mesh_string_to_bytes", mesh_rt::mesh_string_to_bytes as *const ());
+    add_sym("mesh_string_from_utf8", mesh_rt::mesh_string_from_utf8 as *const ());
+    add_sym("mesh_string_byte_size", mesh_rt::mesh_string_byte_size as *const ());
+    add_sym("mesh_string_graphemes", mesh_rt::mesh_string_graphemes as *const ());
+    add_sym("mesh_string_chars", mesh_rt::mesh_string_chars as *const ());
+    add_sym("mesh_string_codepoints", mesh_rt::mesh_string_codepoints as *const ());
+    add_sym("mesh_string_at", mesh_rt::mesh_string_at as *const ());
+    add_sym("mesh_string_index_of", mesh_rt::mesh_string_index_of as *const ());
+    add_sym("mesh_string_pad_left", mesh_rt::mesh_string_pad_left as *const ());
+    add_sym("mesh_string_pad_right", mesh_rt::mesh_string_pad_right as *const ());
+    add_sym("mesh_string_reverse", mesh_rt::mesh_string_reverse as *const ());
+    add_sym("mesh_string_fold_case", mesh_rt::mesh_string_fold_case as *const ());
+    add_sym("mesh_string_normalize_nfc", mesh_rt::mesh_string_normalize_nfc as *const ());
+    add_sym("mesh_string_normalize_nfd", mesh_rt::mesh_string_normalize_nfd as *const ());
+    add_sym("mesh_format_int", mesh_rt::mesh_format_int as *const ());
+    add_sym("mesh_format_uint64", mesh_rt::mesh_format_uint64 as *const ());
+    add_sym("mesh_format_float", mesh_rt::mesh_format_float as *const ());
+    add_sym("mesh_format_string", mesh_rt::mesh_format_string as *const ());
+    add_sym("mesh_string_format", mesh_rt::mesh_string_format as *const ());
+
+    // Int overflow arithmetic
+    add_sym("mesh_int_wrapping_add", mesh_rt::mesh_int_wrapping_add as *const ());
+    add_sym("mesh_int_wrapping_sub", mesh_rt::mesh_int_wrapping_sub as *const ());
+    add_sym("mesh_int_wrapping_mul", mesh_rt::mesh_int_wrapping_mul as *const ());
+    add_sym("mesh_int_checked_add", mesh_rt::mesh_int_checked_add as *const ());
+    add_sym("mesh_int_checked_sub", mesh_rt::mesh_int_checked_sub as *const ());
+    add_sym("mesh_int_checked_mul", mesh_rt::mesh_int_checked_mul as *const ());
+    add_sym("mesh_int_checked_div", mesh_rt::mesh_int_checked_div as *const ());
+    add_sym("mesh_int_saturating_add", mesh_rt::mesh_int_saturating_add as *const ());
+    add_sym("mesh_int_saturating_sub", mesh_rt::mesh_int_saturating_sub as *const ());
+    add_sym("mesh_int_saturating_mul", mesh_rt::mesh_int_saturating_mul as *const ());
+
+    // Fixed-width integers
+    add_sym("mesh_int8_from_int", mesh_rt::mesh_int8_from_int as *const ());
+    add_sym("mesh_int16_from_int", mesh_rt::mesh_int16_from_int as *const ());
+    add_sym("mesh_int32_from_int", mesh_rt::mesh_int32_from_int as *const ());
+    add_sym("mesh_uint8_from_int", mesh_rt::mesh_uint8_from_int as *const ());
+    add_sym("mesh_uint16_from_int", mesh_rt::mesh_uint16_from_int as *const ());
+    add_sym("mesh_uint32_from_int", mesh_rt::mesh_uint32_from_int as *const ());
+    add_sym("mesh_uint64_from_int", mesh_rt::mesh_uint64_from_int as *const ());
+    add_sym("mesh_int8_try_from_int", mesh_rt::mesh_int8_try_from_int as *const ());
+    add_sym("mesh_int16_try_from_int", mesh_rt::mesh_int16_try_from_int as *const ());
+    add_sym("mesh_int32_try_from_int", mesh_rt::mesh_int32_try_from_int as *const ());
+    add_sym("mesh_uint8_try_from_int", mesh_rt::mesh_uint8_try_from_int as *const ());
+    add_sym("mesh_uint16_try_from_int", mesh_rt::mesh_uint16_try_from_int as *const ());
+    add_sym("mesh_uint32_try_from_int", mesh_rt::mesh_uint32_try_from_int as *const ());
+    add_sym("mesh_uint64_try_from_int", mesh_rt::mesh_uint64_try_from_int as *const ());
+    add_sym("mesh_fixed_int_to_int", mesh_rt::mesh_fixed_int_to_int as *const ());
+    add_sym("mesh_uint64_div", mesh_rt::mesh_uint64_div as *const ());
+    add_sym("mesh_uint64_rem", mesh_rt::mesh_uint64_rem as *const ());
+    add_sym("mesh_uint64_lt", mesh_rt::mesh_uint64_lt as *const ());
+    add_sym("mesh_uint64_to_string", mesh_rt::mesh_uint64_to_string as *const ());
+
+    // BigInt and Decimal
+    add_sym("mesh_bigint_from_int", mesh_rt::mesh_bigint_from_int as *const ());
+    add_sym("mesh_bigint_parse", mesh_rt::mesh_bigint_parse as *const ());
+    add_sym("mesh_bigint_to_string", mesh_rt::mesh_bigint_to_string as *const ());
+    add_sym("mesh_bigint_add", mesh_rt::mesh_bigint_add as *const ());
+    add_sym("mesh_bigint_sub", mesh_rt::mesh_bigint_sub as *const ());
+    add_sym("mesh_bigint_mul", mesh_rt::mesh_bigint_mul as *const ());
+    add_sym("mesh_bigint_div", mesh_rt::mesh_bigint_div as *const ());
+    add_sym("mesh_bigint_mod", mesh_rt::mesh_bigint_mod as *const ());
+    add_sym("mesh_bigint_neg", mesh_rt::mesh_bigint_neg as *const ());
+    add_sym("mesh_bigint_eq", mesh_rt::mesh_bigint_eq as *const ());
+    add_sym("mesh_bigint_compare", mesh_rt::mesh_bigint_compare as *const ());
+    add_sym("mesh_bigint_hash", mesh_rt::mesh_bigint_hash as *const ());
+    add_sym("mesh_bigint_to_json", mesh_rt::mesh_bigint_to_json as *const ());
+    add_sym("mesh_bigint_from_json", mesh_rt::mesh_bigint_from_json as *const ());
+    add_sym("mesh_bigint_to_int", mesh_rt::mesh_bigint_to_int as *const ());
+    add_sym("mesh_bigint_abs", mesh_rt::mesh_bigint_abs as *const ());
+    add_sym("mesh_bigint_pow", mesh_rt::mesh_bigint_pow as *const ());
+    add_sym("mesh_decimal_from_int", mesh_rt::mesh_decimal_from_int as *const ());
+    add_sym("mesh_decimal_parse", mesh_rt::mesh_decimal_parse as *const ());
+    add_sym("mesh_decimal_to_string", mesh_rt::mesh_decimal_to_string as *const ());
+    add_sym("mesh_decimal_add", mesh_rt::mesh_decimal_add as *const ());
+    add_sym("mesh_decimal_sub", mesh_rt::mesh_decimal_sub as *const ());
+    add_sym("mesh_decimal_mul", mesh_rt::mesh_decimal_mul as *const ());
+    add_sym("mesh_decimal_div", mesh_rt::mesh_decimal_div as *const ());
+    add_sym("mesh_decimal_mod", mesh_rt::mesh_decimal_mod as *const ());
+    add_sym("mesh_decimal_neg", mesh_rt::mesh_decimal_neg as *const ());
+    add_sym("mesh_decimal_eq", mesh_rt::mesh_decimal_eq as *const ());
+    add_sym("mesh_decimal_compare", mesh_rt::mesh_decimal_compare as *const ());
+    add_sym("mesh_decimal_hash", mesh_rt::mesh_decimal_hash as *const ());
+    add_sym("mesh_decimal_to_json", mesh_rt::mesh_decimal_to_json as *const ());
+    add_sym("mesh_decimal_from_json", mesh_rt::mesh_decimal_from_json as *const ());
+    add_sym("mesh_decimal_to_float", mesh_rt::mesh_decimal_to_float as *const ());
+    add_sym("mesh_decimal_scale", mesh_rt::mesh_decimal_scale as *const ());
+    add_sym("mesh_decimal_round", mesh_rt::mesh_decimal_round as *const ());
+
+    // Bytes
+    add_sym("mesh_bytes_new", mesh_rt::mesh_bytes_new as *const ());
+    add_sym("mesh_bytes_from_list", mesh_rt::mesh_bytes_from_list as *const ());
+    add_sym("mesh_bytes_to_list", mesh_rt::mesh_bytes_to_list as *const ());
+    add_sym("mesh_bytes_length", mesh_rt::mesh_bytes_length as *const ());
+    add_sym("mesh_bytes_get", mesh_rt::mesh_bytes_get as *const ());
+    add_sym("mesh_bytes_slice", mesh_rt::mesh_bytes_slice as *const ());
+    add_sym("mesh_bytes_concat", mesh_rt::mesh_bytes_concat as *const ());
+    add_sym("mesh_bytes_eq", mesh_rt::mesh_bytes_eq as *const ());
+    add_sym("mesh_bytes_to_string", mesh_rt::mesh_bytes_to_string as *const ());
+    add_sym("mesh_bytes_matches", mesh_rt::mesh_bytes_matches as *const ());
+    add_sym("mesh_bytes_segment", mesh_rt::mesh_bytes_segment as *const ());
+    add_sym("mesh_bytes_put_int", mesh_rt::mesh_bytes_put_int as *const ());
+    add_sym("mesh_bytes_put_bytes", mesh_rt::mesh_bytes_put_bytes as *const ());
+
+    // IoData
+    add_sym("mesh_iodata_new", mesh_rt::mesh_iodata_new as *const ());
+    add_sym("mesh_iodata_from_string", mesh_rt::mesh_iodata_from_string as *const ());
+    add_sym("mesh_iodata_from_bytes", mesh_rt::mesh_iodata_from_bytes as *const ());
+    add_sym("mesh_iodata_from_list", mesh_rt::mesh_iodata_from_list as *const ());
+    add_sym("mesh_iodata_append", mesh_rt::mesh_iodata_append as *const ());
+    add_sym("mesh_iodata_append_bytes", mesh_rt::mesh_iodata_append_bytes as *const ());
+    add_sym("mesh_iodata_concat", mesh_rt::mesh_iodata_concat as *const ());
+    add_sym("mesh_iodata_byte_size", mesh_rt::mesh_iodata_byte_size as *const ());
+    add_sym("mesh_iodata_to_string", mesh_rt::mesh_iodata_to_string as *const ());
+    add_sym("mesh_iodata_to_bytes", mesh_rt::mesh_iodata_to_bytes as *const ());
 
     // Panic
     add_sym("mesh_panic", mesh_rt::mesh_panic as *const ());
@@ -91,6 +208,7 @@ fn register_runtime_symbols() {
     // Actor runtime
     add_sym("mesh_rt_init_actor", mesh_rt::mesh_rt_init_actor as *const ());
     add_sym("mesh_rt_run_scheduler", mesh_rt::mesh_rt_run_scheduler as *const ());
+    add_sym("mesh_rt_set_stack_size", mesh_rt::mesh_rt_set_stack_size as *const ());
     add_sym("mesh_actor_spawn", mesh_rt::mesh_actor_spawn as *const ());
     add_sym("mesh_actor_send", mesh_rt::mesh_actor_send as *const ());
     add_sym("mesh_actor_receive", mesh_rt::mesh_actor_receive as *const ());
@@ -113,6 +231,7 @@ fn register_runtime_symbols() {
     add_sym("mesh_list_tail", mesh_rt::mesh_list_tail as *const ());
     add_sym("mesh_list_length", mesh_rt::mesh_list_length as *const ());
     add_sym("mesh_list_append", mesh_rt::mesh_list_append as *const ());
+    add_sym("mesh_list_prepend", mesh_rt::mesh_list_prepend as *const ());
     add_sym("mesh_list_concat", mesh_rt::mesh_list_concat as *const ());
     add_sym("mesh_list_map", mesh_rt::mesh_list_map as *const ());
     add_sym("mesh_list_filter", mesh_rt::mesh_list_filter as *const ());
@@ -138,6 +257,7 @@ fn register_runtime_symbols() {
     add_sym("mesh_map_get", mesh_rt::mesh_map_get as *const ());
     add_sym("mesh_map_delete", mesh_rt::mesh_map_delete as *const ());
     add_sym("mesh_map_has_key", mesh_rt::mesh_map_has_key as *const ());
+    add_sym("mesh_map_tag_hashed", mesh_rt::mesh_map_tag_hashed as *const ());
     add_sym("mesh_map_size", mesh_rt::mesh_map_size as *const ());
     add_sym("mesh_map_keys", mesh_rt::mesh_map_keys as *const ());
     add_sym("mesh_map_values", mesh_rt::mesh_map_values as *const ());
@@ -156,6 +276,7 @@ fn register_runtime_symbols() {
     add_sym("mesh_set_difference", mesh_rt::mesh_set_difference as *const ());
     add_sym("mesh_set_to_list", mesh_rt::mesh_set_to_list as *const ());
     add_sym("mesh_set_from_list", mesh_rt::mesh_set_from_list as *const ());
+    add_sym("mesh_set_eq", mesh_rt::mesh_set_eq as *const ());
 
     // Collections -- Queue
     add_sym("mesh_queue_new", mesh_rt::mesh_queue_new as *const ());
@@ -164,6 +285,10 @@ fn register_runtime_symbols() {
     add_sym("mesh_queue_peek", mesh_rt::mesh_queue_peek as *const ());
     add_sym("mesh_queue_size", mesh_rt::mesh_queue_size as *const ());
     add_sym("mesh_queue_is_empty", mesh_rt::mesh_queue_is_empty as *const ());
+    add_sym("mesh_queue_to_list", mesh_rt::mesh_queue_to_list as *const ());
+    add_sym("mesh_queue_from_list", mesh_rt::mesh_queue_from_list as *const ());
+    add_sym("mesh_queue_eq", mesh_rt::mesh_queue_eq as *const ());
+    add_sym("mesh_queue_to_string", mesh_rt::mesh_queue_to_string as *const ());
 
     // Collections -- Range
     add_sym("mesh_range_new", mesh_rt::mesh_range_new as *const ());
@@ -235,11 +360,14 @@ fn register_runtime_symbols() {
     add_sym("mesh_tuple_second", mesh_rt::mesh_tuple_second as *const ());
     add_sym("mesh_tuple_nth", mesh_rt::mesh_tuple_nth as *const ());
     add_sym("mesh_tuple_size", mesh_rt::mesh_tuple_size as *const ());
+    add_sym("mesh_tuple_to_list", mesh_rt::mesh_tuple_to_list as *const ());
 
     // File operations
     add_sym("mesh_file_read", mesh_rt::mesh_file_read as *const ());
     add_sym("mesh_file_write", mesh_rt::mesh_file_write as *const ());
     add_sym("mesh_file_append", mesh_rt::mesh_file_append as *const ());
+    add_sym("mesh_file_write_iodata", mesh_rt::mesh_file_write_iodata as *const ());
+    add_sym("mesh_file_append_iodata", mesh_rt::mesh_file_append_iodata as *const ());
     add_sym("mesh_file_exists", mesh_rt::mesh_file_exists as *const ());
     add_sym("mesh_file_delete", mesh_rt::mesh_file_delete as *const ());
 
@@ -337,6 +465,16 @@ fn register_runtime_symbols() {
     add_sym("mesh_changeset_get_change", mesh_rt::mesh_changeset_get_change as *const ());
     add_sym("mesh_changeset_get_error", mesh_rt::mesh_changeset_get_error as *const ());
 
+    // Regex
+    add_sym("mesh_regex_compile", mesh_rt::mesh_regex_compile as *const ());
+    add_sym("mesh_regex_literal", mesh_rt::mesh_regex_literal as *const ());
+    add_sym("mesh_regex_is_match", mesh_rt::mesh_regex_is_match as *const ());
+    add_sym("mesh_regex_captures", mesh_rt::mesh_regex_captures as *const ());
+    add_sym("mesh_regex_named_captures", mesh_rt::mesh_regex_named_captures as *const ());
+    add_sym("mesh_regex_scan", mesh_rt::mesh_regex_scan as *const ());
+    add_sym("mesh_regex_replace", mesh_rt::mesh_regex_replace as *const ());
+    add_sym("mesh_regex_split", mesh_rt::mesh_regex_split as *const ());
+
     // HTTP
     add_sym("mesh_http_get", mesh_rt::mesh_http_get as *const ());
     add_sym("mesh_http_post", mesh_rt::mesh_http_post as *const ());
@@ -349,6 +487,7 @@ fn register_runtime_symbols() {
     add_sym("mesh_http_request_header", mesh_rt::mesh_http_request_header as *const ());
     add_sym("mesh_http_request_query", mesh_rt::mesh_http_request_query as *const ());
     add_sym("mesh_http_response_new", mesh_rt::mesh_http_response_new as *const ());
+    add_sym("mesh_http_response_new_iodata", mesh_rt::mesh_http_response_new_iodata as *const ());
 }
 
 /// The result of evaluating an expression in the REPL.
@@ -96,7 +96,7 @@ pub enum ChildType {
 pub struct ChildSpec {
     /// Unique identifier for this child within the supervisor.
     pub id: String,
-    /// Function pointer to the spawn function (extern "C" fn(*const u8)).
+    /// Function pointer to the spawn function (extern "C-unwind" fn(*const u8)).
     pub start_fn: *const u8,
     /// Pointer to serialized initial arguments for the start function.
     pub start_args_ptr: *const u8,
@@ -110,6 +110,9 @@ impl GcHeader {
 /// Default GC pressure threshold: 256 KiB.
 const DEFAULT_GC_THRESHOLD: usize = 256 * 1024;
 
+/// Releases what a GC object owns outside the heap, given its data pointer.
+pub type Finalizer = unsafe fn(*mut u8);
+
 /// Per-actor heap with GcHeader-prepended free-list allocator.
 ///
 /// Owns a list of pages and bump-allocates within the current page.
@@ -134,6 +137,10 @@ pub struct ActorHeap {
     gc_threshold: usize,
     /// Re-entrancy guard: prevents GC from triggering during GC.
     gc_in_progress: bool,
+    /// Objects that own memory outside the heap, with the function that
+    /// releases it. Each runs once, when its object is swept or the heap
+    /// is reset or dropped.
+    finalizers: Vec<(*mut u8, Finalizer)>,
 }
 
 // Raw pointers in ActorHeap are only accessed from the owning actor's thread.
@@ -150,6 +157,7 @@ impl ActorHeap {
             free_list: ptr::null_mut(),
             gc_threshold: DEFAULT_GC_THRESHOLD,
             gc_in_progress: false,
+            finalizers: Vec::new(),
         };
         heap.pages.push(vec![0u8; ACTOR_PAGE_SIZE]);
         heap
@@ -293,6 +301,7 @@ impl ActorHeap {
     ///
     /// Used for actor termination cleanup or after full GC sweep.
     pub fn reset(&mut self) {
+        self.run_all_finalizers();
         self.pages.clear();
         self.offset = 0;
         self.total_allocated = 0;
@@ -300,6 +309,31 @@ impl ActorHeap {
         self.free_list = ptr::null_mut();
     }
 
+    /// Call `finalizer` with `data` (an object allocated from this heap)
+    /// when the object is collected, or when the heap is reset or dropped.
+    pub fn register_finalizer(&mut self, data: *mut u8, finalizer: Finalizer) {
+        self.finalizers.push((data, finalizer));
+    }
+
+    /// Run the finalizers of objects the mark phase left unmarked. Must run
+    /// between marking and sweeping, while the mark bits are still set.
+    fn run_unreachable_finalizers(&mut self) {
+        self.finalizers.retain(|&(data, finalizer)| {
+            let header = unsafe { &*GcHeader::from_data_ptr(data) };
+            if header.is_marked() {
+                return true;
+            }
+            unsafe { finalizer(data) };
+            false
+        });
+    }
+
+    fn run_all_finalizers(&mut self) {
+        for (data, finalizer) in std::mem::take(&mut self.finalizers) {
+            unsafe { finalizer(data) };
+        }
+    }
+
     /// Returns the total number of bytes allocated from this heap
     /// (including GcHeader overhead).
     pub fn total_bytes(&self) -> usize {
@@ -381,6 +415,7 @@ impl ActorHeap {
         self.gc_in_progress = true;
 
         self.mark_from_roots(stack_bottom, stack_top);
+        self.run_unreachable_finalizers();
         self.sweep();
 
         self.gc_in_progress = false;
@@ -543,6 +578,12 @@ impl ActorHeap {
     }
 }
 
+impl Drop for ActorHeap {
+    fn drop(&mut self) {
+        self.run_all_finalizers();
+    }
+}
+
 impl Default for ActorHeap {
     fn default() -> Self {
         Self::new()
@@ -559,6 +600,7 @@ impl std::fmt::Debug for ActorHeap {
             .field("free_list", &(!self.free_list.is_null()))
             .field("gc_threshold", &self.gc_threshold)
             .field("gc_in_progress", &self.gc_in_progress)
+            .field("finalizers", &self.finalizers.len())
             .finish()
     }
 }
@@ -949,6 +991,32 @@ mod tests {
         assert!(!header.is_marked(), "mark bit should be cleared after sweep");
     }
 
+    #[test]
+    fn test_collect_runs_finalizers_of_unreachable_objects() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static FINALIZED: AtomicUsize = AtomicUsize::new(0);
+        unsafe fn count(_: *mut u8) {
+            FINALIZED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let mut heap = ActorHeap::new();
+        let kept = heap.alloc(64, 8);
+        let dropped = heap.alloc(64, 8);
+        heap.register_finalizer(kept, count);
+        heap.register_finalizer(dropped, count);
+
+        let fake_stack: [usize; 2] = [kept as usize, 0];
+        let stack_bottom = unsafe {
+            (&fake_stack[0] as *const usize as *const u8).add(std::mem::size_of_val(&fake_stack))
+        };
+        heap.collect(stack_bottom, &fake_stack[0] as *const usize as *const u8);
+        assert_eq!(FINALIZED.load(Ordering::SeqCst), 1, "only the unreachable object is finalized");
+
+        // The rest run when the heap goes away.
+        drop(heap);
+        assert_eq!(FINALIZED.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn test_collect_reduces_total_bytes() {
         // Allocate 10 objects, collect with empty roots. Total bytes should drop to 0.
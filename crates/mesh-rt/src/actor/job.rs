@@ -74,7 +74,7 @@ fn err_result(msg: &str) -> *mut MeshResult {
 /// - `fn_ptr`: pointer to the function to run (signature: fn(env) -> i64)
 /// - `env_ptr`: pointer to the closure environment
 #[no_mangle]
-pub extern "C" fn mesh_job_async(fn_ptr: *const u8, env_ptr: *const u8) -> u64 {
+pub extern "C-unwind" fn mesh_job_async(fn_ptr: *const u8, env_ptr: *const u8) -> u64 {
     let caller_pid = match stack::get_current_pid() {
         Some(pid) => pid.as_u64(),
         None => {
@@ -121,7 +121,7 @@ fn spawn_job_actor(fn_ptr: *const u8, env_ptr: *const u8, caller_pid: u64) -> u6
 ///
 /// Unpacks the args buffer, links to the caller, calls the user function,
 /// sends the result, and exits.
-extern "C" fn job_entry(args: *const u8) {
+extern "C-unwind" fn job_entry(args: *const u8) {
     if args.is_null() {
         return;
     }
@@ -146,7 +146,7 @@ extern "C" fn job_entry(args: *const u8) {
     }
 
     // Call the user function: fn(env_ptr) -> i64
-    let user_fn: extern "C" fn(*const u8) -> i64 =
+    let user_fn: extern "C-unwind" fn(*const u8) -> i64 =
         unsafe { std::mem::transmute(fn_ptr) };
     let result = user_fn(env_ptr);
 
@@ -192,7 +192,7 @@ extern "C" fn job_entry(args: *const u8) {
 ///
 /// Returns a pointer to a heap-allocated MeshResult.
 #[no_mangle]
-pub extern "C" fn mesh_job_await(_job_pid: u64) -> *const u8 {
+pub extern "C-unwind" fn mesh_job_await(_job_pid: u64) -> *const u8 {
     // Use mesh_actor_receive(-1) to block indefinitely.
     let msg_ptr = super::mesh_actor_receive(-1);
     if msg_ptr.is_null() {
@@ -212,7 +212,7 @@ pub extern "C" fn mesh_job_await(_job_pid: u64) -> *const u8 {
 ///
 /// Returns a pointer to a heap-allocated MeshResult.
 #[no_mangle]
-pub extern "C" fn mesh_job_await_timeout(_job_pid: u64, timeout_ms: i64) -> *const u8 {
+pub extern "C-unwind" fn mesh_job_await_timeout(_job_pid: u64, timeout_ms: i64) -> *const u8 {
     let msg_ptr = super::mesh_actor_receive(timeout_ms);
     if msg_ptr.is_null() {
         return err_result("timeout") as *const u8;
@@ -329,7 +329,7 @@ fn decode_job_message(msg_ptr: *const u8) -> *const u8 {
 ///
 /// Returns a pointer to a new Mesh list containing MeshResult values.
 #[no_mangle]
-pub extern "C" fn mesh_job_map(
+pub extern "C-unwind" fn mesh_job_map(
     list_ptr: *const u8,
     fn_ptr: *const u8,
     env_ptr: *const u8,
@@ -401,7 +401,7 @@ pub extern "C" fn mesh_job_map(
 ///
 /// Unpacks args: [u64 fn_ptr][u64 env_ptr][u64 element][u64 caller_pid]
 /// Calls fn_ptr(env_ptr, element) and sends result to caller.
-extern "C" fn map_job_entry(args: *const u8) {
+extern "C-unwind" fn map_job_entry(args: *const u8) {
     if args.is_null() {
         return;
     }
@@ -428,7 +428,7 @@ extern "C" fn map_job_entry(args: *const u8) {
     }
 
     // Call the mapping function: fn(env_ptr, element) -> i64
-    let user_fn: extern "C" fn(*const u8, i64) -> i64 =
+    let user_fn: extern "C-unwind" fn(*const u8, i64) -> i64 =
         unsafe { std::mem::transmute(fn_ptr as *const u8) };
     let result = user_fn(env_ptr as *const u8, element as i64);
 
@@ -542,7 +542,7 @@ mod tests {
         // Without GLOBAL_SCHEDULER, should return u64::MAX.
         // Note: the scheduler may be initialized by other tests, so
         // we just verify the function doesn't crash.
-        extern "C" fn dummy_fn(_env: *const u8) -> i64 { 42 }
+        extern "C-unwind" fn dummy_fn(_env: *const u8) -> i64 { 42 }
         let _pid = mesh_job_async(dummy_fn as *const u8, std::ptr::null());
         // If scheduler not initialized, returns u64::MAX.
         // If initialized (from other tests), returns a valid PID.
@@ -99,7 +99,7 @@ pub(crate) fn global_scheduler() -> &'static Scheduler {
 ///
 /// This function is idempotent -- subsequent calls are no-ops.
 #[no_mangle]
-pub extern "C" fn mesh_rt_init_actor(num_schedulers: u32) {
+pub extern "C-unwind" fn mesh_rt_init_actor(num_schedulers: u32) {
     GLOBAL_SCHEDULER.get_or_init(|| {
         let sched = Scheduler::new(num_schedulers);
 
@@ -116,10 +116,19 @@ pub extern "C" fn mesh_rt_init_actor(num_schedulers: u32) {
     });
 }
 
+/// Set the stack size, in bytes, of actors spawned from now on.
+///
+/// Called by the generated `main` before `mesh_rt_init_actor()` when the
+/// build profile sets `stack-size`. A size of 0 restores the default.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_rt_set_stack_size(bytes: u64) {
+    stack::set_stack_size(bytes as usize);
+}
+
 /// Spawn a new actor process.
 ///
 /// The actor will run `fn_ptr(args)` on a worker thread. The entry function
-/// must have the signature `extern "C" fn(args: *const u8)`.
+/// must have the signature `extern "C-unwind" fn(args: *const u8)`.
 ///
 /// Returns the PID of the new actor as a `u64`.
 ///
@@ -128,7 +137,7 @@ pub extern "C" fn mesh_rt_init_actor(num_schedulers: u32) {
 /// - `args_size`: size of the arguments in bytes
 /// - `priority`: 0 = High, 1 = Normal, 2 = Low
 #[no_mangle]
-pub extern "C" fn mesh_actor_spawn(
+pub extern "C-unwind" fn mesh_actor_spawn(
     fn_ptr: *const u8,
     args: *const u8,
     args_size: u64,
@@ -143,7 +152,7 @@ pub extern "C" fn mesh_actor_spawn(
 /// Returns the PID as a `u64`. Returns `u64::MAX` if called outside of an
 /// actor context (should not happen in compiled Mesh programs).
 #[no_mangle]
-pub extern "C" fn mesh_actor_self() -> u64 {
+pub extern "C-unwind" fn mesh_actor_self() -> u64 {
     stack::get_current_pid()
         .map(|pid| pid.as_u64())
         .unwrap_or(u64::MAX)
@@ -157,7 +166,7 @@ pub extern "C" fn mesh_actor_self() -> u64 {
 ///
 /// The reduction counter is reset to `DEFAULT_REDUCTIONS` (4000) after yield.
 #[no_mangle]
-pub extern "C" fn mesh_reduction_check() {
+pub extern "C-unwind" fn mesh_reduction_check() {
     // Get the current actor's process from the process table.
     // We decrement a thread-local shadow counter to avoid locking on every
     // reduction check. The actual Process.reductions field is updated by
@@ -258,7 +267,7 @@ fn try_trigger_gc() {
 /// of the message data (if available), or 0 for empty messages. Future phases
 /// will use compiler-generated type tags.
 #[no_mangle]
-pub extern "C" fn mesh_actor_send(target_pid: u64, msg_ptr: *const u8, msg_size: u64) {
+pub extern "C-unwind" fn mesh_actor_send(target_pid: u64, msg_ptr: *const u8, msg_size: u64) {
     // Locality check: upper 16 bits == 0 means local PID.
     // Single shift+compare -- essentially free on modern CPUs.
     if target_pid >> 48 == 0 {
@@ -361,7 +370,7 @@ fn dist_send(target_pid: u64, msg_ptr: *const u8, msg_size: u64) {
 /// If the target node is ourselves, performs a local registry lookup + send.
 /// Silently drops if node not started, name not found, or session unavailable.
 #[no_mangle]
-pub extern "C" fn mesh_actor_send_named(
+pub extern "C-unwind" fn mesh_actor_send_named(
     name_ptr: *const u8,
     name_len: u64,
     node_ptr: *const u8,
@@ -434,7 +443,7 @@ pub extern "C" fn mesh_actor_send_named(
 /// The returned pointer points to a layout: `[u64 type_tag, u64 data_len, u8... data]`
 /// allocated in the current actor's heap.
 #[no_mangle]
-pub extern "C" fn mesh_actor_receive(timeout_ms: i64) -> *const u8 {
+pub extern "C-unwind" fn mesh_actor_receive(timeout_ms: i64) -> *const u8 {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return std::ptr::null(),
@@ -548,7 +557,7 @@ pub extern "C" fn mesh_actor_receive(timeout_ms: i64) -> *const u8 {
 /// so the scheduler continues to resume it. On each resume, checks if the
 /// deadline has passed. Does NOT consume messages from the mailbox.
 #[no_mangle]
-pub extern "C" fn mesh_timer_sleep(ms: i64) {
+pub extern "C-unwind" fn mesh_timer_sleep(ms: i64) {
     if ms <= 0 {
         return;
     }
@@ -582,7 +591,7 @@ pub extern "C" fn mesh_timer_sleep(ms: i64) {
 /// The message bytes are deep-copied at call time so the caller's stack frame
 /// can be freed safely.
 #[no_mangle]
-pub extern "C" fn mesh_timer_send_after(target_pid: i64, ms: i64, msg_ptr: *const u8, msg_size: i64) {
+pub extern "C-unwind" fn mesh_timer_send_after(target_pid: i64, ms: i64, msg_ptr: *const u8, msg_size: i64) {
     // Deep-copy message bytes before spawning thread
     let data = if msg_ptr.is_null() || msg_size <= 0 {
         Vec::new()
@@ -658,7 +667,7 @@ pub(crate) fn copy_msg_to_actor_heap(
 ///
 /// - `target_pid`: the PID of the actor to link with
 #[no_mangle]
-pub extern "C" fn mesh_actor_link(target_pid: u64) {
+pub extern "C-unwind" fn mesh_actor_link(target_pid: u64) {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return,
@@ -691,9 +700,9 @@ pub extern "C" fn mesh_actor_link(target_pid: u64) {
 ///
 /// - `pid`: the PID of the actor to set the callback for
 /// - `callback_fn_ptr`: pointer to the terminate callback function
-///   with signature `extern "C" fn(state_ptr: *const u8, reason_ptr: *const u8)`
+///   with signature `extern "C-unwind" fn(state_ptr: *const u8, reason_ptr: *const u8)`
 #[no_mangle]
-pub extern "C" fn mesh_actor_set_terminate(pid: u64, callback_fn_ptr: *const u8) {
+pub extern "C-unwind" fn mesh_actor_set_terminate(pid: u64, callback_fn_ptr: *const u8) {
     if callback_fn_ptr.is_null() {
         return;
     }
@@ -717,7 +726,7 @@ pub extern "C" fn mesh_actor_set_terminate(pid: u64, callback_fn_ptr: *const u8)
 /// The scheduler shuts down when the active process count reaches zero
 /// (i.e., all spawned actors have completed or been force-terminated).
 #[no_mangle]
-pub extern "C" fn mesh_rt_run_scheduler() {
+pub extern "C-unwind" fn mesh_rt_run_scheduler() {
     // Get the main thread's PID before clearing it.
     let main_pid = stack::get_current_pid();
 
@@ -752,7 +761,7 @@ pub extern "C" fn mesh_rt_run_scheduler() {
 /// - `name_ptr`: pointer to UTF-8 name bytes
 /// - `name_len`: length of the name in bytes
 #[no_mangle]
-pub extern "C" fn mesh_actor_register(name_ptr: *const u8, name_len: u64) -> u64 {
+pub extern "C-unwind" fn mesh_actor_register(name_ptr: *const u8, name_len: u64) -> u64 {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return 1,
@@ -782,7 +791,7 @@ pub extern "C" fn mesh_actor_register(name_ptr: *const u8, name_len: u64) -> u64
 /// Takes a MeshString pointer for the name and a raw PID u64.
 /// Returns 0 on success, 1 on error.
 #[no_mangle]
-pub extern "C" fn mesh_process_register(name: *const crate::string::MeshString, pid: u64) -> u64 {
+pub extern "C-unwind" fn mesh_process_register(name: *const crate::string::MeshString, pid: u64) -> u64 {
     if name.is_null() || pid == 0 {
         return 1;
     }
@@ -800,7 +809,7 @@ pub extern "C" fn mesh_process_register(name: *const crate::string::MeshString,
 /// Takes a MeshString pointer for the name.
 /// Returns the PID as u64, or 0 if not found.
 #[no_mangle]
-pub extern "C" fn mesh_process_whereis(name: *const crate::string::MeshString) -> u64 {
+pub extern "C-unwind" fn mesh_process_whereis(name: *const crate::string::MeshString) -> u64 {
     if name.is_null() {
         return 0;
     }
@@ -819,7 +828,7 @@ pub extern "C" fn mesh_process_whereis(name: *const crate::string::MeshString) -
 /// - `name_ptr`: pointer to UTF-8 name bytes
 /// - `name_len`: length of the name in bytes
 #[no_mangle]
-pub extern "C" fn mesh_actor_whereis(name_ptr: *const u8, name_len: u64) -> u64 {
+pub extern "C-unwind" fn mesh_actor_whereis(name_ptr: *const u8, name_len: u64) -> u64 {
     if name_ptr.is_null() || name_len == 0 {
         return 0;
     }
@@ -867,7 +876,7 @@ pub extern "C" fn mesh_actor_whereis(name_ptr: *const u8, name_len: u64) -> u64
 ///
 /// Returns the supervisor PID as `u64`, or `u64::MAX` on error.
 #[no_mangle]
-pub extern "C" fn mesh_supervisor_start(
+pub extern "C-unwind" fn mesh_supervisor_start(
     config_ptr: *const u8,
     config_size: u64,
 ) -> u64 {
@@ -906,7 +915,7 @@ pub extern "C" fn mesh_supervisor_start(
     // Spawn the supervisor as a normal actor (no-op entry -- it doesn't run
     // a coroutine. The supervisor logic is driven externally by the compiled
     // Mesh program's receive loop or by the runtime's supervisor_entry).
-    extern "C" fn supervisor_noop(_args: *const u8) {}
+    extern "C-unwind" fn supervisor_noop(_args: *const u8) {}
     let sup_pid = sched.spawn(supervisor_noop as *const u8, std::ptr::null(), 0, 1);
 
     // Set trap_exit on the supervisor process.
@@ -936,7 +945,7 @@ pub extern "C" fn mesh_supervisor_start(
 ///
 /// Returns the child PID as `u64`, or `u64::MAX` on error.
 #[no_mangle]
-pub extern "C" fn mesh_supervisor_start_child(
+pub extern "C-unwind" fn mesh_supervisor_start_child(
     sup_pid: u64,
     args_ptr: *const u8,
     args_size: u64,
@@ -988,7 +997,7 @@ pub extern "C" fn mesh_supervisor_start_child(
 ///
 /// Returns 0 on success, 1 on failure.
 #[no_mangle]
-pub extern "C" fn mesh_supervisor_terminate_child(
+pub extern "C-unwind" fn mesh_supervisor_terminate_child(
     sup_pid: u64,
     child_pid: u64,
 ) -> u64 {
@@ -1019,7 +1028,7 @@ pub extern "C" fn mesh_supervisor_terminate_child(
 /// Returns the number of currently running children, or 0 if the
 /// supervisor PID is not found.
 #[no_mangle]
-pub extern "C" fn mesh_supervisor_count_children(sup_pid: u64) -> u64 {
+pub extern "C-unwind" fn mesh_supervisor_count_children(sup_pid: u64) -> u64 {
     let sup_pid = ProcessId(sup_pid);
 
     match supervisor::get_supervisor_state(sup_pid) {
@@ -1035,7 +1044,7 @@ pub extern "C" fn mesh_supervisor_count_children(sup_pid: u64) -> u64 {
 /// causing this process to crash. Used by supervisors to monitor
 /// children, and by regular actors that want to handle linked exits.
 #[no_mangle]
-pub extern "C" fn mesh_actor_trap_exit() {
+pub extern "C-unwind" fn mesh_actor_trap_exit() {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return,
@@ -1060,7 +1069,7 @@ pub extern "C" fn mesh_actor_trap_exit() {
 /// For other reasons: if the target has trap_exit enabled, the signal is
 /// delivered as a message. Otherwise, the target is terminated immediately.
 #[no_mangle]
-pub extern "C" fn mesh_actor_exit(target_pid: u64, reason_tag: u8) {
+pub extern "C-unwind" fn mesh_actor_exit(target_pid: u64, reason_tag: u8) {
     let sched = global_scheduler();
     let pid = ProcessId(target_pid);
 
@@ -1117,7 +1126,7 @@ pub extern "C" fn mesh_actor_exit(target_pid: u64, reason_tag: u8) {
 ///
 /// Returns a unique monitor reference (u64) that can be used to demonitor.
 #[no_mangle]
-pub extern "C" fn mesh_process_monitor(target_pid: u64) -> u64 {
+pub extern "C-unwind" fn mesh_process_monitor(target_pid: u64) -> u64 {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return 0,
@@ -1215,7 +1224,7 @@ fn send_dist_monitor(from_pid: ProcessId, to_pid: ProcessId, monitor_ref: u64) -
 ///
 /// Returns 0 on success, 1 on failure (monitor not found).
 #[no_mangle]
-pub extern "C" fn mesh_process_demonitor(monitor_ref: u64) -> u64 {
+pub extern "C-unwind" fn mesh_process_demonitor(monitor_ref: u64) -> u64 {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return 1,
@@ -1275,7 +1284,7 @@ fn deliver_down_immediately(
 ///
 /// Returns 0 on success, 1 on failure.
 #[no_mangle]
-pub extern "C" fn mesh_node_monitor(node_ptr: *const u8, node_len: u64) -> u64 {
+pub extern "C-unwind" fn mesh_node_monitor(node_ptr: *const u8, node_len: u64) -> u64 {
     let my_pid = match stack::get_current_pid() {
         Some(pid) => pid,
         None => return 1,
@@ -1323,7 +1332,7 @@ pub extern "C" fn mesh_node_monitor(node_ptr: *const u8, node_len: u64) -> u64 {
 /// - `name_len`: length of the name in bytes
 /// - `pid`: raw u64 PID value
 #[no_mangle]
-pub extern "C" fn mesh_global_register(
+pub extern "C-unwind" fn mesh_global_register(
     name_ptr: *const u8,
     name_len: u64,
     pid: u64,
@@ -1369,7 +1378,7 @@ pub extern "C" fn mesh_global_register(
 /// - `name_ptr`: pointer to UTF-8 name bytes
 /// - `name_len`: length of the name in bytes
 #[no_mangle]
-pub extern "C" fn mesh_global_whereis(
+pub extern "C-unwind" fn mesh_global_whereis(
     name_ptr: *const u8,
     name_len: u64,
 ) -> u64 {
@@ -1399,7 +1408,7 @@ pub extern "C" fn mesh_global_whereis(
 /// - `name_ptr`: pointer to UTF-8 name bytes
 /// - `name_len`: length of the name in bytes
 #[no_mangle]
-pub extern "C" fn mesh_global_unregister(
+pub extern "C-unwind" fn mesh_global_unregister(
     name_ptr: *const u8,
     name_len: u64,
 ) -> u64 {
@@ -1619,7 +1628,7 @@ mod tests {
     /// Helper: create a process in a scheduler and return its PID.
     fn create_test_process(sched: &Scheduler) -> ProcessId {
         // Use a no-op entry function.
-        extern "C" fn noop(_args: *const u8) {}
+        extern "C-unwind" fn noop(_args: *const u8) {}
         sched.spawn(noop as *const u8, std::ptr::null(), 0, 1)
     }
 
@@ -1724,7 +1733,7 @@ mod tests {
     #[test]
     fn test_receive_returns_null_outside_actor() {
         // mesh_actor_receive requires a current PID. Without one, returns null.
-        // Note: we can't easily test this through the extern "C" fn because
+        // Note: we can't easily test this through the extern "C-unwind" fn because
         // it requires GLOBAL_SCHEDULER. Test the logic instead.
         assert!(stack::get_current_pid().is_none());
         // If we called mesh_actor_receive here, it would return null because
@@ -1931,7 +1940,7 @@ mod tests {
 
         static TERM_CB_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn test_terminate_cb(_state: *const u8, _reason: *const u8) {
+        extern "C-unwind" fn test_terminate_cb(_state: *const u8, _reason: *const u8) {
             TERM_CB_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
@@ -1965,7 +1974,7 @@ mod tests {
 
         static ORDER_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn order_terminate_cb(_state: *const u8, _reason: *const u8) {
+        extern "C-unwind" fn order_terminate_cb(_state: *const u8, _reason: *const u8) {
             ORDER_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
@@ -140,6 +140,9 @@ pub enum ExitReason {
     /// Transient children do NOT restart on Shutdown.
     Shutdown,
     /// Runtime error (e.g., pattern match failure, division by zero).
+    ///
+    /// Crashes of compiled Mesh code carry a backtrace after the message
+    /// (see [`crate::backtrace`]).
     Error(String),
     /// Explicitly killed via `Process.exit(pid, :kill)`.
     Killed,
@@ -154,6 +157,19 @@ pub enum ExitReason {
     Noconnection,
 }
 
+impl fmt::Display for ExitReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExitReason::Normal => write!(f, "normal"),
+            ExitReason::Shutdown => write!(f, "shutdown"),
+            ExitReason::Error(msg) | ExitReason::Custom(msg) => write!(f, "{}", msg),
+            ExitReason::Killed => write!(f, "killed"),
+            ExitReason::Linked(pid, reason) => write!(f, "linked process {} exited: {}", pid, reason),
+            ExitReason::Noconnection => write!(f, "noconnection"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Priority
 // ---------------------------------------------------------------------------
@@ -207,7 +223,7 @@ pub struct Message {
 ///
 /// - `state_ptr`: pointer to the actor's current state (GenServer state, etc.)
 /// - `reason_ptr`: pointer to a serialized `ExitReason`
-pub type TerminateCallback = extern "C" fn(state_ptr: *const u8, reason_ptr: *const u8);
+pub type TerminateCallback = extern "C-unwind" fn(state_ptr: *const u8, reason_ptr: *const u8);
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -395,6 +411,16 @@ mod tests {
         assert!(dbg.contains("High"));
     }
 
+    #[test]
+    fn test_exit_reason_display() {
+        assert_eq!(ExitReason::Normal.to_string(), "normal");
+        assert_eq!(ExitReason::Error("boom".to_string()).to_string(), "boom");
+        assert_eq!(
+            ExitReason::Linked(ProcessId(7), Box::new(ExitReason::Killed)).to_string(),
+            "linked process <0.7> exited: killed"
+        );
+    }
+
     #[test]
     fn test_pid_bit_packing_roundtrip() {
         let pid = ProcessId::from_remote(5, 3, 42);
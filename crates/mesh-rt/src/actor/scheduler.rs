@@ -38,7 +38,9 @@ use super::process::{
     ExitReason, Priority, Process, ProcessId, ProcessState, TerminateCallback, DEFAULT_REDUCTIONS,
 };
 use super::registry;
-use super::stack::{clear_current_pid, set_current_pid, CoroutineHandle, CURRENT_YIELDER};
+use super::stack::{
+    clear_current_pid, set_current_pid, take_pending_crash, CoroutineHandle, CURRENT_YIELDER,
+};
 
 // ---------------------------------------------------------------------------
 // SpawnRequest
@@ -436,8 +438,8 @@ fn worker_loop(
                 }
                 still_suspended.push((pid, handle));
             } else {
-                // Actor completed.
-                handle_process_exit(&process_table, pid, ExitReason::Normal);
+                // Actor completed, or crashed and unwound its stack.
+                handle_process_exit(&process_table, pid, completion_reason());
                 active_count.fetch_sub(1, Ordering::SeqCst);
             }
         }
@@ -478,8 +480,8 @@ fn worker_loop(
                 }
                 suspended.push((req.pid, handle));
             } else {
-                // Actor completed on first run.
-                handle_process_exit(&process_table, req.pid, ExitReason::Normal);
+                // Actor completed (or crashed) on first run.
+                handle_process_exit(&process_table, req.pid, completion_reason());
                 active_count.fetch_sub(1, Ordering::SeqCst);
             }
         }
@@ -598,6 +600,15 @@ fn try_get_request(
     None
 }
 
+/// Exit reason of a coroutine that has just completed: `Error` with the
+/// reason it left in `PENDING_CRASH` if it crashed, `Normal` otherwise.
+fn completion_reason() -> ExitReason {
+    match take_pending_crash() {
+        Some(reason) => ExitReason::Error(reason),
+        None => ExitReason::Normal,
+    }
+}
+
 /// Handle process exit: invoke terminate callback, propagate exit to links,
 /// clean up from process table.
 ///
@@ -716,7 +727,7 @@ mod tests {
 
     static SPAWN_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-    extern "C" fn increment_entry(_args: *const u8) {
+    extern "C-unwind" fn increment_entry(_args: *const u8) {
         SPAWN_COUNTER.fetch_add(1, Ordering::SeqCst);
     }
 
@@ -774,6 +785,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_crashed_actors_release_their_stacks() {
+        static DROPPED: AtomicU64 = AtomicU64::new(0);
+
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        extern "C-unwind" fn crash_in_callback(_elem: u64) -> u64 {
+            crate::actor::stack::crash_current("boom".to_string());
+            0
+        }
+
+        extern "C-unwind" fn crashing_entry(_args: *const u8) {
+            let _guard = Guard;
+            // Crash from inside a runtime callback, as a Mesh closure would.
+            let list = crate::collections::list::mesh_list_from_array([1u64].as_ptr(), 1);
+            crate::collections::list::mesh_list_map(list, crash_in_callback as *mut u8, std::ptr::null_mut());
+        }
+
+        let num_actors = 50;
+        let sched = Scheduler::new(1);
+        let pids: Vec<ProcessId> = (0..num_actors)
+            .map(|_| sched.spawn(crashing_entry as *const u8, std::ptr::null(), 0, 1))
+            .collect();
+        sched.signal_shutdown();
+        sched.run();
+
+        // Every crashed actor unwound its stack, dropping its locals.
+        assert_eq!(DROPPED.load(Ordering::SeqCst), num_actors as u64);
+        let state = sched.get_process(pids[0]).map(|p| p.lock().state.clone());
+        assert!(
+            matches!(&state, Some(ProcessState::Exited(ExitReason::Error(r))) if r.starts_with("boom")),
+            "unexpected state: {:?}",
+            state
+        );
+    }
+
     #[test]
     fn test_work_stealing_distributes() {
         // Use a test-specific counter and thread-ID list to avoid
@@ -781,7 +833,7 @@ mod tests {
         static WS_COUNTER: AtomicU64 = AtomicU64::new(0);
         static WS_THREAD_IDS: Mutex<Vec<u64>> = Mutex::new(Vec::new());
 
-        extern "C" fn ws_record_entry(_args: *const u8) {
+        extern "C-unwind" fn ws_record_entry(_args: *const u8) {
             WS_COUNTER.fetch_add(1, Ordering::SeqCst);
             let tid = thread_id_hash();
             WS_THREAD_IDS.lock().push(tid);
@@ -818,7 +870,7 @@ mod tests {
         // Use a dedicated counter to avoid interference from concurrent tests.
         static YIELD_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn yield_entry(_args: *const u8) {
+        extern "C-unwind" fn yield_entry(_args: *const u8) {
             for _ in 0..5 {
                 super::super::stack::yield_current();
             }
@@ -845,14 +897,14 @@ mod tests {
         // Use a dedicated counter to avoid interference from concurrent tests.
         static STARVE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn starve_yield_entry(_args: *const u8) {
+        extern "C-unwind" fn starve_yield_entry(_args: *const u8) {
             for _ in 0..5 {
                 super::super::stack::yield_current();
             }
             STARVE_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
-        extern "C" fn starve_simple_entry(_args: *const u8) {
+        extern "C-unwind" fn starve_simple_entry(_args: *const u8) {
             STARVE_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
@@ -881,7 +933,7 @@ mod tests {
         // Use a dedicated counter to avoid interference from concurrent tests.
         static PRIO_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn prio_entry(_args: *const u8) {
+        extern "C-unwind" fn prio_entry(_args: *const u8) {
             PRIO_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
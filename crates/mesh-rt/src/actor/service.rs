@@ -36,7 +36,7 @@ use super::GLOBAL_SCHEDULER;
 /// - `payload_ptr`: pointer to argument bytes (array of i64 values)
 /// - `payload_size`: size of the payload in bytes
 #[no_mangle]
-pub extern "C" fn mesh_service_call(
+pub extern "C-unwind" fn mesh_service_call(
     target_pid: u64,
     msg_tag: u64,
     payload_ptr: *const u8,
@@ -123,7 +123,7 @@ pub extern "C" fn mesh_service_call(
 /// - `reply_ptr`: pointer to the reply data bytes
 /// - `reply_size`: size of the reply data in bytes
 #[no_mangle]
-pub extern "C" fn mesh_service_reply(
+pub extern "C-unwind" fn mesh_service_reply(
     caller_pid: u64,
     reply_ptr: *const u8,
     reply_size: u64,
@@ -145,7 +145,7 @@ mod tests {
     fn test_service_reply_sends_message() {
         let sched = Scheduler::new(1);
         // Create a "caller" process.
-        extern "C" fn noop(_args: *const u8) {}
+        extern "C-unwind" fn noop(_args: *const u8) {}
         let caller_pid = sched.spawn(noop as *const u8, std::ptr::null(), 0, 1);
 
         // Initialize the global scheduler for this test.
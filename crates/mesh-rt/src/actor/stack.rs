@@ -1,22 +1,43 @@
 //! Corosensei-based stackful coroutine management for Mesh actors.
 //!
-//! Each actor runs as a stackful coroutine with a 64 KiB stack. The coroutine
-//! yields when its reduction counter is exhausted, allowing the scheduler to
-//! run other actors on the same OS thread.
+//! Each actor runs as a stackful coroutine with a 512 KiB stack, or the size
+//! set with `mesh_rt_set_stack_size()`. The coroutine yields when its
+//! reduction counter is exhausted, allowing the scheduler to run other actors
+//! on the same OS thread.
 //!
 //! ## Thread-local State
 //!
-//! Three thread-locals track the current execution context:
+//! Four thread-locals track the current execution context:
 //! - `CURRENT_YIELDER`: pointer to the active coroutine's Yielder (for yield on reduction exhaustion)
 //! - `CURRENT_PID`: the PID of the currently running actor (for `mesh_actor_self()`)
 //! - `STACK_BASE`: base address of the coroutine stack (for GC stack scanning bounds)
+//! - `PENDING_CRASH`: crash reason left by an actor whose entry function unwound
 
 use corosensei::stack::DefaultStack;
 use corosensei::{Coroutine, CoroutineResult, Yielder};
 
 use super::process::{ProcessId, DEFAULT_STACK_SIZE};
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ---------------------------------------------------------------------------
+// Stack size
+// ---------------------------------------------------------------------------
+
+/// Stack size of newly spawned coroutines, set by `mesh_rt_set_stack_size()`.
+static STACK_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_STACK_SIZE);
+
+/// Set the stack size of coroutines spawned from now on (0 = the default).
+pub fn set_stack_size(bytes: usize) {
+    let bytes = if bytes == 0 { DEFAULT_STACK_SIZE } else { bytes };
+    STACK_SIZE.store(bytes, Ordering::Relaxed);
+}
+
+/// The stack size new coroutines are allocated with.
+pub fn stack_size() -> usize {
+    STACK_SIZE.load(Ordering::Relaxed)
+}
 
 // ---------------------------------------------------------------------------
 // Thread-local current-actor context
@@ -43,6 +64,13 @@ thread_local! {
     /// as `stack_bottom` when scanning for roots (stack grows downward, so
     /// the base is the highest address).
     pub static STACK_BASE: Cell<*const u8> = const { Cell::new(std::ptr::null()) };
+
+    /// Crash reason recorded when the current coroutine's entry function
+    /// unwinds (see `crash_current`).
+    ///
+    /// The scheduler checks this when a coroutine completes: a set value means
+    /// the actor crashed rather than returning normally.
+    pub static PENDING_CRASH: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
 /// Set the current actor PID on this thread.
@@ -99,6 +127,49 @@ pub fn yield_current() {
     });
 }
 
+/// Unwind payload of an actor crash, carrying the reason.
+struct ActorCrash(String);
+
+/// Crash the current actor with the given reason.
+///
+/// Appends the actor's Mesh backtrace (see [`crate::backtrace`]) to the
+/// reason and unwinds the coroutine stack, so destructors in every frame
+/// run: lock guards are released and owned spawn args, buffers and sockets
+/// are dropped. `catch_unwind` sites on the way (an HTTP handler, a
+/// transaction) can recover; otherwise the coroutine body records the
+/// reason in `PENDING_CRASH` and the scheduler exits the process with
+/// `ExitReason::Error(reason)`. Runtime entry points use the `"C-unwind"`
+/// ABI so the unwind can pass through them and through Mesh frames.
+///
+/// Returns immediately, without recording anything, when called outside of
+/// a coroutine; the caller decides how to fail in that case.
+pub fn crash_current(reason: String) {
+    if CURRENT_YIELDER.with(|c| c.get().is_none()) {
+        return;
+    }
+    let reason = crate::backtrace::with_backtrace(reason);
+    std::panic::resume_unwind(Box::new(ActorCrash(reason)));
+}
+
+/// The crash reason carried by an unwind payload: the reason given to
+/// `crash_current`, or the message of a Rust panic.
+pub fn crash_reason(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(crash) = payload.downcast_ref::<ActorCrash>() {
+        crash.0.clone()
+    } else if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "actor panicked".to_string()
+    }
+}
+
+/// Take the crash reason left by the coroutine that just ran, if any.
+pub fn take_pending_crash() -> Option<String> {
+    PENDING_CRASH.with(|c| c.borrow_mut().take())
+}
+
 // ---------------------------------------------------------------------------
 // CoroutineHandle
 // ---------------------------------------------------------------------------
@@ -119,13 +190,13 @@ impl CoroutineHandle {
     /// Create a new coroutine that will call `entry_fn(args_ptr)`.
     ///
     /// The entry function signature matches the Mesh actor ABI:
-    /// `extern "C" fn(args: *const u8)`.
+    /// `extern "C-unwind" fn(args: *const u8)`.
     ///
     /// The coroutine installs its Yielder into the thread-local before
     /// calling the entry function, so `mesh_reduction_check()` can yield.
     pub fn new(entry_fn: *const u8, args_ptr: *const u8) -> Self {
         let stack =
-            DefaultStack::new(DEFAULT_STACK_SIZE).expect("failed to allocate coroutine stack");
+            DefaultStack::new(stack_size()).expect("failed to allocate coroutine stack");
 
         // Capture the function pointer and args for the closure.
         let fn_ptr = entry_fn as usize;
@@ -156,10 +227,13 @@ impl CoroutineHandle {
             });
 
             // Call the actor entry function.
-            // Safety: The fn_ptr was provided by the scheduler from a valid extern "C" fn.
-            let func: extern "C" fn(*const u8) =
-                unsafe { std::mem::transmute::<usize, extern "C" fn(*const u8)>(fn_ptr) };
-            func(args as *const u8);
+            // Safety: The fn_ptr was provided by the scheduler from a valid extern "C-unwind" fn.
+            let func: extern "C-unwind" fn(*const u8) =
+                unsafe { std::mem::transmute::<usize, extern "C-unwind" fn(*const u8)>(fn_ptr) };
+            if let Err(payload) = std::panic::catch_unwind(|| func(args as *const u8)) {
+                // The stack has unwound; leave the reason for the scheduler.
+                PENDING_CRASH.with(|c| *c.borrow_mut() = Some(crash_reason(&*payload)));
+            }
 
             // No need to clear CURRENT_YIELDER here -- the scheduler clears
             // the thread-local context after resume returns (whether yield or
@@ -209,7 +283,7 @@ mod tests {
         // Use a test-specific counter to avoid interference from concurrent tests.
         static COMPLETION_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn completion_entry(_args: *const u8) {
+        extern "C-unwind" fn completion_entry(_args: *const u8) {
             COMPLETION_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
 
@@ -228,7 +302,7 @@ mod tests {
         // Use a test-specific counter to avoid interference from concurrent tests.
         static YIELD_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-        extern "C" fn yield_entry(_args: *const u8) {
+        extern "C-unwind" fn yield_entry(_args: *const u8) {
             YIELD_COUNTER.fetch_add(1, Ordering::SeqCst);
             yield_current();
             YIELD_COUNTER.fetch_add(1, Ordering::SeqCst);
@@ -250,6 +324,45 @@ mod tests {
         assert_eq!(YIELD_COUNTER.load(Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn test_crash_current_records_reason_and_unwinds() {
+        static AFTER_CRASH: AtomicU64 = AtomicU64::new(0);
+        static DROPPED: AtomicU64 = AtomicU64::new(0);
+
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        extern "C-unwind" fn crashing_entry(_args: *const u8) {
+            let _guard = Guard;
+            crash_current("boom".to_string());
+            AFTER_CRASH.fetch_add(1, Ordering::SeqCst);
+        }
+
+        AFTER_CRASH.store(0, Ordering::SeqCst);
+        DROPPED.store(0, Ordering::SeqCst);
+        let mut handle = CoroutineHandle::new(crashing_entry as *const u8, std::ptr::null());
+
+        // A crash completes the coroutine, with the reason left behind and
+        // the crashing frame's locals dropped.
+        assert!(!handle.resume());
+        assert!(handle.done());
+        CURRENT_YIELDER.with(|c| c.set(None));
+        assert_eq!(take_pending_crash().as_deref(), Some("boom"));
+        assert_eq!(take_pending_crash(), None);
+        assert_eq!(AFTER_CRASH.load(Ordering::SeqCst), 0);
+        assert_eq!(DROPPED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_crash_current_outside_coroutine_is_noop() {
+        crash_current("ignored".to_string());
+        assert_eq!(take_pending_crash(), None);
+    }
+
     #[test]
     fn test_current_pid_thread_local() {
         assert!(get_current_pid().is_none());
@@ -259,4 +372,19 @@ mod tests {
         clear_current_pid();
         assert!(get_current_pid().is_none());
     }
+
+    #[test]
+    fn test_stack_size_setting() {
+        // Larger than the default, so concurrently spawned test coroutines
+        // are unaffected.
+        extern "C-unwind" fn noop_entry(_args: *const u8) {}
+
+        set_stack_size(1024 * 1024);
+        assert_eq!(stack_size(), 1024 * 1024);
+        let mut handle = CoroutineHandle::new(noop_entry as *const u8, std::ptr::null());
+        assert!(!handle.resume());
+
+        set_stack_size(0);
+        assert_eq!(stack_size(), DEFAULT_STACK_SIZE);
+    }
 }
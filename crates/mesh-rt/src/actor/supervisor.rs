@@ -521,6 +521,16 @@ pub fn handle_child_exit(
         RestartType::Temporary => false,
     };
 
+    // Log abnormal exits for every child kind, including the crash
+    // backtrace carried by the reason, before deciding whether to restart.
+    let child_id = state.children[child_idx].spec.id.clone();
+    if !matches!(reason, ExitReason::Normal | ExitReason::Shutdown) {
+        eprintln!(
+            "[mesh-rt] supervisor {}: child '{}' ({}) exited: {}",
+            sup_pid, child_id, child_pid, reason
+        );
+    }
+
     if restart_type == RestartType::Temporary {
         // Remove from children list.
         state.children.remove(child_idx);
@@ -534,6 +544,10 @@ pub fn handle_child_exit(
     // Check restart limit before restarting.
     if !check_restart_limit(state) {
         // Restart limit exceeded -- terminate all children and fail.
+        eprintln!(
+            "[mesh-rt] supervisor {}: restart limit reached ({} restarts in {} seconds), not restarting '{}'",
+            sup_pid, state.max_restarts, state.max_seconds, child_id
+        );
         terminate_all_children(state, scheduler, sup_pid);
         return Err(format!(
             "restart limit exceeded: {} restarts in {} seconds",
@@ -541,6 +555,8 @@ pub fn handle_child_exit(
         ));
     }
 
+    eprintln!("[mesh-rt] supervisor {}: restarting child '{}'", sup_pid, child_id);
+
     // Apply the strategy.
     apply_strategy(state, child_idx, scheduler, sup_pid)
 }
@@ -588,7 +604,7 @@ mod tests {
     }
 
     /// No-op entry function for test actors.
-    extern "C" fn noop_entry(_args: *const u8) {
+    extern "C-unwind" fn noop_entry(_args: *const u8) {
         // Do nothing -- immediately returns.
     }
 
@@ -0,0 +1,219 @@
+//! Symbolized backtraces for crashed actors.
+//!
+//! When an actor crashes (`crash_current`), the runtime captures the native
+//! stack and keeps only the frames of compiled Mesh functions, shown under
+//! their source names (`Module.fn/arity`) rather than their mangled symbols.
+//! The result is appended to the crash reason, so links, monitors,
+//! supervisor restart logs and the `meshc test` runner all see it:
+//!
+//! ```text
+//! math.mpl:3: assert failed
+//! backtrace:
+//!     Math.div/2 at /app/math.mpl:3
+//!     Main.worker/1 at /app/main.mpl:12
+//! ```
+//!
+//! Compiled programs register the symbol of every Mesh function at startup
+//! with `mesh_register_symbol`. File and line information comes from the
+//! binary's DWARF debug info (`meshc build --debug`, on by default at `-O0`);
+//! without it, frames show only the function name.
+
+use std::sync::OnceLock;
+
+use parking_lot::RwLock;
+use rustc_hash::FxHashMap;
+
+/// Separates the crash message from the backtrace in a crash reason.
+pub const BACKTRACE_HEADER: &str = "\nbacktrace:\n";
+
+/// Most frames shown in one backtrace; deeper frames are summarized.
+const MAX_FRAMES: usize = 50;
+
+/// Symbol name -> Mesh source name (`Module.fn/arity`).
+static SYMBOLS: OnceLock<RwLock<FxHashMap<String, String>>> = OnceLock::new();
+
+fn symbols() -> &'static RwLock<FxHashMap<String, String>> {
+    SYMBOLS.get_or_init(|| RwLock::new(FxHashMap::default()))
+}
+
+/// Register the source name of a compiled Mesh function.
+///
+/// Called by codegen-emitted code in the main wrapper at program startup,
+/// once per function with a known source location.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_register_symbol(
+    symbol_ptr: *const u8,
+    symbol_len: u64,
+    name_ptr: *const u8,
+    name_len: u64,
+) {
+    if symbol_ptr.is_null() || name_ptr.is_null() {
+        return;
+    }
+    let (symbol, name) = unsafe {
+        (
+            std::str::from_utf8_unchecked(std::slice::from_raw_parts(symbol_ptr, symbol_len as usize)),
+            std::str::from_utf8_unchecked(std::slice::from_raw_parts(name_ptr, name_len as usize)),
+        )
+    };
+    symbols().write().insert(symbol.to_string(), name.to_string());
+}
+
+/// Append the current Mesh backtrace to a crash reason.
+///
+/// Returns `reason` unchanged when no Mesh symbols are registered (e.g. in
+/// runtime unit tests) or no Mesh frame is on the stack.
+pub fn with_backtrace(reason: String) -> String {
+    match capture() {
+        Some(backtrace) => format!("{}{}{}", reason, BACKTRACE_HEADER, backtrace),
+        None => reason,
+    }
+}
+
+/// Split a crash reason into its message and backtrace, if it has one.
+pub fn split_backtrace(reason: &str) -> (&str, Option<&str>) {
+    match reason.split_once(BACKTRACE_HEADER) {
+        Some((message, backtrace)) => (message, Some(backtrace)),
+        None => (reason, None),
+    }
+}
+
+/// Capture the stack and render its Mesh frames, innermost first.
+fn capture() -> Option<String> {
+    let symbols = symbols().read();
+    if symbols.is_empty() {
+        return None;
+    }
+    let raw = std::backtrace::Backtrace::force_capture().to_string();
+    let frames = symbolize(&raw, &symbols);
+    (!frames.is_empty()).then(|| frames.join("\n"))
+}
+
+/// Extract the Mesh frames from a rendered `std::backtrace::Backtrace`.
+///
+/// Each symbol is printed on its own line (`  3: Math__div`, or without the
+/// index for inlined frames), optionally followed by an `at file:line:col`
+/// line. Symbols that are not registered Mesh functions are dropped.
+fn symbolize(raw: &str, symbols: &FxHashMap<String, String>) -> Vec<String> {
+    let mut frames = Vec::new();
+    let mut current: Option<String> = None;
+    let mut omitted = 0;
+
+    let mut push = |frame: String, frames: &mut Vec<String>| {
+        if frames.len() < MAX_FRAMES {
+            frames.push(frame);
+        } else {
+            omitted += 1;
+        }
+    };
+
+    for line in raw.lines() {
+        let line = line.trim();
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(name) = current.take() {
+                push(format!("    {} at {}", name, strip_column(location)), &mut frames);
+            }
+            continue;
+        }
+        if let Some(name) = current.take() {
+            push(format!("    {}", name), &mut frames);
+        }
+        let symbol = match line.split_once(": ") {
+            Some((index, symbol)) if index.chars().all(|c| c.is_ascii_digit()) => symbol,
+            _ => line,
+        };
+        current = lookup(symbols, symbol).map(str::to_string);
+    }
+    if let Some(name) = current {
+        push(format!("    {}", name), &mut frames);
+    }
+
+    if omitted > 0 {
+        frames.push(format!("    ... {} more", omitted));
+    }
+    frames
+}
+
+/// Source name of a symbol. Mach-O symbols may keep their leading underscore.
+fn lookup<'a>(symbols: &'a FxHashMap<String, String>, symbol: &str) -> Option<&'a str> {
+    symbols
+        .get(symbol)
+        .or_else(|| symbol.strip_prefix('_').and_then(|s| symbols.get(s)))
+        .map(String::as_str)
+}
+
+/// `file:line:col` -> `file:line`. Mesh debug info has no column numbers.
+fn strip_column(location: &str) -> &str {
+    let mut parts = location.rsplitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(col), Some(line), Some(_))
+            if col.chars().all(|c| c.is_ascii_digit()) && line.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            &location[..location.len() - col.len() - 1]
+        }
+        _ => location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> FxHashMap<String, String> {
+        [("Math__div", "Math.div/2"), ("mesh_main", "Main.main/0")]
+            .into_iter()
+            .map(|(s, n)| (s.to_string(), n.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_symbolize_keeps_mesh_frames_with_locations() {
+        let raw = "   0: std::backtrace::Backtrace::force_capture
+             at /rustc/library/std/src/backtrace.rs:312:13
+   1: mesh_rt::backtrace::capture
+   2: Math__div
+             at /app/math.mpl:3:0
+   3: _mesh_main
+             at /app/main.mpl:8
+   4: main
+";
+        assert_eq!(
+            symbolize(raw, &table()),
+            vec![
+                "    Math.div/2 at /app/math.mpl:3",
+                "    Main.main/0 at /app/main.mpl:8",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symbolize_without_debug_info_and_inlined_frames() {
+        let raw = "   0: mesh_assert_fail
+   1: Math__div
+      mesh_main
+   2: main
+";
+        assert_eq!(
+            symbolize(raw, &table()),
+            vec!["    Math.div/2", "    Main.main/0"]
+        );
+    }
+
+    #[test]
+    fn test_symbolize_truncates_deep_stacks() {
+        let raw = (0..MAX_FRAMES + 5)
+            .map(|i| format!("{:4}: Math__div", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let frames = symbolize(&raw, &table());
+        assert_eq!(frames.len(), MAX_FRAMES + 1);
+        assert_eq!(frames.last().unwrap(), "    ... 5 more");
+    }
+
+    #[test]
+    fn test_split_backtrace() {
+        let reason = format!("boom{}    Math.div/2", BACKTRACE_HEADER);
+        assert_eq!(split_backtrace(&reason), ("boom", Some("    Math.div/2")));
+        assert_eq!(split_backtrace("boom"), ("boom", None));
+    }
+}
@@ -0,0 +1,623 @@
+//! Arbitrary-precision integers for the Mesh runtime.
+//!
+//! A `BigInt` is an immutable, GC-allocated sign and magnitude. The magnitude
+//! is stored as little-endian limbs in base 10^9, so printing, parsing and
+//! the power-of-ten scaling that `Decimal` relies on need no base conversion.
+//! Every operation returns a new value.
+//!
+//! The arithmetic itself works on the owned [`Big`] type; the `mesh_bigint_*`
+//! entry points copy their operands out of the GC heap, compute, and copy the
+//! result back in.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::gc::mesh_gc_alloc_actor;
+use crate::io::alloc_result;
+use crate::json::MeshJson;
+use crate::option::alloc_option;
+use crate::string::{mesh_string_new, MeshString};
+
+const BASE: u64 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+/// An owned arbitrary-precision integer.
+///
+/// Normalized: the magnitude has no high zero limbs, and zero is never
+/// negative, so the derived equality is numeric equality.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Big {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl Big {
+    pub(crate) fn from_i64(value: i64) -> Big {
+        let mut mag = value.unsigned_abs();
+        let mut limbs = Vec::new();
+        while mag > 0 {
+            limbs.push((mag % BASE) as u32);
+            mag /= BASE;
+        }
+        Big { negative: value < 0, limbs }
+    }
+
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> Big {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        let negative = negative && !limbs.is_empty();
+        Big { negative, limbs }
+    }
+
+    /// Parse an optionally signed run of ASCII digits.
+    pub(crate) fn parse(text: &str) -> Option<Big> {
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let limbs = digits
+            .as_bytes()
+            .rchunks(BASE_DIGITS)
+            .map(|chunk| chunk.iter().fold(0u32, |acc, d| acc * 10 + (d - b'0') as u32))
+            .collect();
+        Some(Big::from_parts(negative, limbs))
+    }
+
+    pub(crate) fn to_i64(&self) -> Option<i64> {
+        let mut mag: i128 = 0;
+        for &limb in self.limbs.iter().rev() {
+            mag = mag * BASE as i128 + limb as i128;
+            if mag > i64::MAX as i128 + 1 {
+                return None;
+            }
+        }
+        i64::try_from(if self.negative { -mag } else { mag }).ok()
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub(crate) fn neg(&self) -> Big {
+        Big::from_parts(!self.negative, self.limbs.clone())
+    }
+
+    pub(crate) fn abs(&self) -> Big {
+        Big { negative: false, limbs: self.limbs.clone() }
+    }
+
+    pub(crate) fn add(&self, other: &Big) -> Big {
+        if self.negative == other.negative {
+            return Big::from_parts(self.negative, add_mag(&self.limbs, &other.limbs));
+        }
+        match cmp_mag(&self.limbs, &other.limbs) {
+            Ordering::Less => Big::from_parts(other.negative, sub_mag(&other.limbs, &self.limbs)),
+            _ => Big::from_parts(self.negative, sub_mag(&self.limbs, &other.limbs)),
+        }
+    }
+
+    pub(crate) fn sub(&self, other: &Big) -> Big {
+        self.add(&other.neg())
+    }
+
+    pub(crate) fn mul(&self, other: &Big) -> Big {
+        Big::from_parts(self.negative != other.negative, mul_mag(&self.limbs, &other.limbs))
+    }
+
+    /// Truncating division: the quotient rounds toward zero and the
+    /// remainder takes the sign of the dividend, like `Int`'s `/` and `%`.
+    /// None when `other` is zero.
+    pub(crate) fn div_rem(&self, other: &Big) -> Option<(Big, Big)> {
+        if other.is_zero() {
+            return None;
+        }
+        let (q, r) = div_rem_mag(&self.limbs, &other.limbs);
+        Some((
+            Big::from_parts(self.negative != other.negative, q),
+            Big::from_parts(self.negative, r),
+        ))
+    }
+
+    /// `self * 10^exp`.
+    pub(crate) fn mul_pow10(&self, exp: u32) -> Big {
+        let mut limbs = vec![0; exp as usize / BASE_DIGITS];
+        limbs.extend_from_slice(&self.limbs);
+        let rest = 10u32.pow(exp % BASE_DIGITS as u32);
+        Big::from_parts(self.negative, mul_small(&limbs, rest))
+    }
+
+    /// Whether the magnitude is a multiple of ten.
+    pub(crate) fn is_multiple_of_ten(&self) -> bool {
+        self.limbs.first().is_none_or(|low| low % 10 == 0)
+    }
+
+    pub(crate) fn pow(&self, mut exp: u64) -> Big {
+        let mut result = Big::from_i64(1);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /// The decimal digits of the magnitude, without sign.
+    pub(crate) fn magnitude_digits(&self) -> String {
+        let mut out = match self.limbs.last() {
+            Some(top) => top.to_string(),
+            None => return "0".to_string(),
+        };
+        for limb in self.limbs.iter().rev().skip(1) {
+            out.push_str(&format!("{:09}", limb));
+        }
+        out
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Big) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.limbs, &other.limbs),
+            (true, true) => cmp_mag(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Big) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Big {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.magnitude_digits())
+    }
+}
+
+// ── Magnitude arithmetic ────────────────────────────────────────────────
+
+fn trim(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        out.push((sum % BASE) as u32);
+        carry = sum / BASE;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// `a - b`, where `a >= b`.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = (diff < 0) as i64;
+        if diff < 0 {
+            diff += BASE as i64;
+        }
+        out.push(diff as u32);
+    }
+    trim(&mut out);
+    out
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut acc = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = acc[i + j] + x as u64 * y as u64 + carry;
+            acc[i + j] = cur % BASE;
+            carry = cur / BASE;
+        }
+        acc[i + b.len()] += carry;
+    }
+    let mut out: Vec<u32> = acc.into_iter().map(|limb| limb as u32).collect();
+    trim(&mut out);
+    out
+}
+
+fn mul_small(a: &[u32], m: u32) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for &limb in a {
+        let cur = limb as u64 * m as u64 + carry;
+        out.push((cur % BASE) as u32);
+        carry = cur / BASE;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    trim(&mut out);
+    out
+}
+
+/// Schoolbook long division of magnitudes; `b` is nonzero. Each quotient
+/// limb is found by binary search over the base.
+fn div_rem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem: Vec<u32> = Vec::new();
+    for i in (0..a.len()).rev() {
+        rem.insert(0, a[i]);
+        trim(&mut rem);
+        let (mut lo, mut hi) = (0u32, (BASE - 1) as u32);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if cmp_mag(&mul_small(b, mid), &rem) == Ordering::Greater {
+                hi = mid - 1;
+            } else {
+                lo = mid;
+            }
+        }
+        if lo > 0 {
+            rem = sub_mag(&rem, &mul_small(b, lo));
+        }
+        quotient[i] = lo;
+    }
+    trim(&mut quotient);
+    (quotient, rem)
+}
+
+// ── GC representation ───────────────────────────────────────────────────
+
+/// A GC-managed Mesh `BigInt`.
+///
+/// Layout: `{ negative: u32, len: u32 }` followed by `len` u32 limbs.
+#[repr(C)]
+pub struct MeshBigInt {
+    pub negative: u32,
+    pub len: u32,
+    // limbs follow immediately after this struct in memory
+}
+
+impl MeshBigInt {
+    /// Size of the header (the `negative` and `len` fields).
+    pub(crate) const HEADER_SIZE: usize = std::mem::size_of::<MeshBigInt>();
+
+    /// Copy the value out of the GC heap.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `self` points to a valid MeshBigInt allocation
+    /// with `self.len` limbs following the header.
+    pub(crate) unsafe fn to_big(&self) -> Big {
+        let data = (self as *const Self as *const u8).add(Self::HEADER_SIZE) as *const u32;
+        let limbs = std::slice::from_raw_parts(data, self.len as usize).to_vec();
+        Big { negative: self.negative != 0, limbs }
+    }
+
+    /// Write `value` at `dst`, which must have room for
+    /// `HEADER_SIZE + 4 * limbs` bytes.
+    pub(crate) unsafe fn write(dst: *mut MeshBigInt, value: &Big) {
+        (*dst).negative = value.negative as u32;
+        (*dst).len = value.limbs.len() as u32;
+        let data = (dst as *mut u8).add(Self::HEADER_SIZE) as *mut u32;
+        std::ptr::copy_nonoverlapping(value.limbs.as_ptr(), data, value.limbs.len());
+    }
+
+    /// Bytes needed to store `value`.
+    pub(crate) fn size_of(value: &Big) -> usize {
+        Self::HEADER_SIZE + value.limbs.len() * std::mem::size_of::<u32>()
+    }
+}
+
+/// Allocate a new BigInt holding `value`.
+pub(crate) fn alloc_bigint(value: &Big) -> *mut MeshBigInt {
+    unsafe {
+        let ptr = mesh_gc_alloc_actor(
+            MeshBigInt::size_of(value) as u64,
+            std::mem::align_of::<MeshBigInt>() as u64,
+        ) as *mut MeshBigInt;
+        MeshBigInt::write(ptr, value);
+        ptr
+    }
+}
+
+unsafe fn big(b: *const MeshBigInt) -> Big {
+    (*b).to_big()
+}
+
+fn mesh_str(s: &str) -> *mut MeshString {
+    mesh_string_new(s.as_ptr(), s.len() as u64)
+}
+
+/// Crash the current actor (or panic outside one) with `reason`.
+pub(crate) fn arith_crash(reason: &str) -> ! {
+    crate::actor::stack::crash_current(reason.to_string());
+    panic!("{}", reason);
+}
+
+// ── Public API ──────────────────────────────────────────────────────────
+
+/// BigInt.from_int(Int) -> BigInt
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_from_int(value: i64) -> *mut MeshBigInt {
+    alloc_bigint(&Big::from_i64(value))
+}
+
+/// BigInt.parse(String) -> Option<BigInt>
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_parse(s: *const MeshString) -> *mut u8 {
+    match Big::parse(unsafe { (*s).as_str() }) {
+        Some(v) => alloc_option(0, alloc_bigint(&v) as *mut u8) as *mut u8,
+        None => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+    }
+}
+
+/// BigInt.to_int(BigInt) -> Option<Int>, None when the value does not fit.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_to_int(b: *const MeshBigInt) -> *mut u8 {
+    match unsafe { big(b) }.to_i64() {
+        Some(v) => alloc_option(0, v as u64 as *mut u8) as *mut u8,
+        None => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+    }
+}
+
+/// BigInt.to_string(BigInt) -> String
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_to_string(b: *const MeshBigInt) -> *mut MeshString {
+    mesh_str(&unsafe { big(b) }.to_string())
+}
+
+/// `a + b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_add(a: *const MeshBigInt, b: *const MeshBigInt) -> *mut MeshBigInt {
+    unsafe { alloc_bigint(&big(a).add(&big(b))) }
+}
+
+/// `a - b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_sub(a: *const MeshBigInt, b: *const MeshBigInt) -> *mut MeshBigInt {
+    unsafe { alloc_bigint(&big(a).sub(&big(b))) }
+}
+
+/// `a * b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_mul(a: *const MeshBigInt, b: *const MeshBigInt) -> *mut MeshBigInt {
+    unsafe { alloc_bigint(&big(a).mul(&big(b))) }
+}
+
+/// `a / b`, rounding toward zero. Crashes on division by zero.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_div(a: *const MeshBigInt, b: *const MeshBigInt) -> *mut MeshBigInt {
+    match unsafe { big(a).div_rem(&big(b)) } {
+        Some((q, _)) => alloc_bigint(&q),
+        None => arith_crash("BigInt division by zero"),
+    }
+}
+
+/// `a % b`, with the sign of `a`. Crashes on division by zero.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_mod(a: *const MeshBigInt, b: *const MeshBigInt) -> *mut MeshBigInt {
+    match unsafe { big(a).div_rem(&big(b)) } {
+        Some((_, r)) => alloc_bigint(&r),
+        None => arith_crash("BigInt division by zero"),
+    }
+}
+
+/// `-a`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_neg(a: *const MeshBigInt) -> *mut MeshBigInt {
+    unsafe { alloc_bigint(&big(a).neg()) }
+}
+
+/// BigInt.abs(BigInt) -> BigInt
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_abs(a: *const MeshBigInt) -> *mut MeshBigInt {
+    unsafe { alloc_bigint(&big(a).abs()) }
+}
+
+/// BigInt.pow(BigInt, Int) -> BigInt. Crashes on a negative exponent.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_pow(a: *const MeshBigInt, exp: i64) -> *mut MeshBigInt {
+    if exp < 0 {
+        arith_crash("BigInt.pow with a negative exponent");
+    }
+    unsafe { alloc_bigint(&big(a).pow(exp as u64)) }
+}
+
+/// `a == b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_eq(a: *const MeshBigInt, b: *const MeshBigInt) -> i8 {
+    unsafe { (big(a) == big(b)) as i8 }
+}
+
+/// Three-way comparison: -1, 0 or 1.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_compare(a: *const MeshBigInt, b: *const MeshBigInt) -> i64 {
+    unsafe { big(a).cmp(&big(b)) as i64 }
+}
+
+/// Hash of a BigInt; equal values hash equally.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_hash(a: *const MeshBigInt) -> i64 {
+    crate::hash::mesh_hash_string(mesh_bigint_to_string(a))
+}
+
+/// Encode a BigInt as a JSON string, which keeps every digit.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_to_json(a: *const MeshBigInt) -> *mut u8 {
+    crate::json::mesh_json_from_string(mesh_bigint_to_string(a) as *const MeshString)
+}
+
+/// Decode a BigInt from a JSON string or integer. Returns MeshResult.
+///
+/// A JSON number outside the `i64` range is parsed as an `f64`, so it is
+/// accepted only when it is a whole number below 2^53, where the `f64` is
+/// certain to be the number that was written. Larger ones must be sent as
+/// strings.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bigint_from_json(json: *mut u8) -> *mut u8 {
+    let json = json as *const MeshJson;
+    let text = unsafe {
+        match (*json).tag {
+            crate::json::JSON_FLOAT => {
+                let f = f64::from_bits((*json).value);
+                if f.fract() != 0.0 || f.abs() >= F64_EXACT_INT_LIMIT {
+                    return alloc_result(1, mesh_str("BigInt JSON number is not exact; encode it as a string") as *mut u8)
+                        as *mut u8;
+                }
+                Some((f as i64).to_string())
+            }
+            _ => json_number_text(json),
+        }
+    };
+    match text.and_then(|t| Big::parse(&t)) {
+        Some(v) => alloc_result(0, alloc_bigint(&v) as *mut u8) as *mut u8,
+        None => alloc_result(1, mesh_str("expected BigInt") as *mut u8) as *mut u8,
+    }
+}
+
+/// 2^53: every whole number below it is exactly representable as an `f64`.
+const F64_EXACT_INT_LIMIT: f64 = 9_007_199_254_740_992.0;
+
+/// The exact text of a JSON string or integer, for the lossless numeric
+/// decoders. A JSON float has no exact text: it was rounded to an `f64` and
+/// lost any trailing zeros, so it yields `None`.
+pub(crate) unsafe fn json_number_text(json: *const MeshJson) -> Option<String> {
+    match (*json).tag {
+        crate::json::JSON_STR => Some((*((*json).value as *const MeshString)).as_str().to_string()),
+        crate::json::JSON_INT => Some(((*json).value as i64).to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gc::mesh_rt_init;
+    use crate::io::MeshResult;
+    use crate::option::MeshOption;
+
+    fn b(s: &str) -> Big {
+        Big::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(b("0").to_string(), "0");
+        assert_eq!(b("-0").to_string(), "0");
+        assert_eq!(b("+42").to_string(), "42");
+        assert_eq!(b("000123456789012345678901").to_string(), "123456789012345678901");
+        assert_eq!(b("-1000000000").to_string(), "-1000000000");
+        assert_eq!(Big::parse(""), None);
+        assert_eq!(Big::parse("-"), None);
+        assert_eq!(Big::parse("1.5"), None);
+        assert_eq!(Big::parse(" 1"), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let big = b("123456789012345678901234567890");
+        assert_eq!(big.add(&b("987654321098765432109876543210")).to_string(), "1111111110111111111011111111100");
+        assert_eq!(b("5").sub(&b("12")).to_string(), "-7");
+        assert_eq!(b("-5").add(&b("5")), Big::default());
+        assert_eq!(big.mul(&big).to_string(), "15241578753238836750495351562536198787501905199875019052100");
+        assert_eq!(b("-3").mul(&b("4")).to_string(), "-12");
+        assert_eq!(b("2").pow(100).to_string(), "1267650600228229401496703205376");
+    }
+
+    #[test]
+    fn test_div_rem_truncates() {
+        let (q, r) = b("1267650600228229401496703205376").div_rem(&b("1000000007")).unwrap();
+        assert_eq!(q.to_string(), "1267650591354675262013");
+        assert_eq!(r.to_string(), "976371285");
+        let (q, r) = b("-7").div_rem(&b("2")).unwrap();
+        assert_eq!((q.to_string(), r.to_string()), ("-3".to_string(), "-1".to_string()));
+        assert_eq!(b("1").div_rem(&Big::default()), None);
+    }
+
+    #[test]
+    fn test_compare_and_convert() {
+        assert!(b("-10") < b("-9"));
+        assert!(b("1000000000") > b("999999999"));
+        assert_eq!(b("9223372036854775807").to_i64(), Some(i64::MAX));
+        assert_eq!(b("-9223372036854775808").to_i64(), Some(i64::MIN));
+        assert_eq!(b("9223372036854775808").to_i64(), None);
+        assert_eq!(Big::from_i64(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(b("12").mul_pow10(11).to_string(), "1200000000000");
+    }
+
+    #[test]
+    fn test_runtime_round_trip() {
+        mesh_rt_init();
+        let s = mesh_str("-98765432109876543210");
+        let opt = mesh_bigint_parse(s) as *mut MeshOption;
+        unsafe {
+            assert_eq!((*opt).tag, 0);
+            let v = (*opt).value as *const MeshBigInt;
+            let sum = mesh_bigint_add(v, mesh_bigint_from_int(10));
+            assert_eq!((*mesh_bigint_to_string(sum)).as_str(), "-98765432109876543200");
+            assert_eq!(mesh_bigint_compare(sum, v), 1);
+            assert_eq!(mesh_bigint_eq(v, v), 1);
+            let again = mesh_bigint_parse(mesh_bigint_to_string(v)) as *mut MeshOption;
+            assert_eq!(mesh_bigint_hash(v), mesh_bigint_hash((*again).value as *const MeshBigInt));
+        }
+    }
+
+    /// Decode `text` as a JSON document and then as a BigInt.
+    fn from_json_text(text: &str) -> *mut MeshResult {
+        let json = crate::json::mesh_json_parse(mesh_str(text));
+        unsafe {
+            assert_eq!((*json).tag, 0);
+            mesh_bigint_from_json((*json).value) as *mut MeshResult
+        }
+    }
+
+    #[test]
+    fn test_from_json_rejects_rounded_numbers() {
+        mesh_rt_init();
+        unsafe {
+            let quoted = from_json_text("\"123456789012345678901\"");
+            assert_eq!((*quoted).tag, 0);
+            assert_eq!((*mesh_bigint_to_string((*quoted).value as *const MeshBigInt)).as_str(), "123456789012345678901");
+
+            let exponent = from_json_text("1e3");
+            assert_eq!((*exponent).tag, 0);
+            assert_eq!((*mesh_bigint_to_string((*exponent).value as *const MeshBigInt)).as_str(), "1000");
+
+            // Above i64::MAX (and 2^53), parsed as an f64 that rounded it.
+            let rounded = from_json_text("123456789012345678901");
+            assert_eq!((*rounded).tag, 1);
+            assert!((*((*rounded).value as *const MeshString)).as_str().contains("not exact"));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! GC-managed immutable byte buffers for the Mesh runtime.
+//!
+//! `Bytes` shares the string layout `{ len: u64, data: [u8; len] }` but makes
+//! no promise about the contents being UTF-8. Every operation returns a new
+//! buffer; nothing mutates one in place, so buffers can be shared freely.
+//!
+//! Individual bytes cross into Mesh as `UInt8` values (an `i64` in 0..=255).
+
+use crate::collections::list::{elems, mesh_list_builder_new, mesh_list_builder_push};
+use crate::gc::mesh_gc_alloc_actor;
+use crate::io::alloc_result;
+use crate::string::{mesh_string_new, MeshString};
+
+/// A GC-managed Mesh byte buffer.
+///
+/// Layout: `[u64 len][u8 data...]`, the same as `MeshString`.
+#[repr(C)]
+pub struct MeshBytes {
+    pub len: u64,
+    // data bytes follow immediately after this struct in memory
+}
+
+impl MeshBytes {
+    /// Size of the header (the `len` field).
+    const HEADER_SIZE: usize = std::mem::size_of::<u64>();
+
+    /// View the buffer's contents.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `self` points to a valid MeshBytes allocation
+    /// with at least `self.len` bytes following the header.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        let data = (self as *const Self as *const u8).add(Self::HEADER_SIZE);
+        std::slice::from_raw_parts(data, self.len as usize)
+    }
+}
+
+/// Allocate a new byte buffer holding a copy of `data`.
+pub fn bytes_from_slice(data: &[u8]) -> *mut MeshBytes {
+    unsafe {
+        let ptr = mesh_gc_alloc_actor(
+            (MeshBytes::HEADER_SIZE + data.len()) as u64,
+            std::mem::align_of::<MeshBytes>() as u64,
+        ) as *mut MeshBytes;
+        (*ptr).len = data.len() as u64;
+        let dst = (ptr as *mut u8).add(MeshBytes::HEADER_SIZE);
+        std::ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len());
+        ptr
+    }
+}
+
+/// Create an empty byte buffer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_new() -> *mut MeshBytes {
+    bytes_from_slice(&[])
+}
+
+/// Build a byte buffer from a List<UInt8>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_from_list(list: *mut u8) -> *mut MeshBytes {
+    let data: Vec<u8> = unsafe { elems(list) }.map(|b| b as u8).collect();
+    bytes_from_slice(&data)
+}
+
+/// The bytes of a buffer as a List<UInt8>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_to_list(b: *const MeshBytes) -> *mut u8 {
+    unsafe {
+        let data = (*b).as_slice();
+        let list = mesh_list_builder_new(data.len() as i64);
+        for &byte in data {
+            mesh_list_builder_push(list, byte as u64);
+        }
+        list
+    }
+}
+
+/// Number of bytes in the buffer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_length(b: *const MeshBytes) -> i64 {
+    unsafe { (*b).len as i64 }
+}
+
+/// The byte at `index`. Panics when the index is out of bounds.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_get(b: *const MeshBytes, index: i64) -> i64 {
+    unsafe {
+        let data = (*b).as_slice();
+        if index < 0 || index as usize >= data.len() {
+            panic!(
+                "mesh_bytes_get: index {} out of bounds (len {})",
+                index,
+                data.len()
+            );
+        }
+        data[index as usize] as i64
+    }
+}
+
+/// Bytes `start..end` (exclusive end) of the buffer. Clamps to bounds.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_slice(b: *const MeshBytes, start: i64, end: i64) -> *mut MeshBytes {
+    unsafe {
+        let data = (*b).as_slice();
+        let start = (start.max(0) as usize).min(data.len());
+        let end = (end.max(0) as usize).min(data.len()).max(start);
+        bytes_from_slice(&data[start..end])
+    }
+}
+
+/// Concatenate two buffers into a new one.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_concat(a: *const MeshBytes, b: *const MeshBytes) -> *mut MeshBytes {
+    unsafe {
+        let mut data = Vec::with_capacity(((*a).len + (*b).len) as usize);
+        data.extend_from_slice((*a).as_slice());
+        data.extend_from_slice((*b).as_slice());
+        bytes_from_slice(&data)
+    }
+}
+
+/// Returns 1 if both buffers hold the same bytes, 0 otherwise.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_eq(a: *const MeshBytes, b: *const MeshBytes) -> i8 {
+    unsafe { ((*a).as_slice() == (*b).as_slice()) as i8 }
+}
+
+/// Render a buffer as `<<1, 2, 3>>`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_to_string(b: *const MeshBytes) -> *mut MeshString {
+    let parts: Vec<String> = unsafe { (*b).as_slice() }.iter().map(|byte| byte.to_string()).collect();
+    let s = format!("<<{}>>", parts.join(", "));
+    mesh_string_new(s.as_ptr(), s.len() as u64)
+}
+
+/// The UTF-8 encoding of a string.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_to_bytes(s: *const MeshString) -> *mut MeshBytes {
+    unsafe { bytes_from_slice((*s).as_bytes()) }
+}
+
+/// Decode a buffer as UTF-8, returning Result<String, String>.
+///
+/// Returns Ok(string) (tag 0), or Err(message) (tag 1) naming the offset of
+/// the first invalid byte.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_from_utf8(b: *const MeshBytes) -> *mut u8 {
+    unsafe {
+        match std::str::from_utf8((*b).as_slice()) {
+            Ok(text) => {
+                let s = mesh_string_new(text.as_ptr(), text.len() as u64);
+                alloc_result(0, s as *mut u8) as *mut u8
+            }
+            Err(e) => {
+                let msg = format!("invalid UTF-8 at byte {}", e.valid_up_to());
+                let s = mesh_string_new(msg.as_ptr(), msg.len() as u64);
+                alloc_result(1, s as *mut u8) as *mut u8
+            }
+        }
+    }
+}
+
+// ── Binary patterns ─────────────────────────────────────────────────────
+//
+// The compiler lowers a `<<...>>` pattern to a segment spec: one entry per
+// segment, separated by commas.
+//
+// - `u8` `u16` `u32` `u64` `i8` `i16` `i32` `i64`: a big-endian integer of
+//   that width; a trailing `le` (`u16le`) makes it little-endian.
+// - `bytes:N`: exactly N bytes; `bytes:@K` takes the size from the value of
+//   integer segment K.
+// - `rest`: all remaining bytes (only ever the last segment).
+//
+// A pattern matches only when its segments consume the whole buffer.
+
+/// One parsed segment of a spec.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Segment {
+    Int { width: usize, signed: bool, little: bool },
+    Bytes(SegmentSize),
+    Rest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SegmentSize {
+    Fixed(usize),
+    FromSegment(usize),
+}
+
+/// A matched segment: an integer, or the byte range of a bytes segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SegmentValue {
+    Int(u64),
+    Range(usize, usize),
+}
+
+fn parse_segment(text: &str) -> Segment {
+    if text == "rest" {
+        return Segment::Rest;
+    }
+    if let Some(size) = text.strip_prefix("bytes:") {
+        return Segment::Bytes(match size.strip_prefix('@') {
+            Some(index) => SegmentSize::FromSegment(index.parse().unwrap_or(usize::MAX)),
+            None => SegmentSize::Fixed(size.parse().unwrap_or(usize::MAX)),
+        });
+    }
+    let (text, little) = match text.strip_suffix("le") {
+        Some(t) => (t, true),
+        None => (text, false),
+    };
+    let signed = text.starts_with('i');
+    let bits: usize = text[1..].parse().unwrap_or(8);
+    Segment::Int { width: bits / 8, signed, little }
+}
+
+fn parse_spec(spec: &str) -> Vec<Segment> {
+    if spec.is_empty() {
+        return Vec::new();
+    }
+    spec.split(',').map(parse_segment).collect()
+}
+
+/// Match `data` against a spec, returning every segment's value.
+fn match_segments(data: &[u8], spec: &str) -> Option<Vec<SegmentValue>> {
+    let mut values = Vec::new();
+    let mut offset = 0;
+    for segment in parse_spec(spec) {
+        let remaining = data.len() - offset;
+        match segment {
+            Segment::Int { width, signed, little } => {
+                if remaining < width {
+                    return None;
+                }
+                let raw = &data[offset..offset + width];
+                let mut word = [0u8; 8];
+                if little {
+                    word[..width].copy_from_slice(raw);
+                } else {
+                    word[8 - width..].copy_from_slice(raw);
+                }
+                let mut value = if little { u64::from_le_bytes(word) } else { u64::from_be_bytes(word) };
+                if signed && width < 8 {
+                    let shift = 64 - width * 8;
+                    value = (((value << shift) as i64) >> shift) as u64;
+                }
+                values.push(SegmentValue::Int(value));
+                offset += width;
+            }
+            Segment::Bytes(size) => {
+                let size = match size {
+                    SegmentSize::Fixed(n) => n,
+                    SegmentSize::FromSegment(index) => match values.get(index) {
+                        Some(SegmentValue::Int(n)) if (*n as i64) >= 0 => *n as usize,
+                        _ => return None,
+                    },
+                };
+                if remaining < size {
+                    return None;
+                }
+                values.push(SegmentValue::Range(offset, offset + size));
+                offset += size;
+            }
+            Segment::Rest => {
+                values.push(SegmentValue::Range(offset, data.len()));
+                offset = data.len();
+            }
+        }
+    }
+    (offset == data.len()).then_some(values)
+}
+
+/// Returns 1 if the buffer matches the segment spec, 0 otherwise.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_matches(b: *const MeshBytes, spec: *const MeshString) -> i8 {
+    unsafe { match_segments((*b).as_slice(), (*spec).as_str()).is_some() as i8 }
+}
+
+/// The value of segment `index` of a buffer known to match `spec`.
+///
+/// Integer segments come back as their normalized Int word; bytes and rest
+/// segments as a new `Bytes` pointer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_segment(b: *const MeshBytes, spec: *const MeshString, index: i64) -> u64 {
+    unsafe {
+        let data = (*b).as_slice();
+        let values = match match_segments(data, (*spec).as_str()) {
+            Some(values) => values,
+            None => panic!("mesh_bytes_segment: buffer does not match `{}`", (*spec).as_str()),
+        };
+        match values[index as usize] {
+            SegmentValue::Int(value) => value,
+            SegmentValue::Range(start, end) => bytes_from_slice(&data[start..end]) as u64,
+        }
+    }
+}
+
+/// Append an integer segment (a single spec entry such as `u16le`) to a
+/// buffer, keeping the low bytes of `value`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_put_int(acc: *const MeshBytes, value: i64, spec: *const MeshString) -> *mut MeshBytes {
+    unsafe {
+        let (width, little) = match parse_segment((*spec).as_str()) {
+            Segment::Int { width, little, .. } => (width, little),
+            _ => panic!("mesh_bytes_put_int: `{}` is not an integer segment", (*spec).as_str()),
+        };
+        let mut data = (*acc).as_slice().to_vec();
+        if little {
+            data.extend_from_slice(&value.to_le_bytes()[..width]);
+        } else {
+            data.extend_from_slice(&value.to_be_bytes()[8 - width..]);
+        }
+        bytes_from_slice(&data)
+    }
+}
+
+/// Append the first `size` bytes of `value` to a buffer, or all of them when
+/// `size` is negative. Panics when `value` is shorter than `size`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_bytes_put_bytes(acc: *const MeshBytes, value: *const MeshBytes, size: i64) -> *mut MeshBytes {
+    unsafe {
+        let src = (*value).as_slice();
+        let src = if size < 0 {
+            src
+        } else if size as usize <= src.len() {
+            &src[..size as usize]
+        } else {
+            panic!(
+                "mesh_bytes_put_bytes: segment needs {} bytes but the value has {}",
+                size,
+                src.len()
+            );
+        };
+        let mut data = (*acc).as_slice().to_vec();
+        data.extend_from_slice(src);
+        bytes_from_slice(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gc::mesh_rt_init;
+    use crate::io::MeshResult;
+
+    fn spec(s: &str) -> *mut MeshString {
+        mesh_string_new(s.as_ptr(), s.len() as u64)
+    }
+
+    fn contents(b: *const MeshBytes) -> Vec<u8> {
+        unsafe { (*b).as_slice().to_vec() }
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        mesh_rt_init();
+        let list = mesh_list_builder_new(3);
+        for v in [1u64, 2, 255] {
+            mesh_list_builder_push(list, v);
+        }
+        let b = mesh_bytes_from_list(list);
+        assert_eq!(contents(b), vec![1, 2, 255]);
+        assert_eq!(mesh_bytes_length(b), 3);
+        assert_eq!(mesh_bytes_get(b, 2), 255);
+        let back: Vec<u64> = unsafe { elems(mesh_bytes_to_list(b)) }.collect();
+        assert_eq!(back, vec![1, 2, 255]);
+    }
+
+    #[test]
+    fn test_slice_and_concat() {
+        mesh_rt_init();
+        let a = bytes_from_slice(b"mesh");
+        let b = bytes_from_slice(b"lang");
+        let ab = mesh_bytes_concat(a, b);
+        assert_eq!(contents(ab), b"meshlang");
+        assert_eq!(contents(mesh_bytes_slice(ab, 2, 6)), b"shla");
+        assert_eq!(contents(mesh_bytes_slice(ab, -3, 100)), b"meshlang");
+        assert_eq!(contents(mesh_bytes_slice(ab, 5, 2)), b"");
+        assert_eq!(mesh_bytes_eq(mesh_bytes_slice(ab, 0, 4), a), 1);
+        assert_eq!(mesh_bytes_eq(a, b), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        mesh_rt_init();
+        let s = mesh_bytes_to_string(bytes_from_slice(&[0, 16, 255]));
+        assert_eq!(unsafe { (*s).as_str() }, "<<0, 16, 255>>");
+        let s = mesh_bytes_to_string(mesh_bytes_new());
+        assert_eq!(unsafe { (*s).as_str() }, "<<>>");
+    }
+
+    #[test]
+    fn test_utf8_conversions() {
+        mesh_rt_init();
+        let text = mesh_string_new("héllo".as_ptr(), "héllo".len() as u64);
+        let b = mesh_string_to_bytes(text);
+        assert_eq!(mesh_bytes_length(b), 6);
+
+        let ok = mesh_string_from_utf8(b) as *mut MeshResult;
+        unsafe {
+            assert_eq!((*ok).tag, 0);
+            assert_eq!((*((*ok).value as *const MeshString)).as_str(), "héllo");
+        }
+
+        let err = mesh_string_from_utf8(bytes_from_slice(&[104, 0xff])) as *mut MeshResult;
+        unsafe {
+            assert_eq!((*err).tag, 1);
+            assert_eq!((*((*err).value as *const MeshString)).as_str(), "invalid UTF-8 at byte 1");
+        }
+    }
+
+    #[test]
+    fn test_match_segments() {
+        mesh_rt_init();
+        // A length-prefixed frame: u16 length, payload, trailing bytes.
+        let frame = bytes_from_slice(&[0, 3, b'a', b'b', b'c', 9, 9]);
+        let s = spec("u16,bytes:@0,rest");
+        assert_eq!(mesh_bytes_matches(frame, s), 1);
+        assert_eq!(mesh_bytes_segment(frame, s, 0), 3);
+        assert_eq!(contents(mesh_bytes_segment(frame, s, 1) as *const MeshBytes), b"abc");
+        assert_eq!(contents(mesh_bytes_segment(frame, s, 2) as *const MeshBytes), &[9, 9]);
+
+        // Every byte must be consumed, and sizes must fit.
+        assert_eq!(mesh_bytes_matches(frame, spec("u16,bytes:@0")), 0);
+        assert_eq!(mesh_bytes_matches(frame, spec("u16,bytes:8")), 0);
+        assert_eq!(mesh_bytes_matches(mesh_bytes_new(), spec("")), 1);
+        assert_eq!(mesh_bytes_matches(mesh_bytes_new(), spec("u8")), 0);
+    }
+
+    #[test]
+    fn test_match_integer_widths() {
+        mesh_rt_init();
+        let b = bytes_from_slice(&[0xff, 0xfe, 0x01, 0x02]);
+        assert_eq!(mesh_bytes_segment(b, spec("u16,u16"), 0), 0xfffe);
+        assert_eq!(mesh_bytes_segment(b, spec("i16,u16le"), 0) as i64, -2);
+        assert_eq!(mesh_bytes_segment(b, spec("i16,u16le"), 1), 0x0201);
+        assert_eq!(mesh_bytes_segment(b, spec("i32le"), 0) as i64, 0x0201feff);
+        assert_eq!(mesh_bytes_segment(b, spec("i8,rest"), 0) as i64, -1);
+    }
+
+    #[test]
+    fn test_put_segments() {
+        mesh_rt_init();
+        let b = mesh_bytes_put_int(mesh_bytes_new(), 258, spec("u16"));
+        let b = mesh_bytes_put_int(b, 258, spec("u16le"));
+        let b = mesh_bytes_put_int(b, -1, spec("i8"));
+        let b = mesh_bytes_put_bytes(b, bytes_from_slice(b"mesh"), 2);
+        let b = mesh_bytes_put_bytes(b, bytes_from_slice(b"!"), -1);
+        assert_eq!(contents(b), &[1, 2, 2, 1, 255, b'm', b'e', b'!']);
+    }
+}
@@ -1,20 +1,319 @@
 //! GC-managed immutable List for the Mesh runtime.
 //!
-//! A MeshList stores elements as uniform 8-byte (`u64`) values in a contiguous
-//! GC-allocated buffer. Layout: `{ len: u64, cap: u64, data: [u64; cap] }`.
+//! A MeshList is a persistent vector of uniform 8-byte (`u64`) values. Its
+//! elements live in 32-way tries whose nodes are shared between lists, so
+//! append, prepend, tail and get are O(1) or O(log32 n) instead of copying.
 //!
-//! All mutation operations (append, tail, concat, etc.) return a NEW list,
-//! preserving immutability semantics.
+//! A list is two tries ("sides") read back to back:
+//! - `front` holds prepended elements, newest last, so it reads in reverse;
+//! - `back` holds everything else and reads forwards.
+//!
+//! Each side is a Clojure-style vector: a trie of full 32-element leaves plus
+//! a tail leaf of up to 32 elements kept outside the trie, so pushing onto it
+//! copies at most one leaf. A side also has a start `offset`: `tail` and
+//! `drop` move it instead of copying, and the dropped prefix stays shared.
+//!
+//! Header layout (11 words):
+//! `{ len, front: Side, back: Side }` with `Side = { size, offset, shift, root, tail }`.
+//! Nodes (leaves and branches) are `[u64; 32]`; unused slots are zero.
+//!
+//! All operations (append, tail, concat, etc.) return a NEW list, preserving
+//! immutability semantics. Only the for-in list builder mutates in place.
 
 use crate::gc::mesh_gc_alloc_actor;
 use crate::option::alloc_option;
 use std::ptr;
 
-/// Header size: len (8 bytes) + cap (8 bytes) = 16 bytes.
-const HEADER_SIZE: usize = 16;
+/// Index bits per trie level.
+const BITS: u64 = 5;
+
+/// Slots per trie node.
+const WIDTH: usize = 1 << BITS;
+
+const MASK: u64 = WIDTH as u64 - 1;
+
+/// Byte size of one node.
+const NODE_SIZE: u64 = (WIDTH * 8) as u64;
+
+/// Header words: len + two sides of five words each.
+const HEADER_WORDS: usize = 11;
+
+/// Word offsets of the two sides in the header.
+const FRONT: usize = 1;
+const BACK: usize = 6;
+
+// ── Trie nodes ────────────────────────────────────────────────────────
+
+unsafe fn alloc_node() -> *mut u8 {
+    mesh_gc_alloc_actor(NODE_SIZE, 8)
+}
+
+unsafe fn slot(node: *const u8, i: u64) -> u64 {
+    *(node as *const u64).add(i as usize)
+}
+
+unsafe fn set_slot(node: *mut u8, i: u64, value: u64) {
+    *(node as *mut u64).add(i as usize) = value;
+}
+
+/// A new node holding `elems` (at most 32) in its first slots.
+unsafe fn node_from(elems: &[u64]) -> *mut u8 {
+    let node = alloc_node();
+    ptr::copy_nonoverlapping(elems.as_ptr(), node as *mut u64, elems.len());
+    node
+}
+
+unsafe fn copy_node(node: *const u8) -> *mut u8 {
+    let copy = alloc_node();
+    ptr::copy_nonoverlapping(node as *const u64, copy as *mut u64, WIDTH);
+    copy
+}
+
+/// A chain of single-child branches `level` bits deep ending at `node`.
+unsafe fn new_path(level: u64, node: *mut u8) -> *mut u8 {
+    if level == 0 {
+        return node;
+    }
+    let branch = alloc_node();
+    set_slot(branch, 0, new_path(level - BITS, node) as u64);
+    branch
+}
+
+/// Copy the path to the last leaf of a trie holding `size` elements
+/// (including the full `tail`) and hang `tail` there.
+unsafe fn push_tail(size: u64, level: u64, parent: *const u8, tail: *mut u8) -> *mut u8 {
+    let index = ((size - 1) >> level) & MASK;
+    let copy = copy_node(parent);
+    let child = if level == BITS {
+        tail
+    } else {
+        let existing = slot(parent, index) as *const u8;
+        if existing.is_null() {
+            new_path(level - BITS, tail)
+        } else {
+            push_tail(size, level - BITS, existing, tail)
+        }
+    };
+    set_slot(copy, index, child as u64);
+    copy
+}
+
+/// Copy the path to the last leaf of a trie whose side holds `size`
+/// elements, with that leaf removed. Returns null when nothing is left.
+unsafe fn pop_tail(size: u64, level: u64, node: *const u8) -> *mut u8 {
+    let index = ((size - 2) >> level) & MASK;
+    if level > BITS {
+        let child = pop_tail(size, level - BITS, slot(node, index) as *const u8);
+        if child.is_null() && index == 0 {
+            ptr::null_mut()
+        } else {
+            let copy = copy_node(node);
+            set_slot(copy, index, child as u64);
+            copy
+        }
+    } else if index == 0 {
+        ptr::null_mut()
+    } else {
+        let copy = copy_node(node);
+        set_slot(copy, index, 0);
+        copy
+    }
+}
+
+// ── Sides ─────────────────────────────────────────────────────────────
+
+/// One persistent vector. Physical positions `[offset, size)` are live;
+/// `[0, tail_off)` are in the trie under `root` and the rest in `tail`.
+#[derive(Clone, Copy)]
+struct Side {
+    size: u64,
+    offset: u64,
+    shift: u64,
+    root: *mut u8,
+    tail: *mut u8,
+}
+
+impl Side {
+    const EMPTY: Side = Side {
+        size: 0,
+        offset: 0,
+        shift: BITS,
+        root: ptr::null_mut(),
+        tail: ptr::null_mut(),
+    };
+
+    unsafe fn read(list: *const u8, at: usize) -> Side {
+        let words = (list as *const u64).add(at);
+        Side {
+            size: *words,
+            offset: *words.add(1),
+            shift: *words.add(2),
+            root: *words.add(3) as *mut u8,
+            tail: *words.add(4) as *mut u8,
+        }
+    }
+
+    unsafe fn write(self, list: *mut u8, at: usize) {
+        let words = (list as *mut u64).add(at);
+        *words = self.size;
+        *words.add(1) = self.offset;
+        *words.add(2) = self.shift;
+        *words.add(3) = self.root as u64;
+        *words.add(4) = self.tail as u64;
+    }
+
+    fn len(&self) -> u64 {
+        self.size - self.offset
+    }
+
+    /// First physical position held by the tail.
+    fn tail_off(&self) -> u64 {
+        if self.size == 0 {
+            0
+        } else {
+            (self.size - 1) & !MASK
+        }
+    }
+
+    /// The leaf holding physical position `pos`.
+    unsafe fn leaf_for(&self, pos: u64) -> *const u8 {
+        if pos >= self.tail_off() {
+            return self.tail;
+        }
+        let mut node = self.root as *const u8;
+        let mut level = self.shift;
+        while level > 0 {
+            node = slot(node, (pos >> level) & MASK) as *const u8;
+            level -= BITS;
+        }
+        node
+    }
+
+    unsafe fn get(&self, pos: u64) -> u64 {
+        slot(self.leaf_for(pos), pos & MASK)
+    }
+
+    /// Move the full tail into the trie (the tail is not replaced).
+    unsafe fn push_tail_into_trie(&mut self) {
+        if self.root.is_null() {
+            self.root = new_path(BITS, self.tail);
+            self.shift = BITS;
+        } else if (self.size >> BITS) > (1u64 << self.shift) {
+            // The trie is full at this depth: grow a new root.
+            let root = alloc_node();
+            set_slot(root, 0, self.root as u64);
+            set_slot(root, 1, new_path(self.shift, self.tail) as u64);
+            self.root = root;
+            self.shift += BITS;
+        } else {
+            self.root = push_tail(self.size, self.shift, self.root, self.tail);
+        }
+    }
+
+    /// Push `elems` after the last position, copying at most the current
+    /// tail and the trie paths the new leaves hang from.
+    unsafe fn extend(&self, elems: &[u64]) -> Side {
+        let mut side = *self;
+        let mut rest = elems;
+        while !rest.is_empty() {
+            let tail_len = side.size - side.tail_off();
+            if side.size == 0 || tail_len == WIDTH as u64 {
+                if side.size > 0 {
+                    side.push_tail_into_trie();
+                }
+                let take = rest.len().min(WIDTH);
+                side.tail = node_from(&rest[..take]);
+                side.size += take as u64;
+                rest = &rest[take..];
+            } else {
+                let take = rest.len().min(WIDTH - tail_len as usize);
+                let tail = alloc_node();
+                ptr::copy_nonoverlapping(side.tail as *const u64, tail as *mut u64, tail_len as usize);
+                ptr::copy_nonoverlapping(rest.as_ptr(), (tail as *mut u64).add(tail_len as usize), take);
+                side.tail = tail;
+                side.size += take as u64;
+                rest = &rest[take..];
+            }
+        }
+        side
+    }
 
-/// Byte size of one element.
-const ELEM_SIZE: usize = 8;
+    unsafe fn push(&self, elem: u64) -> Side {
+        self.extend(&[elem])
+    }
+
+    /// Remove the last physical position.
+    unsafe fn pop(&self) -> Side {
+        if self.len() <= 1 {
+            return Side::EMPTY;
+        }
+        let mut side = *self;
+        if side.size - side.tail_off() > 1 {
+            // The shared tail keeps its slot; this side just stops reading it.
+            side.size -= 1;
+            return side;
+        }
+        // The tail empties: the trie's last leaf becomes the tail.
+        side.tail = side.leaf_for(side.size - 2) as *mut u8;
+        let root = pop_tail(side.size, side.shift, side.root);
+        if root.is_null() {
+            side.root = ptr::null_mut();
+            side.shift = BITS;
+        } else if side.shift > BITS && slot(root, 1) == 0 {
+            side.root = slot(root, 0) as *mut u8;
+            side.shift -= BITS;
+        } else {
+            side.root = root;
+        }
+        side.size -= 1;
+        side
+    }
+
+    /// Skip the first `n` live positions.
+    fn drop_first(&self, n: u64) -> Side {
+        if n >= self.len() {
+            Side::EMPTY
+        } else {
+            Side { offset: self.offset + n, ..*self }
+        }
+    }
+
+    /// A side holding `elems`, building the trie bottom-up.
+    unsafe fn from_slice(elems: &[u64]) -> Side {
+        if elems.is_empty() {
+            return Side::EMPTY;
+        }
+        let size = elems.len() as u64;
+        let tail_off = ((size - 1) & !MASK) as usize;
+        let mut side = Side {
+            size,
+            tail: node_from(&elems[tail_off..]),
+            ..Side::EMPTY
+        };
+        let mut level: Vec<*mut u8> = elems[..tail_off].chunks(WIDTH).map(|c| node_from(c)).collect();
+        if level.is_empty() {
+            return side;
+        }
+        loop {
+            let parents: Vec<*mut u8> = level
+                .chunks(WIDTH)
+                .map(|children| {
+                    let branch = alloc_node();
+                    for (i, child) in children.iter().enumerate() {
+                        set_slot(branch, i as u64, *child as u64);
+                    }
+                    branch
+                })
+                .collect();
+            if parents.len() == 1 {
+                side.root = parents[0];
+                return side;
+            }
+            level = parents;
+            side.shift += BITS;
+        }
+    }
+}
 
 // ── Internal helpers ──────────────────────────────────────────────────
 
@@ -23,40 +322,92 @@ unsafe fn list_len(list: *const u8) -> u64 {
     *(list as *const u64)
 }
 
-/// Read the capacity field from a list pointer.
-#[allow(dead_code)]
-unsafe fn list_cap(list: *const u8) -> u64 {
-    *((list as *const u64).add(1))
+unsafe fn sides(list: *const u8) -> (Side, Side) {
+    (Side::read(list, FRONT), Side::read(list, BACK))
+}
+
+/// Allocate a list header over the two sides.
+unsafe fn alloc_list(front: Side, back: Side) -> *mut u8 {
+    let p = mesh_gc_alloc_actor((HEADER_WORDS * 8) as u64, 8);
+    *(p as *mut u64) = front.len() + back.len();
+    front.write(p, FRONT);
+    back.write(p, BACK);
+    p
 }
 
-/// Get a pointer to the data region (past the header).
-unsafe fn list_data(list: *const u8) -> *const u64 {
-    (list as *const u64).add(2)
+/// Element `index`, which must be in bounds.
+unsafe fn get_unchecked(list: *const u8, index: u64) -> u64 {
+    let (front, back) = sides(list);
+    let front_len = front.len();
+    if index < front_len {
+        front.get(front.size - 1 - index)
+    } else {
+        back.get(back.offset + index - front_len)
+    }
 }
 
-/// Get a mutable pointer to the data region.
-unsafe fn list_data_mut(list: *mut u8) -> *mut u64 {
-    (list as *mut u64).add(2)
+/// Iterator over a list's elements in order, walking each leaf once.
+pub(crate) struct Elems {
+    front: Side,
+    back: Side,
+    index: u64,
+    len: u64,
+    leaf: *const u8,
+    /// Leaf base position times two, plus 1 for the front side.
+    leaf_key: u64,
 }
 
-/// Allocate a new list with the given capacity, length set to 0.
-unsafe fn alloc_list(cap: u64) -> *mut u8 {
-    let total = HEADER_SIZE + (cap as usize) * ELEM_SIZE;
-    let p = mesh_gc_alloc_actor(total as u64, 8);
-    // len = 0, cap = cap
-    *(p as *mut u64) = 0;
-    *((p as *mut u64).add(1)) = cap;
-    p
+impl Iterator for Elems {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.index >= self.len {
+            return None;
+        }
+        let front_len = self.front.len();
+        let (side, pos, is_front) = if self.index < front_len {
+            (&self.front, self.front.size - 1 - self.index, 1)
+        } else {
+            (&self.back, self.back.offset + self.index - front_len, 0)
+        };
+        let key = ((pos & !MASK) << 1) | is_front;
+        unsafe {
+            if key != self.leaf_key {
+                self.leaf = side.leaf_for(pos);
+                self.leaf_key = key;
+            }
+            self.index += 1;
+            Some(slot(self.leaf, pos & MASK))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
-/// Allocate a new list with the given length and capacity, copying `len` elements from `src`.
-unsafe fn alloc_list_from(src: *const u64, len: u64, cap: u64) -> *mut u8 {
-    let p = alloc_list(cap);
-    *(p as *mut u64) = len;
-    if len > 0 {
-        ptr::copy_nonoverlapping(src, list_data_mut(p), len as usize);
+/// Iterate over the elements of `list`.
+pub(crate) unsafe fn elems(list: *const u8) -> Elems {
+    let (front, back) = sides(list);
+    Elems {
+        front,
+        back,
+        index: 0,
+        len: list_len(list),
+        leaf: ptr::null(),
+        leaf_key: u64::MAX,
     }
-    p
+}
+
+/// Copy the elements of `list` into a Vec.
+pub(crate) unsafe fn list_to_vec(list: *const u8) -> Vec<u64> {
+    elems(list).collect()
+}
+
+/// Build a list holding `elems`.
+pub(crate) unsafe fn list_from_slice(elems: &[u64]) -> *mut u8 {
+    alloc_list(Side::EMPTY, Side::from_slice(elems))
 }
 
 /// Allocate a 2-element tuple on the GC heap matching Mesh's tuple layout.
@@ -74,60 +425,66 @@ pub(crate) unsafe fn alloc_pair(a: u64, b: u64) -> *mut u8 {
 
 /// Create an empty list.
 #[no_mangle]
-pub extern "C" fn mesh_list_new() -> *mut u8 {
-    unsafe { alloc_list(0) }
+pub extern "C-unwind" fn mesh_list_new() -> *mut u8 {
+    unsafe { alloc_list(Side::EMPTY, Side::EMPTY) }
 }
 
 /// Return the number of elements in the list.
 #[no_mangle]
-pub extern "C" fn mesh_list_length(list: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_list_length(list: *mut u8) -> i64 {
     unsafe { list_len(list) as i64 }
 }
 
 /// Return a NEW list with `element` appended at the end.
 #[no_mangle]
-pub extern "C" fn mesh_list_append(list: *mut u8, element: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_append(list: *mut u8, element: u64) -> *mut u8 {
     unsafe {
-        let len = list_len(list);
-        let new_cap = len + 1;
-        let new_list = alloc_list(new_cap);
-        *(new_list as *mut u64) = new_cap; // len = old len + 1
-        if len > 0 {
-            ptr::copy_nonoverlapping(list_data(list), list_data_mut(new_list), len as usize);
-        }
-        *list_data_mut(new_list).add(len as usize) = element;
-        new_list
+        let (front, back) = sides(list);
+        alloc_list(front, back.push(element))
+    }
+}
+
+/// Return a NEW list with `element` inserted at the front.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_list_prepend(list: *mut u8, element: u64) -> *mut u8 {
+    unsafe {
+        let (front, back) = sides(list);
+        alloc_list(front.push(element), back)
     }
 }
 
 /// Return the first element. Panics if empty.
 #[no_mangle]
-pub extern "C" fn mesh_list_head(list: *mut u8) -> u64 {
+pub extern "C-unwind" fn mesh_list_head(list: *mut u8) -> u64 {
     unsafe {
         let len = list_len(list);
         if len == 0 {
             panic!("mesh_list_head: empty list");
         }
-        *list_data(list)
+        get_unchecked(list, 0)
     }
 }
 
 /// Return a NEW list without the first element. Panics if empty.
 #[no_mangle]
-pub extern "C" fn mesh_list_tail(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_tail(list: *mut u8) -> *mut u8 {
     unsafe {
         let len = list_len(list);
         if len == 0 {
             panic!("mesh_list_tail: empty list");
         }
-        let new_len = len - 1;
-        alloc_list_from(list_data(list).add(1), new_len, new_len)
+        let (front, back) = sides(list);
+        if front.len() > 0 {
+            alloc_list(front.pop(), back)
+        } else {
+            alloc_list(front, back.drop_first(1))
+        }
     }
 }
 
 /// Get the element at `index`. Panics if out of bounds.
 #[no_mangle]
-pub extern "C" fn mesh_list_get(list: *mut u8, index: i64) -> u64 {
+pub extern "C-unwind" fn mesh_list_get(list: *mut u8, index: i64) -> u64 {
     unsafe {
         let len = list_len(list);
         if index < 0 || index as u64 >= len {
@@ -136,47 +493,56 @@ pub extern "C" fn mesh_list_get(list: *mut u8, index: i64) -> u64 {
                 index, len
             );
         }
-        *list_data(list).add(index as usize)
+        get_unchecked(list, index as u64)
     }
 }
 
 /// Concatenate two lists into a NEW list.
+///
+/// The shorter list's elements are pushed onto the longer one, so the cost
+/// is proportional to the shorter list.
 #[no_mangle]
-pub extern "C" fn mesh_list_concat(a: *mut u8, b: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_concat(a: *mut u8, b: *mut u8) -> *mut u8 {
     unsafe {
-        let a_len = list_len(a);
-        let b_len = list_len(b);
-        let new_len = a_len + b_len;
-        let new_list = alloc_list(new_len);
-        *(new_list as *mut u64) = new_len;
-        if a_len > 0 {
-            ptr::copy_nonoverlapping(list_data(a), list_data_mut(new_list), a_len as usize);
-        }
-        if b_len > 0 {
-            ptr::copy_nonoverlapping(
-                list_data(b),
-                list_data_mut(new_list).add(a_len as usize),
-                b_len as usize,
-            );
+        let (a_front, a_back) = sides(a);
+        let (b_front, b_back) = sides(b);
+        if list_len(b) <= list_len(a) {
+            alloc_list(a_front, a_back.extend(&list_to_vec(b)))
+        } else {
+            // The front side reads in reverse, so push `a` last to first.
+            let mut a_elems = list_to_vec(a);
+            a_elems.reverse();
+            alloc_list(b_front.extend(&a_elems), b_back)
         }
-        new_list
     }
 }
 
 /// Return a reversed copy of the list.
+///
+/// The front side reads in reverse and the back side forwards, so swapping
+/// them reverses the list without touching any element.
 #[no_mangle]
-pub extern "C" fn mesh_list_reverse(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_reverse(list: *mut u8) -> *mut u8 {
     unsafe {
-        let len = list_len(list);
-        let new_list = alloc_list(len);
-        *(new_list as *mut u64) = len;
-        let src = list_data(list);
-        let dst = list_data_mut(new_list);
-        for i in 0..len as usize {
-            *dst.add(i) = *src.add(len as usize - 1 - i);
-        }
-        new_list
+        let (front, back) = sides(list);
+        alloc_list(back, front)
+    }
+}
+
+/// Push `values` onto a new list as they are produced.
+///
+/// Used where producing a value calls a closure, which can trigger a
+/// collection. The GC scans only the coroutine stack and GC objects, so
+/// results buffered in a `Vec` could be swept before being copied into a
+/// list. Here each result is in the list, reachable from a stack slot,
+/// before the next closure call.
+pub(crate) unsafe fn collect_rooted(values: impl Iterator<Item = u64>) -> *mut u8 {
+    let list = mesh_list_builder_new(0);
+    let root = std::hint::black_box(&list);
+    for value in values {
+        mesh_list_builder_push(*root, value);
     }
+    *root
 }
 
 /// Apply a closure to each element, returning a new list.
@@ -184,77 +550,43 @@ pub extern "C" fn mesh_list_reverse(list: *mut u8) -> *mut u8 {
 /// If `env_ptr` is null, `fn_ptr` is called as `fn(element) -> result`.
 /// If `env_ptr` is non-null, `fn_ptr` is called as `fn(env_ptr, element) -> result`.
 #[no_mangle]
-pub extern "C" fn mesh_list_map(
+pub extern "C-unwind" fn mesh_list_map(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> *mut u8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let new_list = alloc_list(len);
-        *(new_list as *mut u64) = len;
-        let src = list_data(list);
-        let dst = list_data_mut(new_list);
-
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                *dst.add(i) = f(*src.add(i));
-            }
+            collect_rooted(elems(list).map(|elem| f(elem)))
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                *dst.add(i) = f(env_ptr, *src.add(i));
-            }
+            collect_rooted(elems(list).map(|elem| f(env_ptr, elem)))
         }
-        new_list
     }
 }
 
 /// Keep elements where the closure returns non-zero (true).
 #[no_mangle]
-pub extern "C" fn mesh_list_filter(
+pub extern "C-unwind" fn mesh_list_filter(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> *mut u8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        // Allocate worst case, then shrink.
-        let temp = alloc_list(len);
-        let src = list_data(list);
-        let dst = list_data_mut(temp);
-        let mut count = 0u64;
-
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let elem = *src.add(i);
-                if f(elem) != 0 {
-                    *dst.add(count as usize) = elem;
-                    count += 1;
-                }
-            }
+            collect_rooted(elems(list).filter(|&elem| f(elem) != 0))
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let elem = *src.add(i);
-                if f(env_ptr, elem) != 0 {
-                    *dst.add(count as usize) = elem;
-                    count += 1;
-                }
-            }
+            collect_rooted(elems(list).filter(|&elem| f(env_ptr, elem) != 0))
         }
-
-        // Set actual length.
-        *(temp as *mut u64) = count;
-        temp
     }
 }
 
@@ -263,61 +595,63 @@ pub extern "C" fn mesh_list_filter(
 /// If `env_ptr` is null: `fn_ptr(acc, element) -> acc`
 /// If `env_ptr` is non-null: `fn_ptr(env_ptr, acc, element) -> acc`
 #[no_mangle]
-pub extern "C" fn mesh_list_reduce(
+pub extern "C-unwind" fn mesh_list_reduce(
     list: *mut u8,
     init: u64,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> u64 {
-    type BareFn = unsafe extern "C" fn(u64, u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64, u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
-        let mut acc = init;
-
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                acc = f(acc, *src.add(i));
-            }
+            elems(list).fold(init, |acc, elem| f(acc, elem))
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                acc = f(env_ptr, acc, *src.add(i));
-            }
+            elems(list).fold(init, |acc, elem| f(env_ptr, acc, elem))
         }
-        acc
     }
 }
 
-/// Create a list with pre-allocated capacity for N elements.
-/// Length starts at 0. Used by for-in codegen for O(N) result building.
+/// Create an empty list to build with `mesh_list_builder_push`.
+/// Used by for-in codegen for O(N) result building; `capacity` is a hint
+/// the persistent representation does not need.
 #[no_mangle]
-pub extern "C" fn mesh_list_builder_new(capacity: i64) -> *mut u8 {
-    unsafe { alloc_list(capacity.max(0) as u64) }
+pub extern "C-unwind" fn mesh_list_builder_new(_capacity: i64) -> *mut u8 {
+    mesh_list_new()
 }
 
-/// Push an element to a list builder (in-place mutation, O(1)).
+/// Push an element to a list builder (in-place mutation, O(1) amortized).
 /// SAFETY: Only valid during construction before the list is shared.
-/// Increments len and writes element at data[len].
+/// Writes into the builder's own tail leaf while it has room.
 #[no_mangle]
-pub extern "C" fn mesh_list_builder_push(list: *mut u8, element: u64) {
+pub extern "C-unwind" fn mesh_list_builder_push(list: *mut u8, element: u64) {
     unsafe {
-        let len = list_len(list) as usize;
-        let data = list_data_mut(list);
-        *data.add(len) = element;
-        *(list as *mut u64) = (len + 1) as u64;
+        let mut back = Side::read(list, BACK);
+        let tail_len = back.size - back.tail_off();
+        if back.size > 0 && tail_len < WIDTH as u64 {
+            // Every tail of a builder was allocated by this builder.
+            set_slot(back.tail, tail_len, element);
+            back.size += 1;
+        } else {
+            back = back.push(element);
+        }
+        back.write(list, BACK);
+        *(list as *mut u64) += 1;
     }
 }
 
 /// Create a list from an array of u64 elements.
 #[no_mangle]
-pub extern "C" fn mesh_list_from_array(data: *const u64, count: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_from_array(data: *const u64, count: i64) -> *mut u8 {
     unsafe {
-        let count = count.max(0) as u64;
-        alloc_list_from(data, count, count)
+        let count = count.max(0) as usize;
+        if count == 0 {
+            return mesh_list_new();
+        }
+        list_from_slice(std::slice::from_raw_parts(data, count))
     }
 }
 
@@ -326,28 +660,19 @@ pub extern "C" fn mesh_list_from_array(data: *const u64, count: i64) -> *mut u8
 /// `elem_eq` is a bare function pointer `fn(u64, u64) -> i8` that returns 1
 /// if two elements are equal, 0 otherwise. Returns 1 if lists are equal, 0 if not.
 #[no_mangle]
-pub extern "C" fn mesh_list_eq(
+pub extern "C-unwind" fn mesh_list_eq(
     list_a: *mut u8,
     list_b: *mut u8,
     elem_eq: *mut u8,
 ) -> i8 {
-    type ElemEq = unsafe extern "C" fn(u64, u64) -> i8;
+    type ElemEq = unsafe extern "C-unwind" fn(u64, u64) -> i8;
 
     unsafe {
-        let len_a = list_len(list_a);
-        let len_b = list_len(list_b);
-        if len_a != len_b {
+        if list_len(list_a) != list_len(list_b) {
             return 0;
         }
-        let data_a = list_data(list_a);
-        let data_b = list_data(list_b);
         let f: ElemEq = std::mem::transmute(elem_eq);
-        for i in 0..len_a as usize {
-            if f(*data_a.add(i), *data_b.add(i)) == 0 {
-                return 0;
-            }
-        }
-        1
+        elems(list_a).zip(elems(list_b)).all(|(a, b)| f(a, b) != 0) as i8
     }
 }
 
@@ -357,22 +682,19 @@ pub extern "C" fn mesh_list_eq(
 /// negative if a < b, 0 if equal, positive if a > b. Returns negative/0/positive
 /// for the lexicographic ordering of the two lists.
 #[no_mangle]
-pub extern "C" fn mesh_list_compare(
+pub extern "C-unwind" fn mesh_list_compare(
     list_a: *mut u8,
     list_b: *mut u8,
     elem_cmp: *mut u8,
 ) -> i64 {
-    type ElemCmp = unsafe extern "C" fn(u64, u64) -> i64;
+    type ElemCmp = unsafe extern "C-unwind" fn(u64, u64) -> i64;
 
     unsafe {
-        let len_a = list_len(list_a) as usize;
-        let len_b = list_len(list_b) as usize;
-        let data_a = list_data(list_a);
-        let data_b = list_data(list_b);
+        let len_a = list_len(list_a);
+        let len_b = list_len(list_b);
         let f: ElemCmp = std::mem::transmute(elem_cmp);
-        let min_len = len_a.min(len_b);
-        for i in 0..min_len {
-            let cmp = f(*data_a.add(i), *data_b.add(i));
+        for (a, b) in elems(list_a).zip(elems(list_b)) {
+            let cmp = f(a, b);
             if cmp != 0 {
                 return cmp;
             }
@@ -394,20 +716,18 @@ pub extern "C" fn mesh_list_compare(
 /// lowerer passes the appropriate runtime to_string function (e.g.,
 /// `mesh_int_to_string` for `List<Int>`).
 #[no_mangle]
-pub extern "C" fn mesh_list_to_string(
+pub extern "C-unwind" fn mesh_list_to_string(
     list: *mut u8,
     elem_to_str: *mut u8,
 ) -> *mut u8 {
-    type ElemToStr = unsafe extern "C" fn(u64) -> *mut u8;
+    type ElemToStr = unsafe extern "C-unwind" fn(u64) -> *mut u8;
 
     unsafe {
-        let len = list_len(list) as usize;
-        let data = list_data(list);
         let f: ElemToStr = std::mem::transmute(elem_to_str);
 
         // Build the result string piece by piece using mesh_string_concat.
         let mut result = crate::string::mesh_string_new(b"[".as_ptr(), 1) as *mut u8;
-        for i in 0..len {
+        for (i, elem) in elems(list).enumerate() {
             if i > 0 {
                 let sep = crate::string::mesh_string_new(b", ".as_ptr(), 2) as *mut u8;
                 result = crate::string::mesh_string_concat(
@@ -415,7 +735,7 @@ pub extern "C" fn mesh_list_to_string(
                     sep as *const crate::string::MeshString,
                 ) as *mut u8;
             }
-            let elem_str = f(*data.add(i));
+            let elem_str = f(elem);
             result = crate::string::mesh_string_concat(
                 result as *const crate::string::MeshString,
                 elem_str as *const crate::string::MeshString,
@@ -438,60 +758,26 @@ pub extern "C" fn mesh_list_to_string(
 /// If `env_ptr` is null, `fn_ptr` is called as `fn(a, b) -> i64`.
 /// If `env_ptr` is non-null, `fn_ptr` is called as `fn(env_ptr, a, b) -> i64`.
 #[no_mangle]
-pub extern "C" fn mesh_list_sort(
+pub extern "C-unwind" fn mesh_list_sort(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> *mut u8 {
-    type BareFn = unsafe extern "C" fn(u64, u64) -> i64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64, u64) -> i64;
+    type BareFn = unsafe extern "C-unwind" fn(u64, u64) -> i64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64, u64) -> i64;
 
     unsafe {
-        let len = list_len(list);
-        if len <= 1 {
-            // Return a copy to preserve immutability semantics.
-            return alloc_list_from(list_data(list), len, len);
-        }
         // Copy elements into a mutable Vec for sorting.
-        let src = list_data(list);
-        let mut elements: Vec<u64> = Vec::with_capacity(len as usize);
-        for i in 0..len as usize {
-            elements.push(*src.add(i));
-        }
+        let mut elements = list_to_vec(list);
         // Sort using the comparator.
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            elements.sort_by(|a, b| {
-                let cmp = f(*a, *b);
-                if cmp < 0 {
-                    std::cmp::Ordering::Less
-                } else if cmp > 0 {
-                    std::cmp::Ordering::Greater
-                } else {
-                    std::cmp::Ordering::Equal
-                }
-            });
+            elements.sort_by(|a, b| f(*a, *b).cmp(&0));
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            elements.sort_by(|a, b| {
-                let cmp = f(env_ptr, *a, *b);
-                if cmp < 0 {
-                    std::cmp::Ordering::Less
-                } else if cmp > 0 {
-                    std::cmp::Ordering::Greater
-                } else {
-                    std::cmp::Ordering::Equal
-                }
-            });
-        }
-        // Allocate new list with sorted elements.
-        let new_list = alloc_list(len);
-        *(new_list as *mut u64) = len;
-        let dst = list_data_mut(new_list);
-        for (i, elem) in elements.iter().enumerate() {
-            *dst.add(i) = *elem;
+            elements.sort_by(|a, b| f(env_ptr, *a, *b).cmp(&0));
         }
-        new_list
+        list_from_slice(&elements)
     }
 }
 
@@ -501,35 +787,26 @@ pub extern "C" fn mesh_list_sort(
 /// If `env_ptr` is null, `fn_ptr` is called as `fn(elem) -> u64` (nonzero = true).
 /// If `env_ptr` is non-null, `fn_ptr` is called as `fn(env_ptr, elem) -> u64`.
 #[no_mangle]
-pub extern "C" fn mesh_list_find(
+pub extern "C-unwind" fn mesh_list_find(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> *mut u8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
-        if env_ptr.is_null() {
+        let found = if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let elem = *src.add(i);
-                if f(elem) != 0 {
-                    return alloc_option(0, elem as *mut u8) as *mut u8; // Some(elem)
-                }
-            }
+            elems(list).find(|&elem| f(elem) != 0)
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let elem = *src.add(i);
-                if f(env_ptr, elem) != 0 {
-                    return alloc_option(0, elem as *mut u8) as *mut u8; // Some(elem)
-                }
-            }
+            elems(list).find(|&elem| f(env_ptr, elem) != 0)
+        };
+        match found {
+            Some(elem) => alloc_option(0, elem as *mut u8) as *mut u8, // Some(elem)
+            None => alloc_option(1, std::ptr::null_mut()) as *mut u8,  // None
         }
-        alloc_option(1, std::ptr::null_mut()) as *mut u8 // None
     }
 }
 
@@ -538,33 +815,22 @@ pub extern "C" fn mesh_list_find(
 /// Returns 1 (true) if at least one element matches, 0 (false) otherwise.
 /// Short-circuits on first match.
 #[no_mangle]
-pub extern "C" fn mesh_list_any(
+pub extern "C-unwind" fn mesh_list_any(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> i8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                if f(*src.add(i)) != 0 {
-                    return 1;
-                }
-            }
+            elems(list).any(|elem| f(elem) != 0) as i8
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                if f(env_ptr, *src.add(i)) != 0 {
-                    return 1;
-                }
-            }
+            elems(list).any(|elem| f(env_ptr, elem) != 0) as i8
         }
-        0
     }
 }
 
@@ -573,33 +839,22 @@ pub extern "C" fn mesh_list_any(
 /// Returns 1 (true) if every element matches, 0 (false) otherwise.
 /// Short-circuits on first non-match.
 #[no_mangle]
-pub extern "C" fn mesh_list_all(
+pub extern "C-unwind" fn mesh_list_all(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> i8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
         if env_ptr.is_null() {
             let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                if f(*src.add(i)) == 0 {
-                    return 0;
-                }
-            }
+            elems(list).all(|elem| f(elem) != 0) as i8
         } else {
             let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                if f(env_ptr, *src.add(i)) == 0 {
-                    return 0;
-                }
-            }
+            elems(list).all(|elem| f(env_ptr, elem) != 0) as i8
         }
-        1
     }
 }
 
@@ -609,38 +864,19 @@ pub extern "C" fn mesh_list_all(
 /// Works correctly for Int, Bool, and pointer identity. For String content
 /// equality, users should use `List.any(list, fn(x) -> x == elem end)`.
 #[no_mangle]
-pub extern "C" fn mesh_list_contains(list: *mut u8, elem: u64) -> i8 {
-    unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
-        for i in 0..len as usize {
-            if *src.add(i) == elem {
-                return 1;
-            }
-        }
-        0
-    }
+pub extern "C-unwind" fn mesh_list_contains(list: *mut u8, elem: u64) -> i8 {
+    unsafe { elems(list).any(|e| e == elem) as i8 }
 }
 
 /// Zip two lists into a list of 2-tuples, truncated to the shorter length.
 #[no_mangle]
-pub extern "C" fn mesh_list_zip(a: *mut u8, b: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_zip(a: *mut u8, b: *mut u8) -> *mut u8 {
     unsafe {
-        let len_a = list_len(a);
-        let len_b = list_len(b);
-        let len = len_a.min(len_b);
-
-        let result = alloc_list(len);
-        *(result as *mut u64) = len;
-        let src_a = list_data(a);
-        let src_b = list_data(b);
-        let dst = list_data_mut(result);
-
-        for i in 0..len as usize {
-            let pair = alloc_pair(*src_a.add(i), *src_b.add(i));
-            *dst.add(i) = pair as u64;
-        }
-        result
+        let pairs: Vec<u64> = elems(a)
+            .zip(elems(b))
+            .map(|(x, y)| alloc_pair(x, y) as u64)
+            .collect();
+        list_from_slice(&pairs)
     }
 }
 
@@ -649,49 +885,25 @@ pub extern "C" fn mesh_list_zip(a: *mut u8, b: *mut u8) -> *mut u8 {
 /// If `env_ptr` is null, `fn_ptr` is called as `fn(element) -> list_ptr_as_u64`.
 /// If `env_ptr` is non-null, `fn_ptr` is called as `fn(env_ptr, element) -> list_ptr_as_u64`.
 #[no_mangle]
-pub extern "C" fn mesh_list_flat_map(
+pub extern "C-unwind" fn mesh_list_flat_map(
     list: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
 ) -> *mut u8 {
-    type BareFn = unsafe extern "C" fn(u64) -> u64;
-    type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
 
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
-        let mut all_elems: Vec<u64> = Vec::new();
-
-        if env_ptr.is_null() {
-            let f: BareFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let sub_list = f(*src.add(i)) as *mut u8;
-                let sub_len = list_len(sub_list) as usize;
-                let sub_data = list_data(sub_list);
-                for j in 0..sub_len {
-                    all_elems.push(*sub_data.add(j));
-                }
-            }
-        } else {
-            let f: ClosureFn = std::mem::transmute(fn_ptr);
-            for i in 0..len as usize {
-                let sub_list = f(env_ptr, *src.add(i)) as *mut u8;
-                let sub_len = list_len(sub_list) as usize;
-                let sub_data = list_data(sub_list);
-                for j in 0..sub_len {
-                    all_elems.push(*sub_data.add(j));
-                }
-            }
-        }
-
-        let result_len = all_elems.len() as u64;
-        let result = alloc_list(result_len);
-        *(result as *mut u64) = result_len;
-        let dst = list_data_mut(result);
-        for (i, elem) in all_elems.iter().enumerate() {
-            *dst.add(i) = *elem;
-        }
-        result
+        collect_rooted(elems(list).flat_map(|elem| {
+            let sub_list = if env_ptr.is_null() {
+                let f: BareFn = std::mem::transmute(fn_ptr);
+                f(elem)
+            } else {
+                let f: ClosureFn = std::mem::transmute(fn_ptr);
+                f(env_ptr, elem)
+            } as *const u8;
+            elems(sub_list)
+        }))
     }
 }
 
@@ -699,89 +911,84 @@ pub extern "C" fn mesh_list_flat_map(
 ///
 /// Each element of the outer list is treated as a list pointer (stored as u64).
 #[no_mangle]
-pub extern "C" fn mesh_list_flatten(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_flatten(list: *mut u8) -> *mut u8 {
     unsafe {
-        let outer_len = list_len(list) as usize;
-        let outer_data = list_data(list);
         let mut all_elems: Vec<u64> = Vec::new();
-
-        for i in 0..outer_len {
-            let sub_list = *outer_data.add(i) as *mut u8;
-            let sub_len = list_len(sub_list) as usize;
-            let sub_data = list_data(sub_list);
-            for j in 0..sub_len {
-                all_elems.push(*sub_data.add(j));
-            }
+        for sub_list in elems(list) {
+            all_elems.extend(elems(sub_list as *const u8));
         }
-
-        let result_len = all_elems.len() as u64;
-        let result = alloc_list(result_len);
-        *(result as *mut u64) = result_len;
-        let dst = list_data_mut(result);
-        for (i, elem) in all_elems.iter().enumerate() {
-            *dst.add(i) = *elem;
-        }
-        result
+        list_from_slice(&all_elems)
     }
 }
 
 /// Create a list of (index, element) tuples from a list.
 #[no_mangle]
-pub extern "C" fn mesh_list_enumerate(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_enumerate(list: *mut u8) -> *mut u8 {
     unsafe {
-        let len = list_len(list);
-        let src = list_data(list);
-        let result = alloc_list(len);
-        *(result as *mut u64) = len;
-        let dst = list_data_mut(result);
-
-        for i in 0..len as usize {
-            let pair = alloc_pair(i as u64, *src.add(i));
-            *dst.add(i) = pair as u64;
-        }
-        result
+        let pairs: Vec<u64> = elems(list)
+            .enumerate()
+            .map(|(i, elem)| alloc_pair(i as u64, elem) as u64)
+            .collect();
+        list_from_slice(&pairs)
     }
 }
 
 /// Return a new list with the first `n` elements.
 /// Clamps `n` to [0, len].
 #[no_mangle]
-pub extern "C" fn mesh_list_take(list: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_take(list: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let len = list_len(list);
         let actual_n = (n.max(0) as u64).min(len);
-        alloc_list_from(list_data(list), actual_n, actual_n)
+        let (front, back) = sides(list);
+        if actual_n == len {
+            alloc_list(front, back)
+        } else if actual_n <= front.len() {
+            // The first `n` elements are the last `n` positions of the front.
+            alloc_list(front.drop_first(front.len() - actual_n), Side::EMPTY)
+        } else {
+            let taken: Vec<u64> = elems(list).take(actual_n as usize).collect();
+            list_from_slice(&taken)
+        }
     }
 }
 
 /// Return a new list with the first `n` elements removed.
 /// Clamps `n` to [0, len].
 #[no_mangle]
-pub extern "C" fn mesh_list_drop(list: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_drop(list: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let len = list_len(list);
         let actual_n = (n.max(0) as u64).min(len);
-        let remaining = len - actual_n;
-        alloc_list_from(list_data(list).add(actual_n as usize), remaining, remaining)
+        let (mut front, back) = sides(list);
+        let front_len = front.len();
+        if actual_n >= front_len {
+            alloc_list(Side::EMPTY, back.drop_first(actual_n - front_len))
+        } else {
+            for _ in 0..actual_n {
+                front = front.pop();
+            }
+            alloc_list(front, back)
+        }
     }
 }
 
 /// Return the last element of the list. Panics if empty.
 #[no_mangle]
-pub extern "C" fn mesh_list_last(list: *mut u8) -> u64 {
+pub extern "C-unwind" fn mesh_list_last(list: *mut u8) -> u64 {
     unsafe {
         let len = list_len(list);
         if len == 0 {
             panic!("mesh_list_last: empty list");
         }
-        *list_data(list).add(len as usize - 1)
+        get_unchecked(list, len - 1)
     }
 }
 
 /// Return the element at index `n`. Panics if out of bounds.
 /// (Alias for get, used by List.nth module-qualified access.)
 #[no_mangle]
-pub extern "C" fn mesh_list_nth(list: *mut u8, index: i64) -> u64 {
+pub extern "C-unwind" fn mesh_list_nth(list: *mut u8, index: i64) -> u64 {
     mesh_list_get(list, index)
 }
 
@@ -798,7 +1005,7 @@ struct ListIterator {
 
 /// Create a new iterator handle for a list.
 #[no_mangle]
-pub extern "C" fn mesh_list_iter_new(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_iter_new(list: *mut u8) -> *mut u8 {
     unsafe {
         let len = mesh_list_length(list);
         let iter = mesh_gc_alloc_actor(
@@ -815,7 +1022,7 @@ pub extern "C" fn mesh_list_iter_new(list: *mut u8) -> *mut u8 {
 
 /// Advance the list iterator, returning Option (tag 0 = Some, tag 1 = None).
 #[no_mangle]
-pub extern "C" fn mesh_list_iter_next(iter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_iter_next(iter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let iter = iter_ptr as *mut ListIterator;
         if (*iter).index >= (*iter).length {
@@ -832,7 +1039,7 @@ pub extern "C" fn mesh_list_iter_next(iter_ptr: *mut u8) -> *mut u8 {
 /// For Phase 76, this is equivalent to mesh_list_iter_new.
 /// Future phases can add type-tag dispatch for Map/Set/Range.
 #[no_mangle]
-pub extern "C" fn mesh_iter_from(collection: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_from(collection: *mut u8) -> *mut u8 {
     // Delegate to list iterator creation.
     mesh_list_iter_new(collection)
 }
@@ -920,7 +1127,7 @@ mod tests {
         let list = mesh_list_append(list, 2);
         let list = mesh_list_append(list, 3);
 
-        unsafe extern "C" fn double(x: u64) -> u64 {
+        unsafe extern "C-unwind" fn double(x: u64) -> u64 {
             x * 2
         }
 
@@ -941,7 +1148,7 @@ mod tests {
         let list = mesh_list_append(list, 4);
 
         // Keep only even numbers (value % 2 == 0).
-        unsafe extern "C" fn is_even(x: u64) -> u64 {
+        unsafe extern "C-unwind" fn is_even(x: u64) -> u64 {
             if x % 2 == 0 { 1 } else { 0 }
         }
 
@@ -959,7 +1166,7 @@ mod tests {
         let list = mesh_list_append(list, 2);
         let list = mesh_list_append(list, 3);
 
-        unsafe extern "C" fn add(acc: u64, x: u64) -> u64 {
+        unsafe extern "C-unwind" fn add(acc: u64, x: u64) -> u64 {
             acc + x
         }
 
@@ -975,7 +1182,7 @@ mod tests {
         let list = mesh_list_append(list, 20);
 
         // Simulate a closure with an environment: add the value stored at env_ptr.
-        unsafe extern "C" fn add_env(env: *mut u8, x: u64) -> u64 {
+        unsafe extern "C-unwind" fn add_env(env: *mut u8, x: u64) -> u64 {
             let offset = *(env as *const u64);
             x + offset
         }
@@ -1012,7 +1219,7 @@ mod tests {
         mesh_rt_init();
         let list = mesh_list_new();
 
-        unsafe extern "C" fn add(acc: u64, x: u64) -> u64 {
+        unsafe extern "C-unwind" fn add(acc: u64, x: u64) -> u64 {
             acc + x
         }
 
@@ -1049,7 +1256,7 @@ mod tests {
         let b = mesh_list_append(b, 2);
         let b = mesh_list_append(b, 3);
 
-        unsafe extern "C" fn int_eq(a: u64, b: u64) -> i8 {
+        unsafe extern "C-unwind" fn int_eq(a: u64, b: u64) -> i8 {
             if a == b { 1 } else { 0 }
         }
 
@@ -1066,7 +1273,7 @@ mod tests {
         let b = mesh_list_append(b, 1);
         let b = mesh_list_append(b, 3);
 
-        unsafe extern "C" fn int_eq(a: u64, b: u64) -> i8 {
+        unsafe extern "C-unwind" fn int_eq(a: u64, b: u64) -> i8 {
             if a == b { 1 } else { 0 }
         }
 
@@ -1082,7 +1289,7 @@ mod tests {
         let b = mesh_list_new();
         let b = mesh_list_append(b, 1);
 
-        unsafe extern "C" fn int_eq(a: u64, b: u64) -> i8 {
+        unsafe extern "C-unwind" fn int_eq(a: u64, b: u64) -> i8 {
             if a == b { 1 } else { 0 }
         }
 
@@ -1099,7 +1306,7 @@ mod tests {
         let b = mesh_list_append(b, 1);
         let b = mesh_list_append(b, 3);
 
-        unsafe extern "C" fn int_cmp(a: u64, b: u64) -> i64 {
+        unsafe extern "C-unwind" fn int_cmp(a: u64, b: u64) -> i64 {
             (a as i64) - (b as i64)
         }
 
@@ -1116,7 +1323,7 @@ mod tests {
         let b = mesh_list_append(b, 1);
         let b = mesh_list_append(b, 2);
 
-        unsafe extern "C" fn int_cmp(a: u64, b: u64) -> i64 {
+        unsafe extern "C-unwind" fn int_cmp(a: u64, b: u64) -> i64 {
             (a as i64) - (b as i64)
         }
 
@@ -1134,7 +1341,7 @@ mod tests {
         let b = mesh_list_append(b, 2);
         let b = mesh_list_append(b, 3);
 
-        unsafe extern "C" fn int_cmp(a: u64, b: u64) -> i64 {
+        unsafe extern "C-unwind" fn int_cmp(a: u64, b: u64) -> i64 {
             (a as i64) - (b as i64)
         }
 
@@ -1181,4 +1388,180 @@ mod tests {
         assert_eq!(mesh_list_get(list, 1), 20);
         assert_eq!(mesh_list_get(list, 2), 30);
     }
+
+    fn to_vec(list: *mut u8) -> Vec<u64> {
+        (0..mesh_list_length(list)).map(|i| mesh_list_get(list, i)).collect()
+    }
+
+    #[test]
+    fn test_list_append_many_spans_trie_levels() {
+        mesh_rt_init();
+        // 40_000 elements need a trie three levels deep (32^3 = 32_768).
+        let mut list = mesh_list_new();
+        for i in 0..40_000u64 {
+            list = mesh_list_append(list, i);
+        }
+        assert_eq!(mesh_list_length(list), 40_000);
+        for i in [0u64, 31, 32, 1023, 1024, 1056, 32_767, 32_768, 39_999] {
+            assert_eq!(mesh_list_get(list, i as i64), i);
+        }
+        assert_eq!(to_vec(list), (0..40_000).collect::<Vec<_>>());
+        assert_eq!(unsafe { list_to_vec(list) }, (0..40_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_list_from_array_matches_appends() {
+        mesh_rt_init();
+        for n in [1usize, 31, 32, 33, 1024, 1025, 1057, 33_000] {
+            let data: Vec<u64> = (0..n as u64).collect();
+            let built = mesh_list_from_array(data.as_ptr(), n as i64);
+            assert_eq!(unsafe { list_to_vec(built) }, data);
+            // Appending onto a bottom-up trie keeps working.
+            let more = mesh_list_append(built, 7);
+            assert_eq!(mesh_list_get(more, n as i64), 7);
+            assert_eq!(mesh_list_length(built), n as i64);
+        }
+    }
+
+    #[test]
+    fn test_list_versions_share_structure_independently() {
+        mesh_rt_init();
+        let mut base = mesh_list_new();
+        for i in 0..100 {
+            base = mesh_list_append(base, i);
+        }
+        let a = mesh_list_append(base, 1000);
+        let b = mesh_list_append(base, 2000);
+        assert_eq!(mesh_list_get(a, 100), 1000);
+        assert_eq!(mesh_list_get(b, 100), 2000);
+        assert_eq!(mesh_list_length(base), 100);
+    }
+
+    #[test]
+    fn test_list_prepend_and_tail() {
+        mesh_rt_init();
+        let mut list = mesh_list_from_array([100u64, 101].as_ptr(), 2);
+        for i in 0..2_000u64 {
+            list = mesh_list_prepend(list, i);
+        }
+        let mut expected: Vec<u64> = (0..2_000).rev().collect();
+        expected.extend([100, 101]);
+        assert_eq!(to_vec(list), expected);
+        assert_eq!(mesh_list_head(list), 1_999);
+        assert_eq!(mesh_list_last(list), 101);
+
+        // Tail walks off the prepended elements, then the appended ones.
+        let mut rest = list;
+        for _ in 0..2_001 {
+            rest = mesh_list_tail(rest);
+        }
+        assert_eq!(to_vec(rest), vec![101]);
+        assert_eq!(mesh_list_length(mesh_list_tail(rest)), 0);
+        assert_eq!(mesh_list_length(list), 2_002);
+    }
+
+    #[test]
+    fn test_list_tail_of_long_list() {
+        mesh_rt_init();
+        let data: Vec<u64> = (0..5_000).collect();
+        let mut list = mesh_list_from_array(data.as_ptr(), data.len() as i64);
+        for i in 0..4_990u64 {
+            assert_eq!(mesh_list_head(list), i);
+            list = mesh_list_tail(list);
+        }
+        assert_eq!(to_vec(list), (4_990..5_000).collect::<Vec<_>>());
+        let list = mesh_list_append(list, 9);
+        assert_eq!(mesh_list_last(list), 9);
+    }
+
+    #[test]
+    fn test_list_reverse_twice_and_mixed_sides() {
+        mesh_rt_init();
+        let list = mesh_list_prepend(mesh_list_from_array([1u64, 2, 3].as_ptr(), 3), 0);
+        let reversed = mesh_list_reverse(list);
+        assert_eq!(to_vec(reversed), vec![3, 2, 1, 0]);
+        let grown = mesh_list_prepend(mesh_list_append(reversed, 9), 8);
+        assert_eq!(to_vec(grown), vec![8, 3, 2, 1, 0, 9]);
+        assert_eq!(to_vec(mesh_list_reverse(grown)), vec![9, 0, 1, 2, 3, 8]);
+        assert_eq!(to_vec(mesh_list_tail(reversed)), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_list_concat_either_longer() {
+        mesh_rt_init();
+        let short = mesh_list_from_array([1u64, 2].as_ptr(), 2);
+        let data: Vec<u64> = (10..110).collect();
+        let long = mesh_list_from_array(data.as_ptr(), data.len() as i64);
+        let mut expected = vec![1, 2];
+        expected.extend(10..110);
+        assert_eq!(to_vec(mesh_list_concat(short, long)), expected);
+        let mut expected: Vec<u64> = (10..110).collect();
+        expected.extend([1, 2]);
+        assert_eq!(to_vec(mesh_list_concat(long, short)), expected);
+    }
+
+    #[test]
+    fn test_list_take_drop_across_sides() {
+        mesh_rt_init();
+        let mut list = mesh_list_from_array([10u64, 11, 12, 13].as_ptr(), 4);
+        for i in (0..3u64).rev() {
+            list = mesh_list_prepend(list, i);
+        }
+        // [0, 1, 2, 10, 11, 12, 13]
+        assert_eq!(to_vec(mesh_list_take(list, 2)), vec![0, 1]);
+        assert_eq!(to_vec(mesh_list_take(list, 5)), vec![0, 1, 2, 10, 11]);
+        assert_eq!(to_vec(mesh_list_take(list, 99)), to_vec(list));
+        assert_eq!(to_vec(mesh_list_drop(list, 1)), vec![1, 2, 10, 11, 12, 13]);
+        assert_eq!(to_vec(mesh_list_drop(list, 4)), vec![11, 12, 13]);
+        assert_eq!(mesh_list_length(mesh_list_drop(list, 99)), 0);
+    }
+
+    #[test]
+    fn test_list_builder_push_many() {
+        mesh_rt_init();
+        let list = mesh_list_builder_new(0);
+        for i in 0..3_000u64 {
+            mesh_list_builder_push(list, i * 2);
+        }
+        assert_eq!(mesh_list_length(list), 3_000);
+        assert_eq!(to_vec(list), (0..3_000).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_list_map_results_survive_gc_in_closure() {
+        use crate::actor::{global_scheduler, mesh_rt_init_actor, ProcessState};
+        use crate::string::{mesh_string_new, MeshString};
+        use std::sync::Mutex;
+
+        static MAPPED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        extern "C-unwind" fn label(i: u64) -> u64 {
+            // Collect on every call, as a closure reaching a reduction check
+            // might, so earlier results must already be reachable.
+            crate::gc::mesh_gc_collect();
+            let text = format!("item-{}", i);
+            mesh_string_new(text.as_ptr(), text.len() as u64) as u64
+        }
+
+        extern "C-unwind" fn entry(_args: *const u8) {
+            let input = unsafe { list_from_slice(&(0..100).collect::<Vec<u64>>()) };
+            let mapped = mesh_list_map(input, label as *mut u8, ptr::null_mut());
+            std::hint::black_box(input);
+            *MAPPED.lock().unwrap() = (0..mesh_list_length(mapped))
+                .map(|i| unsafe { (*(mesh_list_get(mapped, i) as *const MeshString)).as_str().to_string() })
+                .collect();
+        }
+
+        mesh_rt_init_actor(1);
+        let sched = global_scheduler();
+        let pid = sched.spawn(entry as *const u8, ptr::null(), 0, 1);
+        while sched
+            .get_process(pid)
+            .is_some_and(|p| !matches!(p.lock().state, ProcessState::Exited(_)))
+        {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        let expected: Vec<String> = (0..100).map(|i| format!("item-{}", i)).collect();
+        assert_eq!(*MAPPED.lock().unwrap(), expected);
+    }
 }
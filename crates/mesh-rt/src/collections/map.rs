@@ -1,253 +1,619 @@
-//! GC-managed immutable Map for the Mesh runtime.
+//! GC-managed persistent Map for the Mesh runtime.
 //!
 //! A MeshMap stores key-value pairs where both keys and values are uniform
-//! 8-byte (`u64`) values. Backed by a simple vector of `(u64, u64)` pairs
-//! with linear scan -- efficient for the small maps typical in Phase 8.
+//! 8-byte (`u64`) values. Entries live in a hash array mapped trie (HAMT) in
+//! the compressed CHAMP layout: each node branches 32 ways on 5 bits of the
+//! key's hash, so put/get/delete are O(log32 n). Updates copy only the path
+//! from the root to the changed entry and share every other node with the
+//! original map (immutable semantics: all mutation operations return a NEW
+//! map).
 //!
-//! All mutation operations return a NEW map (immutable semantics).
-//!
-//! The upper 8 bits of the capacity field store a key_type tag:
+//! Keys are hashed and compared according to the map's key_type:
 //! - 0 = integer keys (compared by value)
 //! - 1 = string keys (compared by content via mesh_string_eq)
+//! - 2 = keys with compiler-generated hash and eq callbacks, used for
+//!   struct, sum type and tuple keys (their derived `Hash` and `Eq`)
+//!
+//! Iteration (keys, values, to_list, for-in, Display) follows insertion
+//! order: every entry records a sequence number, and the entries sorted by
+//! it are cached on the map the first time they are needed.
 
 use crate::gc::mesh_gc_alloc_actor;
 use super::list::alloc_pair;
 use std::ptr;
 
-/// Map header: len (u64), cap (u64).
-const HEADER_SIZE: usize = 16;
-/// Each entry is a (key, value) pair = 16 bytes.
-const ENTRY_SIZE: usize = 16;
-
 /// Key type tag: integer keys (compared by value equality).
 const KEY_TYPE_INT: u64 = 0;
 /// Key type tag: string keys (compared by content via mesh_string_eq).
 const KEY_TYPE_STR: u64 = 1;
-/// Number of bits to shift for the key_type tag in the cap field.
-const TAG_SHIFT: u64 = 56;
-/// Mask for extracting the raw capacity (lower 56 bits).
-const CAP_MASK: u64 = (1u64 << 56) - 1;
+/// Key type tag: keys hashed and compared by the callbacks in the header.
+const KEY_TYPE_HASHED: u64 = 2;
+
+/// Map header: len, key_type, key_hash, key_eq, root, next_seq, order.
+const MAP_WORDS: usize = 7;
+const MAP_LEN: usize = 0;
+const MAP_KEY_TYPE: usize = 1;
+const MAP_KEY_HASH: usize = 2;
+const MAP_KEY_EQ: usize = 3;
+const MAP_ROOT: usize = 4;
+const MAP_NEXT_SEQ: usize = 5;
+const MAP_ORDER: usize = 6;
+
+/// Trie node kinds. A bitmap node holds `popcount(datamap)` entries
+/// followed by `popcount(nodemap)` children; a collision node holds the
+/// entries whose 64-bit hashes are all equal.
+const NODE_BITMAP: u64 = 0;
+const NODE_COLLISION: u64 = 1;
+/// Node header: kind, then `datamap | nodemap << 32` (bitmap) or the
+/// entry count (collision).
+const NODE_HEADER_WORDS: usize = 2;
+
+/// Hash bits consumed per trie level.
+const BITS: u32 = 5;
+const FRAG_MASK: u64 = (1 << BITS) - 1;
+
+type KeyHash = unsafe extern "C-unwind" fn(u64) -> i64;
+type KeyEq = unsafe extern "C-unwind" fn(u64, u64) -> i8;
+
+/// A key-value pair plus its hash and insertion sequence number.
+#[repr(C)]
+struct Entry {
+    hash: u64,
+    key: u64,
+    value: u64,
+    seq: u64,
+}
+
+// ── Key hashing and equality ─────────────────────────────────────────
+
+/// How a map hashes and compares its keys, read from its header.
+#[derive(Clone, Copy)]
+struct KeyOps {
+    key_type: u64,
+    hash_fn: *mut u8,
+    eq_fn: *mut u8,
+}
+
+impl KeyOps {
+    unsafe fn of(m: *const u8) -> Self {
+        KeyOps {
+            key_type: header(m, MAP_KEY_TYPE),
+            hash_fn: header(m, MAP_KEY_HASH) as *mut u8,
+            eq_fn: header(m, MAP_KEY_EQ) as *mut u8,
+        }
+    }
+
+    unsafe fn hash(&self, key: u64) -> u64 {
+        let raw = match self.key_type {
+            KEY_TYPE_STR => crate::hash::mesh_hash_string(key as *const crate::string::MeshString),
+            KEY_TYPE_HASHED if !self.hash_fn.is_null() => {
+                let f: KeyHash = std::mem::transmute(self.hash_fn);
+                f(key)
+            }
+            _ => crate::hash::mesh_hash_int(key as i64),
+        };
+        mix(raw as u64)
+    }
+
+    unsafe fn eq(&self, a: u64, b: u64) -> bool {
+        match self.key_type {
+            KEY_TYPE_STR => {
+                crate::string::mesh_string_eq(
+                    a as *const crate::string::MeshString,
+                    b as *const crate::string::MeshString,
+                ) != 0
+            }
+            KEY_TYPE_HASHED if !self.eq_fn.is_null() => {
+                let f: KeyEq = std::mem::transmute(self.eq_fn);
+                f(a, b) != 0
+            }
+            _ => a == b,
+        }
+    }
+}
+
+/// Spread a key hash over all 64 bits (the splitmix64 finalizer), so that
+/// derived hashes with weak low bits still branch evenly in the trie.
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xbf58476d1ce4e5b9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94d049bb133111eb);
+    h ^ (h >> 31)
+}
 
-// ── Internal helpers ──────────────────────────────────────────────────
+// ── Map header helpers ───────────────────────────────────────────────
+
+unsafe fn header(m: *const u8, field: usize) -> u64 {
+    *((m as *const u64).add(field))
+}
+
+unsafe fn set_header(m: *mut u8, field: usize, value: u64) {
+    *((m as *mut u64).add(field)) = value;
+}
 
 unsafe fn map_len(m: *const u8) -> u64 {
-    *(m as *const u64)
+    header(m, MAP_LEN)
+}
+
+unsafe fn map_root(m: *const u8) -> *mut u8 {
+    header(m, MAP_ROOT) as *mut u8
+}
+
+unsafe fn alloc_map(ops: KeyOps) -> *mut u8 {
+    let p = mesh_gc_alloc_actor((MAP_WORDS * 8) as u64, 8);
+    ptr::write_bytes(p, 0, MAP_WORDS * 8);
+    set_header(p, MAP_KEY_TYPE, ops.key_type);
+    set_header(p, MAP_KEY_HASH, ops.hash_fn as u64);
+    set_header(p, MAP_KEY_EQ, ops.eq_fn as u64);
+    p
+}
+
+/// A map sharing `m`'s key ops with a new root, length and next sequence number.
+unsafe fn derive_map(m: *const u8, root: *mut u8, len: u64, next_seq: u64) -> *mut u8 {
+    let p = alloc_map(KeyOps::of(m));
+    set_header(p, MAP_LEN, len);
+    set_header(p, MAP_ROOT, root as u64);
+    set_header(p, MAP_NEXT_SEQ, next_seq);
+    p
 }
 
-/// Extract the key_type tag from the upper 8 bits of the cap field.
-unsafe fn map_key_type(m: *const u8) -> u64 {
-    (*((m as *const u64).add(1))) >> TAG_SHIFT
+unsafe fn alloc_entry(hash: u64, key: u64, value: u64, seq: u64) -> *mut Entry {
+    let e = mesh_gc_alloc_actor(std::mem::size_of::<Entry>() as u64, 8) as *mut Entry;
+    ptr::write(e, Entry { hash, key, value, seq });
+    e
 }
 
-/// Extract the raw capacity (lower 56 bits of the cap field).
-unsafe fn map_cap_raw(m: *const u8) -> u64 {
-    (*((m as *const u64).add(1))) & CAP_MASK
+// ── Trie node helpers ────────────────────────────────────────────────
+
+unsafe fn node_kind(n: *const u8) -> u64 {
+    *(n as *const u64)
 }
 
-#[allow(dead_code)]
-unsafe fn map_cap(m: *const u8) -> u64 {
-    map_cap_raw(m)
+unsafe fn node_maps(n: *const u8) -> (u32, u32) {
+    let maps = *((n as *const u64).add(1));
+    (maps as u32, (maps >> 32) as u32)
 }
 
-unsafe fn map_entries(m: *const u8) -> *const [u64; 2] {
-    (m as *const u8).add(HEADER_SIZE) as *const [u64; 2]
+/// Number of entries in a collision node.
+unsafe fn collision_len(n: *const u8) -> usize {
+    *((n as *const u64).add(1)) as usize
 }
 
-unsafe fn map_entries_mut(m: *mut u8) -> *mut [u64; 2] {
-    m.add(HEADER_SIZE) as *mut [u64; 2]
+unsafe fn node_slots(n: *const u8) -> *mut u64 {
+    (n as *mut u64).add(NODE_HEADER_WORDS)
 }
 
-/// Check if two keys are equal, dispatching based on the map's key_type.
-unsafe fn keys_equal(m: *const u8, a: u64, b: u64) -> bool {
-    if map_key_type(m) == KEY_TYPE_STR {
-        crate::string::mesh_string_eq(
-            a as *const crate::string::MeshString,
-            b as *const crate::string::MeshString,
-        ) != 0
+unsafe fn node_slot_count(n: *const u8) -> usize {
+    if node_kind(n) == NODE_COLLISION {
+        collision_len(n)
     } else {
-        a == b
+        let (datamap, nodemap) = node_maps(n);
+        (datamap.count_ones() + nodemap.count_ones()) as usize
     }
 }
 
-unsafe fn alloc_map(cap: u64, key_type: u64) -> *mut u8 {
-    let total = HEADER_SIZE + (cap as usize) * ENTRY_SIZE;
-    let p = mesh_gc_alloc_actor(total as u64, 8);
-    *(p as *mut u64) = 0; // len
-    *((p as *mut u64).add(1)) = (key_type << TAG_SHIFT) | cap; // key_type tag + cap
-    p
+unsafe fn alloc_node(kind: u64, word1: u64, slots: &[u64]) -> *mut u8 {
+    let total = (NODE_HEADER_WORDS + slots.len()) * 8;
+    let n = mesh_gc_alloc_actor(total as u64, 8);
+    *(n as *mut u64) = kind;
+    *((n as *mut u64).add(1)) = word1;
+    ptr::copy_nonoverlapping(slots.as_ptr(), node_slots(n), slots.len());
+    n
 }
 
-/// Find the index of a key, or return None.
-unsafe fn find_key(m: *const u8, key: u64) -> Option<usize> {
-    let len = map_len(m) as usize;
-    let entries = map_entries(m);
-    for i in 0..len {
-        if keys_equal(m, (*entries.add(i))[0], key) {
-            return Some(i);
+unsafe fn alloc_bitmap_node(datamap: u32, nodemap: u32, slots: &[u64]) -> *mut u8 {
+    alloc_node(NODE_BITMAP, datamap as u64 | ((nodemap as u64) << 32), slots)
+}
+
+unsafe fn slots_vec(n: *const u8) -> Vec<u64> {
+    let count = node_slot_count(n);
+    std::slice::from_raw_parts(node_slots(n), count).to_vec()
+}
+
+/// The 5-bit hash fragment that selects a branch at `shift`.
+fn fragment(hash: u64, shift: u32) -> u32 {
+    ((hash >> shift) & FRAG_MASK) as u32
+}
+
+/// Position of `bit` among the bits set in `bitmap`.
+fn index_of(bitmap: u32, bit: u32) -> usize {
+    (bitmap & (bit - 1)).count_ones() as usize
+}
+
+/// The sole entry of a node that holds one entry and no children, if any.
+/// Such nodes are inlined into their parent after a delete.
+unsafe fn single_entry(n: *const u8) -> Option<*mut Entry> {
+    let only = if node_kind(n) == NODE_COLLISION {
+        collision_len(n) == 1
+    } else {
+        let (datamap, nodemap) = node_maps(n);
+        datamap.count_ones() == 1 && nodemap == 0
+    };
+    if only {
+        Some(*node_slots(n) as *mut Entry)
+    } else {
+        None
+    }
+}
+
+// ── Trie operations ──────────────────────────────────────────────────
+
+unsafe fn find_entry(ops: KeyOps, root: *const u8, hash: u64, key: u64) -> *mut Entry {
+    let mut node = root;
+    let mut shift = 0;
+    while !node.is_null() {
+        let slots = node_slots(node);
+        if node_kind(node) == NODE_COLLISION {
+            for i in 0..collision_len(node) {
+                let e = *slots.add(i) as *mut Entry;
+                if (*e).hash == hash && ops.eq((*e).key, key) {
+                    return e;
+                }
+            }
+            return ptr::null_mut();
+        }
+        let (datamap, nodemap) = node_maps(node);
+        let bit = 1u32 << fragment(hash, shift);
+        if datamap & bit != 0 {
+            let e = *slots.add(index_of(datamap, bit)) as *mut Entry;
+            if (*e).hash == hash && ops.eq((*e).key, key) {
+                return e;
+            }
+            return ptr::null_mut();
+        }
+        if nodemap & bit == 0 {
+            return ptr::null_mut();
         }
+        let child = datamap.count_ones() as usize + index_of(nodemap, bit);
+        node = *slots.add(child) as *const u8;
+        shift += BITS;
     }
-    None
+    ptr::null_mut()
+}
+
+/// A node holding two entries with different keys, branching from `shift`.
+unsafe fn merge_entries(a: *mut Entry, b: *mut Entry, shift: u32) -> *mut u8 {
+    if shift >= 64 {
+        return alloc_node(NODE_COLLISION, 2, &[a as u64, b as u64]);
+    }
+    let fa = fragment((*a).hash, shift);
+    let fb = fragment((*b).hash, shift);
+    if fa == fb {
+        let child = merge_entries(a, b, shift + BITS);
+        return alloc_bitmap_node(0, 1 << fa, &[child as u64]);
+    }
+    let slots = if fa < fb { [a as u64, b as u64] } else { [b as u64, a as u64] };
+    alloc_bitmap_node((1 << fa) | (1 << fb), 0, &slots)
+}
+
+/// Insert `entry` under `node`. An entry for an equal key is replaced,
+/// keeping its sequence number (so iteration order is unchanged). Returns
+/// the new node and whether a key was replaced.
+unsafe fn node_put(ops: KeyOps, node: *const u8, shift: u32, entry: *mut Entry) -> (*mut u8, bool) {
+    let hash = (*entry).hash;
+    let mut slots = slots_vec(node);
+
+    if node_kind(node) == NODE_COLLISION {
+        for slot in slots.iter_mut() {
+            let e = *slot as *mut Entry;
+            if ops.eq((*e).key, (*entry).key) {
+                (*entry).seq = (*e).seq;
+                *slot = entry as u64;
+                return (alloc_node(NODE_COLLISION, slots.len() as u64, &slots), true);
+            }
+        }
+        slots.push(entry as u64);
+        return (alloc_node(NODE_COLLISION, slots.len() as u64, &slots), false);
+    }
+
+    let (datamap, nodemap) = node_maps(node);
+    let bit = 1u32 << fragment(hash, shift);
+    if datamap & bit != 0 {
+        let idx = index_of(datamap, bit);
+        let existing = slots[idx] as *mut Entry;
+        if (*existing).hash == hash && ops.eq((*existing).key, (*entry).key) {
+            (*entry).seq = (*existing).seq;
+            slots[idx] = entry as u64;
+            return (alloc_bitmap_node(datamap, nodemap, &slots), true);
+        }
+        // Two keys share this branch: push both one level down.
+        let child = merge_entries(existing, entry, shift + BITS);
+        slots.remove(idx);
+        let new_datamap = datamap & !bit;
+        let new_nodemap = nodemap | bit;
+        let child_idx = new_datamap.count_ones() as usize + index_of(new_nodemap, bit);
+        slots.insert(child_idx, child as u64);
+        return (alloc_bitmap_node(new_datamap, new_nodemap, &slots), false);
+    }
+    if nodemap & bit != 0 {
+        let child_idx = datamap.count_ones() as usize + index_of(nodemap, bit);
+        let (child, replaced) = node_put(ops, slots[child_idx] as *const u8, shift + BITS, entry);
+        slots[child_idx] = child as u64;
+        return (alloc_bitmap_node(datamap, nodemap, &slots), replaced);
+    }
+    slots.insert(index_of(datamap, bit), entry as u64);
+    (alloc_bitmap_node(datamap | bit, nodemap, &slots), false)
+}
+
+/// Result of removing a key from a subtree.
+enum Removal {
+    /// The key was not present; the subtree is unchanged.
+    Absent,
+    /// The key was removed; the new subtree, or null if it became empty.
+    Removed(*mut u8),
+}
+
+unsafe fn node_delete(ops: KeyOps, node: *const u8, shift: u32, hash: u64, key: u64) -> Removal {
+    let mut slots = slots_vec(node);
+
+    if node_kind(node) == NODE_COLLISION {
+        let Some(pos) = slots.iter().position(|&s| ops.eq((*(s as *mut Entry)).key, key)) else {
+            return Removal::Absent;
+        };
+        slots.remove(pos);
+        if slots.is_empty() {
+            return Removal::Removed(ptr::null_mut());
+        }
+        return Removal::Removed(alloc_node(NODE_COLLISION, slots.len() as u64, &slots));
+    }
+
+    let (datamap, nodemap) = node_maps(node);
+    let bit = 1u32 << fragment(hash, shift);
+    if datamap & bit != 0 {
+        let idx = index_of(datamap, bit);
+        let e = slots[idx] as *mut Entry;
+        if (*e).hash != hash || !ops.eq((*e).key, key) {
+            return Removal::Absent;
+        }
+        slots.remove(idx);
+        if slots.is_empty() {
+            return Removal::Removed(ptr::null_mut());
+        }
+        return Removal::Removed(alloc_bitmap_node(datamap & !bit, nodemap, &slots));
+    }
+    if nodemap & bit == 0 {
+        return Removal::Absent;
+    }
+
+    let child_idx = datamap.count_ones() as usize + index_of(nodemap, bit);
+    let child = match node_delete(ops, slots[child_idx] as *const u8, shift + BITS, hash, key) {
+        Removal::Absent => return Removal::Absent,
+        Removal::Removed(child) => child,
+    };
+    if child.is_null() {
+        slots.remove(child_idx);
+        if slots.is_empty() {
+            return Removal::Removed(ptr::null_mut());
+        }
+        return Removal::Removed(alloc_bitmap_node(datamap, nodemap & !bit, &slots));
+    }
+    if let Some(e) = single_entry(child) {
+        // Keep the trie compact: pull a lone entry up into this node.
+        slots.remove(child_idx);
+        slots.insert(index_of(datamap, bit), e as u64);
+        return Removal::Removed(alloc_bitmap_node(datamap | bit, nodemap & !bit, &slots));
+    }
+    slots[child_idx] = child as u64;
+    Removal::Removed(alloc_bitmap_node(datamap, nodemap, &slots))
+}
+
+unsafe fn collect_entries(node: *const u8, out: &mut Vec<*mut Entry>) {
+    if node.is_null() {
+        return;
+    }
+    let slots = node_slots(node);
+    let (entries, children) = if node_kind(node) == NODE_COLLISION {
+        (collision_len(node), 0)
+    } else {
+        let (datamap, nodemap) = node_maps(node);
+        (datamap.count_ones() as usize, nodemap.count_ones() as usize)
+    };
+    for i in 0..entries {
+        out.push(*slots.add(i) as *mut Entry);
+    }
+    for i in entries..entries + children {
+        collect_entries(*slots.add(i) as *const u8, out);
+    }
+}
+
+/// The map's entries in insertion order: `{ len, entry pointers... }`.
+///
+/// Built on first use and cached in the header. A map never changes after
+/// it is returned, so the cached order stays valid.
+unsafe fn ordered_entries(m: *mut u8) -> *const u64 {
+    let cached = header(m, MAP_ORDER) as *const u64;
+    if !cached.is_null() {
+        return cached;
+    }
+    let mut entries = Vec::with_capacity(map_len(m) as usize);
+    collect_entries(map_root(m), &mut entries);
+    entries.sort_unstable_by_key(|e| (**e).seq);
+
+    let order = mesh_gc_alloc_actor(((entries.len() + 1) * 8) as u64, 8) as *mut u64;
+    *order = entries.len() as u64;
+    for (i, e) in entries.iter().enumerate() {
+        *order.add(i + 1) = *e as u64;
+    }
+    set_header(m, MAP_ORDER, order as u64);
+    order
+}
+
+/// The entry at insertion-order position `index`.
+unsafe fn entry_at(m: *mut u8, index: usize) -> *const Entry {
+    *ordered_entries(m).add(index + 1) as *const Entry
+}
+
+/// Insert `key` into `map`, returning the new map.
+unsafe fn insert(map: *mut u8, key: u64, value: u64) -> *mut u8 {
+    let ops = KeyOps::of(map);
+    let hash = ops.hash(key);
+    let seq = header(map, MAP_NEXT_SEQ);
+    let entry = alloc_entry(hash, key, value, seq);
+    let root = map_root(map);
+    if root.is_null() {
+        let bit = 1u32 << fragment(hash, 0);
+        let root = alloc_bitmap_node(bit, 0, &[entry as u64]);
+        return derive_map(map, root, 1, seq + 1);
+    }
+    let (root, replaced) = node_put(ops, root, 0, entry);
+    let len = map_len(map) + if replaced { 0 } else { 1 };
+    derive_map(map, root, len, seq + 1)
+}
+
+/// Rebuild `map` with the given key ops, re-inserting its entries in order.
+unsafe fn rekey(map: *mut u8, ops: KeyOps) -> *mut u8 {
+    let mut result = alloc_map(ops);
+    let len = map_len(map) as usize;
+    for i in 0..len {
+        let e = entry_at(map, i);
+        result = insert(result, (*e).key, (*e).value);
+    }
+    result
+}
+
+/// An empty map with the same key ops as `m`.
+pub(crate) unsafe fn empty_like(m: *const u8) -> *mut u8 {
+    alloc_map(KeyOps::of(m))
+}
+
+/// The map's key_type tag, as recorded by the wire format.
+pub(crate) fn map_key_type_tag(map: *mut u8) -> u8 {
+    unsafe { header(map, MAP_KEY_TYPE) as u8 }
 }
 
 // ── Public API ────────────────────────────────────────────────────────
 
 /// Create an empty map (integer keys, backward compatible).
 #[no_mangle]
-pub extern "C" fn mesh_map_new() -> *mut u8 {
-    unsafe { alloc_map(0, KEY_TYPE_INT) }
+pub extern "C-unwind" fn mesh_map_new() -> *mut u8 {
+    mesh_map_new_typed(KEY_TYPE_INT as i64)
 }
 
 /// Create an empty map with a specific key_type tag.
 /// key_type: 0 = Int, 1 = String.
 #[no_mangle]
-pub extern "C" fn mesh_map_new_typed(key_type: i64) -> *mut u8 {
-    unsafe { alloc_map(0, key_type as u64) }
+pub extern "C-unwind" fn mesh_map_new_typed(key_type: i64) -> *mut u8 {
+    unsafe {
+        alloc_map(KeyOps {
+            key_type: key_type as u64,
+            hash_fn: ptr::null_mut(),
+            eq_fn: ptr::null_mut(),
+        })
+    }
 }
 
-/// Ensure a map has string key_type. If the map is empty and has integer key_type,
-/// returns a new empty map with string key_type. Otherwise returns the map unchanged.
-/// Used by codegen to tag maps before the first string-key put.
+/// Ensure a map has string key_type. An empty map is replaced by a new empty
+/// map with string key_type; a non-empty map with another key_type (built by
+/// `Map.from_list` or `Map.collect`) is rebuilt with string keys. Otherwise
+/// returns the map unchanged. Used by codegen to tag maps before string-key
+/// operations.
 #[no_mangle]
-pub extern "C" fn mesh_map_tag_string(map: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_tag_string(map: *mut u8) -> *mut u8 {
     unsafe {
-        if map_len(map) == 0 && map_key_type(map) != KEY_TYPE_STR {
-            alloc_map(0, KEY_TYPE_STR)
-        } else {
-            map
+        if header(map, MAP_KEY_TYPE) == KEY_TYPE_STR {
+            return map;
         }
+        let ops = KeyOps { key_type: KEY_TYPE_STR, hash_fn: ptr::null_mut(), eq_fn: ptr::null_mut() };
+        rekey(map, ops)
     }
 }
 
-/// Return a NEW map with the key-value pair added (or updated).
+/// Ensure a map hashes and compares its keys with `key_hash`
+/// (`fn(u64) -> i64`) and `key_eq` (`fn(u64, u64) -> i8`), the callbacks
+/// codegen generates from a struct, sum type or tuple key type's `Hash` and
+/// `Eq`. Returns the map unchanged if it already uses them; otherwise an
+/// empty map is replaced and a non-empty one rebuilt. Codegen wraps the map
+/// argument of put/get/has_key/delete in this call.
 #[no_mangle]
-pub extern "C" fn mesh_map_put(map: *mut u8, key: u64, value: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_tag_hashed(map: *mut u8, key_hash: *mut u8, key_eq: *mut u8) -> *mut u8 {
     unsafe {
-        let len = map_len(map) as usize;
-        let kt = map_key_type(map);
-
-        // Check if key already exists -- replace.
-        if let Some(idx) = find_key(map, key) {
-            let new_map = alloc_map(len as u64, kt);
-            *(new_map as *mut u64) = len as u64;
-            ptr::copy_nonoverlapping(
-                map_entries(map) as *const u8,
-                map_entries_mut(new_map) as *mut u8,
-                len * ENTRY_SIZE,
-            );
-            (*map_entries_mut(new_map).add(idx))[1] = value;
-            return new_map;
+        let ops = KeyOps::of(map);
+        if ops.key_type == KEY_TYPE_HASHED && ops.hash_fn == key_hash && ops.eq_fn == key_eq {
+            return map;
         }
-
-        // Add new entry.
-        let new_len = len + 1;
-        let new_map = alloc_map(new_len as u64, kt);
-        *(new_map as *mut u64) = new_len as u64;
-        if len > 0 {
-            ptr::copy_nonoverlapping(
-                map_entries(map) as *const u8,
-                map_entries_mut(new_map) as *mut u8,
-                len * ENTRY_SIZE,
-            );
-        }
-        (*map_entries_mut(new_map).add(len))[0] = key;
-        (*map_entries_mut(new_map).add(len))[1] = value;
-        new_map
+        rekey(map, KeyOps { key_type: KEY_TYPE_HASHED, hash_fn: key_hash, eq_fn: key_eq })
     }
 }
 
+/// Return a NEW map with the key-value pair added (or updated).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_map_put(map: *mut u8, key: u64, value: u64) -> *mut u8 {
+    unsafe { insert(map, key, value) }
+}
+
 /// Get the value for a key. Returns 0 if not found.
 #[no_mangle]
-pub extern "C" fn mesh_map_get(map: *mut u8, key: u64) -> u64 {
+pub extern "C-unwind" fn mesh_map_get(map: *mut u8, key: u64) -> u64 {
     unsafe {
-        if let Some(idx) = find_key(map, key) {
-            (*map_entries(map).add(idx))[1]
-        } else {
+        let ops = KeyOps::of(map);
+        let e = find_entry(ops, map_root(map), ops.hash(key), key);
+        if e.is_null() {
             0
+        } else {
+            (*e).value
         }
     }
 }
 
 /// Returns 1 if the key exists, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_map_has_key(map: *mut u8, key: u64) -> i8 {
+pub extern "C-unwind" fn mesh_map_has_key(map: *mut u8, key: u64) -> i8 {
     unsafe {
-        if find_key(map, key).is_some() {
-            1
-        } else {
+        let ops = KeyOps::of(map);
+        if find_entry(ops, map_root(map), ops.hash(key), key).is_null() {
             0
+        } else {
+            1
         }
     }
 }
 
 /// Return a NEW map without the given key.
 #[no_mangle]
-pub extern "C" fn mesh_map_delete(map: *mut u8, key: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_delete(map: *mut u8, key: u64) -> *mut u8 {
     unsafe {
-        let len = map_len(map) as usize;
-        let kt = map_key_type(map);
-        match find_key(map, key) {
-            Some(idx) => {
-                let new_len = len - 1;
-                let new_map = alloc_map(new_len as u64, kt);
-                *(new_map as *mut u64) = new_len as u64;
-                let src = map_entries(map);
-                let dst = map_entries_mut(new_map);
-                let mut j = 0;
-                for i in 0..len {
-                    if i != idx {
-                        (*dst.add(j))[0] = (*src.add(i))[0];
-                        (*dst.add(j))[1] = (*src.add(i))[1];
-                        j += 1;
-                    }
-                }
-                new_map
-            }
-            None => {
-                // Key not found -- return a copy.
-                let new_map = alloc_map(len as u64, kt);
-                *(new_map as *mut u64) = len as u64;
-                if len > 0 {
-                    ptr::copy_nonoverlapping(
-                        map_entries(map) as *const u8,
-                        map_entries_mut(new_map) as *mut u8,
-                        len * ENTRY_SIZE,
-                    );
-                }
-                new_map
-            }
+        let ops = KeyOps::of(map);
+        let root = map_root(map);
+        let len = map_len(map);
+        let next_seq = header(map, MAP_NEXT_SEQ);
+        if root.is_null() {
+            return derive_map(map, root, len, next_seq);
+        }
+        match node_delete(ops, root, 0, ops.hash(key), key) {
+            Removal::Absent => derive_map(map, root, len, next_seq),
+            Removal::Removed(root) => derive_map(map, root, len - 1, next_seq),
         }
     }
 }
 
 /// Return the number of entries in the map.
 #[no_mangle]
-pub extern "C" fn mesh_map_size(map: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_map_size(map: *mut u8) -> i64 {
     unsafe { map_len(map) as i64 }
 }
 
-/// Return a List of all keys in the map.
+/// Return a List of all keys in the map, in insertion order.
 #[no_mangle]
-pub extern "C" fn mesh_map_keys(map: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_keys(map: *mut u8) -> *mut u8 {
     unsafe {
         let len = map_len(map) as usize;
-        let entries = map_entries(map);
-        let mut list = super::list::mesh_list_new();
+        let list = super::list::mesh_list_builder_new(len as i64);
         for i in 0..len {
-            list = super::list::mesh_list_append(list, (*entries.add(i))[0]);
+            super::list::mesh_list_builder_push(list, (*entry_at(map, i)).key);
         }
         list
     }
 }
 
-/// Return a List of all values in the map.
+/// Return a List of all values in the map, in insertion order.
 #[no_mangle]
-pub extern "C" fn mesh_map_values(map: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_values(map: *mut u8) -> *mut u8 {
     unsafe {
         let len = map_len(map) as usize;
-        let entries = map_entries(map);
-        let mut list = super::list::mesh_list_new();
+        let list = super::list::mesh_list_builder_new(len as i64);
         for i in 0..len {
-            list = super::list::mesh_list_append(list, (*entries.add(i))[1]);
+            super::list::mesh_list_builder_push(list, (*entry_at(map, i)).value);
         }
         list
     }
@@ -256,7 +622,7 @@ pub extern "C" fn mesh_map_values(map: *mut u8) -> *mut u8 {
 /// Get the key at index i (insertion order). Panics if out of bounds.
 /// Used by for-in codegen for indexed map iteration.
 #[no_mangle]
-pub extern "C" fn mesh_map_entry_key(map: *mut u8, index: i64) -> u64 {
+pub extern "C-unwind" fn mesh_map_entry_key(map: *mut u8, index: i64) -> u64 {
     unsafe {
         let len = map_len(map);
         if index < 0 || index as u64 >= len {
@@ -265,15 +631,14 @@ pub extern "C" fn mesh_map_entry_key(map: *mut u8, index: i64) -> u64 {
                 index, len
             );
         }
-        let entries = map_entries(map);
-        (*entries.add(index as usize))[0]
+        (*entry_at(map, index as usize)).key
     }
 }
 
 /// Get the value at index i (insertion order). Panics if out of bounds.
 /// Used by for-in codegen for indexed map iteration.
 #[no_mangle]
-pub extern "C" fn mesh_map_entry_value(map: *mut u8, index: i64) -> u64 {
+pub extern "C-unwind" fn mesh_map_entry_value(map: *mut u8, index: i64) -> u64 {
     unsafe {
         let len = map_len(map);
         if index < 0 || index as u64 >= len {
@@ -282,8 +647,7 @@ pub extern "C" fn mesh_map_entry_value(map: *mut u8, index: i64) -> u64 {
                 index, len
             );
         }
-        let entries = map_entries(map);
-        (*entries.add(index as usize))[1]
+        (*entry_at(map, index as usize)).value
     }
 }
 
@@ -292,16 +656,15 @@ pub extern "C" fn mesh_map_entry_value(map: *mut u8, index: i64) -> u64 {
 /// `key_to_str` and `val_to_str` are bare function pointers `fn(u64) -> *mut u8`
 /// that convert keys and values to MeshString pointers respectively.
 #[no_mangle]
-pub extern "C" fn mesh_map_to_string(
+pub extern "C-unwind" fn mesh_map_to_string(
     map: *mut u8,
     key_to_str: *mut u8,
     val_to_str: *mut u8,
 ) -> *mut u8 {
-    type ElemToStr = unsafe extern "C" fn(u64) -> *mut u8;
+    type ElemToStr = unsafe extern "C-unwind" fn(u64) -> *mut u8;
 
     unsafe {
         let len = map_len(map) as usize;
-        let entries = map_entries(map);
         let kf: ElemToStr = std::mem::transmute(key_to_str);
         let vf: ElemToStr = std::mem::transmute(val_to_str);
 
@@ -314,9 +677,8 @@ pub extern "C" fn mesh_map_to_string(
                     sep as *const crate::string::MeshString,
                 ) as *mut u8;
             }
-            let key = (*entries.add(i))[0];
-            let val = (*entries.add(i))[1];
-            let key_str = kf(key);
+            let entry = entry_at(map, i);
+            let key_str = kf((*entry).key);
             result = crate::string::mesh_string_concat(
                 result as *const crate::string::MeshString,
                 key_str as *const crate::string::MeshString,
@@ -326,7 +688,7 @@ pub extern "C" fn mesh_map_to_string(
                 result as *const crate::string::MeshString,
                 arrow as *const crate::string::MeshString,
             ) as *mut u8;
-            let val_str = vf(val);
+            let val_str = vf((*entry).value);
             result = crate::string::mesh_string_concat(
                 result as *const crate::string::MeshString,
                 val_str as *const crate::string::MeshString,
@@ -342,48 +704,33 @@ pub extern "C" fn mesh_map_to_string(
 }
 
 /// Merge two maps. All entries from `a` are included; entries from `b`
-/// overwrite duplicates from `a`. Returns a NEW merged map.
+/// overwrite duplicates from `a`. Returns a NEW merged map that shares
+/// `a`'s trie, using `b`'s key type when `a` is an empty untyped map.
 #[no_mangle]
-pub extern "C" fn mesh_map_merge(a: *mut u8, b: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_merge(a: *mut u8, b: *mut u8) -> *mut u8 {
     unsafe {
-        let a_len = map_len(a) as usize;
-        let b_len = map_len(b) as usize;
-        let kt = map_key_type(a);
-
-        // Start with a copy of `a`.
-        let mut result = alloc_map((a_len + b_len) as u64, kt);
-        *(result as *mut u64) = a_len as u64;
-        if a_len > 0 {
-            ptr::copy_nonoverlapping(
-                map_entries(a) as *const u8,
-                map_entries_mut(result) as *mut u8,
-                a_len * ENTRY_SIZE,
-            );
-        }
-
-        // Add/overwrite entries from `b`.
-        let b_entries = map_entries(b);
-        for i in 0..b_len {
-            let key = (*b_entries.add(i))[0];
-            let val = (*b_entries.add(i))[1];
-            result = mesh_map_put(result, key, val);
+        let mut result = if map_len(a) == 0 && header(a, MAP_KEY_TYPE) == KEY_TYPE_INT {
+            alloc_map(KeyOps::of(b))
+        } else {
+            derive_map(a, map_root(a), map_len(a), header(a, MAP_NEXT_SEQ))
+        };
+        for i in 0..map_len(b) as usize {
+            let e = entry_at(b, i);
+            result = insert(result, (*e).key, (*e).value);
         }
-
         result
     }
 }
 
-/// Convert a map to a list of (key, value) 2-tuples.
+/// Convert a map to a list of (key, value) 2-tuples, in insertion order.
 #[no_mangle]
-pub extern "C" fn mesh_map_to_list(map: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_to_list(map: *mut u8) -> *mut u8 {
     unsafe {
         let len = map_len(map) as usize;
-        let entries = map_entries(map);
         let list = super::list::mesh_list_builder_new(len as i64);
         for i in 0..len {
-            let key = (*entries.add(i))[0];
-            let val = (*entries.add(i))[1];
-            let pair = alloc_pair(key, val);
+            let entry = entry_at(map, i);
+            let pair = alloc_pair((*entry).key, (*entry).value);
             super::list::mesh_list_builder_push(list, pair as u64);
         }
         list
@@ -391,15 +738,15 @@ pub extern "C" fn mesh_map_to_list(map: *mut u8) -> *mut u8 {
 }
 
 /// Build a map from a list of (key, value) 2-tuples.
-/// Defaults to KEY_TYPE_INT since runtime cannot detect key type.
+/// Defaults to KEY_TYPE_INT since runtime cannot detect key type; codegen
+/// retags the result for string and hashed key types.
 #[no_mangle]
-pub extern "C" fn mesh_map_from_list(list: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_from_list(list: *mut u8) -> *mut u8 {
     unsafe {
         let len = super::list::mesh_list_length(list);
         let mut map = mesh_map_new();
-        let data = (list as *const u64).add(2); // skip len + cap header
-        for i in 0..len as usize {
-            let tuple_ptr = *data.add(i) as *mut u8;
+        for i in 0..len {
+            let tuple_ptr = super::list::mesh_list_get(list, i) as *mut u8;
             let key = *((tuple_ptr as *const u64).add(1)); // offset 1 = first tuple element
             let val = *((tuple_ptr as *const u64).add(2)); // offset 2 = second tuple element
             map = mesh_map_put(map, key, val);
@@ -421,7 +768,7 @@ struct MapIterator {
 
 /// Create a new iterator handle for a map.
 #[no_mangle]
-pub extern "C" fn mesh_map_iter_new(map: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_iter_new(map: *mut u8) -> *mut u8 {
     unsafe {
         let size = mesh_map_size(map);
         let iter = crate::gc::mesh_gc_alloc_actor(
@@ -439,7 +786,7 @@ pub extern "C" fn mesh_map_iter_new(map: *mut u8) -> *mut u8 {
 /// Advance the map iterator, returning Option<(K, V)> (tag 0 = Some, tag 1 = None).
 /// The Some payload is a GC-allocated 2-tuple (key, value).
 #[no_mangle]
-pub extern "C" fn mesh_map_iter_next(iter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_iter_next(iter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let iter = iter_ptr as *mut MapIterator;
         if (*iter).index >= (*iter).size {
@@ -619,4 +966,88 @@ mod tests {
         assert_eq!(mesh_map_entry_key(map, 2), 30);
         assert_eq!(mesh_map_entry_value(map, 2), 300);
     }
+
+    #[test]
+    fn test_map_large_put_get_delete() {
+        mesh_rt_init();
+        let mut map = mesh_map_new();
+        for i in 0..5000u64 {
+            map = mesh_map_put(map, i, i * 2);
+        }
+        assert_eq!(mesh_map_size(map), 5000);
+        for i in 0..5000u64 {
+            assert_eq!(mesh_map_get(map, i), i * 2);
+        }
+        let before = map;
+        for i in (0..5000u64).step_by(2) {
+            map = mesh_map_delete(map, i);
+        }
+        assert_eq!(mesh_map_size(map), 2500);
+        assert_eq!(mesh_map_has_key(map, 10), 0);
+        assert_eq!(mesh_map_get(map, 11), 22);
+        // The original map shares structure but is unchanged.
+        assert_eq!(mesh_map_size(before), 5000);
+        assert_eq!(mesh_map_get(before, 10), 20);
+        // Iteration keeps insertion order across deletes.
+        assert_eq!(mesh_map_entry_key(map, 0), 1);
+        assert_eq!(mesh_map_entry_key(map, 2499), 4999);
+    }
+
+    #[test]
+    fn test_map_overwrite_keeps_order() {
+        mesh_rt_init();
+        let map = mesh_map_new();
+        let map = mesh_map_put(map, 1, 10);
+        let map = mesh_map_put(map, 2, 20);
+        let map = mesh_map_put(map, 1, 30);
+        assert_eq!(mesh_map_entry_key(map, 0), 1);
+        assert_eq!(mesh_map_entry_value(map, 0), 30);
+        assert_eq!(mesh_map_entry_key(map, 1), 2);
+    }
+
+    /// Keys are (a, b) pairs boxed as tuples; every hash collides so the
+    /// lookups go through collision nodes and the eq callback.
+    unsafe extern "C-unwind" fn pair_hash(_key: u64) -> i64 {
+        7
+    }
+
+    unsafe extern "C-unwind" fn pair_eq(a: u64, b: u64) -> i8 {
+        let (a, b) = (a as *const u64, b as *const u64);
+        (*a.add(1) == *b.add(1) && *a.add(2) == *b.add(2)) as i8
+    }
+
+    #[test]
+    fn test_map_hashed_keys() {
+        mesh_rt_init();
+        let map = mesh_map_tag_hashed(mesh_map_new(), pair_hash as *mut u8, pair_eq as *mut u8);
+        let mut map = map;
+        for i in 0..20u64 {
+            let key = unsafe { alloc_pair(i, i + 1) } as u64;
+            map = mesh_map_put(map, key, i);
+        }
+        assert_eq!(mesh_map_size(map), 20);
+        let lookup = unsafe { alloc_pair(5, 6) } as u64;
+        assert_eq!(mesh_map_get(map, lookup), 5);
+        let map = mesh_map_put(map, lookup, 99);
+        assert_eq!(mesh_map_size(map), 20);
+        assert_eq!(mesh_map_get(map, lookup), 99);
+        let map = mesh_map_delete(map, lookup);
+        assert_eq!(mesh_map_size(map), 19);
+        assert_eq!(mesh_map_has_key(map, lookup), 0);
+        // Tagging again with the same callbacks is a no-op.
+        assert_eq!(mesh_map_tag_hashed(map, pair_hash as *mut u8, pair_eq as *mut u8), map);
+    }
+
+    #[test]
+    fn test_map_tag_string_rekeys_from_list() {
+        mesh_rt_init();
+        let k1 = crate::string::mesh_string_new(b"a".as_ptr(), 1) as u64;
+        let k2 = crate::string::mesh_string_new(b"a".as_ptr(), 1) as u64;
+        let map = mesh_map_put(mesh_map_put(mesh_map_new(), k1, 1), k2, 2);
+        // Untyped: two distinct pointers are two keys.
+        assert_eq!(mesh_map_size(map), 2);
+        let map = mesh_map_tag_string(map);
+        assert_eq!(mesh_map_size(map), 1);
+        assert_eq!(mesh_map_get(map, k1), 2);
+    }
 }
@@ -45,7 +45,7 @@ unsafe fn normalize(front: *mut u8, back: *mut u8) -> (*mut u8, *mut u8) {
 
 /// Create an empty queue.
 #[no_mangle]
-pub extern "C" fn mesh_queue_new() -> *mut u8 {
+pub extern "C-unwind" fn mesh_queue_new() -> *mut u8 {
     unsafe {
         let front = super::list::mesh_list_new();
         let back = super::list::mesh_list_new();
@@ -55,7 +55,7 @@ pub extern "C" fn mesh_queue_new() -> *mut u8 {
 
 /// Push an element to the back of the queue. Returns a NEW queue.
 #[no_mangle]
-pub extern "C" fn mesh_queue_push(queue: *mut u8, element: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_queue_push(queue: *mut u8, element: u64) -> *mut u8 {
     unsafe {
         let front = queue_front(queue);
         let new_back = super::list::mesh_list_append(queue_back(queue), element);
@@ -64,12 +64,12 @@ pub extern "C" fn mesh_queue_push(queue: *mut u8, element: u64) -> *mut u8 {
     }
 }
 
-/// Pop an element from the front. Returns a tuple-like struct:
-/// `{ u64 element, u64 new_queue_ptr }` (16 bytes, GC-allocated).
+/// Pop an element from the front. Returns the 2-tuple
+/// `(element, new_queue)` in the standard tuple layout.
 ///
 /// Panics if the queue is empty.
 #[no_mangle]
-pub extern "C" fn mesh_queue_pop(queue: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_queue_pop(queue: *mut u8) -> *mut u8 {
     unsafe {
         let front = queue_front(queue);
         let back = queue_back(queue);
@@ -86,17 +86,13 @@ pub extern "C" fn mesh_queue_pop(queue: *mut u8) -> *mut u8 {
         let (nf, nb) = normalize(new_front, back);
         let new_queue = alloc_queue(nf, nb);
 
-        // Return a pair: { element, new_queue_ptr }.
-        let result = mesh_gc_alloc_actor(16, 8);
-        *(result as *mut u64) = element;
-        *((result as *mut u64).add(1)) = new_queue as u64;
-        result
+        super::list::alloc_pair(element, new_queue as u64)
     }
 }
 
 /// Peek at the front element without removing it. Panics if empty.
 #[no_mangle]
-pub extern "C" fn mesh_queue_peek(queue: *mut u8) -> u64 {
+pub extern "C-unwind" fn mesh_queue_peek(queue: *mut u8) -> u64 {
     unsafe {
         let front = queue_front(queue);
         let back = queue_back(queue);
@@ -112,7 +108,7 @@ pub extern "C" fn mesh_queue_peek(queue: *mut u8) -> u64 {
 
 /// Return the total number of elements in the queue.
 #[no_mangle]
-pub extern "C" fn mesh_queue_size(queue: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_queue_size(queue: *mut u8) -> i64 {
     unsafe {
         let front_len = super::list::mesh_list_length(queue_front(queue));
         let back_len = super::list::mesh_list_length(queue_back(queue));
@@ -122,7 +118,7 @@ pub extern "C" fn mesh_queue_size(queue: *mut u8) -> i64 {
 
 /// Returns 1 if the queue is empty, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_queue_is_empty(queue: *mut u8) -> i8 {
+pub extern "C-unwind" fn mesh_queue_is_empty(queue: *mut u8) -> i8 {
     if mesh_queue_size(queue) == 0 {
         1
     } else {
@@ -130,6 +126,36 @@ pub extern "C" fn mesh_queue_is_empty(queue: *mut u8) -> i8 {
     }
 }
 
+/// Return the elements from front to back as a list.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_queue_to_list(queue: *mut u8) -> *mut u8 {
+    unsafe { super::list::mesh_list_concat(queue_front(queue), queue_back(queue)) }
+}
+
+/// Build a queue whose front-to-back order is the list's order.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_queue_from_list(list: *mut u8) -> *mut u8 {
+    unsafe { alloc_queue(list, super::list::mesh_list_new()) }
+}
+
+/// Returns 1 if both queues hold equal elements in the same order, 0 otherwise.
+///
+/// `elem_eq` is a bare function pointer `fn(u64, u64) -> i8`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_queue_eq(a: *mut u8, b: *mut u8, elem_eq: *mut u8) -> i8 {
+    super::list::mesh_list_eq(mesh_queue_to_list(a), mesh_queue_to_list(b), elem_eq)
+}
+
+/// Convert a queue to a human-readable MeshString: `Queue[front, ..., back]`.
+///
+/// `elem_to_str` is a bare function pointer `fn(u64) -> *mut u8`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_queue_to_string(queue: *mut u8, elem_to_str: *mut u8) -> *mut u8 {
+    let items = super::list::mesh_list_to_string(mesh_queue_to_list(queue), elem_to_str);
+    let prefix = crate::string::mesh_string_new(b"Queue".as_ptr(), 5);
+    crate::string::mesh_string_concat(prefix, items as *const crate::string::MeshString) as *mut u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,16 +178,17 @@ mod tests {
         let q = mesh_queue_push(q, 30);
         assert_eq!(mesh_queue_size(q), 3);
 
-        // Pop should return elements in FIFO order.
+        // Pop should return (element, new_queue) in FIFO order.
         let result = mesh_queue_pop(q);
         unsafe {
-            let elem = *(result as *const u64);
-            let new_q = *((result as *const u64).add(1)) as *mut u8;
+            assert_eq!(*(result as *const u64), 2); // tuple length
+            let elem = *((result as *const u64).add(1));
+            let new_q = *((result as *const u64).add(2)) as *mut u8;
             assert_eq!(elem, 10);
             assert_eq!(mesh_queue_size(new_q), 2);
 
             let result2 = mesh_queue_pop(new_q);
-            let elem2 = *(result2 as *const u64);
+            let elem2 = *((result2 as *const u64).add(1));
             assert_eq!(elem2, 20);
         }
     }
@@ -193,8 +220,43 @@ mod tests {
         let q = mesh_queue_push(q, 1);
         let result = mesh_queue_pop(q);
         unsafe {
-            let new_q = *((result as *const u64).add(1)) as *mut u8;
+            let new_q = *((result as *const u64).add(2)) as *mut u8;
             assert_eq!(mesh_queue_is_empty(new_q), 1);
         }
     }
+
+    #[test]
+    fn test_queue_to_list_front_to_back() {
+        mesh_rt_init();
+        let q = mesh_queue_push(mesh_queue_push(mesh_queue_new(), 1), 2);
+        let q = mesh_queue_push(q, 3);
+        let popped = mesh_queue_pop(q);
+        let q = unsafe { *((popped as *const u64).add(2)) as *mut u8 };
+        let q = mesh_queue_push(q, 4);
+        let list = mesh_queue_to_list(q);
+        assert_eq!(crate::collections::list::mesh_list_length(list), 3);
+        assert_eq!(crate::collections::list::mesh_list_head(list), 2);
+
+        let rebuilt = mesh_queue_from_list(list);
+        assert_eq!(mesh_queue_peek(rebuilt), 2);
+    }
+
+    #[test]
+    fn test_queue_to_string_and_eq() {
+        mesh_rt_init();
+        let a = mesh_queue_push(mesh_queue_push(mesh_queue_new(), 1), 2);
+        let b = mesh_queue_from_list(mesh_queue_to_list(a));
+        let c = mesh_queue_push(mesh_queue_new(), 2);
+
+        let result = mesh_queue_to_string(a, crate::string::mesh_int_to_string as *mut u8);
+        let text = unsafe { (*(result as *const crate::string::MeshString)).as_str() };
+        assert_eq!(text, "Queue[1, 2]");
+
+        extern "C-unwind" fn int_eq(x: u64, y: u64) -> i8 {
+            (x == y) as i8
+        }
+        let eq = int_eq as extern "C-unwind" fn(u64, u64) -> i8 as *mut u8;
+        assert_eq!(mesh_queue_eq(a, b, eq), 1);
+        assert_eq!(mesh_queue_eq(a, c, eq), 0);
+    }
 }
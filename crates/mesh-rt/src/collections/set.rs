@@ -1,203 +1,110 @@
-//! GC-managed immutable Set for the Mesh runtime.
+//! GC-managed persistent Set for the Mesh runtime.
 //!
-//! A MeshSet stores unique elements as uniform 8-byte (`u64`) values.
-//! Backed by a simple sorted vector with linear scan -- efficient for
-//! the small sets typical in Phase 8.
+//! A MeshSet stores unique elements as uniform 8-byte (`u64`) values. It is
+//! a MeshMap (see `map.rs`) whose keys are the elements and whose values
+//! are unused, so it shares the map's hash array mapped trie: add, remove
+//! and contains are O(log32 n), and derived sets share structure with the
+//! set they came from. Iteration follows insertion order.
+//!
+//! Elements are hashed and compared by the map's key ops. Codegen retags
+//! the set argument of every element operation for the element type, as it
+//! does for map keys: `mesh_map_tag_string` for `Set<String>` and
+//! `mesh_map_tag_hashed` with the type's `Hash` and `Eq` callbacks for
+//! struct, sum type and tuple elements. Int-like elements compare by value.
+//! A set built by the runtime alone (e.g. decoded from the wire) is
+//! therefore rebuilt with the right ops on its first element operation.
 //!
 //! All mutation operations return a NEW set (immutable semantics).
 
+use super::map::{
+    empty_like, mesh_map_delete, mesh_map_entry_key, mesh_map_has_key, mesh_map_keys, mesh_map_new,
+    mesh_map_put, mesh_map_size,
+};
 use crate::gc::mesh_gc_alloc_actor;
-use std::ptr;
-
-/// Header: len (u64), cap (u64).
-const HEADER_SIZE: usize = 16;
-const ELEM_SIZE: usize = 8;
-
-// ── Internal helpers ──────────────────────────────────────────────────
-
-unsafe fn set_len(s: *const u8) -> u64 {
-    *(s as *const u64)
-}
-
-unsafe fn set_data(s: *const u8) -> *const u64 {
-    (s as *const u64).add(2)
-}
-
-unsafe fn set_data_mut(s: *mut u8) -> *mut u64 {
-    (s as *mut u64).add(2)
-}
-
-unsafe fn alloc_set(cap: u64) -> *mut u8 {
-    let total = HEADER_SIZE + (cap as usize) * ELEM_SIZE;
-    let p = mesh_gc_alloc_actor(total as u64, 8);
-    *(p as *mut u64) = 0;
-    *((p as *mut u64).add(1)) = cap;
-    p
-}
-
-unsafe fn contains_elem(s: *const u8, elem: u64) -> bool {
-    let len = set_len(s) as usize;
-    let data = set_data(s);
-    for i in 0..len {
-        if *data.add(i) == elem {
-            return true;
-        }
-    }
-    false
-}
 
 // ── Public API ────────────────────────────────────────────────────────
 
-/// Create an empty set.
+/// Create an empty set whose elements are compared by raw value.
 #[no_mangle]
-pub extern "C" fn mesh_set_new() -> *mut u8 {
-    unsafe { alloc_set(0) }
+pub extern "C-unwind" fn mesh_set_new() -> *mut u8 {
+    mesh_map_new()
 }
 
 /// Return a NEW set with the element added (no-op if already present).
 #[no_mangle]
-pub extern "C" fn mesh_set_add(set: *mut u8, element: u64) -> *mut u8 {
-    unsafe {
-        if contains_elem(set, element) {
-            // Already present -- return a copy.
-            let len = set_len(set);
-            let new_set = alloc_set(len);
-            *(new_set as *mut u64) = len;
-            if len > 0 {
-                ptr::copy_nonoverlapping(set_data(set), set_data_mut(new_set), len as usize);
-            }
-            return new_set;
-        }
-
-        let len = set_len(set) as usize;
-        let new_len = len + 1;
-        let new_set = alloc_set(new_len as u64);
-        *(new_set as *mut u64) = new_len as u64;
-        if len > 0 {
-            ptr::copy_nonoverlapping(set_data(set), set_data_mut(new_set), len);
-        }
-        *set_data_mut(new_set).add(len) = element;
-        new_set
+pub extern "C-unwind" fn mesh_set_add(set: *mut u8, element: u64) -> *mut u8 {
+    if mesh_map_has_key(set, element) != 0 {
+        return set;
     }
+    mesh_map_put(set, element, 0)
 }
 
 /// Return a NEW set without the element.
 #[no_mangle]
-pub extern "C" fn mesh_set_remove(set: *mut u8, element: u64) -> *mut u8 {
-    unsafe {
-        let len = set_len(set) as usize;
-        let data = set_data(set);
-        let new_set = alloc_set(len as u64);
-        let dst = set_data_mut(new_set);
-        let mut j = 0;
-        for i in 0..len {
-            if *data.add(i) != element {
-                *dst.add(j) = *data.add(i);
-                j += 1;
-            }
-        }
-        *(new_set as *mut u64) = j as u64;
-        new_set
-    }
+pub extern "C-unwind" fn mesh_set_remove(set: *mut u8, element: u64) -> *mut u8 {
+    mesh_map_delete(set, element)
 }
 
 /// Returns 1 if the element is in the set, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_set_contains(set: *mut u8, element: u64) -> i8 {
-    unsafe {
-        if contains_elem(set, element) {
-            1
-        } else {
-            0
-        }
-    }
+pub extern "C-unwind" fn mesh_set_contains(set: *mut u8, element: u64) -> i8 {
+    mesh_map_has_key(set, element)
 }
 
 /// Return the number of elements in the set.
 #[no_mangle]
-pub extern "C" fn mesh_set_size(set: *mut u8) -> i64 {
-    unsafe { set_len(set) as i64 }
+pub extern "C-unwind" fn mesh_set_size(set: *mut u8) -> i64 {
+    mesh_map_size(set)
 }
 
-/// Return a NEW set that is the union of `a` and `b`.
+/// Return a NEW set that is the union of `a` and `b`, using `a`'s ops.
 #[no_mangle]
-pub extern "C" fn mesh_set_union(a: *mut u8, b: *mut u8) -> *mut u8 {
-    unsafe {
-        // Start with a copy of `a`, then add elements from `b`.
-        let a_len = set_len(a) as usize;
-        let b_len = set_len(b) as usize;
-        let max_len = a_len + b_len;
-        let result = alloc_set(max_len as u64);
-        let dst = set_data_mut(result);
-
-        // Copy all of `a`.
-        let a_data = set_data(a);
-        if a_len > 0 {
-            ptr::copy_nonoverlapping(a_data, dst, a_len);
-        }
-        let mut count = a_len;
-
-        // Add elements from `b` that are not in `a`.
-        let b_data = set_data(b);
-        for i in 0..b_len {
-            let elem = *b_data.add(i);
-            // Linear search in result so far.
-            let mut found = false;
-            for j in 0..count {
-                if *dst.add(j) == elem {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                *dst.add(count) = elem;
-                count += 1;
-            }
-        }
+pub extern "C-unwind" fn mesh_set_union(a: *mut u8, b: *mut u8) -> *mut u8 {
+    let mut result = a;
+    for i in 0..mesh_set_size(b) {
+        result = mesh_set_add(result, mesh_set_element_at(b, i));
+    }
+    result
+}
 
-        *(result as *mut u64) = count as u64;
-        result
+/// The elements of `a` that are (`keep` = 1) or are not (`keep` = 0) in `b`.
+fn filter_by_membership(a: *mut u8, b: *mut u8, keep: i8) -> *mut u8 {
+    let mut result = unsafe { empty_like(a) };
+    for i in 0..mesh_set_size(a) {
+        let elem = mesh_set_element_at(a, i);
+        if mesh_set_contains(b, elem) == keep {
+            result = mesh_map_put(result, elem, 0);
+        }
     }
+    result
 }
 
 /// Return a NEW set that is the intersection of `a` and `b`.
 #[no_mangle]
-pub extern "C" fn mesh_set_intersection(a: *mut u8, b: *mut u8) -> *mut u8 {
-    unsafe {
-        let a_len = set_len(a) as usize;
-        let result = alloc_set(a_len as u64);
-        let a_data = set_data(a);
-        let dst = set_data_mut(result);
-        let mut count = 0;
-
-        for i in 0..a_len {
-            let elem = *a_data.add(i);
-            if contains_elem(b, elem) {
-                *dst.add(count) = elem;
-                count += 1;
-            }
-        }
+pub extern "C-unwind" fn mesh_set_intersection(a: *mut u8, b: *mut u8) -> *mut u8 {
+    filter_by_membership(a, b, 1)
+}
 
-        *(result as *mut u64) = count as u64;
-        result
+/// Returns 1 if both sets hold the same elements (in any order), 0 otherwise.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_set_eq(a: *mut u8, b: *mut u8) -> i8 {
+    let len = mesh_set_size(a);
+    if len != mesh_set_size(b) {
+        return 0;
+    }
+    for i in 0..len {
+        if mesh_set_contains(b, mesh_set_element_at(a, i)) == 0 {
+            return 0;
+        }
     }
+    1
 }
 
-/// Get the element at index i. Panics if out of bounds.
+/// Get the element at index i (insertion order). Panics if out of bounds.
 /// Used by for-in codegen for indexed set iteration.
 #[no_mangle]
-pub extern "C" fn mesh_set_element_at(set: *mut u8, index: i64) -> u64 {
-    unsafe {
-        let len = set_len(set);
-        if index < 0 || index as u64 >= len {
-            panic!(
-                "mesh_set_element_at: index {} out of bounds (len {})",
-                index, len
-            );
-        }
-        let data = set_data(set);
-        *data.add(index as usize)
-    }
+pub extern "C-unwind" fn mesh_set_element_at(set: *mut u8, index: i64) -> u64 {
+    mesh_map_entry_key(set, index)
 }
 
 /// Convert a set to a human-readable MeshString: `#{elem1, elem2, ...}`.
@@ -205,15 +112,14 @@ pub extern "C" fn mesh_set_element_at(set: *mut u8, index: i64) -> u64 {
 /// `elem_to_str` is a bare function pointer `fn(u64) -> *mut u8` that converts
 /// each element to a MeshString pointer.
 #[no_mangle]
-pub extern "C" fn mesh_set_to_string(
+pub extern "C-unwind" fn mesh_set_to_string(
     set: *mut u8,
     elem_to_str: *mut u8,
 ) -> *mut u8 {
-    type ElemToStr = unsafe extern "C" fn(u64) -> *mut u8;
+    type ElemToStr = unsafe extern "C-unwind" fn(u64) -> *mut u8;
 
     unsafe {
-        let len = set_len(set) as usize;
-        let data = set_data(set);
+        let len = mesh_set_size(set);
         let f: ElemToStr = std::mem::transmute(elem_to_str);
 
         let mut result = crate::string::mesh_string_new(b"#{".as_ptr(), 2) as *mut u8;
@@ -225,7 +131,7 @@ pub extern "C" fn mesh_set_to_string(
                     sep as *const crate::string::MeshString,
                 ) as *mut u8;
             }
-            let elem_str = f(*data.add(i));
+            let elem_str = f(mesh_set_element_at(set, i));
             result = crate::string::mesh_string_concat(
                 result as *const crate::string::MeshString,
                 elem_str as *const crate::string::MeshString,
@@ -242,53 +148,26 @@ pub extern "C" fn mesh_set_to_string(
 
 /// Return a NEW set containing elements in `a` that are NOT in `b`.
 #[no_mangle]
-pub extern "C" fn mesh_set_difference(a: *mut u8, b: *mut u8) -> *mut u8 {
-    unsafe {
-        let a_len = set_len(a) as usize;
-        let a_data = set_data(a);
-        let result = alloc_set(a_len as u64);
-        let dst = set_data_mut(result);
-        let mut count = 0;
-
-        for i in 0..a_len {
-            let elem = *a_data.add(i);
-            if !contains_elem(b, elem) {
-                *dst.add(count) = elem;
-                count += 1;
-            }
-        }
-
-        *(result as *mut u64) = count as u64;
-        result
-    }
+pub extern "C-unwind" fn mesh_set_difference(a: *mut u8, b: *mut u8) -> *mut u8 {
+    filter_by_membership(a, b, 0)
 }
 
-/// Convert a set to a list of its elements.
+/// Convert a set to a list of its elements, in insertion order.
 #[no_mangle]
-pub extern "C" fn mesh_set_to_list(set: *mut u8) -> *mut u8 {
-    unsafe {
-        let len = set_len(set) as usize;
-        let src = set_data(set);
-        let list = super::list::mesh_list_builder_new(len as i64);
-        for i in 0..len {
-            super::list::mesh_list_builder_push(list, *src.add(i));
-        }
-        list
-    }
+pub extern "C-unwind" fn mesh_set_to_list(set: *mut u8) -> *mut u8 {
+    mesh_map_keys(set)
 }
 
-/// Build a set from a list. Duplicates are removed via mesh_set_add.
+/// Build a set from a list. Elements are compared by raw value; codegen
+/// retags the result for string and hashed element types, which merges
+/// equal elements.
 #[no_mangle]
-pub extern "C" fn mesh_set_from_list(list: *mut u8) -> *mut u8 {
-    unsafe {
-        let len = super::list::mesh_list_length(list);
-        let data = (list as *const u64).add(2); // skip len + cap header
-        let mut set = mesh_set_new();
-        for i in 0..len as usize {
-            set = mesh_set_add(set, *data.add(i));
-        }
-        set
+pub extern "C-unwind" fn mesh_set_from_list(list: *mut u8) -> *mut u8 {
+    let mut set = mesh_set_new();
+    for elem in unsafe { super::list::elems(list) } {
+        set = mesh_set_add(set, elem);
     }
+    set
 }
 
 // ── Iterator handle ───────────────────────────────────────────────────
@@ -304,7 +183,7 @@ struct SetIterator {
 
 /// Create a new iterator handle for a set.
 #[no_mangle]
-pub extern "C" fn mesh_set_iter_new(set: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_set_iter_new(set: *mut u8) -> *mut u8 {
     unsafe {
         let size = mesh_set_size(set);
         let iter = mesh_gc_alloc_actor(
@@ -321,7 +200,7 @@ pub extern "C" fn mesh_set_iter_new(set: *mut u8) -> *mut u8 {
 
 /// Advance the set iterator, returning Option (tag 0 = Some, tag 1 = None).
 #[no_mangle]
-pub extern "C" fn mesh_set_iter_next(iter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_set_iter_next(iter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let iter = iter_ptr as *mut SetIterator;
         if (*iter).index >= (*iter).size {
@@ -337,6 +216,7 @@ pub extern "C" fn mesh_set_iter_next(iter_ptr: *mut u8) -> *mut u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::collections::map::{mesh_map_tag_hashed, mesh_map_tag_string};
     use crate::gc::mesh_rt_init;
 
     #[test]
@@ -467,4 +347,71 @@ mod tests {
         assert_eq!(mesh_set_element_at(set, 1), 20);
         assert_eq!(mesh_set_element_at(set, 2), 30);
     }
+
+    fn mesh_str(text: &str) -> u64 {
+        crate::string::mesh_string_new(text.as_ptr(), text.len() as u64) as u64
+    }
+
+    #[test]
+    fn test_set_tagged_compares_strings_by_contents() {
+        mesh_rt_init();
+        let set = mesh_map_tag_string(mesh_set_new());
+        let set = mesh_set_add(set, mesh_str("a"));
+        let set = mesh_set_add(set, mesh_str("a"));
+        let set = mesh_set_add(set, mesh_str("b"));
+        assert_eq!(mesh_set_size(set), 2);
+        assert_eq!(mesh_set_contains(set, mesh_str("b")), 1);
+
+        // Derived sets keep the tag.
+        let other = mesh_map_tag_string(mesh_set_add(mesh_set_new(), mesh_str("b")));
+        assert_eq!(mesh_set_size(mesh_set_union(other, set)), 2);
+        assert_eq!(mesh_set_size(mesh_set_intersection(set, other)), 1);
+        let set = mesh_set_remove(set, mesh_str("a"));
+        assert_eq!(mesh_set_size(set), 1);
+        assert_eq!(mesh_set_contains(set, mesh_str("a")), 0);
+    }
+
+    /// A boxed Int standing in for a struct element.
+    fn boxed(value: i64) -> u64 {
+        let p = mesh_gc_alloc_actor(8, 8) as *mut i64;
+        unsafe { *p = value };
+        p as u64
+    }
+
+    extern "C-unwind" fn boxed_hash(a: u64) -> i64 {
+        unsafe { *(a as *const i64) }
+    }
+
+    extern "C-unwind" fn boxed_eq(a: u64, b: u64) -> i8 {
+        unsafe { (*(a as *const i64) == *(b as *const i64)) as i8 }
+    }
+
+    #[test]
+    fn test_set_retagged_for_hashed_elements() {
+        mesh_rt_init();
+        let (hash, eq) = (boxed_hash as *mut u8, boxed_eq as *mut u8);
+        // Built without callbacks, as a decoded set is: equal boxes differ.
+        let raw = mesh_set_add(mesh_set_add(mesh_set_new(), boxed(1)), boxed(2));
+        assert_eq!(mesh_set_contains(raw, boxed(1)), 0);
+
+        // Retagged, as codegen does before every element operation.
+        let set = mesh_map_tag_hashed(raw, hash, eq);
+        assert_eq!(mesh_set_contains(set, boxed(1)), 1);
+        let set = mesh_map_tag_hashed(mesh_set_add(set, boxed(2)), hash, eq);
+        assert_eq!(mesh_set_size(set), 2);
+        let many = (0..1000).fold(set, |s, i| mesh_set_add(s, boxed(i)));
+        assert_eq!(mesh_set_size(many), 1000);
+        assert_eq!(mesh_set_contains(many, boxed(999)), 1);
+        assert_eq!(mesh_set_size(mesh_set_remove(many, boxed(500))), 999);
+    }
+
+    #[test]
+    fn test_set_eq_ignores_order() {
+        mesh_rt_init();
+        let a = mesh_set_add(mesh_set_add(mesh_set_new(), 1), 2);
+        let b = mesh_set_add(mesh_set_add(mesh_set_new(), 2), 1);
+        let c = mesh_set_add(mesh_set_new(), 1);
+        assert_eq!(mesh_set_eq(a, b), 1);
+        assert_eq!(mesh_set_eq(a, c), 0);
+    }
 }
@@ -7,7 +7,7 @@
 
 /// Return the element at `index` in the tuple. Panics if out of bounds.
 #[no_mangle]
-pub extern "C" fn mesh_tuple_nth(tuple: *mut u8, index: i64) -> u64 {
+pub extern "C-unwind" fn mesh_tuple_nth(tuple: *mut u8, index: i64) -> u64 {
     unsafe {
         let len = *(tuple as *const u64);
         if index < 0 || index as u64 >= len {
@@ -23,22 +23,34 @@ pub extern "C" fn mesh_tuple_nth(tuple: *mut u8, index: i64) -> u64 {
 
 /// Return the first element of the tuple. Panics if empty.
 #[no_mangle]
-pub extern "C" fn mesh_tuple_first(tuple: *mut u8) -> u64 {
+pub extern "C-unwind" fn mesh_tuple_first(tuple: *mut u8) -> u64 {
     mesh_tuple_nth(tuple, 0)
 }
 
 /// Return the second element of the tuple. Panics if fewer than 2 elements.
 #[no_mangle]
-pub extern "C" fn mesh_tuple_second(tuple: *mut u8) -> u64 {
+pub extern "C-unwind" fn mesh_tuple_second(tuple: *mut u8) -> u64 {
     mesh_tuple_nth(tuple, 1)
 }
 
 /// Return the number of elements in the tuple.
 #[no_mangle]
-pub extern "C" fn mesh_tuple_size(tuple: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_tuple_size(tuple: *mut u8) -> i64 {
     unsafe { *(tuple as *const u64) as i64 }
 }
 
+/// Copy the tuple's elements, in order, into a new list.
+///
+/// The type checker only allows this for tuples whose elements share a type.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_tuple_to_list(tuple: *mut u8) -> *mut u8 {
+    unsafe {
+        let len = *(tuple as *const u64);
+        let data = (tuple as *const u64).add(1);
+        super::list::mesh_list_from_array(data, len as i64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,6 +94,15 @@ mod tests {
         assert_eq!(mesh_tuple_size(t), 4);
     }
 
+    #[test]
+    fn test_tuple_to_list() {
+        mesh_rt_init();
+        let t = make_tuple(&[7, 8, 9]);
+        let list = mesh_tuple_to_list(t);
+        assert_eq!(crate::collections::list::mesh_list_length(list), 3);
+        assert_eq!(crate::collections::list::mesh_list_get(list, 2), 9);
+    }
+
     #[test]
     fn test_tuple_empty() {
         mesh_rt_init();
@@ -130,7 +130,7 @@ fn coerce_value(val: &str, sql_type: &str) -> Result<String, ()> {
 /// Filters `params` to only include keys present in `allowed` list.
 /// Creates a new changeset with the filtered params as `changes`.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_cast(
+pub extern "C-unwind" fn mesh_changeset_cast(
     data: *mut u8,
     params: *mut u8,
     allowed: *mut u8,
@@ -161,7 +161,7 @@ pub extern "C" fn mesh_changeset_cast(
 /// Same as cast but additionally coerces string values based on SQL type metadata.
 /// field_types is a List<String> of "field_name:SQL_TYPE" entries.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_cast_with_types(
+pub extern "C-unwind" fn mesh_changeset_cast_with_types(
     data: *mut u8,
     params: *mut u8,
     allowed: *mut u8,
@@ -230,7 +230,7 @@ pub extern "C" fn mesh_changeset_cast_with_types(
 /// Checks that each field in fields_list exists and is non-empty in
 /// either `changes` or `data`. Adds "can't be blank" error for missing fields.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_validate_required(
+pub extern "C-unwind" fn mesh_changeset_validate_required(
     cs: *mut u8,
     fields: *mut u8,
 ) -> *mut u8 {
@@ -278,7 +278,7 @@ pub extern "C" fn mesh_changeset_validate_required(
 /// Checks that the string length of the field value is within [min, max].
 /// Use -1 for "not set" (no bound). Only validates fields present in changes.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_validate_length(
+pub extern "C-unwind" fn mesh_changeset_validate_length(
     cs: *mut u8,
     field: *mut u8,
     min: i64,
@@ -324,7 +324,7 @@ pub extern "C" fn mesh_changeset_validate_length(
 /// Checks that the field value contains the pattern substring.
 /// Adds "has invalid format" error if pattern is not found.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_validate_format(
+pub extern "C-unwind" fn mesh_changeset_validate_format(
     cs: *mut u8,
     field: *mut u8,
     pattern: *mut u8,
@@ -364,7 +364,7 @@ pub extern "C" fn mesh_changeset_validate_format(
 /// Checks that the field value is one of the allowed values.
 /// Adds "is invalid" error if not found in the list.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_validate_inclusion(
+pub extern "C-unwind" fn mesh_changeset_validate_inclusion(
     cs: *mut u8,
     field: *mut u8,
     allowed_values: *mut u8,
@@ -406,7 +406,7 @@ pub extern "C" fn mesh_changeset_validate_inclusion(
 /// Checks that the field value (parsed as i64) is within the specified bounds.
 /// Use -1 for "not set" (no bound). Adds appropriate error messages.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_validate_number(
+pub extern "C-unwind" fn mesh_changeset_validate_number(
     cs: *mut u8,
     field: *mut u8,
     gt: i64,
@@ -468,7 +468,7 @@ pub extern "C" fn mesh_changeset_validate_number(
 /// Returns 1 (true) if changeset has no errors, 0 (false) otherwise.
 /// Return type is i64 cast to *mut u8, matching the Bool convention.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_valid(cs: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_changeset_valid(cs: *mut u8) -> *mut u8 {
     unsafe { cs_get_int(cs, SLOT_VALID) as *mut u8 }
 }
 
@@ -476,7 +476,7 @@ pub extern "C" fn mesh_changeset_valid(cs: *mut u8) -> *mut u8 {
 ///
 /// Returns the errors map.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_errors(cs: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_changeset_errors(cs: *mut u8) -> *mut u8 {
     unsafe { cs_get(cs, SLOT_ERRORS) }
 }
 
@@ -484,7 +484,7 @@ pub extern "C" fn mesh_changeset_errors(cs: *mut u8) -> *mut u8 {
 ///
 /// Returns the changes map.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_changes(cs: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_changeset_changes(cs: *mut u8) -> *mut u8 {
     unsafe { cs_get(cs, SLOT_CHANGES) }
 }
 
@@ -492,7 +492,7 @@ pub extern "C" fn mesh_changeset_changes(cs: *mut u8) -> *mut u8 {
 ///
 /// Returns the value of a field from the changes map, or empty string if not found.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_get_change(cs: *mut u8, field: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_changeset_get_change(cs: *mut u8, field: *mut u8) -> *mut u8 {
     unsafe {
         let changes = cs_get(cs, SLOT_CHANGES);
         let field_str = mesh_str_ref(field);
@@ -511,7 +511,7 @@ pub extern "C" fn mesh_changeset_get_change(cs: *mut u8, field: *mut u8) -> *mut
 ///
 /// Returns the error message for a field, or empty string if no error.
 #[no_mangle]
-pub extern "C" fn mesh_changeset_get_error(cs: *mut u8, field: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_changeset_get_error(cs: *mut u8, field: *mut u8) -> *mut u8 {
     unsafe {
         let errors = cs_get(cs, SLOT_ERRORS);
         let field_str = mesh_str_ref(field);
@@ -61,7 +61,7 @@ fn value_to_string(val: Option<&serde_json::Value>) -> String {
 ///
 /// Returns empty string on invalid JSON or missing key (matches COALESCE behavior).
 #[no_mangle]
-pub extern "C" fn mesh_json_get(json_ptr: *mut u8, key_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_get(json_ptr: *mut u8, key_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let json_str = (*(json_ptr as *const MeshString)).as_str();
         let key = (*(key_ptr as *const MeshString)).as_str();
@@ -77,7 +77,7 @@ pub extern "C" fn mesh_json_get(json_ptr: *mut u8, key_ptr: *mut u8) -> *mut u8
 ///
 /// Returns empty string on invalid JSON or missing path (matches COALESCE behavior).
 #[no_mangle]
-pub extern "C" fn mesh_json_get_nested(json_ptr: *mut u8, path1_ptr: *mut u8, path2_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_get_nested(json_ptr: *mut u8, path1_ptr: *mut u8, path2_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let json_str = (*(json_ptr as *const MeshString)).as_str();
         let path1 = (*(path1_ptr as *const MeshString)).as_str();
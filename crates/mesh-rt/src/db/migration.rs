@@ -186,7 +186,7 @@ pub(crate) fn build_drop_index_sql(table: &str, columns: &[String]) -> String {
 ///
 /// Returns: Result<Int, String> (from Pool.execute)
 #[no_mangle]
-pub extern "C" fn mesh_migration_create_table(
+pub extern "C-unwind" fn mesh_migration_create_table(
     pool: u64,
     table: *const MeshString,
     columns: *mut u8,
@@ -207,7 +207,7 @@ pub extern "C" fn mesh_migration_create_table(
 ///
 /// `mesh_migration_drop_table(pool: u64, table: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_drop_table(
+pub extern "C-unwind" fn mesh_migration_drop_table(
     pool: u64,
     table: *const MeshString,
 ) -> *mut u8 {
@@ -226,7 +226,7 @@ pub extern "C" fn mesh_migration_drop_table(
 ///
 /// `mesh_migration_add_column(pool: u64, table: ptr, column_def: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_add_column(
+pub extern "C-unwind" fn mesh_migration_add_column(
     pool: u64,
     table: *const MeshString,
     column_def: *const MeshString,
@@ -247,7 +247,7 @@ pub extern "C" fn mesh_migration_add_column(
 ///
 /// `mesh_migration_drop_column(pool: u64, table: ptr, column: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_drop_column(
+pub extern "C-unwind" fn mesh_migration_drop_column(
     pool: u64,
     table: *const MeshString,
     column: *const MeshString,
@@ -268,7 +268,7 @@ pub extern "C" fn mesh_migration_drop_column(
 ///
 /// `mesh_migration_rename_column(pool: u64, table: ptr, old_name: ptr, new_name: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_rename_column(
+pub extern "C-unwind" fn mesh_migration_rename_column(
     pool: u64,
     table: *const MeshString,
     old_name: *const MeshString,
@@ -293,7 +293,7 @@ pub extern "C" fn mesh_migration_rename_column(
 ///
 /// Options: `"unique:true"` for unique index, `"where:condition"` for partial.
 #[no_mangle]
-pub extern "C" fn mesh_migration_create_index(
+pub extern "C-unwind" fn mesh_migration_create_index(
     pool: u64,
     table: *const MeshString,
     columns: *mut u8,
@@ -316,7 +316,7 @@ pub extern "C" fn mesh_migration_create_index(
 ///
 /// `mesh_migration_drop_index(pool: u64, table: ptr, columns: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_drop_index(
+pub extern "C-unwind" fn mesh_migration_drop_index(
     pool: u64,
     table: *const MeshString,
     columns: *mut u8,
@@ -337,7 +337,7 @@ pub extern "C" fn mesh_migration_drop_index(
 ///
 /// `mesh_migration_execute(pool: u64, sql: ptr) -> ptr`
 #[no_mangle]
-pub extern "C" fn mesh_migration_execute(
+pub extern "C-unwind" fn mesh_migration_execute(
     pool: u64,
     sql: *const MeshString,
 ) -> *mut u8 {
@@ -295,7 +295,7 @@ fn build_delete_sql(table: &str, wheres: &[String], returning: &[String]) -> Str
 /// - `limit`: -1 means no limit, otherwise LIMIT N
 /// - `offset`: -1 means no offset, otherwise OFFSET N
 #[no_mangle]
-pub extern "C" fn mesh_orm_build_select(
+pub extern "C-unwind" fn mesh_orm_build_select(
     table: *const MeshString,
     columns: *mut u8,
     where_clauses: *mut u8,
@@ -322,7 +322,7 @@ pub extern "C" fn mesh_orm_build_select(
 /// - `columns`: List<String> of column names for the VALUES clause
 /// - `returning`: List<String> for RETURNING clause (empty = no RETURNING)
 #[no_mangle]
-pub extern "C" fn mesh_orm_build_insert(
+pub extern "C-unwind" fn mesh_orm_build_insert(
     table: *const MeshString,
     columns: *mut u8,
     returning: *mut u8,
@@ -347,7 +347,7 @@ pub extern "C" fn mesh_orm_build_insert(
 /// - `where_clauses`: List<String> of "column op" entries (params continue after SET)
 /// - `returning`: List<String> for RETURNING clause
 #[no_mangle]
-pub extern "C" fn mesh_orm_build_update(
+pub extern "C-unwind" fn mesh_orm_build_update(
     table: *const MeshString,
     set_columns: *mut u8,
     where_clauses: *mut u8,
@@ -372,7 +372,7 @@ pub extern "C" fn mesh_orm_build_update(
 /// - `where_clauses`: List<String> of "column op" entries
 /// - `returning`: List<String> for RETURNING clause
 #[no_mangle]
-pub extern "C" fn mesh_orm_build_delete(
+pub extern "C-unwind" fn mesh_orm_build_delete(
     table: *const MeshString,
     where_clauses: *mut u8,
     returning: *mut u8,
@@ -29,7 +29,7 @@ use pbkdf2::pbkdf2_hmac;
 use rand::Rng;
 use sha2::Sha256;
 
-use crate::collections::list::{mesh_list_append, mesh_list_get, mesh_list_length, mesh_list_new};
+use crate::collections::list::{elems, mesh_list_append, mesh_list_get, mesh_list_length, mesh_list_new};
 use crate::collections::map::{mesh_map_new_typed, mesh_map_put};
 use crate::io::alloc_result;
 use crate::string::{mesh_string_new, MeshString};
@@ -645,14 +645,11 @@ fn err_result(msg: &str) -> *mut u8 {
 
 /// Extract param strings from a Mesh List<String>.
 ///
-/// MeshList layout: `{ len: u64, cap: u64, data: [u64; cap] }`
 /// Each element is a u64 that is actually a pointer to a MeshString.
 unsafe fn extract_params(params: *mut u8) -> Vec<String> {
-    let len = *(params as *const u64);
-    let data_ptr = (params as *const u64).add(2); // skip len + cap
-    let mut result = Vec::with_capacity(len as usize);
-    for i in 0..len as usize {
-        let param_ptr = *data_ptr.add(i) as *const MeshString;
+    let mut result = Vec::new();
+    for param in elems(params) {
+        let param_ptr = param as *const MeshString;
         let param_str = mesh_str_to_rust(param_ptr);
         result.push(param_str.to_string());
     }
@@ -682,7 +679,7 @@ fn parse_command_tag(tag: &str) -> i64 {
 /// Returns MeshResult with tag 0 (Ok) containing the connection handle as
 /// a u64, or tag 1 (Err) containing an error message string.
 #[no_mangle]
-pub extern "C" fn mesh_pg_connect(url: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_pg_connect(url: *const MeshString) -> *mut u8 {
     unsafe {
         let url_str = mesh_str_to_rust(url);
         let pg_url = match parse_pg_url(url_str) {
@@ -927,7 +924,7 @@ pub extern "C" fn mesh_pg_connect(url: *const MeshString) -> *mut u8 {
 /// Recovers the Box<PgConn> from the handle, sends Terminate message,
 /// and lets Box::drop free the Rust memory and close the TcpStream.
 #[no_mangle]
-pub extern "C" fn mesh_pg_close(conn_handle: u64) {
+pub extern "C-unwind" fn mesh_pg_close(conn_handle: u64) {
     unsafe {
         let mut conn = Box::from_raw(conn_handle as *mut PgConn);
         let mut buf = Vec::new();
@@ -947,7 +944,7 @@ pub extern "C" fn mesh_pg_close(conn_handle: u64) {
 /// Parameters are bound via the Extended Query protocol using $1, $2, etc.
 /// Returns the number of rows affected from the CommandComplete tag.
 #[no_mangle]
-pub extern "C" fn mesh_pg_execute(
+pub extern "C-unwind" fn mesh_pg_execute(
     conn_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -1020,7 +1017,7 @@ pub extern "C" fn mesh_pg_execute(
 /// are the text representation of column values. NULL columns become empty
 /// strings.
 #[no_mangle]
-pub extern "C" fn mesh_pg_query(
+pub extern "C-unwind" fn mesh_pg_query(
     conn_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -1185,7 +1182,7 @@ pub(super) fn pg_simple_command(conn: &mut PgConn, sql: &str) -> Result<(), Stri
 ///
 /// Sends `BEGIN` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_pg_begin(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_pg_begin(conn_handle: u64) -> *mut u8 {
     unsafe {
         let conn = &mut *(conn_handle as *mut PgConn);
         match pg_simple_command(conn, "BEGIN") {
@@ -1203,7 +1200,7 @@ pub extern "C" fn mesh_pg_begin(conn_handle: u64) -> *mut u8 {
 ///
 /// Sends `COMMIT` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_pg_commit(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_pg_commit(conn_handle: u64) -> *mut u8 {
     unsafe {
         let conn = &mut *(conn_handle as *mut PgConn);
         match pg_simple_command(conn, "COMMIT") {
@@ -1221,7 +1218,7 @@ pub extern "C" fn mesh_pg_commit(conn_handle: u64) -> *mut u8 {
 ///
 /// Sends `ROLLBACK` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_pg_rollback(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_pg_rollback(conn_handle: u64) -> *mut u8 {
     unsafe {
         let conn = &mut *(conn_handle as *mut PgConn);
         match pg_simple_command(conn, "ROLLBACK") {
@@ -1246,7 +1243,7 @@ pub extern "C" fn mesh_pg_rollback(conn_handle: u64) -> *mut u8 {
 /// 4. On Err result from closure: ROLLBACK and propagate the Err.
 /// 5. On panic: ROLLBACK and return Err("transaction aborted: panic in callback").
 #[no_mangle]
-pub extern "C" fn mesh_pg_transaction(
+pub extern "C-unwind" fn mesh_pg_transaction(
     conn_handle: u64,
     fn_ptr: *const u8,
     env_ptr: *const u8,
@@ -1262,10 +1259,10 @@ pub extern "C" fn mesh_pg_transaction(
         // 2. Call the closure with catch_unwind for panic safety
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             if env_ptr.is_null() {
-                let f: extern "C" fn(u64) -> *mut u8 = std::mem::transmute(fn_ptr);
+                let f: extern "C-unwind" fn(u64) -> *mut u8 = std::mem::transmute(fn_ptr);
                 f(conn_handle)
             } else {
-                let f: extern "C" fn(*const u8, u64) -> *mut u8 = std::mem::transmute(fn_ptr);
+                let f: extern "C-unwind" fn(*const u8, u64) -> *mut u8 = std::mem::transmute(fn_ptr);
                 f(env_ptr, conn_handle)
             }
         }));
@@ -1298,7 +1295,7 @@ pub extern "C" fn mesh_pg_transaction(
 
 // ── Struct-to-Row Query ───────────────────────────────────────────────
 
-type FromRowFn = unsafe extern "C" fn(*mut u8) -> *mut u8;
+type FromRowFn = unsafe extern "C-unwind" fn(*mut u8) -> *mut u8;
 
 /// Execute a SELECT query and map each row through a from_row callback.
 ///
@@ -1313,7 +1310,7 @@ type FromRowFn = unsafe extern "C" fn(*mut u8) -> *mut u8;
 /// 4. Collects all per-row results into a new list.
 /// 5. Returns Ok(list_of_results).
 #[no_mangle]
-pub extern "C" fn mesh_pg_query_as(
+pub extern "C-unwind" fn mesh_pg_query_as(
     conn_handle: u64,
     sql: *mut u8,
     params: *mut u8,
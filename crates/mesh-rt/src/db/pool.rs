@@ -106,7 +106,7 @@ unsafe fn health_check(handle: u64) -> bool {
 /// Pre-creates `min_conns` connections. Returns MeshResult with tag 0 (Ok)
 /// containing the pool handle as u64, or tag 1 (Err) with error message.
 #[no_mangle]
-pub extern "C" fn mesh_pool_open(
+pub extern "C-unwind" fn mesh_pool_open(
     url: *const MeshString,
     min_conns: i64,
     max_conns: i64,
@@ -170,7 +170,7 @@ pub extern "C" fn mesh_pool_open(
 /// with timeout if pool is exhausted. Performs health check on idle
 /// connections before returning them.
 #[no_mangle]
-pub extern "C" fn mesh_pool_checkout(pool_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_pool_checkout(pool_handle: u64) -> *mut u8 {
     unsafe {
         let pool = &*(pool_handle as *const PgPool);
         let timeout = Duration::from_millis({
@@ -243,7 +243,7 @@ pub extern "C" fn mesh_pool_checkout(pool_handle: u64) -> *mut u8 {
 /// sends ROLLBACK to clean it up. If ROLLBACK fails, the connection
 /// is destroyed instead of returned to idle.
 #[no_mangle]
-pub extern "C" fn mesh_pool_checkin(pool_handle: u64, conn_handle: u64) {
+pub extern "C-unwind" fn mesh_pool_checkin(pool_handle: u64, conn_handle: u64) {
     unsafe {
         let pool = &*(pool_handle as *const PgPool);
 
@@ -291,7 +291,7 @@ pub extern "C" fn mesh_pool_checkin(pool_handle: u64, conn_handle: u64) {
 /// `mesh_pool_query(pool_handle: u64, sql: *const MeshString, params: *mut u8)
 ///     -> *mut u8 (MeshResult<List<Map<String, String>>, String>)`
 #[no_mangle]
-pub extern "C" fn mesh_pool_query(
+pub extern "C-unwind" fn mesh_pool_query(
     pool_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -322,7 +322,7 @@ pub extern "C" fn mesh_pool_query(
 /// `mesh_pool_execute(pool_handle: u64, sql: *const MeshString, params: *mut u8)
 ///     -> *mut u8 (MeshResult<Int, String>)`
 #[no_mangle]
-pub extern "C" fn mesh_pool_execute(
+pub extern "C-unwind" fn mesh_pool_execute(
     pool_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -356,7 +356,7 @@ pub extern "C" fn mesh_pool_execute(
 /// Same checkout/query_as/checkin pattern as `mesh_pool_query` but delegates to
 /// `mesh_pg_query_as` for struct mapping.
 #[no_mangle]
-pub extern "C" fn mesh_pool_query_as(
+pub extern "C-unwind" fn mesh_pool_query_as(
     pool_handle: u64,
     sql: *mut u8,
     params: *mut u8,
@@ -391,7 +391,7 @@ pub extern "C" fn mesh_pool_query_as(
 /// all blocked checkouts so they return "pool is closed" errors.
 /// Active connections will be closed when checked in.
 #[no_mangle]
-pub extern "C" fn mesh_pool_close(pool_handle: u64) {
+pub extern "C-unwind" fn mesh_pool_close(pool_handle: u64) {
     unsafe {
         let pool = &*(pool_handle as *const PgPool);
         let idle_conns: Vec<u64>;
@@ -155,7 +155,7 @@ unsafe fn clone_query(src: *mut u8) -> *mut u8 {
 ///
 /// `Query.from("users")` -> opaque Query pointer
 #[no_mangle]
-pub extern "C" fn mesh_query_from(table: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_from(table: *mut u8) -> *mut u8 {
     unsafe {
         let q = alloc_query();
         query_set(q, SLOT_SOURCE, table);
@@ -167,7 +167,7 @@ pub extern "C" fn mesh_query_from(table: *mut u8) -> *mut u8 {
 ///
 /// `Query.where(q, :name, "Alice")` -> new Query with WHERE name = $N
 #[no_mangle]
-pub extern "C" fn mesh_query_where(q: *mut u8, field: *mut u8, value: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_where(q: *mut u8, field: *mut u8, value: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         let field_str = mesh_str_ref(field);
@@ -185,7 +185,7 @@ pub extern "C" fn mesh_query_where(q: *mut u8, field: *mut u8, value: *mut u8) -
 ///
 /// `Query.where_op(q, :age, :gt, "21")` -> new Query with WHERE age > $N
 #[no_mangle]
-pub extern "C" fn mesh_query_where_op(
+pub extern "C-unwind" fn mesh_query_where_op(
     q: *mut u8,
     field: *mut u8,
     op: *mut u8,
@@ -210,7 +210,7 @@ pub extern "C" fn mesh_query_where_op(
 ///
 /// `Query.where_in(q, :status, ["active", "pending"])` -> new Query with WHERE status IN ($N, $M)
 #[no_mangle]
-pub extern "C" fn mesh_query_where_in(
+pub extern "C-unwind" fn mesh_query_where_in(
     q: *mut u8,
     field: *mut u8,
     values: *mut u8,
@@ -238,7 +238,7 @@ pub extern "C" fn mesh_query_where_in(
 ///
 /// `Query.where_null(q, :deleted_at)` -> new Query with WHERE deleted_at IS NULL
 #[no_mangle]
-pub extern "C" fn mesh_query_where_null(q: *mut u8, field: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_where_null(q: *mut u8, field: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         let field_str = mesh_str_ref(field);
@@ -254,7 +254,7 @@ pub extern "C" fn mesh_query_where_null(q: *mut u8, field: *mut u8) -> *mut u8 {
 ///
 /// `Query.where_not_null(q, :name)` -> new Query with WHERE name IS NOT NULL
 #[no_mangle]
-pub extern "C" fn mesh_query_where_not_null(q: *mut u8, field: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_where_not_null(q: *mut u8, field: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         let field_str = mesh_str_ref(field);
@@ -270,7 +270,7 @@ pub extern "C" fn mesh_query_where_not_null(q: *mut u8, field: *mut u8) -> *mut
 ///
 /// `Query.select(q, ["id", "name"])` -> new Query with SELECT id, name
 #[no_mangle]
-pub extern "C" fn mesh_query_select(q: *mut u8, fields: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_select(q: *mut u8, fields: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         query_set(new_q, SLOT_SELECT, fields);
@@ -282,7 +282,7 @@ pub extern "C" fn mesh_query_select(q: *mut u8, fields: *mut u8) -> *mut u8 {
 ///
 /// `Query.order_by(q, :name, :asc)` -> new Query with ORDER BY name ASC
 #[no_mangle]
-pub extern "C" fn mesh_query_order_by(
+pub extern "C-unwind" fn mesh_query_order_by(
     q: *mut u8,
     field: *mut u8,
     direction: *mut u8,
@@ -304,7 +304,7 @@ pub extern "C" fn mesh_query_order_by(
 ///
 /// `Query.limit(q, 10)` -> new Query with LIMIT 10
 #[no_mangle]
-pub extern "C" fn mesh_query_limit(q: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_limit(q: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         query_set_int(new_q, SLOT_LIMIT, n);
@@ -316,7 +316,7 @@ pub extern "C" fn mesh_query_limit(q: *mut u8, n: i64) -> *mut u8 {
 ///
 /// `Query.offset(q, 20)` -> new Query with OFFSET 20
 #[no_mangle]
-pub extern "C" fn mesh_query_offset(q: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_offset(q: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         query_set_int(new_q, SLOT_OFFSET, n);
@@ -328,7 +328,7 @@ pub extern "C" fn mesh_query_offset(q: *mut u8, n: i64) -> *mut u8 {
 ///
 /// `Query.join(q, :inner, "posts", "users.id = posts.user_id")` -> new Query with INNER JOIN
 #[no_mangle]
-pub extern "C" fn mesh_query_join(
+pub extern "C-unwind" fn mesh_query_join(
     q: *mut u8,
     join_type: *mut u8,
     table: *mut u8,
@@ -352,7 +352,7 @@ pub extern "C" fn mesh_query_join(
 ///
 /// `Query.group_by(q, :category)` -> new Query with GROUP BY category
 #[no_mangle]
-pub extern "C" fn mesh_query_group_by(q: *mut u8, field: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_group_by(q: *mut u8, field: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         let gf = query_get(new_q, SLOT_GROUP);
@@ -365,7 +365,7 @@ pub extern "C" fn mesh_query_group_by(q: *mut u8, field: *mut u8) -> *mut u8 {
 ///
 /// `Query.having(q, "count(*) >", "5")` -> new Query with HAVING count(*) > $N
 #[no_mangle]
-pub extern "C" fn mesh_query_having(
+pub extern "C-unwind" fn mesh_query_having(
     q: *mut u8,
     clause: *mut u8,
     value: *mut u8,
@@ -387,7 +387,7 @@ pub extern "C" fn mesh_query_having(
 /// Each expression is stored with a "RAW:" prefix so the SQL builder emits it verbatim.
 /// Can be mixed with Query.select -- normal fields get quoted, RAW: fields don't.
 #[no_mangle]
-pub extern "C" fn mesh_query_select_raw(q: *mut u8, expressions: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_query_select_raw(q: *mut u8, expressions: *mut u8) -> *mut u8 {
     unsafe {
         let new_q = clone_query(q);
         let expr_len = mesh_list_length(expressions);
@@ -413,7 +413,7 @@ pub extern "C" fn mesh_query_select_raw(q: *mut u8, expressions: *mut u8) -> *mu
 /// replaced with the next sequential `$N` by the SQL builder. Parameters are appended
 /// to the where_params list.
 #[no_mangle]
-pub extern "C" fn mesh_query_where_raw(
+pub extern "C-unwind" fn mesh_query_where_raw(
     q: *mut u8,
     clause: *mut u8,
     params: *mut u8,
@@ -441,7 +441,7 @@ pub extern "C" fn mesh_query_where_raw(
 ///
 /// `Query.fragment(q, "WHERE custom_fn($1)", params)` -> new Query with raw fragment
 #[no_mangle]
-pub extern "C" fn mesh_query_fragment(
+pub extern "C-unwind" fn mesh_query_fragment(
     q: *mut u8,
     sql: *mut u8,
     params: *mut u8,
@@ -23,7 +23,9 @@
 //! - `mesh_repo_transaction`: Wraps callback in checkout/begin/commit-or-rollback/checkin
 
 use crate::collections::list::{mesh_list_get, mesh_list_length, mesh_list_new, mesh_list_append};
-use crate::collections::map::{mesh_map_get, mesh_map_put};
+use crate::collections::map::{
+    mesh_map_entry_key, mesh_map_entry_value, mesh_map_get, mesh_map_put, mesh_map_size,
+};
 use crate::db::pool::{mesh_pool_query, mesh_pool_execute, mesh_pool_checkout, mesh_pool_checkin};
 use crate::db::pg::{mesh_pg_begin, mesh_pg_commit, mesh_pg_rollback};
 use crate::db::changeset::{
@@ -628,7 +630,7 @@ fn build_exists_sql_from_parts(
 /// Reads the Query struct's slots, builds complete SELECT SQL with all
 /// clause types, and executes via Pool.query.
 #[no_mangle]
-pub extern "C" fn mesh_repo_all(pool: u64, query: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_all(pool: u64, query: *mut u8) -> *mut u8 {
     unsafe {
         let (sql, params) = query_to_select_sql(query);
         let sql_ptr = rust_str_to_mesh(&sql) as *const MeshString;
@@ -644,7 +646,7 @@ pub extern "C" fn mesh_repo_all(pool: u64, query: *mut u8) -> *mut u8 {
 /// Adds LIMIT 1 to the query, executes, and extracts the first row.
 /// Returns Err("not found") if no rows match.
 #[no_mangle]
-pub extern "C" fn mesh_repo_one(pool: u64, query: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_one(pool: u64, query: *mut u8) -> *mut u8 {
     unsafe {
         // Read the query but force limit to 1
         let source_ptr = query_get(query, SLOT_SOURCE);
@@ -705,7 +707,7 @@ pub extern "C" fn mesh_repo_one(pool: u64, query: *mut u8) -> *mut u8 {
 ///
 /// Builds: `SELECT * FROM "table" WHERE "id" = $1 LIMIT 1`
 #[no_mangle]
-pub extern "C" fn mesh_repo_get(pool: u64, table: *mut u8, id: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_get(pool: u64, table: *mut u8, id: *mut u8) -> *mut u8 {
     unsafe {
         let table_str = mesh_str_ref(table);
         let sql = format!(
@@ -741,7 +743,7 @@ pub extern "C" fn mesh_repo_get(pool: u64, table: *mut u8, id: *mut u8) -> *mut
 ///
 /// Builds: `SELECT * FROM "table" WHERE "field" = $1 LIMIT 1`
 #[no_mangle]
-pub extern "C" fn mesh_repo_get_by(
+pub extern "C-unwind" fn mesh_repo_get_by(
     pool: u64,
     table: *mut u8,
     field: *mut u8,
@@ -783,7 +785,7 @@ pub extern "C" fn mesh_repo_get_by(
 /// Builds: `SELECT COUNT(*) FROM "table" WHERE ...`
 /// Parses the integer from the first row's first column.
 #[no_mangle]
-pub extern "C" fn mesh_repo_count(pool: u64, query: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_count(pool: u64, query: *mut u8) -> *mut u8 {
     unsafe {
         let (sql, params) = query_to_count_sql(query);
         let sql_ptr = rust_str_to_mesh(&sql) as *const MeshString;
@@ -825,7 +827,7 @@ pub extern "C" fn mesh_repo_count(pool: u64, query: *mut u8) -> *mut u8 {
 /// Builds: `SELECT EXISTS(SELECT 1 FROM "table" WHERE ... LIMIT 1)`
 /// Returns true (1) or false (0) as the result value.
 #[no_mangle]
-pub extern "C" fn mesh_repo_exists(pool: u64, query: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_exists(pool: u64, query: *mut u8) -> *mut u8 {
     unsafe {
         let (sql, params) = query_to_exists_sql(query);
         let sql_ptr = rust_str_to_mesh(&sql) as *const MeshString;
@@ -863,19 +865,15 @@ pub extern "C" fn mesh_repo_exists(pool: u64, query: *mut u8) -> *mut u8 {
 
 // ── Map extraction helpers ─────────────────────────────────────────
 
-/// Map header size in bytes (must match collections/map.rs)
-const MAP_HEADER_SIZE: usize = 16;
-
-/// Extract (column_names, values) from a Mesh Map<String, String> pointer.
-/// Reads the map's internal structure directly for efficiency.
+/// Extract (column_names, values) from a Mesh Map<String, String> pointer,
+/// in the map's insertion order.
 unsafe fn map_to_columns_and_values(map: *mut u8) -> (Vec<String>, Vec<String>) {
-    let len = *(map as *const u64) as usize;
-    let entries = map.add(MAP_HEADER_SIZE) as *const [u64; 2];
+    let len = mesh_map_size(map) as usize;
     let mut columns = Vec::with_capacity(len);
     let mut values = Vec::with_capacity(len);
-    for i in 0..len {
-        let key_ptr = (*entries.add(i))[0] as *const MeshString;
-        let val_ptr = (*entries.add(i))[1] as *const MeshString;
+    for i in 0..len as i64 {
+        let key_ptr = mesh_map_entry_key(map, i) as *const MeshString;
+        let val_ptr = mesh_map_entry_value(map, i) as *const MeshString;
         if !key_ptr.is_null() {
             columns.push((*key_ptr).as_str().to_string());
         }
@@ -899,7 +897,7 @@ unsafe fn map_to_columns_and_values(map: *mut u8) -> (Vec<String>, Vec<String>)
 /// 3. Executes via Pool.query (RETURNING produces rows)
 /// 4. Returns the first (inserted) row
 #[no_mangle]
-pub extern "C" fn mesh_repo_insert(pool: u64, table: *mut u8, fields: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_insert(pool: u64, table: *mut u8, fields: *mut u8) -> *mut u8 {
     unsafe {
         let table_str = mesh_str_ref(table);
         let (columns, values) = map_to_columns_and_values(fields);
@@ -943,7 +941,7 @@ pub extern "C" fn mesh_repo_insert(pool: u64, table: *mut u8, fields: *mut u8) -
 /// 3. Params: SET values first ($1..$N), then id ($N+1)
 /// 4. Returns the first (updated) row
 #[no_mangle]
-pub extern "C" fn mesh_repo_update(
+pub extern "C-unwind" fn mesh_repo_update(
     pool: u64,
     table: *mut u8,
     id: *mut u8,
@@ -993,7 +991,7 @@ pub extern "C" fn mesh_repo_update(
 /// 1. Builds DELETE SQL with WHERE id = $1, RETURNING *
 /// 2. Returns the first (deleted) row
 #[no_mangle]
-pub extern "C" fn mesh_repo_delete(pool: u64, table: *mut u8, id: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_repo_delete(pool: u64, table: *mut u8, id: *mut u8) -> *mut u8 {
     unsafe {
         let table_str = mesh_str_ref(table);
 
@@ -1036,7 +1034,7 @@ pub extern "C" fn mesh_repo_delete(pool: u64, table: *mut u8, id: *mut u8) -> *m
 /// 5. On Err: Pg.rollback(conn), Pool.checkin(pool, conn), return Err(error)
 /// 6. On panic: Pg.rollback(conn), Pool.checkin(pool, conn), return Err("transaction panicked")
 #[no_mangle]
-pub extern "C" fn mesh_repo_transaction(
+pub extern "C-unwind" fn mesh_repo_transaction(
     pool: u64,
     fn_ptr: *const u8,
     env_ptr: *const u8,
@@ -1062,10 +1060,10 @@ pub extern "C" fn mesh_repo_transaction(
         // 3. Call the user callback with catch_unwind for panic safety
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             if env_ptr.is_null() {
-                let f: extern "C" fn(u64) -> *mut u8 = std::mem::transmute(fn_ptr);
+                let f: extern "C-unwind" fn(u64) -> *mut u8 = std::mem::transmute(fn_ptr);
                 f(conn_handle)
             } else {
-                let f: extern "C" fn(*const u8, u64) -> *mut u8 = std::mem::transmute(fn_ptr);
+                let f: extern "C-unwind" fn(*const u8, u64) -> *mut u8 = std::mem::transmute(fn_ptr);
                 f(env_ptr, conn_handle)
             }
         }));
@@ -1143,7 +1141,7 @@ fn parse_pg_error_string(err: &str) -> (&str, &str, &str, &str, &str) {
 /// 4. On success: return Ok(first_row)
 /// 5. On PG error: parse structured error, map constraint to changeset error, return Err(changeset)
 #[no_mangle]
-pub extern "C" fn mesh_repo_insert_changeset(
+pub extern "C-unwind" fn mesh_repo_insert_changeset(
     pool: u64,
     table: *mut u8,
     changeset: *mut u8,
@@ -1205,7 +1203,7 @@ pub extern "C" fn mesh_repo_insert_changeset(
 ///
 /// Same pattern as insert_changeset but builds UPDATE SQL with WHERE id = $N+1.
 #[no_mangle]
-pub extern "C" fn mesh_repo_update_changeset(
+pub extern "C-unwind" fn mesh_repo_update_changeset(
     pool: u64,
     table: *mut u8,
     id: *mut u8,
@@ -1575,7 +1573,7 @@ unsafe fn preload_nested(
 /// Associations are sorted by nesting depth (atoms/direct first, then "a.b", then "a.b.c")
 /// to ensure parent-level data is loaded before nested preloading accesses it.
 #[no_mangle]
-pub extern "C" fn mesh_repo_preload(
+pub extern "C-unwind" fn mesh_repo_preload(
     pool: u64,
     rows: *mut u8,
     associations: *mut u8,
@@ -1705,7 +1703,7 @@ fn build_where_from_query_parts(
 /// Update rows matching a Query's WHERE conditions.
 /// `Repo.update_where(pool, table, fields_map, query)` -> `Result<Map<String,String>, String>`
 #[no_mangle]
-pub extern "C" fn mesh_repo_update_where(
+pub extern "C-unwind" fn mesh_repo_update_where(
     pool: u64,
     table: *mut u8,
     fields: *mut u8,
@@ -1764,7 +1762,7 @@ pub extern "C" fn mesh_repo_update_where(
 /// Delete rows matching a Query's WHERE conditions.
 /// `Repo.delete_where(pool, table, query)` -> `Result<Int, String>`
 #[no_mangle]
-pub extern "C" fn mesh_repo_delete_where(
+pub extern "C-unwind" fn mesh_repo_delete_where(
     pool: u64,
     table: *mut u8,
     query: *mut u8,
@@ -1793,7 +1791,7 @@ pub extern "C" fn mesh_repo_delete_where(
 /// Execute raw SQL and return rows.
 /// `Repo.query_raw(pool, sql, params)` -> `Result<List<Map<String,String>>, String>`
 #[no_mangle]
-pub extern "C" fn mesh_repo_query_raw(
+pub extern "C-unwind" fn mesh_repo_query_raw(
     pool: u64,
     sql: *mut u8,
     params: *mut u8,
@@ -1805,7 +1803,7 @@ pub extern "C" fn mesh_repo_query_raw(
 /// Execute raw SQL and return affected row count.
 /// `Repo.execute_raw(pool, sql, params)` -> `Result<Int, String>`
 #[no_mangle]
-pub extern "C" fn mesh_repo_execute_raw(
+pub extern "C-unwind" fn mesh_repo_execute_raw(
     pool: u64,
     sql: *mut u8,
     params: *mut u8,
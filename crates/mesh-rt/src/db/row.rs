@@ -10,8 +10,12 @@
 //! - `mesh_row_parse_int`: Parse a string to Int (i64)
 //! - `mesh_row_parse_float`: Parse a string to Float (f64)
 //! - `mesh_row_parse_bool`: Parse a string to Bool (0 or 1)
+//! - `mesh_row_parse_bigint`: Parse a string to BigInt
+//! - `mesh_row_parse_decimal`: Parse a string to Decimal (e.g. a PostgreSQL `numeric`)
 
+use crate::bigint::{alloc_bigint, Big};
 use crate::collections::map::{mesh_map_get, mesh_map_has_key};
+use crate::decimal::{alloc_decimal, Dec};
 use crate::io::alloc_result;
 use crate::string::{mesh_string_new, MeshString};
 
@@ -26,7 +30,7 @@ use crate::string::{mesh_string_new, MeshString};
 ///
 /// Returns Ok(value_string_ptr) if the key exists, or Err("missing column: {name}").
 #[no_mangle]
-pub extern "C" fn mesh_row_from_row_get(row: *mut u8, col_name: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_row_from_row_get(row: *mut u8, col_name: *mut u8) -> *mut u8 {
     unsafe {
         let key = col_name as u64;
         if mesh_map_has_key(row, key) != 0 {
@@ -51,7 +55,7 @@ pub extern "C" fn mesh_row_from_row_get(row: *mut u8, col_name: *mut u8) -> *mut
 /// Trims the input string and parses as i64.
 /// Returns Ok(value_as_i64) or Err("cannot parse '{text}' as Int").
 #[no_mangle]
-pub extern "C" fn mesh_row_parse_int(s: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_row_parse_int(s: *mut u8) -> *mut u8 {
     unsafe {
         let text = (*(s as *const MeshString)).as_str().trim();
         match text.parse::<i64>() {
@@ -78,7 +82,7 @@ pub extern "C" fn mesh_row_parse_int(s: *mut u8) -> *mut u8 {
 /// Returns Ok(f64::to_bits(value)) or Err("cannot parse '{text}' as Float").
 /// Uses `f64::to_bits()` for float-to-u64 encoding, matching Mesh Float convention.
 #[no_mangle]
-pub extern "C" fn mesh_row_parse_float(s: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_row_parse_float(s: *mut u8) -> *mut u8 {
     unsafe {
         let raw = (*(s as *const MeshString)).as_str().trim();
         // Pre-normalize PostgreSQL-specific infinity representations
@@ -110,7 +114,7 @@ pub extern "C" fn mesh_row_parse_float(s: *mut u8) -> *mut u8 {
 ///
 /// Returns Ok(1) for true, Ok(0) for false, or Err("cannot parse '{text}' as Bool").
 #[no_mangle]
-pub extern "C" fn mesh_row_parse_bool(s: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_row_parse_bool(s: *mut u8) -> *mut u8 {
     unsafe {
         let raw = (*(s as *const MeshString)).as_str().trim();
         let lower = raw.to_lowercase();
@@ -126,6 +130,55 @@ pub extern "C" fn mesh_row_parse_bool(s: *mut u8) -> *mut u8 {
     }
 }
 
+/// Parse a string value to a BigInt.
+///
+/// # Signature
+///
+/// `mesh_row_parse_bigint(s: *mut u8) -> *mut u8 (MeshResult)`
+///
+/// Trims the input string and parses it as an integer of any size.
+/// Returns Ok(bigint_ptr) or Err("cannot parse '{text}' as BigInt").
+#[no_mangle]
+pub extern "C-unwind" fn mesh_row_parse_bigint(s: *mut u8) -> *mut u8 {
+    unsafe {
+        let text = (*(s as *const MeshString)).as_str().trim();
+        match Big::parse(text) {
+            Some(val) => alloc_result(0, alloc_bigint(&val) as *mut u8) as *mut u8,
+            None => {
+                let msg = format!("cannot parse '{}' as BigInt", text);
+                let err_mesh = mesh_string_new(msg.as_ptr(), msg.len() as u64);
+                alloc_result(1, err_mesh as *mut u8) as *mut u8
+            }
+        }
+    }
+}
+
+/// Parse a string value to a Decimal.
+///
+/// # Signature
+///
+/// `mesh_row_parse_decimal(s: *mut u8) -> *mut u8 (MeshResult)`
+///
+/// PostgreSQL sends `numeric` columns as exact decimal text (`"1234.50"`),
+/// so the value keeps every digit and the column's scale. PostgreSQL's
+/// `NaN` and infinities have no Decimal equivalent and are errors.
+///
+/// Returns Ok(decimal_ptr) or Err("cannot parse '{text}' as Decimal").
+#[no_mangle]
+pub extern "C-unwind" fn mesh_row_parse_decimal(s: *mut u8) -> *mut u8 {
+    unsafe {
+        let text = (*(s as *const MeshString)).as_str().trim();
+        match Dec::parse(text) {
+            Some(val) => alloc_result(0, alloc_decimal(&val) as *mut u8) as *mut u8,
+            None => {
+                let msg = format!("cannot parse '{}' as Decimal", text);
+                let err_mesh = mesh_string_new(msg.as_ptr(), msg.len() as u64);
+                alloc_result(1, err_mesh as *mut u8) as *mut u8
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,4 +370,41 @@ mod tests {
             assert!(msg.contains("maybe"), "got: {}", msg);
         }
     }
+
+    // ── parse_bigint / parse_decimal tests ───────────────────────────────
+
+    #[test]
+    fn test_parse_bigint_beyond_int() {
+        mesh_rt_init();
+        let s = make_mesh_string(" 123456789012345678901234567890 ");
+        let r = mesh_row_parse_bigint(s);
+        unsafe {
+            assert_eq!(result_tag(r), 0);
+            let b = result_value_i64(r) as *const crate::bigint::MeshBigInt;
+            assert_eq!((*crate::bigint::mesh_bigint_to_string(b)).as_str(), "123456789012345678901234567890");
+        }
+    }
+
+    #[test]
+    fn test_parse_decimal_numeric_keeps_scale() {
+        mesh_rt_init();
+        let s = make_mesh_string("-98765432109876543210.0100");
+        let r = mesh_row_parse_decimal(s);
+        unsafe {
+            assert_eq!(result_tag(r), 0);
+            let d = result_value_i64(r) as *const crate::decimal::MeshDecimal;
+            assert_eq!((*crate::decimal::mesh_decimal_to_string(d)).as_str(), "-98765432109876543210.0100");
+        }
+    }
+
+    #[test]
+    fn test_parse_decimal_failure() {
+        mesh_rt_init();
+        let s = make_mesh_string("NaN");
+        let r = mesh_row_parse_decimal(s);
+        unsafe {
+            assert_eq!(result_tag(r), 1);
+            assert_eq!(result_value_str(r), "cannot parse 'NaN' as Decimal");
+        }
+    }
 }
@@ -15,7 +15,7 @@ use libsqlite3_sys::*;
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
 
-use crate::collections::list::{mesh_list_append, mesh_list_new};
+use crate::collections::list::{list_to_vec, mesh_list_append, mesh_list_new};
 use crate::collections::map::{mesh_map_new_typed, mesh_map_put};
 use crate::io::alloc_result;
 use crate::string::{mesh_string_new, MeshString};
@@ -80,8 +80,9 @@ unsafe fn sqlite_err_result(db: *mut sqlite3) -> *mut u8 {
 /// Read the MeshList of MeshString parameters and bind them to a prepared
 /// statement using sqlite3_bind_text with SQLITE_TRANSIENT.
 ///
-/// MeshList layout: `{ len: u64, cap: u64, data: [u64; cap] }`
-/// Each element is a u64 that is actually a pointer to a MeshString.
+/// The list is a persistent vector, so its elements are copied out with
+/// `list_to_vec`. Each element is a u64 that is actually a pointer to a
+/// MeshString.
 ///
 /// Returns Ok(()) on success, Err(error_string) on bind failure.
 unsafe fn bind_params(
@@ -89,14 +90,13 @@ unsafe fn bind_params(
     stmt: *mut sqlite3_stmt,
     params: *mut u8,
 ) -> Result<(), *mut u8> {
-    let len = *(params as *const u64);
-    let data_ptr = (params as *const u64).add(2); // skip len + cap
+    let params = list_to_vec(params);
 
     // We need to keep CStrings alive until all binds are complete.
-    let mut cstrings = Vec::with_capacity(len as usize);
+    let mut cstrings = Vec::with_capacity(params.len());
 
-    for i in 0..len as usize {
-        let param_ptr = *data_ptr.add(i) as *const MeshString;
+    for &param in &params {
+        let param_ptr = param as *const MeshString;
         let param_str = mesh_str_to_rust(param_ptr);
         let cstr = match CString::new(param_str) {
             Ok(c) => c,
@@ -134,7 +134,7 @@ unsafe fn bind_params(
 /// Returns MeshResult with tag 0 (Ok) containing the connection handle as
 /// a u64, or tag 1 (Err) containing an error message string.
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_open(path: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_sqlite_open(path: *const MeshString) -> *mut u8 {
     unsafe {
         let path_str = mesh_str_to_rust(path);
         let c_path = match CString::new(path_str) {
@@ -173,7 +173,7 @@ pub extern "C" fn mesh_sqlite_open(path: *const MeshString) -> *mut u8 {
 /// Recovers the Box<SqliteConn> from the handle, calls sqlite3_close,
 /// and lets Box::drop free the Rust memory.
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_close(conn_handle: u64) {
+pub extern "C-unwind" fn mesh_sqlite_close(conn_handle: u64) {
     unsafe {
         let conn = Box::from_raw(conn_handle as *mut SqliteConn);
         sqlite3_close(conn.db);
@@ -191,7 +191,7 @@ pub extern "C" fn mesh_sqlite_close(conn_handle: u64) {
 /// Parameters are bound as text via sqlite3_bind_text. Returns the number
 /// of rows affected (via sqlite3_changes) on success.
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_execute(
+pub extern "C-unwind" fn mesh_sqlite_execute(
     conn_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -245,7 +245,7 @@ pub extern "C" fn mesh_sqlite_execute(
 /// are the text representation of column values. NULL columns become empty
 /// strings.
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_query(
+pub extern "C-unwind" fn mesh_sqlite_query(
     conn_handle: u64,
     sql: *const MeshString,
     params: *mut u8,
@@ -365,7 +365,7 @@ fn sqlite_simple_exec(conn: &SqliteConn, sql: &str) -> *mut u8 {
 ///
 /// Sends `BEGIN` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_begin(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_sqlite_begin(conn_handle: u64) -> *mut u8 {
     let conn = unsafe { &*(conn_handle as *const SqliteConn) };
     sqlite_simple_exec(conn, "BEGIN")
 }
@@ -378,7 +378,7 @@ pub extern "C" fn mesh_sqlite_begin(conn_handle: u64) -> *mut u8 {
 ///
 /// Sends `COMMIT` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_commit(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_sqlite_commit(conn_handle: u64) -> *mut u8 {
     let conn = unsafe { &*(conn_handle as *const SqliteConn) };
     sqlite_simple_exec(conn, "COMMIT")
 }
@@ -391,7 +391,7 @@ pub extern "C" fn mesh_sqlite_commit(conn_handle: u64) -> *mut u8 {
 ///
 /// Sends `ROLLBACK` and returns Ok(()) or Err(error_message).
 #[no_mangle]
-pub extern "C" fn mesh_sqlite_rollback(conn_handle: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_sqlite_rollback(conn_handle: u64) -> *mut u8 {
     let conn = unsafe { &*(conn_handle as *const SqliteConn) };
     sqlite_simple_exec(conn, "ROLLBACK")
 }
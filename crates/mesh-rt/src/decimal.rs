@@ -0,0 +1,460 @@
+//! Fixed-scale decimal numbers for the Mesh runtime.
+//!
+//! A `Decimal` is a `BigInt` mantissa plus a scale, the number of digits
+//! after the decimal point: `12.50` is mantissa 1250 at scale 2. Results keep
+//! a scale derived from their operands, so amounts stay in whole cents:
+//!
+//! - `+`, `-` and `%` use the larger operand scale,
+//! - `*` adds the operand scales,
+//! - `/` keeps the larger operand scale, rounding half away from zero (as
+//!   PostgreSQL does) when the exact quotient has more digits.
+//!
+//! Equality and ordering are numeric, so `1.0 == 1.00`; `to_string` keeps
+//! the scale.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::bigint::{arith_crash, json_number_text, Big, MeshBigInt};
+use crate::gc::mesh_gc_alloc_actor;
+use crate::io::alloc_result;
+use crate::json::MeshJson;
+use crate::option::alloc_option;
+use crate::string::{mesh_string_new, MeshString};
+
+/// An owned decimal: `mantissa / 10^scale`.
+#[derive(Clone, Debug)]
+pub(crate) struct Dec {
+    mantissa: Big,
+    scale: u32,
+}
+
+impl Dec {
+    pub(crate) fn from_big(mantissa: Big, scale: u32) -> Dec {
+        Dec { mantissa, scale }
+    }
+
+    /// Parse an optionally signed decimal such as `-12.50`, `7` or `.5`.
+    /// The scale is the number of digits written after the point.
+    pub(crate) fn parse(text: &str) -> Option<Dec> {
+        let (int_part, frac_part) = match text.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (text, ""),
+        };
+        if !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let unsigned = int_part.trim_start_matches(['-', '+']);
+        if unsigned.len() + 1 < int_part.len() || (unsigned.is_empty() && frac_part.is_empty()) {
+            return None;
+        }
+        let digits = format!("{}{}0", int_part, frac_part);
+        // The trailing 0 keeps `-` and `-.5` parseable; divide it back out.
+        let mantissa = Big::parse(&digits)?.div_rem(&Big::from_i64(10))?.0;
+        Some(Dec { mantissa, scale: frac_part.len() as u32 })
+    }
+
+    /// The mantissa at `scale`, which must be at least `self.scale`.
+    fn rescaled(&self, scale: u32) -> Big {
+        self.mantissa.mul_pow10(scale - self.scale)
+    }
+
+    /// Both mantissas at the larger of the two scales, and that scale.
+    fn aligned(&self, other: &Dec) -> (Big, Big, u32) {
+        let scale = self.scale.max(other.scale);
+        (self.rescaled(scale), other.rescaled(scale), scale)
+    }
+
+    pub(crate) fn add(&self, other: &Dec) -> Dec {
+        let (a, b, scale) = self.aligned(other);
+        Dec { mantissa: a.add(&b), scale }
+    }
+
+    pub(crate) fn sub(&self, other: &Dec) -> Dec {
+        let (a, b, scale) = self.aligned(other);
+        Dec { mantissa: a.sub(&b), scale }
+    }
+
+    pub(crate) fn mul(&self, other: &Dec) -> Dec {
+        Dec {
+            mantissa: self.mantissa.mul(&other.mantissa),
+            scale: self.scale + other.scale,
+        }
+    }
+
+    /// None when `other` is zero.
+    pub(crate) fn div(&self, other: &Dec) -> Option<Dec> {
+        if other.mantissa.is_zero() {
+            return None;
+        }
+        let scale = self.scale.max(other.scale);
+        let numerator = self.mantissa.mul_pow10(scale - self.scale + other.scale);
+        Some(Dec { mantissa: div_round(&numerator, &other.mantissa), scale })
+    }
+
+    /// Truncating remainder with the sign of `self`; None when `other` is zero.
+    pub(crate) fn rem(&self, other: &Dec) -> Option<Dec> {
+        let (a, b, scale) = self.aligned(other);
+        Some(Dec { mantissa: a.div_rem(&b)?.1, scale })
+    }
+
+    pub(crate) fn neg(&self) -> Dec {
+        Dec { mantissa: self.mantissa.neg(), scale: self.scale }
+    }
+
+    /// The same value at `scale` digits, rounding half away from zero when
+    /// digits are dropped.
+    pub(crate) fn round(&self, scale: u32) -> Dec {
+        if scale >= self.scale {
+            return Dec { mantissa: self.rescaled(scale), scale };
+        }
+        let divisor = Big::from_i64(1).mul_pow10(self.scale - scale);
+        Dec { mantissa: div_round(&self.mantissa, &divisor), scale }
+    }
+
+    /// The value with trailing zeros after the point removed, so equal
+    /// values have equal representations.
+    fn normalized(&self) -> Dec {
+        let mut out = self.clone();
+        let ten = Big::from_i64(10);
+        while out.scale > 0 && !out.mantissa.is_zero() && out.mantissa.is_multiple_of_ten() {
+            out.mantissa = out.mantissa.div_rem(&ten).map(|(q, _)| q).unwrap_or_default();
+            out.scale -= 1;
+        }
+        if out.mantissa.is_zero() {
+            out.scale = 0;
+        }
+        out
+    }
+
+    pub(crate) fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+/// `n / d` rounded half away from zero; `d` is nonzero.
+fn div_round(n: &Big, d: &Big) -> Big {
+    let (q, r) = n.div_rem(d).unwrap_or_default();
+    if r.abs().mul(&Big::from_i64(2)) < d.abs() {
+        q
+    } else if n.is_negative() != d.is_negative() {
+        q.sub(&Big::from_i64(1))
+    } else {
+        q.add(&Big::from_i64(1))
+    }
+}
+
+impl PartialEq for Dec {
+    fn eq(&self, other: &Dec) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Dec {}
+
+impl Ord for Dec {
+    fn cmp(&self, other: &Dec) -> Ordering {
+        let (a, b, _) = self.aligned(other);
+        a.cmp(&b)
+    }
+}
+
+impl PartialOrd for Dec {
+    fn partial_cmp(&self, other: &Dec) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Dec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.mantissa.magnitude_digits(), width = scale + 1);
+        if self.mantissa.is_negative() {
+            f.write_str("-")?;
+        }
+        if scale == 0 {
+            return f.write_str(&digits);
+        }
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+        write!(f, "{}.{}", int_part, frac_part)
+    }
+}
+
+// ── GC representation ───────────────────────────────────────────────────
+
+/// A GC-managed Mesh `Decimal`.
+///
+/// Layout: `{ scale: u64 }` followed by the mantissa laid out inline as a
+/// `MeshBigInt` (header, then limbs).
+#[repr(C)]
+pub struct MeshDecimal {
+    pub scale: u64,
+    pub mantissa: MeshBigInt,
+}
+
+impl MeshDecimal {
+    /// Copy the value out of the GC heap.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `self` points to a valid MeshDecimal allocation.
+    pub(crate) unsafe fn to_dec(&self) -> Dec {
+        Dec { mantissa: self.mantissa.to_big(), scale: self.scale as u32 }
+    }
+}
+
+/// Allocate a new Decimal holding `value`.
+pub(crate) fn alloc_decimal(value: &Dec) -> *mut MeshDecimal {
+    unsafe {
+        let ptr = mesh_gc_alloc_actor(
+            (std::mem::size_of::<u64>() + MeshBigInt::size_of(&value.mantissa)) as u64,
+            std::mem::align_of::<MeshDecimal>() as u64,
+        ) as *mut MeshDecimal;
+        (*ptr).scale = value.scale as u64;
+        MeshBigInt::write(std::ptr::addr_of_mut!((*ptr).mantissa), &value.mantissa);
+        ptr
+    }
+}
+
+unsafe fn dec(d: *const MeshDecimal) -> Dec {
+    (*d).to_dec()
+}
+
+fn mesh_str(s: &str) -> *mut MeshString {
+    mesh_string_new(s.as_ptr(), s.len() as u64)
+}
+
+/// A scale argument from Mesh code; negative scales count as zero.
+fn scale_arg(scale: i64) -> u32 {
+    scale.clamp(0, u32::MAX as i64) as u32
+}
+
+// ── Public API ──────────────────────────────────────────────────────────
+
+/// Decimal.from_int(Int) -> Decimal, at scale 0.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_from_int(value: i64) -> *mut MeshDecimal {
+    alloc_decimal(&Dec::from_big(Big::from_i64(value), 0))
+}
+
+/// Decimal.parse(String) -> Option<Decimal>
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_parse(s: *const MeshString) -> *mut u8 {
+    match Dec::parse(unsafe { (*s).as_str() }) {
+        Some(v) => alloc_option(0, alloc_decimal(&v) as *mut u8) as *mut u8,
+        None => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+    }
+}
+
+/// Decimal.to_string(Decimal) -> String, with exactly `scale` digits after
+/// the point.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_to_string(d: *const MeshDecimal) -> *mut MeshString {
+    mesh_str(&unsafe { dec(d) }.to_string())
+}
+
+/// Decimal.to_float(Decimal) -> Float, the nearest Float to the value.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_to_float(d: *const MeshDecimal) -> f64 {
+    unsafe { dec(d) }.to_f64()
+}
+
+/// Decimal.scale(Decimal) -> Int
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_scale(d: *const MeshDecimal) -> i64 {
+    unsafe { (*d).scale as i64 }
+}
+
+/// Decimal.round(Decimal, Int) -> Decimal: rescale to the given number of
+/// digits after the point, rounding half away from zero.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_round(d: *const MeshDecimal, scale: i64) -> *mut MeshDecimal {
+    alloc_decimal(&unsafe { dec(d) }.round(scale_arg(scale)))
+}
+
+/// `a + b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_add(a: *const MeshDecimal, b: *const MeshDecimal) -> *mut MeshDecimal {
+    unsafe { alloc_decimal(&dec(a).add(&dec(b))) }
+}
+
+/// `a - b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_sub(a: *const MeshDecimal, b: *const MeshDecimal) -> *mut MeshDecimal {
+    unsafe { alloc_decimal(&dec(a).sub(&dec(b))) }
+}
+
+/// `a * b`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_mul(a: *const MeshDecimal, b: *const MeshDecimal) -> *mut MeshDecimal {
+    unsafe { alloc_decimal(&dec(a).mul(&dec(b))) }
+}
+
+/// `a / b`. Crashes on division by zero.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_div(a: *const MeshDecimal, b: *const MeshDecimal) -> *mut MeshDecimal {
+    match unsafe { dec(a).div(&dec(b)) } {
+        Some(q) => alloc_decimal(&q),
+        None => arith_crash("Decimal division by zero"),
+    }
+}
+
+/// `a % b`. Crashes on division by zero.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_mod(a: *const MeshDecimal, b: *const MeshDecimal) -> *mut MeshDecimal {
+    match unsafe { dec(a).rem(&dec(b)) } {
+        Some(r) => alloc_decimal(&r),
+        None => arith_crash("Decimal division by zero"),
+    }
+}
+
+/// `-a`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_neg(a: *const MeshDecimal) -> *mut MeshDecimal {
+    unsafe { alloc_decimal(&dec(a).neg()) }
+}
+
+/// `a == b`, comparing values rather than scales.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_eq(a: *const MeshDecimal, b: *const MeshDecimal) -> i8 {
+    unsafe { (dec(a) == dec(b)) as i8 }
+}
+
+/// Three-way comparison: -1, 0 or 1.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_compare(a: *const MeshDecimal, b: *const MeshDecimal) -> i64 {
+    unsafe { dec(a).cmp(&dec(b)) as i64 }
+}
+
+/// Hash of a Decimal; equal values hash equally whatever their scale.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_hash(a: *const MeshDecimal) -> i64 {
+    let text = unsafe { dec(a) }.normalized().to_string();
+    crate::hash::mesh_hash_string(mesh_str(&text))
+}
+
+/// Encode a Decimal as a JSON string, which keeps every digit and the scale.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_to_json(a: *const MeshDecimal) -> *mut u8 {
+    crate::json::mesh_json_from_string(mesh_decimal_to_string(a) as *const MeshString)
+}
+
+/// Decode a Decimal from a JSON string or integer. Returns MeshResult.
+///
+/// A JSON number with a fraction or exponent is rejected: it was parsed as
+/// an `f64`, which may have rounded it and has dropped its trailing zeros,
+/// so neither its digits nor its scale can be trusted.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_decimal_from_json(json: *mut u8) -> *mut u8 {
+    let json = json as *const MeshJson;
+    if unsafe { (*json).tag } == crate::json::JSON_FLOAT {
+        return alloc_result(1, mesh_str("Decimal JSON number is not exact; encode it as a string") as *mut u8)
+            as *mut u8;
+    }
+    let parsed = unsafe { json_number_text(json) }.and_then(|t| Dec::parse(&t));
+    match parsed {
+        Some(v) => alloc_result(0, alloc_decimal(&v) as *mut u8) as *mut u8,
+        None => alloc_result(1, mesh_str("expected Decimal") as *mut u8) as *mut u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gc::mesh_rt_init;
+    use crate::io::MeshResult;
+    use crate::option::MeshOption;
+
+    fn d(s: &str) -> Dec {
+        Dec::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(d("12.50").to_string(), "12.50");
+        assert_eq!(d("-0.05").to_string(), "-0.05");
+        assert_eq!(d(".5").to_string(), "0.5");
+        assert_eq!(d("-.5").to_string(), "-0.5");
+        assert_eq!(d("7").to_string(), "7");
+        assert_eq!(d("+3.").to_string(), "3");
+        assert_eq!(d("123456789012345678901234.000001").to_string(), "123456789012345678901234.000001");
+        for bad in ["", "-", ".", "1.2.3", "1e5", "--1", "1-", "abc", " 1"] {
+            assert!(Dec::parse(bad).is_none(), "{:?} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_scales() {
+        assert_eq!(d("1.10").add(&d("2.2")).to_string(), "3.30");
+        assert_eq!(d("1").sub(&d("0.01")).to_string(), "0.99");
+        assert_eq!(d("1.5").mul(&d("1.25")).to_string(), "1.875");
+        assert_eq!(d("10.00").div(&d("3")).unwrap().to_string(), "3.33");
+        assert_eq!(d("20.00").div(&d("3")).unwrap().to_string(), "6.67");
+        assert_eq!(d("-20.00").div(&d("3")).unwrap().to_string(), "-6.67");
+        assert_eq!(d("1").div(&d("8")).unwrap().to_string(), "0");
+        assert_eq!(d("1.000").div(&d("8")).unwrap().to_string(), "0.125");
+        assert_eq!(d("7.5").rem(&d("2")).unwrap().to_string(), "1.5");
+        assert!(d("1").div(&d("0.00")).is_none());
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(d("2.345").round(2).to_string(), "2.35");
+        assert_eq!(d("-2.345").round(2).to_string(), "-2.35");
+        assert_eq!(d("2.344").round(2).to_string(), "2.34");
+        assert_eq!(d("0.5").round(0).to_string(), "1");
+        assert_eq!(d("2").round(2).to_string(), "2.00");
+    }
+
+    #[test]
+    fn test_compare_is_numeric() {
+        assert_eq!(d("1.0"), d("1.00"));
+        assert!(d("0.1") > d("0.09"));
+        assert!(d("-1.5") < d("-1.49"));
+        assert_eq!(d("1.50").normalized().to_string(), "1.5");
+        assert_eq!(d("0.000").normalized().to_string(), "0");
+        assert_eq!(d("0.1").to_f64(), 0.1);
+    }
+
+    #[test]
+    fn test_runtime_round_trip() {
+        mesh_rt_init();
+        let price = mesh_decimal_parse(mesh_str("19.99")) as *mut MeshOption;
+        unsafe {
+            assert_eq!((*price).tag, 0);
+            let price = (*price).value as *const MeshDecimal;
+            let total = mesh_decimal_mul(price, mesh_decimal_from_int(3));
+            assert_eq!((*mesh_decimal_to_string(total)).as_str(), "59.97");
+            assert_eq!(mesh_decimal_scale(total), 2);
+            let padded = mesh_decimal_round(price, 4);
+            assert_eq!((*mesh_decimal_to_string(padded)).as_str(), "19.9900");
+            assert_eq!(mesh_decimal_eq(price, padded), 1);
+            assert_eq!(mesh_decimal_hash(price), mesh_decimal_hash(padded));
+            assert_eq!(mesh_decimal_compare(price, total), -1);
+        }
+    }
+
+    #[test]
+    fn test_from_json_keeps_the_scale() {
+        mesh_rt_init();
+        let decode = |text: &str| unsafe {
+            let json = crate::json::mesh_json_parse(mesh_str(text));
+            assert_eq!((*json).tag, 0);
+            mesh_decimal_from_json((*json).value) as *mut MeshResult
+        };
+        unsafe {
+            let quoted = decode("\"12.50\"");
+            assert_eq!((*quoted).tag, 0);
+            assert_eq!((*mesh_decimal_to_string((*quoted).value as *const MeshDecimal)).as_str(), "12.50");
+
+            let whole = decode("12");
+            assert_eq!((*whole).tag, 0);
+            assert_eq!(mesh_decimal_scale((*whole).value as *const MeshDecimal), 0);
+
+            // As a JSON number the trailing zero is gone, so it is rejected.
+            let number = decode("12.50");
+            assert_eq!((*number).tag, 1);
+            assert!((*((*number).value as *const MeshString)).as_str().contains("not exact"));
+        }
+    }
+}
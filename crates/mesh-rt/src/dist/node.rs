@@ -129,7 +129,7 @@ fn function_registry() -> &'static RwLock<FxHashMap<String, FnPtr>> {
 /// Each top-level (non-closure) function is registered so that remote nodes
 /// can spawn it by name.
 #[no_mangle]
-pub extern "C" fn mesh_register_function(name_ptr: *const u8, name_len: u64, fn_ptr: *const u8) {
+pub extern "C-unwind" fn mesh_register_function(name_ptr: *const u8, name_len: u64, fn_ptr: *const u8) {
     if name_ptr.is_null() || fn_ptr.is_null() {
         return;
     }
@@ -2282,7 +2282,7 @@ fn accept_loop(listener: TcpListener, state: &NodeState) {
 /// - `-1` if node already started
 /// - `-2` if TCP bind failed
 #[no_mangle]
-pub extern "C" fn mesh_node_start(
+pub extern "C-unwind" fn mesh_node_start(
     name_ptr: *const u8,
     name_len: u64,
     cookie_ptr: *const u8,
@@ -2378,7 +2378,7 @@ pub extern "C" fn mesh_node_start(
 /// - `-2` if TCP connection failed
 /// - `-3` if handshake failed (wrong cookie, I/O error, or invalid format)
 #[no_mangle]
-pub extern "C" fn mesh_node_connect(
+pub extern "C-unwind" fn mesh_node_connect(
     name_ptr: *const u8,
     name_len: u64,
 ) -> i64 {
@@ -2472,7 +2472,7 @@ pub extern "C" fn mesh_node_connect(
 /// Returns an empty string if node is not started (mesh_node_start not called).
 /// The returned string is GC-allocated via mesh_string_new.
 #[no_mangle]
-pub extern "C" fn mesh_node_self() -> *const u8 {
+pub extern "C-unwind" fn mesh_node_self() -> *const u8 {
     match node_state() {
         Some(state) => {
             crate::string::mesh_string_new(
@@ -2494,7 +2494,7 @@ pub extern "C" fn mesh_node_self() -> *const u8 {
 /// Each element is a GC-allocated Mesh string. The list itself is allocated
 /// via mesh_list_from_array.
 #[no_mangle]
-pub extern "C" fn mesh_node_list() -> *mut u8 {
+pub extern "C-unwind" fn mesh_node_list() -> *mut u8 {
     let state = match node_state() {
         Some(s) => s,
         None => {
@@ -2545,7 +2545,7 @@ pub extern "C" fn mesh_node_list() -> *mut u8 {
 /// - Remote PID (u64) on success
 /// - 0 on failure (not connected, function not found, write error, etc.)
 #[no_mangle]
-pub extern "C" fn mesh_node_spawn(
+pub extern "C-unwind" fn mesh_node_spawn(
     node_ptr: *const u8,
     node_len: u64,
     fn_name_ptr: *const u8,
@@ -186,15 +186,13 @@ pub fn stf_encode(value: u64, type_hint: &StfType, buf: &mut Vec<u8>) -> Result<
 
         StfType::List(elem_type) => {
             buf.push(TAG_LIST);
-            let ptr = value as *const u8;
-            let len = unsafe { *(ptr as *const u64) } as u32;
+            let list = value as *const u8;
+            let len = crate::collections::list::mesh_list_length(list as *mut u8) as u32;
             if len > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(len));
             }
             buf.extend_from_slice(&len.to_le_bytes());
-            let data = unsafe { (ptr as *const u64).add(2) };
-            for i in 0..len as usize {
-                let elem = unsafe { *data.add(i) };
+            for elem in unsafe { crate::collections::list::elems(list) } {
                 stf_encode(elem, elem_type, buf)?;
             }
             Ok(())
@@ -207,15 +205,12 @@ pub fn stf_encode(value: u64, type_hint: &StfType, buf: &mut Vec<u8>) -> Result<
             if len > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(len));
             }
-            // Extract key_type_tag from upper 8 bits of cap field.
-            let key_type_tag = unsafe { (*((ptr as *const u64).add(1))) >> 56 } as u8;
-            buf.push(key_type_tag);
+            let map = ptr as *mut u8;
+            buf.push(crate::collections::map::map_key_type_tag(map));
             buf.extend_from_slice(&len.to_le_bytes());
-            // Entries start at offset 2 words. Each entry is [u64 key, u64 value].
-            let entries = unsafe { (ptr as *const u64).add(2) };
-            for i in 0..len as usize {
-                let key = unsafe { *entries.add(i * 2) };
-                let val = unsafe { *entries.add(i * 2 + 1) };
+            for i in 0..len as i64 {
+                let key = crate::collections::map::mesh_map_entry_key(map, i);
+                let val = crate::collections::map::mesh_map_entry_value(map, i);
                 stf_encode(key, key_type, buf)?;
                 stf_encode(val, val_type, buf)?;
             }
@@ -224,15 +219,14 @@ pub fn stf_encode(value: u64, type_hint: &StfType, buf: &mut Vec<u8>) -> Result<
 
         StfType::Set(elem_type) => {
             buf.push(TAG_SET);
-            let ptr = value as *const u8;
-            let len = unsafe { *(ptr as *const u64) } as u32;
+            let set = value as *mut u8;
+            let len = crate::collections::set::mesh_set_size(set) as u32;
             if len > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(len));
             }
             buf.extend_from_slice(&len.to_le_bytes());
-            let data = unsafe { (ptr as *const u64).add(2) };
-            for i in 0..len as usize {
-                let elem = unsafe { *data.add(i) };
+            for i in 0..len as i64 {
+                let elem = crate::collections::set::mesh_set_element_at(set, i);
                 stf_encode(elem, elem_type, buf)?;
             }
             Ok(())
@@ -457,22 +451,16 @@ pub fn stf_decode(data: &[u8], pos: &mut usize) -> Result<(u64, StfType), StfErr
             if count > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(count));
             }
-            // Allocate list: { len: u64, cap: u64, data: [u64; count] }
-            let total = 16 + (count as usize) * 8;
-            let ptr = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-            unsafe {
-                *(ptr as *mut u64) = count as u64;       // len
-                *((ptr as *mut u64).add(1)) = count as u64; // cap
-            }
-            let data_ptr = unsafe { (ptr as *mut u64).add(2) };
+            let mut elems = Vec::with_capacity(count as usize);
             let mut elem_type = StfType::Unit;
             for i in 0..count as usize {
                 let (val, et) = stf_decode(data, pos)?;
-                unsafe { *data_ptr.add(i) = val; }
+                elems.push(val);
                 if i == 0 {
                     elem_type = et;
                 }
             }
+            let ptr = unsafe { crate::collections::list::list_from_slice(&elems) };
             Ok((ptr as u64, StfType::List(Box::new(elem_type))))
         }
 
@@ -482,30 +470,21 @@ pub fn stf_decode(data: &[u8], pos: &mut usize) -> Result<(u64, StfType), StfErr
             if count > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(count));
             }
-            // Allocate map: { len: u64, cap|key_type: u64, entries: [(u64,u64); count] }
-            let total = 16 + (count as usize) * 16;
-            let ptr = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-            unsafe {
-                *(ptr as *mut u64) = count as u64; // len
-                // Store cap with key_type_tag in upper 8 bits.
-                *((ptr as *mut u64).add(1)) = ((key_type_tag as u64) << 56) | (count as u64);
-            }
-            let entries_ptr = unsafe { (ptr as *mut u64).add(2) };
+            // Hashed key callbacks are not sent; codegen re-tags the map
+            // with its own callbacks on the first key operation.
+            let mut map = crate::collections::map::mesh_map_new_typed(key_type_tag as i64);
             let mut kt = StfType::Int;
             let mut vt = StfType::Unit;
             for i in 0..count as usize {
                 let (key, key_t) = stf_decode(data, pos)?;
                 let (val, val_t) = stf_decode(data, pos)?;
-                unsafe {
-                    *entries_ptr.add(i * 2) = key;
-                    *entries_ptr.add(i * 2 + 1) = val;
-                }
+                map = crate::collections::map::mesh_map_put(map, key, val);
                 if i == 0 {
                     kt = key_t;
                     vt = val_t;
                 }
             }
-            Ok((ptr as u64, StfType::Map(Box::new(kt), Box::new(vt))))
+            Ok((map as u64, StfType::Map(Box::new(kt), Box::new(vt))))
         }
 
         TAG_SET => {
@@ -513,22 +492,22 @@ pub fn stf_decode(data: &[u8], pos: &mut usize) -> Result<(u64, StfType), StfErr
             if count > MAX_COLLECTION_LEN {
                 return Err(StfError::PayloadTooLarge(count));
             }
-            // Allocate set: { len: u64, cap: u64, data: [u64; count] }
-            let total = 16 + (count as usize) * 8;
-            let ptr = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-            unsafe {
-                *(ptr as *mut u64) = count as u64;       // len
-                *((ptr as *mut u64).add(1)) = count as u64; // cap
-            }
-            let data_ptr = unsafe { (ptr as *mut u64).add(2) };
+            // Hashed element callbacks are not sent; codegen re-tags the set
+            // with its own callbacks on the first element operation. Strings
+            // compare by contents, everything else by raw value.
+            let mut set = crate::collections::set::mesh_set_new();
             let mut elem_type = StfType::Unit;
             for i in 0..count as usize {
                 let (val, et) = stf_decode(data, pos)?;
-                unsafe { *data_ptr.add(i) = val; }
                 if i == 0 {
+                    if et == StfType::String {
+                        set = crate::collections::map::mesh_map_tag_string(set);
+                    }
                     elem_type = et;
                 }
+                set = crate::collections::set::mesh_set_add(set, val);
             }
+            let ptr = set;
             Ok((ptr as u64, StfType::Set(Box::new(elem_type))))
         }
 
@@ -652,6 +631,15 @@ pub fn stf_decode_value(data: &[u8]) -> Result<(u64, StfType), StfError> {
 mod tests {
     use super::*;
     use crate::gc::mesh_rt_init;
+    use crate::collections::map::{
+        mesh_map_entry_key, mesh_map_entry_value, mesh_map_get, mesh_map_new, mesh_map_put, mesh_map_size,
+    };
+    use crate::collections::list::{
+        mesh_list_append, mesh_list_from_array, mesh_list_get, mesh_list_length, mesh_list_new,
+    };
+    use crate::collections::set::{
+        mesh_set_add, mesh_set_contains, mesh_set_element_at, mesh_set_new, mesh_set_size,
+    };
 
     #[test]
     fn test_int_roundtrip() {
@@ -775,34 +763,15 @@ mod tests {
 
     /// Helper: allocate a list of i64 values on the GC heap.
     fn alloc_list_of_ints(values: &[i64]) -> *mut u8 {
-        let count = values.len() as u64;
-        let total = 16 + (count as usize) * 8; // header + data
-        let ptr = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-        unsafe {
-            *(ptr as *mut u64) = count;           // len
-            *((ptr as *mut u64).add(1)) = count;  // cap
-            let data = (ptr as *mut u64).add(2);
-            for (i, &v) in values.iter().enumerate() {
-                *data.add(i) = v as u64;
-            }
-        }
-        ptr
+        let elems: Vec<u64> = values.iter().map(|&v| v as u64).collect();
+        mesh_list_from_array(elems.as_ptr(), elems.len() as i64)
     }
 
     /// Helper: allocate a set of i64 values on the GC heap.
     fn alloc_set_of_ints(values: &[i64]) -> *mut u8 {
-        let count = values.len() as u64;
-        let total = 16 + (count as usize) * 8;
-        let ptr = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-        unsafe {
-            *(ptr as *mut u64) = count;           // len
-            *((ptr as *mut u64).add(1)) = count;  // cap
-            let data = (ptr as *mut u64).add(2);
-            for (i, &v) in values.iter().enumerate() {
-                *data.add(i) = v as u64;
-            }
-        }
-        ptr
+        values
+            .iter()
+            .fold(mesh_set_new(), |set, &v| mesh_set_add(set, v as u64))
     }
 
     #[test]
@@ -813,15 +782,11 @@ mod tests {
         let encoded = stf_encode_value(list as u64, &ty).unwrap();
         let (decoded_ptr, decoded_type) = stf_decode_value(&encoded).unwrap();
         assert_eq!(decoded_type, StfType::List(Box::new(StfType::Int)));
-        unsafe {
-            let ptr = decoded_ptr as *const u64;
-            let len = *ptr;
-            assert_eq!(len, 3);
-            let data = ptr.add(2);
-            assert_eq!(*data as i64, 10);
-            assert_eq!(*data.add(1) as i64, 20);
-            assert_eq!(*data.add(2) as i64, 30);
-        }
+        let decoded = decoded_ptr as *mut u8;
+        assert_eq!(mesh_list_length(decoded), 3);
+        assert_eq!(mesh_list_get(decoded, 0) as i64, 10);
+        assert_eq!(mesh_list_get(decoded, 1) as i64, 20);
+        assert_eq!(mesh_list_get(decoded, 2) as i64, 30);
     }
 
     #[test]
@@ -829,23 +794,16 @@ mod tests {
         mesh_rt_init();
         let s1 = mesh_string_new("hello".as_ptr(), 5);
         let s2 = mesh_string_new("world".as_ptr(), 5);
-        // Allocate list of 2 string pointers.
-        let list = crate::gc::mesh_gc_alloc_actor(16 + 2 * 8, 8);
-        unsafe {
-            *(list as *mut u64) = 2;                       // len
-            *((list as *mut u64).add(1)) = 2;              // cap
-            *((list as *mut u64).add(2)) = s1 as u64;      // data[0]
-            *((list as *mut u64).add(3)) = s2 as u64;      // data[1]
-        }
+        let list = mesh_list_append(mesh_list_append(mesh_list_new(), s1 as u64), s2 as u64);
         let ty = StfType::List(Box::new(StfType::String));
         let encoded = stf_encode_value(list as u64, &ty).unwrap();
         let (decoded_ptr, decoded_type) = stf_decode_value(&encoded).unwrap();
         assert_eq!(decoded_type, StfType::List(Box::new(StfType::String)));
+        let decoded = decoded_ptr as *mut u8;
+        assert_eq!(mesh_list_length(decoded), 2);
         unsafe {
-            let ptr = decoded_ptr as *const u64;
-            assert_eq!(*ptr, 2); // len
-            let d0 = *ptr.add(2) as *const MeshString;
-            let d1 = *ptr.add(3) as *const MeshString;
+            let d0 = mesh_list_get(decoded, 0) as *const MeshString;
+            let d1 = mesh_list_get(decoded, 1) as *const MeshString;
             assert_eq!((*d0).as_str(), "hello");
             assert_eq!((*d1).as_str(), "world");
         }
@@ -857,30 +815,20 @@ mod tests {
         // Create a map with 2 int->string entries.
         let v1 = mesh_string_new("alpha".as_ptr(), 5);
         let v2 = mesh_string_new("beta".as_ptr(), 4);
-        // Map layout: { len, cap|key_type, entries: [(key, val), ...] }
-        let total = 16 + 2 * 16; // header + 2 entries of 16 bytes
-        let map = crate::gc::mesh_gc_alloc_actor(total as u64, 8);
-        unsafe {
-            *(map as *mut u64) = 2;                          // len
-            *((map as *mut u64).add(1)) = (0u64 << 56) | 2; // key_type=0 (int), cap=2
-            let entries = (map as *mut u64).add(2);
-            *entries = 1;                          // key[0]
-            *entries.add(1) = v1 as u64;           // val[0]
-            *entries.add(2) = 2;                   // key[1]
-            *entries.add(3) = v2 as u64;           // val[1]
-        }
+        let map = mesh_map_new();
+        let map = mesh_map_put(map, 1, v1 as u64);
+        let map = mesh_map_put(map, 2, v2 as u64);
         let ty = StfType::Map(Box::new(StfType::Int), Box::new(StfType::String));
         let encoded = stf_encode_value(map as u64, &ty).unwrap();
         let (decoded_ptr, decoded_type) = stf_decode_value(&encoded).unwrap();
         assert_eq!(decoded_type, StfType::Map(Box::new(StfType::Int), Box::new(StfType::String)));
+        let decoded = decoded_ptr as *mut u8;
+        assert_eq!(mesh_map_size(decoded), 2);
+        assert_eq!(mesh_map_entry_key(decoded, 0), 1);
+        assert_eq!(mesh_map_entry_key(decoded, 1), 2);
         unsafe {
-            let ptr = decoded_ptr as *const u64;
-            assert_eq!(*ptr, 2); // len
-            let entries = ptr.add(2);
-            assert_eq!(*entries, 1);       // key[0]
-            assert_eq!(*entries.add(2), 2); // key[1]
-            let dv0 = *entries.add(1) as *const MeshString;
-            let dv1 = *entries.add(3) as *const MeshString;
+            let dv0 = mesh_map_entry_value(decoded, 0) as *const MeshString;
+            let dv1 = mesh_map_entry_value(decoded, 1) as *const MeshString;
             assert_eq!((*dv0).as_str(), "alpha");
             assert_eq!((*dv1).as_str(), "beta");
         }
@@ -894,14 +842,28 @@ mod tests {
         let encoded = stf_encode_value(set as u64, &ty).unwrap();
         let (decoded_ptr, decoded_type) = stf_decode_value(&encoded).unwrap();
         assert_eq!(decoded_type, StfType::Set(Box::new(StfType::Int)));
-        unsafe {
-            let ptr = decoded_ptr as *const u64;
-            assert_eq!(*ptr, 3); // len
-            let data = ptr.add(2);
-            assert_eq!(*data as i64, 100);
-            assert_eq!(*data.add(1) as i64, 200);
-            assert_eq!(*data.add(2) as i64, 300);
-        }
+        let set = decoded_ptr as *mut u8;
+        assert_eq!(mesh_set_size(set), 3);
+        assert_eq!(mesh_set_element_at(set, 0) as i64, 100);
+        assert_eq!(mesh_set_element_at(set, 1) as i64, 200);
+        assert_eq!(mesh_set_element_at(set, 2) as i64, 300);
+    }
+
+    #[test]
+    fn test_set_of_strings_roundtrip() {
+        mesh_rt_init();
+        let s = |text: &str| mesh_string_new(text.as_ptr(), text.len() as u64) as u64;
+        let set = crate::collections::map::mesh_map_tag_string(mesh_set_new());
+        let set = mesh_set_add(mesh_set_add(set, s("red")), s("blue"));
+        let ty = StfType::Set(Box::new(StfType::String));
+        let encoded = stf_encode_value(set as u64, &ty).unwrap();
+        let (decoded_ptr, decoded_type) = stf_decode_value(&encoded).unwrap();
+        assert_eq!(decoded_type, ty);
+        // Fresh Strings with the same contents are found, not added again.
+        let set = decoded_ptr as *mut u8;
+        assert_eq!(mesh_set_contains(set, s("blue")), 1);
+        assert_eq!(mesh_set_size(mesh_set_add(set, s("red"))), 2);
+        assert_eq!(mesh_set_size(mesh_set_add(set, s("green"))), 3);
     }
 
     #[test]
@@ -1046,77 +1008,45 @@ mod tests {
         let inner1 = alloc_list_of_ints(&[1, 2]);
         let inner2 = alloc_list_of_ints(&[3, 4, 5]);
         // Outer list holds 2 list pointers.
-        let outer = crate::gc::mesh_gc_alloc_actor(16 + 2 * 8, 8);
-        unsafe {
-            *(outer as *mut u64) = 2;
-            *((outer as *mut u64).add(1)) = 2;
-            *((outer as *mut u64).add(2)) = inner1 as u64;
-            *((outer as *mut u64).add(3)) = inner2 as u64;
-        }
+        let outer = mesh_list_append(mesh_list_append(mesh_list_new(), inner1 as u64), inner2 as u64);
         let ty = StfType::List(Box::new(StfType::List(Box::new(StfType::Int))));
         let encoded = stf_encode_value(outer as u64, &ty).unwrap();
         let (decoded_ptr, _) = stf_decode_value(&encoded).unwrap();
-        unsafe {
-            let ptr = decoded_ptr as *const u64;
-            assert_eq!(*ptr, 2); // outer len
-            let d0 = *ptr.add(2) as *const u64; // inner list 0
-            let d1 = *ptr.add(3) as *const u64; // inner list 1
-            assert_eq!(*d0, 2); // inner list 0 len
-            assert_eq!(*d0.add(2) as i64, 1);
-            assert_eq!(*d0.add(3) as i64, 2);
-            assert_eq!(*d1, 3); // inner list 1 len
-            assert_eq!(*d1.add(2) as i64, 3);
-            assert_eq!(*d1.add(3) as i64, 4);
-            assert_eq!(*d1.add(4) as i64, 5);
-        }
+        let decoded = decoded_ptr as *mut u8;
+        assert_eq!(mesh_list_length(decoded), 2); // outer len
+        let d0 = mesh_list_get(decoded, 0) as *mut u8; // inner list 0
+        let d1 = mesh_list_get(decoded, 1) as *mut u8; // inner list 1
+        assert_eq!(mesh_list_length(d0), 2);
+        assert_eq!(mesh_list_get(d0, 0) as i64, 1);
+        assert_eq!(mesh_list_get(d0, 1) as i64, 2);
+        assert_eq!(mesh_list_length(d1), 3);
+        assert_eq!(mesh_list_get(d1, 0) as i64, 3);
+        assert_eq!(mesh_list_get(d1, 1) as i64, 4);
+        assert_eq!(mesh_list_get(d1, 2) as i64, 5);
     }
 
     #[test]
     fn test_list_of_maps() {
         mesh_rt_init();
-        // Create a map with 1 int->int entry: {10 => 20}
-        let map1 = crate::gc::mesh_gc_alloc_actor(16 + 1 * 16, 8);
-        unsafe {
-            *(map1 as *mut u64) = 1;                          // len
-            *((map1 as *mut u64).add(1)) = (0u64 << 56) | 1; // key_type=0, cap=1
-            *((map1 as *mut u64).add(2)) = 10;                // key
-            *((map1 as *mut u64).add(3)) = 20;                // val
-        }
-        // Create a map with 1 int->int entry: {30 => 40}
-        let map2 = crate::gc::mesh_gc_alloc_actor(16 + 1 * 16, 8);
-        unsafe {
-            *(map2 as *mut u64) = 1;
-            *((map2 as *mut u64).add(1)) = (0u64 << 56) | 1;
-            *((map2 as *mut u64).add(2)) = 30;
-            *((map2 as *mut u64).add(3)) = 40;
-        }
+        let map1 = mesh_map_put(mesh_map_new(), 10, 20);
+        let map2 = mesh_map_put(mesh_map_new(), 30, 40);
         // Outer list of 2 maps.
-        let outer = crate::gc::mesh_gc_alloc_actor(16 + 2 * 8, 8);
-        unsafe {
-            *(outer as *mut u64) = 2;
-            *((outer as *mut u64).add(1)) = 2;
-            *((outer as *mut u64).add(2)) = map1 as u64;
-            *((outer as *mut u64).add(3)) = map2 as u64;
-        }
+        let outer = mesh_list_append(mesh_list_append(mesh_list_new(), map1 as u64), map2 as u64);
         let ty = StfType::List(Box::new(
             StfType::Map(Box::new(StfType::Int), Box::new(StfType::Int)),
         ));
         let encoded = stf_encode_value(outer as u64, &ty).unwrap();
         let (decoded_ptr, _) = stf_decode_value(&encoded).unwrap();
-        unsafe {
-            let ptr = decoded_ptr as *const u64;
-            assert_eq!(*ptr, 2); // outer len
-            // Check first decoded map
-            let dm0 = *ptr.add(2) as *const u64;
-            assert_eq!(*dm0, 1); // map0 len
-            assert_eq!(*dm0.add(2), 10);  // key
-            assert_eq!(*dm0.add(3), 20);  // val
-            // Check second decoded map
-            let dm1 = *ptr.add(3) as *const u64;
-            assert_eq!(*dm1, 1); // map1 len
-            assert_eq!(*dm1.add(2), 30);
-            assert_eq!(*dm1.add(3), 40);
-        }
+        let decoded = decoded_ptr as *mut u8;
+        assert_eq!(mesh_list_length(decoded), 2); // outer len
+        // Check first decoded map
+        let dm0 = mesh_list_get(decoded, 0) as *mut u8;
+        assert_eq!(mesh_map_size(dm0), 1);
+        assert_eq!(mesh_map_get(dm0, 10), 20);
+        // Check second decoded map
+        let dm1 = mesh_list_get(decoded, 1) as *mut u8;
+        assert_eq!(mesh_map_size(dm1), 1);
+        assert_eq!(mesh_map_get(dm1, 30), 40);
     }
 
     // ── Error condition tests ─────────────────────────────────────
@@ -1160,24 +1090,16 @@ mod tests {
         let ty = StfType::List(Box::new(StfType::Int));
         let encoded = stf_encode_value(list as u64, &ty).unwrap();
         let (decoded_ptr, _) = stf_decode_value(&encoded).unwrap();
-        unsafe {
-            assert_eq!(*(decoded_ptr as *const u64), 0);
-        }
+        assert_eq!(mesh_list_length(decoded_ptr as *mut u8), 0);
     }
 
     #[test]
     fn test_empty_map_roundtrip() {
         mesh_rt_init();
-        let map = crate::gc::mesh_gc_alloc_actor(16, 8);
-        unsafe {
-            *(map as *mut u64) = 0;
-            *((map as *mut u64).add(1)) = 0;
-        }
+        let map = mesh_map_new();
         let ty = StfType::Map(Box::new(StfType::Int), Box::new(StfType::Int));
         let encoded = stf_encode_value(map as u64, &ty).unwrap();
         let (decoded_ptr, _) = stf_decode_value(&encoded).unwrap();
-        unsafe {
-            assert_eq!(*(decoded_ptr as *const u64), 0);
-        }
+        assert_eq!(mesh_map_size(decoded_ptr as *mut u8), 0);
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! Provides `Env.get(key)` and `Env.args()` for Mesh programs.
 
-use crate::gc::mesh_gc_alloc_actor;
+use crate::collections::list::{mesh_list_builder_new, mesh_list_builder_push};
 use crate::option::{MeshOption, alloc_option};
 use crate::string::{mesh_string_new, MeshString};
 
@@ -10,7 +10,7 @@ use crate::string::{mesh_string_new, MeshString};
 /// - tag 0, value = MeshString if the variable exists (Some)
 /// - tag 1, value = null if the variable does not exist (None)
 #[no_mangle]
-pub extern "C" fn mesh_env_get(key: *const MeshString) -> *mut MeshOption {
+pub extern "C-unwind" fn mesh_env_get(key: *const MeshString) -> *mut MeshOption {
     unsafe {
         let key_str = (*key).as_str();
         match std::env::var(key_str) {
@@ -23,32 +23,20 @@ pub extern "C" fn mesh_env_get(key: *const MeshString) -> *mut MeshOption {
     }
 }
 
-/// Return CLI arguments as a packed array of MeshString pointers.
+/// Return CLI arguments as a `List<String>`.
 ///
-/// Layout: `[u64 count, *mut MeshString arg0, *mut MeshString arg1, ...]`
-///
-/// This temporary representation will be replaced by proper List<String>
-/// in Plan 02 when the List type is implemented.
+/// The first element is the program path (argv[0]), followed by the
+/// arguments the program was invoked with (e.g. everything after `--` in
+/// `meshc run app -- a b`).
 #[no_mangle]
-pub extern "C" fn mesh_env_args() -> *mut u8 {
+pub extern "C-unwind" fn mesh_env_args() -> *mut u8 {
     let args: Vec<String> = std::env::args().collect();
-    let count = args.len();
-    // Layout: u64 count + count * pointer-sized entries
-    let ptr_size = std::mem::size_of::<*mut MeshString>();
-    let total_size = std::mem::size_of::<u64>() + count * ptr_size;
-
-    unsafe {
-        let buf = mesh_gc_alloc_actor(total_size as u64, 8);
-        // Write count
-        *(buf as *mut u64) = count as u64;
-        // Write string pointers
-        let ptrs = buf.add(std::mem::size_of::<u64>()) as *mut *mut MeshString;
-        for (i, arg) in args.iter().enumerate() {
-            let s = mesh_string_new(arg.as_ptr(), arg.len() as u64);
-            *ptrs.add(i) = s;
-        }
-        buf
+    let list = mesh_list_builder_new(args.len() as i64);
+    for arg in &args {
+        let s = mesh_string_new(arg.as_ptr(), arg.len() as u64);
+        mesh_list_builder_push(list, s as u64);
     }
+    list
 }
 
 #[cfg(test)]
@@ -87,11 +75,13 @@ mod tests {
     #[test]
     fn test_env_args() {
         mesh_rt_init();
-        let buf = mesh_env_args();
+        let list = mesh_env_args();
+        let count = crate::collections::list::mesh_list_length(list);
+        // There should be at least 1 arg (the test binary itself)
+        assert!(count >= 1, "expected at least 1 arg, got {}", count);
         unsafe {
-            let count = *(buf as *const u64);
-            // There should be at least 1 arg (the test binary itself)
-            assert!(count >= 1, "expected at least 1 arg, got {}", count);
+            let first = crate::collections::list::mesh_list_get(list, 0) as *const MeshString;
+            assert!(!(*first).as_str().is_empty());
         }
     }
 }
@@ -3,11 +3,12 @@
 //! Provides file read, write, append, exists, and delete operations.
 //! All fallible operations return MeshResult (tag 0 = Ok, tag 1 = Err).
 
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
 
 use crate::gc::mesh_gc_alloc_actor;
 use crate::io::MeshResult;
+use crate::iodata::{write_iodata, MeshIoData};
 use crate::string::{mesh_string_new, MeshString};
 
 /// Allocate a MeshResult on the GC heap.
@@ -35,7 +36,7 @@ fn err_result(msg: &str) -> *mut MeshResult {
 /// - tag 0 (Ok): value = pointer to MeshString containing file contents
 /// - tag 1 (Err): value = pointer to MeshString containing error message
 #[no_mangle]
-pub extern "C" fn mesh_file_read(path: *const MeshString) -> *mut MeshResult {
+pub extern "C-unwind" fn mesh_file_read(path: *const MeshString) -> *mut MeshResult {
     unsafe {
         let path_str = (*path).as_str();
         match fs::read_to_string(path_str) {
@@ -54,7 +55,7 @@ pub extern "C" fn mesh_file_read(path: *const MeshString) -> *mut MeshResult {
 /// - tag 0 (Ok): value = null (Unit payload)
 /// - tag 1 (Err): value = pointer to MeshString containing error message
 #[no_mangle]
-pub extern "C" fn mesh_file_write(
+pub extern "C-unwind" fn mesh_file_write(
     path: *const MeshString,
     content: *const MeshString,
 ) -> *mut MeshResult {
@@ -74,7 +75,7 @@ pub extern "C" fn mesh_file_write(
 /// - tag 0 (Ok): value = null (Unit payload)
 /// - tag 1 (Err): value = pointer to MeshString containing error message
 #[no_mangle]
-pub extern "C" fn mesh_file_append(
+pub extern "C-unwind" fn mesh_file_append(
     path: *const MeshString,
     content: *const MeshString,
 ) -> *mut MeshResult {
@@ -95,11 +96,44 @@ pub extern "C" fn mesh_file_append(
     }
 }
 
+/// Write the chunks of an IoData to an open file through a buffer.
+fn write_iodata_to(file: std::io::Result<File>, content: *const MeshIoData) -> *mut MeshResult {
+    let written = file.and_then(|file| {
+        let mut out = BufWriter::new(file);
+        unsafe { write_iodata(content, &mut out)? };
+        out.flush()
+    });
+    match written {
+        Ok(()) => alloc_result(0, std::ptr::null_mut()),
+        Err(e) => err_result(&e.to_string()),
+    }
+}
+
+/// `File.write` with IoData content, flattened as it is written.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_file_write_iodata(
+    path: *const MeshString,
+    content: *const MeshIoData,
+) -> *mut MeshResult {
+    let path_str = unsafe { (*path).as_str() };
+    write_iodata_to(File::create(path_str), content)
+}
+
+/// `File.append` with IoData content, flattened as it is written.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_file_append_iodata(
+    path: *const MeshString,
+    content: *const MeshIoData,
+) -> *mut MeshResult {
+    let path_str = unsafe { (*path).as_str() };
+    write_iodata_to(OpenOptions::new().append(true).create(true).open(path_str), content)
+}
+
 /// Check if a file exists at the given path.
 ///
 /// Returns 1 if the file exists, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_file_exists(path: *const MeshString) -> i8 {
+pub extern "C-unwind" fn mesh_file_exists(path: *const MeshString) -> i8 {
     unsafe {
         let path_str = (*path).as_str();
         if std::path::Path::new(path_str).exists() {
@@ -116,7 +150,7 @@ pub extern "C" fn mesh_file_exists(path: *const MeshString) -> i8 {
 /// - tag 0 (Ok): value = null (Unit payload)
 /// - tag 1 (Err): value = pointer to MeshString containing error message
 #[no_mangle]
-pub extern "C" fn mesh_file_delete(path: *const MeshString) -> *mut MeshResult {
+pub extern "C-unwind" fn mesh_file_delete(path: *const MeshString) -> *mut MeshResult {
     unsafe {
         let path_str = (*path).as_str();
         match fs::remove_file(path_str) {
@@ -160,6 +194,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_file_write_and_append_iodata() {
+        use crate::iodata::{mesh_iodata_append, mesh_iodata_new};
+
+        mesh_rt_init();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rows.csv");
+        let path_mesh = make_string(path.to_str().unwrap());
+
+        let mut rows = mesh_iodata_new();
+        for row in ["id,name\n", "1,ada\n"] {
+            rows = mesh_iodata_append(rows, make_string(row));
+        }
+        unsafe {
+            assert_eq!((*mesh_file_write_iodata(path_mesh, rows)).tag, 0);
+            assert_eq!((*mesh_file_append_iodata(path_mesh, rows)).tag, 0);
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "id,name\n1,ada\nid,name\n1,ada\n");
+    }
+
     #[test]
     fn test_file_read_nonexistent() {
         mesh_rt_init();
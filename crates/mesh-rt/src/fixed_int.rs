@@ -0,0 +1,125 @@
+//! Runtime support for Mesh's fixed-width integer types.
+//!
+//! `Int8`, `Int16`, `Int32`, `UInt8`, `UInt16`, `UInt32` and `UInt64` are all
+//! carried as an `i64`. A value is kept normalized: the `i64` always holds the
+//! number itself (sign-extended for the signed types, zero-extended for the
+//! unsigned ones), so equality, hashing and the signed comparisons of the
+//! narrow types work on the raw word. The compiler calls the `from_int`
+//! function of the type after every arithmetic operation to wrap the result
+//! back into range.
+//!
+//! `UInt64` uses every bit of the word, so it needs its own division,
+//! remainder, comparison and formatting.
+
+use crate::option::alloc_option;
+use crate::string::{mesh_string_new, MeshString};
+
+macro_rules! fixed_int_conversions {
+    ($rust_ty:ty, $name:literal, $from_int:ident, $try_from_int:ident) => {
+        #[doc = concat!("Wrap an Int into `", $name, "` (two's complement truncation).")]
+        #[no_mangle]
+        pub extern "C-unwind" fn $from_int(value: i64) -> i64 {
+            value as $rust_ty as i64
+        }
+
+        #[doc = concat!("Convert an Int to `", $name, "`, returning None when it is out of range.")]
+        #[no_mangle]
+        pub extern "C-unwind" fn $try_from_int(value: i64) -> *mut u8 {
+            match <$rust_ty>::try_from(value) {
+                Ok(v) => alloc_option(0, v as i64 as u64 as *mut u8) as *mut u8,
+                Err(_) => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+            }
+        }
+    };
+}
+
+fixed_int_conversions!(i8, "Int8", mesh_int8_from_int, mesh_int8_try_from_int);
+fixed_int_conversions!(i16, "Int16", mesh_int16_from_int, mesh_int16_try_from_int);
+fixed_int_conversions!(i32, "Int32", mesh_int32_from_int, mesh_int32_try_from_int);
+fixed_int_conversions!(u8, "UInt8", mesh_uint8_from_int, mesh_uint8_try_from_int);
+fixed_int_conversions!(u16, "UInt16", mesh_uint16_from_int, mesh_uint16_try_from_int);
+fixed_int_conversions!(u32, "UInt32", mesh_uint32_from_int, mesh_uint32_try_from_int);
+fixed_int_conversions!(u64, "UInt64", mesh_uint64_from_int, mesh_uint64_try_from_int);
+
+/// Widen any fixed-width integer to Int.
+///
+/// Normalized values already are their Int value, so this is the identity;
+/// a `UInt64` above `Int`'s maximum comes back negative.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_fixed_int_to_int(value: i64) -> i64 {
+    value
+}
+
+/// Unsigned division of two `UInt64` values.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_uint64_div(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        panic!("mesh_uint64_div: division by zero");
+    }
+    ((a as u64) / (b as u64)) as i64
+}
+
+/// Unsigned remainder of two `UInt64` values.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_uint64_rem(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        panic!("mesh_uint64_rem: division by zero");
+    }
+    ((a as u64) % (b as u64)) as i64
+}
+
+/// Unsigned `a < b` for two `UInt64` values.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_uint64_lt(a: i64, b: i64) -> i8 {
+    ((a as u64) < (b as u64)) as i8
+}
+
+/// Format a `UInt64` value in decimal.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_uint64_to_string(value: i64) -> *mut MeshString {
+    let s = (value as u64).to_string();
+    mesh_string_new(s.as_ptr(), s.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gc::mesh_rt_init;
+    use crate::option::MeshOption;
+
+    fn option_value(opt: *mut u8) -> Option<i64> {
+        let opt = opt as *mut MeshOption;
+        unsafe { ((*opt).tag == 0).then(|| (*opt).value as u64 as i64) }
+    }
+
+    #[test]
+    fn test_from_int_wraps() {
+        assert_eq!(mesh_uint8_from_int(256), 0);
+        assert_eq!(mesh_uint8_from_int(-1), 255);
+        assert_eq!(mesh_int8_from_int(128), -128);
+        assert_eq!(mesh_int16_from_int(40000), -25536);
+        assert_eq!(mesh_uint32_from_int(1 << 32), 0);
+        assert_eq!(mesh_uint64_from_int(-1), -1);
+    }
+
+    #[test]
+    fn test_try_from_int() {
+        mesh_rt_init();
+        assert_eq!(option_value(mesh_uint8_try_from_int(255)), Some(255));
+        assert_eq!(option_value(mesh_uint8_try_from_int(256)), None);
+        assert_eq!(option_value(mesh_int32_try_from_int(-5)), Some(-5));
+        assert_eq!(option_value(mesh_uint64_try_from_int(-1)), None);
+    }
+
+    #[test]
+    fn test_uint64_ops() {
+        mesh_rt_init();
+        let max = -1i64; // u64::MAX
+        assert_eq!(mesh_uint64_div(max, 2), i64::MAX);
+        assert_eq!(mesh_uint64_rem(max, 10), 5);
+        assert_eq!(mesh_uint64_lt(1, max), 1);
+        assert_eq!(mesh_uint64_lt(max, 1), 0);
+        let s = mesh_uint64_to_string(max);
+        assert_eq!(unsafe { (*s).as_str() }, "18446744073709551615");
+    }
+}
@@ -0,0 +1,240 @@
+//! Format specifiers for `${value:spec}` interpolation and `String.format`.
+//!
+//! The grammar lives in [`mesh_common::format_spec`]. The compiler has
+//! already checked an interpolation's spec against the value's type, so the
+//! `mesh_format_*` functions it calls trust theirs. `String.format` takes its
+//! template at runtime and reports problems as an `Err`.
+
+use mesh_common::format_spec::{Align, FormatSpec};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::collections::list::list_to_vec;
+use crate::io::alloc_result;
+use crate::string::{mesh_string_new, MeshString};
+
+/// Parse a spec the compiler has already validated.
+fn compiled_spec(spec: *const MeshString) -> FormatSpec {
+    unsafe { FormatSpec::parse((*spec).as_str()).unwrap_or_default() }
+}
+
+fn new_str(text: &str) -> *mut MeshString {
+    mesh_string_new(text.as_ptr(), text.len() as u64)
+}
+
+/// Pad `body` to the spec's width. `numeric` values align right by default
+/// and take `0` padding between their sign or prefix and their digits.
+fn pad(body: String, spec: &FormatSpec, numeric: bool) -> String {
+    let len = body.graphemes(true).count();
+    if len >= spec.width {
+        return body;
+    }
+    let missing = spec.width - len;
+    if spec.zero && numeric {
+        let sign = body.len() - body.trim_start_matches(['+', '-']).len();
+        return format!("{}{}{}", &body[..sign], "0".repeat(missing), &body[sign..]);
+    }
+    let fill = |n: usize| spec.fill.to_string().repeat(n);
+    let align = spec.align.unwrap_or(if numeric { Align::Right } else { Align::Left });
+    match align {
+        Align::Left => body + &fill(missing),
+        Align::Right => fill(missing) + &body,
+        Align::Center => fill(missing / 2) + &body + &fill(missing - missing / 2),
+    }
+}
+
+fn signed(negative: bool, digits: String, spec: &FormatSpec) -> String {
+    let sign = if negative { "-" } else if spec.plus { "+" } else { "" };
+    format!("{}{}", sign, digits)
+}
+
+fn render_int(value: i128, spec: &FormatSpec) -> String {
+    let n = value.unsigned_abs();
+    let digits = match spec.kind {
+        Some('x') => format!("{:x}", n),
+        Some('X') => format!("{:X}", n),
+        Some('o') => format!("{:o}", n),
+        Some('b') => format!("{:b}", n),
+        _ => n.to_string(),
+    };
+    pad(signed(value < 0, digits, spec), spec, true)
+}
+
+fn render_float(value: f64, spec: &FormatSpec) -> String {
+    let magnitude = value.abs();
+    let digits = match (spec.kind, spec.precision) {
+        (Some('e'), Some(p)) => format!("{:.*e}", p, magnitude),
+        (Some('e'), None) => format!("{:e}", magnitude),
+        (_, Some(p)) => format!("{:.*}", p, magnitude),
+        _ => magnitude.to_string(),
+    };
+    pad(signed(value.is_sign_negative() && !value.is_nan(), digits, spec), spec, true)
+}
+
+fn render_str(value: &str, spec: &FormatSpec) -> String {
+    let body = match spec.precision {
+        Some(p) => value.graphemes(true).take(p).collect(),
+        None => value.to_string(),
+    };
+    pad(body, spec, false)
+}
+
+/// Format an Int (or a narrower fixed-width integer) with a spec.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_format_int(value: i64, spec: *const MeshString) -> *mut MeshString {
+    new_str(&render_int(value as i128, &compiled_spec(spec)))
+}
+
+/// Format a `UInt64` with a spec, reading the word as unsigned.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_format_uint64(value: i64, spec: *const MeshString) -> *mut MeshString {
+    new_str(&render_int(value as u64 as i128, &compiled_spec(spec)))
+}
+
+/// Format a Float with a spec.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_format_float(value: f64, spec: *const MeshString) -> *mut MeshString {
+    new_str(&render_float(value, &compiled_spec(spec)))
+}
+
+/// Format a String, or any value already converted with `to_string`, with a spec.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_format_string(value: *const MeshString, spec: *const MeshString) -> *mut MeshString {
+    unsafe { new_str(&render_str((*value).as_str(), &compiled_spec(spec))) }
+}
+
+/// Format one `String.format` argument. The argument is a String, read as
+/// a number when the spec asks for one: `x`, `X`, `o`, `b`, `+` and `0`
+/// need an Int (or a Float for `+` and `0`), `e` needs a Float, and a
+/// precision formats the argument as a Float when it is one.
+fn format_arg(index: usize, arg: &str, spec: &FormatSpec) -> Result<String, String> {
+    let int = arg.parse::<i64>().ok();
+    let float = arg.parse::<f64>().ok();
+    let not_a = |what: &str| format!("argument {} (`{}`) is not {}", index, arg, what);
+    match spec.kind {
+        Some('e') => float.map(|f| render_float(f, spec)).ok_or_else(|| not_a("a Float")),
+        Some(_) => int.map(|n| render_int(n as i128, spec)).ok_or_else(|| not_a("an Int")),
+        None if spec.plus || spec.zero => match (int, float) {
+            (Some(n), _) if spec.precision.is_none() => Ok(render_int(n as i128, spec)),
+            (_, Some(f)) => Ok(render_float(f, spec)),
+            _ => Err(not_a("a number")),
+        },
+        None => match float {
+            Some(f) if spec.precision.is_some() => Ok(render_float(f, spec)),
+            _ => Ok(render_str(arg, spec)),
+        },
+    }
+}
+
+/// Fill a template's `{}` placeholders from `args`.
+///
+/// A placeholder is `{}` (the next argument), `{N}` (argument N) or either
+/// followed by `:spec`. `{{` and `}}` stand for literal braces.
+fn format_template(template: &str, args: &[&str]) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut rest = template;
+    while let Some(i) = rest.find(['{', '}']) {
+        out.push_str(&rest[..i]);
+        let brace = rest.as_bytes()[i];
+        rest = &rest[i + 1..];
+        if rest.as_bytes().first() == Some(&brace) {
+            out.push(brace as char);
+            rest = &rest[1..];
+            continue;
+        }
+        if brace == b'}' {
+            return Err("unmatched `}` in template; write `}}` for a literal brace".to_string());
+        }
+        let end = rest.find('}').ok_or("unclosed `{` in template")?;
+        let placeholder = &rest[..end];
+        rest = &rest[end + 1..];
+        let (position, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        let index = if position.is_empty() {
+            next += 1;
+            next - 1
+        } else {
+            position
+                .parse::<usize>()
+                .map_err(|_| format!("invalid placeholder `{{{}}}`", placeholder))?
+        };
+        let arg = args
+            .get(index)
+            .ok_or_else(|| format!("placeholder `{{{}}}` refers to argument {} of a {}-element list", placeholder, index, args.len()))?;
+        let spec = FormatSpec::parse(spec).map_err(|e| format!("invalid format spec `{}`: {}", spec, e))?;
+        out.push_str(&format_arg(index, arg, &spec)?);
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// `String.format(template, args)`: fill the template's placeholders from a
+/// List<String>, returning Result<String, String>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_format(template: *const MeshString, args: *mut u8) -> *mut u8 {
+    unsafe {
+        let args: Vec<&str> = list_to_vec(args)
+            .into_iter()
+            .map(|p| (*(p as *const MeshString)).as_str())
+            .collect();
+        match format_template((*template).as_str(), &args) {
+            Ok(text) => alloc_result(0, new_str(&text) as *mut u8) as *mut u8,
+            Err(msg) => alloc_result(1, new_str(&msg) as *mut u8) as *mut u8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(text: &str) -> FormatSpec {
+        FormatSpec::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_render_numbers() {
+        assert_eq!(render_int(255, &spec("x")), "ff");
+        assert_eq!(render_int(-255, &spec("#>6X")), "###-FF");
+        assert_eq!(render_int(-42, &spec("06")), "-00042");
+        assert_eq!(render_int(42, &spec("+")), "+42");
+        assert_eq!(render_int(5, &spec("08b")), "00000101");
+        assert_eq!(render_int(u64::MAX as i128, &spec("x")), "ffffffffffffffff");
+        assert_eq!(render_float(1.23456, &spec(".2")), "1.23");
+        assert_eq!(render_float(-2.5, &spec("+08.3")), "-002.500");
+        assert_eq!(render_float(1234.5, &spec(".2e")), "1.23e3");
+        assert_eq!(render_float(0.5, &spec("^9")), "   0.5   ");
+        assert_eq!(render_float(2.0, &spec("+")), "+2");
+    }
+
+    #[test]
+    fn test_render_strings() {
+        assert_eq!(render_str("id", &spec(">5")), "   id");
+        assert_eq!(render_str("id", &spec("5")), "id   ");
+        assert_eq!(render_str("abcdef", &spec("-^8.3")), "--abc---");
+        // Width and precision count graphemes: "e" + U+0301 is one.
+        assert_eq!(render_str("e\u{301}t\u{e9}", &spec("*<4.2")), "e\u{301}t**");
+    }
+
+    #[test]
+    fn test_format_template() {
+        assert_eq!(
+            format_template("{} has {:>4} items at {:.2}", &["cart", "3", "9.5"]).unwrap(),
+            "cart has    3 items at 9.50"
+        );
+        assert_eq!(format_template("{1}-{0}-{1:x} {{ok}}", &["a", "255"]).unwrap(), "255-a-ff {ok}");
+        assert_eq!(format_template("{:.3}", &["abcdef"]).unwrap(), "abc");
+        assert_eq!(
+            format_template("{} {}", &["a"]).unwrap_err(),
+            "placeholder `{}` refers to argument 1 of a 1-element list"
+        );
+        assert_eq!(format_template("{:x}", &["1.5"]).unwrap_err(), "argument 0 (`1.5`) is not an Int");
+        assert_eq!(format_template("{:+}", &["n/a"]).unwrap_err(), "argument 0 (`n/a`) is not a number");
+        assert_eq!(
+            format_template("{:q}", &["1"]).unwrap_err(),
+            "invalid format spec `q`: unexpected `q`; expected `[[fill]align][+][0][width][.precision][type]`"
+        );
+        assert_eq!(format_template("x}", &[]).unwrap_err(), "unmatched `}` in template; write `}}` for a literal brace");
+        assert_eq!(format_template("{0", &["a"]).unwrap_err(), "unclosed `{` in template");
+        assert_eq!(format_template("{a}", &["a"]).unwrap_err(), "invalid placeholder `{a}`");
+    }
+}
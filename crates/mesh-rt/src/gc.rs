@@ -16,6 +16,8 @@
 
 use std::sync::Mutex;
 
+use crate::actor::heap::Finalizer;
+
 /// Default page size: 64 KiB.
 const PAGE_SIZE: usize = 64 * 1024;
 
@@ -93,7 +95,7 @@ static ARENA: Mutex<Option<Arena>> = Mutex::new(None);
 ///
 /// This function is safe to call multiple times; subsequent calls are no-ops.
 #[no_mangle]
-pub extern "C" fn mesh_rt_init() {
+pub extern "C-unwind" fn mesh_rt_init() {
     let mut guard = ARENA.lock().unwrap();
     if guard.is_none() {
         let mut arena = Arena::new();
@@ -116,7 +118,7 @@ pub extern "C" fn mesh_rt_init() {
 /// The returned pointer must not be freed by the caller. The arena owns
 /// the memory.
 #[no_mangle]
-pub extern "C" fn mesh_gc_alloc(size: u64, align: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_gc_alloc(size: u64, align: u64) -> *mut u8 {
     let mut guard = ARENA.lock().unwrap();
     let arena = guard.get_or_insert_with(|| {
         let mut a = Arena::new();
@@ -141,7 +143,7 @@ pub extern "C" fn mesh_gc_alloc(size: u64, align: u64) -> *mut u8 {
 /// The returned pointer must not be freed by the caller. The actor's heap
 /// owns the memory.
 #[no_mangle]
-pub extern "C" fn mesh_gc_alloc_actor(size: u64, align: u64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_gc_alloc_actor(size: u64, align: u64) -> *mut u8 {
     // Try to allocate from the current actor's heap.
     if let Some(ptr) = try_alloc_from_actor_heap(size as usize, align as usize) {
         return ptr;
@@ -167,6 +169,27 @@ fn try_alloc_from_actor_heap(size: usize, align: usize) -> Option<*mut u8> {
     Some(proc.heap.alloc(size, align))
 }
 
+/// Allocate like `mesh_gc_alloc_actor`, calling `finalizer` with the data
+/// pointer once the object is collected or its actor's heap is released.
+///
+/// Outside an actor the object comes from the global arena, which is never
+/// collected, so its finalizer never runs.
+pub(crate) fn mesh_gc_alloc_finalized(size: u64, align: u64, finalizer: Finalizer) -> *mut u8 {
+    use crate::actor::stack::get_current_pid;
+    use crate::actor::GLOBAL_SCHEDULER;
+
+    let proc_arc = get_current_pid().and_then(|pid| GLOBAL_SCHEDULER.get()?.get_process(pid));
+    match proc_arc {
+        Some(proc_arc) => {
+            let mut proc = proc_arc.lock();
+            let ptr = proc.heap.alloc(size as usize, align as usize);
+            proc.heap.register_finalizer(ptr, finalizer);
+            ptr
+        }
+        None => mesh_gc_alloc(size, align),
+    }
+}
+
 /// Trigger garbage collection on the current actor's heap.
 ///
 /// Explicitly forces a mark-sweep GC cycle on the calling actor's heap,
@@ -181,7 +204,7 @@ fn try_alloc_from_actor_heap(size: usize, align: usize) -> Option<*mut u8> {
 /// No-op if called outside of an actor context or if GC is already in
 /// progress (re-entrancy guard).
 #[no_mangle]
-pub extern "C" fn mesh_gc_collect() {
+pub extern "C-unwind" fn mesh_gc_collect() {
     use crate::actor::stack;
     use crate::actor::GLOBAL_SCHEDULER;
 
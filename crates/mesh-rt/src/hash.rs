@@ -16,28 +16,28 @@ fn fnv1a_bytes(bytes: &[u8]) -> u64 {
 }
 
 #[no_mangle]
-pub extern "C" fn mesh_hash_int(value: i64) -> i64 {
+pub extern "C-unwind" fn mesh_hash_int(value: i64) -> i64 {
     fnv1a_bytes(&value.to_le_bytes()) as i64
 }
 
 #[no_mangle]
-pub extern "C" fn mesh_hash_float(value: f64) -> i64 {
+pub extern "C-unwind" fn mesh_hash_float(value: f64) -> i64 {
     fnv1a_bytes(&value.to_bits().to_le_bytes()) as i64
 }
 
 #[no_mangle]
-pub extern "C" fn mesh_hash_bool(value: i8) -> i64 {
+pub extern "C-unwind" fn mesh_hash_bool(value: i8) -> i64 {
     fnv1a_bytes(&[value as u8]) as i64
 }
 
 #[no_mangle]
-pub extern "C" fn mesh_hash_string(s: *const crate::string::MeshString) -> i64 {
+pub extern "C-unwind" fn mesh_hash_string(s: *const crate::string::MeshString) -> i64 {
     unsafe { fnv1a_bytes((*s).as_str().as_bytes()) as i64 }
 }
 
 /// Combine two hash values (for struct field hashing).
 #[no_mangle]
-pub extern "C" fn mesh_hash_combine(hash_a: i64, hash_b: i64) -> i64 {
+pub extern "C-unwind" fn mesh_hash_combine(hash_a: i64, hash_b: i64) -> i64 {
     let mut hash = hash_a as u64;
     for &b in &(hash_b as u64).to_le_bytes() {
         hash ^= b as u64;
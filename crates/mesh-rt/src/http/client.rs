@@ -24,7 +24,7 @@ fn alloc_result(tag: u8, value: *mut u8) -> *mut MeshResult {
 /// - tag 0 (Ok): value = MeshString response body
 /// - tag 1 (Err): value = MeshString error message
 #[no_mangle]
-pub extern "C" fn mesh_http_get(url: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_get(url: *const MeshString) -> *mut u8 {
     unsafe {
         let url_str = (*url).as_str();
         match ureq::get(url_str).call() {
@@ -46,7 +46,7 @@ pub extern "C" fn mesh_http_get(url: *const MeshString) -> *mut u8 {
 /// - tag 0 (Ok): value = MeshString response body
 /// - tag 1 (Err): value = MeshString error message
 #[no_mangle]
-pub extern "C" fn mesh_http_post(url: *const MeshString, body: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_post(url: *const MeshString, body: *const MeshString) -> *mut u8 {
     unsafe {
         let url_str = (*url).as_str();
         let body_str = (*body).as_str();
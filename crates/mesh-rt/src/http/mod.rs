@@ -30,5 +30,6 @@ pub use router::{
 pub use server::{
     mesh_http_request_body, mesh_http_request_header, mesh_http_request_method,
     mesh_http_request_param, mesh_http_request_path, mesh_http_request_query,
-    mesh_http_response_new, mesh_http_serve, mesh_http_serve_tls,
+    mesh_http_response_new, mesh_http_response_new_iodata, mesh_http_response_with_headers_iodata,
+    mesh_http_serve, mesh_http_serve_tls,
 };
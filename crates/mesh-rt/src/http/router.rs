@@ -191,7 +191,7 @@ fn route_with_method(
 
 /// Create an empty router. Returns a pointer to a heap-allocated MeshRouter.
 #[no_mangle]
-pub extern "C" fn mesh_http_router() -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_router() -> *mut u8 {
     let router = Box::new(MeshRouter {
         routes: Vec::new(),
         middlewares: Vec::new(),
@@ -204,7 +204,7 @@ pub extern "C" fn mesh_http_router() -> *mut u8 {
 /// This is the existing `HTTP.route(router, pattern, handler)` -- matches
 /// any HTTP method (backward compatible).
 #[no_mangle]
-pub extern "C" fn mesh_http_route(
+pub extern "C-unwind" fn mesh_http_route(
     router: *mut u8,
     pattern: *const MeshString,
     handler_fn: *mut u8,
@@ -214,7 +214,7 @@ pub extern "C" fn mesh_http_route(
 
 /// Add a GET-only route. Returns a NEW router pointer.
 #[no_mangle]
-pub extern "C" fn mesh_http_route_get(
+pub extern "C-unwind" fn mesh_http_route_get(
     router: *mut u8,
     pattern: *const MeshString,
     handler_fn: *mut u8,
@@ -224,7 +224,7 @@ pub extern "C" fn mesh_http_route_get(
 
 /// Add a POST-only route. Returns a NEW router pointer.
 #[no_mangle]
-pub extern "C" fn mesh_http_route_post(
+pub extern "C-unwind" fn mesh_http_route_post(
     router: *mut u8,
     pattern: *const MeshString,
     handler_fn: *mut u8,
@@ -234,7 +234,7 @@ pub extern "C" fn mesh_http_route_post(
 
 /// Add a PUT-only route. Returns a NEW router pointer.
 #[no_mangle]
-pub extern "C" fn mesh_http_route_put(
+pub extern "C-unwind" fn mesh_http_route_put(
     router: *mut u8,
     pattern: *const MeshString,
     handler_fn: *mut u8,
@@ -244,7 +244,7 @@ pub extern "C" fn mesh_http_route_put(
 
 /// Add a DELETE-only route. Returns a NEW router pointer.
 #[no_mangle]
-pub extern "C" fn mesh_http_route_delete(
+pub extern "C-unwind" fn mesh_http_route_delete(
     router: *mut u8,
     pattern: *const MeshString,
     handler_fn: *mut u8,
@@ -257,7 +257,7 @@ pub extern "C" fn mesh_http_route_delete(
 /// The middleware function receives (request, next_closure) and returns a response.
 /// Multiple middleware compose in registration order: first added = outermost.
 #[no_mangle]
-pub extern "C" fn mesh_http_use_middleware(
+pub extern "C-unwind" fn mesh_http_use_middleware(
     router: *mut u8,
     middleware_fn: *mut u8,
 ) -> *mut u8 {
@@ -29,6 +29,7 @@ use rustls_pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
 use crate::actor;
 use crate::collections::map;
 use crate::gc::mesh_gc_alloc_actor;
+use crate::iodata::{iodata_to_vec, MeshIoData};
 use crate::string::{mesh_string_new, MeshString};
 
 use super::router::{MiddlewareEntry, MeshRouter};
@@ -131,6 +132,9 @@ pub struct MeshHttpResponse {
     /// Optional response headers as MeshMap (string keys -> string values).
     /// Null when no custom headers are set (backward compatible).
     pub headers: *mut u8,
+    /// Response body as IoData, flattened only when the response is written.
+    /// Null when the body is the MeshString in `body`.
+    pub body_iodata: *mut u8,
 }
 
 // ── Response constructor ───────────────────────────────────────────────
@@ -138,7 +142,7 @@ pub struct MeshHttpResponse {
 /// Create a new HTTP response with the given status code and body.
 /// Headers are set to null (no custom headers).
 #[no_mangle]
-pub extern "C" fn mesh_http_response_new(status: i64, body: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_response_new(status: i64, body: *const MeshString) -> *mut u8 {
     unsafe {
         let ptr = mesh_gc_alloc_actor(
             std::mem::size_of::<MeshHttpResponse>() as u64,
@@ -147,6 +151,7 @@ pub extern "C" fn mesh_http_response_new(status: i64, body: *const MeshString) -
         (*ptr).status = status;
         (*ptr).body = body as *mut u8;
         (*ptr).headers = std::ptr::null_mut();
+        (*ptr).body_iodata = std::ptr::null_mut();
         ptr as *mut u8
     }
 }
@@ -156,7 +161,7 @@ pub extern "C" fn mesh_http_response_new(status: i64, body: *const MeshString) -
 /// The `headers` parameter is a MeshMap pointer (string keys -> string values).
 /// These headers are emitted in the HTTP response alongside the standard headers.
 #[no_mangle]
-pub extern "C" fn mesh_http_response_with_headers(
+pub extern "C-unwind" fn mesh_http_response_with_headers(
     status: i64,
     body: *const MeshString,
     headers: *mut u8,
@@ -169,34 +174,55 @@ pub extern "C" fn mesh_http_response_with_headers(
         (*ptr).status = status;
         (*ptr).body = body as *mut u8;
         (*ptr).headers = headers;
+        (*ptr).body_iodata = std::ptr::null_mut();
         ptr as *mut u8
     }
 }
 
+/// Create a new HTTP response whose body is an IoData.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_http_response_new_iodata(status: i64, body: *const MeshIoData) -> *mut u8 {
+    mesh_http_response_with_headers_iodata(status, body, std::ptr::null_mut())
+}
+
+/// Create a new HTTP response with custom headers whose body is an IoData.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_http_response_with_headers_iodata(
+    status: i64,
+    body: *const MeshIoData,
+    headers: *mut u8,
+) -> *mut u8 {
+    let ptr = mesh_http_response_with_headers(status, std::ptr::null(), headers);
+    unsafe {
+        (*(ptr as *mut MeshHttpResponse)).body_iodata = body as *mut u8;
+    }
+    ptr
+}
+
 // ── Request accessors ──────────────────────────────────────────────────
 
 /// Get the HTTP method from a request.
 #[no_mangle]
-pub extern "C" fn mesh_http_request_method(req: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_method(req: *mut u8) -> *mut u8 {
     unsafe { (*(req as *const MeshHttpRequest)).method }
 }
 
 /// Get the URL path from a request.
 #[no_mangle]
-pub extern "C" fn mesh_http_request_path(req: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_path(req: *mut u8) -> *mut u8 {
     unsafe { (*(req as *const MeshHttpRequest)).path }
 }
 
 /// Get the request body.
 #[no_mangle]
-pub extern "C" fn mesh_http_request_body(req: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_body(req: *mut u8) -> *mut u8 {
     unsafe { (*(req as *const MeshHttpRequest)).body }
 }
 
 /// Get the value of a request header by name. Returns MeshOption
 /// (tag 0 = Some with MeshString, tag 1 = None).
 #[no_mangle]
-pub extern "C" fn mesh_http_request_header(req: *mut u8, name: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_header(req: *mut u8, name: *const MeshString) -> *mut u8 {
     unsafe {
         let request = &*(req as *const MeshHttpRequest);
         let key_str = (*name).as_str();
@@ -216,7 +242,7 @@ pub extern "C" fn mesh_http_request_header(req: *mut u8, name: *const MeshString
 /// Get the value of a query parameter by name. Returns MeshOption
 /// (tag 0 = Some with MeshString, tag 1 = None).
 #[no_mangle]
-pub extern "C" fn mesh_http_request_query(req: *mut u8, name: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_query(req: *mut u8, name: *const MeshString) -> *mut u8 {
     unsafe {
         let request = &*(req as *const MeshHttpRequest);
         let key_str = (*name).as_str();
@@ -237,7 +263,7 @@ pub extern "C" fn mesh_http_request_query(req: *mut u8, name: *const MeshString)
 /// like `/users/:id`. For a request matching this pattern with path
 /// `/users/42`, `Request.param(req, "id")` returns `Some("42")`.
 #[no_mangle]
-pub extern "C" fn mesh_http_request_param(req: *mut u8, name: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_http_request_param(req: *mut u8, name: *const MeshString) -> *mut u8 {
     unsafe {
         let request = &*(req as *const MeshHttpRequest);
         let key_str = (*name).as_str();
@@ -411,15 +437,16 @@ struct ConnectionArgs {
 ///
 /// Receives a raw pointer to `ConnectionArgs` containing the router
 /// address and a boxed `HttpStream`. Wraps the handler call in
-/// `catch_unwind` for crash isolation -- a panic in one handler does
-/// not affect other connections.
+/// `catch_unwind` so a crashing handler still answers with a 500 before
+/// its actor exits with the crash reason; other connections are not
+/// affected.
 ///
 /// The read timeout is already set on the underlying TcpStream before
 /// wrapping in `HttpStream` (both Plain and Tls variants). For TLS
 /// connections, the actual TLS handshake happens lazily on the first
 /// `read` call (via `StreamOwned`), which occurs inside this actor --
 /// not in the accept loop.
-extern "C" fn connection_handler_entry(args: *const u8) {
+extern "C-unwind" fn connection_handler_entry(args: *const u8) {
     if args.is_null() {
         return;
     }
@@ -428,9 +455,11 @@ extern "C" fn connection_handler_entry(args: *const u8) {
     let router_ptr = args.router_addr as *mut u8;
     let mut stream = unsafe { *Box::from_raw(args.request_ptr as *mut HttpStream) };
 
+    let mut parsed_ok = false;
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         match parse_request(&mut stream) {
             Ok(parsed) => {
+                parsed_ok = true;
                 let (status, body, headers) = process_request(router_ptr, parsed);
                 let _ = write_response(&mut stream, status, &body, headers);
             }
@@ -441,7 +470,19 @@ extern "C" fn connection_handler_entry(args: *const u8) {
     }));
 
     if let Err(panic_info) = result {
-        eprintln!("[mesh-rt] HTTP handler panicked: {:?}", panic_info);
+        eprintln!(
+            "[mesh-rt] HTTP handler panicked: {}",
+            actor::stack::crash_reason(&*panic_info)
+        );
+        // The handler crashed before responding; answer with a 500 and
+        // close the connection.
+        if parsed_ok {
+            let _ = write_response(&mut stream, 500, b"Internal Server Error", None);
+        }
+        drop(stream);
+        // Then finish crashing, so the actor exits with
+        // `ExitReason::Error(reason)` and its links and monitors see it.
+        std::panic::resume_unwind(panic_info);
     }
 }
 
@@ -458,7 +499,7 @@ extern "C" fn connection_handler_entry(args: *const u8) {
 /// - If handler_env is null: `fn(request_ptr) -> response_ptr`
 /// - If handler_env is non-null: `fn(handler_env, request_ptr) -> response_ptr`
 #[no_mangle]
-pub extern "C" fn mesh_http_serve(router: *mut u8, port: i64) {
+pub extern "C-unwind" fn mesh_http_serve(router: *mut u8, port: i64) {
     // Ensure the actor scheduler is initialized (idempotent).
     crate::actor::mesh_rt_init_actor(0);
 
@@ -519,7 +560,7 @@ pub extern "C" fn mesh_http_serve(router: *mut u8, port: i64) {
 /// handshake occurs on the first `read` call inside the actor's coroutine,
 /// ensuring the accept loop is never blocked by slow TLS clients.
 #[no_mangle]
-pub extern "C" fn mesh_http_serve_tls(
+pub extern "C-unwind" fn mesh_http_serve_tls(
     router: *mut u8,
     port: i64,
     cert_path: *const MeshString,
@@ -623,7 +664,7 @@ struct ChainState {
 /// This is what Mesh calls when middleware invokes `next(request)`.
 /// If all middleware has been traversed, calls the route handler.
 /// Otherwise, calls the next middleware with a new `next` closure.
-extern "C" fn chain_next(env_ptr: *mut u8, request_ptr: *mut u8) -> *mut u8 {
+extern "C-unwind" fn chain_next(env_ptr: *mut u8, request_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let state = &*(env_ptr as *const ChainState);
         if state.index >= state.middlewares.len() {
@@ -793,7 +834,7 @@ fn process_request(router_ptr: *mut u8, parsed: ParsedRequest) -> (u16, Vec<u8>,
             let req_ptr = build_mesh_request(path_params_map);
 
             // Synthetic 404 handler: returns a 404 response.
-            extern "C" fn not_found_handler(_request: *mut u8) -> *mut u8 {
+            extern "C-unwind" fn not_found_handler(_request: *mut u8) -> *mut u8 {
                 let body_text = b"Not Found";
                 let body = mesh_string_new(body_text.as_ptr(), body_text.len() as u64);
                 mesh_http_response_new(404, body)
@@ -814,11 +855,13 @@ fn process_request(router_ptr: *mut u8, parsed: ParsedRequest) -> (u16, Vec<u8>,
         // Extract response from the Mesh response pointer.
         let resp = &*(response_ptr as *const MeshHttpResponse);
         let status_code = resp.status as u16;
-        let body_str = if resp.body.is_null() {
-            ""
+        let body = if !resp.body_iodata.is_null() {
+            iodata_to_vec(resp.body_iodata as *const MeshIoData)
+        } else if resp.body.is_null() {
+            Vec::new()
         } else {
             let body_mesh = &*(resp.body as *const MeshString);
-            body_mesh.as_str()
+            body_mesh.as_str().as_bytes().to_vec()
         };
 
         // Extract custom headers from the response if present.
@@ -831,14 +874,11 @@ fn process_request(router_ptr: *mut u8, parsed: ParsedRequest) -> (u16, Vec<u8>,
                 None
             } else {
                 let mut headers_vec = Vec::with_capacity(len);
-                // Iterate the MeshMap's internal entries array.
-                // Layout: [u64; 2] per entry where [0] = key, [1] = value.
-                // Both are MeshString pointers cast to u64 (string-keyed map).
-                let entries = (headers_map as *const u8).add(16) as *const [u64; 2];
-                for i in 0..len {
-                    let entry = &*entries.add(i);
-                    let key_ptr = entry[0] as *const MeshString;
-                    let val_ptr = entry[1] as *const MeshString;
+                // Keys and values are MeshString pointers cast to u64
+                // (string-keyed map).
+                for i in 0..len as i64 {
+                    let key_ptr = map::mesh_map_entry_key(headers_map, i) as *const MeshString;
+                    let val_ptr = map::mesh_map_entry_value(headers_map, i) as *const MeshString;
                     let key_str = (*key_ptr).as_str().to_string();
                     let val_str = (*val_ptr).as_str().to_string();
                     headers_vec.push((key_str, val_str));
@@ -847,13 +887,14 @@ fn process_request(router_ptr: *mut u8, parsed: ParsedRequest) -> (u16, Vec<u8>,
             }
         };
 
-        (status_code, body_str.as_bytes().to_vec(), extra_headers)
+        (status_code, body, extra_headers)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::actor::{ExitReason, ProcessState};
     use crate::gc::mesh_rt_init;
 
     #[test]
@@ -897,6 +938,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_response_iodata_body() {
+        use crate::iodata::{mesh_iodata_append, mesh_iodata_new};
+
+        mesh_rt_init();
+        let mut body = mesh_iodata_new();
+        for part in ["<ul>", "<li>a</li>", "</ul>"] {
+            body = mesh_iodata_append(body, mesh_string_new(part.as_ptr(), part.len() as u64));
+        }
+        let resp_ptr = mesh_http_response_new_iodata(200, body);
+        unsafe {
+            let resp = &*(resp_ptr as *const MeshHttpResponse);
+            assert_eq!(resp.status, 200);
+            assert!(resp.body.is_null() && resp.headers.is_null());
+            let flat = iodata_to_vec(resp.body_iodata as *const MeshIoData);
+            assert_eq!(flat, b"<ul><li>a</li></ul>");
+        }
+    }
+
     #[test]
     fn test_request_accessors() {
         mesh_rt_init();
@@ -939,4 +999,59 @@ mod tests {
             assert_eq!(b_str.as_str(), "");
         }
     }
+
+    /// Route handler that fails with a Mesh panic.
+    fn panicking_handler(_request: *mut u8) -> *mut u8 {
+        let msg = "handler failed";
+        let file = "app.mpl";
+        crate::panic::mesh_panic(
+            msg.as_ptr(),
+            msg.len() as u64,
+            file.as_ptr(),
+            file.len() as u64,
+            7,
+        )
+    }
+
+    #[test]
+    fn test_panicking_handler_gets_500_and_closes_connection() {
+        mesh_rt_init();
+        let port = {
+            let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            l.local_addr().unwrap().port()
+        };
+        let pattern = mesh_string_new(b"/boom".as_ptr(), 5) as *const MeshString;
+        let router = crate::http::router::mesh_http_router();
+        let router = crate::http::router::mesh_http_route_get(
+            router,
+            pattern,
+            panicking_handler as *mut u8,
+        );
+        let router_addr = router as usize;
+        std::thread::spawn(move || mesh_http_serve(router_addr as *mut u8, port as i64));
+        std::thread::sleep(Duration::from_millis(200));
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        stream.write_all(b"GET /boom HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+
+        // read_to_end only returns Ok once the server has closed the socket.
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).expect("connection should be closed");
+        let response = String::from_utf8_lossy(&response);
+        assert!(response.starts_with("HTTP/1.1 500"), "got: {}", response);
+
+        // The connection's actor then exits with the handler's crash reason.
+        let sched = crate::actor::GLOBAL_SCHEDULER.get().unwrap();
+        let crashed = || {
+            sched.process_table().read().values().any(|proc| {
+                matches!(&proc.lock().state, ProcessState::Exited(ExitReason::Error(r)) if r.contains("handler failed"))
+            })
+        };
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while !crashed() && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(crashed(), "connection actor should exit with Error(\"handler failed...\")");
+    }
 }
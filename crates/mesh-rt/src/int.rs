@@ -0,0 +1,101 @@
+//! Explicit overflow behavior for `Int` arithmetic.
+//!
+//! The arithmetic operators trap on overflow in builds with overflow checks
+//! and wrap otherwise. Code that needs one behavior regardless of the
+//! build calls these instead: `wrapping_*` wraps around in two's complement,
+//! `checked_*` returns None on overflow and `saturating_*` clamps to `Int`'s
+//! minimum or maximum.
+
+use crate::option::alloc_option;
+
+/// Box an Int result as an Option: Some for `Some(value)`, None otherwise.
+fn int_option(value: Option<i64>) -> *mut u8 {
+    match value {
+        Some(v) => alloc_option(0, v as u64 as *mut u8) as *mut u8,
+        None => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+    }
+}
+
+macro_rules! int_overflow_ops {
+    ($op:literal, $wrapping:ident, $checked:ident, $saturating:ident,
+     $wrapping_fn:ident, $checked_fn:ident, $saturating_fn:ident) => {
+        #[doc = concat!("`a ", $op, " b`, wrapping around on overflow.")]
+        #[no_mangle]
+        pub extern "C-unwind" fn $wrapping_fn(a: i64, b: i64) -> i64 {
+            a.$wrapping(b)
+        }
+
+        #[doc = concat!("`a ", $op, " b`, or None on overflow.")]
+        #[no_mangle]
+        pub extern "C-unwind" fn $checked_fn(a: i64, b: i64) -> *mut u8 {
+            int_option(a.$checked(b))
+        }
+
+        #[doc = concat!("`a ", $op, " b`, clamped to the range of Int.")]
+        #[no_mangle]
+        pub extern "C-unwind" fn $saturating_fn(a: i64, b: i64) -> i64 {
+            a.$saturating(b)
+        }
+    };
+}
+
+int_overflow_ops!(
+    "+", wrapping_add, checked_add, saturating_add,
+    mesh_int_wrapping_add, mesh_int_checked_add, mesh_int_saturating_add
+);
+int_overflow_ops!(
+    "-", wrapping_sub, checked_sub, saturating_sub,
+    mesh_int_wrapping_sub, mesh_int_checked_sub, mesh_int_saturating_sub
+);
+int_overflow_ops!(
+    "*", wrapping_mul, checked_mul, saturating_mul,
+    mesh_int_wrapping_mul, mesh_int_checked_mul, mesh_int_saturating_mul
+);
+
+/// `a / b`, or None when `b` is zero or the quotient overflows.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_int_checked_div(a: i64, b: i64) -> *mut u8 {
+    int_option(a.checked_div(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gc::mesh_rt_init;
+    use crate::option::MeshOption;
+
+    fn option_value(opt: *mut u8) -> Option<i64> {
+        let opt = opt as *mut MeshOption;
+        unsafe { ((*opt).tag == 0).then(|| (*opt).value as u64 as i64) }
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(mesh_int_wrapping_add(i64::MAX, 1), i64::MIN);
+        assert_eq!(mesh_int_wrapping_sub(i64::MIN, 1), i64::MAX);
+        assert_eq!(mesh_int_wrapping_mul(i64::MAX, 2), -2);
+        assert_eq!(mesh_int_wrapping_add(2, 3), 5);
+    }
+
+    #[test]
+    fn test_checked() {
+        mesh_rt_init();
+        assert_eq!(option_value(mesh_int_checked_add(2, 3)), Some(5));
+        assert_eq!(option_value(mesh_int_checked_add(i64::MAX, 1)), None);
+        assert_eq!(option_value(mesh_int_checked_sub(i64::MIN, 1)), None);
+        assert_eq!(option_value(mesh_int_checked_mul(-4, 5)), Some(-20));
+        assert_eq!(option_value(mesh_int_checked_mul(i64::MAX, 2)), None);
+        assert_eq!(option_value(mesh_int_checked_div(7, 2)), Some(3));
+        assert_eq!(option_value(mesh_int_checked_div(7, 0)), None);
+        assert_eq!(option_value(mesh_int_checked_div(i64::MIN, -1)), None);
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(mesh_int_saturating_add(i64::MAX, 1), i64::MAX);
+        assert_eq!(mesh_int_saturating_sub(0, 5), -5);
+        assert_eq!(mesh_int_saturating_sub(i64::MIN, 1), i64::MIN);
+        assert_eq!(mesh_int_saturating_mul(i64::MIN, 2), i64::MIN);
+        assert_eq!(mesh_int_saturating_mul(i64::MIN, -1), i64::MAX);
+    }
+}
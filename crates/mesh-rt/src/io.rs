@@ -1,9 +1,12 @@
 //! Console I/O runtime functions for the Mesh standard library.
 //!
-//! Provides stdin reading and stderr output. Functions match the Mesh
-//! module `IO` with `read_line` and `eprintln`.
+//! Provides stdin reading, stdout writing and stderr output. Functions match
+//! the Mesh module `IO` with `read_line`, `write` and `eprintln`.
+
+use std::io::Write;
 
 use crate::gc::mesh_gc_alloc_actor;
+use crate::iodata::{write_iodata, MeshIoData};
 use crate::string::{mesh_string_new, MeshString};
 
 /// Tagged result value for Mesh's Result<T, E> representation.
@@ -35,20 +38,20 @@ pub(crate) fn alloc_result(tag: u8, value: *mut u8) -> *mut MeshResult {
 /// Allocate a MeshResult on the GC heap with the given tag and value.
 /// Tag 0 = Ok, tag 1 = Err.
 #[no_mangle]
-pub extern "C" fn mesh_alloc_result(tag: i64, value: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_alloc_result(tag: i64, value: *mut u8) -> *mut u8 {
     alloc_result(tag as u8, value) as *mut u8
 }
 
 /// Check if a MeshResult is Ok (tag == 0). Returns 1 for Ok, 0 for Err.
 #[no_mangle]
-pub extern "C" fn mesh_result_is_ok(result: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_result_is_ok(result: *mut u8) -> i64 {
     let r = result as *const MeshResult;
     unsafe { if (*r).tag == 0 { 1 } else { 0 } }
 }
 
 /// Extract the value from a MeshResult (Ok or Err payload).
 #[no_mangle]
-pub extern "C" fn mesh_result_unwrap(result: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_result_unwrap(result: *mut u8) -> *mut u8 {
     let r = result as *const MeshResult;
     unsafe { (*r).value }
 }
@@ -58,7 +61,7 @@ pub extern "C" fn mesh_result_unwrap(result: *mut u8) -> *mut u8 {
 ///
 /// The trailing newline is stripped from the result.
 #[no_mangle]
-pub extern "C" fn mesh_io_read_line() -> *mut MeshResult {
+pub extern "C-unwind" fn mesh_io_read_line() -> *mut MeshResult {
     let mut input = String::new();
     match std::io::stdin().read_line(&mut input) {
         Ok(_) => {
@@ -82,13 +85,33 @@ pub extern "C" fn mesh_io_read_line() -> *mut MeshResult {
 
 /// Print a Mesh string to stderr with a trailing newline.
 #[no_mangle]
-pub extern "C" fn mesh_io_eprintln(s: *const MeshString) {
+pub extern "C-unwind" fn mesh_io_eprintln(s: *const MeshString) {
     unsafe {
         let text = (*s).as_str();
         eprintln!("{}", text);
     }
 }
 
+/// Write a string to stdout without a trailing newline, then flush.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_io_write(s: *const MeshString) {
+    let mut out = std::io::stdout().lock();
+    unsafe {
+        let _ = out.write_all((*s).as_str().as_bytes());
+    }
+    let _ = out.flush();
+}
+
+/// Write each chunk of an IoData to stdout, then flush.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_io_write_iodata(io: *const MeshIoData) {
+    let mut out = std::io::stdout().lock();
+    unsafe {
+        let _ = write_iodata(io, &mut out);
+    }
+    let _ = out.flush();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,236 @@
+//! IoData: cheap string building for output.
+//!
+//! Concatenating Strings copies both sides, so building a body piece by
+//! piece in a loop costs quadratic copying. An IoData is instead a tree of
+//! String and Bytes chunks: appending allocates one node and copies nothing.
+//! The tree is only flattened when it is written out (`IO.write`,
+//! `File.write`, an HTTP response body, `Ws.send`) or converted with
+//! `IoData.to_string`/`IoData.to_bytes`.
+//!
+//! Like every Mesh value an IoData is immutable, so appending to one never
+//! changes another that shares its chunks.
+
+use std::io::Write;
+
+use crate::bytes::{bytes_from_slice, MeshBytes};
+use crate::collections::list::list_to_vec;
+use crate::gc::mesh_gc_alloc_actor;
+use crate::string::{mesh_string_new, MeshString};
+
+/// A leaf holds a String or Bytes buffer (null for the empty IoData).
+const IODATA_LEAF: u64 = 0;
+/// A branch holds two IoData, written left first.
+const IODATA_BRANCH: u64 = 1;
+
+/// A node of an IoData tree.
+#[repr(C)]
+pub struct MeshIoData {
+    tag: u64,
+    /// Total number of bytes under this node.
+    len: u64,
+    /// The chunk of a leaf (`{ len: u64, data }`, shared by String and
+    /// Bytes), or the left child of a branch.
+    left: *mut u8,
+    /// The right child of a branch; null for a leaf.
+    right: *mut u8,
+}
+
+fn alloc_node(tag: u64, len: u64, left: *mut u8, right: *mut u8) -> *mut MeshIoData {
+    unsafe {
+        let ptr = mesh_gc_alloc_actor(
+            std::mem::size_of::<MeshIoData>() as u64,
+            std::mem::align_of::<MeshIoData>() as u64,
+        ) as *mut MeshIoData;
+        (*ptr).tag = tag;
+        (*ptr).len = len;
+        (*ptr).left = left;
+        (*ptr).right = right;
+        ptr
+    }
+}
+
+/// A leaf for a String or Bytes buffer. Both start with their length.
+fn leaf(chunk: *mut u8) -> *mut MeshIoData {
+    let len = unsafe { *(chunk as *const u64) };
+    alloc_node(IODATA_LEAF, len, chunk, std::ptr::null_mut())
+}
+
+/// Join two IoData, skipping an empty side.
+fn join(a: *mut MeshIoData, b: *mut MeshIoData) -> *mut MeshIoData {
+    unsafe {
+        if (*b).len == 0 {
+            return a;
+        }
+        if (*a).len == 0 {
+            return b;
+        }
+        alloc_node(IODATA_BRANCH, (*a).len + (*b).len, a as *mut u8, b as *mut u8)
+    }
+}
+
+/// Call `f` with each chunk of `io` in order.
+///
+/// Walks the tree with an explicit stack: appending in a loop builds a tree
+/// as deep as the number of appends.
+///
+/// # Safety
+///
+/// `io` must point to a valid MeshIoData.
+pub(crate) unsafe fn for_each_chunk(io: *const MeshIoData, mut f: impl FnMut(&[u8])) {
+    let mut stack = vec![io];
+    while let Some(node) = stack.pop() {
+        let node = &*node;
+        if node.tag == IODATA_BRANCH {
+            stack.push(node.right as *const MeshIoData);
+            stack.push(node.left as *const MeshIoData);
+        } else if !node.left.is_null() {
+            f((*(node.left as *const MeshBytes)).as_slice());
+        }
+    }
+}
+
+/// Flatten `io` into one buffer.
+///
+/// # Safety
+///
+/// `io` must point to a valid MeshIoData.
+pub(crate) unsafe fn iodata_to_vec(io: *const MeshIoData) -> Vec<u8> {
+    let mut out = Vec::with_capacity((*io).len as usize);
+    for_each_chunk(io, |chunk| out.extend_from_slice(chunk));
+    out
+}
+
+/// Write each chunk of `io` to `w`.
+///
+/// # Safety
+///
+/// `io` must point to a valid MeshIoData.
+pub(crate) unsafe fn write_iodata(io: *const MeshIoData, w: &mut impl Write) -> std::io::Result<()> {
+    let mut result = Ok(());
+    for_each_chunk(io, |chunk| {
+        if result.is_ok() {
+            result = w.write_all(chunk);
+        }
+    });
+    result
+}
+
+/// An empty IoData.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_new() -> *mut MeshIoData {
+    alloc_node(IODATA_LEAF, 0, std::ptr::null_mut(), std::ptr::null_mut())
+}
+
+/// An IoData holding one String.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_from_string(s: *const MeshString) -> *mut MeshIoData {
+    leaf(s as *mut u8)
+}
+
+/// An IoData holding one Bytes buffer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_from_bytes(b: *const MeshBytes) -> *mut MeshIoData {
+    leaf(b as *mut u8)
+}
+
+/// An IoData holding the Strings of a List<String>, in order.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_from_list(list: *mut u8) -> *mut MeshIoData {
+    unsafe { list_to_vec(list) }
+        .into_iter()
+        .fold(mesh_iodata_new(), |io, s| join(io, leaf(s as *mut u8)))
+}
+
+/// `io` followed by a String.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_append(io: *mut MeshIoData, s: *const MeshString) -> *mut MeshIoData {
+    join(io, leaf(s as *mut u8))
+}
+
+/// `io` followed by a Bytes buffer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_append_bytes(io: *mut MeshIoData, b: *const MeshBytes) -> *mut MeshIoData {
+    join(io, leaf(b as *mut u8))
+}
+
+/// `a` followed by `b`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_concat(a: *mut MeshIoData, b: *mut MeshIoData) -> *mut MeshIoData {
+    join(a, b)
+}
+
+/// Total number of bytes, without flattening.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_byte_size(io: *const MeshIoData) -> i64 {
+    unsafe { (*io).len as i64 }
+}
+
+/// Flatten into a String. Bytes chunks that are not valid UTF-8 become
+/// U+FFFD REPLACEMENT CHARACTER.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_to_string(io: *const MeshIoData) -> *mut MeshString {
+    let data = unsafe { iodata_to_vec(io) };
+    let text = String::from_utf8_lossy(&data);
+    mesh_string_new(text.as_ptr(), text.len() as u64)
+}
+
+/// Flatten into a Bytes buffer.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iodata_to_bytes(io: *const MeshIoData) -> *mut MeshBytes {
+    bytes_from_slice(&unsafe { iodata_to_vec(io) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::list::{mesh_list_builder_new, mesh_list_builder_push};
+    use crate::gc::mesh_rt_init;
+
+    fn s(text: &str) -> *mut MeshString {
+        mesh_string_new(text.as_ptr(), text.len() as u64)
+    }
+
+    fn flat(io: *const MeshIoData) -> String {
+        unsafe { (*mesh_iodata_to_string(io)).as_str().to_string() }
+    }
+
+    #[test]
+    fn test_append_and_concat() {
+        mesh_rt_init();
+        let head = mesh_iodata_append(mesh_iodata_new(), s("<p>"));
+        let body = mesh_iodata_append(head, s("hi"));
+        let shared = mesh_iodata_concat(body, mesh_iodata_from_string(s("</p>")));
+        // Appending to `head` again leaves `shared` untouched.
+        let other = mesh_iodata_append(head, s("bye"));
+        assert_eq!(flat(shared), "<p>hi</p>");
+        assert_eq!(flat(other), "<p>bye");
+        assert_eq!(mesh_iodata_byte_size(shared), 9);
+        assert_eq!(flat(mesh_iodata_append(mesh_iodata_new(), s(""))), "");
+    }
+
+    #[test]
+    fn test_bytes_and_lists() {
+        mesh_rt_init();
+        let list = mesh_list_builder_new(2);
+        mesh_list_builder_push(list, s("a,") as u64);
+        mesh_list_builder_push(list, s("b\n") as u64);
+        let io = mesh_iodata_append_bytes(mesh_iodata_from_list(list), bytes_from_slice(&[0xff, b'!']));
+        unsafe {
+            assert_eq!((*mesh_iodata_to_bytes(io)).as_slice(), b"a,b\n\xff!");
+        }
+        assert_eq!(flat(io), "a,b\n\u{fffd}!");
+    }
+
+    #[test]
+    fn test_deep_tree_is_written_in_order() {
+        mesh_rt_init();
+        let mut io = mesh_iodata_new();
+        for i in 0..100_000 {
+            io = mesh_iodata_append(io, s(if i % 2 == 0 { "a" } else { "b" }));
+        }
+        let mut out = Vec::new();
+        unsafe { write_iodata(io, &mut out).unwrap() };
+        assert_eq!(out.len(), 100_000);
+        assert!(out.starts_with(b"abab") && out.ends_with(b"ab"));
+    }
+}
@@ -4,8 +4,13 @@
 //! - Type tag constants for generic iterator dispatch
 //! - Generic next dispatch (`mesh_iter_generic_next`)
 //! - Combinator adapter structs (MapAdapter, FilterAdapter, TakeAdapter,
-//!   SkipAdapter, EnumerateAdapter, ZipAdapter) with `_new` and `_next` functions
-//! - Terminal operations (count, sum, any, all, find, reduce)
+//!   SkipAdapter, EnumerateAdapter, ZipAdapter, TakeWhileAdapter,
+//!   SkipWhileAdapter, FlatMapAdapter, ChainAdapter, ChunksAdapter,
+//!   WindowsAdapter, StepByAdapter, DedupAdapter, ScanAdapter, InspectAdapter)
+//!   with `_new` and `_next` functions
+//! - Generators (repeat, unfold, iterate), which may be endless
+//! - Terminal operations (count, sum, any, all, find, reduce, min_by, max_by,
+//!   group_by, partition)
 //!
 //! All combinator adapters are lazy -- they do not allocate intermediate
 //! collections. Each adapter's `_next` function delegates to its source
@@ -14,15 +19,15 @@
 
 use crate::gc::mesh_gc_alloc_actor;
 use crate::option::{MeshOption, alloc_option};
-use crate::collections::list::alloc_pair;
+use crate::collections::list::{alloc_pair, collect_rooted};
 use crate::collections::list::mesh_list_iter_next;
-use crate::collections::list::mesh_list_from_array;
+use crate::collections::list::{mesh_list_append, mesh_list_length, mesh_list_new, mesh_list_tail};
 use crate::collections::map::mesh_map_iter_next;
-use crate::collections::map::{mesh_map_new, mesh_map_put};
+use crate::collections::map::{mesh_map_get, mesh_map_has_key, mesh_map_new, mesh_map_put};
 use crate::collections::set::mesh_set_iter_next;
 use crate::collections::set::{mesh_set_new, mesh_set_add};
 use crate::collections::range::mesh_range_iter_next;
-use crate::string::{MeshString, mesh_string_new, mesh_string_concat};
+use crate::string::{MeshString, mesh_string_new, mesh_string_concat, mesh_string_iter_next};
 
 // ── Type tag constants ──────────────────────────────────────────────────
 
@@ -30,19 +35,33 @@ pub const ITER_TAG_LIST: u8 = 0;
 pub const ITER_TAG_MAP: u8 = 1;
 pub const ITER_TAG_SET: u8 = 2;
 pub const ITER_TAG_RANGE: u8 = 3;
+pub const ITER_TAG_STRING: u8 = 4;
 pub const ITER_TAG_MAP_ADAPTER: u8 = 10;
 pub const ITER_TAG_FILTER_ADAPTER: u8 = 11;
 pub const ITER_TAG_TAKE_ADAPTER: u8 = 12;
 pub const ITER_TAG_SKIP_ADAPTER: u8 = 13;
 pub const ITER_TAG_ENUMERATE_ADAPTER: u8 = 14;
 pub const ITER_TAG_ZIP_ADAPTER: u8 = 15;
+pub const ITER_TAG_TAKE_WHILE_ADAPTER: u8 = 16;
+pub const ITER_TAG_SKIP_WHILE_ADAPTER: u8 = 17;
+pub const ITER_TAG_FLAT_MAP_ADAPTER: u8 = 18;
+pub const ITER_TAG_CHAIN_ADAPTER: u8 = 19;
+pub const ITER_TAG_CHUNKS_ADAPTER: u8 = 20;
+pub const ITER_TAG_WINDOWS_ADAPTER: u8 = 21;
+pub const ITER_TAG_STEP_BY_ADAPTER: u8 = 22;
+pub const ITER_TAG_DEDUP_ADAPTER: u8 = 23;
+pub const ITER_TAG_SCAN_ADAPTER: u8 = 24;
+pub const ITER_TAG_INSPECT_ADAPTER: u8 = 25;
+pub const ITER_TAG_REPEAT: u8 = 30;
+pub const ITER_TAG_UNFOLD: u8 = 31;
+pub const ITER_TAG_ITERATE: u8 = 32;
 
 // ── Generic next dispatch ───────────────────────────────────────────────
 
 /// Generic next() dispatch. Reads the type tag (first byte of the iterator
 /// handle) and delegates to the correct `_next` function.
 #[no_mangle]
-pub extern "C" fn mesh_iter_generic_next(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_generic_next(iter: *mut u8) -> *mut u8 {
     unsafe {
         let tag = *iter; // First byte is the type tag
         match tag {
@@ -50,12 +69,26 @@ pub extern "C" fn mesh_iter_generic_next(iter: *mut u8) -> *mut u8 {
             ITER_TAG_MAP => mesh_map_iter_next(iter),
             ITER_TAG_SET => mesh_set_iter_next(iter),
             ITER_TAG_RANGE => mesh_range_iter_next(iter),
+            ITER_TAG_STRING => mesh_string_iter_next(iter),
             ITER_TAG_MAP_ADAPTER => mesh_iter_map_next(iter),
             ITER_TAG_FILTER_ADAPTER => mesh_iter_filter_next(iter),
             ITER_TAG_TAKE_ADAPTER => mesh_iter_take_next(iter),
             ITER_TAG_SKIP_ADAPTER => mesh_iter_skip_next(iter),
             ITER_TAG_ENUMERATE_ADAPTER => mesh_iter_enumerate_next(iter),
             ITER_TAG_ZIP_ADAPTER => mesh_iter_zip_next(iter),
+            ITER_TAG_TAKE_WHILE_ADAPTER => mesh_iter_take_while_next(iter),
+            ITER_TAG_SKIP_WHILE_ADAPTER => mesh_iter_skip_while_next(iter),
+            ITER_TAG_FLAT_MAP_ADAPTER => mesh_iter_flat_map_next(iter),
+            ITER_TAG_CHAIN_ADAPTER => mesh_iter_chain_next(iter),
+            ITER_TAG_CHUNKS_ADAPTER => mesh_iter_chunks_next(iter),
+            ITER_TAG_WINDOWS_ADAPTER => mesh_iter_windows_next(iter),
+            ITER_TAG_STEP_BY_ADAPTER => mesh_iter_step_by_next(iter),
+            ITER_TAG_DEDUP_ADAPTER => mesh_iter_dedup_next(iter),
+            ITER_TAG_SCAN_ADAPTER => mesh_iter_scan_next(iter),
+            ITER_TAG_INSPECT_ADAPTER => mesh_iter_inspect_next(iter),
+            ITER_TAG_REPEAT => mesh_iter_repeat_next(iter),
+            ITER_TAG_UNFOLD => mesh_iter_unfold_next(iter),
+            ITER_TAG_ITERATE => mesh_iter_iterate_next(iter),
             _ => alloc_option(1, std::ptr::null_mut()) as *mut u8, // Unknown -> None
         }
     }
@@ -64,10 +97,10 @@ pub extern "C" fn mesh_iter_generic_next(iter: *mut u8) -> *mut u8 {
 // ── Combinator Adapter Structs ──────────────────────────────────────────
 
 // Closure calling type aliases (proven from list.rs)
-type BareFn = unsafe extern "C" fn(u64) -> u64;
-type ClosureFn = unsafe extern "C" fn(*mut u8, u64) -> u64;
-type BareFn2 = unsafe extern "C" fn(u64, u64) -> u64;
-type ClosureFn2 = unsafe extern "C" fn(*mut u8, u64, u64) -> u64;
+type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
+type BareFn2 = unsafe extern "C-unwind" fn(u64, u64) -> u64;
+type ClosureFn2 = unsafe extern "C-unwind" fn(*mut u8, u64, u64) -> u64;
 
 // ── MapAdapter (tag=10) ─────────────────────────────────────────────────
 
@@ -82,7 +115,7 @@ struct MapAdapter {
 
 /// Create a lazy map adapter: Iter.map(source, fn_ptr, env_ptr).
 #[no_mangle]
-pub extern "C" fn mesh_iter_map(
+pub extern "C-unwind" fn mesh_iter_map(
     source: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
@@ -102,7 +135,7 @@ pub extern "C" fn mesh_iter_map(
 
 /// Advance the map adapter: call source next(), apply fn, return mapped value.
 #[no_mangle]
-pub extern "C" fn mesh_iter_map_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_map_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut MapAdapter;
         let option = mesh_iter_generic_next((*adapter).source);
@@ -135,7 +168,7 @@ struct FilterAdapter {
 
 /// Create a lazy filter adapter: Iter.filter(source, fn_ptr, env_ptr).
 #[no_mangle]
-pub extern "C" fn mesh_iter_filter(
+pub extern "C-unwind" fn mesh_iter_filter(
     source: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
@@ -156,7 +189,7 @@ pub extern "C" fn mesh_iter_filter(
 /// Advance the filter adapter: loop calling source next() until predicate
 /// passes or source is exhausted.
 #[no_mangle]
-pub extern "C" fn mesh_iter_filter_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_filter_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut FilterAdapter;
         loop {
@@ -193,7 +226,7 @@ struct TakeAdapter {
 
 /// Create a lazy take adapter: Iter.take(source, n).
 #[no_mangle]
-pub extern "C" fn mesh_iter_take(source: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_take(source: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let adapter = mesh_gc_alloc_actor(
             std::mem::size_of::<TakeAdapter>() as u64,
@@ -209,7 +242,7 @@ pub extern "C" fn mesh_iter_take(source: *mut u8, n: i64) -> *mut u8 {
 /// Advance the take adapter: return None if remaining <= 0, else delegate
 /// to source and decrement remaining.
 #[no_mangle]
-pub extern "C" fn mesh_iter_take_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_take_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut TakeAdapter;
         if (*adapter).remaining <= 0 {
@@ -233,7 +266,7 @@ struct SkipAdapter {
 
 /// Create a lazy skip adapter: Iter.skip(source, n).
 #[no_mangle]
-pub extern "C" fn mesh_iter_skip(source: *mut u8, n: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_skip(source: *mut u8, n: i64) -> *mut u8 {
     unsafe {
         let adapter = mesh_gc_alloc_actor(
             std::mem::size_of::<SkipAdapter>() as u64,
@@ -250,7 +283,7 @@ pub extern "C" fn mesh_iter_skip(source: *mut u8, n: i64) -> *mut u8 {
 /// Advance the skip adapter: on first call, skip `n` elements from source,
 /// then delegate to source.
 #[no_mangle]
-pub extern "C" fn mesh_iter_skip_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_skip_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut SkipAdapter;
         if (*adapter).skipped == 0 {
@@ -281,7 +314,7 @@ struct EnumerateAdapter {
 
 /// Create a lazy enumerate adapter: Iter.enumerate(source).
 #[no_mangle]
-pub extern "C" fn mesh_iter_enumerate(source: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_enumerate(source: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = mesh_gc_alloc_actor(
             std::mem::size_of::<EnumerateAdapter>() as u64,
@@ -297,7 +330,7 @@ pub extern "C" fn mesh_iter_enumerate(source: *mut u8) -> *mut u8 {
 /// Advance the enumerate adapter: call source next(), if Some wrap in
 /// (index, value) pair, increment index.
 #[no_mangle]
-pub extern "C" fn mesh_iter_enumerate_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_enumerate_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut EnumerateAdapter;
         let option = mesh_iter_generic_next((*adapter).source);
@@ -325,7 +358,7 @@ struct ZipAdapter {
 
 /// Create a lazy zip adapter: Iter.zip(source_a, source_b).
 #[no_mangle]
-pub extern "C" fn mesh_iter_zip(source_a: *mut u8, source_b: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_zip(source_a: *mut u8, source_b: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = mesh_gc_alloc_actor(
             std::mem::size_of::<ZipAdapter>() as u64,
@@ -341,7 +374,7 @@ pub extern "C" fn mesh_iter_zip(source_a: *mut u8, source_b: *mut u8) -> *mut u8
 /// Advance the zip adapter: call next() on both sources. If either is None,
 /// return None. Otherwise return alloc_pair(a, b) wrapped in Some.
 #[no_mangle]
-pub extern "C" fn mesh_iter_zip_next(adapter_ptr: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_iter_zip_next(adapter_ptr: *mut u8) -> *mut u8 {
     unsafe {
         let adapter = adapter_ptr as *mut ZipAdapter;
         let opt_a = mesh_iter_generic_next((*adapter).source_a);
@@ -361,11 +394,659 @@ pub extern "C" fn mesh_iter_zip_next(adapter_ptr: *mut u8) -> *mut u8 {
     }
 }
 
+// ── Shared adapter helpers ──────────────────────────────────────────────
+
+/// Allocate a zeroed adapter struct on the actor heap.
+unsafe fn alloc_adapter<T>() -> *mut T {
+    mesh_gc_alloc_actor(
+        std::mem::size_of::<T>() as u64,
+        std::mem::align_of::<T>() as u64,
+    ) as *mut T
+}
+
+/// Pull the next element from `iter`, or `None` once it is exhausted.
+unsafe fn next_elem(iter: *mut u8) -> Option<u64> {
+    let option = mesh_iter_generic_next(iter) as *mut MeshOption;
+    if (*option).tag == 1 {
+        None
+    } else {
+        Some((*option).value as u64)
+    }
+}
+
+/// Call a one-argument Mesh function (bare when `env_ptr` is null).
+unsafe fn call1(fn_ptr: *mut u8, env_ptr: *mut u8, arg: u64) -> u64 {
+    if env_ptr.is_null() {
+        let f: BareFn = std::mem::transmute(fn_ptr);
+        f(arg)
+    } else {
+        let f: ClosureFn = std::mem::transmute(fn_ptr);
+        f(env_ptr, arg)
+    }
+}
+
+/// Call a two-argument Mesh function (bare when `env_ptr` is null).
+unsafe fn call2(fn_ptr: *mut u8, env_ptr: *mut u8, a: u64, b: u64) -> u64 {
+    if env_ptr.is_null() {
+        let f: BareFn2 = std::mem::transmute(fn_ptr);
+        f(a, b)
+    } else {
+        let f: ClosureFn2 = std::mem::transmute(fn_ptr);
+        f(env_ptr, a, b)
+    }
+}
+
+fn some(value: u64) -> *mut u8 {
+    alloc_option(0, value as *mut u8) as *mut u8
+}
+
+fn none() -> *mut u8 {
+    alloc_option(1, std::ptr::null_mut()) as *mut u8
+}
+
+// ── TakeWhileAdapter (tag=16) ───────────────────────────────────────────
+
+/// Adapter state for Iter.take_while(iter, fn).
+#[repr(C)]
+struct TakeWhileAdapter {
+    tag: u8,
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    done: u8,
+}
+
+/// Create a lazy take_while adapter: Iter.take_while(source, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_take_while(
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<TakeWhileAdapter>();
+        (*adapter).tag = ITER_TAG_TAKE_WHILE_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        (*adapter).done = 0;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the take_while adapter: yield source elements until the first
+/// one that fails the predicate, then stay exhausted.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_take_while_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut TakeWhileAdapter;
+        if (*adapter).done != 0 {
+            return none();
+        }
+        match next_elem((*adapter).source) {
+            Some(elem) if call1((*adapter).fn_ptr, (*adapter).env_ptr, elem) != 0 => some(elem),
+            _ => {
+                (*adapter).done = 1;
+                none()
+            }
+        }
+    }
+}
+
+// ── SkipWhileAdapter (tag=17) ───────────────────────────────────────────
+
+/// Adapter state for Iter.skip_while(iter, fn).
+#[repr(C)]
+struct SkipWhileAdapter {
+    tag: u8,
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    skipped: u8, // 0 = still skipping, 1 = predicate has failed once
+}
+
+/// Create a lazy skip_while adapter: Iter.skip_while(source, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_skip_while(
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<SkipWhileAdapter>();
+        (*adapter).tag = ITER_TAG_SKIP_WHILE_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        (*adapter).skipped = 0;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the skip_while adapter: drop leading elements that pass the
+/// predicate, then delegate to source.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_skip_while_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut SkipWhileAdapter;
+        if (*adapter).skipped != 0 {
+            return mesh_iter_generic_next((*adapter).source);
+        }
+        while let Some(elem) = next_elem((*adapter).source) {
+            if call1((*adapter).fn_ptr, (*adapter).env_ptr, elem) == 0 {
+                (*adapter).skipped = 1;
+                return some(elem);
+            }
+        }
+        (*adapter).skipped = 1;
+        none()
+    }
+}
+
+// ── FlatMapAdapter (tag=18) ─────────────────────────────────────────────
+
+/// Adapter state for Iter.flat_map(iter, fn). `fn` returns an iterator
+/// whose elements are yielded in turn.
+#[repr(C)]
+struct FlatMapAdapter {
+    tag: u8,
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    inner: *mut u8, // current inner iterator, null between elements
+}
+
+/// Create a lazy flat_map adapter: Iter.flat_map(source, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_flat_map(
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<FlatMapAdapter>();
+        (*adapter).tag = ITER_TAG_FLAT_MAP_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        (*adapter).inner = std::ptr::null_mut();
+        adapter as *mut u8
+    }
+}
+
+/// Advance the flat_map adapter: drain the current inner iterator, mapping
+/// the next source element to a new one whenever it runs out.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_flat_map_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut FlatMapAdapter;
+        loop {
+            if !(*adapter).inner.is_null() {
+                if let Some(elem) = next_elem((*adapter).inner) {
+                    return some(elem);
+                }
+                (*adapter).inner = std::ptr::null_mut();
+            }
+            match next_elem((*adapter).source) {
+                Some(elem) => {
+                    (*adapter).inner = call1((*adapter).fn_ptr, (*adapter).env_ptr, elem) as *mut u8;
+                }
+                None => return none(),
+            }
+        }
+    }
+}
+
+// ── ChainAdapter (tag=19) ───────────────────────────────────────────────
+
+/// Adapter state for Iter.chain(iter_a, iter_b).
+#[repr(C)]
+struct ChainAdapter {
+    tag: u8,
+    source_a: *mut u8,
+    source_b: *mut u8,
+    on_b: u8, // 0 = reading source_a, 1 = source_a exhausted
+}
+
+/// Create a lazy chain adapter: Iter.chain(source_a, source_b).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_chain(source_a: *mut u8, source_b: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<ChainAdapter>();
+        (*adapter).tag = ITER_TAG_CHAIN_ADAPTER;
+        (*adapter).source_a = source_a;
+        (*adapter).source_b = source_b;
+        (*adapter).on_b = 0;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the chain adapter: yield all of source_a, then all of source_b.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_chain_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut ChainAdapter;
+        if (*adapter).on_b == 0 {
+            if let Some(elem) = next_elem((*adapter).source_a) {
+                return some(elem);
+            }
+            (*adapter).on_b = 1;
+        }
+        mesh_iter_generic_next((*adapter).source_b)
+    }
+}
+
+// ── ChunksAdapter (tag=20) ──────────────────────────────────────────────
+
+/// Adapter state for Iter.chunks(iter, n).
+#[repr(C)]
+struct ChunksAdapter {
+    tag: u8,
+    source: *mut u8,
+    size: i64,
+}
+
+/// Create a lazy chunks adapter: Iter.chunks(source, n). Sizes below 1
+/// are treated as 1.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_chunks(source: *mut u8, n: i64) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<ChunksAdapter>();
+        (*adapter).tag = ITER_TAG_CHUNKS_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).size = n.max(1);
+        adapter as *mut u8
+    }
+}
+
+/// Advance the chunks adapter: yield a List of the next `n` elements. The
+/// last chunk holds whatever is left and may be shorter.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_chunks_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut ChunksAdapter;
+        let mut chunk = mesh_list_new();
+        for _ in 0..(*adapter).size {
+            match next_elem((*adapter).source) {
+                Some(elem) => chunk = mesh_list_append(chunk, elem),
+                None => break,
+            }
+        }
+        if mesh_list_length(chunk) == 0 {
+            return none();
+        }
+        some(chunk as u64)
+    }
+}
+
+// ── WindowsAdapter (tag=21) ─────────────────────────────────────────────
+
+/// Adapter state for Iter.windows(iter, n).
+#[repr(C)]
+struct WindowsAdapter {
+    tag: u8,
+    source: *mut u8,
+    size: i64,
+    window: *mut u8, // last window yielded, null before the first
+}
+
+/// Create a lazy windows adapter: Iter.windows(source, n). Sizes below 1
+/// are treated as 1.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_windows(source: *mut u8, n: i64) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<WindowsAdapter>();
+        (*adapter).tag = ITER_TAG_WINDOWS_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).size = n.max(1);
+        (*adapter).window = std::ptr::null_mut();
+        adapter as *mut u8
+    }
+}
+
+/// Advance the windows adapter: yield overlapping Lists of exactly `n`
+/// consecutive elements. A source shorter than `n` yields nothing.
+///
+/// Each window shares structure with the previous one: the next window is
+/// the previous one with its first element dropped and the new one appended.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_windows_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut WindowsAdapter;
+        let window = if (*adapter).window.is_null() {
+            let mut window = mesh_list_new();
+            for _ in 0..(*adapter).size {
+                match next_elem((*adapter).source) {
+                    Some(elem) => window = mesh_list_append(window, elem),
+                    None => return none(),
+                }
+            }
+            window
+        } else {
+            match next_elem((*adapter).source) {
+                Some(elem) => mesh_list_append(mesh_list_tail((*adapter).window), elem),
+                None => return none(),
+            }
+        };
+        (*adapter).window = window;
+        some(window as u64)
+    }
+}
+
+// ── StepByAdapter (tag=22) ──────────────────────────────────────────────
+
+/// Adapter state for Iter.step_by(iter, n).
+#[repr(C)]
+struct StepByAdapter {
+    tag: u8,
+    source: *mut u8,
+    step: i64,
+    started: u8, // 0 = first element not yet yielded
+}
+
+/// Create a lazy step_by adapter: Iter.step_by(source, n). Steps below 1
+/// are treated as 1.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_step_by(source: *mut u8, n: i64) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<StepByAdapter>();
+        (*adapter).tag = ITER_TAG_STEP_BY_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).step = n.max(1);
+        (*adapter).started = 0;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the step_by adapter: yield the first element, then every `n`th
+/// element after it.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_step_by_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut StepByAdapter;
+        if (*adapter).started == 0 {
+            (*adapter).started = 1;
+            return mesh_iter_generic_next((*adapter).source);
+        }
+        for _ in 1..(*adapter).step {
+            if next_elem((*adapter).source).is_none() {
+                return none();
+            }
+        }
+        mesh_iter_generic_next((*adapter).source)
+    }
+}
+
+// ── DedupAdapter (tag=23) ───────────────────────────────────────────────
+
+/// Adapter state for Iter.dedup(iter) and Iter.dedup_by(iter, fn).
+#[repr(C)]
+struct DedupAdapter {
+    tag: u8,
+    source: *mut u8,
+    fn_ptr: *mut u8, // null = compare values directly
+    env_ptr: *mut u8,
+    has_prev: u8,
+    prev: u64,
+}
+
+/// Create a lazy dedup adapter: Iter.dedup(source). Consecutive elements
+/// are compared by value, which suits Int, Bool and other immediate values.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_dedup(source: *mut u8) -> *mut u8 {
+    mesh_iter_dedup_by(source, std::ptr::null_mut(), std::ptr::null_mut())
+}
+
+/// Create a lazy dedup adapter that compares consecutive elements with
+/// `fn(prev, next) -> Bool`: Iter.dedup_by(source, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_dedup_by(
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<DedupAdapter>();
+        (*adapter).tag = ITER_TAG_DEDUP_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        (*adapter).has_prev = 0;
+        (*adapter).prev = 0;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the dedup adapter: skip elements equal to the one yielded last.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_dedup_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut DedupAdapter;
+        while let Some(elem) = next_elem((*adapter).source) {
+            let repeat = (*adapter).has_prev != 0
+                && if (*adapter).fn_ptr.is_null() {
+                    elem == (*adapter).prev
+                } else {
+                    call2((*adapter).fn_ptr, (*adapter).env_ptr, (*adapter).prev, elem) != 0
+                };
+            if !repeat {
+                (*adapter).has_prev = 1;
+                (*adapter).prev = elem;
+                return some(elem);
+            }
+        }
+        none()
+    }
+}
+
+// ── ScanAdapter (tag=24) ────────────────────────────────────────────────
+
+/// Adapter state for Iter.scan(iter, init, fn).
+#[repr(C)]
+struct ScanAdapter {
+    tag: u8,
+    source: *mut u8,
+    acc: u64,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+}
+
+/// Create a lazy scan adapter: Iter.scan(source, init, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_scan(
+    source: *mut u8,
+    init: u64,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<ScanAdapter>();
+        (*adapter).tag = ITER_TAG_SCAN_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).acc = init;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the scan adapter: fold the next element into the accumulator
+/// and yield the new accumulator.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_scan_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut ScanAdapter;
+        match next_elem((*adapter).source) {
+            Some(elem) => {
+                (*adapter).acc = call2((*adapter).fn_ptr, (*adapter).env_ptr, (*adapter).acc, elem);
+                some((*adapter).acc)
+            }
+            None => none(),
+        }
+    }
+}
+
+// ── InspectAdapter (tag=25) ─────────────────────────────────────────────
+
+/// Adapter state for Iter.inspect(iter, fn).
+#[repr(C)]
+struct InspectAdapter {
+    tag: u8,
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+}
+
+/// Create a lazy inspect adapter: Iter.inspect(source, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_inspect(
+    source: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let adapter = alloc_adapter::<InspectAdapter>();
+        (*adapter).tag = ITER_TAG_INSPECT_ADAPTER;
+        (*adapter).source = source;
+        (*adapter).fn_ptr = fn_ptr;
+        (*adapter).env_ptr = env_ptr;
+        adapter as *mut u8
+    }
+}
+
+/// Advance the inspect adapter: call fn on each element for its side
+/// effects and pass the element through unchanged.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_inspect_next(adapter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let adapter = adapter_ptr as *mut InspectAdapter;
+        let option = mesh_iter_generic_next((*adapter).source);
+        let option_ref = option as *mut MeshOption;
+        if (*option_ref).tag == 0 {
+            call1((*adapter).fn_ptr, (*adapter).env_ptr, (*option_ref).value as u64);
+        }
+        option
+    }
+}
+
+// ── Generators ──────────────────────────────────────────────────────────
+
+/// Generator state for Iter.repeat(value).
+#[repr(C)]
+struct RepeatIter {
+    tag: u8,
+    value: u64,
+}
+
+/// Create an endless iterator of `value`: Iter.repeat(value).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_repeat(value: u64) -> *mut u8 {
+    unsafe {
+        let iter = alloc_adapter::<RepeatIter>();
+        (*iter).tag = ITER_TAG_REPEAT;
+        (*iter).value = value;
+        iter as *mut u8
+    }
+}
+
+/// Advance Iter.repeat: always Some(value).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_repeat_next(iter_ptr: *mut u8) -> *mut u8 {
+    unsafe { some((*(iter_ptr as *mut RepeatIter)).value) }
+}
+
+/// Generator state for Iter.unfold(state, fn) and Iter.iterate(value, fn).
+#[repr(C)]
+struct UnfoldIter {
+    tag: u8,
+    state: u64,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    done: u8,
+}
+
+/// Unfold callbacks return a Mesh `Option<(T, S)>` by value, which has the
+/// same C layout as `MeshOption`.
+type BareUnfoldFn = unsafe extern "C-unwind" fn(u64) -> MeshOption;
+type ClosureUnfoldFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> MeshOption;
+
+/// Create an iterator driven by `fn(state) -> Option<(T, S)>`:
+/// Iter.unfold(state, fn_ptr, env_ptr). Each `Some((elem, next))` yields
+/// `elem` and continues from `next`; `None` ends the iterator.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_unfold(state: u64, fn_ptr: *mut u8, env_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let iter = alloc_adapter::<UnfoldIter>();
+        (*iter).tag = ITER_TAG_UNFOLD;
+        (*iter).state = state;
+        (*iter).fn_ptr = fn_ptr;
+        (*iter).env_ptr = env_ptr;
+        (*iter).done = 0;
+        iter as *mut u8
+    }
+}
+
+/// Advance Iter.unfold: call fn with the current state.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_unfold_next(iter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let iter = iter_ptr as *mut UnfoldIter;
+        if (*iter).done != 0 {
+            return none();
+        }
+        let step = if (*iter).env_ptr.is_null() {
+            let f: BareUnfoldFn = std::mem::transmute((*iter).fn_ptr);
+            f((*iter).state)
+        } else {
+            let f: ClosureUnfoldFn = std::mem::transmute((*iter).fn_ptr);
+            f((*iter).env_ptr, (*iter).state)
+        };
+        if step.tag == 1 {
+            (*iter).done = 1;
+            return none();
+        }
+        // Payload is a tuple: { u64 len=2, u64 elem, u64 next_state }
+        let tuple = step.value as *const u64;
+        (*iter).state = *tuple.add(2);
+        some(*tuple.add(1))
+    }
+}
+
+/// Create an endless iterator of `value`, `fn(value)`, `fn(fn(value))`, ...:
+/// Iter.iterate(value, fn_ptr, env_ptr).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_iterate(value: u64, fn_ptr: *mut u8, env_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let iter = alloc_adapter::<UnfoldIter>();
+        (*iter).tag = ITER_TAG_ITERATE;
+        (*iter).state = value;
+        (*iter).fn_ptr = fn_ptr;
+        (*iter).env_ptr = env_ptr;
+        (*iter).done = 0; // 0 = `state` not yet yielded
+        iter as *mut u8
+    }
+}
+
+/// Advance Iter.iterate: yield the seed first, then apply fn to the
+/// previous element.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_iterate_next(iter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let iter = iter_ptr as *mut UnfoldIter;
+        if (*iter).done == 0 {
+            (*iter).done = 1;
+        } else {
+            (*iter).state = call1((*iter).fn_ptr, (*iter).env_ptr, (*iter).state);
+        }
+        some((*iter).state)
+    }
+}
+
 // ── Terminal Operations ─────────────────────────────────────────────────
 
 /// Iter.count(iter) -- count elements until exhausted.
 #[no_mangle]
-pub extern "C" fn mesh_iter_count(iter: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_iter_count(iter: *mut u8) -> i64 {
     unsafe {
         let mut count: i64 = 0;
         loop {
@@ -382,7 +1063,7 @@ pub extern "C" fn mesh_iter_count(iter: *mut u8) -> i64 {
 
 /// Iter.sum(iter) -- sum numeric (Int) elements until exhausted.
 #[no_mangle]
-pub extern "C" fn mesh_iter_sum(iter: *mut u8) -> i64 {
+pub extern "C-unwind" fn mesh_iter_sum(iter: *mut u8) -> i64 {
     unsafe {
         let mut sum: i64 = 0;
         loop {
@@ -400,7 +1081,7 @@ pub extern "C" fn mesh_iter_sum(iter: *mut u8) -> i64 {
 /// Iter.any(iter, fn) -- return 1 if any element passes predicate, 0 otherwise.
 /// Short-circuits on first match.
 #[no_mangle]
-pub extern "C" fn mesh_iter_any(
+pub extern "C-unwind" fn mesh_iter_any(
     iter: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
@@ -430,7 +1111,7 @@ pub extern "C" fn mesh_iter_any(
 /// Iter.all(iter, fn) -- return 1 if all elements pass predicate, 0 otherwise.
 /// Short-circuits on first non-match.
 #[no_mangle]
-pub extern "C" fn mesh_iter_all(
+pub extern "C-unwind" fn mesh_iter_all(
     iter: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
@@ -459,7 +1140,7 @@ pub extern "C" fn mesh_iter_all(
 
 /// Iter.find(iter, fn) -- return Option: Some(elem) on first match, None if exhausted.
 #[no_mangle]
-pub extern "C" fn mesh_iter_find(
+pub extern "C-unwind" fn mesh_iter_find(
     iter: *mut u8,
     fn_ptr: *mut u8,
     env_ptr: *mut u8,
@@ -488,7 +1169,7 @@ pub extern "C" fn mesh_iter_find(
 
 /// Iter.reduce(iter, init, fn) -- fold with accumulator.
 #[no_mangle]
-pub extern "C" fn mesh_iter_reduce(
+pub extern "C-unwind" fn mesh_iter_reduce(
     iter: *mut u8,
     init: u64,
     fn_ptr: *mut u8,
@@ -515,32 +1196,118 @@ pub extern "C" fn mesh_iter_reduce(
     }
 }
 
+/// Iter.min_by(iter, fn) -- Option of the smallest element under the
+/// comparator `fn(a, b) -> Int` (negative when a < b, as for List.sort).
+/// Ties keep the first element.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_min_by(
+    iter: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe { extreme_by(iter, fn_ptr, env_ptr, |ord| ord < 0) }
+}
+
+/// Iter.max_by(iter, fn) -- Option of the largest element under the
+/// comparator `fn(a, b) -> Int`. Ties keep the last element.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_max_by(
+    iter: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe { extreme_by(iter, fn_ptr, env_ptr, |ord| ord >= 0) }
+}
+
+/// Keep the element for which `replaces(cmp(elem, best))` holds.
+unsafe fn extreme_by(
+    iter: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    replaces: impl Fn(i64) -> bool,
+) -> *mut u8 {
+    let Some(mut best) = next_elem(iter) else {
+        return none();
+    };
+    while let Some(elem) = next_elem(iter) {
+        if replaces(call2(fn_ptr, env_ptr, elem, best) as i64) {
+            best = elem;
+        }
+    }
+    some(best)
+}
+
+/// Iter.group_by(iter, fn) -- Map from each key `fn(elem)` to the List of
+/// elements with that key, in iteration order. `groups` is the empty map to
+/// fill; codegen passes one already tagged for the key type so that string,
+/// struct and tuple keys group by value.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_group_by(
+    iter: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+    groups: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let mut groups = groups;
+        while let Some(elem) = next_elem(iter) {
+            let key = call1(fn_ptr, env_ptr, elem);
+            let group = if mesh_map_has_key(groups, key) != 0 {
+                mesh_map_get(groups, key) as *mut u8
+            } else {
+                mesh_list_new()
+            };
+            groups = mesh_map_put(groups, key, mesh_list_append(group, elem) as u64);
+        }
+        groups
+    }
+}
+
+/// Iter.partition(iter, fn) -- (List of elements passing the predicate,
+/// List of the rest), both in iteration order.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_partition(
+    iter: *mut u8,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    unsafe {
+        let mut pass = mesh_list_new();
+        let mut fail = mesh_list_new();
+        while let Some(elem) = next_elem(iter) {
+            if call1(fn_ptr, env_ptr, elem) != 0 {
+                pass = mesh_list_append(pass, elem);
+            } else {
+                fail = mesh_list_append(fail, elem);
+            }
+        }
+        alloc_pair(pass as u64, fail as u64)
+    }
+}
+
 // ── Collect Terminal Operations (Phase 79) ──────────────────────────
 
 /// List.collect(iter) -- materialize iterator into a List.
-/// Collects all elements into a safe Rust Vec, then builds the final
-/// GC-allocated list via mesh_list_from_array in one shot.
-/// This avoids mesh_list_builder_push which has NO bounds checking.
+/// Elements are pushed into a rooted list as they are produced, since
+/// pulling from a lazy adapter can run closures that trigger a collection.
 #[no_mangle]
-pub extern "C" fn mesh_list_collect(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_list_collect(iter: *mut u8) -> *mut u8 {
     unsafe {
-        let mut elements: Vec<u64> = Vec::new();
-        loop {
-            let option = mesh_iter_generic_next(iter);
-            let opt_ref = option as *mut MeshOption;
+        collect_rooted(std::iter::from_fn(|| {
+            let opt_ref = mesh_iter_generic_next(iter) as *mut MeshOption;
             if (*opt_ref).tag == 1 {
-                break; // None -- done
+                None // None -- done
+            } else {
+                Some((*opt_ref).value as u64)
             }
-            elements.push((*opt_ref).value as u64);
-        }
-        mesh_list_from_array(elements.as_ptr(), elements.len() as i64)
+        }))
     }
 }
 
 /// Map.collect(iter) -- materialize iterator of (key, value) tuples into a Map.
 /// Expects each element to be a tuple pointer with layout { len: u64, key: u64, value: u64 }.
 #[no_mangle]
-pub extern "C" fn mesh_map_collect(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_collect(iter: *mut u8) -> *mut u8 {
     unsafe {
         let mut map = mesh_map_new();
         loop {
@@ -563,7 +1330,7 @@ pub extern "C" fn mesh_map_collect(iter: *mut u8) -> *mut u8 {
 /// tuples into a Map with string key_type (KEY_TYPE_STR = 1).
 /// Called by codegen when the type checker infers the map's key type as String.
 #[no_mangle]
-pub extern "C" fn mesh_map_collect_string_keys(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_map_collect_string_keys(iter: *mut u8) -> *mut u8 {
     unsafe {
         // Create map with string key_type from the start (key_type = 1)
         let mut map = crate::collections::map::mesh_map_new_typed(1);
@@ -584,9 +1351,10 @@ pub extern "C" fn mesh_map_collect_string_keys(iter: *mut u8) -> *mut u8 {
 }
 
 /// Set.collect(iter) -- materialize iterator into a Set.
-/// Duplicates are handled automatically by mesh_set_add.
+/// Duplicates are handled automatically by mesh_set_add; codegen retags the
+/// result for string and hashed element types.
 #[no_mangle]
-pub extern "C" fn mesh_set_collect(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_set_collect(iter: *mut u8) -> *mut u8 {
     unsafe {
         let mut set = mesh_set_new();
         loop {
@@ -605,7 +1373,7 @@ pub extern "C" fn mesh_set_collect(iter: *mut u8) -> *mut u8 {
 /// String.collect(iter) -- materialize string iterator into a single concatenated String.
 /// Each yielded value is treated as a *const MeshString pointer (NOT an integer).
 #[no_mangle]
-pub extern "C" fn mesh_string_collect(iter: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_string_collect(iter: *mut u8) -> *mut u8 {
     unsafe {
         let mut result = mesh_string_new(std::ptr::null(), 0) as *mut u8;
         loop {
@@ -684,4 +1452,162 @@ mod tests {
             assert_eq!(mesh_set_size(collected_set), 3); // Deduplication: {1, 2, 3}
         }
     }
+
+    fn list_iter(values: &[u64]) -> *mut u8 {
+        mesh_list_iter_new(mesh_list_from_array(values.as_ptr(), values.len() as i64))
+    }
+
+    fn collect_vec(iter: *mut u8) -> Vec<u64> {
+        let list = mesh_list_collect(iter);
+        (0..mesh_list_length(list)).map(|i| mesh_list_get(list, i)).collect()
+    }
+
+    fn list_vec(list: u64) -> Vec<u64> {
+        let list = list as *mut u8;
+        (0..mesh_list_length(list)).map(|i| mesh_list_get(list, i)).collect()
+    }
+
+    extern "C-unwind" fn less_than_3(x: u64) -> u64 {
+        (x < 3) as u64
+    }
+
+    extern "C-unwind" fn is_even(x: u64) -> u64 {
+        x.is_multiple_of(2) as u64
+    }
+
+    extern "C-unwind" fn add(a: u64, b: u64) -> u64 {
+        a + b
+    }
+
+    extern "C-unwind" fn compare(a: u64, b: u64) -> u64 {
+        (a as i64 - b as i64) as u64
+    }
+
+    extern "C-unwind" fn compare_tens(a: u64, b: u64) -> u64 {
+        (a as i64 / 10 - b as i64 / 10) as u64
+    }
+
+    extern "C-unwind" fn double(x: u64) -> u64 {
+        x * 2
+    }
+
+    extern "C-unwind" fn mod_3(x: u64) -> u64 {
+        x % 3
+    }
+
+    extern "C-unwind" fn same_tens(a: u64, b: u64) -> u64 {
+        (a / 10 == b / 10) as u64
+    }
+
+    extern "C-unwind" fn pair_of(x: u64) -> u64 {
+        list_iter(&[x, x]) as u64
+    }
+
+    extern "C-unwind" fn count_into(env: *mut u8, x: u64) -> u64 {
+        unsafe { *(env as *mut u64) += x };
+        0
+    }
+
+    extern "C-unwind" fn countdown(n: u64) -> MeshOption {
+        if n == 0 {
+            MeshOption { tag: 1, value: std::ptr::null_mut() }
+        } else {
+            MeshOption { tag: 0, value: unsafe { alloc_pair(n * 10, n - 1) } }
+        }
+    }
+
+    #[test]
+    fn test_take_while_and_skip_while() {
+        init_runtime();
+        let taken = mesh_iter_take_while(list_iter(&[1, 2, 3, 1]), less_than_3 as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(taken), vec![1, 2]);
+        let skipped = mesh_iter_skip_while(list_iter(&[1, 2, 3, 1]), less_than_3 as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(skipped), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_flat_map_and_chain() {
+        init_runtime();
+        let flat = mesh_iter_flat_map(list_iter(&[1, 2]), pair_of as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(flat), vec![1, 1, 2, 2]);
+        let chained = mesh_iter_chain(list_iter(&[1, 2]), list_iter(&[3]));
+        assert_eq!(collect_vec(chained), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_chunks_and_windows() {
+        init_runtime();
+        let chunks: Vec<Vec<u64>> = collect_vec(mesh_iter_chunks(list_iter(&[1, 2, 3, 4, 5]), 2))
+            .into_iter()
+            .map(list_vec)
+            .collect();
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+        let windows: Vec<Vec<u64>> = collect_vec(mesh_iter_windows(list_iter(&[1, 2, 3, 4]), 3))
+            .into_iter()
+            .map(list_vec)
+            .collect();
+        assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4]]);
+        assert!(collect_vec(mesh_iter_windows(list_iter(&[1, 2]), 3)).is_empty());
+    }
+
+    #[test]
+    fn test_step_by_dedup_and_scan() {
+        init_runtime();
+        assert_eq!(collect_vec(mesh_iter_step_by(list_iter(&[0, 1, 2, 3, 4, 5, 6]), 3)), vec![0, 3, 6]);
+        assert_eq!(collect_vec(mesh_iter_dedup(list_iter(&[1, 1, 2, 1, 1]))), vec![1, 2, 1]);
+        let by_tens = mesh_iter_dedup_by(list_iter(&[11, 12, 25, 31, 39]), same_tens as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(by_tens), vec![11, 25, 31]);
+        let sums = mesh_iter_scan(list_iter(&[1, 2, 3]), 10, add as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(sums), vec![11, 13, 16]);
+    }
+
+    #[test]
+    fn test_inspect_is_lazy() {
+        init_runtime();
+        let mut seen: u64 = 0;
+        let env = &mut seen as *mut u64 as *mut u8;
+        let inspected = mesh_iter_inspect(list_iter(&[1, 2, 3]), count_into as *mut u8, env);
+        let first_two = mesh_iter_take(inspected, 2);
+        assert_eq!(seen, 0);
+        assert_eq!(collect_vec(first_two), vec![1, 2]);
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn test_min_by_and_max_by() {
+        init_runtime();
+        unsafe {
+            let min = mesh_iter_min_by(list_iter(&[15, 3, 12, 31]), compare_tens as *mut u8, std::ptr::null_mut()) as *mut MeshOption;
+            assert_eq!(((*min).tag, (*min).value as u64), (0, 3));
+            let max = mesh_iter_max_by(list_iter(&[15, 3, 31, 38]), compare_tens as *mut u8, std::ptr::null_mut()) as *mut MeshOption;
+            assert_eq!(((*max).tag, (*max).value as u64), (0, 38));
+            let empty = mesh_iter_min_by(list_iter(&[]), compare as *mut u8, std::ptr::null_mut()) as *mut MeshOption;
+            assert_eq!((*empty).tag, 1);
+        }
+    }
+
+    #[test]
+    fn test_group_by_and_partition() {
+        init_runtime();
+        unsafe {
+            let groups = mesh_iter_group_by(list_iter(&[1, 2, 3, 4, 5, 6, 7]), mod_3 as *mut u8, std::ptr::null_mut(), mesh_map_new());
+            assert_eq!(mesh_map_size(groups), 3);
+            assert_eq!(list_vec(mesh_map_get(groups, 1)), vec![1, 4, 7]);
+            assert_eq!(list_vec(mesh_map_get(groups, 0)), vec![3, 6]);
+
+            let pair = mesh_iter_partition(list_iter(&[1, 2, 3, 4]), is_even as *mut u8, std::ptr::null_mut()) as *const u64;
+            assert_eq!(list_vec(*pair.add(1)), vec![2, 4]);
+            assert_eq!(list_vec(*pair.add(2)), vec![1, 3]);
+        }
+    }
+
+    #[test]
+    fn test_generators() {
+        init_runtime();
+        assert_eq!(collect_vec(mesh_iter_take(mesh_iter_repeat(7), 3)), vec![7, 7, 7]);
+        let powers = mesh_iter_iterate(1, double as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(mesh_iter_take(powers, 5)), vec![1, 2, 4, 8, 16]);
+        let unfolded = mesh_iter_unfold(3, countdown as *mut u8, std::ptr::null_mut());
+        assert_eq!(collect_vec(unfolded), vec![30, 20, 10]);
+    }
 }
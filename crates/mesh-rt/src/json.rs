@@ -21,13 +21,13 @@ use crate::io::MeshResult;
 use crate::string::{mesh_string_new, MeshString};
 
 /// Tag constants for MeshJson variants.
-const JSON_NULL: u8 = 0;
-const JSON_BOOL: u8 = 1;
-const JSON_INT: u8 = 2;
-const JSON_STR: u8 = 3;
-const JSON_ARRAY: u8 = 4;
-const JSON_OBJECT: u8 = 5;
-const JSON_FLOAT: u8 = 6;
+pub(crate) const JSON_NULL: u8 = 0;
+pub(crate) const JSON_BOOL: u8 = 1;
+pub(crate) const JSON_INT: u8 = 2;
+pub(crate) const JSON_STR: u8 = 3;
+pub(crate) const JSON_ARRAY: u8 = 4;
+pub(crate) const JSON_OBJECT: u8 = 5;
+pub(crate) const JSON_FLOAT: u8 = 6;
 
 /// GC-allocated JSON value.
 ///
@@ -182,7 +182,7 @@ unsafe fn mesh_json_to_serde_value(json: *const MeshJson) -> serde_json::Value {
 /// - tag 0 (Ok): value = pointer to MeshJson
 /// - tag 1 (Err): value = pointer to MeshString error message
 #[no_mangle]
-pub extern "C" fn mesh_json_parse(input: *const MeshString) -> *mut MeshResult {
+pub extern "C-unwind" fn mesh_json_parse(input: *const MeshString) -> *mut MeshResult {
     unsafe {
         let text = (*input).as_str();
         match serde_json::from_str::<serde_json::Value>(text) {
@@ -199,7 +199,7 @@ pub extern "C" fn mesh_json_parse(input: *const MeshString) -> *mut MeshResult {
 
 /// Encode a MeshJson value to a JSON string.
 #[no_mangle]
-pub extern "C" fn mesh_json_encode(json: *mut u8) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode(json: *mut u8) -> *mut MeshString {
     unsafe {
         let json_ptr = json as *const MeshJson;
         let val = mesh_json_to_serde_value(json_ptr);
@@ -212,7 +212,7 @@ pub extern "C" fn mesh_json_encode(json: *mut u8) -> *mut MeshString {
 
 /// Encode a Mesh string directly to a JSON string (with quotes).
 #[no_mangle]
-pub extern "C" fn mesh_json_encode_string(s: *const MeshString) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode_string(s: *const MeshString) -> *mut MeshString {
     unsafe {
         let text = (*s).as_str();
         let val = serde_json::Value::String(text.to_string());
@@ -223,14 +223,14 @@ pub extern "C" fn mesh_json_encode_string(s: *const MeshString) -> *mut MeshStri
 
 /// Encode an integer to a JSON string.
 #[no_mangle]
-pub extern "C" fn mesh_json_encode_int(val: i64) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode_int(val: i64) -> *mut MeshString {
     let text = val.to_string();
     mesh_string_new(text.as_ptr(), text.len() as u64)
 }
 
 /// Encode a boolean to a JSON string.
 #[no_mangle]
-pub extern "C" fn mesh_json_encode_bool(val: i8) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode_bool(val: i8) -> *mut MeshString {
     let text = if val != 0 { "true" } else { "false" };
     mesh_string_new(text.as_ptr(), text.len() as u64)
 }
@@ -240,7 +240,7 @@ pub extern "C" fn mesh_json_encode_bool(val: i8) -> *mut MeshString {
 /// Assumes map keys are MeshString pointers and values are MeshString pointers.
 /// Produces a JSON object like `{"key1":"val1","key2":"val2"}`.
 #[no_mangle]
-pub extern "C" fn mesh_json_encode_map(map_ptr: *mut u8) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode_map(map_ptr: *mut u8) -> *mut MeshString {
     unsafe {
         let keys = map::mesh_map_keys(map_ptr);
         let vals = map::mesh_map_values(map_ptr);
@@ -264,7 +264,7 @@ pub extern "C" fn mesh_json_encode_map(map_ptr: *mut u8) -> *mut MeshString {
 /// Assumes list elements are MeshString pointers.
 /// Produces a JSON array like `["a","b","c"]`.
 #[no_mangle]
-pub extern "C" fn mesh_json_encode_list(list_ptr: *mut u8) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_json_encode_list(list_ptr: *mut u8) -> *mut MeshString {
     unsafe {
         let len = list::mesh_list_length(list_ptr);
         let mut arr = Vec::with_capacity(len as usize);
@@ -283,25 +283,25 @@ pub extern "C" fn mesh_json_encode_list(list_ptr: *mut u8) -> *mut MeshString {
 
 /// Create a MeshJson Int from an i64.
 #[no_mangle]
-pub extern "C" fn mesh_json_from_int(val: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_from_int(val: i64) -> *mut u8 {
     alloc_json(JSON_INT, val as u64) as *mut u8
 }
 
 /// Create a MeshJson Float from an f64.
 #[no_mangle]
-pub extern "C" fn mesh_json_from_float(val: f64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_from_float(val: f64) -> *mut u8 {
     alloc_json(JSON_FLOAT, val.to_bits()) as *mut u8
 }
 
 /// Create a MeshJson Bool from an i8 (0 = false, non-zero = true).
 #[no_mangle]
-pub extern "C" fn mesh_json_from_bool(val: i8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_from_bool(val: i8) -> *mut u8 {
     alloc_json(JSON_BOOL, if val != 0 { 1 } else { 0 }) as *mut u8
 }
 
 /// Create a MeshJson Str from a MeshString.
 #[no_mangle]
-pub extern "C" fn mesh_json_from_string(s: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_from_string(s: *const MeshString) -> *mut u8 {
     alloc_json(JSON_STR, s as u64) as *mut u8
 }
 
@@ -310,14 +310,14 @@ pub extern "C" fn mesh_json_from_string(s: *const MeshString) -> *mut u8 {
 /// Create an empty JSON object.
 /// Uses string-typed map (KEY_TYPE_STR) so key lookups use content comparison.
 #[no_mangle]
-pub extern "C" fn mesh_json_object_new() -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_object_new() -> *mut u8 {
     let m = map::mesh_map_new_typed(1);
     alloc_json(JSON_OBJECT, m as u64) as *mut u8
 }
 
 /// Add a key-value pair to a JSON object. Returns a new JSON object.
 #[no_mangle]
-pub extern "C" fn mesh_json_object_put(obj: *mut u8, key: *mut u8, val: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_object_put(obj: *mut u8, key: *mut u8, val: *mut u8) -> *mut u8 {
     unsafe {
         let j = obj as *mut MeshJson;
         let m = (*j).value as *mut u8;
@@ -328,7 +328,7 @@ pub extern "C" fn mesh_json_object_put(obj: *mut u8, key: *mut u8, val: *mut u8)
 
 /// Get a value from a JSON object by key. Returns MeshResult (Ok/Err).
 #[no_mangle]
-pub extern "C" fn mesh_json_object_get(obj: *mut u8, key: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_object_get(obj: *mut u8, key: *mut u8) -> *mut u8 {
     unsafe {
         let j = obj as *mut MeshJson;
         if (*j).tag != JSON_OBJECT {
@@ -347,14 +347,14 @@ pub extern "C" fn mesh_json_object_get(obj: *mut u8, key: *mut u8) -> *mut u8 {
 
 /// Create an empty JSON array.
 #[no_mangle]
-pub extern "C" fn mesh_json_array_new() -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_array_new() -> *mut u8 {
     let l = list::mesh_list_new();
     alloc_json(JSON_ARRAY, l as u64) as *mut u8
 }
 
 /// Append a value to a JSON array. Returns a new JSON array.
 #[no_mangle]
-pub extern "C" fn mesh_json_array_push(arr: *mut u8, val: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_array_push(arr: *mut u8, val: *mut u8) -> *mut u8 {
     unsafe {
         let j = arr as *mut MeshJson;
         let l = (*j).value as *mut u8;
@@ -366,7 +366,7 @@ pub extern "C" fn mesh_json_array_push(arr: *mut u8, val: *mut u8) -> *mut u8 {
 /// Extract an Int from a MeshJson value. Returns MeshResult.
 /// Coerces Float to Int if needed.
 #[no_mangle]
-pub extern "C" fn mesh_json_as_int(json: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_as_int(json: *mut u8) -> *mut u8 {
     unsafe {
         let j = json as *mut MeshJson;
         match (*j).tag {
@@ -385,7 +385,7 @@ pub extern "C" fn mesh_json_as_int(json: *mut u8) -> *mut u8 {
 /// Extract a Float from a MeshJson value. Returns MeshResult.
 /// Promotes Int to Float if needed.
 #[no_mangle]
-pub extern "C" fn mesh_json_as_float(json: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_as_float(json: *mut u8) -> *mut u8 {
     unsafe {
         let j = json as *mut MeshJson;
         match (*j).tag {
@@ -404,7 +404,7 @@ pub extern "C" fn mesh_json_as_float(json: *mut u8) -> *mut u8 {
 
 /// Extract a String from a MeshJson value. Returns MeshResult.
 #[no_mangle]
-pub extern "C" fn mesh_json_as_string(json: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_as_string(json: *mut u8) -> *mut u8 {
     unsafe {
         let j = json as *mut MeshJson;
         if (*j).tag == JSON_STR {
@@ -417,7 +417,7 @@ pub extern "C" fn mesh_json_as_string(json: *mut u8) -> *mut u8 {
 
 /// Extract a Bool from a MeshJson value. Returns MeshResult.
 #[no_mangle]
-pub extern "C" fn mesh_json_as_bool(json: *mut u8) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_as_bool(json: *mut u8) -> *mut u8 {
     unsafe {
         let j = json as *mut MeshJson;
         if (*j).tag == JSON_BOOL {
@@ -430,14 +430,14 @@ pub extern "C" fn mesh_json_as_bool(json: *mut u8) -> *mut u8 {
 
 /// Return a MeshJson null value. Used for Option::None encoding.
 #[no_mangle]
-pub extern "C" fn mesh_json_null() -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_null() -> *mut u8 {
     alloc_json(JSON_NULL, 0) as *mut u8
 }
 
 /// Extract an element at the given index from a JSON array. Returns MeshResult.
 /// Ok(element) on success, Err(message) if not an array or index out of bounds.
 #[no_mangle]
-pub extern "C" fn mesh_json_array_get(json_arr: *mut u8, index: i64) -> *mut u8 {
+pub extern "C-unwind" fn mesh_json_array_get(json_arr: *mut u8, index: i64) -> *mut u8 {
     unsafe {
         let j = json_arr as *mut MeshJson;
         if (*j).tag != JSON_ARRAY {
@@ -461,9 +461,9 @@ pub extern "C" fn mesh_json_array_get(json_arr: *mut u8, index: i64) -> *mut u8
 /// Convert a MeshList to a JSON array using a per-element callback.
 /// `elem_fn` converts each list element (u64) to a *mut MeshJson.
 #[no_mangle]
-pub extern "C" fn mesh_json_from_list(
+pub extern "C-unwind" fn mesh_json_from_list(
     list_ptr: *mut u8,
-    elem_fn: extern "C" fn(u64) -> *mut u8,
+    elem_fn: extern "C-unwind" fn(u64) -> *mut u8,
 ) -> *mut u8 {
     let len = list::mesh_list_length(list_ptr);
     let mut arr = mesh_json_array_new();
@@ -478,9 +478,9 @@ pub extern "C" fn mesh_json_from_list(
 /// Convert a MeshMap to a JSON object using a per-value callback.
 /// Keys must be MeshString pointers. `val_fn` converts each value (u64) to a *mut MeshJson.
 #[no_mangle]
-pub extern "C" fn mesh_json_from_map(
+pub extern "C-unwind" fn mesh_json_from_map(
     map_ptr: *mut u8,
-    val_fn: extern "C" fn(u64) -> *mut u8,
+    val_fn: extern "C-unwind" fn(u64) -> *mut u8,
 ) -> *mut u8 {
     let keys_list = map::mesh_map_keys(map_ptr);
     let vals_list = map::mesh_map_values(map_ptr);
@@ -500,9 +500,9 @@ pub extern "C" fn mesh_json_from_map(
 /// `elem_fn` converts each *mut MeshJson to a *mut MeshResult.
 /// Returns MeshResult: Ok(MeshList) or Err on first element failure.
 #[no_mangle]
-pub extern "C" fn mesh_json_to_list(
+pub extern "C-unwind" fn mesh_json_to_list(
     json_arr: *mut u8,
-    elem_fn: extern "C" fn(*mut u8) -> *mut u8,
+    elem_fn: extern "C-unwind" fn(*mut u8) -> *mut u8,
 ) -> *mut u8 {
     unsafe {
         let j = json_arr as *mut MeshJson;
@@ -530,9 +530,9 @@ pub extern "C" fn mesh_json_to_list(
 /// Keys remain as MeshStrings. `val_fn` converts each *mut MeshJson to a *mut MeshResult.
 /// Returns MeshResult: Ok(MeshMap) or Err on first value failure.
 #[no_mangle]
-pub extern "C" fn mesh_json_to_map(
+pub extern "C-unwind" fn mesh_json_to_map(
     json_obj: *mut u8,
-    val_fn: extern "C" fn(*mut u8) -> *mut u8,
+    val_fn: extern "C-unwind" fn(*mut u8) -> *mut u8,
 ) -> *mut u8 {
     unsafe {
         let j = json_obj as *mut MeshJson;
@@ -940,7 +940,7 @@ mod tests {
         }
     }
 
-    extern "C" fn int_to_json(val: u64) -> *mut u8 {
+    extern "C-unwind" fn int_to_json(val: u64) -> *mut u8 {
         mesh_json_from_int(val as i64)
     }
 
@@ -959,7 +959,7 @@ mod tests {
         }
     }
 
-    extern "C" fn json_to_int_result(json: *mut u8) -> *mut u8 {
+    extern "C-unwind" fn json_to_int_result(json: *mut u8) -> *mut u8 {
         mesh_json_as_int(json)
     }
 
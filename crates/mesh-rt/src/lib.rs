@@ -11,7 +11,10 @@
 //! - [`gc`]: Arena/bump allocator for GC-managed memory (Phase 5: no collection)
 //! - [`string`]: GC-managed string operations (create, concat, format, print)
 //! - [`panic`]: Runtime panic handler with source locations
+//! - [`regex`]: Compiled regular expressions for the `Regex` module
 //! - [`actor`]: Actor runtime -- PCB, M:N scheduler, corosensei coroutines
+//! - [`backtrace`]: Symbolized Mesh backtraces attached to actor crash reasons
+//! - [`testing`]: Test runner for `meshc test` binaries
 //!
 //! ## ABI Contract
 //!
@@ -21,28 +24,38 @@
 //! (or at least across a single phase).
 
 pub mod actor;
+pub mod backtrace;
+pub mod bigint;
+pub mod bytes;
 pub mod collections;
 pub mod db;
+pub mod decimal;
 pub mod env;
 pub mod file;
+pub mod fixed_int;
+pub mod format;
 pub mod gc;
 pub mod hash;
 pub mod http;
+pub mod int;
 pub mod io;
+pub mod iodata;
 pub mod ws;
 pub mod dist;
 pub mod json;
 pub mod iter;
 pub mod option;
 pub mod panic;
+pub mod regex;
 pub mod string;
+pub mod testing;
 
 // Re-export key functions for convenient Rust-side access and testing.
 pub use actor::{
     mesh_actor_link, mesh_actor_receive, mesh_actor_register, mesh_actor_self, mesh_actor_send,
     mesh_actor_send_named,
     mesh_actor_set_terminate, mesh_actor_spawn, mesh_actor_whereis, mesh_reduction_check,
-    mesh_rt_init_actor, mesh_rt_run_scheduler,
+    mesh_rt_init_actor, mesh_rt_run_scheduler, mesh_rt_set_stack_size,
     mesh_timer_sleep, mesh_timer_send_after,
     mesh_process_monitor, mesh_process_demonitor,
     mesh_node_monitor,
@@ -99,26 +112,30 @@ pub use collections::list::{
     mesh_list_all, mesh_list_any, mesh_list_append, mesh_list_concat, mesh_list_contains,
     mesh_list_drop, mesh_list_enumerate, mesh_list_filter, mesh_list_find, mesh_list_flat_map,
     mesh_list_flatten, mesh_list_from_array, mesh_list_get, mesh_list_head, mesh_list_last,
-    mesh_list_length, mesh_list_map, mesh_list_new, mesh_list_nth, mesh_list_reduce,
-    mesh_list_reverse, mesh_list_sort, mesh_list_tail, mesh_list_take, mesh_list_zip,
+    mesh_list_length, mesh_list_map, mesh_list_new, mesh_list_nth, mesh_list_prepend,
+    mesh_list_reduce, mesh_list_reverse, mesh_list_sort, mesh_list_tail, mesh_list_take,
+    mesh_list_zip,
 };
 pub use collections::map::{
     mesh_map_delete, mesh_map_from_list, mesh_map_get, mesh_map_has_key, mesh_map_keys,
-    mesh_map_merge, mesh_map_new, mesh_map_put, mesh_map_size, mesh_map_to_list, mesh_map_values,
+    mesh_map_merge, mesh_map_new, mesh_map_put, mesh_map_size, mesh_map_tag_hashed,
+    mesh_map_to_list, mesh_map_values,
 };
 pub use collections::queue::{
-    mesh_queue_is_empty, mesh_queue_new, mesh_queue_peek, mesh_queue_pop, mesh_queue_push,
-    mesh_queue_size,
+    mesh_queue_eq, mesh_queue_from_list, mesh_queue_is_empty, mesh_queue_new, mesh_queue_peek,
+    mesh_queue_pop, mesh_queue_push, mesh_queue_size, mesh_queue_to_list, mesh_queue_to_string,
 };
 pub use collections::range::{
     mesh_range_filter, mesh_range_length, mesh_range_map, mesh_range_new, mesh_range_to_list,
 };
 pub use collections::set::{
-    mesh_set_add, mesh_set_contains, mesh_set_difference, mesh_set_from_list,
-    mesh_set_intersection, mesh_set_new, mesh_set_remove, mesh_set_size, mesh_set_to_list,
-    mesh_set_union,
+    mesh_set_add, mesh_set_contains, mesh_set_difference, mesh_set_eq, mesh_set_from_list,
+    mesh_set_intersection, mesh_set_new, mesh_set_remove, mesh_set_size,
+    mesh_set_to_list, mesh_set_union,
+};
+pub use collections::tuple::{
+    mesh_tuple_first, mesh_tuple_nth, mesh_tuple_second, mesh_tuple_size, mesh_tuple_to_list,
 };
-pub use collections::tuple::{mesh_tuple_first, mesh_tuple_nth, mesh_tuple_second, mesh_tuple_size};
 pub use env::{mesh_env_args, mesh_env_get};
 pub use iter::{
     mesh_iter_generic_next,
@@ -128,33 +145,96 @@ pub use iter::{
     mesh_iter_skip, mesh_iter_skip_next,
     mesh_iter_enumerate, mesh_iter_enumerate_next,
     mesh_iter_zip, mesh_iter_zip_next,
+    mesh_iter_take_while, mesh_iter_take_while_next,
+    mesh_iter_skip_while, mesh_iter_skip_while_next,
+    mesh_iter_flat_map, mesh_iter_flat_map_next,
+    mesh_iter_chain, mesh_iter_chain_next,
+    mesh_iter_chunks, mesh_iter_chunks_next,
+    mesh_iter_windows, mesh_iter_windows_next,
+    mesh_iter_step_by, mesh_iter_step_by_next,
+    mesh_iter_dedup, mesh_iter_dedup_by, mesh_iter_dedup_next,
+    mesh_iter_scan, mesh_iter_scan_next,
+    mesh_iter_inspect, mesh_iter_inspect_next,
+    mesh_iter_repeat, mesh_iter_repeat_next,
+    mesh_iter_unfold, mesh_iter_unfold_next,
+    mesh_iter_iterate, mesh_iter_iterate_next,
     mesh_iter_count, mesh_iter_sum, mesh_iter_any, mesh_iter_all, mesh_iter_find, mesh_iter_reduce,
+    mesh_iter_min_by, mesh_iter_max_by, mesh_iter_group_by, mesh_iter_partition,
     mesh_list_collect, mesh_map_collect, mesh_map_collect_string_keys, mesh_set_collect, mesh_string_collect,
 };
 pub use option::{MeshOption, alloc_option};
+pub use bytes::{
+    mesh_bytes_concat, mesh_bytes_eq, mesh_bytes_from_list, mesh_bytes_get, mesh_bytes_length,
+    mesh_bytes_matches, mesh_bytes_new, mesh_bytes_put_bytes, mesh_bytes_put_int,
+    mesh_bytes_segment, mesh_bytes_slice, mesh_bytes_to_list, mesh_bytes_to_string,
+    mesh_string_from_utf8, mesh_string_to_bytes, MeshBytes,
+};
+pub use bigint::{
+    mesh_bigint_abs, mesh_bigint_add, mesh_bigint_compare, mesh_bigint_div, mesh_bigint_eq,
+    mesh_bigint_from_int, mesh_bigint_from_json, mesh_bigint_hash, mesh_bigint_mod, mesh_bigint_mul,
+    mesh_bigint_neg, mesh_bigint_parse, mesh_bigint_pow, mesh_bigint_sub, mesh_bigint_to_int,
+    mesh_bigint_to_json, mesh_bigint_to_string, MeshBigInt,
+};
+pub use decimal::{
+    mesh_decimal_add, mesh_decimal_compare, mesh_decimal_div, mesh_decimal_eq, mesh_decimal_from_int,
+    mesh_decimal_from_json, mesh_decimal_hash, mesh_decimal_mod, mesh_decimal_mul, mesh_decimal_neg,
+    mesh_decimal_parse, mesh_decimal_round, mesh_decimal_scale, mesh_decimal_sub, mesh_decimal_to_float,
+    mesh_decimal_to_json, mesh_decimal_to_string, MeshDecimal,
+};
+pub use fixed_int::{
+    mesh_fixed_int_to_int,
+    mesh_int8_from_int, mesh_int8_try_from_int, mesh_int16_from_int, mesh_int16_try_from_int,
+    mesh_int32_from_int, mesh_int32_try_from_int, mesh_uint8_from_int, mesh_uint8_try_from_int,
+    mesh_uint16_from_int, mesh_uint16_try_from_int, mesh_uint32_from_int, mesh_uint32_try_from_int,
+    mesh_uint64_from_int, mesh_uint64_try_from_int,
+    mesh_uint64_div, mesh_uint64_lt, mesh_uint64_rem, mesh_uint64_to_string,
+};
 pub use file::{
-    mesh_file_append, mesh_file_delete, mesh_file_exists, mesh_file_read, mesh_file_write,
+    mesh_file_append, mesh_file_append_iodata, mesh_file_delete, mesh_file_exists, mesh_file_read,
+    mesh_file_write, mesh_file_write_iodata,
 };
 pub use gc::{mesh_gc_alloc, mesh_gc_alloc_actor, mesh_rt_init};
 pub use hash::{mesh_hash_bool, mesh_hash_combine, mesh_hash_float, mesh_hash_int, mesh_hash_string};
 pub use http::{
     mesh_http_get, mesh_http_post, mesh_http_request_body, mesh_http_request_header,
     mesh_http_request_method, mesh_http_request_path, mesh_http_request_query, mesh_http_response_new,
+    mesh_http_response_new_iodata, mesh_http_response_with_headers_iodata,
     mesh_http_route, mesh_http_router, mesh_http_serve,
 };
-pub use io::{mesh_io_eprintln, mesh_io_read_line};
+pub use int::{
+    mesh_int_checked_add, mesh_int_checked_div, mesh_int_checked_mul, mesh_int_checked_sub,
+    mesh_int_saturating_add, mesh_int_saturating_mul, mesh_int_saturating_sub,
+    mesh_int_wrapping_add, mesh_int_wrapping_mul, mesh_int_wrapping_sub,
+};
+pub use io::{mesh_io_eprintln, mesh_io_read_line, mesh_io_write, mesh_io_write_iodata};
+pub use iodata::{
+    mesh_iodata_append, mesh_iodata_append_bytes, mesh_iodata_byte_size, mesh_iodata_concat,
+    mesh_iodata_from_bytes, mesh_iodata_from_list, mesh_iodata_from_string, mesh_iodata_new,
+    mesh_iodata_to_bytes, mesh_iodata_to_string, MeshIoData,
+};
 pub use json::{
     mesh_json_encode, mesh_json_encode_bool, mesh_json_encode_int, mesh_json_encode_list,
     mesh_json_encode_map, mesh_json_encode_string, mesh_json_from_bool, mesh_json_from_float,
     mesh_json_from_int, mesh_json_from_string, mesh_json_parse,
 };
 pub use dist::node::{mesh_node_self, mesh_node_list, mesh_node_start, mesh_node_connect, mesh_register_function, mesh_node_spawn};
+pub use format::{
+    mesh_format_float, mesh_format_int, mesh_format_string, mesh_format_uint64, mesh_string_format,
+};
 pub use panic::mesh_panic;
+pub use regex::{
+    mesh_regex_compile, mesh_regex_literal, mesh_regex_is_match, mesh_regex_captures,
+    mesh_regex_named_captures, mesh_regex_scan, mesh_regex_replace, mesh_regex_split,
+};
 pub use string::{
     mesh_bool_to_string, mesh_float_to_string, mesh_int_to_string, mesh_print, mesh_println,
     mesh_string_concat, mesh_string_contains, mesh_string_ends_with, mesh_string_eq,
     mesh_string_join, mesh_string_length, mesh_string_new, mesh_string_replace, mesh_string_slice,
     mesh_string_split, mesh_string_starts_with, mesh_string_to_float, mesh_string_to_int,
     mesh_string_to_lower, mesh_string_to_upper, mesh_string_trim,
+    mesh_string_byte_size, mesh_string_graphemes, mesh_string_chars, mesh_string_codepoints,
+    mesh_string_at, mesh_string_index_of, mesh_string_reverse, mesh_string_pad_left,
+    mesh_string_pad_right, mesh_string_fold_case, mesh_string_normalize_nfc,
+    mesh_string_normalize_nfd, mesh_iter_chars, mesh_iter_graphemes, mesh_string_iter_next,
     MeshString,
 };
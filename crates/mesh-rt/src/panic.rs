@@ -3,33 +3,68 @@
 //! Called when a Mesh program encounters an unrecoverable error at runtime,
 //! such as a non-exhaustive match failure (guarded arms edge case).
 //!
-//! Uses `panic!()` rather than `abort()` so that actor crash isolation
-//! via `catch_unwind` can intercept handler failures without bringing
-//! down the entire process.
+//! Inside an actor, a panic unwinds and crashes only that actor (see
+//! `crate::actor::stack::crash_current`), with the source-located message
+//! and a Mesh backtrace as its exit reason. Runtime code that catches
+//! unwinds, such as the HTTP connection handler, sees the crash and cleans
+//! up. Outside actors it falls back to a Rust `panic!()`.
 
 /// Panic with a source-located error message.
 ///
-/// Triggers a Rust `panic!()` with a formatted source-located error
-/// message. In actor contexts, this is caught by `catch_unwind` for
-/// crash isolation. Outside actors, the panic unwinds and terminates
-/// the process as usual.
+/// Inside an actor, the actor exits with `ExitReason::Error` carrying the
+/// message and a backtrace, which linked and monitoring processes and
+/// supervisors observe. Outside actors, triggers a Rust `panic!()` with the
+/// same message.
 ///
 /// # Safety
 ///
 /// `msg` must point to `msg_len` valid UTF-8 bytes.
 /// `file` must point to `file_len` valid UTF-8 bytes.
 #[no_mangle]
-pub extern "C" fn mesh_panic(
+pub extern "C-unwind" fn mesh_panic(
     msg: *const u8,
     msg_len: u64,
     file: *const u8,
     file_len: u64,
     line: u32,
 ) -> ! {
-    unsafe {
+    let reason = unsafe {
         let msg = std::str::from_utf8_unchecked(std::slice::from_raw_parts(msg, msg_len as usize));
         let file =
             std::str::from_utf8_unchecked(std::slice::from_raw_parts(file, file_len as usize));
-        panic!("Mesh panic at {}:{}: {}", file, line, msg);
+        format!("Mesh panic at {}:{}: {}", file, line, msg)
+    };
+
+    // Only returns when not running inside an actor.
+    crate::actor::stack::crash_current(reason.clone());
+    panic!("{}", reason);
+}
+
+/// Fail an `assert`, `assert_eq`, or `refute` call.
+///
+/// Inside an actor, the failure crashes only the current actor: it exits
+/// with `ExitReason::Error` carrying the located message and a Mesh
+/// backtrace, so linked and monitoring processes (and the `meshc test`
+/// runner) observe the failure while every other actor keeps running. Outside an actor, the message is
+/// printed to stderr and the process exits with status 1.
+///
+/// # Safety
+///
+/// `msg` and `file` must be valid MeshString pointers.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_assert_fail(
+    msg: *const crate::string::MeshString,
+    file: *const crate::string::MeshString,
+    line: i64,
+) -> ! {
+    let (msg, file) = unsafe { ((*msg).as_str(), (*file).as_str()) };
+    let reason = format!("{}:{}: {}", file, line, msg);
+
+    if !crate::testing::is_test_run() {
+        eprintln!("assertion failed at {}", reason);
     }
+
+    // Only returns when not running inside an actor.
+    crate::actor::stack::crash_current(reason);
+    std::process::exit(1);
 }
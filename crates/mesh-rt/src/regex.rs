@@ -0,0 +1,363 @@
+//! Regular expressions for the Mesh standard library (`Regex` module).
+//!
+//! A compiled regex is an opaque u64 handle pointing at a `regex::Regex`,
+//! lowered to Int at the MIR level (same scheme as `PoolHandle`). Where the
+//! `Regex` lives depends on how it was made:
+//!
+//! - `Regex.compile` moves it into a GC object of the calling actor with a
+//!   finalizer that drops it, so regexes compiled at runtime are freed once
+//!   unreachable.
+//! - A `~r/.../` literal is compiled the first time its call site runs and
+//!   cached in a zeroed global slot codegen emits for that site, so a literal
+//!   in a loop compiles once and lives as long as the program.
+//!
+//! Capture lists always start with the whole match (group 0) followed by
+//! each numbered group. Groups that did not participate in the match are
+//! the empty string.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use ::regex::{Captures, Regex, RegexBuilder};
+
+use crate::collections::list::{mesh_list_builder_new, mesh_list_builder_push};
+use crate::collections::map::{mesh_map_new_typed, mesh_map_put};
+use crate::gc::mesh_gc_alloc_finalized;
+use crate::io::alloc_result;
+use crate::option::alloc_option;
+use crate::string::{mesh_string_new, MeshString};
+
+/// Flag letters accepted by `~r/.../flags` literals.
+///
+/// `i` case-insensitive, `m` multi-line anchors, `s` dot matches newline,
+/// `x` ignore whitespace and `#` comments, `U` swap greedy and lazy.
+pub const REGEX_FLAGS: &str = "imsxU";
+
+/// Compile `pattern` with `flags`.
+///
+/// Errors carry only the engine's one-line reason (e.g. "unclosed group"),
+/// not its multi-line caret diagram.
+fn compile(pattern: &str, flags: &str) -> Result<Regex, String> {
+    let mut builder = RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'U' => builder.swap_greed(true),
+            other => return Err(format!("unknown regex flag `{}`", other)),
+        };
+    }
+    builder.build().map_err(|e| error_reason(&e))
+}
+
+/// Reduce a `regex::Error` to its one-line reason.
+fn error_reason(err: &::regex::Error) -> String {
+    let text = err.to_string();
+    text.lines()
+        .find_map(|line| line.strip_prefix("error: "))
+        .unwrap_or(text.as_str())
+        .to_string()
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────
+
+unsafe fn handle_ref(re: u64) -> &'static Regex {
+    &*(re as *const Regex)
+}
+
+/// Finalizer of a `Regex.compile` handle: drop the `Regex` in place.
+unsafe fn drop_regex(data: *mut u8) {
+    std::ptr::drop_in_place(data as *mut Regex);
+}
+
+/// Move `re` into a GC object that drops it when collected.
+fn alloc_regex(re: Regex) -> *mut u8 {
+    let size = std::mem::size_of::<Regex>() as u64;
+    let align = std::mem::align_of::<Regex>() as u64;
+    let ptr = mesh_gc_alloc_finalized(size, align, drop_regex);
+    unsafe { std::ptr::write(ptr as *mut Regex, re) };
+    ptr
+}
+
+unsafe fn mesh_str(s: &str) -> *mut u8 {
+    mesh_string_new(s.as_ptr(), s.len() as u64) as *mut u8
+}
+
+/// Build a List<String> of the whole match followed by every group.
+unsafe fn captures_to_list(caps: &Captures) -> *mut u8 {
+    let list = mesh_list_builder_new(caps.len() as i64);
+    for group in caps.iter() {
+        let text = group.map_or("", |m| m.as_str());
+        mesh_list_builder_push(list, mesh_str(text) as u64);
+    }
+    list
+}
+
+// ── Public API ───────────────────────────────────────────────────────
+
+/// Regex.compile(pattern) -> Result<Regex, String>
+///
+/// Flags can be set inline, e.g. `(?i)abc`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_compile(pattern: *const MeshString) -> *mut u8 {
+    unsafe {
+        match compile((*pattern).as_str(), "") {
+            Ok(re) => alloc_result(0, alloc_regex(re)) as *mut u8,
+            Err(e) => alloc_result(1, mesh_str(&e)) as *mut u8,
+        }
+    }
+}
+
+/// Compile a `~r/pattern/flags` literal, caching it in `slot`.
+///
+/// `slot` is the literal's call-site global, zero until the first call
+/// stores the leaked regex in it. Two actors racing on the first call may
+/// both compile; the loser drops its copy and uses the winner's.
+///
+/// The type checker has already compiled the pattern, so failure here
+/// means the compiler and runtime disagree; it crashes the current actor.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_literal(
+    slot: *mut u64,
+    pattern: *const MeshString,
+    flags: *const MeshString,
+) -> u64 {
+    unsafe {
+        let slot = AtomicU64::from_ptr(slot);
+        let cached = slot.load(Ordering::Acquire);
+        if cached != 0 {
+            return cached;
+        }
+        match compile((*pattern).as_str(), (*flags).as_str()) {
+            Ok(re) => {
+                let re = Box::into_raw(Box::new(re));
+                match slot.compare_exchange(0, re as u64, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => re as u64,
+                    Err(winner) => {
+                        drop(Box::from_raw(re));
+                        winner
+                    }
+                }
+            }
+            Err(e) => {
+                let reason = format!("invalid regex literal: {}", e);
+                crate::actor::stack::crash_current(reason.clone());
+                panic!("{}", reason);
+            }
+        }
+    }
+}
+
+/// Regex.is_match(re, s) -> Bool
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_is_match(re: u64, s: *const MeshString) -> i8 {
+    unsafe { handle_ref(re).is_match((*s).as_str()) as i8 }
+}
+
+/// Regex.captures(re, s) -> Option<List<String>>
+///
+/// Returns the groups of the first match, or None when nothing matches.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_captures(re: u64, s: *const MeshString) -> *mut u8 {
+    unsafe {
+        match handle_ref(re).captures((*s).as_str()) {
+            Some(caps) => alloc_option(0, captures_to_list(&caps)) as *mut u8,
+            None => alloc_option(1, std::ptr::null_mut()) as *mut u8,
+        }
+    }
+}
+
+/// Regex.named_captures(re, s) -> Option<Map<String, String>>
+///
+/// Maps each `(?<name>...)` group of the first match to its text.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_named_captures(re: u64, s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let re = handle_ref(re);
+        let Some(caps) = re.captures((*s).as_str()) else {
+            return alloc_option(1, std::ptr::null_mut()) as *mut u8;
+        };
+        let mut map = mesh_map_new_typed(1);
+        for name in re.capture_names().flatten() {
+            let text = caps.name(name).map_or("", |m| m.as_str());
+            map = mesh_map_put(map, mesh_str(name) as u64, mesh_str(text) as u64);
+        }
+        alloc_option(0, map) as *mut u8
+    }
+}
+
+/// Regex.scan(re, s) -> List<List<String>>
+///
+/// The capture list of every non-overlapping match, left to right.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_scan(re: u64, s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let all: Vec<Captures> = handle_ref(re).captures_iter((*s).as_str()).collect();
+        let list = mesh_list_builder_new(all.len() as i64);
+        for caps in &all {
+            mesh_list_builder_push(list, captures_to_list(caps) as u64);
+        }
+        list
+    }
+}
+
+/// Regex.replace(re, s, fn(List<String>) -> String) -> String
+///
+/// Replaces every match with the closure's result for its capture list.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_replace(
+    re: u64,
+    s: *const MeshString,
+    fn_ptr: *mut u8,
+    env_ptr: *mut u8,
+) -> *mut u8 {
+    type BareFn = unsafe extern "C-unwind" fn(u64) -> u64;
+    type ClosureFn = unsafe extern "C-unwind" fn(*mut u8, u64) -> u64;
+
+    unsafe {
+        let text = (*s).as_str();
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for caps in handle_ref(re).captures_iter(text) {
+            let whole = caps.get(0).unwrap();
+            out.push_str(&text[last..whole.start()]);
+            let arg = captures_to_list(&caps) as u64;
+            let replacement = if env_ptr.is_null() {
+                let f: BareFn = std::mem::transmute(fn_ptr);
+                f(arg)
+            } else {
+                let f: ClosureFn = std::mem::transmute(fn_ptr);
+                f(env_ptr, arg)
+            };
+            out.push_str((*(replacement as *const MeshString)).as_str());
+            last = whole.end();
+        }
+        out.push_str(&text[last..]);
+        mesh_str(&out)
+    }
+}
+
+/// Regex.split(re, s) -> List<String>
+#[no_mangle]
+pub extern "C-unwind" fn mesh_regex_split(re: u64, s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let parts: Vec<&str> = handle_ref(re).split((*s).as_str()).collect();
+        let list = mesh_list_builder_new(parts.len() as i64);
+        for part in parts {
+            mesh_list_builder_push(list, mesh_str(part) as u64);
+        }
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::list::{mesh_list_get, mesh_list_length};
+    use crate::collections::map::mesh_map_get;
+    use crate::gc::mesh_rt_init;
+    use crate::io::MeshResult;
+    use crate::option::MeshOption;
+
+    fn ms(s: &str) -> *const MeshString {
+        mesh_string_new(s.as_ptr(), s.len() as u64)
+    }
+
+    unsafe fn strings(list: *mut u8) -> Vec<String> {
+        (0..mesh_list_length(list))
+            .map(|i| (*(mesh_list_get(list, i) as *const MeshString)).as_str().to_string())
+            .collect()
+    }
+
+    /// A literal at a fresh call site.
+    fn literal(pattern: &str, flags: &str) -> u64 {
+        let slot = Box::leak(Box::new(0u64));
+        mesh_regex_literal(slot, ms(pattern), ms(flags))
+    }
+
+    #[test]
+    fn test_regex_compile_result() {
+        mesh_rt_init();
+        unsafe {
+            let ok = mesh_regex_compile(ms("a+b")) as *const MeshResult;
+            assert_eq!((*ok).tag, 0);
+            assert_eq!(mesh_regex_is_match((*ok).value as u64, ms("xaab")), 1);
+
+            let err = mesh_regex_compile(ms("(abc")) as *const MeshResult;
+            assert_eq!((*err).tag, 1);
+            assert_eq!((*((*err).value as *const MeshString)).as_str(), "unclosed group");
+        }
+    }
+
+    #[test]
+    fn test_regex_literal_slot_and_flags() {
+        mesh_rt_init();
+        let mut slot = 0u64;
+        let first = mesh_regex_literal(&mut slot, ms("[a-z]+"), ms("i"));
+        assert_eq!(slot, first);
+        assert_eq!(mesh_regex_literal(&mut slot, ms("[a-z]+"), ms("i")), first);
+        assert_ne!(literal("[a-z]+", "i"), first);
+        assert_eq!(mesh_regex_is_match(literal("^abc$", "i"), ms("ABC")), 1);
+        assert_eq!(mesh_regex_is_match(literal("^abc$", ""), ms("ABC")), 0);
+        assert_eq!(mesh_regex_is_match(literal("^b$", "m"), ms("a\nb")), 1);
+        assert_eq!(compile("a", "q").unwrap_err(), "unknown regex flag `q`");
+    }
+
+    #[test]
+    fn test_regex_captures() {
+        mesh_rt_init();
+        unsafe {
+            let re = literal(r"(\w+)@(\w+)(\.org)?", "");
+            let some = mesh_regex_captures(re, ms("mail bob@example now")) as *const MeshOption;
+            assert_eq!((*some).tag, 0);
+            assert_eq!(strings((*some).value), vec!["bob@example", "bob", "example", ""]);
+
+            let none = mesh_regex_captures(re, ms("nothing here")) as *const MeshOption;
+            assert_eq!((*none).tag, 1);
+        }
+    }
+
+    #[test]
+    fn test_regex_named_captures() {
+        mesh_rt_init();
+        unsafe {
+            let re = literal(r"(?<year>\d{4})-(?<month>\d{2})", "");
+            let some = mesh_regex_named_captures(re, ms("on 2024-06")) as *const MeshOption;
+            assert_eq!((*some).tag, 0);
+            let month = mesh_map_get((*some).value, ms("month") as u64) as *const MeshString;
+            assert_eq!((*month).as_str(), "06");
+        }
+    }
+
+    #[test]
+    fn test_regex_scan_and_split() {
+        mesh_rt_init();
+        unsafe {
+            let re = literal(r"(\d)(\d)?", "");
+            let all = mesh_regex_scan(re, ms("1 23 4"));
+            assert_eq!(mesh_list_length(all), 3);
+            assert_eq!(strings(mesh_list_get(all, 1) as *mut u8), vec!["23", "2", "3"]);
+
+            let parts = mesh_regex_split(literal(r"\s*,\s*", ""), ms("a , b,c"));
+            assert_eq!(strings(parts), vec!["a", "b", "c"]);
+        }
+    }
+
+    extern "C-unwind" fn shout(caps: u64) -> u64 {
+        unsafe {
+            let word = mesh_list_get(caps as *mut u8, 1) as *const MeshString;
+            mesh_str(&(*word).as_str().to_uppercase()) as u64
+        }
+    }
+
+    #[test]
+    fn test_regex_replace_with_closure() {
+        mesh_rt_init();
+        unsafe {
+            let re = literal(r"<(\w+)>", "");
+            let out = mesh_regex_replace(re, ms("a <b> c <d>"), shout as *mut u8, std::ptr::null_mut());
+            assert_eq!((*(out as *const MeshString)).as_str(), "a B c D");
+        }
+    }
+}
@@ -7,9 +7,18 @@
 //! All string functions allocate via `mesh_gc_alloc_actor` so they are managed
 //! by the per-actor GC heap (falling back to the global arena outside actor
 //! context).
+//!
+//! Lengths and positions (`length`, `slice`, `at`, `index_of`, padding
+//! widths) count extended grapheme clusters -- what a reader sees as one
+//! character, such as `e` plus a combining accent or a flag emoji. Only
+//! `byte_size` counts UTF-8 bytes. `chars` and `codepoints` expose the
+//! individual Unicode scalar values for code that needs them.
 
 use std::ptr;
 
+use icu_normalizer::{ComposingNormalizerBorrowed, DecomposingNormalizerBorrowed};
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::gc::mesh_gc_alloc_actor;
 use crate::option::alloc_option;
 use crate::collections::list::{mesh_list_builder_new, mesh_list_builder_push};
@@ -79,7 +88,7 @@ impl MeshString {
 /// `data` must point to at least `len` valid bytes. If `data` is null,
 /// the string data is zeroed.
 #[no_mangle]
-pub extern "C" fn mesh_string_new(data: *const u8, len: u64) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_string_new(data: *const u8, len: u64) -> *mut MeshString {
     unsafe {
         let total = MeshString::HEADER_SIZE + len as usize;
         let ptr = mesh_gc_alloc_actor(total as u64, 8) as *mut MeshString;
@@ -94,7 +103,7 @@ pub extern "C" fn mesh_string_new(data: *const u8, len: u64) -> *mut MeshString
 
 /// Concatenate two Mesh strings, returning a new GC-managed string.
 #[no_mangle]
-pub extern "C" fn mesh_string_concat(
+pub extern "C-unwind" fn mesh_string_concat(
     a: *const MeshString,
     b: *const MeshString,
 ) -> *mut MeshString {
@@ -112,21 +121,21 @@ pub extern "C" fn mesh_string_concat(
 
 /// Convert an i64 integer to a GC-managed Mesh string.
 #[no_mangle]
-pub extern "C" fn mesh_int_to_string(val: i64) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_int_to_string(val: i64) -> *mut MeshString {
     let s = val.to_string();
     mesh_string_new(s.as_ptr(), s.len() as u64)
 }
 
 /// Convert an f64 float to a GC-managed Mesh string.
 #[no_mangle]
-pub extern "C" fn mesh_float_to_string(val: f64) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_float_to_string(val: f64) -> *mut MeshString {
     let s = val.to_string();
     mesh_string_new(s.as_ptr(), s.len() as u64)
 }
 
 /// Convert a boolean (i8: 0 = false, non-zero = true) to a GC-managed Mesh string.
 #[no_mangle]
-pub extern "C" fn mesh_bool_to_string(val: i8) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_bool_to_string(val: i8) -> *mut MeshString {
     let s = if val != 0 { "true" } else { "false" };
     mesh_string_new(s.as_ptr(), s.len() as u64)
 }
@@ -138,13 +147,13 @@ pub extern "C" fn mesh_bool_to_string(val: i8) -> *mut MeshString {
 /// it as a `*mut MeshString`. This allows collection to_string helpers
 /// to use a uniform callback signature `fn(u64) -> *mut u8`.
 #[no_mangle]
-pub extern "C" fn mesh_string_to_string(val: u64) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_string_to_string(val: u64) -> *mut MeshString {
     val as *mut MeshString
 }
 
 /// Print a Mesh string to stdout (no trailing newline).
 #[no_mangle]
-pub extern "C" fn mesh_print(s: *const MeshString) {
+pub extern "C-unwind" fn mesh_print(s: *const MeshString) {
     unsafe {
         let text = (*s).as_str();
         print!("{}", text);
@@ -153,7 +162,7 @@ pub extern "C" fn mesh_print(s: *const MeshString) {
 
 /// Print a Mesh string to stdout with a trailing newline.
 #[no_mangle]
-pub extern "C" fn mesh_println(s: *const MeshString) {
+pub extern "C-unwind" fn mesh_println(s: *const MeshString) {
     unsafe {
         let text = (*s).as_str();
         println!("{}", text);
@@ -162,32 +171,31 @@ pub extern "C" fn mesh_println(s: *const MeshString) {
 
 // ── String operations (Phase 8) ──────────────────────────────────────────
 
-/// Return the number of Unicode codepoints in the string (NOT byte length).
+/// Return the number of grapheme clusters in the string (NOT byte length).
 #[no_mangle]
-pub extern "C" fn mesh_string_length(s: *const MeshString) -> i64 {
-    unsafe { (*s).as_str().chars().count() as i64 }
+pub extern "C-unwind" fn mesh_string_length(s: *const MeshString) -> i64 {
+    unsafe { (*s).as_str().graphemes(true).count() as i64 }
 }
 
-/// Codepoint-based slice (0-indexed, exclusive end). Clamps to bounds.
+/// Grapheme-based slice (0-indexed, exclusive end). Clamps to bounds.
 #[no_mangle]
-pub extern "C" fn mesh_string_slice(
+pub extern "C-unwind" fn mesh_string_slice(
     s: *const MeshString,
     start: i64,
     end: i64,
 ) -> *mut MeshString {
     unsafe {
         let text = (*s).as_str();
-        let char_count = text.chars().count();
-        let start = (start.max(0) as usize).min(char_count);
-        let end = (end.max(0) as usize).min(char_count).max(start);
-        let sliced: String = text.chars().skip(start).take(end - start).collect();
+        let start = start.max(0) as usize;
+        let end = (end.max(0) as usize).max(start);
+        let sliced: String = text.graphemes(true).skip(start).take(end - start).collect();
         mesh_string_new(sliced.as_ptr(), sliced.len() as u64)
     }
 }
 
 /// Returns 1 if haystack contains needle, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_string_contains(
+pub extern "C-unwind" fn mesh_string_contains(
     haystack: *const MeshString,
     needle: *const MeshString,
 ) -> i8 {
@@ -202,7 +210,7 @@ pub extern "C" fn mesh_string_contains(
 
 /// Returns 1 if string starts with prefix, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_string_starts_with(
+pub extern "C-unwind" fn mesh_string_starts_with(
     s: *const MeshString,
     prefix: *const MeshString,
 ) -> i8 {
@@ -217,7 +225,7 @@ pub extern "C" fn mesh_string_starts_with(
 
 /// Returns 1 if string ends with suffix, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_string_ends_with(
+pub extern "C-unwind" fn mesh_string_ends_with(
     s: *const MeshString,
     suffix: *const MeshString,
 ) -> i8 {
@@ -232,7 +240,7 @@ pub extern "C" fn mesh_string_ends_with(
 
 /// Trim whitespace from both sides.
 #[no_mangle]
-pub extern "C" fn mesh_string_trim(s: *const MeshString) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_string_trim(s: *const MeshString) -> *mut MeshString {
     unsafe {
         let trimmed = (*s).as_str().trim();
         mesh_string_new(trimmed.as_ptr(), trimmed.len() as u64)
@@ -241,7 +249,7 @@ pub extern "C" fn mesh_string_trim(s: *const MeshString) -> *mut MeshString {
 
 /// Convert to uppercase.
 #[no_mangle]
-pub extern "C" fn mesh_string_to_upper(s: *const MeshString) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_string_to_upper(s: *const MeshString) -> *mut MeshString {
     unsafe {
         let upper = (*s).as_str().to_uppercase();
         mesh_string_new(upper.as_ptr(), upper.len() as u64)
@@ -250,16 +258,239 @@ pub extern "C" fn mesh_string_to_upper(s: *const MeshString) -> *mut MeshString
 
 /// Convert to lowercase.
 #[no_mangle]
-pub extern "C" fn mesh_string_to_lower(s: *const MeshString) -> *mut MeshString {
+pub extern "C-unwind" fn mesh_string_to_lower(s: *const MeshString) -> *mut MeshString {
     unsafe {
         let lower = (*s).as_str().to_lowercase();
         mesh_string_new(lower.as_ptr(), lower.len() as u64)
     }
 }
 
+// ── Unicode text ─────────────────────────────────────────────────────────
+
+/// Allocate a Mesh string holding `text`.
+fn new_str(text: &str) -> *mut MeshString {
+    mesh_string_new(text.as_ptr(), text.len() as u64)
+}
+
+/// Build a `List<String>` from string slices.
+fn str_list<'a>(parts: impl ExactSizeIterator<Item = &'a str>) -> *mut u8 {
+    let list = mesh_list_builder_new(parts.len() as i64);
+    for part in parts {
+        mesh_list_builder_push(list, new_str(part) as u64);
+    }
+    list
+}
+
+/// Return the number of UTF-8 bytes in the string.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_byte_size(s: *const MeshString) -> i64 {
+    unsafe { (*s).len as i64 }
+}
+
+/// Split the string into its grapheme clusters, returning a List<String>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_graphemes(s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let parts: Vec<&str> = (*s).as_str().graphemes(true).collect();
+        str_list(parts.into_iter())
+    }
+}
+
+/// Split the string into its Unicode scalar values, each as a one-codepoint
+/// String, returning a List<String>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_chars(s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let text = (*s).as_str();
+        let parts: Vec<&str> = text.char_indices().map(|(i, c)| &text[i..i + c.len_utf8()]).collect();
+        str_list(parts.into_iter())
+    }
+}
+
+/// Return the Unicode scalar values of the string as a List<Int>.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_codepoints(s: *const MeshString) -> *mut u8 {
+    unsafe {
+        let text = (*s).as_str();
+        let list = mesh_list_builder_new(text.chars().count() as i64);
+        for c in text.chars() {
+            mesh_list_builder_push(list, c as u64);
+        }
+        list
+    }
+}
+
+/// The grapheme at `index`, returning Option<String>. A negative index
+/// counts back from the end (-1 is the last grapheme).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_at(s: *const MeshString, index: i64) -> *mut u8 {
+    unsafe {
+        let text = (*s).as_str();
+        let grapheme = if index >= 0 {
+            text.graphemes(true).nth(index as usize)
+        } else {
+            text.graphemes(true).rev().nth((-(index + 1)) as usize)
+        };
+        match grapheme {
+            Some(g) => alloc_option(0, new_str(g) as *mut u8) as *mut u8,
+            None => alloc_option(1, ptr::null_mut()) as *mut u8,
+        }
+    }
+}
+
+/// The grapheme index of the first occurrence of `needle`, returning
+/// Option<Int>. A match must start and end on grapheme boundaries, so `"e"`
+/// is not found inside `"e\u{301}"`. An empty needle is found at 0.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_index_of(s: *const MeshString, needle: *const MeshString) -> *mut u8 {
+    unsafe {
+        let text = (*s).as_str();
+        let needle = (*needle).as_str();
+        let boundaries: Vec<usize> = text
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let found = text.match_indices(needle).find_map(|(start, _)| {
+            let index = boundaries.binary_search(&start).ok()?;
+            boundaries.binary_search(&(start + needle.len())).ok()?;
+            Some(index)
+        });
+        match found {
+            Some(i) => alloc_option(0, i as u64 as *mut u8) as *mut u8,
+            None => alloc_option(1, ptr::null_mut()) as *mut u8,
+        }
+    }
+}
+
+/// Reverse the order of the graphemes, keeping each grapheme intact.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_reverse(s: *const MeshString) -> *mut MeshString {
+    unsafe {
+        let reversed: String = (*s).as_str().graphemes(true).rev().collect();
+        new_str(&reversed)
+    }
+}
+
+/// The padding that brings `text` up to `width` graphemes: `pad` repeated,
+/// and cut short in the last repetition. Empty when `text` is already wide
+/// enough or `pad` is empty.
+fn padding(text: &str, width: i64, pad: &str) -> String {
+    let missing = (width.max(0) as usize).saturating_sub(text.graphemes(true).count());
+    pad.graphemes(true).cycle().take(missing).collect()
+}
+
+/// Pad the start of the string with `pad` until it is `width` graphemes long.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_pad_left(
+    s: *const MeshString,
+    width: i64,
+    pad: *const MeshString,
+) -> *mut MeshString {
+    unsafe {
+        let text = (*s).as_str();
+        let padded = padding(text, width, (*pad).as_str()) + text;
+        new_str(&padded)
+    }
+}
+
+/// Pad the end of the string with `pad` until it is `width` graphemes long.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_pad_right(
+    s: *const MeshString,
+    width: i64,
+    pad: *const MeshString,
+) -> *mut MeshString {
+    unsafe {
+        let text = (*s).as_str();
+        let padded = text.to_string() + &padding(text, width, (*pad).as_str());
+        new_str(&padded)
+    }
+}
+
+/// Full Unicode case folding, for case-insensitive comparison: `"Straße"`
+/// and `"STRASSE"` both fold to `"strasse"`.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_fold_case(s: *const MeshString) -> *mut MeshString {
+    unsafe { new_str(&caseless::default_case_fold_str((*s).as_str())) }
+}
+
+/// Normalize to NFC (canonical composition).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_normalize_nfc(s: *const MeshString) -> *mut MeshString {
+    unsafe { new_str(&ComposingNormalizerBorrowed::new_nfc().normalize((*s).as_str())) }
+}
+
+/// Normalize to NFD (canonical decomposition).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_normalize_nfd(s: *const MeshString) -> *mut MeshString {
+    unsafe { new_str(&DecomposingNormalizerBorrowed::new_nfd().normalize((*s).as_str())) }
+}
+
+// ── Lazy character iteration ─────────────────────────────────────────────
+
+/// Iterator state for Iter.chars(s) and Iter.graphemes(s).
+#[repr(C)]
+struct StringIter {
+    tag: u8,
+    /// 1 to yield grapheme clusters, 0 to yield Unicode scalar values.
+    graphemes: u8,
+    /// Byte offset of the next element.
+    offset: u64,
+    string: *const MeshString,
+}
+
+fn string_iter_new(s: *const MeshString, graphemes: u8) -> *mut u8 {
+    unsafe {
+        let iter = mesh_gc_alloc_actor(
+            std::mem::size_of::<StringIter>() as u64,
+            std::mem::align_of::<StringIter>() as u64,
+        ) as *mut StringIter;
+        (*iter).tag = crate::iter::ITER_TAG_STRING;
+        (*iter).graphemes = graphemes;
+        (*iter).offset = 0;
+        (*iter).string = s;
+        iter as *mut u8
+    }
+}
+
+/// Create a lazy iterator over the string's Unicode scalar values, each
+/// yielded as a one-codepoint String.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_chars(s: *const MeshString) -> *mut u8 {
+    string_iter_new(s, 0)
+}
+
+/// Create a lazy iterator over the string's grapheme clusters.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_iter_graphemes(s: *const MeshString) -> *mut u8 {
+    string_iter_new(s, 1)
+}
+
+/// Advance a string iterator, returning Option (tag 0 = Some, tag 1 = None).
+#[no_mangle]
+pub extern "C-unwind" fn mesh_string_iter_next(iter_ptr: *mut u8) -> *mut u8 {
+    unsafe {
+        let iter = iter_ptr as *mut StringIter;
+        let rest = &(*(*iter).string).as_str()[(*iter).offset as usize..];
+        let next = if (*iter).graphemes != 0 {
+            rest.graphemes(true).next()
+        } else {
+            rest.chars().next().map(|c| &rest[..c.len_utf8()])
+        };
+        match next {
+            Some(part) => {
+                (*iter).offset += part.len() as u64;
+                alloc_option(0, new_str(part) as *mut u8) as *mut u8
+            }
+            None => alloc_option(1, ptr::null_mut()) as *mut u8,
+        }
+    }
+}
+
 /// Replace all occurrences of `from` with `to` in the string.
 #[no_mangle]
-pub extern "C" fn mesh_string_replace(
+pub extern "C-unwind" fn mesh_string_replace(
     s: *const MeshString,
     from: *const MeshString,
     to: *const MeshString,
@@ -274,7 +505,7 @@ pub extern "C" fn mesh_string_replace(
 
 /// Compare two Mesh strings for equality. Returns 1 if equal, 0 otherwise.
 #[no_mangle]
-pub extern "C" fn mesh_string_eq(a: *const MeshString, b: *const MeshString) -> i8 {
+pub extern "C-unwind" fn mesh_string_eq(a: *const MeshString, b: *const MeshString) -> i8 {
     unsafe {
         if (*a).as_str() == (*b).as_str() {
             1
@@ -290,7 +521,7 @@ pub extern "C" fn mesh_string_eq(a: *const MeshString, b: *const MeshString) ->
 ///
 /// Uses the list builder API to construct the result list efficiently.
 #[no_mangle]
-pub extern "C" fn mesh_string_split(
+pub extern "C-unwind" fn mesh_string_split(
     s: *const MeshString,
     delim: *const MeshString,
 ) -> *mut u8 {
@@ -310,20 +541,15 @@ pub extern "C" fn mesh_string_split(
 /// Join a list of strings with a separator, returning a new String.
 ///
 /// Reads list elements as MeshString pointers (stored as u64 in the list).
-/// List layout: u64 length at offset 0, u64 elements starting at offset 16
-/// (after length + capacity header).
 #[no_mangle]
-pub extern "C" fn mesh_string_join(
+pub extern "C-unwind" fn mesh_string_join(
     list: *mut u8,
     sep: *const MeshString,
 ) -> *mut u8 {
     unsafe {
         let separator = (*sep).as_str();
-        let len = *(list as *const u64) as usize;
-        let data = (list as *const u64).add(2); // skip length + capacity header
-        let mut parts: Vec<&str> = Vec::with_capacity(len);
-        for i in 0..len {
-            let elem = *data.add(i);
+        let mut parts: Vec<&str> = Vec::new();
+        for elem in crate::collections::list::elems(list) {
             let mesh_str = elem as *const MeshString;
             parts.push((*mesh_str).as_str());
         }
@@ -336,7 +562,7 @@ pub extern "C" fn mesh_string_join(
 ///
 /// Returns Some(n) on success (tag 0), None on failure (tag 1).
 #[no_mangle]
-pub extern "C" fn mesh_string_to_int(s: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_string_to_int(s: *const MeshString) -> *mut u8 {
     unsafe {
         let text = (*s).as_str().trim();
         match text.parse::<i64>() {
@@ -352,7 +578,7 @@ pub extern "C" fn mesh_string_to_int(s: *const MeshString) -> *mut u8 {
 /// CRITICAL: Uses f64::to_bits() to store the float as its bit pattern
 /// in the u64 value field of MeshOption.
 #[no_mangle]
-pub extern "C" fn mesh_string_to_float(s: *const MeshString) -> *mut u8 {
+pub extern "C-unwind" fn mesh_string_to_float(s: *const MeshString) -> *mut u8 {
     unsafe {
         let text = (*s).as_str().trim();
         match text.parse::<f64>() {
@@ -451,8 +677,7 @@ mod tests {
     #[test]
     fn test_string_length_unicode() {
         mesh_rt_init();
-        // "cafe\u{0301}" = "cafe\u{0301}" -- 5 codepoints, but more bytes
-        let text = "caf\u{00e9}"; // 4 codepoints, e-with-accent is 2 bytes
+        let text = "caf\u{00e9}"; // 4 graphemes, e-with-accent is 2 bytes
         let s = mesh_string_new(text.as_ptr(), text.len() as u64);
         assert_eq!(mesh_string_length(s), 4);
     }
@@ -566,4 +791,100 @@ mod tests {
         assert_eq!(mesh_string_eq(a, b), 1);
         assert_eq!(mesh_string_eq(a, c), 0);
     }
+
+    fn ms(text: &str) -> *mut MeshString {
+        mesh_string_new(text.as_ptr(), text.len() as u64)
+    }
+
+    fn strings(list: *mut u8) -> Vec<String> {
+        unsafe {
+            crate::collections::list::list_to_vec(list)
+                .into_iter()
+                .map(|p| (*(p as *const MeshString)).as_str().to_string())
+                .collect()
+        }
+    }
+
+    fn option_string(opt: *mut u8) -> Option<String> {
+        let opt = opt as *mut crate::option::MeshOption;
+        unsafe { ((*opt).tag == 0).then(|| (*((*opt).value as *const MeshString)).as_str().to_string()) }
+    }
+
+    fn option_int(opt: *mut u8) -> Option<i64> {
+        let opt = opt as *mut crate::option::MeshOption;
+        unsafe { ((*opt).tag == 0).then(|| (*opt).value as u64 as i64) }
+    }
+
+    #[test]
+    fn test_string_graphemes_vs_bytes() {
+        mesh_rt_init();
+        // "e" + combining acute, and a family emoji joined with ZWJs.
+        let s = ms("e\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!");
+        assert_eq!(mesh_string_length(s), 3);
+        assert_eq!(mesh_string_byte_size(s), 3 + 18 + 1);
+        assert_eq!(
+            strings(mesh_string_graphemes(s)),
+            ["e\u{301}", "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "!"]
+        );
+        assert_eq!(strings(mesh_string_chars(ms("e\u{301}"))), ["e", "\u{301}"]);
+        let codepoints = unsafe { crate::collections::list::list_to_vec(mesh_string_codepoints(ms("a\u{e9}"))) };
+        assert_eq!(codepoints, [0x61, 0xe9]);
+        unsafe {
+            assert_eq!((*mesh_string_slice(s, 1, 2)).as_str(), "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}");
+        }
+    }
+
+    #[test]
+    fn test_string_at_and_index_of() {
+        mesh_rt_init();
+        let s = ms("ne\u{301}e");
+        assert_eq!(option_string(mesh_string_at(s, 1)).as_deref(), Some("e\u{301}"));
+        assert_eq!(option_string(mesh_string_at(s, -1)).as_deref(), Some("e"));
+        assert_eq!(option_string(mesh_string_at(s, 3)), None);
+        assert_eq!(option_string(mesh_string_at(s, -4)), None);
+        // The "e" inside "e\u{301}" is not a whole grapheme, so the match is the last one.
+        assert_eq!(option_int(mesh_string_index_of(s, ms("e"))), Some(2));
+        assert_eq!(option_int(mesh_string_index_of(s, ms("x"))), None);
+        assert_eq!(option_int(mesh_string_index_of(s, ms(""))), Some(0));
+    }
+
+    #[test]
+    fn test_string_reverse_and_pad() {
+        mesh_rt_init();
+        unsafe {
+            assert_eq!((*mesh_string_reverse(ms("ae\u{301}b"))).as_str(), "be\u{301}a");
+            assert_eq!((*mesh_string_pad_left(ms("42"), 5, ms("0"))).as_str(), "00042");
+            assert_eq!((*mesh_string_pad_right(ms("ab"), 7, ms("-="))).as_str(), "ab-=-=-");
+            assert_eq!((*mesh_string_pad_left(ms("e\u{301}"), 2, ms(" "))).as_str(), " e\u{301}");
+            assert_eq!((*mesh_string_pad_left(ms("long"), 2, ms(" "))).as_str(), "long");
+            assert_eq!((*mesh_string_pad_right(ms("ab"), 5, ms(""))).as_str(), "ab");
+        }
+    }
+
+    #[test]
+    fn test_string_fold_case_and_normalize() {
+        mesh_rt_init();
+        unsafe {
+            assert_eq!((*mesh_string_fold_case(ms("Stra\u{df}e"))).as_str(), "strasse");
+            assert_eq!((*mesh_string_fold_case(ms("STRASSE"))).as_str(), "strasse");
+            assert_eq!((*mesh_string_normalize_nfc(ms("e\u{301}"))).as_str(), "\u{e9}");
+            assert_eq!((*mesh_string_normalize_nfd(ms("\u{e9}"))).as_str(), "e\u{301}");
+        }
+    }
+
+    #[test]
+    fn test_string_iterators() {
+        mesh_rt_init();
+        let s = ms("ae\u{301}");
+        let collect = |iter: *mut u8| {
+            let mut out = Vec::new();
+            while let Some(part) = option_string(crate::iter::mesh_iter_generic_next(iter)) {
+                out.push(part);
+            }
+            out
+        };
+        assert_eq!(collect(mesh_iter_chars(s)), ["a", "e", "\u{301}"]);
+        assert_eq!(collect(mesh_iter_graphemes(s)), ["a", "e\u{301}"]);
+        assert!(collect(mesh_iter_chars(ms(""))).is_empty());
+    }
 }
@@ -0,0 +1,310 @@
+//! Test runner for `meshc test` binaries.
+//!
+//! A test binary's `main` registers every `test "name" do ... end` block with
+//! `mesh_test_register` and then calls `mesh_test_run`, whose return value
+//! becomes the process exit code.
+//!
+//! Each test runs in its own actor. A failing assertion crashes only that
+//! actor (see `mesh_assert_fail`), so the runner records the failure and moves
+//! on to the next test. Tests run one at a time, in registration order, so
+//! their output does not interleave.
+//!
+//! The binary understands two command-line options, forwarded by `meshc test`:
+//! - `--filter <text>`: only run tests whose full name contains `<text>`
+//! - `--json`: print one JSON object per test plus a summary line
+//!
+//! Failures are reported with the Mesh backtrace of the failing test actor
+//! (a separate `backtrace` field in JSON output).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::actor::process::{ExitReason, ProcessId, ProcessState};
+
+/// How long a single test may run before it is reported as failed.
+const TEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A registered test: display name, declaring module, and compiled body.
+struct TestCase {
+    name: String,
+    module: String,
+    fn_ptr: usize,
+}
+
+static TESTS: Mutex<Vec<TestCase>> = Mutex::new(Vec::new());
+
+/// Set while `mesh_test_run` is executing tests.
+static TEST_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Whether the process is currently running tests.
+///
+/// Assertion failures are reported by the runner during a test run instead
+/// of being printed where they happen.
+pub fn is_test_run() -> bool {
+    TEST_RUN.load(Ordering::SeqCst)
+}
+
+/// Register a test with the runner.
+///
+/// - `name_ptr`/`name_len`: UTF-8 test name
+/// - `module_ptr`/`module_len`: UTF-8 name of the declaring module (may be empty)
+/// - `fn_ptr`: the compiled test body, `extern "C-unwind" fn()`
+#[no_mangle]
+pub extern "C-unwind" fn mesh_test_register(
+    name_ptr: *const u8,
+    name_len: u64,
+    module_ptr: *const u8,
+    module_len: u64,
+    fn_ptr: *const u8,
+) {
+    let (name, module) = unsafe {
+        (
+            utf8_lossy(name_ptr, name_len),
+            utf8_lossy(module_ptr, module_len),
+        )
+    };
+    TESTS.lock().push(TestCase {
+        name,
+        module,
+        fn_ptr: fn_ptr as usize,
+    });
+}
+
+/// Run all registered tests and return the process exit code.
+///
+/// Returns 0 when every selected test passed and 1 otherwise. The actor
+/// scheduler must already be initialized.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_test_run() -> i32 {
+    let options = RunOptions::from_args(std::env::args().skip(1));
+    let tests = std::mem::take(&mut *TESTS.lock());
+
+    TEST_RUN.store(true, Ordering::SeqCst);
+
+    let (selected, filtered): (Vec<TestCase>, Vec<TestCase>) = tests
+        .into_iter()
+        .partition(|t| options.matches(&full_name(t)));
+
+    if !options.json {
+        println!(
+            "running {} test{}",
+            selected.len(),
+            if selected.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    let started = Instant::now();
+    let mut failures: Vec<(String, String)> = Vec::new();
+    for test in &selected {
+        let test_started = Instant::now();
+        let outcome = run_one(test);
+        let elapsed = test_started.elapsed();
+
+        if options.json {
+            let mut event = serde_json::json!({
+                "type": "test",
+                "name": test.name,
+                "module": test.module,
+                "status": if outcome.is_ok() { "passed" } else { "failed" },
+                "duration_ms": elapsed.as_millis() as u64,
+            });
+            if let Err(ref reason) = outcome {
+                let (message, backtrace) = crate::backtrace::split_backtrace(reason);
+                event["message"] = serde_json::Value::String(message.to_string());
+                if let Some(backtrace) = backtrace {
+                    event["backtrace"] = serde_json::Value::String(backtrace.to_string());
+                }
+            }
+            println!("{}", event);
+        } else {
+            let status = if outcome.is_ok() { "ok" } else { "FAILED" };
+            println!("test {} ... {}", full_name(test), status);
+        }
+
+        if let Err(message) = outcome {
+            failures.push((full_name(test), message));
+        }
+    }
+
+    TEST_RUN.store(false, Ordering::SeqCst);
+
+    let failed = failures.len();
+    let passed = selected.len() - failed;
+
+    if options.json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "type": "summary",
+                "passed": passed,
+                "failed": failed,
+                "filtered_out": filtered.len(),
+                "duration_ms": started.elapsed().as_millis() as u64,
+            })
+        );
+    } else {
+        if !failures.is_empty() {
+            println!("\nfailures:");
+            for (name, message) in &failures {
+                println!("\n---- {} ----\n{}", name, message);
+            }
+        }
+        println!(
+            "\ntest result: {}. {} passed; {} failed; {} filtered out; finished in {:.2}s",
+            if failed == 0 { "ok" } else { "FAILED" },
+            passed,
+            failed,
+            filtered.len(),
+            started.elapsed().as_secs_f64()
+        );
+    }
+
+    if failed == 0 {
+        0
+    } else {
+        1
+    }
+}
+
+/// Spawn a test in its own actor and wait for it to exit.
+fn run_one(test: &TestCase) -> Result<(), String> {
+    let sched = crate::actor::global_scheduler();
+    // The spawn args pointer is handed to the entry function untouched, so
+    // it carries the test body's function pointer directly.
+    let pid = sched.spawn(test_entry as *const u8, test.fn_ptr as *const u8, 0, 1);
+    wait_for_exit(pid, TEST_TIMEOUT)
+}
+
+/// Actor entry point for a single test.
+extern "C-unwind" fn test_entry(test_fn: *const u8) {
+    let body: extern "C-unwind" fn() = unsafe { std::mem::transmute(test_fn) };
+    body();
+}
+
+/// Poll the process table until `pid` has exited or `timeout` elapses.
+fn wait_for_exit(pid: ProcessId, timeout: Duration) -> Result<(), String> {
+    let sched = crate::actor::global_scheduler();
+    let deadline = Instant::now() + timeout;
+    loop {
+        let state = sched.get_process(pid).map(|p| p.lock().state.clone());
+        match state {
+            Some(ProcessState::Exited(reason)) => return exit_result(reason),
+            None => return Ok(()),
+            Some(_) => {}
+        }
+        if Instant::now() >= deadline {
+            return Err(format!("test timed out after {}s", timeout.as_secs()));
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Map an actor's exit reason to a test outcome.
+fn exit_result(reason: ExitReason) -> Result<(), String> {
+    match reason {
+        ExitReason::Normal | ExitReason::Shutdown => Ok(()),
+        ExitReason::Error(message) | ExitReason::Custom(message) => Err(message),
+        other => Err(format!("test actor exited: {:?}", other)),
+    }
+}
+
+/// Name shown in reports and matched by `--filter`.
+fn full_name(test: &TestCase) -> String {
+    if test.module.is_empty() {
+        test.name.clone()
+    } else {
+        format!("{}: {}", test.module, test.name)
+    }
+}
+
+unsafe fn utf8_lossy(ptr: *const u8, len: u64) -> String {
+    if ptr.is_null() || len == 0 {
+        return String::new();
+    }
+    String::from_utf8_lossy(std::slice::from_raw_parts(ptr, len as usize)).into_owned()
+}
+
+/// Command-line options understood by a test binary.
+#[derive(Debug, Default, PartialEq)]
+struct RunOptions {
+    filter: Option<String>,
+    json: bool,
+}
+
+impl RunOptions {
+    fn from_args(args: impl Iterator<Item = String>) -> Self {
+        let mut options = RunOptions::default();
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => options.json = true,
+                "--filter" => options.filter = args.next(),
+                other => {
+                    if let Some(value) = other.strip_prefix("--filter=") {
+                        options.filter = Some(value.to_string());
+                    }
+                }
+            }
+        }
+        options
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.filter.as_deref().is_none_or(|f| name.contains(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn test_run_options_parsing() {
+        assert_eq!(RunOptions::from_args(args(&[])), RunOptions::default());
+        assert_eq!(
+            RunOptions::from_args(args(&["--json", "--filter", "math"])),
+            RunOptions { filter: Some("math".to_string()), json: true }
+        );
+        assert_eq!(
+            RunOptions::from_args(args(&["--filter=parse"])).filter.as_deref(),
+            Some("parse")
+        );
+    }
+
+    #[test]
+    fn test_filter_matches_substring() {
+        let all = RunOptions::default();
+        assert!(all.matches("anything"));
+
+        let some = RunOptions { filter: Some("add".to_string()), json: false };
+        assert!(some.matches("Math: adds numbers"));
+        assert!(!some.matches("Math: subtracts"));
+    }
+
+    #[test]
+    fn test_exit_result_maps_reasons() {
+        assert!(exit_result(ExitReason::Normal).is_ok());
+        assert_eq!(
+            exit_result(ExitReason::Error("main.mpl:3: assertion failed".to_string())),
+            Err("main.mpl:3: assertion failed".to_string())
+        );
+        assert!(exit_result(ExitReason::Killed).is_err());
+    }
+
+    #[test]
+    fn test_full_name_includes_module() {
+        let case = |module: &str| TestCase {
+            name: "adds".to_string(),
+            module: module.to_string(),
+            fn_ptr: 0,
+        };
+        assert_eq!(full_name(&case("")), "adds");
+        assert_eq!(full_name(&case("Math")), "Math: adds");
+    }
+}
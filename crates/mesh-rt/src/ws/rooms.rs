@@ -214,7 +214,7 @@ pub(crate) fn broadcast_room_to_cluster(room: &str, msg: &str) {
 ///
 /// Returns 0 on success, -1 on null arguments.
 #[no_mangle]
-pub extern "C" fn mesh_ws_join(conn: *mut u8, room_name: *const MeshString) -> i64 {
+pub extern "C-unwind" fn mesh_ws_join(conn: *mut u8, room_name: *const MeshString) -> i64 {
     if conn.is_null() || room_name.is_null() {
         return -1;
     }
@@ -231,7 +231,7 @@ pub extern "C" fn mesh_ws_join(conn: *mut u8, room_name: *const MeshString) -> i
 ///
 /// Returns 0 on success, -1 on null arguments.
 #[no_mangle]
-pub extern "C" fn mesh_ws_leave(conn: *mut u8, room_name: *const MeshString) -> i64 {
+pub extern "C-unwind" fn mesh_ws_leave(conn: *mut u8, room_name: *const MeshString) -> i64 {
     if conn.is_null() || room_name.is_null() {
         return -1;
     }
@@ -249,7 +249,7 @@ pub extern "C" fn mesh_ws_leave(conn: *mut u8, room_name: *const MeshString) ->
 /// Returns the number of local write failures (0 = all succeeded), or -1 on
 /// null arguments.
 #[no_mangle]
-pub extern "C" fn mesh_ws_broadcast(
+pub extern "C-unwind" fn mesh_ws_broadcast(
     room_name: *const MeshString,
     msg: *const MeshString,
 ) -> i64 {
@@ -280,7 +280,7 @@ pub extern "C" fn mesh_ws_broadcast(
 /// Returns the number of local write failures (0 = all succeeded), or -1 on
 /// null room_name or msg.
 #[no_mangle]
-pub extern "C" fn mesh_ws_broadcast_except(
+pub extern "C-unwind" fn mesh_ws_broadcast_except(
     room_name: *const MeshString,
     msg: *const MeshString,
     except_conn: *mut u8,
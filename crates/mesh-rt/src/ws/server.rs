@@ -37,6 +37,7 @@ use rustls::{ServerConfig, ServerConnection, StreamOwned};
 use crate::actor::{global_scheduler, MessageBuffer, Message, ProcessId, ProcessState};
 use crate::actor::process::Process;
 use crate::actor::stack;
+use crate::iodata::{iodata_to_vec, MeshIoData};
 use crate::string::MeshString;
 use super::frame::{read_frame, write_frame, WsFrame, WsOpcode};
 use super::handshake::perform_upgrade;
@@ -320,7 +321,7 @@ unsafe impl Send for WsConnectionArgs {}
 /// - `on_close_fn/env`: Called when connection ends with (conn, code, reason)
 /// - `port`: TCP port to listen on
 #[no_mangle]
-pub extern "C" fn mesh_ws_serve(
+pub extern "C-unwind" fn mesh_ws_serve(
     on_connect_fn: *mut u8,
     on_connect_env: *mut u8,
     on_message_fn: *mut u8,
@@ -426,7 +427,7 @@ fn ws_accept_loop(listener: TcpListener, h: SendableHandler) {
 /// Same as `mesh_ws_serve` but wraps each connection in TLS via rustls.
 /// Certificate and private key are loaded from PEM files at the given paths.
 #[no_mangle]
-pub extern "C" fn mesh_ws_serve_tls(
+pub extern "C-unwind" fn mesh_ws_serve_tls(
     on_connect_fn: *mut u8,
     on_connect_env: *mut u8,
     on_message_fn: *mut u8,
@@ -516,7 +517,7 @@ pub extern "C" fn mesh_ws_serve_tls(
 ///
 /// Returns 0 on success, -1 on error.
 #[no_mangle]
-pub extern "C" fn mesh_ws_send(conn: *mut u8, msg: *const MeshString) -> i64 {
+pub extern "C-unwind" fn mesh_ws_send(conn: *mut u8, msg: *const MeshString) -> i64 {
     if conn.is_null() || msg.is_null() {
         return -1;
     }
@@ -529,6 +530,27 @@ pub extern "C" fn mesh_ws_send(conn: *mut u8, msg: *const MeshString) -> i64 {
     }
 }
 
+/// Send an IoData as a text frame, flattening it into the frame's payload.
+///
+/// Returns 0 on success, -1 on error, including when the IoData holds
+/// Bytes that are not valid UTF-8.
+#[no_mangle]
+pub extern "C-unwind" fn mesh_ws_send_iodata(conn: *mut u8, msg: *const MeshIoData) -> i64 {
+    if conn.is_null() || msg.is_null() {
+        return -1;
+    }
+    let conn = unsafe { &*(conn as *const WsConnection) };
+    let payload = unsafe { iodata_to_vec(msg) };
+    if std::str::from_utf8(&payload).is_err() {
+        return -1;
+    }
+    let mut stream = conn.write_stream.lock();
+    match write_frame(&mut *stream, WsOpcode::Text, &payload, true) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
 /// Send a binary frame to a WebSocket client.
 ///
 /// `conn` is a pointer to a `WsConnection`. `data` and `len` specify the
@@ -536,7 +558,7 @@ pub extern "C" fn mesh_ws_send(conn: *mut u8, msg: *const MeshString) -> i64 {
 ///
 /// Returns 0 on success, -1 on error.
 #[no_mangle]
-pub extern "C" fn mesh_ws_send_binary(conn: *mut u8, data: *const u8, len: i64) -> i64 {
+pub extern "C-unwind" fn mesh_ws_send_binary(conn: *mut u8, data: *const u8, len: i64) -> i64 {
     if conn.is_null() || data.is_null() {
         return -1;
     }
@@ -557,7 +579,7 @@ pub extern "C" fn mesh_ws_send_binary(conn: *mut u8, data: *const u8, len: i64)
 ///
 /// Performs the upgrade handshake, splits the stream, spawns the reader
 /// thread, runs the message loop, and handles cleanup on exit or crash.
-extern "C" fn ws_connection_entry(args: *const u8) {
+extern "C-unwind" fn ws_connection_entry(args: *const u8) {
     if args.is_null() {
         return;
     }
@@ -1011,7 +1033,7 @@ mod tests {
     // ── Callback functions ───────────────────────────────────────────
 
     /// on_connect with per-test counter via env pointer. Returns non-null (accept).
-    extern "C" fn counting_on_connect(
+    extern "C-unwind" fn counting_on_connect(
         env: *mut u8, _conn: *mut u8, _path: *mut u8, _headers: *mut u8,
     ) -> *mut u8 {
         if !env.is_null() {
@@ -1021,14 +1043,14 @@ mod tests {
     }
 
     /// on_connect: accept without counting (env=null calling convention).
-    extern "C" fn accept_on_connect(
+    extern "C-unwind" fn accept_on_connect(
         _conn: *mut u8, _path: *mut u8, _headers: *mut u8,
     ) -> *mut u8 {
         1 as *mut u8
     }
 
     /// on_message: echo the message back to the client (env=null).
-    extern "C" fn echo_on_message(conn: *mut u8, msg: *mut u8) -> *mut u8 {
+    extern "C-unwind" fn echo_on_message(conn: *mut u8, msg: *mut u8) -> *mut u8 {
         mesh_ws_send(conn, msg as *const MeshString);
         std::ptr::null_mut()
     }
@@ -1041,7 +1063,7 @@ mod tests {
     }
 
     /// on_close with per-test counter via env pointer.
-    extern "C" fn counting_on_close(
+    extern "C-unwind" fn counting_on_close(
         env: *mut u8, _conn: *mut u8, _code: i64, _reason: *mut u8,
     ) -> *mut u8 {
         if !env.is_null() {
@@ -1051,7 +1073,7 @@ mod tests {
     }
 
     /// on_close: no-op (env=null calling convention).
-    extern "C" fn noop_on_close(
+    extern "C-unwind" fn noop_on_close(
         _conn: *mut u8, _code: i64, _reason: *mut u8,
     ) -> *mut u8 {
         std::ptr::null_mut()
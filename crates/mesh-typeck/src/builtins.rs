@@ -9,7 +9,7 @@ use rustc_hash::FxHashMap;
 
 use crate::env::TypeEnv;
 use crate::traits::{AssocTypeDef, ImplDef, ImplMethodSig, TraitDef, TraitMethodSig, TraitRegistry};
-use crate::ty::{Scheme, Ty, TyCon, TyVar};
+use crate::ty::{Scheme, Ty, TyCon, TyVar, FIXED_INT_TYPES};
 use crate::unify::InferCtx;
 
 /// Register all built-in types and functions into the environment.
@@ -40,6 +40,13 @@ pub fn register_builtins(
     env.insert("Float".into(), Scheme::mono(Ty::float()));
     env.insert("String".into(), Scheme::mono(Ty::string()));
     env.insert("Bool".into(), Scheme::mono(Ty::bool()));
+    for name in FIXED_INT_TYPES {
+        env.insert((*name).into(), Scheme::mono(Ty::fixed_int(name)));
+    }
+    env.insert("Bytes".into(), Scheme::mono(Ty::bytes()));
+    env.insert("IoData".into(), Scheme::mono(Ty::iodata()));
+    env.insert("BigInt".into(), Scheme::mono(Ty::bigint()));
+    env.insert("Decimal".into(), Scheme::mono(Ty::decimal()));
 
     // ── Actor type constructor ────────────────────────────────────
     //
@@ -92,6 +99,31 @@ pub fn register_builtins(
         );
     }
 
+    // ── Test assertions ─────────────────────────────────────────────
+    //
+    // assert(Bool) -> (), refute(Bool) -> (), assert_eq(T, T) -> ().
+    // MIR lowering expands these inline so failures report the call's
+    // source location.
+    env.insert(
+        "assert".into(),
+        Scheme::mono(Ty::fun(vec![Ty::bool()], Ty::Tuple(vec![]))),
+    );
+    env.insert(
+        "refute".into(),
+        Scheme::mono(Ty::fun(vec![Ty::bool()], Ty::Tuple(vec![]))),
+    );
+    {
+        let t_var = TyVar(99003);
+        let t = Ty::Var(t_var);
+        env.insert(
+            "assert_eq".into(),
+            Scheme {
+                vars: vec![t_var],
+                ty: Ty::fun(vec![t.clone(), t], Ty::Tuple(vec![])),
+            },
+        );
+    }
+
     // ── Compiler-known traits ──────────────────────────────────────
 
     register_compiler_known_traits(trait_registry);
@@ -259,14 +291,12 @@ pub fn register_builtins(
     env.insert("Map".into(), Scheme::mono(Ty::map_untyped()));
     env.insert("Set".into(), Scheme::mono(Ty::set_untyped()));
     env.insert("Range".into(), Scheme::mono(Ty::range()));
-    env.insert("Queue".into(), Scheme::mono(Ty::queue()));
+    env.insert("Queue".into(), Scheme::mono(Ty::queue_untyped()));
 
     // Use opaque types (untyped) for non-list collection function signatures.
     // At the LLVM level these are all pointers; type safety is checked by Mesh's type system.
     let map_t = Ty::map_untyped();
-    let set_t = Ty::set_untyped();
     let range_t = Ty::range();
-    let queue_t = Ty::queue();
 
     // ── Polymorphic List functions ──────────────────────────────────────
     // List functions use TyVar(91000) for T and TyVar(91001) for U
@@ -311,6 +341,8 @@ pub fn register_builtins(
         env.insert("list_length".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone()], Ty::int()) });
         // List.append(List<T>, T) -> List<T>
         env.insert("list_append".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), t.clone()], list_t.clone()) });
+        // List.prepend(List<T>, T) -> List<T>
+        env.insert("list_prepend".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), t.clone()], list_t.clone()) });
         // List.get(List<T>, Int) -> T
         env.insert("list_get".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), Ty::int()], t.clone()) });
         // List.concat(List<T>, List<T>) -> List<T>
@@ -360,49 +392,23 @@ pub fn register_builtins(
         env.insert("map_from_list".into(), Scheme { vars: vec![k_var, v_var], ty: Ty::fun(vec![Ty::list(Ty::Tuple(vec![k.clone(), v.clone()]))], map_kv.clone()) });
     }
 
-    // ── Set module functions ──────────────────────────────────────────
-
-    env.insert(
-        "set_new".into(),
-        Scheme::mono(Ty::fun(vec![], set_t.clone())),
-    );
-    env.insert(
-        "set_add".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], set_t.clone())),
-    );
-    env.insert(
-        "set_remove".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], set_t.clone())),
-    );
-    env.insert(
-        "set_contains".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], Ty::bool())),
-    );
-    env.insert(
-        "set_size".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone()], Ty::int())),
-    );
-    env.insert(
-        "set_union".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())),
-    );
-    env.insert(
-        "set_intersection".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())),
-    );
-    // Phase 47: difference, to_list, from_list
-    env.insert(
-        "set_difference".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())),
-    );
-    env.insert(
-        "set_to_list".into(),
-        Scheme::mono(Ty::fun(vec![set_t.clone()], Ty::list(Ty::int()))),
-    );
-    env.insert(
-        "set_from_list".into(),
-        Scheme::mono(Ty::fun(vec![Ty::list(Ty::int())], set_t.clone())),
-    );
+    // ── Set module functions (polymorphic) ────────────────────────────
+    {
+        let t_var = TyVar(93000);
+        let t = Ty::Var(t_var);
+        let set_t = Ty::set(t.clone());
+        env.insert("set_new".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![], set_t.clone()) });
+        env.insert("set_add".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), t.clone()], set_t.clone()) });
+        env.insert("set_remove".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), t.clone()], set_t.clone()) });
+        env.insert("set_contains".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), t.clone()], Ty::bool()) });
+        env.insert("set_size".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone()], Ty::int()) });
+        env.insert("set_union".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
+        env.insert("set_intersection".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
+        // Phase 47: difference, to_list, from_list
+        env.insert("set_difference".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
+        env.insert("set_to_list".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![set_t.clone()], Ty::list(t.clone())) });
+        env.insert("set_from_list".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![Ty::list(t.clone())], set_t.clone()) });
+    }
 
     // ── Tuple module functions ────────────────────────────────────────
 
@@ -422,6 +428,10 @@ pub fn register_builtins(
         "tuple_size".into(),
         Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::int())),
     );
+    env.insert(
+        "tuple_to_list".into(),
+        Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::list(Ty::int()))),
+    );
 
     // ── Range module functions ────────────────────────────────────────
     // Re-declare opaque list and closure types for range/JSON functions.
@@ -450,32 +460,20 @@ pub fn register_builtins(
         Scheme::mono(Ty::fun(vec![range_t.clone()], Ty::int())),
     );
 
-    // ── Queue module functions ────────────────────────────────────────
-
-    env.insert(
-        "queue_new".into(),
-        Scheme::mono(Ty::fun(vec![], queue_t.clone())),
-    );
-    env.insert(
-        "queue_push".into(),
-        Scheme::mono(Ty::fun(vec![queue_t.clone(), Ty::int()], queue_t.clone())),
-    );
-    env.insert(
-        "queue_pop".into(),
-        Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::Con(TyCon::new("Tuple")))),
-    );
-    env.insert(
-        "queue_peek".into(),
-        Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::int())),
-    );
-    env.insert(
-        "queue_size".into(),
-        Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::int())),
-    );
-    env.insert(
-        "queue_is_empty".into(),
-        Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::bool())),
-    );
+    // ── Queue module functions (polymorphic) ──────────────────────────
+    {
+        let t_var = TyVar(93100);
+        let t = Ty::Var(t_var);
+        let queue_t = Ty::queue(t.clone());
+        env.insert("queue_new".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![], queue_t.clone()) });
+        env.insert("queue_push".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone(), t.clone()], queue_t.clone()) });
+        env.insert("queue_pop".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone()], Ty::Tuple(vec![t.clone(), queue_t.clone()])) });
+        env.insert("queue_peek".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone()], t.clone()) });
+        env.insert("queue_size".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone()], Ty::int()) });
+        env.insert("queue_is_empty".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone()], Ty::bool()) });
+        env.insert("queue_to_list".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![queue_t.clone()], Ty::list(t.clone())) });
+        env.insert("queue_from_list".into(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![Ty::list(t.clone())], queue_t.clone()) });
+    }
 
     // ── Standard library: JSON functions (Phase 8 Plan 04) ────────────
 
@@ -831,6 +829,16 @@ pub fn register_builtins(
 /// inference engine encounters `a + b`, it checks that the resolved type
 /// of `a` has an impl for `Add`.
 fn register_compiler_known_traits(registry: &mut TraitRegistry) {
+    let fixed_int_types: Vec<(Ty, &str)> =
+        FIXED_INT_TYPES.iter().map(|name| (Ty::fixed_int(name), *name)).collect();
+    let big_numeric_types: Vec<(Ty, &str)> =
+        vec![(Ty::bigint(), "BigInt"), (Ty::decimal(), "Decimal")];
+    let numeric_types: Vec<(Ty, &str)> = [(Ty::int(), "Int"), (Ty::float(), "Float")]
+        .into_iter()
+        .chain(fixed_int_types.iter().cloned())
+        .chain(big_numeric_types.iter().cloned())
+        .collect();
+
     // ── Arithmetic traits ──────────────────────────────────────────
 
     let arithmetic_traits = ["Add", "Sub", "Mul", "Div", "Mod"];
@@ -847,8 +855,8 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
             associated_types: vec![AssocTypeDef { name: "Output".to_string() }],
         });
 
-        // Register impls for Int and Float.
-        for (ty, ty_name) in &[(Ty::int(), "Int"), (Ty::float(), "Float")] {
+        // Register impls for Int, Float, the fixed-width integers, BigInt and Decimal.
+        for (ty, ty_name) in &numeric_types {
             let mut methods = FxHashMap::default();
             methods.insert(
                 trait_name.to_lowercase(),
@@ -885,8 +893,8 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![AssocTypeDef { name: "Output".to_string() }],
     });
 
-    // Neg impls for Int and Float.
-    for (ty, ty_name) in &[(Ty::int(), "Int"), (Ty::float(), "Float")] {
+    // Neg impls for Int, Float, the signed fixed-width integers, BigInt and Decimal.
+    for (ty, ty_name) in numeric_types.iter().filter(|(_, name)| !name.starts_with("UInt")) {
         let mut methods = FxHashMap::default();
         methods.insert(
             "neg".to_string(),
@@ -1028,9 +1036,9 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         });
     }
 
-    // impl Iterable for Set (untyped -- Set stores Int elements)
+    // impl Iterable for Set<T>
     {
-        let set_t = Ty::Con(TyCon::new("Set"));
+        let set_t = Ty::set(Ty::Con(TyCon::new("T")));
         let mut methods = FxHashMap::default();
         methods.insert("iter".to_string(), ImplMethodSig {
             has_self: true,
@@ -1038,7 +1046,7 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
             return_type: None,
         });
         let mut assoc_types = FxHashMap::default();
-        assoc_types.insert("Item".to_string(), Ty::int());
+        assoc_types.insert("Item".to_string(), Ty::Con(TyCon::new("T")));
         assoc_types.insert("Iter".to_string(), Ty::Con(TyCon::new("SetIterator")));
         let _ = registry.register_impl(ImplDef {
             trait_name: "Iterable".to_string(),
@@ -1059,7 +1067,7 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
             return_type: None,
         });
         let mut assoc_types = FxHashMap::default();
-        assoc_types.insert("Item".to_string(), Ty::int());
+        assoc_types.insert("Item".to_string(), Ty::Con(TyCon::new("T")));
         let _ = registry.register_impl(ImplDef {
             trait_name: "Iterator".to_string(),
             trait_type_args: vec![],
@@ -1126,13 +1134,19 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![],
     });
 
-    // Eq impls for Int, Float, String, Bool.
-    for (ty, ty_name) in &[
+    // Eq impls for Int, Float, String, Bool, the fixed-width integers, Bytes,
+    // BigInt and Decimal.
+    for (ty, ty_name) in [
         (Ty::int(), "Int"),
         (Ty::float(), "Float"),
         (Ty::string(), "String"),
         (Ty::bool(), "Bool"),
-    ] {
+    ]
+    .iter()
+    .chain(&fixed_int_types)
+    .chain(&[(Ty::bytes(), "Bytes")])
+    .chain(&big_numeric_types)
+    {
         let mut methods = FxHashMap::default();
         methods.insert(
             "eq".to_string(),
@@ -1175,12 +1189,16 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![],
     });
 
-    // Ord impls for Int, Float, String.
-    for (ty, ty_name) in &[
+    // Ord impls for Int, Float, String, the fixed-width integers, BigInt and Decimal.
+    for (ty, ty_name) in [
         (Ty::int(), "Int"),
         (Ty::float(), "Float"),
         (Ty::string(), "String"),
-    ] {
+    ]
+    .iter()
+    .chain(&fixed_int_types)
+    .chain(&big_numeric_types)
+    {
         let mut methods = FxHashMap::default();
         methods.insert(
             "lt".to_string(),
@@ -1257,6 +1275,30 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         });
     }
 
+    // Eq for Set<T> (order-insensitive) and Queue<T> (front to back).
+    for (coll_t, coll_name) in [
+        (Ty::set(Ty::Con(TyCon::new("T"))), "Set"),
+        (Ty::queue(Ty::Con(TyCon::new("T"))), "Queue"),
+    ] {
+        let mut eq_methods = FxHashMap::default();
+        eq_methods.insert(
+            "eq".to_string(),
+            ImplMethodSig {
+                has_self: true,
+                param_count: 1,
+                return_type: Some(Ty::bool()),
+            },
+        );
+        let _ = registry.register_impl(ImplDef {
+            trait_name: "Eq".to_string(),
+            trait_type_args: vec![],
+            impl_type: coll_t,
+            impl_type_name: coll_name.to_string(),
+            methods: eq_methods,
+            associated_types: FxHashMap::default(),
+        });
+    }
+
     // ── Not trait ───────────────────────────────────────────────────
 
     registry.register_trait(TraitDef {
@@ -1302,12 +1344,17 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![],
     });
 
-    for (ty, ty_name) in &[
+    for (ty, ty_name) in [
         (Ty::int(), "Int"),
         (Ty::float(), "Float"),
         (Ty::string(), "String"),
         (Ty::bool(), "Bool"),
-    ] {
+    ]
+    .iter()
+    .chain(&fixed_int_types)
+    .chain(&[(Ty::bytes(), "Bytes")])
+    .chain(&big_numeric_types)
+    {
         let mut methods = FxHashMap::default();
         methods.insert(
             "to_string".to_string(),
@@ -1328,7 +1375,7 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
     }
 
     // ── Display/Debug for collection types (Phase 31) ──────────
-    // Register Display for List<T>, Map<K,V>, Set so method dot-syntax
+    // Register Display for List<T>, Map<K,V>, Set<T>, Queue<T> so method dot-syntax
     // works for to_string() on collections. The actual runtime Display
     // is handled by wrap_collection_to_string in MIR lowering.
     {
@@ -1374,8 +1421,10 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
             associated_types: FxHashMap::default(),
         });
     }
-    {
-        let set_t = Ty::Con(TyCon::new("Set"));
+    for (coll_t, coll_name) in [
+        (Ty::set(Ty::Con(TyCon::new("T"))), "Set"),
+        (Ty::queue(Ty::Con(TyCon::new("T"))), "Queue"),
+    ] {
         let mut methods = FxHashMap::default();
         methods.insert(
             "to_string".to_string(),
@@ -1388,8 +1437,8 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         let _ = registry.register_impl(ImplDef {
             trait_name: "Display".to_string(),
             trait_type_args: vec![],
-            impl_type: set_t,
-            impl_type_name: "Set".to_string(),
+            impl_type: coll_t,
+            impl_type_name: coll_name.to_string(),
             methods,
             associated_types: FxHashMap::default(),
         });
@@ -1408,15 +1457,21 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![],
     });
 
-    // Debug impls for primitives (Int, Float, String, Bool).
+    // Debug impls for primitives (Int, Float, String, Bool, fixed-width integers, Bytes,
+    // BigInt, Decimal).
     // For primitives, inspect produces the same output as to_string
     // (except String wraps in quotes -- handled at codegen).
-    for (ty, ty_name) in &[
+    for (ty, ty_name) in [
         (Ty::int(), "Int"),
         (Ty::float(), "Float"),
         (Ty::string(), "String"),
         (Ty::bool(), "Bool"),
-    ] {
+    ]
+    .iter()
+    .chain(&fixed_int_types)
+    .chain(&[(Ty::bytes(), "Bytes")])
+    .chain(&big_numeric_types)
+    {
         let mut methods = FxHashMap::default();
         methods.insert(
             "inspect".to_string(),
@@ -1436,6 +1491,31 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         });
     }
 
+    // Debug for Set<T> and Queue<T>; like Display, the runtime output is
+    // produced by wrap_collection_to_string in MIR lowering.
+    for (coll_t, coll_name) in [
+        (Ty::set(Ty::Con(TyCon::new("T"))), "Set"),
+        (Ty::queue(Ty::Con(TyCon::new("T"))), "Queue"),
+    ] {
+        let mut methods = FxHashMap::default();
+        methods.insert(
+            "inspect".to_string(),
+            ImplMethodSig {
+                has_self: true,
+                param_count: 0,
+                return_type: Some(Ty::string()),
+            },
+        );
+        let _ = registry.register_impl(ImplDef {
+            trait_name: "Debug".to_string(),
+            trait_type_args: vec![],
+            impl_type: coll_t,
+            impl_type_name: coll_name.to_string(),
+            methods,
+            associated_types: FxHashMap::default(),
+        });
+    }
+
     // ── Hash trait ──────────────────────────────────────────────
     registry.register_trait(TraitDef {
         name: "Hash".to_string(),
@@ -1449,13 +1529,18 @@ fn register_compiler_known_traits(registry: &mut TraitRegistry) {
         associated_types: vec![],
     });
 
-    // Hash impls for primitives (Int, Float, String, Bool).
-    for (ty, ty_name) in &[
+    // Hash impls for primitives (Int, Float, String, Bool, fixed-width integers,
+    // BigInt, Decimal).
+    for (ty, ty_name) in [
         (Ty::int(), "Int"),
         (Ty::float(), "Float"),
         (Ty::string(), "String"),
         (Ty::bool(), "Bool"),
-    ] {
+    ]
+    .iter()
+    .chain(&fixed_int_types)
+    .chain(&big_numeric_types)
+    {
         let mut methods = FxHashMap::default();
         methods.insert(
             "hash".to_string(),
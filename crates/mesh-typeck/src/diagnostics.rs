@@ -15,6 +15,7 @@ use ariadne::{Color, Config, Label, Report, ReportKind};
 use serde::Serialize;
 
 use crate::error::{ConstraintOrigin, TypeError};
+use crate::lint::LintLevel;
 use crate::ty::Ty;
 
 // ── Diagnostic Options ───────────────────────────────────────────────
@@ -133,6 +134,12 @@ fn error_code(err: &TypeError) -> &'static str {
         TypeError::MissingAssocType { .. } => "E0040",
         TypeError::ExtraAssocType { .. } => "E0041",
         TypeError::UnresolvedAssocType { .. } => "E0042",
+        TypeError::PipePositionOutOfRange { .. } => "E0043",
+        TypeError::InvalidRegex { .. } => "E0044",
+        TypeError::TupleAccess { .. } => "E0045",
+        TypeError::InvalidBytesSegment { .. } => "E0046",
+        TypeError::InvalidFormatSpec { .. } => "E0047",
+        TypeError::Lint { lint, .. } => lint.code(),
     }
 }
 
@@ -140,6 +147,7 @@ fn error_code(err: &TypeError) -> &'static str {
 fn severity(err: &TypeError) -> &'static str {
     match err {
         TypeError::RedundantArm { .. } => "warning",
+        TypeError::Lint { level, .. } if *level != LintLevel::Deny => "warning",
         _ => "error",
     }
 }
@@ -461,6 +469,62 @@ pub fn render_json_diagnostic(
             });
             fix = Some("remove this arm or reorder the match".to_string());
         }
+        TypeError::PipePositionOutOfRange { arity, span, .. } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: pipe_position_label(*arity),
+            });
+            fix = Some(pipe_position_help(*arity));
+        }
+        TypeError::InvalidRegex { message, span, .. } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: message.clone(),
+            });
+            fix = Some(REGEX_HELP.to_string());
+        }
+        TypeError::TupleAccess { reason, span, .. } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: reason.clone(),
+            });
+            fix = Some(TUPLE_ACCESS_HELP.to_string());
+        }
+        TypeError::InvalidBytesSegment { message, span } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: message.clone(),
+            });
+            fix = Some(BYTES_SEGMENT_HELP.to_string());
+        }
+        TypeError::InvalidFormatSpec { message, span, .. } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: message.clone(),
+            });
+            fix = Some(FORMAT_SPEC_HELP.to_string());
+        }
+        TypeError::Lint {
+            label, help, span, ..
+        } => {
+            let range = text_range_to_range(*span);
+            spans.push(JsonSpan {
+                start: range.start,
+                end: range.end,
+                label: label.clone(),
+            });
+            fix = Some(help.clone());
+        }
         _ => {
             fix = None;
             match error {
@@ -526,6 +590,39 @@ pub fn render_json_diagnostic(
     serde_json::to_string(&diag).unwrap_or_else(|_| "{}".to_string())
 }
 
+/// Label of an E0043 span: the positions the call has.
+fn pipe_position_label(arity: usize) -> String {
+    if arity == 1 {
+        "the call only has the piped argument".to_string()
+    } else {
+        format!("valid positions are 1 to {}", arity)
+    }
+}
+
+/// Suggested fix for E0043.
+fn pipe_position_help(arity: usize) -> String {
+    match arity {
+        1 => "use `|>` to pass the value as the only argument".to_string(),
+        2 => "use `|>` for the first argument or `|2>` for the second".to_string(),
+        _ => format!("use `|>` for the first argument or `|2>` to `|{}>` for a later one", arity),
+    }
+}
+
+/// Suggested fix for E0044.
+const REGEX_HELP: &str = "regex literals use `~r/pattern/flags` with flags from `imsxU`; escape `/` as `\\/`";
+
+/// Suggested fix for E0045.
+const TUPLE_ACCESS_HELP: &str =
+    "use a literal index within the tuple, or annotate the tuple type, e.g. `pair :: (Int, String)`";
+
+/// Suggested fix for E0046.
+const BYTES_SEGMENT_HELP: &str =
+    "segments are written `value::type-order(size)`, e.g. `len::u16-little` or `body::bytes(len)`";
+
+/// Suggested fix for E0047.
+const FORMAT_SPEC_HELP: &str =
+    "specs are written `[[fill]align][+][0][width][.precision][type]`, e.g. `${price:.2}`, `${id:>8}` or `${n:x}`";
+
 // ── Main Rendering Function ────────────────────────────────────────────
 
 /// Render a type error into a formatted diagnostic string using ariadne.
@@ -1665,6 +1762,123 @@ pub fn render_diagnostic(
                 )
                 .finish()
         }
+
+        TypeError::PipePositionOutOfRange { arity, span, .. } => {
+            let msg = error.to_string();
+            let range = clamp(text_range_to_range(*span));
+
+            Report::build(ReportKind::Error, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(&msg)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(pipe_position_label(*arity))
+                        .with_color(Color::Red),
+                )
+                .with_help(pipe_position_help(*arity))
+                .finish()
+        }
+
+        TypeError::InvalidRegex { message, span, .. } => {
+            let msg = error.to_string();
+            let range = clamp(text_range_to_range(*span));
+
+            Report::build(ReportKind::Error, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(&msg)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(message)
+                        .with_color(Color::Red),
+                )
+                .with_help(REGEX_HELP)
+                .finish()
+        }
+
+        TypeError::TupleAccess { reason, span, .. } => {
+            let msg = error.to_string();
+            let range = clamp(text_range_to_range(*span));
+
+            Report::build(ReportKind::Error, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(&msg)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(reason)
+                        .with_color(Color::Red),
+                )
+                .with_help(TUPLE_ACCESS_HELP)
+                .finish()
+        }
+
+        TypeError::InvalidBytesSegment { message, span } => {
+            let msg = error.to_string();
+            let range = clamp(text_range_to_range(*span));
+
+            Report::build(ReportKind::Error, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(&msg)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(message)
+                        .with_color(Color::Red),
+                )
+                .with_help(BYTES_SEGMENT_HELP)
+                .finish()
+        }
+
+        TypeError::InvalidFormatSpec { message, span, .. } => {
+            let msg = error.to_string();
+            let range = clamp(text_range_to_range(*span));
+
+            Report::build(ReportKind::Error, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(&msg)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(message)
+                        .with_color(Color::Red),
+                )
+                .with_help(FORMAT_SPEC_HELP)
+                .finish()
+        }
+
+        TypeError::Lint {
+            lint,
+            level,
+            message,
+            label,
+            help,
+            span,
+        } => {
+            let range = clamp(text_range_to_range(*span));
+            let (kind, color) = match level {
+                LintLevel::Deny => (ReportKind::Error, Color::Red),
+                _ => (ReportKind::Warning, Color::Yellow),
+            };
+
+            Report::build(kind, (fname.clone(), range.clone()))
+                .with_code(code)
+                .with_message(message)
+                .with_config(config)
+                .with_label(
+                    Label::new((fname.clone(), range))
+                        .with_message(label)
+                        .with_color(color),
+                )
+                .with_help(help)
+                .with_note(format!(
+                    "`{}` is set to `{}`; configure it under [lints] in mesh.toml",
+                    lint.name(),
+                    level.name()
+                ))
+                .finish()
+        }
     };
 
     let mut buf = Vec::new();
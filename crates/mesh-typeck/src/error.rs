@@ -8,6 +8,7 @@ use std::fmt;
 
 use rowan::TextRange;
 
+use crate::lint::{Lint, LintLevel};
 use crate::ty::{Ty, TyVar};
 
 /// The origin of a type constraint -- where in the source code did we
@@ -322,6 +323,57 @@ pub enum TypeError {
         assoc_name: String,
         span: TextRange,
     },
+    /// `|N>` pipes into an argument position the call does not have.
+    PipePositionOutOfRange {
+        /// The operator as written, e.g. `|4>`.
+        op: String,
+        /// Number of arguments of the call, including the piped value.
+        arity: usize,
+        span: TextRange,
+    },
+    /// A `~r/.../` literal whose pattern or flags do not compile.
+    InvalidRegex {
+        /// The pattern as the regex engine sees it (`\/` unescaped).
+        pattern: String,
+        /// The engine's reason, e.g. `unclosed group`.
+        message: String,
+        span: TextRange,
+    },
+    /// A `Tuple.first/second/nth/to_list` call whose result type cannot
+    /// be read off the tuple's element types.
+    TupleAccess {
+        /// The accessor as written, e.g. `Tuple.nth`.
+        function: String,
+        /// Why, e.g. `index 2 is out of range for a 2-element tuple`.
+        reason: String,
+        span: TextRange,
+    },
+    /// A segment of a `<<...>>` binary pattern or construction that the
+    /// compiler cannot lay out.
+    InvalidBytesSegment {
+        /// Why, e.g. `` `rest` must be the last segment ``.
+        message: String,
+        span: TextRange,
+    },
+    /// The format spec of a `${value:spec}` interpolation does not parse or
+    /// does not apply to the value's type.
+    InvalidFormatSpec {
+        /// The spec as written after the `:`, e.g. `.2`.
+        spec: String,
+        /// Why, e.g. `` precision `.2` applies to a Float or String, not Int ``.
+        message: String,
+        span: TextRange,
+    },
+    /// A finding of the lint pass (see [`crate::lint`]), reported as a
+    /// warning or, for denied lints, an error.
+    Lint {
+        lint: Lint,
+        level: LintLevel,
+        message: String,
+        label: String,
+        help: String,
+        span: TextRange,
+    },
 }
 
 impl fmt::Display for TypeError {
@@ -680,6 +732,28 @@ impl fmt::Display for TypeError {
                     assoc_name
                 )
             }
+            TypeError::PipePositionOutOfRange { op, arity, .. } => {
+                write!(
+                    f,
+                    "`{}` is out of range for a call with {} argument{}",
+                    op,
+                    arity,
+                    if *arity == 1 { "" } else { "s" }
+                )
+            }
+            TypeError::InvalidRegex { pattern, message, .. } => {
+                write!(f, "invalid regex `{}`: {}", pattern, message)
+            }
+            TypeError::TupleAccess { function, reason, .. } => {
+                write!(f, "cannot type `{}`: {}", function, reason)
+            }
+            TypeError::InvalidBytesSegment { message, .. } => {
+                write!(f, "invalid binary segment: {}", message)
+            }
+            TypeError::InvalidFormatSpec { spec, message, .. } => {
+                write!(f, "invalid format spec `{}`: {}", spec, message)
+            }
+            TypeError::Lint { message, .. } => write!(f, "{}", message),
         }
     }
 }
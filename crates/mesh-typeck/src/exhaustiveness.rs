@@ -19,6 +19,10 @@ pub enum LitKind {
     Float,
     Bool,
     String,
+    /// A binary pattern (`<<tag::u8, rest::rest>>`), keyed by its source text.
+    Bytes,
+    /// A pinned variable (`^expected`), keyed by the variable's name.
+    Pinned,
 }
 
 /// Abstract pattern representation for exhaustiveness checking.
@@ -39,6 +43,9 @@ pub enum Pat {
     Literal { value: String, ty: LitKind },
     /// Matches any of the alternatives (or-pattern).
     Or { alternatives: Vec<Pat> },
+    /// Matches a map that has every listed key, with each value matching
+    /// its pattern. Keys are written as in the source (`1`, `"id"`).
+    Map { entries: Vec<(String, Pat)> },
 }
 
 /// A row in the pattern matrix (one match arm's patterns).
@@ -191,6 +198,8 @@ fn specialize_row_into(out: &mut Vec<PatternRow>, row: &[Pat], ctor: &Constructo
                 specialize_row_into(out, &expanded_row, ctor);
             }
         }
+        // Map columns are expanded to constructors before specializing.
+        Pat::Map { .. } => {}
     }
 }
 
@@ -237,6 +246,9 @@ fn infer_type_info_for_column(
     // Check if any pattern in this column has a type_name we can look up
     let type_name = find_type_name_in_column(matrix, row, col);
 
+    if type_name.as_deref() == Some(MAP_KEY_TYPE) {
+        return map_key_info();
+    }
     if let Some(ref tn) = type_name {
         if let Some(info) = registry.lookup(tn) {
             return info.clone();
@@ -365,7 +377,7 @@ fn collect_constructors_from_pat(
                 collect_constructors_from_pat(alt, seen, result);
             }
         }
-        Pat::Wildcard => {}
+        Pat::Wildcard | Pat::Map { .. } => {}
     }
 }
 
@@ -404,10 +416,117 @@ fn collect_types_from_pattern(pat: &Pat, registry: &mut TypeRegistry) {
                 collect_types_from_pattern(alt, registry);
             }
         }
+        Pat::Map { entries } => {
+            for (_, value) in entries {
+                collect_types_from_pattern(value, registry);
+            }
+        }
         Pat::Wildcard | Pat::Literal { .. } => {}
     }
 }
 
+// ── Map patterns ─────────────────────────────────────────────────────
+
+/// The constructor a map column is expanded to.
+const MAP_CTOR: &str = "%{}";
+/// The type of one key of an expanded map: `Present(value)` or `Absent`.
+const MAP_KEY_TYPE: &str = "MapKey";
+
+fn map_key_info() -> TypeInfo {
+    TypeInfo::SumType {
+        variants: vec![
+            ConstructorSig { name: "Present".to_string(), arity: 1 },
+            ConstructorSig { name: "Absent".to_string(), arity: 0 },
+        ],
+    }
+}
+
+/// Collect the keys of the map patterns in `pat`, in first-seen order.
+fn collect_map_keys(pat: &Pat, keys: &mut Vec<String>) {
+    match pat {
+        Pat::Map { entries } => {
+            for (key, _) in entries {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        Pat::Or { alternatives } => {
+            for alt in alternatives {
+                collect_map_keys(alt, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrite a map pattern as a constructor with one argument per key in
+/// `keys`: `Present(value)` for the keys it lists, a wildcard (present or
+/// absent) for the others.
+fn expand_map_pat(pat: &Pat, keys: &[String]) -> Pat {
+    match pat {
+        Pat::Map { entries } => Pat::Constructor {
+            name: MAP_CTOR.to_string(),
+            type_name: String::new(),
+            args: keys
+                .iter()
+                .map(|key| match entries.iter().find(|(k, _)| k == key) {
+                    Some((_, value)) => Pat::Constructor {
+                        name: "Present".to_string(),
+                        type_name: MAP_KEY_TYPE.to_string(),
+                        args: vec![value.clone()],
+                    },
+                    None => Pat::Wildcard,
+                })
+                .collect(),
+        },
+        Pat::Or { alternatives } => Pat::Or {
+            alternatives: alternatives.iter().map(|alt| expand_map_pat(alt, keys)).collect(),
+        },
+        other => other.clone(),
+    }
+}
+
+/// If the first column holds map patterns, expand every map in it over the
+/// union of their keys, so the column has a single constructor whose
+/// arguments say whether each key is present.
+fn expand_map_column(
+    matrix: &PatternMatrix,
+    row: &[Pat],
+    type_info: &[TypeInfo],
+) -> Option<(PatternMatrix, Vec<Pat>, Vec<TypeInfo>)> {
+    let heads = matrix.rows.iter().filter_map(|r| r.first()).chain(row.first());
+    fn is_map(pat: &Pat) -> bool {
+        match pat {
+            Pat::Map { .. } => true,
+            Pat::Or { alternatives } => alternatives.iter().any(is_map),
+            _ => false,
+        }
+    }
+    if !heads.clone().any(is_map) {
+        return None;
+    }
+    let mut keys = Vec::new();
+    for head in heads {
+        collect_map_keys(head, &mut keys);
+    }
+    let expand_row = |r: &[Pat]| -> Vec<Pat> {
+        let mut out = r.to_vec();
+        if let Some(head) = out.first_mut() {
+            *head = expand_map_pat(head, &keys);
+        }
+        out
+    };
+    let matrix = PatternMatrix {
+        rows: matrix.rows.iter().map(|r| expand_row(r)).collect(),
+    };
+    let mut info = vec![TypeInfo::SumType {
+        variants: vec![ConstructorSig { name: MAP_CTOR.to_string(), arity: keys.len() }],
+    }];
+    info.extend_from_slice(type_info.get(1..).unwrap_or_default());
+    Some((matrix, expand_row(row), info))
+}
+
 // ── Core algorithm ───────────────────────────────────────────────────
 
 /// Core usefulness predicate (Algorithm U).
@@ -453,6 +572,10 @@ fn is_useful_inner(
         return false;
     }
 
+    if let Some((matrix, row, type_info)) = expand_map_column(matrix, row, type_info) {
+        return is_useful_inner(&matrix, &row, &type_info, registry);
+    }
+
     let head = &row[0];
     let col_type = type_info.first();
 
@@ -508,6 +631,8 @@ fn is_useful_inner(
             is_useful_inner(matrix, &new_row, type_info, registry)
         }),
 
+        Pat::Map { .. } => unreachable!("map columns are expanded before this point"),
+
         // Case 3b: Wildcard
         Pat::Wildcard => {
             let all_constructors = all_constructors_for_type(col_type);
@@ -1173,6 +1298,65 @@ mod tests {
         assert_eq!(result, vec![2]);
     }
 
+    // ── Map and pinned patterns ──────────────────────────────────────
+
+    fn map_pat(entries: Vec<(&str, Pat)>) -> Pat {
+        Pat::Map {
+            entries: entries.into_iter().map(|(k, p)| (k.to_string(), p)).collect(),
+        }
+    }
+
+    fn pinned(name: &str) -> Pat {
+        Pat::Literal {
+            value: name.to_string(),
+            ty: LitKind::Pinned,
+        }
+    }
+
+    #[test]
+    fn test_map_patterns_need_a_catch_all() {
+        let arms = vec![
+            map_pat(vec![("\"type\"", lit_int(1)), ("\"id\"", wildcard())]),
+            map_pat(vec![("\"type\"", wildcard())]),
+        ];
+        assert!(check_exhaustiveness(&arms, &int_type(), &empty_registry()).is_some());
+
+        // `%{}` matches every map.
+        let mut arms = arms;
+        arms.push(map_pat(vec![]));
+        assert!(check_exhaustiveness(&arms, &int_type(), &empty_registry()).is_none());
+    }
+
+    #[test]
+    fn test_map_pattern_redundancy() {
+        // %{"a" => _} covers %{"a" => 1, "b" => x}; %{"b" => _} is still reachable.
+        let arms = vec![
+            map_pat(vec![("\"a\"", wildcard())]),
+            map_pat(vec![("\"a\"", lit_int(1)), ("\"b\"", wildcard())]),
+            map_pat(vec![("\"b\"", wildcard())]),
+            wildcard(),
+        ];
+        assert_eq!(check_redundancy(&arms, &int_type(), &empty_registry()), vec![1]);
+    }
+
+    #[test]
+    fn test_map_values_use_nested_type_info() {
+        // Some(_) and None under the same key cover every map that has it.
+        let arms = vec![
+            map_pat(vec![("1", ctor("Some", "Option", vec![wildcard()]))]),
+            map_pat(vec![("1", ctor("None", "Option", vec![]))]),
+            map_pat(vec![("1", wildcard())]),
+        ];
+        assert_eq!(check_redundancy(&arms, &int_type(), &test_registry()), vec![2]);
+    }
+
+    #[test]
+    fn test_pinned_values_are_never_exhaustive() {
+        let arms = vec![pinned("expected"), lit_int(0), pinned("expected")];
+        assert!(check_exhaustiveness(&arms, &int_type(), &empty_registry()).is_some());
+        assert_eq!(check_redundancy(&arms, &int_type(), &empty_registry()), vec![2]);
+    }
+
     // ── TypeInfo for nested specialization ───────────────────────────
 
     #[test]
@@ -0,0 +1,1380 @@
+//! Long-form explanations of diagnostic codes, shown by `meshc explain`.
+//!
+//! Every code the compiler reports (`E....` type errors, `W....` warnings
+//! and lints, `P0001` parse errors, `C0001` fatal errors) has an entry with
+//! a description, an example that produces the code, and a fixed version
+//! of the example. The examples are checked against the compiler by this
+//! crate's `explain` tests.
+//!
+//! `E0005`, `E0024`, `E0025` and `E0042` are reserved: the type checker has
+//! error variants for them but never reports them, so they have no entry.
+
+/// The explanation of one diagnostic code.
+#[derive(Clone, Debug)]
+pub struct Explanation {
+    /// The code, e.g. `E0001`.
+    pub code: &'static str,
+    /// One-line summary.
+    pub title: &'static str,
+    /// What the diagnostic means and why it is reported.
+    pub description: &'static str,
+    /// A module the example imports, as `(module name, source)`.
+    pub dependency: Option<(&'static str, &'static str)>,
+    /// A program that produces the code.
+    pub example: &'static str,
+    /// The example, fixed.
+    pub fix: &'static str,
+}
+
+impl Explanation {
+    /// Render the explanation as Markdown-style text for the terminal.
+    pub fn render(&self) -> String {
+        let mut out = format!("{}: {}\n\n{}\n", self.code, self.title, self.description.trim());
+        if self.example.is_empty() {
+            return out;
+        }
+        out.push_str("\nExample:\n\n");
+        if let Some((module, source)) = self.dependency {
+            out.push_str(&format!("```mesh\n# {}\n{}\n```\n\n", module_file(module), source.trim()));
+        }
+        out.push_str(&format!("```mesh\n{}\n```\n\nFixed:\n\n```mesh\n{}\n```\n", self.example.trim(), self.fix.trim()));
+        out
+    }
+}
+
+/// File name of a module: `Math.Vector` -> `math/vector.mpl`.
+fn module_file(module: &str) -> String {
+    let path: Vec<String> = module
+        .split('.')
+        .map(|segment| {
+            let mut out = String::new();
+            for (i, c) in segment.chars().enumerate() {
+                if c.is_uppercase() && i > 0 {
+                    out.push('_');
+                }
+                out.extend(c.to_lowercase());
+            }
+            out
+        })
+        .collect();
+    format!("{}.mpl", path.join("/"))
+}
+
+/// Look up the explanation of a code (case-insensitive).
+pub fn explain(code: &str) -> Option<&'static Explanation> {
+    EXPLANATIONS
+        .iter()
+        .find(|e| e.code.eq_ignore_ascii_case(code.trim()))
+}
+
+/// Every explained code, in code order within each category.
+pub const EXPLANATIONS: &[Explanation] = &[
+    // ── Type errors ────────────────────────────────────────────────────
+    Explanation {
+        code: "E0001",
+        title: "mismatched types",
+        description: "
+An expression has a different type than its context requires: a value does
+not match its annotation, a function argument does not match the parameter,
+or the branches of an `if` or `case` produce different types.
+
+Mesh never converts between types implicitly. Convert the value explicitly,
+or change the annotation.",
+        dependency: None,
+        example: "let count :: Int = \"three\"",
+        fix: "let count :: Int = 3",
+    },
+    Explanation {
+        code: "E0002",
+        title: "infinite type",
+        description: "
+Inference found a type that would have to contain itself, such as a value
+`x` that is also called as a function taking `x`. No finite type satisfies
+such a constraint.
+
+This usually means a value is used in two incompatible roles; give it
+separate names or restructure the expression.",
+        dependency: None,
+        example: "let apply_self = fn (x) -> x(x) end",
+        fix: "let apply = fn (f, x) -> f(x) end",
+    },
+    Explanation {
+        code: "E0003",
+        title: "wrong number of arguments",
+        description: "
+A function is called with more or fewer arguments than it declares.
+Mesh has no optional or variadic parameters; multi-clause functions of
+different arities are separate functions.",
+        dependency: None,
+        example: "
+fn add(a :: Int, b :: Int) -> Int do
+  a + b
+end
+
+add(1)",
+        fix: "
+fn add(a :: Int, b :: Int) -> Int do
+  a + b
+end
+
+add(1, 2)",
+    },
+    Explanation {
+        code: "E0004",
+        title: "undefined variable",
+        description: "
+A name is used that is not bound in scope: it was never defined, is
+misspelled, is defined after its use in a block, or belongs to a module
+that was not imported.",
+        dependency: None,
+        example: "
+let total = 10
+totl + 1",
+        fix: "
+let total = 10
+total + 1",
+    },
+    Explanation {
+        code: "E0006",
+        title: "trait not implemented",
+        description: "
+A value is used where its type must implement an interface (a trait), but
+the type has no `impl` for it. This happens with operators (`+` requires
+`Add`, `<` requires `Ord`), with generic functions that have a `where`
+clause, and with derived traits.
+
+Implement or derive the interface for the type, or use a value of a type
+that implements it.",
+        dependency: None,
+        example: "
+struct Version do
+  major :: Int
+end deriving(Eq)
+
+Version { major: 1 } < Version { major: 2 }",
+        fix: "
+struct Version do
+  major :: Int
+end deriving(Eq, Ord)
+
+Version { major: 1 } < Version { major: 2 }",
+    },
+    Explanation {
+        code: "E0007",
+        title: "missing interface method",
+        description: "
+An `impl` block does not define every method its interface declares.
+Every method of an interface must be implemented for each type.",
+        dependency: None,
+        example: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+end",
+        fix: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"a number\"
+  end
+end",
+    },
+    Explanation {
+        code: "E0043",
+        title: "pipe position out of range",
+        description: "
+`value |N> f(args)` passes `value` as argument N of the call, counting the
+piped value itself: `x |2> f(a, b)` calls `f(a, x, b)`. N must be between 1
+and the number of explicit arguments plus one. `|>` is the same as `|1>`.",
+        dependency: None,
+        example: "
+fn pad(s :: String, width :: Int) -> String do
+  s
+end
+
+fn main() do
+  println(\"id\" |3> pad(8))
+end",
+        fix: "
+fn pad(s :: String, width :: Int) -> String do
+  s
+end
+
+fn main() do
+  println(8 |2> pad(\"id\"))
+end",
+    },
+    Explanation {
+        code: "E0044",
+        title: "invalid regex literal",
+        description: "
+A `~r/pattern/flags` literal is compiled when the program is type checked,
+so a pattern the regex engine rejects is a compile error instead of a
+runtime failure. Flags are letters after the closing slash: `i` (ignore
+case), `m` (multi-line anchors), `s` (`.` matches newline), `x` (ignore
+whitespace) and `U` (swap greedy and lazy). Write `/` inside the pattern as
+`\\/`. Patterns built at runtime go through `Regex.compile`, which returns
+a `Result` instead.",
+        dependency: None,
+        example: "
+fn main() do
+  let re = ~r/(\\d+-(\\d+)/
+  println(\"${Regex.is_match(re, \"10-20\")}\")
+end",
+        fix: "
+fn main() do
+  let re = ~r/(\\d+)-(\\d+)/
+  println(\"${Regex.is_match(re, \"10-20\")}\")
+end",
+    },
+    Explanation {
+        code: "E0045",
+        title: "tuple accessor without a known element type",
+        description: "
+`Tuple.first`, `Tuple.second`, `Tuple.nth` and `Tuple.to_list` return the
+tuple's own element types, so the compiler must know which element is meant
+once the tuple type is known. The index of `Tuple.nth` must be an integer
+literal within the tuple unless every element has the same type.
+`Tuple.to_list` needs every element to have the same type.",
+        dependency: None,
+        example: "
+fn main() do
+  let pair = (1, \"one\")
+  println(Tuple.nth(pair, 2))
+end",
+        fix: "
+fn main() do
+  let pair = (1, \"one\")
+  println(Tuple.nth(pair, 1))
+end",
+    },
+    Explanation {
+        code: "E0046",
+        title: "invalid binary segment",
+        description: "
+A `<<...>>` binary pattern or construction is laid out at compile time, so
+each segment must have a known shape. A segment is `value::type-order(size)`.
+The type is `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32` or `i64`
+(big-endian unless marked `-little`), `bytes`, or `rest`; a segment without a
+type is a `u8`. In a pattern, `bytes` needs a size: an integer literal or an
+integer segment bound earlier in the same pattern. `rest` takes every
+remaining byte, so it must come last. Integer segments take no size, and a
+literal must fit its segment type.",
+        dependency: None,
+        example: "
+fn main() do
+  case <<2, 104, 105>> do
+    <<n::u8, body::bytes>> -> println(\"${n}\")
+    _ -> println(\"short\")
+  end
+end",
+        fix: "
+fn main() do
+  case <<2, 104, 105>> do
+    <<n::u8, body::bytes(n)>> -> println(\"${n}\")
+    _ -> println(\"short\")
+  end
+end",
+    },
+    Explanation {
+        code: "E0047",
+        title: "invalid format spec",
+        description: "
+A `:` after the expression of a `${...}` interpolation starts a format spec,
+written `[[fill]align][+][0][width][.precision][type]`. The spec is checked
+against the type of the expression: `.precision` applies to a Float (digits
+after the point) or a String (maximum length), `+` and `0` padding to
+numbers, `x`, `X`, `o` and `b` to an Int and `e` to a Float. Alignment and
+width apply to any value. Convert the value first, e.g. with `Int.to_float`,
+when it has the wrong type. An atom after the start of the expression also
+begins with `:`, so parenthesize such expressions: `${(state == :ok)}`.",
+        dependency: None,
+        example: "
+fn main() do
+  let count = 3
+  println(\"${count:.2}\")
+end",
+        fix: "
+fn main() do
+  let count = 3
+  println(\"${Int.to_float(count):.2}\")
+end",
+    },
+    Explanation {
+        code: "E0008",
+        title: "interface method signature mismatch",
+        description: "
+A method in an `impl` block has a different signature than the method the
+interface declares. Parameter and return types must match the interface
+exactly, with `Self` replaced by the implementing type.",
+        dependency: None,
+        example: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> Int do
+    42
+  end
+end",
+        fix: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"42\"
+  end
+end",
+    },
+    Explanation {
+        code: "E0009",
+        title: "missing or unknown struct field",
+        description: "
+A struct literal leaves out a field the struct declares, sets a field it does
+not declare, or a field access names a field the struct does not have.
+
+Struct literals must set every field exactly once; there are no default
+field values.",
+        dependency: None,
+        example: "
+struct Point do
+  x :: Int
+  y :: Int
+end
+
+Point { x: 1 }",
+        fix: "
+struct Point do
+  x :: Int
+  y :: Int
+end
+
+Point { x: 1, y: 2 }",
+    },
+    Explanation {
+        code: "E0010",
+        title: "unknown variant",
+        description: "
+A pattern or constructor names a variant that the sum type does not
+declare. Check the spelling against the `type` definition.",
+        dependency: None,
+        example: "
+type Shape do
+  Circle(Float)
+  Square(Float)
+end
+
+fn area(s :: Shape) -> Float do
+  case s do
+    Circle(r) -> 3.14 * r * r
+    Triangle(a) -> a
+  end
+end",
+        fix: "
+type Shape do
+  Circle(Float)
+  Square(Float)
+end
+
+fn area(s :: Shape) -> Float do
+  case s do
+    Circle(r) -> 3.14 * r * r
+    Square(a) -> a * a
+  end
+end",
+    },
+    Explanation {
+        code: "E0011",
+        title: "or-pattern binds different variables",
+        description: "
+The alternatives of an or-pattern (`a | b`) must bind exactly the same
+variables, so the arm body can use them whichever alternative matched.",
+        dependency: None,
+        example: "
+type Pair do
+  Left(Int)
+  Right(Int)
+  Both(Int, Int)
+end
+
+fn first(p :: Pair) -> Int do
+  case p do
+    Left(n) | Both(n, m) -> n
+    Right(n) -> n
+  end
+end",
+        fix: "
+type Pair do
+  Left(Int)
+  Right(Int)
+  Both(Int, Int)
+end
+
+fn first(p :: Pair) -> Int do
+  case p do
+    Left(n) | Both(n, _) -> n
+    Right(n) -> n
+  end
+end",
+    },
+    Explanation {
+        code: "E0012",
+        title: "non-exhaustive match",
+        description: "
+A `case` does not cover every possible value of its scrutinee, so some
+input would have no matching arm. The diagnostic lists the missing
+patterns.
+
+Add arms for the missing patterns, or a final wildcard `_` arm.",
+        dependency: None,
+        example: "
+fn describe(x :: Option<Int>) -> String do
+  case x do
+    Some(_) -> \"some\"
+  end
+end",
+        fix: "
+fn describe(x :: Option<Int>) -> String do
+  case x do
+    Some(_) -> \"some\"
+    None -> \"none\"
+  end
+end",
+    },
+    Explanation {
+        code: "E0013",
+        title: "invalid guard expression",
+        description: "
+A `when` guard uses something other than comparisons, boolean operators,
+variables, literals and calls to named functions. Arithmetic and other
+expressions are not allowed in guards.
+
+Compute the value before the `case`, move it into a named function, or
+simplify the comparison.",
+        dependency: None,
+        example: "
+fn classify(n :: Int) -> String do
+  case n do
+    x when x * 2 > 100 -> \"big\"
+    _ -> \"small\"
+  end
+end",
+        fix: "
+fn classify(n :: Int) -> String do
+  case n do
+    x when x > 50 -> \"big\"
+    _ -> \"small\"
+  end
+end",
+    },
+    Explanation {
+        code: "E0014",
+        title: "message type mismatch",
+        description: "
+A message sent to a typed `Pid<M>` is not of type `M`. An actor's message
+type is inferred from its `receive` arms; every `send` must match it.",
+        dependency: None,
+        example: "
+actor counter(state :: Int) do
+  receive do
+    n -> counter(state + n)
+  end
+end
+
+let pid = spawn(counter, 0)
+send(pid, \"hello\")",
+        fix: "
+actor counter(state :: Int) do
+  receive do
+    n -> counter(state + n)
+  end
+end
+
+let pid = spawn(counter, 0)
+send(pid, 1)",
+    },
+    Explanation {
+        code: "E0015",
+        title: "`self()` outside an actor",
+        description: "
+`self()` returns the pid of the running actor, so it can only be used
+inside an `actor` body.",
+        dependency: None,
+        example: "let me = self()",
+        fix: "
+actor pinger(state :: Int) do
+  let me = self()
+  receive do
+    n -> pinger(state + n)
+  end
+end",
+    },
+    Explanation {
+        code: "E0016",
+        title: "`spawn` of a non-function",
+        description: "
+The first argument of `spawn` must be an actor or function to run in the
+new process.",
+        dependency: None,
+        example: "spawn(42)",
+        fix: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+spawn(worker)",
+    },
+    Explanation {
+        code: "E0017",
+        title: "`receive` outside an actor",
+        description: "
+`receive` waits for a message in the running actor's mailbox, so it can
+only be used inside an `actor` body.",
+        dependency: None,
+        example: "
+receive do
+  n -> n
+end",
+        fix: "
+actor echo() do
+  receive do
+    n -> echo()
+  end
+end",
+    },
+    Explanation {
+        code: "E0018",
+        title: "invalid child start function",
+        description: "
+The `start` function of a supervisor child must return the `Pid` of the
+process it starts, normally with `spawn`.",
+        dependency: None,
+        example: "
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child worker do
+    start: fn -> 42 end
+    restart: permanent
+    shutdown: 5000
+  end
+end",
+        fix: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: 5000
+  end
+end",
+    },
+    Explanation {
+        code: "E0019",
+        title: "invalid supervisor strategy",
+        description: "
+A supervisor's `strategy` must be one of `one_for_one`, `one_for_all`,
+`rest_for_one` or `simple_one_for_one`.",
+        dependency: None,
+        example: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: round_robin
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: 5000
+  end
+end",
+        fix: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: 5000
+  end
+end",
+    },
+    Explanation {
+        code: "E0020",
+        title: "invalid restart type",
+        description: "
+A supervisor child's `restart` must be `permanent` (always restart),
+`transient` (restart after abnormal exits) or `temporary` (never restart).",
+        dependency: None,
+        example: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: always
+    shutdown: 5000
+  end
+end",
+        fix: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: 5000
+  end
+end",
+    },
+    Explanation {
+        code: "E0021",
+        title: "invalid shutdown value",
+        description: "
+A supervisor child's `shutdown` must be a timeout in milliseconds or
+`brutal_kill`.",
+        dependency: None,
+        example: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: fast
+  end
+end",
+        fix: "
+actor worker() do
+  receive do
+    _ -> worker()
+  end
+end
+
+supervisor App do
+  strategy: one_for_one
+  max_restarts: 3
+  max_seconds: 5
+  child w1 do
+    start: fn -> spawn(worker) end
+    restart: permanent
+    shutdown: brutal_kill
+  end
+end",
+    },
+    Explanation {
+        code: "E0022",
+        title: "catch-all clause is not last",
+        description: "
+A clause whose parameters are all variables or `_` matches every call, so
+clauses after it could never run. Move the catch-all clause last.",
+        dependency: None,
+        example: "
+fn fib(n) = fib(n - 1) + fib(n - 2)
+fn fib(0) = 0
+fn fib(1) = 1",
+        fix: "
+fn fib(0) = 0
+fn fib(1) = 1
+fn fib(n) = fib(n - 1) + fib(n - 2)",
+    },
+    Explanation {
+        code: "E0023",
+        title: "non-consecutive function clauses",
+        description: "
+The clauses of a multi-clause function must be written one after another.
+A clause separated from the others by another item would start a second
+definition of the same function.",
+        dependency: None,
+        example: "
+fn fib(0) = 0
+fn double(x) = x * 2
+fn fib(n) = fib(n - 1) + fib(n - 2)",
+        fix: "
+fn fib(0) = 0
+fn fib(n) = fib(n - 1) + fib(n - 2)
+fn double(x) = x * 2",
+    },
+    Explanation {
+        code: "E0026",
+        title: "duplicate impl",
+        description: "
+The same interface is implemented twice for the same type. Each type can
+implement an interface at most once; merge the two `impl` blocks.",
+        dependency: None,
+        example: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"a number\"
+  end
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"an integer\"
+  end
+end",
+        fix: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"a number\"
+  end
+end",
+    },
+    Explanation {
+        code: "E0027",
+        title: "ambiguous method",
+        description: "
+A method call matches methods of the same name from more than one
+interface implemented by the receiver's type, so it is unclear which to
+call. Rename one of the methods.",
+        dependency: None,
+        example: "
+interface Describe do
+  fn label(self) -> String
+end
+
+interface Tag do
+  fn label(self) -> String
+end
+
+impl Describe for Int do
+  fn label(self) -> String do
+    \"number\"
+  end
+end
+
+impl Tag for Int do
+  fn label(self) -> String do
+    \"int\"
+  end
+end
+
+42.label()",
+        fix: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+interface Tag do
+  fn tag(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"number\"
+  end
+end
+
+impl Tag for Int do
+  fn tag(self) -> String do
+    \"int\"
+  end
+end
+
+42.tag()",
+    },
+    Explanation {
+        code: "E0028",
+        title: "unsupported derive",
+        description: "
+A `deriving` clause names a trait that cannot be derived. The derivable
+traits are `Eq`, `Ord`, `Display`, `Debug`, `Hash`, `Json`, `Row` and
+`Schema` (`Schema` on structs only). Implement other interfaces with an
+`impl` block.",
+        dependency: None,
+        example: "
+struct Point do
+  x :: Int
+  y :: Int
+end deriving(Clone)",
+        fix: "
+struct Point do
+  x :: Int
+  y :: Int
+end deriving(Eq)",
+    },
+    Explanation {
+        code: "E0029",
+        title: "missing derive prerequisite",
+        description: "
+A derived trait requires another trait that is not derived: `Ord`
+compares with `Eq` first, so deriving `Ord` also requires `Eq`.",
+        dependency: None,
+        example: "
+struct Version do
+  major :: Int
+  minor :: Int
+end deriving(Ord)",
+        fix: "
+struct Version do
+  major :: Int
+  minor :: Int
+end deriving(Eq, Ord)",
+    },
+    Explanation {
+        code: "E0030",
+        title: "no such method",
+        description: "
+A method or module function is called that the receiver's type or the
+module does not define. Check the spelling, and that the interface that
+provides the method is implemented for the type.",
+        dependency: None,
+        example: "String.reverse_words(\"hello world\")",
+        fix: "String.length(\"hello world\")",
+    },
+    Explanation {
+        code: "E0031",
+        title: "module not found",
+        description: "
+An `import` names a module that is neither part of the project nor of the
+standard library. Module names follow file paths: `src/math/vector.mpl`
+is `Math.Vector`.",
+        dependency: None,
+        example: "
+import Geometry
+
+Geometry.area(2)",
+        fix: "
+import Math
+
+Math.abs(-2)",
+    },
+    Explanation {
+        code: "E0032",
+        title: "`break` outside a loop",
+        description: "
+`break` exits the innermost `for` or `while` loop, so it can only be used
+inside one. Use `return` to leave a function early.",
+        dependency: None,
+        example: "
+fn first_negative(xs :: List<Int>) -> Int do
+  break
+end",
+        fix: "
+fn first_negative(xs :: List<Int>) -> Int do
+  for x in xs do
+    if x < 0 do
+      break
+    end
+  end
+  0
+end",
+    },
+    Explanation {
+        code: "E0033",
+        title: "`continue` outside a loop",
+        description: "
+`continue` skips to the next iteration of the innermost `for` or `while`
+loop, so it can only be used inside one.",
+        dependency: None,
+        example: "
+fn skip(x :: Int) -> Int do
+  continue
+end",
+        fix: "
+fn print_positive(xs :: List<Int>) do
+  for x in xs do
+    if x < 0 do
+      continue
+    end
+    println(\"${x}\")
+  end
+end",
+    },
+    Explanation {
+        code: "E0034",
+        title: "name not found in module",
+        description: "
+A `from Module import name` names something the module does not define.
+The diagnostic lists what the module does export.",
+        dependency: Some(("Geometry", "pub fn area(w :: Int, h :: Int) -> Int do\n  w * h\nend")),
+        example: "
+from Geometry import perimeter
+
+perimeter(2, 3)",
+        fix: "
+from Geometry import area
+
+area(2, 3)",
+    },
+    Explanation {
+        code: "E0035",
+        title: "private item",
+        description: "
+An import names a function or type that its module defines without `pub`.
+Only `pub` items can be used from other modules; mark the item `pub` if it
+is part of the module's interface.",
+        dependency: Some((
+            "Geometry",
+            "fn scale(x :: Int) -> Int do\n  x * 2\nend\n\npub fn area(w :: Int, h :: Int) -> Int do\n  scale(w) * h\nend",
+        )),
+        example: "
+from Geometry import scale
+
+scale(2)",
+        fix: "
+from Geometry import area
+
+area(2, 3)",
+    },
+    Explanation {
+        code: "E0036",
+        title: "`?` in a function with an incompatible return type",
+        description: "
+The `?` operator returns early with the error of a `Result` (or with `None`
+for an `Option`), so the enclosing function must return a `Result` with the
+same error type (or an `Option`).",
+        dependency: None,
+        example: "
+fn parse(s :: String) -> Int!String do
+  if s == \"\" do
+    Err(\"empty\")
+  else
+    Ok(1)
+  end
+end
+
+fn double(s :: String) -> Int do
+  let n = parse(s)?
+  n * 2
+end",
+        fix: "
+fn parse(s :: String) -> Int!String do
+  if s == \"\" do
+    Err(\"empty\")
+  else
+    Ok(1)
+  end
+end
+
+fn double(s :: String) -> Int!String do
+  let n = parse(s)?
+  Ok(n * 2)
+end",
+    },
+    Explanation {
+        code: "E0037",
+        title: "`?` on a value that is not a `Result` or `Option`",
+        description: "
+The `?` operator unwraps an `Ok` or `Some` and returns early otherwise, so
+it only applies to `Result` and `Option` values.",
+        dependency: None,
+        example: "
+fn double(n :: Int) -> Int!String do
+  let m = n?
+  Ok(m * 2)
+end",
+        fix: "
+fn double(n :: Int) -> Int!String do
+  Ok(n * 2)
+end",
+    },
+    Explanation {
+        code: "E0038",
+        title: "field cannot be serialized to JSON",
+        description: "
+A type deriving `Json` has a field whose type has no JSON representation,
+such as a function or a `Pid`. Every field must be a primitive, a
+`List`, `Map` or `Option` of serializable types, or a type that derives
+`Json` itself.",
+        dependency: None,
+        example: "
+struct Job do
+  name :: String
+  run :: Fun() -> Int
+end deriving(Json)",
+        fix: "
+struct Job do
+  name :: String
+  priority :: Int
+end deriving(Json)",
+    },
+    Explanation {
+        code: "E0039",
+        title: "field cannot be mapped from a database row",
+        description: "
+A struct deriving `Row` has a field whose type cannot be decoded from a
+database column. Row fields must be `Int`, `Float`, `Bool`, `String`, or an
+`Option` of one of them.",
+        dependency: None,
+        example: "
+struct User do
+  name :: String
+  tags :: List<String>
+end deriving(Row)",
+        fix: "
+struct User do
+  name :: String
+  tags :: String
+end deriving(Row)",
+    },
+    Explanation {
+        code: "E0040",
+        title: "missing associated type",
+        description: "
+An interface declares an associated type (`type Item`) that an `impl` of
+it does not define. Each impl must bind every associated type with
+`type Item = ...`.",
+        dependency: None,
+        example: "
+interface Source do
+  type Item
+  fn next(self) -> Int
+end
+
+impl Source for Int do
+  fn next(self) -> Int do
+    42
+  end
+end",
+        fix: "
+interface Source do
+  type Item
+  fn next(self) -> Int
+end
+
+impl Source for Int do
+  type Item = Int
+  fn next(self) -> Int do
+    42
+  end
+end",
+    },
+    Explanation {
+        code: "E0041",
+        title: "unknown associated type",
+        description: "
+An `impl` defines an associated type that its interface does not declare.
+Remove it, or declare it in the interface.",
+        dependency: None,
+        example: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  type Output = String
+  fn describe(self) -> String do
+    \"a number\"
+  end
+end",
+        fix: "
+interface Describe do
+  fn describe(self) -> String
+end
+
+impl Describe for Int do
+  fn describe(self) -> String do
+    \"a number\"
+  end
+end",
+    },
+    // ── Warnings ───────────────────────────────────────────────────────
+    Explanation {
+        code: "W0001",
+        title: "redundant match arm",
+        description: "
+A `case` arm can never match because the arms before it already cover
+every value it would match. Remove it, or move it before the arm that
+covers it.",
+        dependency: None,
+        example: "
+fn sign(n :: Int) -> String do
+  case n do
+    _ -> \"any\"
+    0 -> \"zero\"
+  end
+end",
+        fix: "
+fn sign(n :: Int) -> String do
+  case n do
+    0 -> \"zero\"
+    _ -> \"any\"
+  end
+end",
+    },
+    Explanation {
+        code: "W0002",
+        title: "annotation on a non-first clause",
+        description: "
+Only the first clause of a multi-clause function may carry its visibility,
+generic parameters, return type and `where` clause; they apply to the whole
+function. On later clauses they are ignored.",
+        dependency: None,
+        example: "
+fn fact(0) -> Int = 1
+fn fact(n) -> Int = n * fact(n - 1)",
+        fix: "
+fn fact(0) -> Int = 1
+fn fact(n) = n * fact(n - 1)",
+    },
+    Explanation {
+        code: "W0003",
+        title: "unused variable",
+        description: "
+A `let` binding is never read. Remove it, or prefix its name with `_` if
+it is kept on purpose. Lint `unused_variable`; configure it under `[lints]`
+in mesh.toml.",
+        dependency: None,
+        example: "
+fn main() do
+  let greeting = \"hello\"
+  println(\"hi\")
+end",
+        fix: "
+fn main() do
+  let greeting = \"hello\"
+  println(greeting)
+end",
+    },
+    Explanation {
+        code: "W0004",
+        title: "unused import",
+        description: "
+An imported module or name is never used. Remove it from the imports.
+Lint `unused_import`; configure it under `[lints]` in mesh.toml.",
+        dependency: None,
+        example: "
+from String import length, split
+
+fn main() do
+  println(\"${length(\"abc\")}\")
+end",
+        fix: "
+from String import length
+
+fn main() do
+  println(\"${length(\"abc\")}\")
+end",
+    },
+    Explanation {
+        code: "W0005",
+        title: "unused private function",
+        description: "
+A function without `pub` is never called in its module, and other modules
+cannot call it. Remove it, or make it `pub`. Functions named `main` or
+starting with `_` are exempt. Lint `dead_code`; configure it under
+`[lints]` in mesh.toml.",
+        dependency: None,
+        example: "
+fn helper() do
+  42
+end
+
+fn main() do
+  println(\"hi\")
+end",
+        fix: "
+fn helper() do
+  42
+end
+
+fn main() do
+  println(\"${helper()}\")
+end",
+    },
+    Explanation {
+        code: "W0006",
+        title: "unused struct field",
+        description: "
+A field of a private struct is set but never read. Structs that are `pub`
+or derive traits are exempt, since their fields are read elsewhere. Lint
+`unused_field`; configure it under `[lints]` in mesh.toml.",
+        dependency: None,
+        example: "
+struct Point do
+  x :: Int
+  y :: Int
+end
+
+fn main() do
+  let p = Point { x: 1, y: 2 }
+  println(\"${p.x}\")
+end",
+        fix: "
+struct Point do
+  x :: Int
+  y :: Int
+end
+
+fn main() do
+  let p = Point { x: 1, y: 2 }
+  println(\"${p.x}, ${p.y}\")
+end",
+    },
+    Explanation {
+        code: "W0007",
+        title: "shadowed binding",
+        description: "
+A `let` binding has the same name as another variable or parameter of the
+same function, hiding it for the rest of the scope. This is allowed, but
+easily confused with updating the variable. Lint `shadowing`, off by
+default; enable it with `shadowing = \"warn\"` under `[lints]` in mesh.toml.",
+        dependency: None,
+        example: "
+fn main() do
+  let total = 1
+  let total = total + 1
+  println(\"${total}\")
+end",
+        fix: "
+fn main() do
+  let base = 1
+  let total = base + 1
+  println(\"${total}\")
+end",
+    },
+    Explanation {
+        code: "W0008",
+        title: "unused `Result`",
+        description: "
+A `Result` is computed and then discarded, so an error would go unnoticed.
+Handle it with `case`, propagate it with `?`, or discard it explicitly
+with `let _ = ...`. Lint `unused_result`; configure it under `[lints]` in
+mesh.toml.",
+        dependency: None,
+        example: "
+fn save(s :: String) -> Int!String do
+  if s == \"\" do
+    Err(\"empty\")
+  else
+    Ok(1)
+  end
+end
+
+fn main() do
+  save(\"\")
+  println(\"saved\")
+end",
+        fix: "
+fn save(s :: String) -> Int!String do
+  if s == \"\" do
+    Err(\"empty\")
+  else
+    Ok(1)
+  end
+end
+
+fn main() do
+  let _ = save(\"\")
+  println(\"saved\")
+end",
+    },
+    Explanation {
+        code: "W0009",
+        title: "unreachable code",
+        description: "
+Statements follow a `return`, `break` or `continue` in the same block and
+can never run. Remove them. Lint `unreachable_code`; configure it under
+`[lints]` in mesh.toml.",
+        dependency: None,
+        example: "
+fn check(x :: Int) -> Int do
+  if x < 0 do
+    return 0
+    println(\"negative\")
+  end
+  x
+end",
+        fix: "
+fn check(x :: Int) -> Int do
+  if x < 0 do
+    println(\"negative\")
+    return 0
+  end
+  x
+end",
+    },
+    // ── Other ──────────────────────────────────────────────────────────
+    Explanation {
+        code: "P0001",
+        title: "parse error",
+        description: "
+The source is not syntactically valid Mesh. The label points at the first
+token the parser could not accept; type checking is skipped until every
+parse error is fixed.",
+        dependency: None,
+        example: "
+fn add(a, b) do
+  a +
+end",
+        fix: "
+fn add(a, b) do
+  a + b
+end",
+    },
+    Explanation {
+        code: "C0001",
+        title: "fatal compiler error",
+        description: "
+The compiler stopped for a reason not tied to a source location: the
+project has no `main.mpl`, a file could not be read or written, code
+generation failed, or the system linker reported an error. The message
+says which. With `--json`, these errors are the only diagnostics with
+code `C0001` and no spans.",
+        dependency: None,
+        example: "",
+        fix: "",
+    },
+];
@@ -12,17 +12,18 @@
 
 use rowan::TextRange;
 use mesh_parser::ast::expr::{
-    BinaryExpr, BreakExpr, CallExpr, CaseExpr, ClosureExpr, ContinueExpr, Expr, FieldAccess,
-    ForInExpr, IfExpr, LinkExpr, ListLiteral, Literal, MapLiteral, NameRef, PipeExpr, ReceiveExpr,
+    BinaryExpr, BreakExpr, BytesLiteral, CallExpr, CaseExpr, ClosureExpr, ContinueExpr, Expr, FieldAccess,
+    ForInExpr, IfExpr, LinkExpr, ListLiteral, Literal, MapLiteral, NameRef, PipeExpr, ReceiveExpr, RegexLiteral,
     ReturnExpr, SendExpr, SelfExpr, SpawnExpr, StructLiteral, StructUpdate, TryExpr, TupleExpr,
     UnaryExpr, WhileExpr,
 };
 use mesh_parser::ast::item::{
     ActorDef, Block, FnDef, InterfaceDef, ImplDef as AstImplDef, Item, LetBinding, ServiceDef,
-    StructDef, SumTypeDef, SupervisorDef, TypeAliasDef,
+    StructDef, SumTypeDef, SupervisorDef, TestDef, TypeAliasDef,
 };
-use mesh_parser::ast::pat::Pattern;
+use mesh_parser::ast::pat::{Pattern, SegmentType};
 use mesh_parser::ast::AstNode;
+use mesh_parser::cst::SyntaxToken;
 use mesh_parser::syntax_kind::SyntaxKind;
 use mesh_parser::Parse;
 
@@ -37,11 +38,13 @@ use crate::traits::{
     AssocTypeDef as TraitAssocTypeDef, ImplDef as TraitImplDef, ImplMethodSig, TraitDef,
     TraitMethodSig, TraitRegistry,
 };
-use crate::ty::{Scheme, Ty, TyCon, TyVar};
-use crate::unify::InferCtx;
+use crate::ty::{fixed_int_fits, Scheme, Ty, TyCon, TyVar, FIXED_INT_TYPES};
+use crate::unify::{InferCtx, PendingTupleAccess};
 use crate::{ImportContext, TypeckResult};
 
+use mesh_common::format_spec::{FormatSpec, FormatTarget};
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 
 /// Helper enum for tracking children in source order during multi-clause grouping.
 enum ChildKind {
@@ -54,7 +57,7 @@ enum ChildKind {
 // ── Struct & Type Registry (03-03) ────────────────────────────────────
 
 /// A registered struct definition with its fields and generic parameters.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StructDefInfo {
     /// The struct's name.
     pub name: String,
@@ -81,7 +84,7 @@ pub struct TypeAliasInfo {
 // ── Sum Type Registry (04-02) ──────────────────────────────────────────
 
 /// A registered sum type definition with its variants and generic parameters.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SumTypeDefInfo {
     /// The sum type's name (e.g. "Shape", "Option").
     pub name: String,
@@ -92,7 +95,7 @@ pub struct SumTypeDefInfo {
 }
 
 /// A single variant of a sum type.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct VariantInfo {
     /// The variant's name (e.g. "Circle", "Some").
     pub name: String,
@@ -101,7 +104,7 @@ pub struct VariantInfo {
 }
 
 /// A field in a variant -- either positional (unnamed) or named.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum VariantFieldInfo {
     /// Positional field (e.g. `Float` in `Circle(Float)`).
     Positional(Ty),
@@ -281,6 +284,47 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     string_mod.insert("collect".to_string(), Scheme::mono(
         Ty::fun(vec![Ty::Con(TyCon::new("Ptr"))], Ty::string())
     ));
+    // String.to_bytes(String) -> Bytes (the UTF-8 encoding)
+    string_mod.insert("to_bytes".to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], Ty::bytes())));
+    // String.from_utf8(Bytes) -> Result<String, String>
+    string_mod.insert(
+        "from_utf8".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bytes()], Ty::result(Ty::string(), Ty::string()))),
+    );
+    // Unicode text: lengths and positions count graphemes, byte_size counts bytes.
+    string_mod.insert("byte_size".to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], Ty::int())));
+    for name in ["graphemes", "chars"] {
+        string_mod.insert(name.to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], Ty::list(Ty::string()))));
+    }
+    string_mod.insert(
+        "codepoints".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string()], Ty::list(Ty::int()))),
+    );
+    string_mod.insert(
+        "at".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string(), Ty::int()], Ty::option(Ty::string()))),
+    );
+    string_mod.insert(
+        "index_of".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string(), Ty::string()], Ty::option(Ty::int()))),
+    );
+    for name in ["pad_left", "pad_right"] {
+        string_mod.insert(
+            name.to_string(),
+            Scheme::mono(Ty::fun(vec![Ty::string(), Ty::int(), Ty::string()], Ty::string())),
+        );
+    }
+    for name in ["reverse", "fold_case", "normalize_nfc", "normalize_nfd"] {
+        string_mod.insert(name.to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], Ty::string())));
+    }
+    // String.format(template, List<String>) -> Result<String, String>
+    string_mod.insert(
+        "format".to_string(),
+        Scheme::mono(Ty::fun(
+            vec![Ty::string(), Ty::list(Ty::string())],
+            Ty::result(Ty::string(), Ty::string()),
+        )),
+    );
     modules.insert("String".to_string(), string_mod);
 
     // ── IO module ──────────────────────────────────────────────────
@@ -293,6 +337,11 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
         "eprintln".to_string(),
         Scheme::mono(Ty::fun(vec![Ty::string()], Ty::Tuple(vec![]))),
     );
+    // IO.write(String) -> ()  (stdout, no newline; also takes IoData)
+    io_mod.insert(
+        "write".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string()], Ty::Tuple(vec![]))),
+    );
     modules.insert("IO".to_string(), io_mod);
 
     // ── Env module ─────────────────────────────────────────────────
@@ -301,6 +350,10 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
         "get".to_string(),
         Scheme::mono(Ty::fun(vec![Ty::string()], Ty::option(Ty::string()))),
     );
+    env_mod.insert(
+        "args".to_string(),
+        Scheme::mono(Ty::fun(vec![], Ty::list(Ty::string()))),
+    );
     modules.insert("Env".to_string(), env_mod);
 
     // ── File module ─────────────────────────────────────────────────
@@ -353,6 +406,7 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     list_mod.insert("new".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![], list_t.clone()) });
     list_mod.insert("length".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone()], Ty::int()) });
     list_mod.insert("append".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), t.clone()], list_t.clone()) });
+    list_mod.insert("prepend".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), t.clone()], list_t.clone()) });
     list_mod.insert("head".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone()], t.clone()) });
     list_mod.insert("tail".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone()], list_t.clone()) });
     list_mod.insert("get".to_string(), Scheme { vars: vec![t_var], ty: Ty::fun(vec![list_t.clone(), Ty::int()], t.clone()) });
@@ -408,28 +462,34 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     map_mod.insert("collect".to_string(), Scheme { vars: vec![k_var, v_var], ty: Ty::fun(vec![Ty::Con(TyCon::new("Ptr"))], map_kv.clone()) });
     modules.insert("Map".to_string(), map_mod);
 
-    let set_t = Ty::set_untyped();
+    // Set module -- polymorphic: Set<T>, elements compared with `==`.
+    let set_var = TyVar(93000);
+    let set_elem = Ty::Var(set_var);
+    let set_t = Ty::set(set_elem.clone());
     let mut set_mod = HashMap::new();
-    set_mod.insert("new".to_string(), Scheme::mono(Ty::fun(vec![], set_t.clone())));
-    set_mod.insert("add".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], set_t.clone())));
-    set_mod.insert("remove".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], set_t.clone())));
-    set_mod.insert("contains".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), Ty::int()], Ty::bool())));
-    set_mod.insert("size".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone()], Ty::int())));
-    set_mod.insert("union".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())));
-    set_mod.insert("intersection".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())));
+    set_mod.insert("new".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![], set_t.clone()) });
+    set_mod.insert("add".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_elem.clone()], set_t.clone()) });
+    set_mod.insert("remove".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_elem.clone()], set_t.clone()) });
+    set_mod.insert("contains".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_elem.clone()], Ty::bool()) });
+    set_mod.insert("size".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone()], Ty::int()) });
+    set_mod.insert("union".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
+    set_mod.insert("intersection".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
     // Phase 47: difference, to_list, from_list
-    set_mod.insert("difference".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone())));
-    set_mod.insert("to_list".to_string(), Scheme::mono(Ty::fun(vec![set_t.clone()], Ty::list(Ty::int()))));
-    set_mod.insert("from_list".to_string(), Scheme::mono(Ty::fun(vec![Ty::list(Ty::int())], set_t.clone())));
-    // Phase 79: Set.collect(iter) -> Set
-    set_mod.insert("collect".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Ptr"))], set_t.clone())));
+    set_mod.insert("difference".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone(), set_t.clone()], set_t.clone()) });
+    set_mod.insert("to_list".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![set_t.clone()], Ty::list(set_elem.clone())) });
+    set_mod.insert("from_list".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![Ty::list(set_elem.clone())], set_t.clone()) });
+    // Phase 79: Set.collect(iter) -> Set<T>
+    set_mod.insert("collect".to_string(), Scheme { vars: vec![set_var], ty: Ty::fun(vec![Ty::Con(TyCon::new("Ptr"))], set_t.clone()) });
     modules.insert("Set".to_string(), set_mod);
 
+    // Calls to first/second/nth/to_list get their real signature from the
+    // tuple argument (see tuple_accessor_sig); these schemes cover the rest.
     let mut tuple_mod = HashMap::new();
     tuple_mod.insert("nth".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple")), Ty::int()], Ty::int())));
     tuple_mod.insert("first".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::int())));
     tuple_mod.insert("second".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::int())));
     tuple_mod.insert("size".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::int())));
+    tuple_mod.insert("to_list".to_string(), Scheme::mono(Ty::fun(vec![Ty::Con(TyCon::new("Tuple"))], Ty::list(Ty::int()))));
     modules.insert("Tuple".to_string(), tuple_mod);
 
     let range_t = Ty::range();
@@ -441,14 +501,19 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     range_mod.insert("length".to_string(), Scheme::mono(Ty::fun(vec![range_t.clone()], Ty::int())));
     modules.insert("Range".to_string(), range_mod);
 
-    let queue_t = Ty::queue();
+    // Queue module -- polymorphic: Queue<T>.
+    let queue_var = TyVar(93100);
+    let queue_elem = Ty::Var(queue_var);
+    let queue_t = Ty::queue(queue_elem.clone());
     let mut queue_mod = HashMap::new();
-    queue_mod.insert("new".to_string(), Scheme::mono(Ty::fun(vec![], queue_t.clone())));
-    queue_mod.insert("push".to_string(), Scheme::mono(Ty::fun(vec![queue_t.clone(), Ty::int()], queue_t.clone())));
-    queue_mod.insert("pop".to_string(), Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::Con(TyCon::new("Tuple")))));
-    queue_mod.insert("peek".to_string(), Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::int())));
-    queue_mod.insert("size".to_string(), Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::int())));
-    queue_mod.insert("is_empty".to_string(), Scheme::mono(Ty::fun(vec![queue_t.clone()], Ty::bool())));
+    queue_mod.insert("new".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![], queue_t.clone()) });
+    queue_mod.insert("push".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone(), queue_elem.clone()], queue_t.clone()) });
+    queue_mod.insert("pop".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone()], Ty::Tuple(vec![queue_elem.clone(), queue_t.clone()])) });
+    queue_mod.insert("peek".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone()], queue_elem.clone()) });
+    queue_mod.insert("size".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone()], Ty::int()) });
+    queue_mod.insert("is_empty".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone()], Ty::bool()) });
+    queue_mod.insert("to_list".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![queue_t.clone()], Ty::list(queue_elem.clone())) });
+    queue_mod.insert("from_list".to_string(), Scheme { vars: vec![queue_var], ty: Ty::fun(vec![Ty::list(queue_elem.clone())], queue_t.clone()) });
     modules.insert("Queue".to_string(), queue_mod);
 
     // ── JSON module (Phase 8 Plan 04) ─────────────────────────────────
@@ -626,6 +691,21 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     // ── Int module (Phase 43 Plan 01) ──────────────────────────────────
     let mut int_mod = HashMap::new();
     int_mod.insert("to_float".to_string(), Scheme::mono(Ty::fun(vec![Ty::int()], Ty::float())));
+    // Arithmetic with an explicit overflow behavior, independent of whether
+    // the build checks `+`, `-`, `*`, `/` and `%` for overflow.
+    for op in ["add", "sub", "mul"] {
+        let binary_int = Ty::fun(vec![Ty::int(), Ty::int()], Ty::int());
+        int_mod.insert(format!("wrapping_{}", op), Scheme::mono(binary_int.clone()));
+        int_mod.insert(format!("saturating_{}", op), Scheme::mono(binary_int));
+        int_mod.insert(
+            format!("checked_{}", op),
+            Scheme::mono(Ty::fun(vec![Ty::int(), Ty::int()], Ty::option(Ty::int()))),
+        );
+    }
+    int_mod.insert(
+        "checked_div".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::int(), Ty::int()], Ty::option(Ty::int()))),
+    );
     modules.insert("Int".to_string(), int_mod);
 
     // ── Float module (Phase 43 Plan 01) ────────────────────────────────
@@ -635,6 +715,111 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
     float_mod.insert("from".to_string(), Scheme::mono(Ty::fun(vec![Ty::int()], Ty::float())));
     modules.insert("Float".to_string(), float_mod);
 
+    // ── Fixed-width integer modules ────────────────────────────────────
+    // Conversions to and from Int are explicit: from_int wraps (two's
+    // complement truncation), try_from_int returns None when out of range.
+    for name in FIXED_INT_TYPES {
+        let fixed = Ty::fixed_int(name);
+        let mut fixed_mod = HashMap::new();
+        fixed_mod.insert("from_int".to_string(), Scheme::mono(Ty::fun(vec![Ty::int()], fixed.clone())));
+        fixed_mod.insert(
+            "try_from_int".to_string(),
+            Scheme::mono(Ty::fun(vec![Ty::int()], Ty::option(fixed.clone()))),
+        );
+        fixed_mod.insert("to_int".to_string(), Scheme::mono(Ty::fun(vec![fixed.clone()], Ty::int())));
+        fixed_mod.insert("to_string".to_string(), Scheme::mono(Ty::fun(vec![fixed], Ty::string())));
+        modules.insert((*name).to_string(), fixed_mod);
+    }
+
+    // ── Bytes module ───────────────────────────────────────────────────
+    let byte = Ty::fixed_int("UInt8");
+    let mut bytes_mod = HashMap::new();
+    bytes_mod.insert("new".to_string(), Scheme::mono(Ty::fun(vec![], Ty::bytes())));
+    bytes_mod.insert(
+        "from_list".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::list(byte.clone())], Ty::bytes())),
+    );
+    bytes_mod.insert(
+        "to_list".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bytes()], Ty::list(byte.clone()))),
+    );
+    bytes_mod.insert("length".to_string(), Scheme::mono(Ty::fun(vec![Ty::bytes()], Ty::int())));
+    bytes_mod.insert("get".to_string(), Scheme::mono(Ty::fun(vec![Ty::bytes(), Ty::int()], byte)));
+    bytes_mod.insert(
+        "slice".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bytes(), Ty::int(), Ty::int()], Ty::bytes())),
+    );
+    bytes_mod.insert(
+        "concat".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bytes(), Ty::bytes()], Ty::bytes())),
+    );
+    bytes_mod.insert("to_string".to_string(), Scheme::mono(Ty::fun(vec![Ty::bytes()], Ty::string())));
+    modules.insert("Bytes".to_string(), bytes_mod);
+
+    // ── IoData module ──────────────────────────────────────────────────
+    // A tree of String and Bytes chunks, flattened only when written.
+    let iodata = Ty::iodata();
+    let mut iodata_mod = HashMap::new();
+    iodata_mod.insert("new".to_string(), Scheme::mono(Ty::fun(vec![], iodata.clone())));
+    iodata_mod.insert("from_string".to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], iodata.clone())));
+    iodata_mod.insert("from_bytes".to_string(), Scheme::mono(Ty::fun(vec![Ty::bytes()], iodata.clone())));
+    iodata_mod.insert(
+        "from_list".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::list(Ty::string())], iodata.clone())),
+    );
+    iodata_mod.insert(
+        "append".to_string(),
+        Scheme::mono(Ty::fun(vec![iodata.clone(), Ty::string()], iodata.clone())),
+    );
+    iodata_mod.insert(
+        "append_bytes".to_string(),
+        Scheme::mono(Ty::fun(vec![iodata.clone(), Ty::bytes()], iodata.clone())),
+    );
+    iodata_mod.insert(
+        "concat".to_string(),
+        Scheme::mono(Ty::fun(vec![iodata.clone(), iodata.clone()], iodata.clone())),
+    );
+    iodata_mod.insert("byte_size".to_string(), Scheme::mono(Ty::fun(vec![iodata.clone()], Ty::int())));
+    iodata_mod.insert("to_string".to_string(), Scheme::mono(Ty::fun(vec![iodata.clone()], Ty::string())));
+    iodata_mod.insert("to_bytes".to_string(), Scheme::mono(Ty::fun(vec![iodata], Ty::bytes())));
+    modules.insert("IoData".to_string(), iodata_mod);
+
+    // ── BigInt and Decimal modules ─────────────────────────────────────
+    // Arbitrary-precision numbers; arithmetic and comparison go through the
+    // operators, these cover construction and conversion.
+    let mut bigint_mod = HashMap::new();
+    bigint_mod.insert("from_int".to_string(), Scheme::mono(Ty::fun(vec![Ty::int()], Ty::bigint())));
+    bigint_mod.insert(
+        "parse".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string()], Ty::option(Ty::bigint()))),
+    );
+    bigint_mod.insert(
+        "to_int".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bigint()], Ty::option(Ty::int()))),
+    );
+    bigint_mod.insert("to_string".to_string(), Scheme::mono(Ty::fun(vec![Ty::bigint()], Ty::string())));
+    bigint_mod.insert("abs".to_string(), Scheme::mono(Ty::fun(vec![Ty::bigint()], Ty::bigint())));
+    bigint_mod.insert(
+        "pow".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::bigint(), Ty::int()], Ty::bigint())),
+    );
+    modules.insert("BigInt".to_string(), bigint_mod);
+
+    let mut decimal_mod = HashMap::new();
+    decimal_mod.insert("from_int".to_string(), Scheme::mono(Ty::fun(vec![Ty::int()], Ty::decimal())));
+    decimal_mod.insert(
+        "parse".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::string()], Ty::option(Ty::decimal()))),
+    );
+    decimal_mod.insert("to_float".to_string(), Scheme::mono(Ty::fun(vec![Ty::decimal()], Ty::float())));
+    decimal_mod.insert("to_string".to_string(), Scheme::mono(Ty::fun(vec![Ty::decimal()], Ty::string())));
+    decimal_mod.insert("scale".to_string(), Scheme::mono(Ty::fun(vec![Ty::decimal()], Ty::int())));
+    decimal_mod.insert(
+        "round".to_string(),
+        Scheme::mono(Ty::fun(vec![Ty::decimal(), Ty::int()], Ty::decimal())),
+    );
+    modules.insert("Decimal".to_string(), decimal_mod);
+
     // ── Timer module (Phase 44 Plan 02) ───────────────────────────────
     let timer_t_var = TyVar(u32::MAX - 20);  // Synthetic type var T for Timer
     let timer_t = Ty::Var(timer_t_var);
@@ -1016,6 +1201,139 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
             });
         }
 
+        // ── Extended adapters ───────────────────────────────────────
+        let ptr = || Ty::Con(TyCon::new("Ptr"));
+        // Iter.take_while / skip_while: fn(Ptr, fn(T) -> Bool) -> Ptr
+        for name in ["take_while", "skip_while"] {
+            let t = TyVar(91208);
+            iter_mod.insert(name.to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![ptr(), Ty::fun(vec![Ty::Var(t)], Ty::bool())], ptr()),
+            });
+        }
+        // Iter.flat_map: fn(Ptr, fn(T) -> Ptr) -> Ptr (fn returns an iterator)
+        {
+            let t = TyVar(91209);
+            iter_mod.insert("flat_map".to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![ptr(), Ty::fun(vec![Ty::Var(t)], ptr())], ptr()),
+            });
+        }
+        // Iter.chain: fn(Ptr, Ptr) -> Ptr
+        iter_mod.insert("chain".to_string(), Scheme::mono(
+            Ty::fun(vec![ptr(), ptr()], ptr())
+        ));
+        // Iter.chunks / windows / step_by: fn(Ptr, Int) -> Ptr
+        for name in ["chunks", "windows", "step_by"] {
+            iter_mod.insert(name.to_string(), Scheme::mono(
+                Ty::fun(vec![ptr(), Ty::int()], ptr())
+            ));
+        }
+        // Iter.dedup: fn(Ptr) -> Ptr
+        iter_mod.insert("dedup".to_string(), Scheme::mono(
+            Ty::fun(vec![ptr()], ptr())
+        ));
+        // Iter.dedup_by: fn(Ptr, fn(T, T) -> Bool) -> Ptr
+        {
+            let t = TyVar(91210);
+            iter_mod.insert("dedup_by".to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![ptr(), Ty::fun(vec![Ty::Var(t), Ty::Var(t)], Ty::bool())], ptr()),
+            });
+        }
+        // Iter.scan: fn(Ptr, A, fn(A, T) -> A) -> Ptr
+        {
+            let a = TyVar(91211);
+            let t = TyVar(91212);
+            iter_mod.insert("scan".to_string(), Scheme {
+                vars: vec![a, t],
+                ty: Ty::fun(vec![
+                    ptr(),
+                    Ty::Var(a),
+                    Ty::fun(vec![Ty::Var(a), Ty::Var(t)], Ty::Var(a)),
+                ], ptr()),
+            });
+        }
+        // Iter.inspect: fn(Ptr, fn(T) -> U) -> Ptr (result of fn is ignored)
+        {
+            let t = TyVar(91213);
+            let u = TyVar(91214);
+            iter_mod.insert("inspect".to_string(), Scheme {
+                vars: vec![t, u],
+                ty: Ty::fun(vec![ptr(), Ty::fun(vec![Ty::Var(t)], Ty::Var(u))], ptr()),
+            });
+        }
+
+        // ── Generators ──────────────────────────────────────────────
+        // Iter.repeat: fn(T) -> Ptr
+        {
+            let t = TyVar(91215);
+            iter_mod.insert("repeat".to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![Ty::Var(t)], ptr()),
+            });
+        }
+        // Iter.unfold: fn(S, fn(S) -> Option<(T, S)>) -> Ptr
+        {
+            let s = TyVar(91216);
+            let t = TyVar(91217);
+            iter_mod.insert("unfold".to_string(), Scheme {
+                vars: vec![s, t],
+                ty: Ty::fun(vec![
+                    Ty::Var(s),
+                    Ty::fun(vec![Ty::Var(s)], Ty::option(Ty::Tuple(vec![Ty::Var(t), Ty::Var(s)]))),
+                ], ptr()),
+            });
+        }
+        // Iter.iterate: fn(T, fn(T) -> T) -> Ptr
+        {
+            let t = TyVar(91218);
+            iter_mod.insert("iterate".to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![Ty::Var(t), Ty::fun(vec![Ty::Var(t)], Ty::Var(t))], ptr()),
+            });
+        }
+        // Iter.chars / Iter.graphemes: fn(String) -> Ptr (yields Strings)
+        for name in ["chars", "graphemes"] {
+            iter_mod.insert(name.to_string(), Scheme::mono(Ty::fun(vec![Ty::string()], ptr())));
+        }
+
+        // ── Extended terminals ──────────────────────────────────────
+        // Iter.min_by / max_by: fn(Ptr, fn(T, T) -> Int) -> Option<T>
+        for name in ["min_by", "max_by"] {
+            let t = TyVar(91219);
+            iter_mod.insert(name.to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![
+                    ptr(),
+                    Ty::fun(vec![Ty::Var(t), Ty::Var(t)], Ty::int()),
+                ], Ty::option(Ty::Var(t))),
+            });
+        }
+        // Iter.group_by: fn(Ptr, fn(T) -> K) -> Map<K, List<T>>
+        {
+            let t = TyVar(91220);
+            let k = TyVar(91221);
+            iter_mod.insert("group_by".to_string(), Scheme {
+                vars: vec![t, k],
+                ty: Ty::fun(vec![
+                    ptr(),
+                    Ty::fun(vec![Ty::Var(t)], Ty::Var(k)),
+                ], Ty::map(Ty::Var(k), Ty::list(Ty::Var(t)))),
+            });
+        }
+        // Iter.partition: fn(Ptr, fn(T) -> Bool) -> (List<T>, List<T>)
+        {
+            let t = TyVar(91222);
+            iter_mod.insert("partition".to_string(), Scheme {
+                vars: vec![t],
+                ty: Ty::fun(vec![
+                    ptr(),
+                    Ty::fun(vec![Ty::Var(t)], Ty::bool()),
+                ], Ty::Tuple(vec![Ty::list(Ty::Var(t)), Ty::list(Ty::Var(t))])),
+            });
+        }
+
         modules.insert("Iter".to_string(), iter_mod);
     }
 
@@ -1356,6 +1674,51 @@ fn stdlib_modules() -> HashMap<String, HashMap<String, Scheme>> {
         modules.insert("Migration".to_string(), migration_mod);
     }
 
+    // ── Regex module ────────────────────────────────────────────────────
+    {
+        let regex_t = Ty::Con(TyCon::new("Regex"));
+        let captures_t = Ty::list(Ty::string());
+        let mut regex_mod = HashMap::new();
+
+        // Regex.compile: fn(String) -> Result<Regex, String>
+        regex_mod.insert("compile".to_string(), Scheme::mono(Ty::fun(
+            vec![Ty::string()],
+            Ty::result(regex_t.clone(), Ty::string()),
+        )));
+        // Regex.is_match: fn(Regex, String) -> Bool
+        regex_mod.insert("is_match".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t.clone(), Ty::string()],
+            Ty::bool(),
+        )));
+        // Regex.captures: fn(Regex, String) -> Option<List<String>>
+        regex_mod.insert("captures".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t.clone(), Ty::string()],
+            Ty::option(captures_t.clone()),
+        )));
+        // Regex.named_captures: fn(Regex, String) -> Option<Map<String, String>>
+        regex_mod.insert("named_captures".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t.clone(), Ty::string()],
+            Ty::option(Ty::map(Ty::string(), Ty::string())),
+        )));
+        // Regex.scan: fn(Regex, String) -> List<List<String>>
+        regex_mod.insert("scan".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t.clone(), Ty::string()],
+            Ty::list(captures_t.clone()),
+        )));
+        // Regex.replace: fn(Regex, String, fn(List<String>) -> String) -> String
+        regex_mod.insert("replace".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t.clone(), Ty::string(), Ty::fun(vec![captures_t], Ty::string())],
+            Ty::string(),
+        )));
+        // Regex.split: fn(Regex, String) -> List<String>
+        regex_mod.insert("split".to_string(), Scheme::mono(Ty::fun(
+            vec![regex_t, Ty::string()],
+            Ty::list(Ty::string()),
+        )));
+
+        modules.insert("Regex".to_string(), regex_mod);
+    }
+
     modules
 }
 
@@ -1372,6 +1735,9 @@ const STDLIB_MODULE_NAMES: &[&str] = &[
     "Repo",  // Phase 98
     "Changeset",  // Phase 99
     "Migration",  // Phase 101
+    "Regex",
+    "Int8", "Int16", "Int32", "UInt8", "UInt16", "UInt32", "UInt64", "Bytes",
+    "BigInt", "Decimal", "IoData",
 ];
 
 /// Check if a name is a known stdlib module.
@@ -1564,12 +1930,16 @@ pub fn infer_with_imports(parse: &Parse, import_ctx: &ImportContext) -> TypeckRe
         }
     }
 
+    settle_tuple_accesses(&mut ctx, true);
+
     // Resolve all types in the type table through the union-find.
     let resolved_types: FxHashMap<TextRange, Ty> = types
         .into_iter()
         .map(|(range, ty)| (range, ctx.resolve(ty)))
         .collect();
 
+    check_hashed_keys(&resolved_types, &type_registry, &trait_registry, &mut ctx.errors);
+
     // Resolve the result type as well.
     let resolved_result = result_type.map(|ty| ctx.resolve(ty));
 
@@ -1596,6 +1966,101 @@ pub fn infer_with_imports(parse: &Parse, import_ctx: &ImportContext) -> TypeckRe
     }
 }
 
+/// Require `Hash` on every struct or sum type used as a map key or set
+/// element. Such keys are hashed through their `Hash` impl at runtime, so a
+/// type without one cannot be stored. Each offending type is reported once,
+/// at the first expression whose type mentions the map or set.
+fn check_hashed_keys(
+    types: &FxHashMap<TextRange, Ty>,
+    type_registry: &TypeRegistry,
+    trait_registry: &TraitRegistry,
+    errors: &mut Vec<TypeError>,
+) {
+    let mut ranges: Vec<&TextRange> = types.keys().collect();
+    ranges.sort_by_key(|r| (r.start(), r.len()));
+
+    let mut reported: Vec<Ty> = Vec::new();
+    for range in ranges {
+        let mut missing = Vec::new();
+        collect_unhashable_keys(&types[range], type_registry, trait_registry, &mut missing);
+        for ty in missing {
+            if reported.contains(&ty) {
+                continue;
+            }
+            reported.push(ty.clone());
+            errors.push(TypeError::TraitNotSatisfied {
+                ty,
+                trait_name: "Hash".to_string(),
+                origin: ConstraintOrigin::FnArg { call_site: *range, param_idx: 0 },
+            });
+        }
+    }
+}
+
+/// Collect the key types of the `Map<K, V>` and `Set<K>` types inside `ty`
+/// that are (or contain, for tuple keys) a struct or sum type with no `Hash`
+/// impl. Unresolved type variables are skipped.
+fn collect_unhashable_keys(
+    ty: &Ty,
+    type_registry: &TypeRegistry,
+    trait_registry: &TraitRegistry,
+    missing: &mut Vec<Ty>,
+) {
+    fn unhashable(
+        key: &Ty,
+        type_registry: &TypeRegistry,
+        trait_registry: &TraitRegistry,
+        missing: &mut Vec<Ty>,
+    ) {
+        let name = match key {
+            Ty::Tuple(elems) => {
+                for elem in elems {
+                    unhashable(elem, type_registry, trait_registry, missing);
+                }
+                return;
+            }
+            Ty::Con(con) => &con.name,
+            Ty::App(con, _) => match con.as_ref() {
+                Ty::Con(con) => &con.name,
+                _ => return,
+            },
+            _ => return,
+        };
+        let is_user_type = type_registry.struct_defs.contains_key(name)
+            || type_registry.sum_type_defs.contains_key(name);
+        if is_user_type && !trait_registry.has_impl("Hash", key) {
+            missing.push(key.clone());
+        }
+    }
+
+    match ty {
+        Ty::App(con, args) => {
+            if let Ty::Con(con) = con.as_ref() {
+                if matches!(con.name.as_str(), "Map" | "Set") {
+                    if let Some(key) = args.first() {
+                        unhashable(key, type_registry, trait_registry, missing);
+                    }
+                }
+            }
+            for arg in args {
+                collect_unhashable_keys(arg, type_registry, trait_registry, missing);
+            }
+        }
+        Ty::Fun(params, ret) => {
+            for param in params {
+                collect_unhashable_keys(param, type_registry, trait_registry, missing);
+            }
+            collect_unhashable_keys(ret, type_registry, trait_registry, missing);
+        }
+        Ty::Tuple(elems) => {
+            for elem in elems {
+                collect_unhashable_keys(elem, type_registry, trait_registry, missing);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Register Option<T> and Result<T, E> as proper sum types in the type registry.
 ///
 /// This replaces the old approach of registering constructors only in the type
@@ -2301,6 +2766,7 @@ fn infer_multi_clause_fn(
     ctx.unify(self_var, fn_ty.clone(), ConstraintOrigin::Builtin)?;
 
     ctx.leave_level();
+    settle_tuple_accesses(ctx, false);
     let scheme = ctx.generalize(fn_ty.clone());
     env.insert(fn_name, scheme);
 
@@ -2576,6 +3042,11 @@ fn infer_item(
         Item::SupervisorDef(sup_def) => {
             infer_supervisor_def(ctx, env, sup_def, types, type_registry, trait_registry, fn_constraints).ok()
         }
+        Item::TestDef(test_def) => {
+            // Errors are recorded in ctx.errors; a test block has no value.
+            let _ = infer_test_def(ctx, env, test_def, types, type_registry, trait_registry, fn_constraints);
+            None
+        }
     }
 }
 
@@ -2944,13 +3415,13 @@ fn register_struct_def(
 }
 
 /// Check if a type is JSON-serializable for deriving(Json) validation.
-/// Serializable types: Int, Float, Bool, String, structs with ToJson impl,
+/// Serializable types: Int, Float, Bool, String, BigInt, Decimal, structs with ToJson impl,
 /// Option<T> where T is serializable, List<T> where T is serializable,
 /// Map<String, V> where V is serializable.
 fn is_json_serializable(ty: &Ty, _type_registry: &TypeRegistry, trait_registry: &TraitRegistry) -> bool {
     match ty {
         Ty::Con(con) => match con.name.as_str() {
-            "Int" | "Float" | "Bool" | "String" => true,
+            "Int" | "Float" | "Bool" | "String" | "BigInt" | "Decimal" => true,
             name => {
                 // Generic type params (single uppercase letter like T, U, V, K, A, B)
                 // are treated as serializable at definition time. Invalid instantiations
@@ -2987,11 +3458,15 @@ fn is_json_serializable(ty: &Ty, _type_registry: &TypeRegistry, trait_registry:
 }
 
 /// Check if a type is row-mappable for deriving(Row) validation.
-/// Row-mappable: Int, Float, Bool, String, Option<T> where T is row-mappable primitive.
+/// Row-mappable: Int, Float, Bool, String, BigInt, Decimal, Option<T> where T is
+/// row-mappable primitive.
 /// NOT mappable: nested structs, sum types (except Option), List, Map, Ptr.
 fn is_row_mappable(ty: &Ty) -> bool {
     match ty {
-        Ty::Con(con) => matches!(con.name.as_str(), "Int" | "Float" | "Bool" | "String"),
+        Ty::Con(con) => matches!(
+            con.name.as_str(),
+            "Int" | "Float" | "Bool" | "String" | "BigInt" | "Decimal"
+        ),
         Ty::App(base, args) => {
             if let Ty::Con(con) = base.as_ref() {
                 if con.name == "Option" {
@@ -3779,7 +4254,8 @@ fn infer_let_binding(
             let origin = ConstraintOrigin::Annotation {
                 annotation_span: annotation.syntax().text_range(),
             };
-            ctx.unify(init_ty.clone(), ann_ty.clone(), origin)?;
+            let init_ty = adopt_int_literal(ctx, &init_expr, &ann_ty, types).unwrap_or(init_ty.clone());
+            ctx.unify(init_ty, ann_ty.clone(), origin)?;
             ann_ty
         } else {
             init_ty.clone()
@@ -3789,6 +4265,7 @@ fn infer_let_binding(
     };
 
     ctx.leave_level();
+    settle_tuple_accesses(ctx, false);
     let scheme = ctx.generalize(binding_ty);
 
     if let Some(name) = let_.name() {
@@ -3955,6 +4432,7 @@ fn infer_fn_def(
     ctx.unify(self_var, fn_ty.clone(), ConstraintOrigin::Builtin)?;
 
     ctx.leave_level();
+    settle_tuple_accesses(ctx, false);
     let scheme = ctx.generalize(fn_ty.clone());
 
     env.insert(fn_name, scheme);
@@ -3965,6 +4443,38 @@ fn infer_fn_def(
     Ok(resolved)
 }
 
+/// Infer a `test "name" do ... end` block.
+///
+/// The body is checked like the body of a zero-argument function so that
+/// bindings stay local to the test and `return` is allowed. The test itself
+/// binds no name in the environment.
+fn infer_test_def(
+    ctx: &mut InferCtx,
+    env: &mut TypeEnv,
+    test_def: &TestDef,
+    types: &mut FxHashMap<TextRange, Ty>,
+    type_registry: &TypeRegistry,
+    trait_registry: &TraitRegistry,
+    fn_constraints: &mut FxHashMap<String, FnConstraints>,
+) -> Result<Ty, TypeError> {
+    ctx.enter_level();
+    env.push_scope();
+
+    ctx.push_fn_return_type(None);
+    let body_result = match test_def.body() {
+        Some(body) => {
+            infer_block(ctx, env, &body, types, type_registry, trait_registry, fn_constraints)
+        }
+        None => Ok(Ty::Tuple(vec![])),
+    };
+    ctx.pop_fn_return_type();
+
+    env.pop_scope();
+    ctx.leave_level();
+
+    body_result
+}
+
 // ── Expression Inference ───────────────────────────────────────────────
 
 /// Infer the type of an expression.
@@ -4012,20 +4522,19 @@ fn infer_expr(
         }
         Expr::StringExpr(se) => {
             // Recurse into interpolation expressions so their types are recorded.
-            for child in se.syntax().children() {
-                if child.kind() == SyntaxKind::INTERPOLATION {
-                    for inner in child.children() {
-                        if let Some(inner_expr) = Expr::cast(inner) {
-                            let _ = infer_expr(
-                                ctx,
-                                env,
-                                &inner_expr,
-                                types,
-                                type_registry,
-                                trait_registry,
-                                fn_constraints,
-                            );
-                        }
+            for interp in se.interpolations() {
+                if let Some(inner_expr) = interp.expr() {
+                    let inner_ty = infer_expr(
+                        ctx,
+                        env,
+                        &inner_expr,
+                        types,
+                        type_registry,
+                        trait_registry,
+                        fn_constraints,
+                    );
+                    if let (Ok(ty), Some(spec)) = (inner_ty, interp.format_spec()) {
+                        check_format_spec(ctx, ty, &spec);
                     }
                 }
             }
@@ -4080,6 +4589,10 @@ fn infer_expr(
             // Atoms have a distinct type from String -- they are opaque typed values.
             Ty::Con(TyCon::new("Atom"))
         }
+        Expr::RegexLiteral(re) => infer_regex_literal(ctx, re),
+        Expr::BytesLiteral(lit) => {
+            infer_bytes_literal(ctx, env, lit, types, type_registry, trait_registry, fn_constraints)?
+        }
         Expr::StructUpdate(update) => {
             infer_struct_update(ctx, env, update, types, type_registry, trait_registry, fn_constraints)?
         }
@@ -4162,6 +4675,10 @@ fn infer_binary(
     let lhs_ty = infer_expr(ctx, env, &lhs_expr, types, type_registry, trait_registry, fn_constraints)?;
     let rhs_ty = infer_expr(ctx, env, &rhs_expr, types, type_registry, trait_registry, fn_constraints)?;
 
+    // An integer literal next to a fixed-width integer takes on its type.
+    let lhs_ty = adopt_int_literal(ctx, &lhs_expr, &rhs_ty, types).unwrap_or(lhs_ty);
+    let rhs_ty = adopt_int_literal(ctx, &rhs_expr, &lhs_ty, types).unwrap_or(rhs_ty);
+
     let op = bin.op();
     let op_kind = op.as_ref().map(|t| t.kind());
 
@@ -4425,6 +4942,22 @@ fn infer_call(
         }
     }
 
+    let index_arg = call.arg_list().and_then(|args| args.args().nth(1));
+    let callee_ty = match tuple_accessor_sig(ctx, &callee_expr, &arg_types, index_arg.as_ref(), call.syntax().text_range()) {
+        Some(sig) => sig?,
+        None => callee_ty,
+    };
+    let callee_ty = iodata_payload_sig(ctx, &callee_expr, callee_ty, &arg_types);
+
+    // Integer literal arguments take on a fixed-width parameter type.
+    if let (Ty::Fun(params, _), Some(arg_list)) = (ctx.resolve(callee_ty.clone()), call.arg_list()) {
+        for ((arg, param), arg_ty) in arg_list.args().zip(&params).zip(arg_types.iter_mut()) {
+            if let Some(ty) = adopt_int_literal_arg(ctx, &arg, param, types) {
+                *arg_ty = ty;
+            }
+        }
+    }
+
     let ret_var = ctx.fresh_var();
     let expected_fn_ty = Ty::Fun(arg_types.clone(), Box::new(ret_var.clone()));
 
@@ -4541,7 +5074,277 @@ fn infer_call(
     Ok(ret_var)
 }
 
-/// Infer the type of a pipe expression: `lhs |> rhs`
+/// The precise signature of a `Tuple.first/second/nth/to_list` call.
+///
+/// The `Tuple` module's schemes take the untyped `Tuple` and return `Int`,
+/// which is only right for tuples of Ints. When `callee` is one of these
+/// accessors, build its signature from the element types of the tuple in
+/// `args` instead; `index` is the expression passed as `nth`'s index and
+/// `span` the call's range. When the tuple's type is not known yet, the
+/// call returns a fresh element type that `settle_tuple_accesses` checks
+/// later. Returns None for any other callee, or when the argument is not a
+/// tuple at all (the module scheme then reports the mismatch).
+fn tuple_accessor_sig(
+    ctx: &mut InferCtx,
+    callee: &Expr,
+    args: &[Ty],
+    index: Option<&Expr>,
+    span: TextRange,
+) -> Option<Result<Ty, TypeError>> {
+    let Expr::FieldAccess(fa) = callee else {
+        return None;
+    };
+    let Some(Expr::NameRef(base)) = fa.base() else {
+        return None;
+    };
+    if base.text().as_deref() != Some("Tuple") || ctx.qualified_modules.contains_key("Tuple") {
+        return None;
+    }
+    let field = fa.field()?;
+    let name = field.text().to_string();
+    if !matches!(name.as_str(), "first" | "second" | "nth" | "to_list") {
+        return None;
+    }
+
+    let tuple_arg = args.first()?.clone();
+    let access = PendingTupleAccess {
+        function: name.clone(),
+        tuple: tuple_arg.clone(),
+        elem: Ty::int(),
+        index: index.and_then(int_literal_value),
+        span: field.text_range(),
+        index_span: index.map_or(field.text_range(), |e| e.syntax().text_range()),
+        call_span: span,
+    };
+    let elem = match ctx.resolve(tuple_arg.clone()) {
+        Ty::Tuple(elems) => match tuple_access_elem(ctx, &access, &elems) {
+            Ok(elem) => elem,
+            Err(err) => return Some(Err(err)),
+        },
+        Ty::Var(_) => {
+            // E.g. a lambda or unannotated parameter: check the access once
+            // the tuple's type is known.
+            let level = ctx.var_level(&tuple_arg).unwrap_or(ctx.current_level());
+            let elem = ctx.fresh_var_at(level);
+            ctx.pending_tuple_accesses.push(PendingTupleAccess { elem: elem.clone(), ..access });
+            elem
+        }
+        _ => return None,
+    };
+
+    let sig = match name.as_str() {
+        "nth" => Ty::fun(vec![tuple_arg, Ty::int()], elem),
+        "to_list" => Ty::fun(vec![tuple_arg], Ty::list(elem)),
+        _ => Ty::fun(vec![tuple_arg], elem),
+    };
+    Some(Ok(sig))
+}
+
+/// The element type a tuple accessor call returns for a tuple with element
+/// types `elems`; for `to_list`, the list's element type.
+fn tuple_access_elem(ctx: &mut InferCtx, access: &PendingTupleAccess, elems: &[Ty]) -> Result<Ty, TypeError> {
+    let name = access.function.as_str();
+    let tuple_ty = Ty::Tuple(elems.to_vec());
+    if elems.is_empty() {
+        return tuple_access_error(ctx, name, "`()` has no elements".to_string(), access.span);
+    }
+    match name {
+        "first" => Ok(elems[0].clone()),
+        "second" => match elems.get(1) {
+            Some(elem) => Ok(elem.clone()),
+            None => {
+                let reason = "a 1-element tuple has no second element".to_string();
+                tuple_access_error(ctx, name, reason, access.span)
+            }
+        },
+        "nth" => match access.index {
+            Some(i) if i >= 0 && (i as usize) < elems.len() => Ok(elems[i as usize].clone()),
+            Some(i) => {
+                let reason = format!("index {} is out of range for a {}-element tuple", i, elems.len());
+                tuple_access_error(ctx, name, reason, access.index_span)
+            }
+            // A computed index is fine as long as every element has the same type.
+            None => {
+                let errors_before = ctx.errors.len();
+                for elem in &elems[1..] {
+                    if ctx.unify(elems[0].clone(), elem.clone(), ConstraintOrigin::Builtin).is_err() {
+                        ctx.errors.truncate(errors_before);
+                        let reason = format!(
+                            "a computed index needs every element to have the same type, found `{}`",
+                            ctx.resolve(tuple_ty)
+                        );
+                        return tuple_access_error(ctx, name, reason, access.span);
+                    }
+                }
+                Ok(elems[0].clone())
+            }
+        },
+        _ => {
+            let origin = ConstraintOrigin::FnArg { call_site: access.call_span, param_idx: 0 };
+            for elem in &elems[1..] {
+                ctx.unify(elems[0].clone(), elem.clone(), origin.clone())?;
+            }
+            Ok(elems[0].clone())
+        }
+    }
+}
+
+/// Check the tuple accessor calls whose tuple type was unknown at the call.
+///
+/// Accesses whose tuple type is now known get their element type. Those
+/// still unknown are left pending, unless their tuple type variable would
+/// be generalized at the current level or `finish` is set: they then fall
+/// back to the `Tuple` module scheme (an untyped tuple of Ints), as they
+/// did before accessors were typed. Called before each binding is
+/// generalized and once at the end of inference.
+fn settle_tuple_accesses(ctx: &mut InferCtx, finish: bool) {
+    for access in std::mem::take(&mut ctx.pending_tuple_accesses) {
+        let origin = ConstraintOrigin::FnArg { call_site: access.call_span, param_idx: 0 };
+        match ctx.resolve(access.tuple.clone()) {
+            Ty::Tuple(elems) => {
+                if let Ok(elem) = tuple_access_elem(ctx, &access, &elems) {
+                    let _ = ctx.unify(access.elem.clone(), elem, origin);
+                }
+            }
+            Ty::Var(_)
+                if !finish && ctx.var_level(&access.tuple).is_some_and(|l| l <= ctx.current_level()) =>
+            {
+                ctx.pending_tuple_accesses.push(access);
+            }
+            _ => {
+                if ctx.unify(access.tuple.clone(), Ty::Con(TyCon::new("Tuple")), origin.clone()).is_ok() {
+                    let _ = ctx.unify(access.elem.clone(), Ty::int(), origin);
+                }
+            }
+        }
+    }
+}
+
+/// Output functions whose String payload also accepts an IoData, with the
+/// payload's argument position.
+const IODATA_PAYLOADS: &[(&str, &str, usize)] = &[
+    ("IO", "write", 0),
+    ("File", "write", 1),
+    ("File", "append", 1),
+    ("HTTP", "response", 1),
+    ("HTTP", "response_with_headers", 1),
+    ("Ws", "send", 1),
+];
+
+/// The signature of an output call given an IoData payload.
+///
+/// The module schemes of `IO.write`, `File.write/append`, `HTTP.response*`
+/// and `Ws.send` take a String payload. When `callee` is one of these and
+/// the payload in `args` is an IoData, return `callee_ty` with the payload
+/// parameter changed to IoData; otherwise return `callee_ty` unchanged.
+fn iodata_payload_sig(ctx: &mut InferCtx, callee: &Expr, callee_ty: Ty, args: &[Ty]) -> Ty {
+    let Expr::FieldAccess(fa) = callee else {
+        return callee_ty;
+    };
+    let (Some(Expr::NameRef(base)), Some(field)) = (fa.base(), fa.field()) else {
+        return callee_ty;
+    };
+    let Some(module) = base.text() else {
+        return callee_ty;
+    };
+    let Some(&(_, _, payload)) = IODATA_PAYLOADS
+        .iter()
+        .find(|(m, f, _)| *m == module && *f == field.text())
+    else {
+        return callee_ty;
+    };
+    if ctx.qualified_modules.contains_key(&module)
+        || args.get(payload).map(|ty| ctx.resolve(ty.clone())) != Some(Ty::iodata())
+    {
+        return callee_ty;
+    }
+    match ctx.resolve(callee_ty.clone()) {
+        Ty::Fun(mut params, ret) if payload < params.len() => {
+            params[payload] = Ty::iodata();
+            Ty::Fun(params, ret)
+        }
+        _ => callee_ty,
+    }
+}
+
+fn tuple_access_error(
+    ctx: &mut InferCtx,
+    name: &str,
+    reason: String,
+    span: TextRange,
+) -> Result<Ty, TypeError> {
+    let err = TypeError::TupleAccess {
+        function: format!("Tuple.{}", name),
+        reason,
+        span,
+    };
+    ctx.errors.push(err.clone());
+    Err(err)
+}
+
+/// The value of an integer literal expression, e.g. the `1` in `Tuple.nth(t, 1)`.
+fn int_literal_value(expr: &Expr) -> Option<i64> {
+    let Expr::Literal(lit) = expr else {
+        return None;
+    };
+    let token = lit.token()?;
+    if token.kind() != SyntaxKind::INT_LITERAL {
+        return None;
+    }
+    token.text().parse().ok()
+}
+
+/// Let an integer literal take on the fixed-width integer type it is used
+/// against, e.g. the `1` in `byte + 1` where `byte :: UInt8`. Returns the
+/// literal's new type, or `None` if `expr` is not a literal or does not fit.
+fn adopt_int_literal(
+    ctx: &mut InferCtx,
+    expr: &Expr,
+    other: &Ty,
+    types: &mut FxHashMap<TextRange, Ty>,
+) -> Option<Ty> {
+    let value = int_literal_value(expr)?;
+    let name = ctx.resolve(other.clone()).fixed_int_name()?;
+    if !fixed_int_fits(name, value) {
+        return None;
+    }
+    let ty = Ty::fixed_int(name);
+    types.insert(expr.syntax().text_range(), ty.clone());
+    Some(ty)
+}
+
+/// Like `adopt_int_literal` for a call argument: a literal passed for a
+/// fixed-width parameter, or a list of literals passed for a `List` of one
+/// (as in `Bytes.from_list([77, 83])`), takes on the parameter's type.
+fn adopt_int_literal_arg(
+    ctx: &mut InferCtx,
+    arg: &Expr,
+    param: &Ty,
+    types: &mut FxHashMap<TextRange, Ty>,
+) -> Option<Ty> {
+    if let Expr::ListLiteral(list) = arg {
+        let elem = match ctx.resolve(param.clone()) {
+            Ty::App(con, args) if *con == Ty::list_untyped() && args.len() == 1 => args[0].clone(),
+            _ => return None,
+        };
+        let name = elem.fixed_int_name()?;
+        let elems: Vec<Expr> = list.elements().collect();
+        if elems.is_empty()
+            || !elems.iter().all(|e| int_literal_value(e).is_some_and(|v| fixed_int_fits(name, v)))
+        {
+            return None;
+        }
+        for e in &elems {
+            adopt_int_literal(ctx, e, &elem, types);
+        }
+        let ty = Ty::list(elem);
+        types.insert(arg.syntax().text_range(), ty.clone());
+        return Some(ty);
+    }
+    adopt_int_literal(ctx, arg, param, types)
+}
+
+/// Infer the type of a pipe expression: `lhs |> rhs` or `lhs |N> rhs`
 fn infer_pipe(
     ctx: &mut InferCtx,
     env: &mut TypeEnv,
@@ -4572,11 +5375,31 @@ fn infer_pipe(
 
     let lhs_ty = infer_expr(ctx, env, &lhs, types, type_registry, trait_registry, fn_constraints)?;
 
+    // `|N>` passes the value as argument N of the call (1-based).
+    let arity = match &rhs {
+        Expr::CallExpr(call) => call.arg_list().map_or(0, |args| args.args().count()) + 1,
+        _ => 1,
+    };
+    let position = match pipe.position() {
+        Some(position) if (1..=arity).contains(&position) => position - 1,
+        _ => {
+            let op = pipe.op();
+            let err = TypeError::PipePositionOutOfRange {
+                op: op.as_ref().map_or_else(|| "|>".to_string(), |t| t.text().to_string()),
+                arity,
+                span: op.map_or_else(|| pipe.syntax().text_range(), |t| t.text_range()),
+            };
+            ctx.errors.push(err.clone());
+            return Err(err);
+        }
+    };
+
     let ret_var = ctx.fresh_var();
 
     match &rhs {
         Expr::CallExpr(call) => {
-            // Pipe-aware call inference: `x |> f(a, b)` desugars to `f(x, a, b)`.
+            // Pipe-aware call inference: `x |> f(a, b)` desugars to `f(x, a, b)`,
+            // and `x |2> f(a, b)` to `f(a, x, b)`.
             // We infer the callee and explicit args separately, then prepend lhs_ty
             // to construct the full expected function type -- matching MIR lowering.
             let callee_expr = call.callee().ok_or_else(|| {
@@ -4600,15 +5423,24 @@ fn infer_pipe(
                 }
             }
 
-            // Build full arg list: [lhs_ty, ...explicit_arg_types]
-            let mut full_args = vec![lhs_ty];
-            full_args.extend(arg_types.clone());
+            // Build full arg list: explicit_arg_types with lhs_ty inserted at the pipe position
+            let mut full_args = arg_types.clone();
+            full_args.insert(position, lhs_ty);
+
+            let mut arg_exprs: Vec<Option<Expr>> = call.arg_list().map_or_else(Vec::new, |args| args.args().map(Some).collect());
+            arg_exprs.insert(position, None);
+            let index_arg = arg_exprs.get(1).cloned().flatten();
+            let callee_ty = match tuple_accessor_sig(ctx, &callee_expr, &full_args, index_arg.as_ref(), call.syntax().text_range()) {
+                Some(sig) => sig?,
+                None => callee_ty,
+            };
+            let callee_ty = iodata_payload_sig(ctx, &callee_expr, callee_ty, &full_args);
 
             let expected_fn_ty = Ty::Fun(full_args.clone(), Box::new(ret_var.clone()));
 
             let origin = ConstraintOrigin::FnArg {
                 call_site: call.syntax().text_range(),
-                param_idx: 0,
+                param_idx: position,
             };
             ctx.unify(callee_ty, expected_fn_ty, origin.clone())?;
 
@@ -4624,7 +5456,7 @@ fn infer_pipe(
                             let mut resolved_type_args: FxHashMap<String, Ty> = FxHashMap::default();
 
                             // Build type param -> resolved type mapping from full arg list
-                            // (including the piped argument at position 0).
+                            // (including the piped argument).
                             for (i, tp_name_opt) in constraints.param_type_param_names.iter().enumerate() {
                                 if let Some(tp_name) = tp_name_opt {
                                     if i < full_args.len() {
@@ -4716,6 +5548,10 @@ fn infer_pipe(
         _ => {
             // Existing behavior: infer rhs as function, unify with Fun([lhs_ty], ret).
             let rhs_ty = infer_expr(ctx, env, &rhs, types, type_registry, trait_registry, fn_constraints)?;
+            let rhs_ty = match tuple_accessor_sig(ctx, &rhs, std::slice::from_ref(&lhs_ty), None, pipe.syntax().text_range()) {
+                Some(sig) => sig?,
+                None => rhs_ty,
+            };
             let expected_fn = Ty::Fun(vec![lhs_ty], Box::new(ret_var.clone()));
             ctx.unify(rhs_ty, expected_fn, ConstraintOrigin::Builtin)?;
         }
@@ -4920,7 +5756,7 @@ fn infer_for_in(
                     env.insert(var_name, Scheme::mono(key_ty));
                 }
             }
-            CollectionType::Set(elem_ty) => {
+            CollectionType::Set(elem_ty) | CollectionType::Queue(elem_ty) => {
                 let var_name = for_in
                     .binding_name()
                     .and_then(|n| n.text())
@@ -4999,10 +5835,11 @@ enum CollectionType {
     List(Ty),
     Map(Ty, Ty),
     Set(Ty),
+    Queue(Ty),
     Unknown,
 }
 
-/// Extract the element type from a collection type (List<T>, Map<K,V>, Set<T>).
+/// Extract the element type from a collection type (List<T>, Map<K,V>, Set<T>, Queue<T>).
 fn extract_collection_elem_type(ty: &Ty) -> CollectionType {
     match ty {
         Ty::App(con, args) => {
@@ -5011,6 +5848,7 @@ fn extract_collection_elem_type(ty: &Ty) -> CollectionType {
                     "List" if !args.is_empty() => CollectionType::List(args[0].clone()),
                     "Map" if args.len() >= 2 => CollectionType::Map(args[0].clone(), args[1].clone()),
                     "Set" if !args.is_empty() => CollectionType::Set(args[0].clone()),
+                    "Queue" if !args.is_empty() => CollectionType::Queue(args[0].clone()),
                     _ => CollectionType::Unknown,
                 }
             } else {
@@ -5021,6 +5859,7 @@ fn extract_collection_elem_type(ty: &Ty) -> CollectionType {
             "List" => CollectionType::List(Ty::int()),
             "Map" => CollectionType::Map(Ty::int(), Ty::int()),
             "Set" => CollectionType::Set(Ty::int()),
+            "Queue" => CollectionType::Queue(Ty::int()),
             _ => CollectionType::Unknown,
         },
         _ => CollectionType::Unknown,
@@ -5536,6 +6375,43 @@ fn ast_pattern_to_abstract(
             // Lists are infinite types so cons alone is never exhaustive anyway.
             AbsPat::Wildcard
         }
+        Pattern::Bytes(bytes_pat) => {
+            // `<<x::rest>>` matches any Bytes value.
+            let mut segments = bytes_pat.segments();
+            if let (Some(only), None) = (segments.next(), segments.next()) {
+                if only.segment_type() == Ok(SegmentType::Rest)
+                    && matches!(only.pattern(), Some(Pattern::Ident(_) | Pattern::Wildcard(_)))
+                {
+                    return AbsPat::Wildcard;
+                }
+            }
+            // Any other binary pattern can fail, so it is a literal of an
+            // infinite type, keyed by its text so that repeating the same
+            // pattern is still reported as redundant.
+            AbsPat::Literal {
+                value: bytes_pat.syntax().text().to_string(),
+                ty: AbsLitKind::Bytes,
+            }
+        }
+        Pattern::Map(map_pat) => AbsPat::Map {
+            entries: map_pat
+                .entries()
+                .filter_map(|entry| {
+                    let key = map_pat_key_text(&entry.key()?);
+                    let value = entry
+                        .value()
+                        .map(|v| ast_pattern_to_abstract(&v, env, type_registry))
+                        .unwrap_or(AbsPat::Wildcard);
+                    Some((key, value))
+                })
+                .collect(),
+        },
+        // A pinned value can fail to match, so it never makes a match
+        // exhaustive; pinning the same variable twice is still redundant.
+        Pattern::Pin(pin_pat) => AbsPat::Literal {
+            value: pin_pat.name().map(|t| t.text().to_string()).unwrap_or_default(),
+            ty: AbsLitKind::Pinned,
+        },
     }
 }
 
@@ -5656,11 +6532,170 @@ fn format_abstract_pat(pat: &AbsPat) -> String {
             let alts_str: Vec<String> = alternatives.iter().map(format_abstract_pat).collect();
             alts_str.join(" | ")
         }
+        AbsPat::Map { entries } => {
+            let entries_str: Vec<String> = entries
+                .iter()
+                .map(|(key, value)| format!("{} => {}", key, format_abstract_pat(value)))
+                .collect();
+            format!("%{{{}}}", entries_str.join(", "))
+        }
     }
 }
 
 // ── Guard Expression Validation (04-04) ────────────────────────────────
 
+/// Infer the type of a `~r/pattern/flags` literal.
+///
+/// The pattern is compiled here with the same engine and flags the runtime
+/// uses, so a malformed pattern is a compile error rather than a crash.
+/// The literal still has type `Regex` when invalid, to avoid cascading errors.
+fn infer_regex_literal(ctx: &mut InferCtx, re: &RegexLiteral) -> Ty {
+    let pattern = re.pattern().unwrap_or_default();
+    let flags = re.flags();
+    let mut builder = regex::RegexBuilder::new(&pattern);
+    let mut problem = None;
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            'U' => builder.swap_greed(true),
+            other => {
+                problem = Some(format!("unknown flag `{}`", other));
+                break;
+            }
+        };
+    }
+    if problem.is_none() {
+        if let Err(e) = builder.build() {
+            // Keep the engine's one-line reason, not its caret diagram.
+            let text = e.to_string();
+            let reason = text.lines().find_map(|l| l.strip_prefix("error: ")).unwrap_or(&text);
+            problem = Some(reason.to_string());
+        }
+    }
+    if let Some(message) = problem {
+        ctx.errors.push(TypeError::InvalidRegex {
+            pattern,
+            message,
+            span: re.syntax().text_range(),
+        });
+    }
+    Ty::Con(TyCon::new("Regex"))
+}
+
+/// Infer a binary construction: `<<tag::u8, len::u16-little, body::bytes>>`.
+///
+/// Integer segments take an `Int` or any fixed-width integer, and an
+/// integer literal must fit the segment. `bytes` and `rest` segments take
+/// `Bytes` or `String`; a `bytes` size, when given, is an integer.
+fn infer_bytes_literal(
+    ctx: &mut InferCtx,
+    env: &mut TypeEnv,
+    lit: &BytesLiteral,
+    types: &mut FxHashMap<TextRange, Ty>,
+    type_registry: &TypeRegistry,
+    trait_registry: &TraitRegistry,
+    fn_constraints: &FxHashMap<String, FnConstraints>,
+) -> Result<Ty, TypeError> {
+    for segment in lit.segments() {
+        let mut problem = None;
+        let value_ty = match segment.value() {
+            Some(value) => {
+                Some(infer_expr(ctx, env, &value, types, type_registry, trait_registry, fn_constraints)?)
+            }
+            None => None,
+        };
+        let size = segment.size();
+        match segment.segment_type() {
+            Err(message) => problem = Some(message),
+            Ok(SegmentType::Int { bits, signed, .. }) => {
+                let seg_ty = segment_int_type(bits, signed);
+                if size.is_some() {
+                    problem = Some("integer segments take no size".to_string());
+                }
+                if let Some(n) = segment.value().as_ref().and_then(int_literal_value) {
+                    if !segment_int_fits(&seg_ty, n) {
+                        let sign = if signed { "i" } else { "u" };
+                        problem = Some(format!("{} does not fit in a `{}{}` segment", n, sign, bits));
+                    }
+                } else if let Some(ty) = value_ty {
+                    if ctx.resolve(ty.clone()).fixed_int_name().is_none() {
+                        ctx.unify(ty, Ty::int(), ConstraintOrigin::Builtin)?;
+                    }
+                }
+            }
+            Ok(seg @ (SegmentType::Bytes | SegmentType::Rest)) => {
+                if let Some(ty) = value_ty {
+                    if ctx.resolve(ty.clone()) != Ty::string() {
+                        ctx.unify(ty, Ty::bytes(), ConstraintOrigin::Builtin)?;
+                    }
+                }
+                if let Some(size) = size {
+                    if seg == SegmentType::Rest {
+                        problem = Some("`rest` segments take no size".to_string());
+                    }
+                    let size_ty = infer_expr(ctx, env, &size, types, type_registry, trait_registry, fn_constraints)?;
+                    if ctx.resolve(size_ty.clone()).fixed_int_name().is_none() {
+                        ctx.unify(size_ty, Ty::int(), ConstraintOrigin::Builtin)?;
+                    }
+                }
+            }
+        }
+        if let Some(message) = problem {
+            ctx.errors.push(TypeError::InvalidBytesSegment {
+                message,
+                span: segment.syntax().text_range(),
+            });
+        }
+    }
+    Ok(Ty::bytes())
+}
+
+/// Check the format spec of a `${value:spec}` interpolation against the
+/// value's type. A value whose type is still unknown takes the type an
+/// Int-only or Float-only spec implies; other specs are left unchecked.
+fn check_format_spec(ctx: &mut InferCtx, ty: Ty, spec: &SyntaxToken) {
+    let text = &spec.text()[1..];
+    let parsed = match FormatSpec::parse(text) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            ctx.errors.push(TypeError::InvalidFormatSpec {
+                spec: text.to_string(),
+                message,
+                span: spec.text_range(),
+            });
+            return;
+        }
+    };
+    let resolved = ctx.resolve(ty.clone());
+    let target = match &resolved {
+        Ty::Var(_) => {
+            let implied = match parsed.kind {
+                Some('e') => Some(Ty::float()),
+                Some(_) => Some(Ty::int()),
+                None => None,
+            };
+            if let Some(implied) = implied {
+                let _ = ctx.unify(ty, implied, ConstraintOrigin::Builtin);
+            }
+            return;
+        }
+        t if *t == Ty::int() || t.fixed_int_name().is_some() => FormatTarget::Int,
+        t if *t == Ty::float() => FormatTarget::Float,
+        t if *t == Ty::string() => FormatTarget::String,
+        _ => FormatTarget::Other,
+    };
+    if let Err(message) = parsed.check(target, &resolved.to_string()) {
+        ctx.errors.push(TypeError::InvalidFormatSpec {
+            spec: text.to_string(),
+            message,
+            span: spec.text_range(),
+        });
+    }
+}
+
 /// Validate that a guard expression only uses allowed constructs:
 /// comparisons, boolean operators, literals, and name references.
 ///
@@ -5816,6 +6851,30 @@ fn infer_case(
     }
 
     // ── Exhaustiveness and redundancy checking ─────────────────────────
+    check_match_arms(
+        ctx,
+        &scrutinee_ty,
+        &arm_patterns,
+        &arm_has_guard,
+        &arm_spans,
+        case.syntax().text_range(),
+        type_registry,
+    );
+
+    Ok(result_ty.unwrap_or_else(|| Ty::Tuple(vec![])))
+}
+
+/// Report a non-exhaustive match and any unreachable arms of a `case` or
+/// `receive` whose arms match values of `scrutinee_ty`.
+fn check_match_arms(
+    ctx: &mut InferCtx,
+    scrutinee_ty: &Ty,
+    arm_patterns: &[AbsPat],
+    arm_has_guard: &[bool],
+    arm_spans: &[TextRange],
+    span: TextRange,
+    type_registry: &TypeRegistry,
+) {
     let resolved_scrutinee = ctx.resolve(scrutinee_ty.clone());
     let scrutinee_type_info = type_to_type_info(&resolved_scrutinee, type_registry);
     let abs_registry = build_abs_type_registry(type_registry);
@@ -5837,23 +6896,21 @@ fn infer_case(
         let err = TypeError::NonExhaustiveMatch {
             scrutinee_type: format!("{}", resolved_scrutinee),
             missing_patterns: missing,
-            span: case.syntax().text_range(),
+            span,
         };
         ctx.errors.push(err);
     }
 
     // For redundancy: check all arms (including guarded ones).
     let redundant_indices =
-        exhaustiveness::check_redundancy(&arm_patterns, &scrutinee_type_info, &abs_registry);
+        exhaustiveness::check_redundancy(arm_patterns, &scrutinee_type_info, &abs_registry);
     for idx in redundant_indices {
         let warn = TypeError::RedundantArm {
             arm_index: idx,
-            span: arm_spans.get(idx).copied().unwrap_or(case.syntax().text_range()),
+            span: arm_spans.get(idx).copied().unwrap_or(span),
         };
         ctx.warnings.push(warn);
     }
-
-    Ok(result_ty.unwrap_or_else(|| Ty::Tuple(vec![])))
 }
 
 /// Infer the type of a return expression.
@@ -6144,12 +7201,17 @@ fn infer_field_access(
             t if *t == Ty::string() => Some("String"),
             t if *t == Ty::range() => Some("Range"),
             t if *t == Ty::set_untyped() => Some("Set"),
+            t if *t == Ty::queue_untyped() => Some("Queue"),
+            t if *t == Ty::bytes() => Some("Bytes"),
+            t if t.fixed_int_name().is_some() => t.fixed_int_name(),
+            t if t.big_numeric_name().is_some() => t.big_numeric_name(),
             Ty::App(con, _) => {
                 if let Ty::Con(c) = con.as_ref() {
                     match c.name.as_str() {
                         "List" => Some("List"),
                         "Map" => Some("Map"),
                         "Set" => Some("Set"),
+                        "Queue" => Some("Queue"),
                         _ => None,
                     }
                 } else {
@@ -6604,6 +7666,34 @@ fn infer_pattern(
         Pattern::Cons(cons_pat) => {
             infer_cons_pattern(ctx, env, cons_pat, pat, types, type_registry)
         }
+        Pattern::Bytes(bytes_pat) => {
+            infer_bytes_pattern(ctx, env, bytes_pat, pat, types, type_registry)
+        }
+        Pattern::Map(map_pat) => {
+            infer_map_pattern(ctx, env, map_pat, pat, types, type_registry)
+        }
+        Pattern::Pin(pin_pat) => {
+            // A pinned variable matches the value it already holds and binds nothing.
+            let name = pin_pat
+                .name()
+                .map(|t| t.text().to_string())
+                .unwrap_or_else(|| "<unknown>".to_string());
+            match env.lookup(&name) {
+                Some(scheme) => {
+                    let ty = ctx.instantiate(scheme);
+                    types.insert(pat.syntax().text_range(), ty.clone());
+                    Ok(ty)
+                }
+                None => {
+                    let err = TypeError::UnboundVariable {
+                        name,
+                        span: pat.syntax().text_range(),
+                    };
+                    ctx.errors.push(err.clone());
+                    Err(err)
+                }
+            }
+        }
     }
 }
 
@@ -6826,6 +7916,21 @@ fn collect_binding_names_recursive(pat: &Pattern, names: &mut Vec<String>, env:
                 collect_binding_names_recursive(&tail, names, env);
             }
         }
+        Pattern::Bytes(bytes_pat) => {
+            for segment in bytes_pat.segments() {
+                if let Some(value) = segment.pattern() {
+                    collect_binding_names_recursive(&value, names, env);
+                }
+            }
+        }
+        Pattern::Map(map_pat) => {
+            for entry in map_pat.entries() {
+                if let Some(value) = entry.value() {
+                    collect_binding_names_recursive(&value, names, env);
+                }
+            }
+        }
+        Pattern::Pin(_) => {}
     }
 }
 
@@ -6891,6 +7996,195 @@ fn infer_cons_pattern(
     Ok(list_ty)
 }
 
+/// The type an integer segment binds: the fixed-width type of its width,
+/// or `Int` for `i64`.
+fn segment_int_type(bits: u32, signed: bool) -> Ty {
+    match (signed, bits) {
+        (true, 64) => Ty::int(),
+        (true, 8) => Ty::fixed_int("Int8"),
+        (true, 16) => Ty::fixed_int("Int16"),
+        (true, _) => Ty::fixed_int("Int32"),
+        (false, 8) => Ty::fixed_int("UInt8"),
+        (false, 16) => Ty::fixed_int("UInt16"),
+        (false, 32) => Ty::fixed_int("UInt32"),
+        (false, _) => Ty::fixed_int("UInt64"),
+    }
+}
+
+/// Whether the integer literal `value` can be stored in a segment of type `ty`.
+fn segment_int_fits(ty: &Ty, value: i64) -> bool {
+    ty.fixed_int_name().is_none_or(|name| fixed_int_fits(name, value))
+}
+
+/// The value of an integer literal pattern, including a leading `-`.
+fn literal_pat_int(lit: &mesh_parser::ast::pat::LiteralPat) -> Option<i64> {
+    let token = lit.token()?;
+    if token.kind() != SyntaxKind::INT_LITERAL {
+        return None;
+    }
+    let negative = lit
+        .syntax()
+        .children_with_tokens()
+        .any(|it| it.kind() == SyntaxKind::MINUS);
+    let text = if negative { format!("-{}", token.text()) } else { token.text().to_string() };
+    text.parse().ok()
+}
+
+/// The key of a map pattern entry as written in messages and compared by
+/// the exhaustiveness checker: `-1` or `"id"`.
+fn map_pat_key_text(lit: &mesh_parser::ast::pat::LiteralPat) -> String {
+    match literal_pat_int(lit) {
+        Some(n) => n.to_string(),
+        None => {
+            let content: String = lit
+                .syntax()
+                .children_with_tokens()
+                .filter(|t| t.kind() == SyntaxKind::STRING_CONTENT)
+                .map(|t| t.to_string())
+                .collect();
+            format!("\"{}\"", content)
+        }
+    }
+}
+
+/// Infer a map pattern: `%{"type" => "error", "id" => id}`.
+///
+/// The pattern matches a `Map<K, V>`: every key literal has type `K` and
+/// every value pattern type `V`.
+fn infer_map_pattern(
+    ctx: &mut InferCtx,
+    env: &mut TypeEnv,
+    map_pat: &mesh_parser::ast::pat::MapPat,
+    pat: &Pattern,
+    types: &mut FxHashMap<TextRange, Ty>,
+    type_registry: &TypeRegistry,
+) -> Result<Ty, TypeError> {
+    let key_ty = ctx.fresh_var();
+    let val_ty = ctx.fresh_var();
+    for entry in map_pat.entries() {
+        if let Some(key) = entry.key() {
+            let ty = infer_pattern(ctx, env, &Pattern::Literal(key), types, type_registry)?;
+            ctx.unify(key_ty.clone(), ty, ConstraintOrigin::Builtin)?;
+        }
+        if let Some(value) = entry.value() {
+            let ty = infer_pattern(ctx, env, &value, types, type_registry)?;
+            ctx.unify(val_ty.clone(), ty, ConstraintOrigin::Builtin)?;
+        }
+    }
+    let ty = Ty::map(key_ty, val_ty);
+    types.insert(pat.syntax().text_range(), ty.clone());
+    Ok(ty)
+}
+
+/// Infer a binary pattern: `<<len::u16, body::bytes(len), rest::rest>>`.
+///
+/// The pattern matches a `Bytes` value. Integer segments bind the
+/// fixed-width type of their width and `bytes`/`rest` segments bind
+/// `Bytes`. A `bytes` segment needs a size: an integer literal, or the
+/// name bound by an earlier integer segment of the same pattern. Segment
+/// problems are reported without failing the enclosing pattern.
+fn infer_bytes_pattern(
+    ctx: &mut InferCtx,
+    env: &mut TypeEnv,
+    bytes_pat: &mesh_parser::ast::pat::BytesPat,
+    pat: &Pattern,
+    types: &mut FxHashMap<TextRange, Ty>,
+    type_registry: &TypeRegistry,
+) -> Result<Ty, TypeError> {
+    let segments: Vec<_> = bytes_pat.segments().collect();
+    // Names bound by integer segments so far, which later sizes may use.
+    let mut int_names: Vec<String> = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        let mut problem = None;
+        let value = segment.pattern();
+        match segment.segment_type() {
+            Err(message) => problem = Some(message),
+            Ok(SegmentType::Int { bits, signed, .. }) => {
+                let seg_ty = segment_int_type(bits, signed);
+                if segment.size().is_some() {
+                    problem = Some("integer segments take no size".to_string());
+                }
+                match &value {
+                    Some(p @ Pattern::Ident(ident)) => {
+                        let ty = infer_pattern(ctx, env, p, types, type_registry)?;
+                        ctx.unify(ty, seg_ty, ConstraintOrigin::Builtin)?;
+                        if let Some(name) = ident.name() {
+                            int_names.push(name.text().to_string());
+                        }
+                    }
+                    Some(Pattern::Literal(lit)) => match literal_pat_int(lit) {
+                        Some(n) if segment_int_fits(&seg_ty, n) => {
+                            types.insert(lit.syntax().text_range(), seg_ty);
+                        }
+                        Some(n) => {
+                            let sign = if signed { "i" } else { "u" };
+                            problem = Some(format!("{} does not fit in a `{}{}` segment", n, sign, bits));
+                        }
+                        None => problem = Some("an integer segment can only match an integer literal".to_string()),
+                    },
+                    Some(Pattern::Wildcard(_)) | None => {}
+                    Some(_) => problem = Some("a segment matches a name, `_` or a literal".to_string()),
+                }
+            }
+            Ok(SegmentType::Bytes) => {
+                let is_string = matches!(
+                    &value,
+                    Some(Pattern::Literal(lit)) if lit.token().is_some_and(|t| t.kind() == SyntaxKind::STRING_START)
+                );
+                let size_ok = match segment.size() {
+                    Some(Expr::NameRef(name)) => name.text().is_some_and(|n| int_names.contains(&n)),
+                    Some(size) => int_literal_value(&size).is_some_and(|n| n >= 0),
+                    None => is_string,
+                };
+                if !size_ok && segment.size().is_some() {
+                    problem = Some(
+                        "a `bytes` size in a pattern must be an integer literal or a name bound by an earlier integer segment"
+                            .to_string(),
+                    );
+                } else if !size_ok {
+                    problem = Some("`bytes` needs a size in a pattern; use `rest` for the remaining bytes".to_string());
+                }
+                match &value {
+                    Some(p @ Pattern::Ident(_)) => {
+                        let ty = infer_pattern(ctx, env, p, types, type_registry)?;
+                        ctx.unify(ty, Ty::bytes(), ConstraintOrigin::Builtin)?;
+                    }
+                    Some(Pattern::Wildcard(_)) | None => {}
+                    Some(Pattern::Literal(lit)) if is_string => {
+                        types.insert(lit.syntax().text_range(), Ty::string());
+                    }
+                    Some(_) => problem = Some("a `bytes` segment matches a name, `_` or a string literal".to_string()),
+                }
+            }
+            Ok(SegmentType::Rest) => {
+                if i + 1 != segments.len() {
+                    problem = Some("`rest` must be the last segment".to_string());
+                } else if segment.size().is_some() {
+                    problem = Some("`rest` segments take no size".to_string());
+                }
+                match &value {
+                    Some(p @ Pattern::Ident(_)) => {
+                        let ty = infer_pattern(ctx, env, p, types, type_registry)?;
+                        ctx.unify(ty, Ty::bytes(), ConstraintOrigin::Builtin)?;
+                    }
+                    Some(Pattern::Wildcard(_)) | None => {}
+                    Some(_) => problem = Some("a `rest` segment matches a name or `_`".to_string()),
+                }
+            }
+        }
+        if let Some(message) = problem {
+            ctx.errors.push(TypeError::InvalidBytesSegment {
+                message,
+                span: segment.syntax().text_range(),
+            });
+        }
+    }
+
+    let ty = Ty::bytes();
+    types.insert(pat.syntax().text_range(), ty.clone());
+    Ok(ty)
+}
+
 
 // ── Actor Inference (06-04) ─────────────────────────────────────────────
 
@@ -6978,6 +8272,7 @@ fn infer_actor_def(
     ctx.unify(self_var, fn_ty.clone(), ConstraintOrigin::Builtin)?;
 
     ctx.leave_level();
+    settle_tuple_accesses(ctx, false);
     let scheme = ctx.generalize(fn_ty.clone());
     env.insert(actor_name, scheme);
 
@@ -7704,6 +8999,8 @@ fn infer_receive(
         .unwrap_or_else(|| ctx.fresh_var());
 
     let mut result_ty: Option<Ty> = None;
+    let mut arm_patterns: Vec<AbsPat> = Vec::new();
+    let mut arm_spans: Vec<TextRange> = Vec::new();
 
     for arm in recv.arms() {
         env.push_scope();
@@ -7712,7 +9009,11 @@ fn infer_receive(
             let pat_ty = infer_pattern(ctx, env, &pat, types, type_registry)?;
             // Unify pattern type with actor message type.
             ctx.unify(pat_ty, actor_msg_ty.clone(), ConstraintOrigin::Builtin)?;
+            arm_patterns.push(ast_pattern_to_abstract(&pat, env, type_registry));
+        } else {
+            arm_patterns.push(AbsPat::Wildcard);
         }
+        arm_spans.push(arm.syntax().text_range());
 
         if let Some(body) = arm.body() {
             let body_ty = infer_expr(ctx, env, &body, types, type_registry, trait_registry, fn_constraints)?;
@@ -7726,6 +9027,20 @@ fn infer_receive(
         env.pop_scope();
     }
 
+    // A message no arm matches is a runtime error, as in `case`.
+    if !arm_patterns.is_empty() {
+        let arm_has_guard = vec![false; arm_patterns.len()];
+        check_match_arms(
+            ctx,
+            &actor_msg_ty,
+            &arm_patterns,
+            &arm_has_guard,
+            &arm_spans,
+            recv.syntax().text_range(),
+            type_registry,
+        );
+    }
+
     // Handle after (timeout) clause.
     if let Some(after) = recv.after_clause() {
         if let Some(timeout_expr) = after.timeout() {
@@ -8226,10 +9541,11 @@ fn substitute_type_params(ty: &Ty, param_names: &[String], param_values: &[Ty])
 /// Convert a type name string to a Ty.
 fn name_to_type(name: &str) -> Ty {
     match name {
-        "Int" => Ty::int(),
+        "Int" | "Int64" => Ty::int(),
         "Float" => Ty::float(),
         "String" => Ty::string(),
         "Bool" => Ty::bool(),
+        "Byte" => Ty::fixed_int("UInt8"),
         other => Ty::Con(TyCon::new(other)),
     }
 }
@@ -18,19 +18,24 @@
 //! - [`builtins`]: Built-in type and operator registration
 //! - [`error`]: Type error types with provenance tracking
 //! - [`infer`]: Algorithm J inference engine
+//! - [`explain`]: Long-form explanations of diagnostic codes
+//! - [`lint`]: Configurable warnings for unused and unreachable code
 
 pub mod builtins;
 pub mod diagnostics;
 pub mod env;
 pub mod error;
 pub mod exhaustiveness;
+pub mod explain;
 pub mod infer;
+pub mod lint;
 pub mod traits;
 pub mod ty;
 pub mod unify;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
 
 use crate::diagnostics::DiagnosticOptions;
 use crate::error::TypeError;
@@ -102,7 +107,7 @@ pub struct ModuleExports {
 }
 
 /// Symbols exported by a module after type checking.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ExportedSymbols {
     /// Function type schemes (name -> scheme).
     pub functions: FxHashMap<String, Scheme>,
@@ -124,7 +129,7 @@ pub struct ExportedSymbols {
 
 /// Information about an exported service, containing the helper function
 /// signatures and method mappings needed by importing modules.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ServiceExportInfo {
     /// Service name (e.g., "Counter").
     pub name: String,
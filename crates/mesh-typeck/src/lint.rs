@@ -0,0 +1,689 @@
+//! Lint pass: warnings about valid but suspicious code.
+//!
+//! Runs on a module's syntax tree after type checking and reports each
+//! finding as a [`TypeError::Lint`], rendered by the same ariadne/JSON
+//! pipeline as type errors. Every lint has a stable name and code:
+//!
+//! | Code  | Name               | Reports                                          |
+//! |-------|--------------------|--------------------------------------------------|
+//! | W0003 | `unused_variable`  | `let` bindings that are never read               |
+//! | W0004 | `unused_import`    | imported modules and names that are never used   |
+//! | W0005 | `dead_code`        | private functions that are never called          |
+//! | W0006 | `unused_field`     | fields of private structs that are never read    |
+//! | W0007 | `shadowing`        | `let` bindings that shadow another binding       |
+//! | W0008 | `unused_result`    | `Result` values computed and then discarded      |
+//! | W0009 | `unreachable_code` | statements after `return`, `break` or `continue` |
+//!
+//! Each lint is `allow`, `warn` or `deny`, configured with a [`LintConfig`],
+//! normally from the `[lints]` table of `mesh.toml`. Every lint warns by
+//! default except `shadowing`, which is opt-in because rebinding a name
+//! (`let x = x + 1`) is idiomatic Mesh. Denied lints are reported as errors. Names starting with `_` are exempt from
+//! the binding lints.
+//!
+//! The analysis is syntactic and module-local: a name counts as used when
+//! any reference with that name could resolve to it, so lints err on the
+//! side of staying quiet.
+
+use rowan::TextRange;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use mesh_parser::cst::{SyntaxNode, SyntaxToken};
+use mesh_parser::syntax_kind::SyntaxKind;
+use mesh_parser::Parse;
+
+use crate::error::TypeError;
+use crate::ty::Ty;
+use crate::TypeckResult;
+
+/// A lint, identified in `mesh.toml` by its [`Lint::name`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariable,
+    UnusedImport,
+    DeadCode,
+    UnusedField,
+    Shadowing,
+    UnusedResult,
+    UnreachableCode,
+}
+
+impl Lint {
+    /// Every lint, in code order.
+    pub const ALL: [Lint; 7] = [
+        Lint::UnusedVariable,
+        Lint::UnusedImport,
+        Lint::DeadCode,
+        Lint::UnusedField,
+        Lint::Shadowing,
+        Lint::UnusedResult,
+        Lint::UnreachableCode,
+    ];
+
+    /// The lint's name, as written in `mesh.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused_variable",
+            Lint::UnusedImport => "unused_import",
+            Lint::DeadCode => "dead_code",
+            Lint::UnusedField => "unused_field",
+            Lint::Shadowing => "shadowing",
+            Lint::UnusedResult => "unused_result",
+            Lint::UnreachableCode => "unreachable_code",
+        }
+    }
+
+    /// The lint's diagnostic code.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "W0003",
+            Lint::UnusedImport => "W0004",
+            Lint::DeadCode => "W0005",
+            Lint::UnusedField => "W0006",
+            Lint::Shadowing => "W0007",
+            Lint::UnusedResult => "W0008",
+            Lint::UnreachableCode => "W0009",
+        }
+    }
+
+    /// The level a lint has unless configured otherwise.
+    pub fn default_level(self) -> LintLevel {
+        match self {
+            Lint::Shadowing => LintLevel::Allow,
+            _ => LintLevel::Warn,
+        }
+    }
+
+    /// Look up a lint by name.
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// How a lint is reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// Not reported.
+    Allow,
+    /// Reported as a warning.
+    Warn,
+    /// Reported as an error; compilation fails.
+    Deny,
+}
+
+impl LintLevel {
+    pub fn name(self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<LintLevel> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// The level of every lint, starting from each lint's [`Lint::default_level`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LintConfig {
+    levels: [LintLevel; Lint::ALL.len()],
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            levels: Lint::ALL.map(Lint::default_level),
+        }
+    }
+}
+
+impl LintConfig {
+    /// Build a configuration from `name = "level"` settings, e.g. the
+    /// `[lints]` table of `mesh.toml`. The name `all` sets every lint;
+    /// settings for individual lints take precedence over it.
+    pub fn from_settings<'a>(
+        settings: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<LintConfig, String> {
+        let mut config = LintConfig::default();
+        let mut specific = Vec::new();
+        for (name, level) in settings {
+            let level = LintLevel::from_name(level).ok_or_else(|| {
+                format!(
+                    "invalid level `{}` for lint `{}` (expected \"allow\", \"warn\" or \"deny\")",
+                    level, name
+                )
+            })?;
+            if name == "all" {
+                config.levels = [level; Lint::ALL.len()];
+            } else {
+                let lint = Lint::from_name(name).ok_or_else(|| format!("unknown lint `{}`", name))?;
+                specific.push((lint, level));
+            }
+        }
+        for (lint, level) in specific {
+            config.set(lint, level);
+        }
+        Ok(config)
+    }
+
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels[lint.index()]
+    }
+
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        self.levels[lint.index()] = level;
+    }
+}
+
+/// Run every lint that is not allowed on a type-checked module.
+///
+/// Findings are returned in source order.
+pub fn lint(parse: &Parse, typeck: &TypeckResult, config: &LintConfig) -> Vec<TypeError> {
+    let root = parse.syntax();
+    let mut linter = Linter {
+        config,
+        findings: Vec::new(),
+    };
+
+    Bindings::new(&mut linter).walk(&root);
+    linter.imports(&root);
+    linter.dead_code(&root);
+    linter.unused_fields(&root);
+    for block in root.descendants().filter(|n| n.kind() == SyntaxKind::BLOCK) {
+        linter.unused_results(&block, typeck);
+        linter.unreachable_code(&block);
+    }
+
+    linter.findings.sort_by_key(|f| f.0.start());
+    linter.findings.into_iter().map(|(_, err)| err).collect()
+}
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    findings: Vec<(TextRange, TypeError)>,
+}
+
+impl Linter<'_> {
+    fn report(&mut self, lint: Lint, span: TextRange, message: String, label: &str, help: String) {
+        let level = self.config.level(lint);
+        if level == LintLevel::Allow {
+            return;
+        }
+        self.findings.push((
+            span,
+            TypeError::Lint {
+                lint,
+                level,
+                message,
+                label: label.to_string(),
+                help,
+                span,
+            },
+        ));
+    }
+
+    /// `import Module` and `from Module import name` whose names are never used.
+    fn imports(&mut self, root: &SyntaxNode) {
+        let is_import = |n: &SyntaxNode| {
+            matches!(n.kind(), SyntaxKind::IMPORT_DECL | SyntaxKind::FROM_IMPORT_DECL)
+        };
+        let used: FxHashSet<String> = root
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+            .filter(|t| t.kind() == SyntaxKind::IDENT)
+            .filter(|t| !t.parent_ancestors().any(|n| is_import(&n)))
+            .map(|t| t.text().to_string())
+            .collect();
+
+        for decl in root.children().filter(is_import) {
+            if decl.kind() == SyntaxKind::IMPORT_DECL {
+                // `import Math.Vector` is used as `Vector.f()`.
+                let Some(namespace) = decl
+                    .descendants_with_tokens()
+                    .filter_map(|e| e.into_token())
+                    .filter(|t| t.kind() == SyntaxKind::IDENT)
+                    .last()
+                else {
+                    continue;
+                };
+                if !used.contains(namespace.text()) {
+                    self.report(
+                        Lint::UnusedImport,
+                        decl.text_range(),
+                        format!("unused import `{}`", namespace.text()),
+                        "never used",
+                        "remove the import".to_string(),
+                    );
+                }
+            } else {
+                let names = decl
+                    .descendants()
+                    .filter(|n| n.kind() == SyntaxKind::IMPORT_LIST)
+                    .flat_map(|list| list.children())
+                    .filter(|n| n.kind() == SyntaxKind::NAME);
+                for name in names {
+                    let Some(ident) = ident(&name) else { continue };
+                    if !used.contains(ident.text()) {
+                        self.report(
+                            Lint::UnusedImport,
+                            name.text_range(),
+                            format!("unused import `{}`", ident.text()),
+                            "never used",
+                            "remove it from the import list".to_string(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Private top-level functions that nothing but themselves refers to.
+    fn dead_code(&mut self, root: &SyntaxNode) {
+        let mut private: FxHashMap<String, TextRange> = FxHashMap::default();
+        for item in root.children().filter(|n| n.kind() == SyntaxKind::FN_DEF) {
+            let is_pub = item.children().any(|n| n.kind() == SyntaxKind::VISIBILITY);
+            let Some(name) = item_name(&item) else { continue };
+            if is_pub || name.text() == "main" || name.text().starts_with('_') {
+                continue;
+            }
+            // Multi-clause functions are reported once, at the first clause.
+            private
+                .entry(name.text().to_string())
+                .or_insert_with(|| name.text_range());
+        }
+
+        for name_ref in root.descendants().filter(|n| n.kind() == SyntaxKind::NAME_REF) {
+            let Some(ident) = ident(&name_ref) else { continue };
+            if !private.contains_key(ident.text()) {
+                continue;
+            }
+            let in_itself = name_ref
+                .ancestors()
+                .find(|n| n.parent().is_some_and(|p| p.kind() == SyntaxKind::SOURCE_FILE))
+                .filter(|item| item.kind() == SyntaxKind::FN_DEF)
+                .and_then(|item| item_name(&item))
+                .is_some_and(|name| name.text() == ident.text());
+            if !in_itself {
+                private.remove(ident.text());
+            }
+        }
+
+        for (name, span) in private {
+            self.report(
+                Lint::DeadCode,
+                span,
+                format!("function `{}` is never used", name),
+                "never called",
+                "remove it, or make it `pub` if other modules need it".to_string(),
+            );
+        }
+    }
+
+    /// Fields of private structs that are never read by a field access or
+    /// a struct pattern. Structs with a `deriving` clause or schema options
+    /// read their fields implicitly and are skipped.
+    fn unused_fields(&mut self, root: &SyntaxNode) {
+        let mut read: FxHashSet<String> = FxHashSet::default();
+        for node in root.descendants() {
+            match node.kind() {
+                SyntaxKind::FIELD_ACCESS => {
+                    if let Some(field) = node
+                        .children_with_tokens()
+                        .filter_map(|e| e.into_token())
+                        .filter(|t| t.kind() == SyntaxKind::IDENT)
+                        .last()
+                    {
+                        read.insert(field.text().to_string());
+                    }
+                }
+                SyntaxKind::STRUCT_PAT => {
+                    read.extend(
+                        node.descendants_with_tokens()
+                            .filter_map(|e| e.into_token())
+                            .filter(|t| t.kind() == SyntaxKind::IDENT)
+                            .map(|t| t.text().to_string()),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        for def in root.children().filter(|n| n.kind() == SyntaxKind::STRUCT_DEF) {
+            let implicit_reads = def.children().any(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::VISIBILITY
+                        | SyntaxKind::DERIVING_CLAUSE
+                        | SyntaxKind::SCHEMA_OPTION
+                        | SyntaxKind::RELATIONSHIP_DECL
+                )
+            });
+            let Some(struct_name) = item_name(&def) else { continue };
+            if implicit_reads {
+                continue;
+            }
+            for field in def.children().filter(|n| n.kind() == SyntaxKind::STRUCT_FIELD) {
+                let Some(name) = field
+                    .children()
+                    .find(|n| n.kind() == SyntaxKind::NAME)
+                    .and_then(|n| ident(&n))
+                else {
+                    continue;
+                };
+                if !read.contains(name.text()) && !name.text().starts_with('_') {
+                    self.report(
+                        Lint::UnusedField,
+                        field.text_range(),
+                        format!("field `{}` of `{}` is never read", name.text(), struct_name.text()),
+                        "never read",
+                        "remove the field".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Statements (every expression of a block but the last) of type `Result`.
+    fn unused_results(&mut self, block: &SyntaxNode, typeck: &TypeckResult) {
+        let stmts: Vec<SyntaxNode> = block.children().collect();
+        for stmt in stmts.iter().take(stmts.len().saturating_sub(1)) {
+            if matches!(
+                stmt.kind(),
+                SyntaxKind::LET_BINDING | SyntaxKind::FN_DEF | SyntaxKind::RETURN_EXPR
+            ) {
+                continue;
+            }
+            let is_result = match typeck.types.get(&stmt.text_range()) {
+                Some(Ty::App(con, _)) => matches!(con.as_ref(), Ty::Con(c) if c.name == "Result"),
+                _ => false,
+            };
+            if is_result {
+                self.report(
+                    Lint::UnusedResult,
+                    stmt.text_range(),
+                    "unused `Result` value".to_string(),
+                    "this `Result` is discarded",
+                    "handle the error, propagate it with `?`, or discard it with `let _ = ...`"
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    /// Statements after a `return`, `break` or `continue` in the same block.
+    fn unreachable_code(&mut self, block: &SyntaxNode) {
+        let stmts: Vec<SyntaxNode> = block.children().collect();
+        let Some(exit) = stmts.iter().position(|s| {
+            matches!(
+                s.kind(),
+                SyntaxKind::RETURN_EXPR | SyntaxKind::BREAK_EXPR | SyntaxKind::CONTINUE_EXPR
+            )
+        }) else {
+            return;
+        };
+        let (Some(first), Some(last)) = (stmts.get(exit + 1), stmts.last()) else {
+            return;
+        };
+        let keyword = match stmts[exit].kind() {
+            SyntaxKind::RETURN_EXPR => "return",
+            SyntaxKind::BREAK_EXPR => "break",
+            _ => "continue",
+        };
+        self.report(
+            Lint::UnreachableCode,
+            TextRange::new(first.text_range().start(), last.text_range().end()),
+            "unreachable code".to_string(),
+            &format!("never executed after `{}`", keyword),
+            "remove it".to_string(),
+        );
+    }
+}
+
+/// The IDENT token of a NAME, NAME_REF or PIN_PAT node.
+fn ident(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|e| e.into_token())
+        .find(|t| t.kind() == SyntaxKind::IDENT)
+}
+
+/// The name of an item (its first NAME child).
+fn item_name(item: &SyntaxNode) -> Option<SyntaxToken> {
+    item.children()
+        .find(|n| n.kind() == SyntaxKind::NAME)
+        .and_then(|n| ident(&n))
+}
+
+// ── Bindings: unused_variable and shadowing ────────────────────────────
+
+struct Binding {
+    name: String,
+    span: TextRange,
+    /// Introduced by `let` (as opposed to a parameter or pattern).
+    is_let: bool,
+    used: bool,
+}
+
+struct Scope {
+    bindings: Vec<Binding>,
+    /// Function bodies (including closures) end shadowing checks.
+    is_function: bool,
+}
+
+/// Scope-tracking walk over a module that resolves every name reference to
+/// the innermost binding of that name.
+struct Bindings<'l, 'a> {
+    linter: &'l mut Linter<'a>,
+    scopes: Vec<Scope>,
+}
+
+impl<'l, 'a> Bindings<'l, 'a> {
+    fn new(linter: &'l mut Linter<'a>) -> Self {
+        Bindings {
+            linter,
+            // Module-level `let`s are never reported.
+            scopes: vec![Scope { bindings: Vec::new(), is_function: true }],
+        }
+    }
+
+    fn walk(&mut self, node: &SyntaxNode) {
+        match node.kind() {
+            SyntaxKind::FN_DEF
+            | SyntaxKind::CLOSURE_EXPR
+            | SyntaxKind::CLOSURE_CLAUSE
+            | SyntaxKind::TRAILING_CLOSURE
+            | SyntaxKind::ACTOR_DEF
+            | SyntaxKind::CALL_HANDLER
+            | SyntaxKind::CAST_HANDLER
+            | SyntaxKind::TEST_DEF => {
+                self.push(true);
+                let mut names = 0;
+                for child in node.children() {
+                    match child.kind() {
+                        SyntaxKind::PARAM_LIST | SyntaxKind::PARAM => self.declare_pattern(&child, false),
+                        // The first NAME is the item's own name; the ones after
+                        // it are handler state parameters (`|state|`).
+                        SyntaxKind::NAME => {
+                            if names > 0 {
+                                self.declare_pattern(&child, false);
+                            }
+                            names += 1;
+                        }
+                        SyntaxKind::VISIBILITY | SyntaxKind::TYPE_ANNOTATION => {}
+                        _ => self.walk(&child),
+                    }
+                }
+                self.pop();
+            }
+            SyntaxKind::BLOCK => {
+                self.push(false);
+                self.walk_children(node);
+                self.pop();
+            }
+            SyntaxKind::MATCH_ARM | SyntaxKind::RECEIVE_ARM => {
+                self.push(false);
+                let mut children = node.children();
+                if let Some(pattern) = children.next() {
+                    self.declare_pattern(&pattern, false);
+                }
+                for child in children {
+                    self.walk(&child);
+                }
+                self.pop();
+            }
+            SyntaxKind::FOR_IN_EXPR => {
+                self.push(false);
+                for child in node.children() {
+                    match child.kind() {
+                        SyntaxKind::NAME | SyntaxKind::DESTRUCTURE_BINDING => {
+                            self.declare_pattern(&child, false)
+                        }
+                        _ => self.walk(&child),
+                    }
+                }
+                self.pop();
+            }
+            SyntaxKind::LET_BINDING => {
+                // The initializer sees the bindings from before the `let`.
+                let mut children = node.children();
+                let binder = children.next();
+                for child in children {
+                    self.walk(&child);
+                }
+                if let Some(binder) = binder {
+                    self.declare_pattern(&binder, true);
+                }
+            }
+            SyntaxKind::NAME_REF => {
+                if let Some(ident) = ident(node) {
+                    self.use_name(ident.text());
+                }
+            }
+            _ => self.walk_children(node),
+        }
+    }
+
+    fn walk_children(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            self.walk(&child);
+        }
+    }
+
+    /// Declare the names bound by a pattern, parameter list or NAME.
+    fn declare_pattern(&mut self, node: &SyntaxNode, is_let: bool) {
+        match node.kind() {
+            SyntaxKind::NAME | SyntaxKind::IDENT_PAT | SyntaxKind::PARAM => {
+                if let Some(ident) = ident(node) {
+                    let is_binding = ident
+                        .text()
+                        .chars()
+                        .next()
+                        .is_some_and(|c| c.is_lowercase() || c == '_');
+                    if is_binding {
+                        self.declare(&ident, is_let);
+                    }
+                }
+                for child in node.children() {
+                    self.declare_pattern(&child, is_let);
+                }
+            }
+            // Types and default values bind nothing.
+            SyntaxKind::TYPE_ANNOTATION => {}
+            SyntaxKind::PARAM_LIST
+            | SyntaxKind::DESTRUCTURE_BINDING
+            | SyntaxKind::TUPLE_PAT
+            | SyntaxKind::CONSTRUCTOR_PAT
+            | SyntaxKind::OR_PAT
+            | SyntaxKind::AS_PAT
+            | SyntaxKind::CONS_PAT
+            | SyntaxKind::STRUCT_PAT
+            | SyntaxKind::WILDCARD_PAT
+            | SyntaxKind::LITERAL_PAT
+            | SyntaxKind::MAP_PAT
+            | SyntaxKind::MAP_PAT_ENTRY => {
+                for child in node.children() {
+                    self.declare_pattern(&child, is_let);
+                }
+            }
+            // A pinned variable reads an existing binding.
+            SyntaxKind::PIN_PAT => {
+                if let Some(ident) = ident(node) {
+                    self.use_name(ident.text());
+                }
+            }
+            _ => self.walk(node),
+        }
+    }
+
+    fn declare(&mut self, ident: &SyntaxToken, is_let: bool) {
+        let name = ident.text().to_string();
+        let span = ident.text_range();
+        if is_let && !name.starts_with('_') {
+            let shadows = self
+                .function_scopes()
+                .iter()
+                .any(|scope| scope.bindings.iter().any(|b| b.name == name));
+            if shadows {
+                self.linter.report(
+                    Lint::Shadowing,
+                    span,
+                    format!("`{}` shadows an earlier binding", name),
+                    "shadows an earlier binding of the same name",
+                    "rename one of the bindings".to_string(),
+                );
+            }
+        }
+        let scope = self.scopes.last_mut().expect("scope stack is never empty");
+        scope.bindings.push(Binding { name, span, is_let, used: false });
+    }
+
+    /// Scopes from the innermost up to and including the innermost function.
+    fn function_scopes(&self) -> &[Scope] {
+        let start = self
+            .scopes
+            .iter()
+            .rposition(|s| s.is_function)
+            .unwrap_or(0);
+        &self.scopes[start..]
+    }
+
+    fn use_name(&mut self, name: &str) {
+        let binding = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|s| s.bindings.iter_mut().rev())
+            .find(|b| b.name == name);
+        if let Some(binding) = binding {
+            binding.used = true;
+        }
+    }
+
+    fn push(&mut self, is_function: bool) {
+        self.scopes.push(Scope { bindings: Vec::new(), is_function });
+    }
+
+    fn pop(&mut self) {
+        let scope = self.scopes.pop().expect("scope stack is never empty");
+        for binding in scope.bindings {
+            if binding.is_let && !binding.used && !binding.name.starts_with('_') {
+                self.linter.report(
+                    Lint::UnusedVariable,
+                    binding.span,
+                    format!("unused variable `{}`", binding.name),
+                    "never read",
+                    format!("remove it, or name it `_{}` to keep it", binding.name),
+                );
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@
 //! (Add, Sub, Mul, Div, Mod, Eq, Ord, Not).
 
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{ConstraintOrigin, TypeError};
 use crate::ty::Ty;
@@ -25,7 +26,7 @@ fn ty_contains_self(ty: &Ty) -> bool {
 }
 
 /// A method signature within a trait definition.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TraitMethodSig {
     /// Method name.
     pub name: String,
@@ -43,13 +44,13 @@ pub struct TraitMethodSig {
 }
 
 /// An associated type declaration in a trait.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AssocTypeDef {
     pub name: String,
 }
 
 /// A trait (interface) definition.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TraitDef {
     /// The trait name.
     pub name: String,
@@ -60,7 +61,7 @@ pub struct TraitDef {
 }
 
 /// An impl registration: which type implements which trait.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ImplDef {
     /// The trait being implemented.
     pub trait_name: String,
@@ -79,7 +80,7 @@ pub struct ImplDef {
 }
 
 /// A method signature in an impl block.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ImplMethodSig {
     /// Whether the method takes self.
     pub has_self: bool,
@@ -7,11 +7,13 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// A type variable, identified by a `u32` index into the unification table.
 ///
 /// Type variables are created during inference and unified with concrete types
 /// or other variables. The `ena` crate handles the union-find mechanics.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TyVar(pub u32);
 
 /// A type constructor -- a named type like `Int`, `String`, `Option`, etc.
@@ -22,7 +24,7 @@ pub struct TyVar(pub u32);
 /// The `display_prefix` field is used ONLY for display in error messages
 /// (e.g., "Geometry.Point"). It is intentionally excluded from `PartialEq`
 /// and `Hash` to preserve type identity semantics.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TyCon {
     pub name: String,
     /// Module origin for display in error messages (e.g., "Geometry").
@@ -73,7 +75,7 @@ impl fmt::Display for TyCon {
 /// - `App`: a type constructor application (Option<Int>, Result<T, E>)
 /// - `Tuple`: a tuple type (Int, String)
 /// - `Never`: the bottom type (never returns)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Ty {
     /// A type variable (unresolved during inference).
     Var(TyVar),
@@ -89,6 +91,27 @@ pub enum Ty {
     Never,
 }
 
+/// The fixed-width integer types.
+///
+/// All of them are stored as an `i64` at runtime, normalized into the type's
+/// range after every arithmetic operation. `Int64` and `Byte` are aliases for
+/// `Int` and `UInt8` and so do not appear here.
+pub const FIXED_INT_TYPES: &[&str] = &["Int8", "Int16", "Int32", "UInt8", "UInt16", "UInt32", "UInt64"];
+
+/// Whether `value` is representable in the fixed-width integer type `name`.
+pub fn fixed_int_fits(name: &str, value: i64) -> bool {
+    match name {
+        "Int8" => i8::try_from(value).is_ok(),
+        "Int16" => i16::try_from(value).is_ok(),
+        "Int32" => i32::try_from(value).is_ok(),
+        "UInt8" => u8::try_from(value).is_ok(),
+        "UInt16" => u16::try_from(value).is_ok(),
+        "UInt32" => u32::try_from(value).is_ok(),
+        "UInt64" => value >= 0,
+        _ => false,
+    }
+}
+
 impl Ty {
     /// Create an `Int` type.
     pub fn int() -> Ty {
@@ -110,6 +133,49 @@ impl Ty {
         Ty::Con(TyCon::new("Bool"))
     }
 
+    /// Create a fixed-width integer type such as `UInt8`.
+    pub fn fixed_int(name: &str) -> Ty {
+        debug_assert!(FIXED_INT_TYPES.contains(&name));
+        Ty::Con(TyCon::new(name))
+    }
+
+    /// Create a `Bytes` type.
+    pub fn bytes() -> Ty {
+        Ty::Con(TyCon::new("Bytes"))
+    }
+
+    /// Create an `IoData` type.
+    pub fn iodata() -> Ty {
+        Ty::Con(TyCon::new("IoData"))
+    }
+
+    /// Create a `BigInt` type.
+    pub fn bigint() -> Ty {
+        Ty::Con(TyCon::new("BigInt"))
+    }
+
+    /// Create a `Decimal` type.
+    pub fn decimal() -> Ty {
+        Ty::Con(TyCon::new("Decimal"))
+    }
+
+    /// The name of this type if it is `BigInt` or `Decimal`.
+    pub fn big_numeric_name(&self) -> Option<&'static str> {
+        match self {
+            Ty::Con(c) if c.name == "BigInt" => Some("BigInt"),
+            Ty::Con(c) if c.name == "Decimal" => Some("Decimal"),
+            _ => None,
+        }
+    }
+
+    /// The name of this type if it is one of the fixed-width integer types.
+    pub fn fixed_int_name(&self) -> Option<&'static str> {
+        match self {
+            Ty::Con(c) => FIXED_INT_TYPES.iter().copied().find(|n| *n == c.name),
+            _ => None,
+        }
+    }
+
     /// Create an `Option<T>` type.
     pub fn option(inner: Ty) -> Ty {
         Ty::App(Box::new(Ty::Con(TyCon::new("Option"))), vec![inner])
@@ -170,8 +236,13 @@ impl Ty {
         Ty::Con(TyCon::new("Range"))
     }
 
-    /// Create a `Queue` type.
-    pub fn queue() -> Ty {
+    /// Create a `Queue<T>` type.
+    pub fn queue(inner: Ty) -> Ty {
+        Ty::App(Box::new(Ty::Con(TyCon::new("Queue"))), vec![inner])
+    }
+
+    /// Create an unparameterized `Queue` type (opaque pointer).
+    pub fn queue_untyped() -> Ty {
         Ty::Con(TyCon::new("Queue"))
     }
 
@@ -235,7 +306,7 @@ impl fmt::Display for Ty {
 ///
 /// For example, the type of `identity` is `forall a. a -> a`, represented as
 /// `Scheme { vars: [a], ty: Fun([Var(a)], Var(a)) }`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Scheme {
     /// The quantified (generic) type variables.
     pub vars: Vec<TyVar>,
@@ -5,6 +5,7 @@
 //! and scheme instantiation.
 
 use ena::unify::InPlaceUnificationTable;
+use rowan::TextRange;
 use rustc_hash::FxHashMap;
 
 use crate::error::{ConstraintOrigin, TypeError};
@@ -51,6 +52,29 @@ pub struct InferCtx {
     /// Pushed when entering a function/closure body, popped when leaving.
     /// `None` means the return type is not yet known (will be inferred).
     pub fn_return_type_stack: Vec<Option<Ty>>,
+    /// Tuple accessor calls whose tuple argument had no known type yet.
+    /// Checked once it is known, or before it would be generalized.
+    pub pending_tuple_accesses: Vec<PendingTupleAccess>,
+}
+
+/// A `Tuple.first/second/nth/to_list` call made on a tuple of unknown type,
+/// such as a lambda or unannotated parameter.
+#[derive(Clone, Debug)]
+pub struct PendingTupleAccess {
+    /// The accessor's name: "first", "second", "nth" or "to_list".
+    pub function: String,
+    /// The type of the tuple argument.
+    pub tuple: Ty,
+    /// The element type the call returns (the list element for `to_list`).
+    pub elem: Ty,
+    /// `nth`'s index when it is an integer literal.
+    pub index: Option<i64>,
+    /// Range of the accessor's name.
+    pub span: TextRange,
+    /// Range of `nth`'s index argument.
+    pub index_span: TextRange,
+    /// Range of the whole call.
+    pub call_span: TextRange,
 }
 
 impl InferCtx {
@@ -69,6 +93,7 @@ impl InferCtx {
             local_service_exports: FxHashMap::default(),
             current_module: None,
             fn_return_type_stack: Vec::new(),
+            pending_tuple_accesses: Vec::new(),
         }
     }
 
@@ -119,15 +144,29 @@ impl InferCtx {
 
     /// Create a fresh type variable at the current level.
     pub fn fresh_var(&mut self) -> Ty {
+        self.fresh_var_at(self.current_level)
+    }
+
+    /// Create a fresh type variable at `level`, so that it is generalized
+    /// together with the variables of that level.
+    pub fn fresh_var_at(&mut self, level: u32) -> Ty {
         let var = self.table.new_key(None);
         // Ensure var_levels is large enough.
         while self.var_levels.len() <= var.0 as usize {
             self.var_levels.push(0);
         }
-        self.var_levels[var.0 as usize] = self.current_level;
+        self.var_levels[var.0 as usize] = level;
         Ty::Var(var)
     }
 
+    /// The level of `ty` if it resolves to an unbound type variable.
+    pub fn var_level(&mut self, ty: &Ty) -> Option<u32> {
+        match self.resolve(ty.clone()) {
+            Ty::Var(v) => Some(self.var_levels.get(v.0 as usize).copied().unwrap_or(0)),
+            _ => None,
+        }
+    }
+
     // ── Resolution ──────────────────────────────────────────────────────
 
     /// Resolve a type by following union-find indirection.
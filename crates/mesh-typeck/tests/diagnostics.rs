@@ -322,6 +322,119 @@ fn test_json_one_line() {
     assert!(!output.contains('\n'), "JSON output should be one line: {}", output);
 }
 
+// ── Numbered Pipe Diagnostics ──────────────────────────────────────
+
+/// `|N>` past the end of the call's arguments.
+#[test]
+fn test_diag_pipe_position_out_of_range() {
+    let src = "fn pad(s :: String, n :: Int) -> String do s end\n\"x\" |3> pad(2)";
+    let output = render_first_error(src);
+    insta::assert_snapshot!(output);
+
+    let result = check_source(src);
+    let json = render_diagnostic(&result.errors[0], src, "test.mpl", &DiagnosticOptions::json_mode(), None);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["code"], "E0043");
+    assert_eq!(parsed["spans"][0]["label"], "valid positions are 1 to 2");
+}
+
+// ── Regex Literal Diagnostics ──────────────────────────────────────
+
+/// A `~r` literal whose pattern the regex engine rejects.
+#[test]
+fn test_diag_invalid_regex() {
+    let src = "let re = ~r/(\\d+-(\\d+)/";
+    let output = render_first_error(src);
+    insta::assert_snapshot!(output);
+
+    let result = check_source(src);
+    let json = render_diagnostic(&result.errors[0], src, "test.mpl", &DiagnosticOptions::json_mode(), None);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["code"], "E0044");
+    assert_eq!(parsed["spans"][0]["label"], "unclosed group");
+}
+
+/// A format spec that does not suit the interpolated value's type.
+#[test]
+fn test_diag_invalid_format_spec() {
+    let src = "let count = 3\nprintln(\"${count:.2} items\")";
+    let output = render_first_error(src);
+    insta::assert_snapshot!(output);
+
+    let result = check_source(src);
+    let json = render_diagnostic(&result.errors[0], src, "test.mpl", &DiagnosticOptions::json_mode(), None);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["code"], "E0047");
+    assert_eq!(parsed["spans"][0]["label"], "precision `.2` applies to a Float or String, not Int");
+}
+
+// ── Tuple Accessor Diagnostics ─────────────────────────────────────
+
+/// `Tuple.nth` with a literal index past the end of the tuple.
+#[test]
+fn test_diag_tuple_index_out_of_range() {
+    let src = "let pair = (1, \"one\")\nTuple.nth(pair, 2)";
+    let output = render_first_error(src);
+    insta::assert_snapshot!(output);
+
+    let result = check_source(src);
+    let json = render_diagnostic(&result.errors[0], src, "test.mpl", &DiagnosticOptions::json_mode(), None);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["code"], "E0045");
+    assert_eq!(parsed["spans"][0]["label"], "index 2 is out of range for a 2-element tuple");
+}
+
+// ── Binary Segment Diagnostics ─────────────────────────────────────
+
+/// A `bytes` segment without a size in a binary pattern.
+#[test]
+fn test_diag_invalid_bytes_segment() {
+    let src = "let b = String.to_bytes(\"hi\")\ncase b do\n  <<n::u8, body::bytes>> -> body\n  _ -> String.to_bytes(\"\")\nend";
+    let output = render_first_error(src);
+    insta::assert_snapshot!(output);
+
+    let result = check_source(src);
+    let json = render_diagnostic(&result.errors[0], src, "test.mpl", &DiagnosticOptions::json_mode(), None);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["code"], "E0046");
+    assert_eq!(parsed["spans"][0]["label"], "`bytes` needs a size in a pattern; use `rest` for the remaining bytes");
+}
+
+// ── Lint Diagnostics ───────────────────────────────────────────────
+
+/// Lint the source at the given levels and render every finding.
+fn render_lints(src: &str, config: &mesh_typeck::lint::LintConfig, opts: &DiagnosticOptions) -> Vec<String> {
+    let parse = mesh_parser::parse(src);
+    let result = mesh_typeck::check(&parse);
+    mesh_typeck::lint::lint(&parse, &result, config)
+        .iter()
+        .map(|finding| render_diagnostic(finding, src, "test.mpl", opts, None))
+        .collect()
+}
+
+#[test]
+fn test_diag_lint_unused_variable() {
+    let src = "fn main() do\nlet unused = 1\nend";
+    let output = render_lints(src, &Default::default(), &opts());
+    assert_eq!(output.len(), 1);
+    insta::assert_snapshot!(output[0]);
+}
+
+#[test]
+fn test_diag_lint_denied_json() {
+    let src = "fn main() do\nlet unused = 1\nend";
+    let config = mesh_typeck::lint::LintConfig::from_settings([("unused_variable", "deny")]).unwrap();
+    let output = render_lints(src, &config, &DiagnosticOptions::json_mode());
+    assert_eq!(output.len(), 1);
+    let parsed: serde_json::Value = serde_json::from_str(&output[0])
+        .unwrap_or_else(|e| panic!("invalid JSON output: {}\n{}", e, output[0]));
+    assert_eq!(parsed["code"], "W0003");
+    assert_eq!(parsed["severity"], "error");
+    assert_eq!(parsed["message"], "unused variable `unused`");
+    assert_eq!(parsed["spans"][0]["label"], "never read");
+    assert_eq!(parsed["fix"], "remove it, or name it `_unused` to keep it");
+}
+
 #[test]
 fn test_not_a_function_fix_suggestion() {
     // Directly construct a NotAFunction error to test the fix suggestion.
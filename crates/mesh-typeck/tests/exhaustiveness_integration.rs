@@ -8,6 +8,7 @@
 //! - Guard expression validation (only comparisons, booleans, names, literals)
 //! - Bool exhaustiveness
 //! - Wildcard covers all
+//! - Map and pinned patterns in `case` and `receive`
 
 use mesh_typeck::error::TypeError;
 use mesh_typeck::TypeckResult;
@@ -268,3 +269,76 @@ fn test_or_pattern_covers_all_variants() {
     );
     assert_no_errors(&result);
 }
+
+// ── Map and pinned patterns ──────────────────────────────────────────
+
+/// Map patterns can fail, so they need a catch-all; `%{}` matches any map.
+#[test]
+fn test_map_patterns_exhaustiveness() {
+    let result = check_source(
+        "let m = %{\"type\" => \"ok\"}\n\
+         case m do\n  %{\"type\" => \"error\"} -> 1\n  %{\"type\" => _} -> 2\nend",
+    );
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::NonExhaustiveMatch { .. }),
+        "NonExhaustiveMatch",
+    );
+
+    let result = check_source(
+        "let m = %{\"type\" => \"ok\"}\n\
+         case m do\n  %{\"type\" => \"error\"} -> 1\n  %{\"type\" => _} -> 2\n  %{} -> 3\nend",
+    );
+    assert_no_errors(&result);
+    assert_no_warnings(&result);
+}
+
+/// A map arm whose keys and values are all covered by an earlier arm is redundant.
+#[test]
+fn test_redundant_map_arm() {
+    let result = check_source(
+        "let m = %{\"type\" => \"ok\"}\n\
+         case m do\n  %{\"type\" => _} -> 1\n  %{\"type\" => \"error\", \"id\" => _} -> 2\n  _ -> 3\nend",
+    );
+    assert_has_warning(
+        &result,
+        |e| matches!(e, TypeError::RedundantArm { arm_index, .. } if *arm_index == 1),
+        "RedundantArm (map arm at index 1)",
+    );
+}
+
+/// A pinned value never makes a match exhaustive, but repeating it is redundant.
+#[test]
+fn test_pinned_value_exhaustiveness() {
+    let result = check_source("let want = 1\ncase 2 do\n  ^want -> 1\nend");
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::NonExhaustiveMatch { .. }),
+        "NonExhaustiveMatch",
+    );
+
+    let result = check_source("let want = 1\ncase 2 do\n  ^want -> 1\n  ^want -> 2\n  _ -> 3\nend");
+    assert_has_warning(
+        &result,
+        |e| matches!(e, TypeError::RedundantArm { arm_index, .. } if *arm_index == 1),
+        "RedundantArm (repeated pin at index 1)",
+    );
+}
+
+/// `receive` arms are checked like `case` arms.
+#[test]
+fn test_receive_exhaustiveness() {
+    let result = check_source(
+        "actor worker() do\nreceive do\n  %{\"type\" => \"stop\"} -> 0\nend\nend",
+    );
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::NonExhaustiveMatch { .. }),
+        "NonExhaustiveMatch",
+    );
+
+    let result = check_source(
+        "actor worker() do\nreceive do\n  %{\"type\" => \"stop\"} -> 0\n  %{\"id\" => _} -> 1\n  _ -> 2\nend\nend",
+    );
+    assert_no_errors(&result);
+}
@@ -0,0 +1,118 @@
+//! Tests for the `meshc explain` registry.
+//!
+//! Every documented example is run through the parser, type checker and
+//! lint pass: the example must produce its code, and the fixed version
+//! must compile without it (and without any error).
+
+use mesh_typeck::diagnostics::{render_diagnostic, DiagnosticOptions};
+use mesh_typeck::explain::{explain, Explanation, EXPLANATIONS};
+use mesh_typeck::lint::{self, LintConfig};
+use mesh_typeck::{ImportContext, ModuleExports};
+
+// ── Helpers ────────────────────────────────────────────────────────────
+
+/// The diagnostic codes the compiler reports for `src`, importing the
+/// entry's dependency module if it has one.
+fn codes(entry: &Explanation, src: &str) -> Vec<String> {
+    let parse = mesh_parser::parse(src);
+    if !parse.errors().is_empty() {
+        return vec!["P0001".to_string()];
+    }
+
+    let mut import_ctx = ImportContext::empty();
+    if let Some((name, dep_src)) = entry.dependency {
+        let dep_parse = mesh_parser::parse(dep_src);
+        let dep_typeck = mesh_typeck::check(&dep_parse);
+        assert!(dep_typeck.errors.is_empty(), "{}: dependency has errors: {:?}", entry.code, dep_typeck.errors);
+        let exports = mesh_typeck::collect_exports(&dep_parse, &dep_typeck);
+        import_ctx.module_exports.insert(
+            name.to_string(),
+            ModuleExports {
+                module_name: name.to_string(),
+                functions: exports.functions,
+                struct_defs: exports.struct_defs,
+                sum_type_defs: exports.sum_type_defs,
+                service_defs: exports.service_defs,
+                actor_defs: exports.actor_defs,
+                private_names: exports.private_names,
+            },
+        );
+    }
+
+    let typeck = mesh_typeck::check_with_imports(&parse, &import_ctx);
+    let lints = if typeck.errors.is_empty() {
+        // Every lint is on, including the opt-in ones.
+        let config = LintConfig::from_settings([("all", "warn")]).unwrap();
+        lint::lint(&parse, &typeck, &config)
+    } else {
+        Vec::new()
+    };
+    typeck
+        .errors
+        .iter()
+        .chain(&typeck.warnings)
+        .chain(&lints)
+        .map(|diag| {
+            let json = render_diagnostic(diag, src, "example.mpl", &DiagnosticOptions::json_mode(), None);
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            value["code"].as_str().unwrap().to_string()
+        })
+        .collect()
+}
+
+// ── Examples ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_examples_produce_their_code() {
+    let mut failures = Vec::new();
+    for entry in EXPLANATIONS.iter().filter(|e| !e.example.is_empty()) {
+        let found = codes(entry, entry.example);
+        if !found.iter().any(|c| c == entry.code) {
+            failures.push(format!("{}: example produced {:?}", entry.code, found));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn test_fixes_compile_without_the_code() {
+    let mut failures = Vec::new();
+    for entry in EXPLANATIONS.iter().filter(|e| !e.example.is_empty()) {
+        let found = codes(entry, entry.fix);
+        if found.iter().any(|c| c == entry.code || !c.starts_with('W')) {
+            failures.push(format!("{}: fix produced {:?}", entry.code, found));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+// ── Registry ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_codes_are_unique_and_documented() {
+    let mut codes: Vec<&str> = EXPLANATIONS.iter().map(|e| e.code).collect();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), EXPLANATIONS.len(), "duplicate codes");
+
+    for entry in EXPLANATIONS {
+        assert!(!entry.title.is_empty() && !entry.description.trim().is_empty(), "{}", entry.code);
+        // Only fatal errors, which have no source location, lack an example.
+        assert_eq!(entry.example.is_empty(), entry.code == "C0001", "{}", entry.code);
+    }
+}
+
+#[test]
+fn test_explain_lookup_and_render() {
+    assert_eq!(explain("e0012").map(|e| e.code), Some("E0012"));
+    assert!(explain("E9999").is_none());
+
+    let text = explain("E0034").unwrap().render();
+    assert!(text.starts_with("E0034: name not found in module\n"), "{}", text);
+    assert!(text.contains("# geometry.mpl\npub fn area"), "{}", text);
+    assert!(text.contains("from Geometry import perimeter"), "{}", text);
+    assert!(text.contains("Fixed:"), "{}", text);
+
+    let text = explain("C0001").unwrap().render();
+    assert!(!text.contains("Example:"), "{}", text);
+}
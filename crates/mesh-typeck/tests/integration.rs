@@ -10,7 +10,7 @@
 //! 5. Error locations: type errors carry source span information
 
 use mesh_typeck::error::TypeError;
-use mesh_typeck::ty::Ty;
+use mesh_typeck::ty::{Ty, TyCon};
 use mesh_typeck::TypeckResult;
 
 // ── Helpers ────────────────────────────────────────────────────────────
@@ -328,3 +328,420 @@ fn test_pipe_bare_function_ref() {
     );
     assert_result_type(&result, Ty::int());
 }
+
+/// `|2>` pipes into the second argument: `"x" |2> repeat(3)` is `repeat(3, "x")`.
+#[test]
+fn test_numbered_pipe_call() {
+    let result = check_source(
+        "fn repeat(n :: Int, s :: String) -> String do s end\n\
+         \"x\" |2> repeat(3)",
+    );
+    assert_result_type(&result, Ty::string());
+
+    // Piping into the wrong position is a type mismatch.
+    let result = check_source(
+        "fn repeat(n :: Int, s :: String) -> String do s end\n\
+         \"x\" |> repeat(3)",
+    );
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Positions past the call's arguments, and position 0, are rejected.
+#[test]
+fn test_numbered_pipe_out_of_range() {
+    for (src, op, arity) in [
+        ("fn f(a :: Int, b :: Int) -> Int do a end\n1 |3> f(2)", "|3>", 2),
+        ("fn f(a :: Int) -> Int do a end\n1 |0> f()", "|0>", 1),
+        ("fn f(a :: Int) -> Int do a end\n1 |2> f", "|2>", 1),
+    ] {
+        let result = check_source(src);
+        assert_has_error(
+            &result,
+            |e| {
+                matches!(e, TypeError::PipePositionOutOfRange { op: o, arity: a, .. }
+                    if o == op && *a == arity)
+            },
+            op,
+        );
+    }
+}
+
+/// `~r` literals have type `Regex` and work with the `Regex` module.
+#[test]
+fn test_regex_literal_and_module() {
+    let result = check_source(
+        "let re = ~r/(?<user>\\w+)@(\\w+)/i\n\
+         let caps = Regex.named_captures(re, \"bob@example\")\n\
+         let parts = Regex.split(~r/,\\s*/, \"a, b\")\n\
+         Regex.replace(re, \"bob@example\", fn caps -> List.get(caps, 1) end)",
+    );
+    assert_result_type(&result, Ty::string());
+
+    let result = check_source("Regex.compile(\"a+\")");
+    assert_result_type(&result, Ty::result(Ty::Con(TyCon::new("Regex")), Ty::string()));
+}
+
+/// Bad patterns and unknown flags are compile errors.
+#[test]
+fn test_invalid_regex_literal() {
+    for (src, message) in [
+        ("~r/(ab/", "unclosed group"),
+        ("~r/ab/q", "unknown flag `q`"),
+        ("~r/[z-a]/", "invalid character class range, the start must be <= the end"),
+    ] {
+        let result = check_source(src);
+        assert_has_error(
+            &result,
+            |e| matches!(e, TypeError::InvalidRegex { message: m, .. } if m == message),
+            message,
+        );
+    }
+}
+
+/// `Set<T>` and `Queue<T>` are parametric in their element type.
+#[test]
+fn test_generic_set_and_queue() {
+    let result = check_source(
+        "let s = Set.add(Set.new(), \"a\")\n\
+         let u = Set.union(s, Set.from_list([\"b\"]))\n\
+         Set.to_list(u)",
+    );
+    assert_result_type(&result, Ty::list(Ty::string()));
+
+    let result = check_source(
+        "let q = Queue.push(Queue.new(), \"job\")\n\
+         Queue.pop(q)",
+    );
+    assert_result_type(
+        &result,
+        Ty::Tuple(vec![Ty::string(), Ty::queue(Ty::string())]),
+    );
+
+    let result = check_source(
+        "let q = Queue.from_list([1, 2])\n\
+         for x in q do\n\
+           x + 1\n\
+         end",
+    );
+    assert_result_type(&result, Ty::list(Ty::int()));
+}
+
+/// Tuple accessors return the tuple's own element types.
+#[test]
+fn test_typed_tuple_accessors() {
+    let result = check_source("Tuple.second((1, \"a\"))");
+    assert_result_type(&result, Ty::string());
+
+    let result = check_source("Tuple.nth((1, \"a\", true), 2)");
+    assert_result_type(&result, Ty::bool());
+
+    let result = check_source("let i = 1\nTuple.nth((1, 2, 3), i)");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("(\"x\", \"y\") |> Tuple.to_list()");
+    assert_result_type(&result, Ty::list(Ty::string()));
+}
+
+/// Accessors on a lambda or unannotated parameter are checked once the
+/// tuple's type is known, or fall back to the untyped `Tuple` scheme.
+#[test]
+fn test_tuple_accessors_on_unknown_tuple_types() {
+    let result = check_source("let pairs = [(1, \"a\"), (2, \"b\")]\nList.map(pairs, fn p -> Tuple.second(p) end)");
+    assert_result_type(&result, Ty::list(Ty::string()));
+
+    let result = check_source("let pairs = [(1, 2), (3, 4)]\nList.map(pairs, fn p -> Tuple.nth(p, 1) + 1 end)");
+    assert_result_type(&result, Ty::list(Ty::int()));
+
+    let result = check_source("fn head(t) do\n  Tuple.first(t)\nend\nhead((1, 2)) + 1");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("let pairs = [(1, \"a\")]\nList.map(pairs, fn p -> Tuple.second(p) + 1 end)");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("let i = 0\nlet pairs = [(1, \"a\")]\nList.map(pairs, fn p -> Tuple.nth(p, i) end)");
+    assert_has_error(&result, |e| matches!(e, TypeError::TupleAccess { .. }), "TupleAccess");
+}
+
+/// Accessors whose element type is not known statically are rejected.
+#[test]
+fn test_tuple_access_errors() {
+    let result = check_source("Tuple.nth((1, \"a\"), 2)");
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::TupleAccess { reason, .. } if reason == "index 2 is out of range for a 2-element tuple"),
+        "TupleAccess",
+    );
+
+    let result = check_source("let i = 0\nTuple.nth((1, \"a\"), i)");
+    assert_has_error(&result, |e| matches!(e, TypeError::TupleAccess { .. }), "TupleAccess");
+
+    let result = check_source("Tuple.to_list((1, \"a\"))");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Mixing element types in a set is a type error.
+#[test]
+fn test_set_element_type_mismatch() {
+    let result = check_source("Set.contains(Set.add(Set.new(), \"a\"), 1)");
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::Mismatch { .. }),
+        "Mismatch",
+    );
+}
+
+/// Fixed-width integers are distinct types; literals adopt them in operators
+/// and annotated lets, and `Int64`/`Byte` alias `Int`/`UInt8`.
+#[test]
+fn test_fixed_width_integers() {
+    let result = check_source("let b :: Byte = 200\nb + 1");
+    assert_result_type(&result, Ty::fixed_int("UInt8"));
+
+    let result = check_source("let n :: Int64 = 5\nn * 2");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("UInt16.to_int(UInt16.from_int(70000)) + 1");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("Int8.try_from_int(-3)");
+    assert_result_type(&result, Ty::option(Ty::fixed_int("Int8")));
+
+    let result = check_source("let x :: Int32 = 7\n-x");
+    assert_result_type(&result, Ty::fixed_int("Int32"));
+}
+
+/// Mixing widths, out-of-range literals and negating unsigned values are
+/// type errors.
+#[test]
+fn test_fixed_width_integer_errors() {
+    let result = check_source("let a :: UInt8 = 1\nlet b :: UInt16 = 1\na + b");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("let a :: UInt8 = 256\na");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("let a :: UInt32 = 1\nlet n = 5\na + n");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("let a :: UInt32 = 1\n-a");
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::TraitNotSatisfied { trait_name, .. } if trait_name == "Neg"),
+        "TraitNotSatisfied",
+    );
+}
+
+/// `Bytes` converts to and from strings and slices into more `Bytes`.
+#[test]
+fn test_bytes_api() {
+    let result = check_source("let b = String.to_bytes(\"hi\")\nBytes.slice(Bytes.concat(b, b), 1, 3)");
+    assert_result_type(&result, Ty::bytes());
+
+    let result = check_source("let b = String.to_bytes(\"hi\")\nb.get(0)");
+    assert_result_type(&result, Ty::fixed_int("UInt8"));
+
+    let result = check_source("String.from_utf8(Bytes.from_list([104, 105]))");
+    assert_result_type(&result, Ty::result(Ty::string(), Ty::string()));
+
+    let result = check_source("Bytes.from_list([104, 256])");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Binary patterns bind the type of each segment and match `Bytes`.
+#[test]
+fn test_bytes_patterns() {
+    let src = "let b = String.to_bytes(\"hi\")\ncase b do\n<<len::u16-little, body::bytes(len), _::rest>> -> len\n_ -> UInt16.from_int(0)\nend";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::fixed_int("UInt16"));
+
+    let src = "let b = String.to_bytes(\"hi\")\ncase b do\n<<\"GET \"::bytes, path::rest>> -> path\n_ -> b\nend";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::bytes());
+
+    let src = "let b = String.to_bytes(\"hi\")\ncase b do\n<<1, n::i64>> -> n + 1\n<<rest::rest>> -> 0\nend";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::int());
+}
+
+/// Map patterns match `Map<K, V>` and bind the value patterns; a pinned
+/// variable matches its current value.
+#[test]
+fn test_map_and_pin_patterns() {
+    let src = "let m = %{\"type\" => \"error\", \"id\" => \"7\"}\ncase m do\n%{\"type\" => \"error\", \"id\" => id} -> id\n_ -> \"\"\nend";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::string());
+
+    let src = "let want = 3\nlet m = %{1 => 3}\ncase m do\n%{1 => ^want, -1 => n} -> n\n_ -> 0\nend";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("let m = %{1 => 3}\ncase m do\n%{\"a\" => n} -> n\n_ -> 0\nend");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("case 1 do\n^missing -> 1\n_ -> 0\nend");
+    assert_has_error(
+        &result,
+        |e| matches!(e, TypeError::UnboundVariable { name, .. } if name == "missing"),
+        "UnboundVariable",
+    );
+
+    let result = check_source("let want = \"a\"\ncase 1 do\n^want -> 1\n_ -> 0\nend");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Binary construction takes integers for integer segments and `Bytes` or
+/// `String` for `bytes` segments.
+#[test]
+fn test_bytes_construction() {
+    let result = check_source("let n = 5\nlet b :: UInt8 = 7\n<<n::u32-big, b, \"tail\"::bytes>>");
+    assert_result_type(&result, Ty::bytes());
+
+    let result = check_source("<<\"x\"::u8>>");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// `BigInt` and `Decimal` support the arithmetic and comparison operators
+/// between values of the same type.
+#[test]
+fn test_big_numeric_types() {
+    let result = check_source("let a = BigInt.from_int(2)\nBigInt.pow(a, 100) * a - a / a % a");
+    assert_result_type(&result, Ty::bigint());
+
+    let result = check_source("BigInt.to_int(-BigInt.from_int(3))");
+    assert_result_type(&result, Ty::option(Ty::int()));
+
+    let result = check_source("let price = Decimal.from_int(20)\nlet rate = Decimal.round(price, 2)\nprice * rate > price");
+    assert_result_type(&result, Ty::bool());
+
+    let result = check_source("Decimal.parse(\"12.50\")");
+    assert_result_type(&result, Ty::option(Ty::decimal()));
+
+    let result = check_source("let d = Decimal.from_int(1)\nd.scale()");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("BigInt.from_int(1) + 1");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+
+    let result = check_source("BigInt.from_int(1) == Decimal.from_int(1)");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Structs with `BigInt` and `Decimal` fields can derive the standard traits,
+/// including `Json` and `Row`.
+#[test]
+fn test_big_numeric_deriving() {
+    let src = "struct Account do\n  id :: BigInt\n  balance :: Decimal\n  overdraft :: Option<Decimal>\nend deriving(Eq, Ord, Hash, Debug, Display, Json, Row)\n\
+               let a = Account { id: BigInt.from_int(1), balance: Decimal.from_int(0), overdraft: None }\na == a";
+    let result = check_source(src);
+    assert_result_type(&result, Ty::bool());
+}
+
+/// The grapheme-aware String functions, called both as module functions and
+/// as methods, and the lazy character iterators.
+#[test]
+fn test_string_unicode_functions() {
+    let result = check_source("String.graphemes(\"ab\")");
+    assert_result_type(&result, Ty::list(Ty::string()));
+
+    let result = check_source("\"ab\".codepoints()");
+    assert_result_type(&result, Ty::list(Ty::int()));
+
+    let result = check_source("String.at(\"ab\", -1)");
+    assert_result_type(&result, Ty::option(Ty::string()));
+
+    let result = check_source("\"ab\".index_of(\"b\")");
+    assert_result_type(&result, Ty::option(Ty::int()));
+
+    let result = check_source("String.pad_left(\"7\", 3, \"0\").reverse().fold_case()");
+    assert_result_type(&result, Ty::string());
+
+    let result = check_source("Iter.graphemes(String.normalize_nfd(\"ab\")) |> Iter.count()");
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("String.pad_right(\"a\", \"3\", \" \")");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Interpolation format specs are checked against the value's type.
+#[test]
+fn test_interpolation_format_specs() {
+    let result = check_source(
+        "let price = 9.5\n\
+         let id = 42\n\
+         let name = \"bob\"\n\
+         \"${price:>10.2} ${id:08x} ${name:-^12.3} ${true:>6} ${price:+.1e}\"",
+    );
+    assert_result_type(&result, Ty::string());
+
+    // `x` on an unannotated parameter makes it an Int.
+    let result = check_source("fn hex(n) do\n\"${n:x}\"\nend\nhex(255)");
+    assert_result_type(&result, Ty::string());
+
+    let result = check_source("String.format(\"{} = {:.2}\", [\"pi\", \"3.14159\"])");
+    assert_result_type(&result, Ty::result(Ty::string(), Ty::string()));
+
+    for (src, message) in [
+        ("let n = 1\n\"${n:.2}\"", "precision `.2` applies to a Float or String, not Int"),
+        ("let f = 1.5\n\"${f:x}\"", "`x` formats an Int, not Float"),
+        ("\"${\"a\":+}\"", "`+` applies to numbers, not String"),
+        ("let n = 1\n\"${n:>8q}\"", "unexpected `q`; expected `[[fill]align][+][0][width][.precision][type]`"),
+        ("let n = 1\n\"${n:*^08}\"", "`0` padding cannot be combined with an alignment"),
+    ] {
+        let result = check_source(src);
+        assert_has_error(
+            &result,
+            |e| matches!(e, TypeError::InvalidFormatSpec { message: m, .. } if m == message),
+            message,
+        );
+    }
+}
+
+/// IoData builds output cheaply; the output functions take it in place of
+/// their String payload.
+#[test]
+fn test_iodata_output() {
+    let result = check_source(
+        "let rows = IoData.from_list([\"id,name\\n\"])\n\
+         let rows = IoData.append(rows, \"1,ada\\n\") |> IoData.append_bytes(String.to_bytes(\"!\"))\n\
+         IO.write(rows)\n\
+         IO.write(\"plain\")\n\
+         rows |> IO.write()\n\
+         let saved = File.write(\"rows.csv\", IoData.concat(rows, IoData.new()))\n\
+         let resp = HTTP.response(200, rows)\n\
+         IoData.byte_size(rows)",
+    );
+    assert_result_type(&result, Ty::int());
+
+    let result = check_source("HTTP.response(200, IoData.from_string(\"ok\"))");
+    assert_result_type(&result, Ty::Con(TyCon::new("Response")));
+
+    let result = check_source("IoData.to_string(IoData.new())");
+    assert_result_type(&result, Ty::string());
+
+    // Other payload types are still rejected.
+    let result = check_source("File.write(\"a.txt\", 42)");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+    let result = check_source("IoData.append(IoData.new(), IoData.new())");
+    assert_has_error(&result, |e| matches!(e, TypeError::Mismatch { .. }), "Mismatch");
+}
+
+/// Segments whose shape cannot be laid out are rejected.
+#[test]
+fn test_bytes_segment_errors() {
+    let cases = [
+        ("let b = String.to_bytes(\"a\")\ncase b do\n<<n::u8, body::bytes>> -> body\n_ -> String.to_bytes(\"\")\nend",
+         "`bytes` needs a size in a pattern; use `rest` for the remaining bytes"),
+        ("let b = String.to_bytes(\"a\")\ncase b do\n<<r::rest, n::u8>> -> r\n_ -> b\nend", "`rest` must be the last segment"),
+        ("let b = String.to_bytes(\"a\")\ncase b do\n<<300>> -> 1\n_ -> 0\nend", "300 does not fit in a `u8` segment"),
+        ("<<1::u24>>", "unknown segment type `u24`; expected u8, u16, u32, u64, i8, i16, i32, i64, bytes or rest"),
+        ("<<1::u16-middle>>", "unknown modifier `-middle`; expected `-big` or `-little`"),
+    ];
+    for (src, expected) in cases {
+        let result = check_source(src);
+        assert_has_error(
+            &result,
+            |e| matches!(e, TypeError::InvalidBytesSegment { message, .. } if message == expected),
+            "InvalidBytesSegment",
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Integration tests for the lint pass.
+//!
+//! These tests exercise:
+//! - Each lint firing on a minimal example, and staying quiet on used code
+//! - `_`-prefixed names being exempt
+//! - Lint levels: allowed lints are dropped, denied lints keep their level
+//! - Parsing `[lints]` settings from mesh.toml
+
+use mesh_typeck::error::TypeError;
+use mesh_typeck::lint::{self, Lint, LintConfig, LintLevel};
+
+// ── Helpers ────────────────────────────────────────────────────────────
+
+/// Parse and type-check Mesh source, then lint it with `config`.
+fn lint_with(src: &str, config: &LintConfig) -> Vec<TypeError> {
+    let parse = mesh_parser::parse(src);
+    let typeck = mesh_typeck::check(&parse);
+    assert!(typeck.errors.is_empty(), "unexpected type errors: {:?}", typeck.errors);
+    lint::lint(&parse, &typeck, config)
+}
+
+/// The (lint, message) pairs reported for `src` at default levels.
+fn findings(src: &str) -> Vec<(Lint, String)> {
+    findings_with(src, &LintConfig::default())
+}
+
+/// The (lint, message) pairs reported for `src` with `config`.
+fn findings_with(src: &str, config: &LintConfig) -> Vec<(Lint, String)> {
+    lint_with(src, config)
+        .into_iter()
+        .map(|finding| match finding {
+            TypeError::Lint { lint, message, .. } => (lint, message),
+            other => panic!("expected a lint finding, got {:?}", other),
+        })
+        .collect()
+}
+
+// ── unused_variable ────────────────────────────────────────────────────
+
+#[test]
+fn test_unused_let_binding() {
+    let src = "fn main() do\n  let x = 1\n  let y = 2\n  println(\"${y}\")\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnusedVariable, "unused variable `x`".to_string())]
+    );
+}
+
+#[test]
+fn test_underscore_and_used_bindings_are_quiet() {
+    let src = "fn main() do\n  let _x = 1\n  let (a, b) = (1, 2)\n  let f = fn(n) -> n + a end\n  println(\"${f(b)}\")\nend";
+    assert_eq!(findings(src), vec![]);
+}
+
+#[test]
+fn test_unused_tuple_binding_element() {
+    let src = "fn main() do\n  let (a, b) = (1, 2)\n  println(\"${a}\")\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnusedVariable, "unused variable `b`".to_string())]
+    );
+}
+
+#[test]
+fn test_pinned_variable_counts_as_use() {
+    let src = "fn main() do\n  let want = 1\n  let m = %{\"id\" => 1}\n  case m do\n    %{\"id\" => ^want} -> println(\"match\")\n    _ -> println(\"other\")\n  end\nend";
+    assert_eq!(findings(src), vec![]);
+}
+
+// ── unused_import ──────────────────────────────────────────────────────
+
+#[test]
+fn test_unused_imports() {
+    let src = "from String import length, split\nfn main() do\n  println(\"${length(\"abc\")}\")\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnusedImport, "unused import `split`".to_string())]
+    );
+}
+
+// ── dead_code ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_unused_private_function() {
+    let src = "fn helper() do\n  1\nend\nfn count(n) do\n  if n > 0 do\n    count(n - 1)\n  else\n    0\n  end\nend\npub fn api() do\n  2\nend\nfn used() do\n  3\nend\nfn main() do\n  println(\"${used()}\")\nend";
+    let mut found = findings(src);
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+    assert_eq!(
+        found,
+        vec![
+            (Lint::DeadCode, "function `count` is never used".to_string()),
+            (Lint::DeadCode, "function `helper` is never used".to_string()),
+        ]
+    );
+}
+
+// ── unused_field ───────────────────────────────────────────────────────
+
+#[test]
+fn test_unused_struct_field() {
+    let src = "struct Point do\n  x :: Int\n  y :: Int\nend\nfn main() do\n  let p = Point { x: 1, y: 2 }\n  println(\"${p.x}\")\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnusedField, "field `y` of `Point` is never read".to_string())]
+    );
+}
+
+#[test]
+fn test_pub_and_deriving_structs_are_quiet() {
+    let src = "pub struct A do\n  x :: Int\nend\nstruct B do\n  y :: Int\nend deriving(Eq)\nfn main() do\n  println(\"${A { x: 1 } == A { x: 1 }}\")\nend";
+    let found = findings(src);
+    assert!(
+        found.iter().all(|(lint, _)| *lint != Lint::UnusedField),
+        "unexpected unused_field findings: {:?}",
+        found
+    );
+}
+
+// ── shadowing ──────────────────────────────────────────────────────────
+
+/// Shadowing is opt-in; these tests turn it on.
+fn shadowing_findings(src: &str) -> Vec<(Lint, String)> {
+    let config = LintConfig::from_settings([("shadowing", "warn")]).unwrap();
+    findings_with(src, &config)
+}
+
+#[test]
+fn test_let_shadowing() {
+    let src = "fn main() do\n  let x = 1\n  let x = x + 1\n  println(\"${x}\")\nend";
+    assert_eq!(
+        shadowing_findings(src),
+        vec![(Lint::Shadowing, "`x` shadows an earlier binding".to_string())]
+    );
+}
+
+#[test]
+fn test_shadowing_is_allowed_by_default() {
+    let src = "fn main() do\n  let x = 1\n  let x = x + 1\n  println(\"${x}\")\nend";
+    assert_eq!(findings(src), vec![]);
+    assert_eq!(LintConfig::default().level(Lint::Shadowing), LintLevel::Allow);
+}
+
+#[test]
+fn test_shadowing_a_parameter() {
+    let src = "fn inc(n) do\n  let n = n + 1\n  n\nend\nfn main() do\n  println(\"${inc(1)}\")\nend";
+    assert_eq!(
+        shadowing_findings(src),
+        vec![(Lint::Shadowing, "`n` shadows an earlier binding".to_string())]
+    );
+}
+
+// ── unused_result ──────────────────────────────────────────────────────
+
+#[test]
+fn test_discarded_result() {
+    let src = "fn parse(s :: String) -> Int!String do\n  if s == \"\" do\n    Err(\"empty\")\n  else\n    Ok(1)\n  end\nend\nfn main() do\n  parse(\"\")\n  let r = parse(\"1\")\n  println(\"done\")\n  r\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnusedResult, "unused `Result` value".to_string())]
+    );
+}
+
+// ── unreachable_code ───────────────────────────────────────────────────
+
+#[test]
+fn test_code_after_return() {
+    let src = "fn f() do\n  return 1\n  println(\"never\")\n  2\nend\nfn main() do\n  println(\"${f()}\")\nend";
+    assert_eq!(
+        findings(src),
+        vec![(Lint::UnreachableCode, "unreachable code".to_string())]
+    );
+}
+
+// ── Levels and configuration ───────────────────────────────────────────
+
+#[test]
+fn test_allowed_lints_are_not_reported() {
+    let src = "fn main() do\n  let x = 1\n  let x = 2\nend";
+    let mut config = LintConfig::default();
+    config.set(Lint::Shadowing, LintLevel::Warn);
+    config.set(Lint::UnusedVariable, LintLevel::Allow);
+    let found = lint_with(src, &config);
+    assert!(
+        matches!(found.as_slice(), [TypeError::Lint { lint: Lint::Shadowing, .. }]),
+        "expected only the shadowing lint, got {:?}",
+        found
+    );
+}
+
+#[test]
+fn test_denied_lints_keep_their_level() {
+    let src = "fn main() do\n  let x = 1\nend";
+    let config = LintConfig::from_settings([("unused_variable", "deny")]).unwrap();
+    let found = lint_with(src, &config);
+    assert!(
+        matches!(
+            found.as_slice(),
+            [TypeError::Lint { lint: Lint::UnusedVariable, level: LintLevel::Deny, .. }]
+        ),
+        "expected a denied unused_variable lint, got {:?}",
+        found
+    );
+}
+
+#[test]
+fn test_config_from_settings() {
+    let config =
+        LintConfig::from_settings([("shadowing", "allow"), ("all", "deny")]).unwrap();
+    assert_eq!(config.level(Lint::Shadowing), LintLevel::Allow);
+    assert_eq!(config.level(Lint::DeadCode), LintLevel::Deny);
+
+    let err = LintConfig::from_settings([("unused_varaible", "allow")]).unwrap_err();
+    assert_eq!(err, "unknown lint `unused_varaible`");
+    let err = LintConfig::from_settings([("dead_code", "forbid")]).unwrap_err();
+    assert!(err.contains("invalid level `forbid`"), "{}", err);
+}
+
+#[test]
+fn test_lint_names_and_codes_are_stable() {
+    let codes: Vec<_> = Lint::ALL.iter().map(|l| (l.name(), l.code())).collect();
+    assert_eq!(
+        codes,
+        vec![
+            ("unused_variable", "W0003"),
+            ("unused_import", "W0004"),
+            ("dead_code", "W0005"),
+            ("unused_field", "W0006"),
+            ("shadowing", "W0007"),
+            ("unused_result", "W0008"),
+            ("unreachable_code", "W0009"),
+        ]
+    );
+    for lint in Lint::ALL {
+        assert_eq!(Lint::from_name(lint.name()), Some(lint));
+    }
+}
@@ -199,3 +199,30 @@ fn test_trait_method_call() {
     );
     assert_result_type(&result, Ty::string());
 }
+
+// ── Hash for map keys and set elements ───────────────────────────────
+
+/// 14. Struct map keys and set elements need a `Hash` impl; tuple keys need
+/// one for each struct element.
+#[test]
+fn test_hashed_keys_require_hash() {
+    let result = check_source(
+        "struct Point do\n  x :: Int\nend deriving(Eq, Hash)\n\
+         let m = Map.put(Map.new(), Point { x: 1 }, 1)\n\
+         Set.contains(Set.from_list([Point { x: 1 }]), Point { x: 2 })",
+    );
+    assert_result_type(&result, Ty::bool());
+
+    for src in [
+        "struct Point do\n  x :: Int\nend deriving(Eq)\nMap.size(Map.put(Map.new(), Point { x: 1 }, 1))",
+        "struct Point do\n  x :: Int\nend deriving(Eq)\nSet.size(Set.add(Set.new(), Point { x: 1 }))",
+        "struct Point do\n  x :: Int\nend deriving(Eq)\nMap.size(Map.put(Map.new(), (1, Point { x: 1 }), 1))",
+    ] {
+        let result = check_source(src);
+        assert_has_error(
+            &result,
+            |e| matches!(e, TypeError::TraitNotSatisfied { trait_name, .. } if trait_name == "Hash"),
+            "TraitNotSatisfied (Hash)",
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Incremental compilation cache for `meshc build`, `meshc run`, and `meshc test`.
+//!
+//! Per-module results are stored under the cache directory (normally
+//! `<project>/target/cache/`) so an edit only re-typechecks and re-lowers the
+//! modules it can affect:
+//!
+//! - `modules/<Module>.json`: the module's type-check exports
+//!   (`collect_exports`), its rendered warnings and lints, and its lowered
+//!   MIR, tagged with the module key. The diagnostics are replayed when the
+//!   entry is reused, so a cached build reports the same warnings.
+//! - `objects/<binary>/<Module>.o`: the object file holding the functions
+//!   `<Module>` contributes to `<binary>`, with its key in `<Module>.key`.
+//!   `main.o` holds the C `main`; with LTO it holds the whole program.
+//!
+//! A module key hashes the compiler, the module's path and source, the keys
+//! of the modules it imports (edges of the `ModuleGraph`), and the trait
+//! definitions and impls visible to it. Changing a module therefore changes
+//! the key of every module that imports it, directly or transitively, and
+//! only those modules are rebuilt.
+//!
+//! MIR from all modules is merged and monomorphized before codegen, so the
+//! key of a module's object also covers which of its functions the program
+//! reaches, and the codegen settings. `main.o` registers every function with
+//! the runtime and is rebuilt whenever any module changes.
+//!
+//! Unreadable, stale, or mismatched entries are treated as cache misses.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use mesh_codegen::mir::MirModule;
+use mesh_typeck::ExportedSymbols;
+use serde::{Deserialize, Serialize};
+
+/// A module's cached compilation results.
+#[derive(Deserialize)]
+pub(crate) struct CachedModule {
+    key: String,
+    pub exports: ExportedSymbols,
+    /// Warnings and lints as they were rendered when the module was checked.
+    pub diagnostics: String,
+    pub mir: MirModule,
+}
+
+/// Borrowed form of [`CachedModule`] used when writing an entry.
+#[derive(Serialize)]
+struct CachedModuleRef<'a> {
+    key: &'a str,
+    exports: &'a ExportedSymbols,
+    diagnostics: &'a str,
+    mir: &'a MirModule,
+}
+
+/// Handle to a cache directory.
+pub(crate) struct BuildCache {
+    dir: PathBuf,
+}
+
+impl BuildCache {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        BuildCache { dir }
+    }
+
+    /// Load a module's entry if it was stored under `key`.
+    pub(crate) fn load_module(&self, name: &str, key: &str) -> Option<CachedModule> {
+        let data = std::fs::read(self.module_path(name)).ok()?;
+        let cached: CachedModule = serde_json::from_slice(&data).ok()?;
+        (cached.key == key).then_some(cached)
+    }
+
+    /// Store a module's exports, rendered diagnostics, and MIR under `key`.
+    pub(crate) fn store_module(
+        &self,
+        name: &str,
+        key: &str,
+        exports: &ExportedSymbols,
+        diagnostics: &str,
+        mir: &MirModule,
+    ) -> Result<(), String> {
+        let path = self.module_path(name);
+        let entry = CachedModuleRef { key, exports, diagnostics, mir };
+        let data = serde_json::to_vec(&entry)
+            .map_err(|e| format!("Failed to serialize cache entry for '{}': {}", name, e))?;
+        write_file(&path, &data)
+    }
+
+    /// Path of the cached object `unit` of `binary` if it was built with `key`.
+    pub(crate) fn cached_object(&self, binary: &str, unit: &str, key: &str) -> Option<PathBuf> {
+        let object = self.object_path(binary, unit);
+        let stored = std::fs::read_to_string(self.object_key_path(binary, unit)).ok()?;
+        (stored.trim() == key && object.exists()).then_some(object)
+    }
+
+    /// Path the object `unit` of `binary` should be written to before calling
+    /// [`BuildCache::store_object_key`].
+    pub(crate) fn object_path(&self, binary: &str, unit: &str) -> PathBuf {
+        self.dir.join("objects").join(binary).join(format!("{}.o", unit))
+    }
+
+    /// Record that the object at [`BuildCache::object_path`] was built with `key`.
+    pub(crate) fn store_object_key(&self, binary: &str, unit: &str, key: &str) -> Result<(), String> {
+        write_file(&self.object_key_path(binary, unit), key.as_bytes())
+    }
+
+    /// Forget the object `unit` of `binary`, e.g. before rebuilding it.
+    pub(crate) fn invalidate_object(&self, binary: &str, unit: &str) {
+        let _ = std::fs::remove_file(self.object_key_path(binary, unit));
+    }
+
+    fn module_path(&self, name: &str) -> PathBuf {
+        self.dir.join("modules").join(format!("{}.json", name))
+    }
+
+    fn object_key_path(&self, binary: &str, unit: &str) -> PathBuf {
+        self.dir.join("objects").join(binary).join(format!("{}.key", unit))
+    }
+}
+
+/// Compute the key of a module from its inputs.
+///
+/// `dep_keys` are the keys of the modules it imports; `visible_traits` is
+/// the [`traits_fingerprint`] of the modules checked before it; `lints`
+/// fingerprints the lint levels and how diagnostics are rendered, so changing
+/// either re-checks the module instead of replaying stale output; `debug`
+/// says whether the module's MIR carries source line markers.
+pub(crate) fn module_key(
+    name: &str,
+    path: &Path,
+    source: &str,
+    dep_keys: &[&str],
+    visible_traits: &str,
+    lints: &str,
+    debug: bool,
+) -> String {
+    let mut hasher = DefaultHasher::new();
+    hash_compiler(&mut hasher);
+    name.hash(&mut hasher);
+    path.hash(&mut hasher);
+    source.hash(&mut hasher);
+    dep_keys.hash(&mut hasher);
+    visible_traits.hash(&mut hasher);
+    lints.hash(&mut hasher);
+    debug.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Key of a module's object: its module key, the functions it defines in the
+/// merged program, and the codegen settings.
+pub(crate) fn module_object_key(module_key: &str, functions: &[&str], settings: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    module_key.hash(&mut hasher);
+    functions.hash(&mut hasher);
+    settings.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Key of `main.o`: every module key plus the codegen settings.
+pub(crate) fn program_object_key(module_keys: &[String], settings: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    module_keys.hash(&mut hasher);
+    settings.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Extend a running fingerprint of visible traits with one module's exports.
+///
+/// Every module sees the trait definitions and impls of all modules checked
+/// before it, not just the ones it imports, so the fingerprint of those is
+/// part of its key. Serializing through `serde_json::Value` sorts map keys,
+/// which keeps the result independent of hash map iteration order.
+pub(crate) fn traits_fingerprint(previous: &str, exports: &ExportedSymbols) -> String {
+    let traits = serde_json::to_value((&exports.trait_defs, &exports.trait_impls))
+        .map(|v| v.to_string())
+        .unwrap_or_default();
+    let mut hasher = DefaultHasher::new();
+    previous.hash(&mut hasher);
+    traits.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Hash the identity of the running compiler: its version and the timestamp
+/// of its executable, so a rebuilt `meshc` never reuses stale results.
+pub(crate) fn hash_compiler(hasher: &mut impl Hasher) {
+    env!("CARGO_PKG_VERSION").hash(hasher);
+    if let Ok(modified) = std::env::current_exe()
+        .and_then(std::fs::metadata)
+        .and_then(|m| m.modified())
+    {
+        modified.hash(hasher);
+    }
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+    }
+    std::fs::write(path, data).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_mir() -> MirModule {
+        MirModule {
+            functions: Vec::new(),
+            structs: Vec::new(),
+            sum_types: Vec::new(),
+            entry_function: None,
+            service_dispatch: std::collections::HashMap::new(),
+            tests: Vec::new(),
+            fn_locations: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_module_key_tracks_source_and_dependencies() {
+        let path = Path::new("math.mpl");
+
+        let key = module_key("Math", path, "fn a() do 1 end", &[], "", "", false);
+        assert_eq!(key, module_key("Math", path, "fn a() do 1 end", &[], "", "", false));
+        assert_ne!(key, module_key("Math", path, "fn a() do 2 end", &[], "", "", false));
+        assert_ne!(key, module_key("Math", path, "fn a() do 1 end", &["0123"], "", "", false));
+        assert_ne!(key, module_key("Math", path, "fn a() do 1 end", &[], "traits", "", false));
+        assert_ne!(key, module_key("Math", path, "fn a() do 1 end", &[], "", "lints", false));
+        assert_ne!(key, module_key("Math", path, "fn a() do 1 end", &[], "", "", true));
+    }
+
+    #[test]
+    fn test_module_round_trip_requires_matching_key() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache = BuildCache::new(tmp.path().to_path_buf());
+
+        let mut exports = ExportedSymbols::default();
+        exports.private_names.insert("helper".to_string());
+        let mut mir = empty_mir();
+        mir.entry_function = Some("mesh_main".to_string());
+
+        let warning = "warning: unused variable `x`\n";
+        cache.store_module("Main", "k1", &exports, warning, &mir).unwrap();
+        assert!(tmp.path().join("modules").join("Main.json").exists());
+
+        let loaded = cache.load_module("Main", "k1").expect("cache hit");
+        assert!(loaded.exports.private_names.contains("helper"));
+        assert_eq!(loaded.diagnostics, warning);
+        assert_eq!(loaded.mir.entry_function.as_deref(), Some("mesh_main"));
+
+        assert!(cache.load_module("Main", "k2").is_none());
+        assert!(cache.load_module("Other", "k1").is_none());
+    }
+
+    #[test]
+    fn test_cached_object_requires_matching_key() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache = BuildCache::new(tmp.path().to_path_buf());
+        let key = module_object_key("k1", &["Math__add"], "O0");
+
+        assert!(cache.cached_object("app", "Math", &key).is_none());
+
+        write_file(&cache.object_path("app", "Math"), b"obj").unwrap();
+        cache.store_object_key("app", "Math", &key).unwrap();
+        assert_eq!(
+            cache.cached_object("app", "Math", &key),
+            Some(cache.object_path("app", "Math"))
+        );
+        assert!(cache.cached_object("app", "Math", &module_object_key("k1", &[], "O0")).is_none());
+        assert!(cache.cached_object("app", "Main", &key).is_none());
+        assert!(cache.cached_object("app-tests", "Math", &key).is_none());
+
+        cache.invalidate_object("app", "Math");
+        assert!(cache.cached_object("app", "Math", &key).is_none());
+    }
+
+    #[test]
+    fn test_object_keys_track_their_inputs() {
+        let key = module_object_key("k1", &["Math__add"], "O0");
+        assert_ne!(key, module_object_key("k2", &["Math__add"], "O0"));
+        assert_ne!(key, module_object_key("k1", &["Math__add", "Math__sub"], "O0"));
+        assert_ne!(key, module_object_key("k1", &["Math__add"], "O2"));
+
+        let keys = vec!["k1".to_string(), "k2".to_string()];
+        let program = program_object_key(&keys, "O0");
+        assert_ne!(program, program_object_key(&keys[..1], "O0"));
+        assert_ne!(program, program_object_key(&keys, "O2"));
+    }
+}
@@ -250,6 +250,32 @@ pub fn build_project(project_root: &Path) -> Result<ProjectData, String> {
     })
 }
 
+/// Build a single-module project from one standalone `.mpl` file.
+///
+/// The file becomes the entry module `Main`, registered with its file name
+/// as the relative path (so diagnostics resolve against the file's parent
+/// directory). Sibling files are not discovered; imports of other project
+/// modules are left to the type checker to report.
+pub fn build_single_file_project(file: &Path) -> Result<ProjectData, String> {
+    let source = std::fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read '{}': {}", file.display(), e))?;
+    let relative_path = PathBuf::from(
+        file.file_name()
+            .ok_or_else(|| format!("'{}' is not a file", file.display()))?,
+    );
+
+    let mut graph = ModuleGraph::new();
+    let id = graph.add_module("Main".to_string(), relative_path, true);
+    let parse = mesh_parser::parse(&source);
+
+    Ok(ProjectData {
+        graph,
+        compilation_order: vec![id],
+        module_sources: vec![source],
+        module_parses: vec![parse],
+    })
+}
+
 /// Build a complete module dependency graph from a Mesh project directory.
 ///
 /// Convenience wrapper around [`build_project`] that returns only the graph
@@ -0,0 +1,951 @@
+//! API documentation generator for `meshc doc`.
+//!
+//! Type-checks a project (or a single `.mpl` file) and documents the public
+//! functions, structs, sum types and interfaces and the services of every
+//! module, with the `##` doc comments attached to them and each module's
+//! `##!` docs:
+//!
+//! - `html` (default): `index.html`, one `<Module>.html` page per module,
+//!   `style.css`, and a search box on every page backed by `search-index.js`.
+//! - `markdown`: `index.md` and one `<Module>.md` page per module.
+//!
+//! Function signatures show the types inferred by the type checker, so
+//! unannotated parameters and return types are documented too. Type names in
+//! signatures link to the item that defines them, across modules.
+//!
+//! Output goes to `<project>/target/doc/` unless `--output` is given.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use mesh_parser::ast::item::{
+    FnDef, InterfaceDef, Item, ServiceDef, SourceFile, StructDef, SumTypeDef,
+};
+use mesh_parser::ast::AstNode;
+use mesh_parser::cst::{SyntaxNode, SyntaxToken};
+use mesh_parser::syntax_kind::SyntaxKind;
+use mesh_typeck::diagnostics::DiagnosticOptions;
+use mesh_typeck::ty::Ty;
+use mesh_typeck::TypeckResult;
+
+/// Output format of `meshc doc`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum DocFormat {
+    Html,
+    Markdown,
+}
+
+impl DocFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+}
+
+/// Documentation of one module.
+#[derive(Debug)]
+struct ModuleDoc {
+    name: String,
+    /// Source path relative to the project root.
+    path: String,
+    doc: Option<String>,
+    items: Vec<ItemDoc>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ItemKind {
+    Function,
+    Struct,
+    SumType,
+    Interface,
+    Service,
+}
+
+/// Sections of a module page, in order.
+const ITEM_KINDS: [ItemKind; 5] = [
+    ItemKind::Function,
+    ItemKind::Struct,
+    ItemKind::SumType,
+    ItemKind::Interface,
+    ItemKind::Service,
+];
+
+impl ItemKind {
+    /// Keyword shown in headings and search results; also the anchor prefix.
+    fn keyword(self) -> &'static str {
+        match self {
+            ItemKind::Function => "fn",
+            ItemKind::Struct => "struct",
+            ItemKind::SumType => "type",
+            ItemKind::Interface => "interface",
+            ItemKind::Service => "service",
+        }
+    }
+
+    fn section(self) -> &'static str {
+        match self {
+            ItemKind::Function => "Functions",
+            ItemKind::Struct => "Structs",
+            ItemKind::SumType => "Types",
+            ItemKind::Interface => "Interfaces",
+            ItemKind::Service => "Services",
+        }
+    }
+}
+
+/// A documented item. `members` are struct fields, variants, interface
+/// methods, or the init function and handlers of a service.
+#[derive(Debug)]
+struct ItemDoc {
+    kind: ItemKind,
+    name: String,
+    signature: String,
+    doc: Option<String>,
+    members: Vec<MemberDoc>,
+}
+
+impl ItemDoc {
+    fn anchor(&self) -> String {
+        format!("{}.{}", self.kind.keyword(), self.name)
+    }
+}
+
+#[derive(Debug)]
+struct MemberDoc {
+    signature: String,
+    doc: Option<String>,
+}
+
+/// Execute the `doc` subcommand. Returns the path of the generated index page.
+pub(crate) fn doc_command(
+    path: &Path,
+    format: DocFormat,
+    output: Option<&Path>,
+    diag_opts: &DiagnosticOptions,
+) -> Result<PathBuf, String> {
+    let (root, project) = crate::load_project(path)?;
+    let checked = crate::check_project(&root, &project, None, false, diag_opts)?;
+
+    let modules: Vec<ModuleDoc> = project
+        .compilation_order
+        .iter()
+        .map(|&id| {
+            let idx = id.0 as usize;
+            let module = project.graph.get(id);
+            collect_module(
+                &module.name,
+                &module.path.display().to_string(),
+                &project.module_parses[idx].tree(),
+                checked.all_typeck[idx].as_ref(),
+            )
+        })
+        .collect();
+
+    let out_dir = output
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| root.join("target").join("doc"));
+    write_docs(&out_dir, &project_title(path), &modules, format)
+}
+
+/// Name shown on the index page: the project directory or the file stem.
+fn project_title(path: &Path) -> String {
+    let name = if path.is_file() {
+        path.file_stem().map(|s| s.to_os_string())
+    } else {
+        path.canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_os_string()))
+    };
+    name.map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Mesh project".to_string())
+}
+
+// ── Collection ──────────────────────────────────────────────────────
+
+/// Collect the documented items of a module, in source order.
+///
+/// Functions, structs, sum types and interfaces are documented when they
+/// are `pub`; services have no visibility and are always documented. The
+/// clauses of a multi-clause function are documented once.
+fn collect_module(
+    name: &str,
+    path: &str,
+    tree: &SourceFile,
+    typeck: Option<&TypeckResult>,
+) -> ModuleDoc {
+    let mut items: Vec<ItemDoc> = Vec::new();
+    for item in tree.items() {
+        let doc = match item {
+            Item::FnDef(f) if f.visibility().is_some() => fn_doc(&f, name, typeck),
+            Item::StructDef(s) if s.visibility().is_some() => struct_doc(&s),
+            Item::SumTypeDef(t) if t.visibility().is_some() => sum_type_doc(&t),
+            Item::InterfaceDef(i) if i.visibility().is_some() => interface_doc(&i),
+            Item::ServiceDef(s) => service_doc(&s, name, typeck),
+            _ => None,
+        };
+        let Some(doc) = doc else { continue };
+
+        let clause = items.iter_mut().find(|other| {
+            other.kind == ItemKind::Function
+                && doc.kind == ItemKind::Function
+                && other.signature == doc.signature
+        });
+        match clause {
+            Some(first) => {
+                if first.doc.is_none() {
+                    first.doc = doc.doc;
+                }
+            }
+            None => items.push(doc),
+        }
+    }
+
+    ModuleDoc {
+        name: name.to_string(),
+        path: path.to_string(),
+        doc: tree.module_doc(),
+        items,
+    }
+}
+
+fn fn_doc(fn_def: &FnDef, module: &str, typeck: Option<&TypeckResult>) -> Option<ItemDoc> {
+    Some(ItemDoc {
+        kind: ItemKind::Function,
+        name: fn_def.name()?.text()?,
+        signature: fn_signature(fn_def, module, typeck),
+        doc: fn_def.doc_comment(),
+        members: Vec::new(),
+    })
+}
+
+fn struct_doc(def: &StructDef) -> Option<ItemDoc> {
+    Some(ItemDoc {
+        kind: ItemKind::Struct,
+        name: def.name()?.text()?,
+        signature: header(def.syntax()),
+        doc: def.doc_comment(),
+        members: def
+            .fields()
+            .map(|field| MemberDoc { signature: source_text(field.syntax()), doc: None })
+            .collect(),
+    })
+}
+
+fn sum_type_doc(def: &SumTypeDef) -> Option<ItemDoc> {
+    Some(ItemDoc {
+        kind: ItemKind::SumType,
+        name: def.name()?.text()?,
+        signature: header(def.syntax()),
+        doc: def.doc_comment(),
+        members: def
+            .variants()
+            .map(|variant| MemberDoc { signature: source_text(variant.syntax()), doc: None })
+            .collect(),
+    })
+}
+
+fn interface_doc(def: &InterfaceDef) -> Option<ItemDoc> {
+    Some(ItemDoc {
+        kind: ItemKind::Interface,
+        name: def.name()?.text()?,
+        signature: header(def.syntax()),
+        doc: def.doc_comment(),
+        members: def
+            .methods()
+            .map(|method| MemberDoc {
+                signature: header(method.syntax()),
+                doc: method.doc_comment(),
+            })
+            .collect(),
+    })
+}
+
+fn service_doc(def: &ServiceDef, module: &str, typeck: Option<&TypeckResult>) -> Option<ItemDoc> {
+    let mut members: Vec<MemberDoc> = def
+        .init_fn()
+        .map(|init| MemberDoc {
+            signature: fn_signature(&init, module, typeck),
+            doc: init.doc_comment(),
+        })
+        .into_iter()
+        .collect();
+    members.extend(def.call_handlers().iter().map(|h| MemberDoc {
+        signature: header(h.syntax()),
+        doc: None,
+    }));
+    members.extend(def.cast_handlers().iter().map(|h| MemberDoc {
+        signature: header(h.syntax()),
+        doc: None,
+    }));
+
+    Some(ItemDoc {
+        kind: ItemKind::Service,
+        name: def.name()?.text()?,
+        signature: header(def.syntax()),
+        doc: def.doc_comment(),
+        members,
+    })
+}
+
+/// Signature of a function with its inferred parameter and return types,
+/// e.g. `fn add(a :: Int, b :: Int) -> Int`. Falls back to the signature as
+/// written when no type was inferred.
+fn fn_signature(fn_def: &FnDef, module: &str, typeck: Option<&TypeckResult>) -> String {
+    let written = || header(fn_def.syntax());
+    let Some(Ty::Fun(param_tys, ret)) =
+        typeck.and_then(|t| t.types.get(&fn_def.syntax().text_range()))
+    else {
+        return written();
+    };
+    let names: Vec<String> = fn_def
+        .param_list()
+        .map(|list| list.params().map(|p| param_name(p.syntax())).collect())
+        .unwrap_or_default();
+    if names.len() != param_tys.len() {
+        return written();
+    }
+    let Some(name) = fn_def.name().and_then(|n| n.text()) else {
+        return written();
+    };
+
+    let mut printer = TypePrinter::new(module);
+    let params: Vec<String> = names
+        .iter()
+        .zip(param_tys)
+        .map(|(name, ty)| format!("{} :: {}", name, printer.print(ty)))
+        .collect();
+    let mut signature = format!("fn {}({})", name, params.join(", "));
+    if !matches!(ret.as_ref(), Ty::Tuple(elems) if elems.is_empty()) {
+        signature.push_str(" -> ");
+        signature.push_str(&printer.print(ret));
+    }
+    signature
+}
+
+/// A parameter's name or pattern, without its type annotation.
+fn param_name(param: &SyntaxNode) -> String {
+    let tokens: Vec<SyntaxToken> = significant_tokens(param)
+        .take_while(|t| t.kind() != SyntaxKind::COLON_COLON)
+        .collect();
+    if tokens.is_empty() {
+        "_".to_string()
+    } else {
+        join_tokens(tokens.iter().map(|t| t.text()))
+    }
+}
+
+/// Renders inferred types in source syntax. Type variables are named `T`,
+/// `U`, `V`, ... in order of appearance, and types defined in the module
+/// being documented lose their module prefix.
+struct TypePrinter<'a> {
+    module: &'a str,
+    vars: Vec<u32>,
+}
+
+impl<'a> TypePrinter<'a> {
+    fn new(module: &'a str) -> Self {
+        TypePrinter { module, vars: Vec::new() }
+    }
+
+    fn print(&mut self, ty: &Ty) -> String {
+        match ty {
+            Ty::Var(var) => {
+                let index = match self.vars.iter().position(|v| *v == var.0) {
+                    Some(index) => index,
+                    None => {
+                        self.vars.push(var.0);
+                        self.vars.len() - 1
+                    }
+                };
+                match ["T", "U", "V", "W"].get(index) {
+                    Some(name) => name.to_string(),
+                    None => format!("T{}", index),
+                }
+            }
+            Ty::Con(con) if con.display_prefix.as_deref() == Some(self.module) => con.name.clone(),
+            Ty::Con(con) => con.to_string(),
+            Ty::Fun(params, ret) => format!("Fun({}) -> {}", self.list(params), self.print(ret)),
+            Ty::App(con, args) if args.is_empty() => self.print(con),
+            Ty::App(con, args) => format!("{}<{}>", self.print(con), self.list(args)),
+            Ty::Tuple(elems) => format!("({})", self.list(elems)),
+            Ty::Never => "Never".to_string(),
+        }
+    }
+
+    fn list(&mut self, tys: &[Ty]) -> String {
+        tys.iter().map(|ty| self.print(ty)).collect::<Vec<_>>().join(", ")
+    }
+}
+
+fn significant_tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|t| !t.kind().is_trivia())
+}
+
+/// Source text of a node, without comments and with normalized spacing.
+fn source_text(node: &SyntaxNode) -> String {
+    let tokens: Vec<SyntaxToken> = significant_tokens(node).collect();
+    join_tokens(tokens.iter().map(|t| t.text()))
+}
+
+/// The declaration line of an item as written, e.g. `struct Box<T>` or
+/// `call Get() :: Int`: its source text without `pub`, up to its body.
+fn header(node: &SyntaxNode) -> String {
+    let tokens: Vec<SyntaxToken> = significant_tokens(node)
+        .filter(|t| {
+            !t.parent_ancestors()
+                .any(|n| n.kind() == SyntaxKind::VISIBILITY)
+        })
+        .take_while(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::DO_KW | SyntaxKind::EQ | SyntaxKind::WHEN_KW | SyntaxKind::END_KW
+            )
+        })
+        .collect();
+    join_tokens(tokens.iter().map(|t| t.text()))
+}
+
+/// Join token texts with the spacing the formatter uses in signatures.
+/// (The syntax tree keeps no whitespace.)
+fn join_tokens<'t>(tokens: impl IntoIterator<Item = &'t str>) -> String {
+    let is_word = |s: &str| s.chars().all(|c| c.is_alphanumeric() || c == '_');
+    let mut out = String::new();
+    let mut prev: Option<&str> = None;
+    for tok in tokens {
+        if let Some(prev) = prev {
+            let tight = matches!(tok, ")" | "]" | ">" | "," | "." | "?" | "!")
+                || matches!(prev, "(" | "[" | "<" | "." | "!")
+                || (matches!(tok, "(" | "<") && is_word(prev));
+            if !tight {
+                out.push(' ');
+            }
+        }
+        out.push_str(tok);
+        prev = Some(tok);
+    }
+    out
+}
+
+// ── Rendering ───────────────────────────────────────────────────────
+
+/// Where each documented type (struct, sum type, interface, service) is
+/// defined: name -> (module, anchor), for cross-links in signatures.
+struct TypeIndex<'a> {
+    types: HashMap<&'a str, Vec<(&'a str, String)>>,
+    extension: &'static str,
+}
+
+impl<'a> TypeIndex<'a> {
+    fn new(modules: &'a [ModuleDoc], format: DocFormat) -> Self {
+        let mut types: HashMap<&str, Vec<(&str, String)>> = HashMap::new();
+        for module in modules {
+            for item in module.items.iter().filter(|i| i.kind != ItemKind::Function) {
+                types
+                    .entry(item.name.as_str())
+                    .or_default()
+                    .push((module.name.as_str(), item.anchor()));
+            }
+        }
+        TypeIndex { types, extension: format.extension() }
+    }
+
+    /// Link target of a type name (`Point` or `Geometry.Point`) used in
+    /// `module`. Unqualified names prefer the module's own types.
+    fn href(&self, word: &str, module: &str) -> Option<String> {
+        let (qualifier, name) = match word.rsplit_once('.') {
+            Some((qualifier, name)) => (Some(qualifier), name),
+            None => (None, word),
+        };
+        let defs = self.types.get(name)?;
+        let (target, anchor) = match qualifier {
+            Some(qualifier) => defs.iter().find(|(m, _)| *m == qualifier)?,
+            None => defs.iter().find(|(m, _)| *m == module).unwrap_or(&defs[0]),
+        };
+        Some(if *target == module {
+            format!("#{}", anchor)
+        } else {
+            format!("{}.{}#{}", target, self.extension, anchor)
+        })
+    }
+
+    /// HTML for a signature, with known type names linked.
+    fn link(&self, signature: &str, module: &str) -> String {
+        let mut html = String::new();
+        let mut rest = signature;
+        while let Some(c) = rest.chars().next() {
+            let len = if c.is_alphanumeric() || c == '_' {
+                rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                    .unwrap_or(rest.len())
+            } else {
+                c.len_utf8()
+            };
+            let (word, tail) = rest.split_at(len);
+            match self.href(word, module).filter(|_| c.is_uppercase()) {
+                Some(href) => html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(&href),
+                    escape_html(word)
+                )),
+                None => html.push_str(&escape_html(word)),
+            }
+            rest = tail;
+        }
+        html
+    }
+}
+
+/// Write the documentation for `modules` to `out_dir`. Returns the path of
+/// the index page.
+fn write_docs(
+    out_dir: &Path,
+    title: &str,
+    modules: &[ModuleDoc],
+    format: DocFormat,
+) -> Result<PathBuf, String> {
+    std::fs::create_dir_all(out_dir)
+        .map_err(|e| format!("Failed to create directory '{}': {}", out_dir.display(), e))?;
+    let index = TypeIndex::new(modules, format);
+    let ext = format.extension();
+
+    let mut files: Vec<(String, String)> = Vec::new();
+    match format {
+        DocFormat::Html => {
+            files.push(("index.html".to_string(), html_index(title, modules)));
+            for module in modules {
+                files.push((format!("{}.html", module.name), html_module(title, module, modules, &index)));
+            }
+            files.push(("style.css".to_string(), STYLE_CSS.to_string()));
+            files.push(("search.js".to_string(), SEARCH_JS.to_string()));
+            files.push((
+                "search-index.js".to_string(),
+                format!("window.searchIndex = {};\n", search_index(modules)),
+            ));
+        }
+        DocFormat::Markdown => {
+            files.push(("index.md".to_string(), markdown_index(title, modules)));
+            for module in modules {
+                files.push((format!("{}.md", module.name), markdown_module(module, &index)));
+            }
+        }
+    }
+
+    for (name, contents) in &files {
+        let path = out_dir.join(name);
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    }
+    Ok(out_dir.join(format!("index.{}", ext)))
+}
+
+/// First paragraph of a doc comment, on one line.
+fn summary(doc: &str) -> String {
+    doc.split("\n\n")
+        .next()
+        .unwrap_or("")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// JSON array of every module and item, searched by `search.js`.
+fn search_index(modules: &[ModuleDoc]) -> String {
+    let mut entries = Vec::new();
+    for module in modules {
+        entries.push(serde_json::json!({
+            "name": module.name,
+            "kind": "module",
+            "href": format!("{}.html", module.name),
+            "summary": module.doc.as_deref().map(summary).unwrap_or_default(),
+        }));
+        for item in &module.items {
+            entries.push(serde_json::json!({
+                "name": format!("{}.{}", module.name, item.name),
+                "kind": item.kind.keyword(),
+                "href": format!("{}.html#{}", module.name, item.anchor()),
+                "summary": item.doc.as_deref().map(summary).unwrap_or_default(),
+            }));
+        }
+    }
+    serde_json::Value::Array(entries).to_string()
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// HTML for a doc comment: paragraphs, ``` fenced code blocks and `code`
+/// spans. Doc comments are otherwise shown as written.
+fn doc_html(doc: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    fn flush(html: &mut String, paragraph: &mut Vec<&str>) {
+        if paragraph.is_empty() {
+            return;
+        }
+        let text = escape_html(&paragraph.join(" "));
+        html.push_str("<p>");
+        for (i, part) in text.split('`').enumerate() {
+            if i % 2 == 1 {
+                html.push_str(&format!("<code>{}</code>", part));
+            } else {
+                html.push_str(part);
+            }
+        }
+        html.push_str("</p>\n");
+        paragraph.clear();
+    }
+
+    for line in doc.lines() {
+        if line.trim_start().starts_with("```") {
+            match code.take() {
+                Some(lines) => html.push_str(&format!(
+                    "<pre><code>{}</code></pre>\n",
+                    escape_html(&lines.join("\n"))
+                )),
+                None => {
+                    flush(&mut html, &mut paragraph);
+                    code = Some(Vec::new());
+                }
+            }
+        } else if let Some(lines) = code.as_mut() {
+            lines.push(line);
+        } else if line.trim().is_empty() {
+            flush(&mut html, &mut paragraph);
+        } else {
+            paragraph.push(line.trim());
+        }
+    }
+    if let Some(lines) = code {
+        html.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(&lines.join("\n"))));
+    }
+    flush(&mut html, &mut paragraph);
+    html
+}
+
+fn html_page(title: &str, page_title: &str, modules: &[ModuleDoc], content: &str) -> String {
+    let nav: String = modules
+        .iter()
+        .map(|m| format!("<li><a href=\"{0}.html\">{0}</a></li>", escape_html(&m.name)))
+        .collect();
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>{page_title}</title>
+<link rel=\"stylesheet\" href=\"style.css\">
+</head>
+<body>
+<nav>
+<a class=\"project\" href=\"index.html\">{title}</a>
+<input id=\"search\" type=\"search\" placeholder=\"Search\" autocomplete=\"off\">
+<ul id=\"search-results\"></ul>
+<h2>Modules</h2>
+<ul>{nav}</ul>
+</nav>
+<main>
+{content}</main>
+<script src=\"search-index.js\"></script>
+<script src=\"search.js\"></script>
+</body>
+</html>
+",
+        title = escape_html(title),
+        page_title = escape_html(page_title),
+    )
+}
+
+fn html_index(title: &str, modules: &[ModuleDoc]) -> String {
+    let mut content = format!("<h1>{}</h1>\n<table class=\"modules\">\n", escape_html(title));
+    for module in modules {
+        content.push_str(&format!(
+            "<tr><td><a href=\"{0}.html\">{0}</a></td><td>{1}</td></tr>\n",
+            escape_html(&module.name),
+            escape_html(&module.doc.as_deref().map(summary).unwrap_or_default()),
+        ));
+    }
+    content.push_str("</table>\n");
+    html_page(title, title, modules, &content)
+}
+
+fn html_module(title: &str, module: &ModuleDoc, modules: &[ModuleDoc], index: &TypeIndex) -> String {
+    let mut content = format!(
+        "<h1>Module <code>{}</code></h1>\n<p class=\"source\">{}</p>\n",
+        escape_html(&module.name),
+        escape_html(&module.path)
+    );
+    if let Some(doc) = &module.doc {
+        content.push_str(&doc_html(doc));
+    }
+
+    for kind in ITEM_KINDS {
+        let items: Vec<&ItemDoc> = module.items.iter().filter(|i| i.kind == kind).collect();
+        if items.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("<h2>{}</h2>\n", kind.section()));
+        for item in items {
+            content.push_str(&format!(
+                "<section class=\"item\" id=\"{}\">\n<pre class=\"signature\"><code>{}</code></pre>\n",
+                escape_html(&item.anchor()),
+                index.link(&item.signature, &module.name)
+            ));
+            if let Some(doc) = &item.doc {
+                content.push_str(&doc_html(doc));
+            }
+            if !item.members.is_empty() {
+                content.push_str("<ul class=\"members\">\n");
+                for member in &item.members {
+                    content.push_str(&format!(
+                        "<li><code>{}</code>{}</li>\n",
+                        index.link(&member.signature, &module.name),
+                        member.doc.as_deref().map(doc_html).unwrap_or_default()
+                    ));
+                }
+                content.push_str("</ul>\n");
+            }
+            content.push_str("</section>\n");
+        }
+    }
+
+    html_page(title, &format!("{} - {}", module.name, title), modules, &content)
+}
+
+fn markdown_index(title: &str, modules: &[ModuleDoc]) -> String {
+    let mut out = format!("# {}\n\n| Module | Summary |\n| --- | --- |\n", title);
+    for module in modules {
+        out.push_str(&format!(
+            "| [{0}]({0}.md) | {1} |\n",
+            module.name,
+            module.doc.as_deref().map(summary).unwrap_or_default().replace('|', "\\|")
+        ));
+    }
+    out
+}
+
+/// Markdown page of a module. Signatures are HTML `<pre>` blocks so that
+/// type names can link to other pages.
+fn markdown_module(module: &ModuleDoc, index: &TypeIndex) -> String {
+    let mut out = format!("# Module `{}`\n\nSource: `{}`\n\n", module.name, module.path);
+    if let Some(doc) = &module.doc {
+        out.push_str(doc);
+        out.push_str("\n\n");
+    }
+
+    for kind in ITEM_KINDS {
+        let items: Vec<&ItemDoc> = module.items.iter().filter(|i| i.kind == kind).collect();
+        if items.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("## {}\n\n", kind.section()));
+        for item in items {
+            out.push_str(&format!(
+                "<a id=\"{}\"></a>\n\n### {} {}\n\n<pre><code>{}</code></pre>\n\n",
+                item.anchor(),
+                kind.keyword(),
+                item.name,
+                index.link(&item.signature, &module.name)
+            ));
+            if let Some(doc) = &item.doc {
+                out.push_str(doc);
+                out.push_str("\n\n");
+            }
+            for member in &item.members {
+                out.push_str(&format!("- <code>{}</code>", index.link(&member.signature, &module.name)));
+                if let Some(doc) = &member.doc {
+                    out.push_str(": ");
+                    out.push_str(&doc.replace('\n', "\n  "));
+                }
+                out.push('\n');
+            }
+            if !item.members.is_empty() {
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+const STYLE_CSS: &str = "body { margin: 0; display: flex; font-family: system-ui, sans-serif; line-height: 1.5; color: #1f2328; }
+nav { width: 16rem; flex-shrink: 0; padding: 1rem; border-right: 1px solid #d0d7de; min-height: 100vh; box-sizing: border-box; }
+nav ul { list-style: none; padding: 0; }
+nav h2 { font-size: 0.9rem; text-transform: uppercase; color: #59636e; }
+nav .project { font-weight: bold; font-size: 1.2rem; text-decoration: none; }
+#search { width: 100%; margin: 1rem 0 0.5rem; padding: 0.3rem; box-sizing: border-box; }
+#search-results li { margin-bottom: 0.3rem; font-size: 0.9rem; }
+main { flex: 1; max-width: 56rem; padding: 1rem 2rem; }
+a { color: #0969da; }
+pre, code { font-family: ui-monospace, monospace; }
+pre { background: #f6f8fa; padding: 0.6rem 0.8rem; border-radius: 6px; overflow-x: auto; }
+.item { margin-bottom: 2rem; }
+.signature { font-weight: 600; }
+.source { color: #59636e; }
+table.modules td { padding: 0.2rem 1rem 0.2rem 0; vertical-align: top; }
+";
+
+const SEARCH_JS: &str = "(function () {
+  var input = document.getElementById(\"search\");
+  var results = document.getElementById(\"search-results\");
+  if (!input || !results || !window.searchIndex) return;
+  input.addEventListener(\"input\", function () {
+    var query = input.value.trim().toLowerCase();
+    results.innerHTML = \"\";
+    if (!query) return;
+    window.searchIndex
+      .filter(function (entry) { return entry.name.toLowerCase().indexOf(query) !== -1; })
+      .slice(0, 20)
+      .forEach(function (entry) {
+        var item = document.createElement(\"li\");
+        var link = document.createElement(\"a\");
+        link.href = entry.href;
+        link.textContent = entry.kind + \" \" + entry.name;
+        item.appendChild(link);
+        if (entry.summary) {
+          var summary = document.createElement(\"div\");
+          summary.textContent = entry.summary;
+          item.appendChild(summary);
+        }
+        results.appendChild(item);
+      });
+  });
+})();
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(name: &str, source: &str) -> ModuleDoc {
+        let parse = mesh_parser::parse(source);
+        let typeck = mesh_typeck::check(&parse);
+        collect_module(name, "geometry.mpl", &parse.tree(), Some(&typeck))
+    }
+
+    const GEOMETRY: &str = "##! Shapes and their areas.
+
+## A point.
+pub struct Point do
+  x :: Int
+  y :: Int
+end
+
+## A shape.
+pub type Shape do
+  Circle(Float)
+  Square(Float)
+end
+
+## Things with a name.
+pub interface Named do
+  ## The name.
+  fn name(self) -> String
+end
+
+## Origin of the plane.
+pub fn origin() -> Point do
+  Point { x: 0, y: 0 }
+end
+
+## Returns its argument.
+pub fn id(x) = x
+
+fn helper() = 1
+";
+
+    #[test]
+    fn test_collect_module_documents_public_items_with_inferred_types() {
+        let doc = module("Geometry", GEOMETRY);
+        assert_eq!(doc.doc.as_deref(), Some("Shapes and their areas."));
+
+        let signatures: Vec<(&str, &str)> = doc
+            .items
+            .iter()
+            .map(|i| (i.name.as_str(), i.signature.as_str()))
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![
+                ("Point", "struct Point"),
+                ("Shape", "type Shape"),
+                ("Named", "interface Named"),
+                ("origin", "fn origin() -> Point"),
+                ("id", "fn id(x :: T) -> T"),
+            ]
+        );
+
+        let point = &doc.items[0];
+        assert_eq!(point.doc.as_deref(), Some("A point."));
+        let fields: Vec<&str> = point.members.iter().map(|m| m.signature.as_str()).collect();
+        assert_eq!(fields, vec!["x :: Int", "y :: Int"]);
+
+        let named = &doc.items[2];
+        assert_eq!(named.members[0].signature, "fn name(self) -> String");
+        assert_eq!(named.members[0].doc.as_deref(), Some("The name."));
+    }
+
+    #[test]
+    fn test_signatures_link_types_across_modules() {
+        let modules = vec![
+            module("Geometry", GEOMETRY),
+            module("Main", "pub fn center(p :: Geometry.Point) -> Point = p"),
+        ];
+        let index = TypeIndex::new(&modules, DocFormat::Html);
+
+        assert_eq!(
+            index.link("fn f(p :: Point) -> Int", "Geometry"),
+            "fn f(p :: <a href=\"#struct.Point\">Point</a>) -&gt; Int"
+        );
+        assert_eq!(
+            index.link("List<Geometry.Shape>", "Main"),
+            "List&lt;<a href=\"Geometry.html#type.Shape\">Geometry.Shape</a>&gt;"
+        );
+    }
+
+    #[test]
+    fn test_write_docs_html_and_markdown() {
+        let tmp = tempfile::tempdir().unwrap();
+        let modules = vec![module("Geometry", GEOMETRY)];
+
+        let html_dir = tmp.path().join("html");
+        let index = write_docs(&html_dir, "app", &modules, DocFormat::Html).unwrap();
+        assert_eq!(index, html_dir.join("index.html"));
+        let page = std::fs::read_to_string(html_dir.join("Geometry.html")).unwrap();
+        assert!(page.contains("id=\"fn.origin\""), "page: {}", page);
+        assert!(page.contains("<p>A point.</p>"), "page: {}", page);
+        let search = std::fs::read_to_string(html_dir.join("search-index.js")).unwrap();
+        assert!(search.contains("\"href\":\"Geometry.html#fn.id\""), "index: {}", search);
+        assert!(!search.contains("helper"), "index: {}", search);
+
+        let md_dir = tmp.path().join("md");
+        write_docs(&md_dir, "app", &modules, DocFormat::Markdown).unwrap();
+        let page = std::fs::read_to_string(md_dir.join("Geometry.md")).unwrap();
+        assert!(page.contains("### fn origin"), "page: {}", page);
+        assert!(page.contains("fn origin() -&gt; <a href=\"#struct.Point\">Point</a>"), "page: {}", page);
+        let index = std::fs::read_to_string(md_dir.join("index.md")).unwrap();
+        assert!(index.contains("| [Geometry](Geometry.md) | Shapes and their areas. |"));
+    }
+
+    #[test]
+    fn test_doc_html_paragraphs_and_code() {
+        assert_eq!(
+            doc_html("Adds `a` and\nb.\n\n```\nadd(1, 2)\n```"),
+            "<p>Adds <code>a</code> and b.</p>\n<pre><code>add(1, 2)</code></pre>\n"
+        );
+    }
+}
@@ -3,6 +3,11 @@
 //! Provides the `meshc` command with the following subcommands:
 //!
 //! - `meshc build <dir>` - Compile a Mesh project to a native binary
+//! - `meshc run [path] -- args...` - Compile (cached) and run a project or single file
+//! - `meshc test [path]` - Run the `test` blocks of a project or single file
+//! - `meshc check [path]` - Type-check a project or single file without compiling
+//! - `meshc doc [path]` - Generate HTML or Markdown API docs from `##` doc comments
+//! - `meshc explain [code]` - Explain a diagnostic code, or list all codes
 //! - `meshc init <name>` - Initialize a new Mesh project
 //! - `meshc deps [dir]` - Resolve and fetch dependencies
 //! - `meshc fmt <path>` - Format Mesh source files in-place
@@ -11,7 +16,9 @@
 //! - `meshc lsp` - Start the LSP server (communicates via stdin/stdout)
 //!
 //! Options:
+//! - `--release` - Build with `[profile.release]` from mesh.toml instead of `[profile.dev]`
 //! - `--opt-level` - Optimization level (0 = debug, 2 = release)
+//! - `--debug` / `--no-debug` - Force DWARF debug info on or off
 //! - `--emit-llvm` - Emit LLVM IR (.ll) alongside the binary
 //! - `--output` - Output path for the compiled binary
 //! - `--target` - Target triple for cross-compilation
@@ -22,8 +29,12 @@
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+mod cache;
 mod discovery;
+mod doc;
 mod migrate;
+mod run;
+mod test_cmd;
 
 use std::path::{Path, PathBuf};
 use std::process;
@@ -31,6 +42,7 @@ use std::process;
 use clap::{Parser, Subcommand};
 
 use mesh_typeck::diagnostics::DiagnosticOptions;
+use mesh_typeck::lint::{LintConfig, LintLevel};
 
 #[derive(Parser)]
 #[command(name = "meshc", version, about = "The Mesh compiler")]
@@ -46,9 +58,21 @@ enum Commands {
         /// Path to the project directory (must contain main.mpl)
         dir: PathBuf,
 
-        /// Optimization level (0 = debug, 2 = release)
-        #[arg(long = "opt-level", default_value = "0")]
-        opt_level: u8,
+        /// Build with the release profile (default: the dev profile)
+        #[arg(long)]
+        release: bool,
+
+        /// Optimization level, overriding the profile (0 = debug, 2 = release)
+        #[arg(long = "opt-level")]
+        opt_level: Option<u8>,
+
+        /// Emit DWARF debug info (the default at --opt-level 0)
+        #[arg(long)]
+        debug: bool,
+
+        /// Omit DWARF debug info, overriding the profile
+        #[arg(long = "no-debug", conflicts_with = "debug")]
+        no_debug: bool,
 
         /// Emit LLVM IR (.ll file) alongside the binary
         #[arg(long = "emit-llvm")]
@@ -70,6 +94,99 @@ enum Commands {
         #[arg(long = "no-color")]
         no_color: bool,
     },
+    /// Compile and run a Mesh project or a single .mpl file
+    Run {
+        /// Project directory or single .mpl file (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Build with the release profile (default: the dev profile)
+        #[arg(long)]
+        release: bool,
+
+        /// Optimization level, overriding the profile (0 = debug, 2 = release)
+        #[arg(long = "opt-level")]
+        opt_level: Option<u8>,
+
+        /// Output diagnostics as JSON (one object per line) instead of human-readable format
+        #[arg(long)]
+        json: bool,
+
+        /// Disable colorized output
+        #[arg(long = "no-color")]
+        no_color: bool,
+
+        /// Arguments passed to the program (available through `Env.args()`)
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Run the test blocks of a Mesh project or a single .mpl file
+    Test {
+        /// Project directory or single .mpl file (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Only run tests whose name contains this text
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Build with the release profile (default: the dev profile)
+        #[arg(long)]
+        release: bool,
+
+        /// Optimization level, overriding the profile (0 = debug, 2 = release)
+        #[arg(long = "opt-level")]
+        opt_level: Option<u8>,
+
+        /// Output diagnostics and test results as JSON (one object per line)
+        #[arg(long)]
+        json: bool,
+
+        /// Disable colorized output
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+    /// Type-check a Mesh project or a single .mpl file without compiling it
+    Check {
+        /// Project directory or single .mpl file (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output diagnostics as JSON (one object per line) instead of human-readable format
+        #[arg(long)]
+        json: bool,
+
+        /// Disable colorized output
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+    /// Generate API documentation for a Mesh project or a single .mpl file
+    Doc {
+        /// Project directory or single .mpl file (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "html")]
+        format: doc::DocFormat,
+
+        /// Output directory (default: <project>/target/doc)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Output diagnostics as JSON (one object per line) instead of human-readable format
+        #[arg(long)]
+        json: bool,
+
+        /// Disable colorized output
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+    /// Explain a diagnostic code (e.g. E0001) with an example and a fix
+    Explain {
+        /// The diagnostic code; lists every code when omitted
+        code: Option<String>,
+    },
     /// Initialize a new Mesh project
     Init {
         /// Project name (creates directory with this name)
@@ -134,7 +251,10 @@ fn main() {
     match cli.command {
         Commands::Build {
             dir,
+            release,
             opt_level,
+            debug,
+            no_debug,
             emit_llvm,
             output,
             target,
@@ -145,23 +265,109 @@ fn main() {
                 color: !no_color && !json,
                 json,
             };
-            if let Err(e) =
-                build(&dir, opt_level, emit_llvm, output.as_deref(), target.as_deref(), &diag_opts)
-            {
-                if json {
-                    // In JSON mode, emit the final error as JSON too.
-                    let msg = serde_json::json!({
-                        "code": "C0001",
-                        "severity": "error",
-                        "message": e,
-                        "file": "",
-                        "spans": [],
-                        "fix": null
-                    });
-                    eprintln!("{}", msg);
-                } else {
-                    eprintln!("error: {}", e);
+            let flags = BuildFlags {
+                release,
+                opt_level,
+                debug: (debug || no_debug).then_some(debug),
+                emit_llvm,
+                output: output.as_deref(),
+                target: target.as_deref(),
+            };
+            if let Err(e) = build(&dir, &flags, &diag_opts) {
+                report_fatal_error(&e, json);
+                process::exit(1);
+            }
+        }
+        Commands::Run {
+            path,
+            release,
+            opt_level,
+            json,
+            no_color,
+            args,
+        } => {
+            let diag_opts = DiagnosticOptions {
+                color: !no_color && !json,
+                json,
+            };
+            let flags = BuildFlags {
+                release,
+                opt_level,
+                ..Default::default()
+            };
+            match run::run_command(&path, &flags, &args, &diag_opts) {
+                Ok(code) => process::exit(code),
+                Err(e) => {
+                    report_fatal_error(&e, json);
+                    process::exit(1);
+                }
+            }
+        }
+        Commands::Test {
+            path,
+            filter,
+            release,
+            opt_level,
+            json,
+            no_color,
+        } => {
+            let diag_opts = DiagnosticOptions {
+                color: !no_color && !json,
+                json,
+            };
+            let options = test_cmd::TestOptions {
+                filter: filter.as_deref(),
+                json,
+                flags: BuildFlags {
+                    release,
+                    opt_level,
+                    ..Default::default()
+                },
+            };
+            match test_cmd::test_command(&path, &options, &diag_opts) {
+                Ok(code) => process::exit(code),
+                Err(e) => {
+                    report_fatal_error(&e, json);
+                    process::exit(1);
+                }
+            }
+        }
+        Commands::Check {
+            path,
+            json,
+            no_color,
+        } => {
+            let diag_opts = DiagnosticOptions {
+                color: !no_color && !json,
+                json,
+            };
+            if let Err(e) = check(&path, &diag_opts) {
+                report_fatal_error(&e, json);
+                process::exit(1);
+            }
+        }
+        Commands::Doc {
+            path,
+            format,
+            output,
+            json,
+            no_color,
+        } => {
+            let diag_opts = DiagnosticOptions {
+                color: !no_color && !json,
+                json,
+            };
+            match doc::doc_command(&path, format, output.as_deref(), &diag_opts) {
+                Ok(index) => eprintln!("  Documented: {}", index.display()),
+                Err(e) => {
+                    report_fatal_error(&e, json);
+                    process::exit(1);
                 }
+            }
+        }
+        Commands::Explain { code } => {
+            if let Err(e) = explain(code.as_deref()) {
+                eprintln!("error: {}", e);
                 process::exit(1);
             }
         }
@@ -236,13 +442,46 @@ fn main() {
     }
 }
 
+/// Print a fatal compiler error, as a `C0001` JSON diagnostic in JSON mode.
+fn report_fatal_error(message: &str, json: bool) {
+    if json {
+        // In JSON mode, emit the final error as JSON too.
+        let msg = serde_json::json!({
+            "code": "C0001",
+            "severity": "error",
+            "message": message,
+            "file": "",
+            "spans": [],
+            "fix": null
+        });
+        eprintln!("{}", msg);
+    } else {
+        eprintln!("error: {}", message);
+    }
+}
+
+/// Command-line settings of `meshc build`, `meshc run` and `meshc test`.
+/// Flags that are set take precedence over the build profile in mesh.toml.
+#[derive(Default)]
+pub(crate) struct BuildFlags<'a> {
+    /// Use `[profile.release]` instead of `[profile.dev]`.
+    pub release: bool,
+    /// Optimization level; `None` uses the profile's.
+    pub opt_level: Option<u8>,
+    /// Force DWARF debug info on or off; `None` uses the profile's.
+    pub debug: Option<bool>,
+    /// Emit LLVM IR (.ll file) alongside the binary.
+    pub emit_llvm: bool,
+    /// Output path of the binary; `None` uses the profile's.
+    pub output: Option<&'a Path>,
+    /// Target triple for cross-compilation; `None` for the host.
+    pub target: Option<&'a str>,
+}
+
 /// Execute the build pipeline: discover all .mpl files -> parse -> typecheck entry -> codegen -> link.
 pub(crate) fn build(
     dir: &Path,
-    opt_level: u8,
-    emit_llvm: bool,
-    output: Option<&Path>,
-    target: Option<&str>,
+    flags: &BuildFlags,
     diag_opts: &DiagnosticOptions,
 ) -> Result<(), String> {
     // Validate the project directory
@@ -268,20 +507,485 @@ pub(crate) fn build(
     // Build the project: discover all files, parse, build module graph
     let project = discovery::build_project(dir)?;
 
+    let profile = load_profile(dir, flags.release)?;
+    let options = resolve_options(flags, &profile, dir.join("target").join("cache"));
+
+    // Determine output path
+    let project_name = dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("output");
+    let output_path = match (flags.output, &profile.output) {
+        (Some(p), _) => p.to_path_buf(),
+        (None, Some(p)) => dir.join(p),
+        (None, None) => dir.join(project_name),
+    };
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+    }
+
+    compile_project(dir, &project, &output_path, &options, diag_opts)?;
+
+    eprintln!("  Compiled: {}", output_path.display());
+
+    Ok(())
+}
+
+/// Execute the check pipeline: discover all .mpl files -> parse -> typecheck all modules.
+///
+/// Reports the same diagnostics as `build` but stops before MIR lowering, so
+/// no LLVM code generation or linking takes place. `path` may be a project
+/// directory (no `main.mpl` required) or a single `.mpl` file.
+fn check(path: &Path, diag_opts: &DiagnosticOptions) -> Result<(), String> {
+    let (root, project) = load_project(path)?;
+
+    check_project(&root, &project, None, false, diag_opts)?;
+
+    if !diag_opts.json {
+        let count = project.compilation_order.len();
+        eprintln!(
+            "  Checked: {} module{}",
+            count,
+            if count == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(())
+}
+
+/// Execute the explain subcommand: print the explanation of `code`, or a
+/// one-line summary of every explained code.
+fn explain(code: Option<&str>) -> Result<(), String> {
+    use mesh_typeck::explain::{explain, EXPLANATIONS};
+
+    match code {
+        Some(code) => {
+            let explanation = explain(code)
+                .ok_or_else(|| format!("no explanation for diagnostic code `{}`", code))?;
+            print!("{}", explanation.render());
+        }
+        None => {
+            for explanation in EXPLANATIONS {
+                println!("{}  {}", explanation.code, explanation.title);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Discover and parse a project directory (no `main.mpl` required) or a
+/// single `.mpl` file. Returns the root that module paths are relative to.
+/// Shared by `meshc check` and `meshc doc`.
+fn load_project(path: &Path) -> Result<(PathBuf, discovery::ProjectData), String> {
+    if !path.exists() {
+        return Err(format!("Path '{}' does not exist", path.display()));
+    }
+
+    if path.is_file() {
+        if path.extension().and_then(|e| e.to_str()) != Some("mpl") {
+            return Err(format!("'{}' is not a .mpl file", path.display()));
+        }
+        let root = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        Ok((root, discovery::build_single_file_project(path)?))
+    } else {
+        Ok((path.to_path_buf(), discovery::build_project(path)?))
+    }
+}
+
+/// Read lint levels from the `[lints]` section of `root/mesh.toml`.
+///
+/// Without a manifest every lint is at its default level.
+fn load_lint_config(root: &Path) -> Result<LintConfig, String> {
+    let manifest_path = root.join("mesh.toml");
+    if !manifest_path.is_file() {
+        return Ok(LintConfig::default());
+    }
+    let manifest = mesh_pkg::Manifest::from_file(&manifest_path)?;
+    LintConfig::from_settings(
+        manifest
+            .lints
+            .iter()
+            .map(|(name, level)| (name.as_str(), level.as_str())),
+    )
+    .map_err(|e| format!("{}: {}", manifest_path.display(), e))
+}
+
+/// Resolve code generation settings: command-line flags, then the build
+/// profile, then defaults. Shared by `meshc build`, `meshc run`, and
+/// `meshc test` so all three compile a project the same way.
+pub(crate) fn resolve_options<'a>(
+    flags: &BuildFlags<'a>,
+    profile: &mesh_pkg::Profile,
+    cache_dir: PathBuf,
+) -> CompileOptions<'a> {
+    let opt_level = flags
+        .opt_level
+        .or(profile.opt_level)
+        .unwrap_or(if flags.release { 2 } else { 0 });
+    CompileOptions {
+        opt_level,
+        debug: flags.debug.or(profile.debug).unwrap_or(opt_level == 0),
+        lto: profile.lto.unwrap_or(false),
+        overflow_checks: profile.overflow_checks.unwrap_or(opt_level == 0),
+        runtime: mesh_codegen::RuntimeConfig {
+            schedulers: profile.schedulers.unwrap_or(0),
+            stack_size: profile.stack_size.unwrap_or(0),
+        },
+        emit_llvm: flags.emit_llvm,
+        target: flags.target,
+        cache_dir: Some(cache_dir),
+    }
+}
+
+/// Read the `[profile.release]` or `[profile.dev]` section of `root/mesh.toml`.
+///
+/// Without a manifest the profile is empty and every setting is a default.
+pub(crate) fn load_profile(root: &Path, release: bool) -> Result<mesh_pkg::Profile, String> {
+    let manifest_path = root.join("mesh.toml");
+    if !manifest_path.is_file() {
+        return Ok(mesh_pkg::Profile::default());
+    }
+    let manifest = mesh_pkg::Manifest::from_file(&manifest_path)
+        .map_err(|e| format!("{}: {}", manifest_path.display(), e))?;
+    Ok(manifest.profile.get(release).clone())
+}
+
+/// Code generation settings shared by `meshc build`, `meshc run`, and `meshc test`.
+pub(crate) struct CompileOptions<'a> {
+    /// Optimization level (0 = debug, 2 = release).
+    pub opt_level: u8,
+    /// Emit DWARF debug info.
+    pub debug: bool,
+    /// Optimize the whole program at link time.
+    pub lto: bool,
+    /// Panic on `Int` overflow instead of wrapping.
+    pub overflow_checks: bool,
+    /// Scheduler count and actor stack size of the generated `main`.
+    pub runtime: mesh_codegen::RuntimeConfig,
+    /// Emit LLVM IR (.ll file) alongside the binary.
+    pub emit_llvm: bool,
+    /// Target triple for cross-compilation; `None` for the host.
+    pub target: Option<&'a str>,
+    /// Directory of the incremental compilation cache; `None` disables it.
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl CompileOptions<'_> {
+    fn codegen(&self) -> mesh_codegen::CodegenOptions<'_> {
+        mesh_codegen::CodegenOptions {
+            opt_level: self.opt_level,
+            debug: self.debug,
+            lto: self.lto,
+            target_triple: self.target,
+            runtime: self.runtime,
+            overflow_checks: self.overflow_checks,
+        }
+    }
+
+    /// Every setting that affects the generated object file, for its cache key.
+    fn fingerprint(&self) -> String {
+        format!(
+            "O{} debug={} lto={} overflow-checks={} {:?} {:?}",
+            self.opt_level,
+            self.debug,
+            self.lto,
+            self.overflow_checks,
+            self.runtime,
+            self.target
+        )
+    }
+}
+
+/// Compile an already-discovered project to a native binary at `output_path`.
+///
+/// Reports parse and type-check diagnostics for every module (paths are shown
+/// relative to `root`), lowers all modules to MIR, merges them, and runs LLVM
+/// codegen and linking. Shared by `meshc build` and `meshc run`.
+pub(crate) fn compile_project(
+    root: &Path,
+    project: &discovery::ProjectData,
+    output_path: &Path,
+    options: &CompileOptions,
+    diag_opts: &DiagnosticOptions,
+) -> Result<(), String> {
     // Find the entry module
     let entry_id = project.compilation_order.iter()
         .copied()
         .find(|id| project.graph.get(*id).is_entry)
         .ok_or("No entry module found in module graph")?;
-    let _entry_idx = entry_id.0 as usize;
+    let entry_mir_idx = project
+        .compilation_order
+        .iter()
+        .position(|&id| id == entry_id)
+        .unwrap_or(0);
 
+    let cache = options.cache_dir.clone().map(cache::BuildCache::new);
+    let lowered = lower_project(root, project, cache.as_ref(), options.debug, diag_opts)?;
+    let mut merged_mir = mesh_codegen::merge_mir_modules(lowered.modules, entry_mir_idx);
+    resolve_source_locations(&mut merged_mir, root);
+
+    // Emit LLVM IR if requested
+    if options.emit_llvm {
+        let ll_path = output_path.with_extension("ll");
+        mesh_codegen::compile_mir_to_llvm_ir(&merged_mir, &ll_path, &options.codegen())?;
+        eprintln!("  LLVM IR: {}", ll_path.display());
+    }
+
+    // Compile to native binary
+    let settings = format!("build {}", options.fingerprint());
+    emit_binary(&merged_mir, output_path, options, cache.as_ref(), &lowered.units, &settings)
+}
+
+/// Compile the `test` blocks of every module into a test binary at `output_path`.
+///
+/// The project does not need an entry module; its `main` function is ignored.
+/// Returns the number of tests found. When there are none, nothing is built.
+pub(crate) fn compile_tests(
+    root: &Path,
+    project: &discovery::ProjectData,
+    output_path: &Path,
+    options: &CompileOptions,
+    diag_opts: &DiagnosticOptions,
+) -> Result<usize, String> {
+    let cache = options.cache_dir.clone().map(cache::BuildCache::new);
+    let lowered = lower_project(root, project, cache.as_ref(), options.debug, diag_opts)?;
+    let mut merged_mir = mesh_codegen::merge_mir_modules_for_tests(lowered.modules);
+    if merged_mir.tests.is_empty() {
+        return Ok(0);
+    }
+    resolve_source_locations(&mut merged_mir, root);
+
+    let settings = format!("test {}", options.fingerprint());
+    emit_binary(&merged_mir, output_path, options, cache.as_ref(), &lowered.units, &settings)?;
+
+    Ok(merged_mir.tests.len())
+}
+
+/// Run codegen for a merged module and link it.
+///
+/// With a cache, the functions each module contributes are compiled to an
+/// object of their own and the C `main` to `main.o`, and only objects whose
+/// key changed are rebuilt. LTO needs the whole program in one module, so
+/// LTO builds compile everything into `main.o`.
+fn emit_binary(
+    mir: &mesh_codegen::mir::MirModule,
+    output_path: &Path,
+    options: &CompileOptions,
+    cache: Option<&cache::BuildCache>,
+    units: &ModuleUnits,
+    settings: &str,
+) -> Result<(), String> {
+    let Some(cache) = cache else {
+        return mesh_codegen::compile_mir_to_binary(mir, output_path, &options.codegen(), None);
+    };
+
+    let binary = output_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("output");
+    let program_key = cache::program_object_key(&units.keys, settings);
+
+    if options.lto {
+        let object = unit_object(cache, binary, "main", &program_key, |path| {
+            mesh_codegen::compile_mir_to_object(mir, path, &options.codegen())
+        })?;
+        return mesh_codegen::link::link_objects(&[object], output_path, None);
+    }
+
+    let mut unit_functions: Vec<Vec<&str>> = vec![Vec::new(); units.names.len()];
+    for func in &mir.functions {
+        if let Some(&owner) = units.owners.get(&func.name) {
+            unit_functions[owner].push(&func.name);
+        }
+    }
+
+    let mut objects = Vec::new();
+    for (idx, functions) in unit_functions.iter().enumerate() {
+        if functions.is_empty() {
+            continue;
+        }
+        let key = cache::module_object_key(&units.keys[idx], functions, settings);
+        objects.push(unit_object(cache, binary, &units.names[idx], &key, |path| {
+            let functions = functions.iter().map(|f| f.to_string()).collect();
+            mesh_codegen::compile_mir_unit_to_object(mir, functions, false, path, &options.codegen())
+        })?);
+    }
+    objects.push(unit_object(cache, binary, "main", &program_key, |path| {
+        let functions = std::collections::HashSet::new();
+        mesh_codegen::compile_mir_unit_to_object(mir, functions, true, path, &options.codegen())
+    })?);
+
+    mesh_codegen::link::link_objects(&objects, output_path, None)
+}
+
+/// Path of the cached object `unit` of `binary` built with `key`, running
+/// `compile` to (re)build it first on a cache miss.
+fn unit_object(
+    cache: &cache::BuildCache,
+    binary: &str,
+    unit: &str,
+    key: &str,
+    compile: impl FnOnce(&Path) -> Result<(), String>,
+) -> Result<PathBuf, String> {
+    if let Some(object) = cache.cached_object(binary, unit, key) {
+        return Ok(object);
+    }
+
+    cache.invalidate_object(binary, unit);
+    let object = cache.object_path(binary, unit);
+    if let Some(parent) = object.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+    }
+    compile(&object)?;
+    cache.store_object_key(binary, unit, key)?;
+    Ok(object)
+}
+
+/// Make the function source locations of a merged module absolute.
+///
+/// Modules are lowered with paths relative to the project root so assertion
+/// messages stay short, but debuggers need to find the files from anywhere.
+fn resolve_source_locations(mir: &mut mesh_codegen::mir::MirModule, root: &Path) {
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    for loc in mir.fn_locations.values_mut() {
+        loc.file = root.join(&loc.file).display().to_string();
+    }
+}
+
+/// All modules of a project lowered to MIR, in compilation order.
+struct LoweredProject {
+    modules: Vec<mesh_codegen::mir::MirModule>,
+    units: ModuleUnits,
+}
+
+/// How the merged program splits into one object file per module.
+/// Indexed like `LoweredProject::modules`.
+struct ModuleUnits {
+    /// Name of each module.
+    names: Vec<String>,
+    /// Cache key of each module (empty when caching is disabled).
+    keys: Vec<String>,
+    /// The module each function comes from. Merging keeps the first
+    /// definition of a name, so the first module to define it owns it.
+    owners: std::collections::HashMap<String, usize>,
+}
+
+/// Parse-check, type-check, and lower every module of a project to MIR.
+///
+/// Diagnostics are reported for all modules before failing. With a cache,
+/// modules whose key is unchanged since the last build are loaded from it
+/// instead of being type-checked and lowered again, and freshly lowered
+/// modules are stored back.
+fn lower_project(
+    root: &Path,
+    project: &discovery::ProjectData,
+    cache: Option<&cache::BuildCache>,
+    debug: bool,
+    diag_opts: &DiagnosticOptions,
+) -> Result<LoweredProject, String> {
+    let CheckedProject {
+        all_exports,
+        all_typeck,
+        mut keys,
+        diagnostics,
+        mut cached_mir,
+    } = check_project(root, project, cache, debug, diag_opts)?;
+
+    // Lower ALL modules to MIR; callers merge them into a single module for codegen
+    let mut mir_modules = Vec::new();
+    for &id in &project.compilation_order {
+        let idx = id.0 as usize;
+        if let Some(mir) = cached_mir[idx].take() {
+            mir_modules.push(mir);
+            continue;
+        }
+
+        let parse = &project.module_parses[idx];
+        let typeck = all_typeck[idx].as_ref()
+            .ok_or("Module was not type-checked")?;
+
+        // Build set of pub function names for module-qualified naming (Phase 41)
+        let module = project.graph.get(id);
+        let pub_fns: std::collections::HashSet<String> = all_exports[idx]
+            .as_ref()
+            .map(|e| e.functions.keys().cloned().collect())
+            .unwrap_or_default();
+
+        // Assertion failures report the path relative to the project root.
+        let source_path = module.path.display().to_string();
+        let mir = mesh_codegen::lower_to_mir_raw(parse, typeck, &module.name, &pub_fns, &source_path, debug)?;
+
+        // A failed cache write only costs a rebuild next time.
+        if let (Some(cache), Some(exports)) = (cache, &all_exports[idx]) {
+            let _ = cache.store_module(&module.name, &keys[idx], exports, &diagnostics[idx], &mir);
+        }
+        mir_modules.push(mir);
+    }
+
+    let keys = project
+        .compilation_order
+        .iter()
+        .map(|id| std::mem::take(&mut keys[id.0 as usize]))
+        .collect();
+    let names = project
+        .compilation_order
+        .iter()
+        .map(|&id| project.graph.get(id).name.clone())
+        .collect();
+    let mut owners = std::collections::HashMap::new();
+    for (idx, mir) in mir_modules.iter().enumerate() {
+        for func in &mir.functions {
+            owners.entry(func.name.clone()).or_insert(idx);
+        }
+    }
+
+    Ok(LoweredProject {
+        modules: mir_modules,
+        units: ModuleUnits { names, keys, owners },
+    })
+}
+
+/// Per-module results of type-checking a project. All Vecs are indexed by
+/// `ModuleId.0`.
+struct CheckedProject {
+    all_exports: Vec<Option<mesh_typeck::ExportedSymbols>>,
+    /// `None` for modules loaded from the cache.
+    all_typeck: Vec<Option<mesh_typeck::TypeckResult>>,
+    /// Cache key of each module (empty when caching is disabled).
+    keys: Vec<String>,
+    /// Rendered warnings and lints of each module, stored with its cache entry.
+    diagnostics: Vec<String>,
+    /// MIR of modules loaded from the cache.
+    cached_mir: Vec<Option<mesh_codegen::mir::MirModule>>,
+}
+
+/// Report parse errors for every module, then type-check every module in
+/// compilation order and report its diagnostics.
+///
+/// Fails after reporting if any module has errors. With a cache, modules
+/// whose key is unchanged are loaded from it instead of being type-checked,
+/// and the warnings and lints stored with them are reported again; `debug`
+/// is part of the key because the cached MIR depends on it.
+/// Shared by the build pipeline and `meshc check`; never touches LLVM.
+fn check_project(
+    root: &Path,
+    project: &discovery::ProjectData,
+    cache: Option<&cache::BuildCache>,
+    debug: bool,
+    diag_opts: &DiagnosticOptions,
+) -> Result<CheckedProject, String> {
     // Check parse errors in ALL modules (not just entry)
     let mut has_errors = false;
     for id in &project.compilation_order {
         let idx = id.0 as usize;
         let parse = &project.module_parses[idx];
         let source = &project.module_sources[idx];
-        let module_path = dir.join(&project.graph.get(*id).path);
+        let module_path = root.join(&project.graph.get(*id).path);
 
         for error in parse.errors() {
             has_errors = true;
@@ -326,17 +1030,56 @@ pub(crate) fn build(
         return Err("Compilation failed due to errors above.".to_string());
     }
 
+    let lint_config = load_lint_config(root)?;
+    // Cached diagnostics are replayed verbatim, so the way they were rendered
+    // is part of the key along with the lint levels.
+    let lint_fingerprint = format!("{:?} {:?} {}", lint_config, diag_opts, root.display());
+
     // Type-check ALL modules in topological order (Phase 39)
     let module_count = project.graph.module_count();
     let mut all_exports: Vec<Option<mesh_typeck::ExportedSymbols>> = (0..module_count).map(|_| None).collect();
     let mut all_typeck: Vec<Option<mesh_typeck::TypeckResult>> = (0..module_count).map(|_| None).collect();
     let mut has_type_errors = false;
 
+    // Incremental cache state: each module's key, MIR loaded from the cache,
+    // and a running fingerprint of the traits and impls checked so far.
+    let mut keys: Vec<String> = vec![String::new(); module_count];
+    let mut diagnostics: Vec<String> = vec![String::new(); module_count];
+    let mut cached_mir: Vec<Option<mesh_codegen::mir::MirModule>> = (0..module_count).map(|_| None).collect();
+    let mut visible_traits = String::new();
+
     for &id in &project.compilation_order {
         let idx = id.0 as usize;
         let parse = &project.module_parses[idx];
         let source = &project.module_sources[idx];
-        let module_path = dir.join(&project.graph.get(id).path);
+        let module_path = root.join(&project.graph.get(id).path);
+
+        if let Some(cache) = cache {
+            let module = project.graph.get(id);
+            let dep_keys: Vec<&str> = module
+                .dependencies
+                .iter()
+                .map(|dep| keys[dep.0 as usize].as_str())
+                .collect();
+            let key = cache::module_key(
+                &module.name,
+                &module.path,
+                source,
+                &dep_keys,
+                &visible_traits,
+                &lint_fingerprint,
+                debug,
+            );
+            let hit = cache.load_module(&module.name, &key);
+            keys[idx] = key;
+            if let Some(cached) = hit {
+                eprint!("{}", cached.diagnostics);
+                visible_traits = cache::traits_fingerprint(&visible_traits, &cached.exports);
+                all_exports[idx] = Some(cached.exports);
+                cached_mir[idx] = Some(cached.mir);
+                continue;
+            }
+        }
 
         // Build ImportContext from already-checked dependencies
         let mut import_ctx = build_import_context(
@@ -365,16 +1108,36 @@ pub(crate) fn build(
             eprint!("{}", rendered);
         }
 
-        // Report warnings
+        // Report warnings, keeping them (and the lints) for the cache entry
+        let mut rendered_warnings = String::new();
         for warning in &typeck.warnings {
             let rendered = mesh_typeck::diagnostics::render_diagnostic(
                 warning, source, &file_name, diag_opts, None,
             );
             eprint!("{}", rendered);
+            rendered_warnings.push_str(&rendered);
         }
 
+        // Lint modules that type-checked cleanly; denied lints are errors
+        if typeck.errors.is_empty() {
+            for finding in mesh_typeck::lint::lint(parse, &typeck, &lint_config) {
+                if let mesh_typeck::error::TypeError::Lint { level: LintLevel::Deny, .. } = finding {
+                    has_type_errors = true;
+                }
+                let rendered = mesh_typeck::diagnostics::render_diagnostic(
+                    &finding, source, &file_name, diag_opts, None,
+                );
+                eprint!("{}", rendered);
+                rendered_warnings.push_str(&rendered);
+            }
+        }
+        diagnostics[idx] = rendered_warnings;
+
         // Collect exports for downstream modules
         let exports = mesh_typeck::collect_exports(parse, &typeck);
+        if cache.is_some() {
+            visible_traits = cache::traits_fingerprint(&visible_traits, &exports);
+        }
         all_exports[idx] = Some(exports);
         all_typeck[idx] = Some(typeck);
     }
@@ -383,53 +1146,13 @@ pub(crate) fn build(
         return Err("Compilation failed due to errors above.".to_string());
     }
 
-    // Lower ALL modules to MIR and merge into a single module for codegen
-    let mut mir_modules = Vec::new();
-    let mut entry_mir_idx = 0;
-    for (i, &id) in project.compilation_order.iter().enumerate() {
-        let idx = id.0 as usize;
-        let parse = &project.module_parses[idx];
-        let typeck = all_typeck[idx].as_ref()
-            .ok_or("Module was not type-checked")?;
-
-        // Build set of pub function names for module-qualified naming (Phase 41)
-        let module_name = &project.graph.get(id).name;
-        let pub_fns: std::collections::HashSet<String> = all_exports[idx]
-            .as_ref()
-            .map(|e| e.functions.keys().cloned().collect())
-            .unwrap_or_default();
-
-        let mir = mesh_codegen::lower_to_mir_raw(parse, typeck, module_name, &pub_fns)?;
-        if id == entry_id {
-            entry_mir_idx = i;
-        }
-        mir_modules.push(mir);
-    }
-    let merged_mir = mesh_codegen::merge_mir_modules(mir_modules, entry_mir_idx);
-
-    // Determine output path
-    let project_name = dir
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("output");
-    let output_path = match output {
-        Some(p) => p.to_path_buf(),
-        None => dir.join(project_name),
-    };
-
-    // Emit LLVM IR if requested
-    if emit_llvm {
-        let ll_path = output_path.with_extension("ll");
-        mesh_codegen::compile_mir_to_llvm_ir(&merged_mir, &ll_path, target)?;
-        eprintln!("  LLVM IR: {}", ll_path.display());
-    }
-
-    // Compile to native binary
-    mesh_codegen::compile_mir_to_binary(&merged_mir, &output_path, opt_level, target, None)?;
-
-    eprintln!("  Compiled: {}", output_path.display());
-
-    Ok(())
+    Ok(CheckedProject {
+        all_exports,
+        all_typeck,
+        keys,
+        diagnostics,
+        cached_mir,
+    })
 }
 
 /// Build an ImportContext for a module from already-checked dependency exports.
@@ -183,14 +183,11 @@ fn compile_and_run_migration(
 
     // Compile the synthetic project
     let output_path = tmp_path.join("_migrate");
-    crate::build(
-        tmp_path,
-        0,
-        false,
-        Some(&output_path),
-        None,
-        &DiagnosticOptions::default(),
-    )
+    let flags = crate::BuildFlags {
+        output: Some(&output_path),
+        ..Default::default()
+    };
+    crate::build(tmp_path, &flags, &DiagnosticOptions::default())
     .map_err(|e| {
         format!(
             "Failed to compile migration {}_{}: {}",
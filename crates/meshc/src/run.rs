@@ -0,0 +1,287 @@
+//! Compile-and-run support for `meshc run`.
+//!
+//! Two modes are supported:
+//! - **Project mode** (`meshc run [dir]`): the directory must contain a
+//!   `main.mpl`. The binary is cached under `<dir>/target/run/`.
+//! - **Single-file mode** (`meshc run hello.mpl`): the file is compiled as a
+//!   standalone `Main` module. The binary is cached in a per-file directory
+//!   under the system temp directory.
+//!
+//! Both modes reuse the `build` pipeline (`crate::compile_project`), including
+//! its incremental module cache, and skip recompilation entirely when a
+//! fingerprint of the sources, the compiler, and the build options matches the
+//! one recorded next to the cached binary.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use mesh_typeck::diagnostics::DiagnosticOptions;
+
+use crate::discovery;
+
+/// A resolved `meshc run` target: what to compile and where to cache it.
+struct RunTarget {
+    /// Directory that module paths are relative to (for diagnostics).
+    root: PathBuf,
+    /// Source files (relative to `root`) that make up the program.
+    files: Vec<PathBuf>,
+    /// Whether this is a single standalone file rather than a project.
+    single_file: bool,
+    /// Directory holding the cached binary and its fingerprint.
+    cache_dir: PathBuf,
+    /// File name of the cached binary.
+    binary_name: String,
+}
+
+/// Execute the `run` subcommand: compile (if needed) and run the program.
+///
+/// `args` are forwarded to the program unchanged. Returns the program's exit
+/// code so the caller can exit with it.
+pub(crate) fn run_command(
+    path: &Path,
+    flags: &crate::BuildFlags,
+    args: &[String],
+    diag_opts: &DiagnosticOptions,
+) -> Result<i32, String> {
+    let target = resolve_target(path)?;
+    let binary = target.cache_dir.join(&target.binary_name);
+    let fingerprint_path = target.cache_dir.join(format!("{}.fingerprint", target.binary_name));
+
+    // Projects share the incremental cache with `meshc build`.
+    let module_cache = if target.single_file {
+        target.cache_dir.join("cache")
+    } else {
+        target.root.join("target").join("cache")
+    };
+    let profile = crate::load_profile(&target.root, flags.release)?;
+    let options = crate::resolve_options(flags, &profile, module_cache);
+
+    let fingerprint = compute_fingerprint(&target.root, &target.files, &options.fingerprint())?;
+    let up_to_date = binary.exists()
+        && std::fs::read_to_string(&fingerprint_path)
+            .map(|stored| stored.trim() == fingerprint)
+            .unwrap_or(false);
+
+    if !up_to_date {
+        std::fs::create_dir_all(&target.cache_dir).map_err(|e| {
+            format!(
+                "Failed to create cache directory '{}': {}",
+                target.cache_dir.display(),
+                e
+            )
+        })?;
+
+        // Drop any stale fingerprint first so a failed build never leaves an
+        // old binary looking fresh.
+        let _ = std::fs::remove_file(&fingerprint_path);
+
+        let project = if target.single_file {
+            discovery::build_single_file_project(&target.root.join(&target.files[0]))?
+        } else {
+            discovery::build_project(&target.root)?
+        };
+        crate::compile_project(&target.root, &project, &binary, &options, diag_opts)?;
+
+        std::fs::write(&fingerprint_path, &fingerprint)
+            .map_err(|e| format!("Failed to write '{}': {}", fingerprint_path.display(), e))?;
+    }
+
+    let status = Command::new(&binary)
+        .args(args)
+        .status()
+        .map_err(|e| format!("Failed to run '{}': {}", binary.display(), e))?;
+
+    Ok(exit_code(status))
+}
+
+/// Resolve a `meshc run` path argument to a project or single-file target.
+fn resolve_target(path: &Path) -> Result<RunTarget, String> {
+    if !path.exists() {
+        return Err(format!("Path '{}' does not exist", path.display()));
+    }
+
+    if path.is_file() {
+        if path.extension().and_then(|e| e.to_str()) != Some("mpl") {
+            return Err(format!("'{}' is not a .mpl file", path.display()));
+        }
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve '{}': {}", path.display(), e))?;
+        let root = canonical
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let file_name = PathBuf::from(canonical.file_name().unwrap_or_default());
+        let stem = canonical
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("main")
+            .to_string();
+
+        // One cache directory per absolute source path, so two `main.mpl`
+        // files in different directories never share a binary.
+        let mut hasher = DefaultHasher::new();
+        canonical.hash(&mut hasher);
+        let cache_dir = std::env::temp_dir()
+            .join("mesh-run")
+            .join(format!("{}-{:016x}", stem, hasher.finish()));
+
+        return Ok(RunTarget {
+            root,
+            files: vec![file_name],
+            single_file: true,
+            cache_dir,
+            binary_name: stem,
+        });
+    }
+
+    let main_mesh = path.join("main.mpl");
+    if !main_mesh.exists() {
+        return Err(format!(
+            "No 'main.mpl' found in '{}'. Pass a .mpl file or a project directory with a main.mpl entry point.",
+            path.display()
+        ));
+    }
+
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve '{}': {}", path.display(), e))?;
+    let binary_name = canonical
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("main")
+        .to_string();
+    let files = discovery::discover_mesh_files(path)?;
+
+    Ok(RunTarget {
+        root: path.to_path_buf(),
+        files,
+        single_file: false,
+        cache_dir: path.join("target").join("run"),
+        binary_name,
+    })
+}
+
+/// Compute a fingerprint of everything that affects the compiled binary.
+///
+/// Covers the compiler version and executable timestamp, the resolved
+/// compile options (see `CompileOptions::fingerprint`), and the path and
+/// contents of every source file. `DefaultHasher` is deterministic for a
+/// given compiler build, which is all the cache needs.
+fn compute_fingerprint(root: &Path, files: &[PathBuf], options: &str) -> Result<String, String> {
+    let mut hasher = DefaultHasher::new();
+    crate::cache::hash_compiler(&mut hasher);
+    options.hash(&mut hasher);
+
+    for relative in files {
+        let full = root.join(relative);
+        let source = std::fs::read(&full)
+            .map_err(|e| format!("Failed to read '{}': {}", full.display(), e))?;
+        relative.hash(&mut hasher);
+        source.hash(&mut hasher);
+    }
+
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+/// Convert a child exit status into the code `meshc run` (or `meshc test`)
+/// should exit with.
+///
+/// Programs killed by a signal map to `128 + signal`, matching shell convention.
+pub(crate) fn exit_code(status: std::process::ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_fingerprint_changes_with_source() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        fs::write(root.join("main.mpl"), "fn main() do\nend\n").unwrap();
+        let files = vec![PathBuf::from("main.mpl")];
+
+        let first = compute_fingerprint(root, &files, "O0").unwrap();
+        assert_eq!(first, compute_fingerprint(root, &files, "O0").unwrap());
+        assert_ne!(first, compute_fingerprint(root, &files, "O2").unwrap());
+
+        fs::write(root.join("main.mpl"), "fn main() do\n  println(\"hi\")\nend\n").unwrap();
+        assert_ne!(first, compute_fingerprint(root, &files, "O0").unwrap());
+    }
+
+    #[test]
+    fn test_fingerprint_follows_profile() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        fs::write(root.join("main.mpl"), "fn main() do\nend\n").unwrap();
+        let files = vec![PathBuf::from("main.mpl")];
+        let flags = crate::BuildFlags::default();
+        let fingerprint = || {
+            let profile = crate::load_profile(root, false).unwrap();
+            let options = crate::resolve_options(&flags, &profile, root.join("cache"));
+            compute_fingerprint(root, &files, &options.fingerprint()).unwrap()
+        };
+
+        let before = fingerprint();
+        fs::write(
+            root.join("mesh.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.dev]\noverflow-checks = false\n",
+        )
+        .unwrap();
+        assert_ne!(before, fingerprint());
+    }
+
+    #[test]
+    fn test_resolve_target_project() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("app");
+        fs::create_dir_all(project.join("lib")).unwrap();
+        fs::write(project.join("main.mpl"), "").unwrap();
+        fs::write(project.join("lib/util.mpl"), "").unwrap();
+
+        let target = resolve_target(&project).unwrap();
+        assert!(!target.single_file);
+        assert_eq!(target.binary_name, "app");
+        assert_eq!(target.cache_dir, project.join("target").join("run"));
+        assert_eq!(
+            target.files,
+            vec![PathBuf::from("lib/util.mpl"), PathBuf::from("main.mpl")]
+        );
+    }
+
+    #[test]
+    fn test_resolve_target_single_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("hello.mpl");
+        fs::write(&file, "").unwrap();
+
+        let target = resolve_target(&file).unwrap();
+        assert!(target.single_file);
+        assert_eq!(target.binary_name, "hello");
+        assert_eq!(target.files, vec![PathBuf::from("hello.mpl")]);
+    }
+
+    #[test]
+    fn test_resolve_target_rejects_non_mpl_and_missing_main() {
+        let tmp = tempfile::tempdir().unwrap();
+        let txt = tmp.path().join("notes.txt");
+        fs::write(&txt, "").unwrap();
+        assert!(resolve_target(&txt).is_err());
+        assert!(resolve_target(tmp.path()).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Test runner support for `meshc test`.
+//!
+//! Collects the `test "name" do ... end` blocks of every module in a project
+//! (or of a single `.mpl` file), compiles them into a test binary, and runs
+//! it. The binary itself runs each test in its own actor and reports results
+//! (see `mesh_rt::testing`); `meshc test` forwards `--filter` and `--json` to
+//! it and exits with its exit code.
+//!
+//! Project test binaries are written to `<dir>/target/test/`. Single-file
+//! test binaries go to a per-file directory under the system temp directory.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use mesh_typeck::diagnostics::DiagnosticOptions;
+
+use crate::discovery;
+
+/// Options for a `meshc test` invocation.
+pub(crate) struct TestOptions<'a> {
+    /// Only run tests whose name contains this text.
+    pub filter: Option<&'a str>,
+    /// Print one JSON object per test instead of human-readable output.
+    pub json: bool,
+    /// Build profile and optimization level of the test binary.
+    pub flags: crate::BuildFlags<'a>,
+}
+
+/// Execute the `test` subcommand: build the test binary and run it.
+///
+/// Returns the test binary's exit code (0 when every selected test passed).
+pub(crate) fn test_command(
+    path: &Path,
+    options: &TestOptions,
+    diag_opts: &DiagnosticOptions,
+) -> Result<i32, String> {
+    if !path.exists() {
+        return Err(format!("Path '{}' does not exist", path.display()));
+    }
+
+    let (root, project, binary, cache_dir) = if path.is_file() {
+        if path.extension().and_then(|e| e.to_str()) != Some("mpl") {
+            return Err(format!("'{}' is not a .mpl file", path.display()));
+        }
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve '{}': {}", path.display(), e))?;
+        let root = canonical
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let project = discovery::build_single_file_project(&canonical)?;
+        let binary = single_file_binary(&canonical);
+        let cache_dir = binary.with_file_name("cache");
+        (root, project, binary, cache_dir)
+    } else {
+        let project = discovery::build_project(path)?;
+        let cache_dir = path.join("target").join("cache");
+        (path.to_path_buf(), project, project_binary(path), cache_dir)
+    };
+
+    if let Some(parent) = binary.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+    }
+
+    let profile = crate::load_profile(&root, options.flags.release)?;
+    let compile_options = crate::resolve_options(&options.flags, &profile, cache_dir);
+    let count = crate::compile_tests(&root, &project, &binary, &compile_options, diag_opts)?;
+    if count == 0 {
+        eprintln!("No tests found in '{}'", path.display());
+        return Ok(0);
+    }
+
+    let status = Command::new(&binary)
+        .args(binary_args(options))
+        .status()
+        .map_err(|e| format!("Failed to run '{}': {}", binary.display(), e))?;
+
+    Ok(crate::run::exit_code(status))
+}
+
+/// Path of the test binary for a project directory.
+fn project_binary(dir: &Path) -> PathBuf {
+    let name = dir
+        .canonicalize()
+        .ok()
+        .and_then(|p| p.file_name().and_then(|n| n.to_str()).map(String::from))
+        .unwrap_or_else(|| "project".to_string());
+    dir.join("target").join("test").join(format!("{}-tests", name))
+}
+
+/// Path of the test binary for a single (canonicalized) source file.
+fn single_file_binary(file: &Path) -> PathBuf {
+    let stem = file
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("main")
+        .to_string();
+    let mut hasher = DefaultHasher::new();
+    file.hash(&mut hasher);
+    std::env::temp_dir()
+        .join("mesh-test")
+        .join(format!("{}-{:016x}", stem, hasher.finish()))
+        .join(format!("{}-tests", stem))
+}
+
+/// Command-line arguments forwarded to the test binary.
+fn binary_args(options: &TestOptions) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(filter) = options.filter {
+        args.push("--filter".to_string());
+        args.push(filter.to_string());
+    }
+    if options.json {
+        args.push("--json".to_string());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_args_forward_filter_and_json() {
+        let none = TestOptions { filter: None, json: false, flags: Default::default() };
+        assert!(binary_args(&none).is_empty());
+
+        let both = TestOptions { filter: Some("math"), json: true, flags: Default::default() };
+        assert_eq!(binary_args(&both), vec!["--filter", "math", "--json"]);
+    }
+
+    #[test]
+    fn test_project_binary_under_target_test() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("app");
+        std::fs::create_dir_all(&project).unwrap();
+
+        assert_eq!(
+            project_binary(&project),
+            project.join("target").join("test").join("app-tests")
+        );
+    }
+}
@@ -149,6 +149,14 @@ fn e2e_pipe() {
     assert_eq!(output, "11\n");
 }
 
+/// `|N>` pipes into argument N.
+#[test]
+fn e2e_pipe_position() {
+    let source = read_fixture("pipe_position.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(output, "a+b+c\n7\n7\npiped\narg\n4\n17\n");
+}
+
 /// SC2: String interpolation with variables.
 #[test]
 fn e2e_string_interp() {
@@ -2764,6 +2772,30 @@ fn e2e_iter_pipeline() {
     assert_eq!(output, "3\n400\n5\n7\n");
 }
 
+/// Extended adapters: take_while, skip_while, flat_map, chain, chunks,
+/// windows, step_by, dedup, dedup_by, scan, and inspect (which stays lazy).
+#[test]
+fn e2e_iter_adapters() {
+    let source = read_fixture("iter_adapters.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "[1, 2, 3]\n[8, 9, 10]\n[1, 10, 2, 20, 3, 30]\n[1, 2, 3, 4]\n[3, 3, 3, 1]\n[2, 6, 12]\n[1, 5, 9]\n[1, 2, 3, 1]\n3\n[1, 3, 6, 10]\nsaw 1\nsaw 2\n2\n"
+    );
+}
+
+/// Iter.repeat/iterate/unfold generators and the min_by, max_by, group_by
+/// and partition terminals.
+#[test]
+fn e2e_iter_generators() {
+    let source = read_fixture("iter_generators.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "12\n[1, 2, 4, 8, 16, 32]\n[5, 4, 3, 2, 1]\nfig\nbanana\n3\nbeet,blueberry\n[2, 4]\n[1, 3, 5]\n"
+    );
+}
+
 // ── Phase 79: Collect E2E Tests ─────────────────────────────────────────
 
 /// Phase 79 COLL-01: List.collect with map, filter, take pipelines and direct call syntax.
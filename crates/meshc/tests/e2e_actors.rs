@@ -305,3 +305,15 @@ fn actors_with_args() {
         output
     );
 }
+
+/// Test 10: Map messages -- receive arms with map and pinned patterns.
+#[test]
+fn actors_map_messages() {
+    let source = read_fixture("actors_map_messages.mpl");
+    let output = compile_and_run_with_timeout(&source, 10);
+    assert!(
+        output.contains("handled error 42"),
+        "Expected 'handled error 42' in output, got: {}",
+        output
+    );
+}
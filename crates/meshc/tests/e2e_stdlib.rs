@@ -230,6 +230,15 @@ fn e2e_file_error_handling() {
     assert_eq!(output, "error\n");
 }
 
+#[test]
+fn e2e_iodata_output() {
+    let source = read_fixture("stdlib_iodata.mpl");
+    let output = compile_and_run(&source);
+    let csv = "id,name\n1,item1\n2,item2\n3,item3\n";
+    let expected = format!("{csv}bytes: 32\n{csv}4,item4\nhi!\nhi!\n");
+    assert_eq!(output, expected);
+}
+
 // ── IO E2E Tests ────────────────────────────────────────────────────────
 
 #[test]
@@ -256,6 +265,102 @@ fn e2e_list_basic() {
     assert_eq!(output, "3\n1\n");
 }
 
+/// Lists are persistent vectors: long append loops, prepend, tail and
+/// indexing all work without copying the whole list.
+#[test]
+fn e2e_list_persistent() {
+    let source = read_fixture("stdlib_list_persistent.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(output, "10000\n9999\n-1\n10000\n5000\n7\n9999\n[1, 2, 3]\n");
+}
+
+/// Fixed-width integers wrap at their width and convert explicitly; Bytes
+/// slice, concatenate, index and round-trip through UTF-8 strings.
+#[test]
+fn e2e_fixed_width_and_bytes() {
+    let source = read_fixture("stdlib_fixed_width.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "4\n-128\n-128\n4464\nout of range\n4294967296\n\
+         18446744073709551615\n9223372036854775807\ntrue\n\
+         <<77, 101, 115, 104>>\n4 77\n<<101, 115, 104, 33>>\n174\n\
+         Mesh!\ninvalid UTF-8 at byte 1\ntrue\n"
+    );
+}
+
+#[test]
+fn e2e_bytes_patterns() {
+    let source = read_fixture("bytes_patterns.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "<<1, 0, 5, 104, 101, 108, 108, 111, 9, 9>>\ntext hello +2\n\
+         number -7\nping\nunknown 7\nempty\n<<104, 101, 97, 100, 44, 1>>\n"
+    );
+}
+
+#[test]
+fn e2e_int_overflow_functions() {
+    let source = read_fixture("int_overflow.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "-9223372036854775808\n-2\n9223372036854775807\n-9223372036854775808\n\
+         overflow\n42\nno quotient\n"
+    );
+}
+
+#[test]
+fn e2e_big_numeric() {
+    let source = read_fixture("stdlib_big_numeric.mpl");
+    let output = compile_and_run(&source);
+    let lines: Vec<&str> = output.trim().lines().collect();
+    assert_eq!(lines.len(), 13, "expected 13 lines, got: {}", output);
+    assert_eq!(
+        lines[..10].join("\n"),
+        "85070591730234615847396907784232501249\ntrue true\n\
+         1267650600228229401496703205376\nfits 9223372036854775807\ntoo big\n\
+         59.97\ntrue\n0.33\n2.35 true\ntrue true"
+    );
+    // Both fields encode as strings so no precision is lost.
+    let json: serde_json::Value = serde_json::from_str(lines[10]).expect("valid JSON");
+    assert_eq!(json["id"], "85070591730234615847396907784232501249");
+    assert_eq!(json["total"], "10.50");
+    assert_eq!(lines[11], "true 10.50");
+    assert_eq!(lines[12], "123456789012345678901234567890 99999999999999999.95");
+}
+
+#[test]
+fn e2e_string_unicode() {
+    let source = read_fixture("stdlib_string_unicode.mpl");
+    let output = compile_and_run(&source);
+    // The fixture spells its accent as "e" + U+0301 COMBINING ACUTE ACCENT.
+    let expected = "5 7 6\nc|a|f|e\u{301}|!\n[104, 233]\n!e\u{301}fac\ne\u{301}!\nat -2: e\u{301}\nat 9: none\n\
+                    no e\n! at 4\n[000042] [cafe\u{301}!...]\ntrue true\n4 6\n6 5\ncafe\u{301}\n";
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn e2e_string_format_specs() {
+    let source = read_fixture("stdlib_format.mpl");
+    let output = compile_and_run(&source);
+    let expected = "[ 1234.57] [0000beef] [--mesh---] [me]\n-00042 +7 FF 101 10 1.5e3\n[  true] [[1, 2]  ]\n\
+                    cart has   7 items at 1.50 {each}\n255-a-ff\nerror: argument 0 (`abc`) is not an Int\n";
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn e2e_map_patterns() {
+    let source = read_fixture("map_patterns.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "error 42\nevent login\nuntyped\n7\n5\n0\nmatch\nexpected 3, got 4\n\
+         found error\nnot found\n"
+    );
+}
+
 // ── List Literal E2E Tests (Phase 26 Plan 02) ────────────────────────────
 
 #[test]
@@ -353,6 +458,15 @@ fn e2e_map_basic() {
     assert_eq!(output, "10\n2\n");
 }
 
+/// Struct and tuple keys hash through their derived Hash and compare with
+/// Eq; the map scales to thousands of keys and keeps insertion order.
+#[test]
+fn e2e_map_hashed_keys() {
+    let source = read_fixture("stdlib_map_hashed_keys.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(output, "1\nstart\n5000\n1234\nfalse\n4999\nfalse\n[3, 1, 2]\n");
+}
+
 #[test]
 fn e2e_map_string_keys() {
     let source = read_fixture("stdlib_map_string_keys.mpl");
@@ -395,6 +509,25 @@ fn e2e_queue_basic() {
     assert_eq!(output, "2\n10\n");
 }
 
+#[test]
+fn e2e_generic_set_queue() {
+    let source = read_fixture("stdlib_generic_set_queue.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "2\ntrue\n#{blue}\ntrue\n1\nfirst\nQueue[second]\ntrue\n[5, 6]\n"
+    );
+}
+
+/// Tuple accessors return the tuple's element types, including structs and
+/// nested tuples, and `Tuple.to_list` converts homogeneous tuples.
+#[test]
+fn e2e_typed_tuple_access() {
+    let source = read_fixture("stdlib_tuple_typed.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(output, "admin: ada\n8\n5\ntrue\n2\na-b-c\nz\n");
+}
+
 // ── JSON E2E Tests (Phase 8 Plan 04) ──────────────────────────────────
 
 #[test]
@@ -1655,3 +1788,27 @@ fn e2e_struct_in_result_roundtrip() {
     let output = compile_and_run(&source);
     assert_eq!(output, "141\n-1\n60\n");
 }
+
+// ── Regex ──────────────────────────────────────────────────────────────
+
+/// `~r` literals and the Regex module: match, captures, named captures,
+/// scan, closure replace, split, and runtime compile errors.
+#[test]
+fn e2e_regex() {
+    let source = read_fixture("stdlib_regex.mpl");
+    let output = compile_and_run(&source);
+    assert_eq!(
+        output,
+        "true\nfalse\nann@mesh.com ann mesh\n06/2024\nabc\nhi NAME, from TEAM\n#1 + #2 = #2\na|b|c\nerror: unclosed group\ntrue\n"
+    );
+}
+
+/// An invalid `~r` pattern fails the build with E0044.
+#[test]
+fn e2e_regex_invalid_literal() {
+    let source = "fn main() do\n  let re = ~r/[a-/\n  println(\"${Regex.is_match(re, \"a\")}\")\nend\n";
+    let result = compile_only(source);
+    assert!(!result.status.success(), "Expected compilation failure for invalid regex literal");
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(stderr.contains("E0044"), "Expected E0044 error, got stderr: {}", stderr);
+}
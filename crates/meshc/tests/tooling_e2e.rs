@@ -2,6 +2,11 @@
 //!
 //! Verifies that the meshc binary's developer-facing subcommands work together:
 //! - `meshc build --json` produces valid JSON diagnostics for type errors
+//! - `meshc build` only rebuilds changed modules and their dependents
+//! - `meshc check` reports diagnostics without producing any output files
+//! - `meshc run` compiles into a cache, forwards args, and reuses the binary
+//! - `meshc test` runs test blocks in isolated actors, filters, and emits JSON
+//!   with crash backtraces
 //! - `meshc fmt` formats files, `meshc fmt --check` verifies formatting
 //! - `meshc init` creates a compilable project
 //! - `meshc repl --help` confirms REPL subcommand availability
@@ -84,6 +89,736 @@ fn test_build_json_output() {
     );
 }
 
+// ── Incremental cache ────────────────────────────────────────────────
+
+#[test]
+fn test_build_rebuilds_only_changed_modules() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(project.join("util.mpl"), "pub fn greeting() -> String do\n  \"one\"\nend\n").unwrap();
+    std::fs::write(project.join("other.mpl"), "pub fn answer() -> Int do\n  42\nend\n").unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "import Util\nimport Other\n\nfn main() do\n  println(Util.greeting())\n  println(\"${Other.answer()}\")\nend\n",
+    )
+    .unwrap();
+
+    let build = || {
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap()])
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let run = Command::new(project.join("app")).output().expect("failed to run binary");
+        String::from_utf8_lossy(&run.stdout).to_string()
+    };
+    let modules = project.join("target").join("cache").join("modules");
+    let entry = |name: &str| std::fs::read(modules.join(format!("{}.json", name))).unwrap();
+    let modified = |name: &str| {
+        std::fs::metadata(modules.join(format!("{}.json", name)))
+            .unwrap()
+            .modified()
+            .unwrap()
+    };
+
+    assert_eq!(build(), "one\n42\n");
+    let (util, main, other) = (entry("Util"), entry("Main"), entry("Other"));
+    let other_written = modified("Other");
+
+    // Editing Util rebuilds Util and Main (which imports it), not Other.
+    std::fs::write(project.join("util.mpl"), "pub fn greeting() -> String do\n  \"two\"\nend\n").unwrap();
+    assert_eq!(build(), "two\n42\n");
+    assert_ne!(entry("Util"), util);
+    assert_ne!(entry("Main"), main);
+    assert_eq!(entry("Other"), other);
+    assert_eq!(modified("Other"), other_written);
+}
+
+#[test]
+fn test_build_caches_objects_and_warnings_per_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(project.join("util.mpl"), "pub fn greeting() -> String do\n  \"one\"\nend\n").unwrap();
+    std::fs::write(
+        project.join("other.mpl"),
+        "pub fn answer() -> Int do\n  let unused = 1\n  42\nend\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "import Util\nimport Other\n\nfn main() do\n  println(Util.greeting())\n  println(\"${Other.answer()}\")\nend\n",
+    )
+    .unwrap();
+
+    let build = || {
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap(), "--no-color"])
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let run = Command::new(project.join("app")).output().expect("failed to run binary");
+        (
+            String::from_utf8_lossy(&run.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )
+    };
+    let objects = project.join("target").join("cache").join("objects").join("app");
+    let modified = |unit: &str| {
+        std::fs::metadata(objects.join(format!("{}.o", unit)))
+            .unwrap()
+            .modified()
+            .unwrap()
+    };
+
+    let (stdout, stderr) = build();
+    assert_eq!(stdout, "one\n42\n");
+    assert!(stderr.contains("unused variable `unused`"), "{}", stderr);
+    let (util, other) = (modified("Util"), modified("Other"));
+
+    // Editing Util recompiles Util's object but reuses Other's, and still
+    // reports the warning stored with Other's cache entry.
+    std::fs::write(project.join("util.mpl"), "pub fn greeting() -> String do\n  \"two\"\nend\n").unwrap();
+    let (stdout, stderr) = build();
+    assert_eq!(stdout, "two\n42\n");
+    assert!(stderr.contains("unused variable `unused`"), "{}", stderr);
+    assert_ne!(modified("Util"), util);
+    assert_eq!(modified("Other"), other);
+}
+
+// ── Debug info ───────────────────────────────────────────────────────
+
+#[test]
+fn test_build_debug_info_maps_to_source_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(project.join("util.mpl"), "pub fn double(x :: Int) -> Int do\n  x * 2\nend\n").unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "import Util\n\nfn main() do\n  let n = Util.double(21)\n  println(\"${n}\")\nend\n",
+    )
+    .unwrap();
+
+    let build = |extra: &[&str]| {
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap(), "--emit-llvm"])
+            .args(extra)
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        std::fs::read_to_string(project.join("app.ll")).unwrap()
+    };
+
+    // Debug info is on by default at opt-level 0.
+    let ir = build(&[]);
+    assert!(ir.contains("!DICompileUnit("), "IR: {}", ir);
+    assert!(ir.contains("filename: \"main.mpl\""), "IR: {}", ir);
+    assert!(ir.contains("filename: \"util.mpl\""), "IR: {}", ir);
+    assert!(ir.contains("!DISubprogram(name: \"mesh_main\""), "IR: {}", ir);
+    assert!(ir.contains("!DILocation(line: 4"), "IR: {}", ir);
+    assert!(ir.contains("!DILocalVariable(name: \"n\""), "IR: {}", ir);
+
+    // Optimized builds only carry it with --debug.
+    assert!(!build(&["--opt-level", "2"]).contains("DISubprogram"));
+    assert!(build(&["--opt-level", "2", "--debug"]).contains("DISubprogram"));
+    // --no-debug drops it, statement locations included, at any level.
+    let ir = build(&["--no-debug"]);
+    assert!(!ir.contains("DISubprogram"), "IR: {}", ir);
+    assert!(!ir.contains("!DILocation("), "IR: {}", ir);
+
+    let run = Command::new(project.join("app")).output().expect("failed to run binary");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "42\n");
+}
+
+// ── Build profiles ───────────────────────────────────────────────────
+
+#[test]
+fn test_build_release_profile_from_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("mesh.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.release]\nlto = true\nstack-size = 1048576\nschedulers = 2\noutput = \"dist/app-release\"\n",
+    )
+    .unwrap();
+    std::fs::write(project.join("main.mpl"), "fn main() do\n  println(\"hi\")\nend\n").unwrap();
+
+    let build = |extra: &[&str]| {
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap(), "--emit-llvm"])
+            .args(extra)
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    };
+
+    // The dev profile is empty: debug info, default runtime settings.
+    build(&[]);
+    let ir = std::fs::read_to_string(project.join("app.ll")).unwrap();
+    assert!(ir.contains("DISubprogram"), "IR: {}", ir);
+    assert!(ir.contains("call void @mesh_rt_init_actor(i32 0)"), "IR: {}", ir);
+    assert!(!ir.contains("call void @mesh_rt_set_stack_size"), "IR: {}", ir);
+
+    build(&["--release"]);
+    let ir = std::fs::read_to_string(project.join("dist").join("app-release.ll")).unwrap();
+    assert!(!ir.contains("DISubprogram"), "IR: {}", ir);
+    assert!(ir.contains("call void @mesh_rt_init_actor(i32 2)"), "IR: {}", ir);
+    assert!(ir.contains("call void @mesh_rt_set_stack_size(i64 1048576)"), "IR: {}", ir);
+    let run = Command::new(project.join("dist").join("app-release"))
+        .output()
+        .expect("failed to run binary");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "hi\n");
+
+    // Invalid settings are reported against the manifest.
+    std::fs::write(
+        project.join("mesh.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.release]\nopt-level = 7\n",
+    )
+    .unwrap();
+    let output = Command::new(meshc_bin())
+        .args(["build", project.to_str().unwrap(), "--release"])
+        .output()
+        .expect("failed to run meshc build");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("opt-level must be between 0 and 3, got 7"), "{}", stderr);
+}
+
+#[test]
+fn test_build_overflow_checks_follow_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "fn bump(n :: Int) -> Int do\n  n + 1\nend\n\nfn main() do\n  println(\"${bump(9223372036854775807)}\")\nend\n",
+    )
+    .unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap()])
+            .args(extra)
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        Command::new(project.join("app")).output().expect("failed to run binary")
+    };
+
+    // Debug builds trap at the overflowing operation.
+    let debug = run(&[]);
+    assert!(!debug.status.success());
+    let stderr = String::from_utf8_lossy(&debug.stderr);
+    assert!(stderr.contains("Mesh panic at main.mpl:2: Int overflow in `+`"), "{}", stderr);
+
+    // Release builds wrap unless the profile turns the checks on.
+    let release = run(&["--release"]);
+    assert_eq!(String::from_utf8_lossy(&release.stdout), "-9223372036854775808\n");
+
+    std::fs::write(
+        project.join("mesh.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.release]\noverflow-checks = true\n",
+    )
+    .unwrap();
+    assert!(!run(&["--release"]).status.success());
+}
+
+#[test]
+fn test_build_overflow_checks_cover_negation_and_division() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    let functions = "fn negate(n :: Int) -> Int do\n  -n\nend\n\n\
+        fn divide(a :: Int, b :: Int) -> Int do\n  a / b\nend\n\n\
+        fn remainder(a :: Int, b :: Int) -> Int do\n  a % b\nend\n\n";
+
+    let run = |call: &str, extra: &[&str]| {
+        std::fs::write(
+            project.join("main.mpl"),
+            format!(
+                "{}fn main() do\n  let min = -9223372036854775807 - 1\n  println(\"${{{}}}\")\nend\n",
+                functions, call
+            ),
+        )
+        .unwrap();
+        let output = Command::new(meshc_bin())
+            .args(["build", project.to_str().unwrap()])
+            .args(extra)
+            .output()
+            .expect("failed to run meshc build");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        Command::new(project.join("app")).output().expect("failed to run binary")
+    };
+
+    for (call, location) in [
+        ("negate(min)", "main.mpl:2: Int overflow in `-`"),
+        ("divide(min, -1)", "main.mpl:6: Int overflow in `/`"),
+        ("remainder(min, -1)", "main.mpl:10: Int overflow in `%`"),
+    ] {
+        let output = run(call, &[]);
+        assert!(!output.status.success(), "{} should panic", call);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains(&format!("Mesh panic at {}", location)), "{}: {}", call, stderr);
+    }
+
+    // The same operations on other operands are unaffected.
+    assert_eq!(String::from_utf8_lossy(&run("divide(min, 2)", &[]).stdout), "-4611686018427387904\n");
+    assert_eq!(String::from_utf8_lossy(&run("remainder(7, -1)", &[]).stdout), "0\n");
+    assert_eq!(String::from_utf8_lossy(&run("negate(min + 1)", &[]).stdout), "9223372036854775807\n");
+
+    // Release builds wrap instead of trapping: there is no SIGFPE from the
+    // hardware division of Int.min by -1.
+    for (call, expected) in [
+        ("negate(min)", "-9223372036854775808\n"),
+        ("divide(min, -1)", "-9223372036854775808\n"),
+        ("remainder(min, -1)", "0\n"),
+        ("divide(7, -1)", "-7\n"),
+    ] {
+        let output = run(call, &["--release"]);
+        assert!(output.status.success(), "{}: {}", call, String::from_utf8_lossy(&output.stderr));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), expected, "{}", call);
+    }
+}
+
+// ── Check ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_check_valid_project_writes_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("lib");
+    std::fs::create_dir_all(&project).unwrap();
+    // Libraries without a main.mpl can be checked too.
+    std::fs::write(project.join("math.mpl"), "pub fn double(x :: Int) -> Int do\n  x * 2\nend\n").unwrap();
+    std::fs::write(project.join("geometry.mpl"), "import Math\n\npub fn area(w :: Int) -> Int do\n  Math.double(w)\nend\n").unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["check", project.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc check");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Checked: 2 modules"));
+
+    let mut entries: Vec<String> = std::fs::read_dir(&project)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    entries.sort();
+    assert_eq!(entries, vec!["geometry.mpl", "math.mpl"]);
+}
+
+#[test]
+fn test_check_json_reports_errors_in_every_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("proj");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(project.join("main.mpl"), "let x :: Int = \"hello\"\n").unwrap();
+    std::fs::write(project.join("util.mpl"), "let y :: String = 1\n").unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["check", "--json", project.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc check --json");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let diagnostics: Vec<serde_json::Value> = serde_json::Deserializer::from_str(&stderr)
+        .into_iter::<serde_json::Value>()
+        .map(|v| v.expect("stderr should only contain JSON"))
+        .collect();
+    let files: Vec<&str> = diagnostics
+        .iter()
+        .filter(|d| d["code"].as_str().is_some_and(|c| c.starts_with('E')))
+        .filter_map(|d| d["file"].as_str())
+        .collect();
+    assert!(files.iter().any(|f| f.ends_with("main.mpl")), "{}", stderr);
+    assert!(files.iter().any(|f| f.ends_with("util.mpl")), "{}", stderr);
+}
+
+#[test]
+fn test_check_single_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("script.mpl");
+    std::fs::write(&file, "fn main() do\n  println(\"hi\")\nend\n").unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["check", file.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc check");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!dir.path().join("script").exists());
+}
+
+#[test]
+fn test_check_lint_levels_from_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("linted");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "fn main() do\n  let unused = 1\n  let x = 2\n  let x = x + 1\n  println(\"${x}\")\nend\n",
+    )
+    .unwrap();
+    let manifest = "[package]\nname = \"linted\"\nversion = \"0.1.0\"\n\n[lints]\n";
+
+    // Default levels: unused_variable warns, shadowing is opt-in, and the
+    // check passes.
+    let check = || {
+        Command::new(meshc_bin())
+            .args(["check", "--json", project.to_str().unwrap()])
+            .output()
+            .expect("failed to run meshc check")
+    };
+    let output = check();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let codes: Vec<(String, String)> = serde_json::Deserializer::from_str(&stderr)
+        .into_iter::<serde_json::Value>()
+        .map(|v| v.expect("stderr should only contain JSON"))
+        .map(|d| (d["code"].as_str().unwrap().to_string(), d["severity"].as_str().unwrap().to_string()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![("W0003".to_string(), "warning".to_string())]
+    );
+
+    // Denying a lint fails the check; enabling shadowing reports it.
+    std::fs::write(
+        project.join("mesh.toml"),
+        format!("{}unused_variable = \"deny\"\nshadowing = \"warn\"\n", manifest),
+    )
+    .unwrap();
+    let output = check();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("\"code\":\"W0003\""), "{}", stderr);
+    assert!(stderr.contains("\"severity\":\"error\""), "{}", stderr);
+    assert!(stderr.contains("W0007"), "{}", stderr);
+
+    // Allowing a lint silences it.
+    std::fs::write(
+        project.join("mesh.toml"),
+        format!("{}unused_variable = \"allow\"\n", manifest),
+    )
+    .unwrap();
+    let output = check();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).trim().is_empty());
+
+    // Unknown lint names are rejected.
+    std::fs::write(project.join("mesh.toml"), format!("{}unused = \"allow\"\n", manifest)).unwrap();
+    let output = check();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown lint `unused`"));
+}
+
+// ── Explain ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_explain_prints_example_and_fix() {
+    let output = Command::new(meshc_bin())
+        .args(["explain", "e0012"])
+        .output()
+        .expect("failed to run meshc explain");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("E0012: non-exhaustive match\n"), "{}", stdout);
+    assert!(stdout.contains("Example:"), "{}", stdout);
+    assert!(stdout.contains("Fixed:"), "{}", stdout);
+
+    let output = Command::new(meshc_bin())
+        .args(["explain", "E9999"])
+        .output()
+        .expect("failed to run meshc explain");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no explanation for diagnostic code `E9999`"));
+}
+
+#[test]
+fn test_explain_lists_codes() {
+    let output = Command::new(meshc_bin())
+        .arg("explain")
+        .output()
+        .expect("failed to run meshc explain");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.lines().any(|l| l == "E0001  mismatched types"), "{}", stdout);
+    assert!(stdout.lines().any(|l| l == "C0001  fatal compiler error"), "{}", stdout);
+}
+
+#[test]
+fn test_fatal_errors_are_c0001() {
+    // C0001 has no source example in the registry; check its description's
+    // first case (a project without main.mpl) here instead.
+    let dir = tempfile::tempdir().unwrap();
+    let output = Command::new(meshc_bin())
+        .args(["build", "--json", dir.path().to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc build");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let diag: serde_json::Value = serde_json::from_str(stderr.trim()).expect("one JSON diagnostic");
+    assert_eq!(diag["code"], "C0001");
+    assert!(diag["message"].as_str().unwrap().contains("main.mpl"), "{}", stderr);
+}
+
+// ── Doc ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_doc_generates_linked_html_with_search_index() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("lib");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("geometry.mpl"),
+        "##! Points in the plane.\n\n## A point.\npub struct Point do\n  x :: Int\n  y :: Int\nend\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.join("shapes.mpl"),
+        "from Geometry import Point\n\n## The origin.\npub fn origin() do\n  Point { x: 0, y: 0 }\nend\n\nfn helper() do\n  1\nend\n",
+    )
+    .unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["doc", project.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc doc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let docs = project.join("target").join("doc");
+    let index = std::fs::read_to_string(docs.join("index.html")).unwrap();
+    assert!(index.contains("Points in the plane."), "{}", index);
+
+    // The return type is inferred and links to the defining module.
+    let shapes = std::fs::read_to_string(docs.join("Shapes.html")).unwrap();
+    assert!(shapes.contains("<p>The origin.</p>"), "{}", shapes);
+    assert!(shapes.contains("fn origin() -&gt; <a href=\"Geometry.html#struct.Point\">"), "{}", shapes);
+    assert!(!shapes.contains("helper"), "{}", shapes);
+
+    let search = std::fs::read_to_string(docs.join("search-index.js")).unwrap();
+    assert!(search.contains("\"Geometry.Point\""), "{}", search);
+}
+
+#[test]
+fn test_doc_markdown_single_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("util.mpl");
+    std::fs::write(&file, "## Doubles `x`.\npub fn double(x) = x * 2\n").unwrap();
+    let out = dir.path().join("docs");
+
+    let output = Command::new(meshc_bin())
+        .args(["doc", "--format", "markdown", "--output", out.to_str().unwrap(), file.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc doc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let page = std::fs::read_to_string(out.join("Main.md")).unwrap();
+    assert!(page.contains("fn double(x :: Int) -&gt; Int"), "{}", page);
+    assert!(page.contains("Doubles `x`."), "{}", page);
+    assert!(out.join("index.md").exists());
+}
+
+// ── Run ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_run_single_file_forwards_args() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("hello.mpl");
+    std::fs::write(
+        &file,
+        "fn main() do\n  let args = Env.args()\n  println(List.get(args, 1))\n  println(List.get(args, 2))\nend\n",
+    )
+    .unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["run", file.to_str().unwrap(), "--", "alpha", "--beta"])
+        .output()
+        .expect("failed to run meshc run");
+
+    assert!(
+        output.status.success(),
+        "meshc run failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, "alpha\n--beta\n");
+}
+
+#[test]
+fn test_run_project_reuses_cached_binary() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(project.join("main.mpl"), "fn main() do\n  println(\"one\")\nend\n").unwrap();
+
+    let run = || {
+        Command::new(meshc_bin())
+            .args(["run", project.to_str().unwrap()])
+            .output()
+            .expect("failed to run meshc run")
+    };
+
+    let first = run();
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stderr));
+    assert_eq!(String::from_utf8_lossy(&first.stdout), "one\n");
+
+    let binary = project.join("target").join("run").join("app");
+    let built_at = std::fs::metadata(&binary).unwrap().modified().unwrap();
+
+    // Unchanged sources: the cached binary is executed as-is.
+    let second = run();
+    assert!(second.status.success());
+    assert_eq!(String::from_utf8_lossy(&second.stdout), "one\n");
+    assert_eq!(std::fs::metadata(&binary).unwrap().modified().unwrap(), built_at);
+
+    // Changed sources: the binary is rebuilt.
+    std::fs::write(project.join("main.mpl"), "fn main() do\n  println(\"two\")\nend\n").unwrap();
+    let third = run();
+    assert!(third.status.success());
+    assert_eq!(String::from_utf8_lossy(&third.stdout), "two\n");
+}
+
+#[test]
+fn test_run_compile_error_exits_nonzero() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("bad.mpl");
+    std::fs::write(&file, "let x :: Int = \"hello\"\n").unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["run", file.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc run");
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+// ── Test ─────────────────────────────────────────────────────────────
+
+/// A project with tests in two modules; `Math: subtracts` fails.
+fn write_test_project(project: &std::path::Path) {
+    std::fs::create_dir_all(project).unwrap();
+    std::fs::write(
+        project.join("math.mpl"),
+        "pub fn add(a :: Int, b :: Int) -> Int do\n  a + b\nend\n\n\
+         test \"adds\" do\n  assert_eq(add(1, 2), 3)\nend\n\n\
+         test \"subtracts\" do\n  assert_eq(add(1, 2), 4)\nend\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.join("main.mpl"),
+        "import Math\n\nfn main() do\n  println(\"unused\")\nend\n\n\
+         test \"truthy\" do\n  assert(Math.add(2, 2) == 4)\n  refute(false)\nend\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_test_reports_pass_and_fail() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    write_test_project(&project);
+
+    let output = Command::new(meshc_bin())
+        .args(["test", project.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc test");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "stdout: {}", stdout);
+    assert!(stdout.contains("running 3 tests"), "stdout: {}", stdout);
+    assert!(stdout.contains("test Math: adds ... ok"), "stdout: {}", stdout);
+    assert!(stdout.contains("test Math: subtracts ... FAILED"), "stdout: {}", stdout);
+    // The crash of the failing test does not stop the tests after it.
+    assert!(stdout.contains("test Main: truthy ... ok"), "stdout: {}", stdout);
+    assert!(stdout.contains("math.mpl:10: assert_eq failed"), "stdout: {}", stdout);
+    assert!(stdout.contains("left:  3"), "stdout: {}", stdout);
+    assert!(stdout.contains("right: 4"), "stdout: {}", stdout);
+    assert!(stdout.contains("2 passed; 1 failed"), "stdout: {}", stdout);
+}
+
+#[test]
+fn test_test_filter_and_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("app");
+    write_test_project(&project);
+
+    let output = Command::new(meshc_bin())
+        .args(["test", project.to_str().unwrap(), "--filter", "adds", "--json"])
+        .output()
+        .expect("failed to run meshc test");
+
+    assert!(
+        output.status.success(),
+        "meshc test failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let events: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be JSON"))
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["type"], "test");
+    assert_eq!(events[0]["name"], "adds");
+    assert_eq!(events[0]["module"], "Math");
+    assert_eq!(events[0]["status"], "passed");
+    assert_eq!(events[1]["type"], "summary");
+    assert_eq!(events[1]["passed"], 1);
+    assert_eq!(events[1]["filtered_out"], 2);
+}
+
+#[test]
+fn test_test_single_file_without_main() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("checks.mpl");
+    std::fs::write(&file, "test \"strings\" do\n  assert_eq(\"a\" <> \"b\", \"ab\")\nend\n").unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["test", file.to_str().unwrap()])
+        .output()
+        .expect("failed to run meshc test");
+
+    assert!(
+        output.status.success(),
+        "meshc test failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("1 passed; 0 failed"));
+}
+
+#[test]
+fn test_test_failure_reports_backtrace() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("checks.mpl");
+    std::fs::write(
+        &file,
+        "fn check(x :: Int) do\n  assert(x > 0)\nend\n\ntest \"positive\" do\n  check(0)\nend\n",
+    )
+    .unwrap();
+
+    let output = Command::new(meshc_bin())
+        .args(["test", file.to_str().unwrap(), "--json"])
+        .output()
+        .expect("failed to run meshc test");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let event: serde_json::Value =
+        serde_json::from_str(stdout.lines().next().unwrap()).expect("test event should be JSON");
+    assert_eq!(event["status"], "failed");
+    assert!(event["message"].as_str().unwrap().contains("checks.mpl:2"), "event: {}", event);
+    let backtrace = event["backtrace"].as_str().expect("failure should carry a backtrace");
+    assert!(backtrace.contains("check/1"), "backtrace: {}", backtrace);
+    assert!(backtrace.contains("test \"positive\"/0"), "backtrace: {}", backtrace);
+}
+
 // ── Formatter ────────────────────────────────────────────────────────
 
 #[test]